@@ -89,7 +89,7 @@ pub struct ConfigResolver {
 
 impl ConfigResolver {
     /// Create a new resolver from a raw JSON config value.
-    #[cfg(feature = "napi")]
+    #[cfg(any(test, feature = "napi"))]
     pub fn from_value(raw_config: Value) -> Self {
         Self { raw_config, editorconfig: None, cached_options: None }
     }
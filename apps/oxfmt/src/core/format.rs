@@ -246,3 +246,73 @@ impl SourceFormatter {
         self.format_by_external_formatter(&source_text, path, parser_name, external_options)
     }
 }
+
+// ---
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use cow_utils::CowUtils;
+    use serde_json::json;
+
+    use super::*;
+    use crate::core::ConfigResolver;
+
+    fn format(file_name: &str, source_text: &str, config: Value) -> String {
+        let mut resolver = ConfigResolver::from_value(config);
+        resolver.build_and_validate().unwrap();
+        let strategy = FormatFileStrategy::try_from(PathBuf::from(file_name)).unwrap();
+        let resolved_options = resolver.resolve(&strategy);
+        match SourceFormatter::new(1).format(&strategy, source_text, resolved_options) {
+            FormatResult::Success { code, .. } => code,
+            FormatResult::Error(errors) => panic!("{errors:?}"),
+        }
+    }
+
+    #[test]
+    fn test_insert_final_newline() {
+        let test_cases = vec![
+            ("test.ts", "const a = 1\nconst b = 2\n", "const a = 1;\nconst b = 2;"),
+            ("test.ts", "const a = 1\n\n\n\n", "const a = 1;"),
+            ("test.ts", "", ""),
+            ("test.ts", "\n\n", ""),
+            ("test.toml", "[foo]\nbar = 1\nbaz = 2\n", "[foo]\nbar = 1\nbaz = 2"),
+            ("test.toml", "[foo]\nbar = 1\n\n\n", "[foo]\nbar = 1"),
+            ("test.toml", "", ""),
+        ];
+
+        for (file_name, source_text, expected) in test_cases {
+            for (end_of_line, line_ending) in [("lf", "\n"), ("crlf", "\r\n"), ("cr", "\r")] {
+                // TOML formatter only supports `lf` and `crlf`
+                let line_ending = if file_name == "test.toml" && end_of_line == "cr" {
+                    "\n"
+                } else {
+                    line_ending
+                };
+
+                let code = format(
+                    file_name,
+                    source_text,
+                    json!({ "insertFinalNewline": false, "endOfLine": end_of_line }),
+                );
+                assert_eq!(
+                    code,
+                    expected.cow_replace('\n', line_ending),
+                    "{file_name} {source_text:?} with endOfLine={end_of_line}"
+                );
+
+                // Formatters already end the output with a line ending
+                let code = format(file_name, source_text, json!({ "endOfLine": end_of_line }));
+                assert_eq!(
+                    code.trim_end(),
+                    expected.cow_replace('\n', line_ending),
+                    "{file_name} {source_text:?} with endOfLine={end_of_line}"
+                );
+                if !expected.is_empty() {
+                    assert!(code.ends_with(line_ending), "{file_name} {source_text:?}");
+                }
+            }
+        }
+    }
+}
@@ -1721,7 +1721,7 @@ export type BinaryOperator =
 
 export type LogicalOperator = "||" | "&&" | "??";
 
-export type UnaryOperator = "+" | "-" | "!" | "~" | "typeof" | "void" | "delete";
+export type UnaryOperator = "+" | "-" | "!" | "~" | "typeof" | "void" | "delete" | "throw";
 
 export type UpdateOperator = "++" | "--";
 
@@ -235,7 +235,15 @@ impl<'a> Codegen<'a> {
         }
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
-        let code = self.code.into_string();
+        let mut code = self.code.into_string();
+        if !self.options.minify
+            && let Some(insert_final_newline) = self.options.insert_final_newline
+        {
+            code.truncate(code.trim_end_matches('\n').len());
+            if insert_final_newline && !code.is_empty() {
+                code.push('\n');
+            }
+        }
         #[cfg(feature = "sourcemap")]
         let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
         CodegenReturn {
@@ -43,6 +43,14 @@ pub struct CodegenOptions {
     ///
     /// Default is `0`.
     pub initial_indent: u32,
+
+    /// End the output with exactly one `\n` if `Some(true)`, or with none if `Some(false)`.
+    ///
+    /// `None` leaves the output as printed. Empty output stays empty.
+    /// Has no effect on minified output.
+    ///
+    /// Default is `Some(true)`.
+    pub insert_final_newline: Option<bool>,
 }
 
 impl Default for CodegenOptions {
@@ -55,6 +63,7 @@ impl Default for CodegenOptions {
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            insert_final_newline: Some(true),
         }
     }
 }
@@ -70,6 +79,7 @@ impl CodegenOptions {
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            insert_final_newline: Some(true),
         }
     }
 
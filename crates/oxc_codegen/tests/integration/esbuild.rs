@@ -1036,9 +1036,9 @@ fn test_avoid_slash_script() {
     test("//! </script\n//! >/script\n//! /script", "//! <\\/script\n//! >/script\n//! /script\n");
     test("//! </SCRIPT\n//! >/SCRIPT\n//! /SCRIPT", "//! <\\/SCRIPT\n//! >/SCRIPT\n//! /SCRIPT\n");
     test("//! </ScRiPt\n//! >/ScRiPt\n//! /ScRiPt", "//! <\\/ScRiPt\n//! >/ScRiPt\n//! /ScRiPt\n");
-    test("/*! </script \n</script */", "/*! <\\/script \n<\\/script */");
-    test("/*! </SCRIPT \n</SCRIPT */", "/*! <\\/SCRIPT \n<\\/SCRIPT */");
-    test("/*! </ScRiPt \n</ScRiPt */", "/*! <\\/ScRiPt \n<\\/ScRiPt */");
+    test("/*! </script \n</script */", "/*! <\\/script \n<\\/script */\n");
+    test("/*! </SCRIPT \n</SCRIPT */", "/*! <\\/SCRIPT \n<\\/SCRIPT */\n");
+    test("/*! </ScRiPt \n</ScRiPt */", "/*! <\\/ScRiPt \n<\\/ScRiPt */\n");
     test("String.raw`</script`", "String.raw`<\\/script`;\n");
     test("String.raw`</script${a}`", "String.raw`<\\/script${a}`;\n");
     test("String.raw`${a}</script`", "String.raw`${a}<\\/script`;\n");
@@ -642,6 +642,22 @@ fn v8_intrinsics() {
     );
 }
 
+#[test]
+fn throw_expressions() {
+    let parse_opts = oxc_parser::ParseOptions {
+        allow_throw_expressions: true,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    test_with_parse_options(
+        "const x = a ?? throw new Error()",
+        "const x = a ?? throw new Error();\n",
+        parse_opts,
+    );
+    test_with_parse_options("f(() => throw e)", "f(() => throw e);\n", parse_opts);
+    test_with_parse_options("x = a ? throw b : c", "x = a ? throw b : c;\n", parse_opts);
+}
+
 #[test]
 fn indentation() {
     // Test default - tabs with width 1
@@ -691,3 +707,33 @@ fn indentation() {
         CodegenOptions { initial_indent: 1, ..CodegenOptions::default() },
     );
 }
+
+#[test]
+fn insert_final_newline() {
+    let as_printed = CodegenOptions { insert_final_newline: None, ..CodegenOptions::default() };
+    let without = CodegenOptions { insert_final_newline: Some(false), ..CodegenOptions::default() };
+
+    test_options("let foo = 1;", "let foo = 1;\n", CodegenOptions::default());
+    test_options("let foo = 1;", "let foo = 1;", without.clone());
+
+    // Empty output stays empty.
+    test_options("", "", CodegenOptions::default());
+    test_options("", "", without.clone());
+
+    // Output ending with a comment.
+    test_options("/*! legal */", "/*! legal */\n", CodegenOptions::default());
+    test_options("/*! legal */", "/*! legal */", without.clone());
+    test_options("foo;\n//! legal\n\n", "foo;\n//! legal\n", CodegenOptions::default());
+    test_options("foo;\n//! legal\n\n", "foo;\n//! legal", without);
+
+    // `None` leaves the output as printed.
+    test_options("/*! legal */", "/*! legal */", as_printed.clone());
+    test_options("let foo = 1;", "let foo = 1;\n", as_printed);
+
+    // Minified output is left unchanged.
+    test_options(
+        "let foo = 1;",
+        "let foo=1;",
+        CodegenOptions { insert_final_newline: Some(true), ..CodegenOptions::minify() },
+    );
+}
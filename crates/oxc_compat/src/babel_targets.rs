@@ -45,12 +45,11 @@ impl TryFrom<BabelTargets> for EngineTargets {
                     if matches!(key.as_str(), "esmodules" | "browsers") {
                         continue;
                     }
-                    // TODO: Implement `Version::from_number`
-                    if matches!(value, BabelTargetsValue::Int(_) | BabelTargetsValue::Float(_)) {
-                        continue;
-                    }
-                    let BabelTargetsValue::String(v) = value else {
-                        return Err(format!("{value:?} is not a string for {key}."));
+                    let v = match value {
+                        BabelTargetsValue::String(v) => v,
+                        BabelTargetsValue::Int(v) => v.to_string(),
+                        BabelTargetsValue::Float(v) => v.to_string(),
+                        _ => return Err(format!("{value:?} is not a string for {key}.")),
                     };
                     // TODO: Implement this target.
                     if key == "node" && v == "current" {
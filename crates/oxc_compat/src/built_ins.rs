@@ -0,0 +1,1150 @@
+//! Availability of runtime built-ins in target engines.
+//!
+//! Unlike [`ESFeature`](crate::ESFeature)s, built-ins can be polyfilled instead of transformed.
+//! Versions are from the [MDN browser compat data], plus an [`Engine::Es`] entry
+//! for the edition which added the built-in.
+//!
+//! Web platform APIs are not part of ECMAScript, so they have no `Es` entry and are assumed to be
+//! available when only an `esXXXX` target is given.
+//!
+//! [MDN browser compat data]: <https://github.com/mdn/browser-compat-data>
+
+use browserslist::Version;
+use rustc_hash::FxHashMap;
+use std::sync::OnceLock;
+
+use super::{Engine, EngineTargets};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BuiltIn {
+    // Globals
+    /// `Map`
+    Map,
+    /// `Promise`
+    Promise,
+    /// `Set`
+    Set,
+    /// `Symbol`, including the well-known symbols of ES2015 and `Symbol.for` / `Symbol.keyFor`.
+    Symbol,
+    /// `WeakMap`
+    WeakMap,
+    /// `WeakSet`
+    WeakSet,
+    /// `globalThis`
+    GlobalThis,
+    /// `AggregateError`
+    AggregateError,
+    /// `FinalizationRegistry`
+    FinalizationRegistry,
+    /// `WeakRef`
+    WeakRef,
+
+    // Static methods
+    /// `Array.from`
+    ArrayFrom,
+    /// `Array.of`
+    ArrayOf,
+    /// `Math.sign`
+    MathSign,
+    /// `Math.trunc`
+    MathTrunc,
+    /// `Number.isFinite`
+    NumberIsFinite,
+    /// `Number.isInteger`
+    NumberIsInteger,
+    /// `Number.isNaN`
+    NumberIsNaN,
+    /// `Number.isSafeInteger`
+    NumberIsSafeInteger,
+    /// `Object.assign`
+    ObjectAssign,
+    /// `Object.is`
+    ObjectIs,
+    /// `Object.setPrototypeOf`
+    ObjectSetPrototypeOf,
+    /// `String.fromCodePoint`
+    StringFromCodePoint,
+    /// `String.raw`
+    StringRaw,
+    /// `Object.entries`
+    ObjectEntries,
+    /// `Object.getOwnPropertyDescriptors`
+    ObjectGetOwnPropertyDescriptors,
+    /// `Object.values`
+    ObjectValues,
+    /// `Object.fromEntries`
+    ObjectFromEntries,
+    /// `Promise.allSettled`
+    PromiseAllSettled,
+    /// `Promise.any`
+    PromiseAny,
+    /// `Object.hasOwn`
+    ObjectHasOwn,
+
+    // Well-known symbols added after ES2015
+    /// `Symbol.asyncIterator`
+    SymbolAsyncIterator,
+
+    // Instance methods
+    /// `Array.prototype.copyWithin`
+    ArrayPrototypeCopyWithin,
+    /// `Array.prototype.fill`
+    ArrayPrototypeFill,
+    /// `Array.prototype.find`
+    ArrayPrototypeFind,
+    /// `Array.prototype.findIndex`
+    ArrayPrototypeFindIndex,
+    /// `String.prototype.codePointAt`
+    StringPrototypeCodePointAt,
+    /// `String.prototype.endsWith`
+    StringPrototypeEndsWith,
+    /// `String.prototype.includes`
+    StringPrototypeIncludes,
+    /// `String.prototype.repeat`
+    StringPrototypeRepeat,
+    /// `String.prototype.startsWith`
+    StringPrototypeStartsWith,
+    /// `Array.prototype.includes`
+    ArrayPrototypeIncludes,
+    /// `String.prototype.padEnd`
+    StringPrototypePadEnd,
+    /// `String.prototype.padStart`
+    StringPrototypePadStart,
+    /// `Promise.prototype.finally`
+    PromisePrototypeFinally,
+    /// `Array.prototype.flat`
+    ArrayPrototypeFlat,
+    /// `Array.prototype.flatMap`
+    ArrayPrototypeFlatMap,
+    /// `String.prototype.trimEnd`
+    StringPrototypeTrimEnd,
+    /// `String.prototype.trimStart`
+    StringPrototypeTrimStart,
+    /// `String.prototype.matchAll`
+    StringPrototypeMatchAll,
+    /// `String.prototype.replaceAll`
+    StringPrototypeReplaceAll,
+    /// `Array.prototype.at`
+    ArrayPrototypeAt,
+    /// `String.prototype.at`
+    StringPrototypeAt,
+
+    // Web platform APIs, which are not part of ECMAScript
+    /// `queueMicrotask`
+    QueueMicrotask,
+    /// `AbortController` and `AbortSignal`
+    AbortController,
+    /// `structuredClone`
+    StructuredClone,
+}
+
+pub fn built_ins() -> &'static FxHashMap<BuiltIn, EngineTargets> {
+    #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
+    use BuiltIn::*;
+    #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
+    use Engine::*;
+    static BUILT_INS: OnceLock<FxHashMap<BuiltIn, EngineTargets>> = OnceLock::new();
+    BUILT_INS.get_or_init(|| {
+        FxHashMap::from_iter([
+            (
+                Map,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 38, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 13, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 25, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                Promise,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 32, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 29, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 19, 0),
+                        (Samsung, 2, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                Set,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 38, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 13, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 25, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                Symbol,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 38, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 36, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 25, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                WeakMap,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 36, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 6, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 23, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                WeakSet,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 36, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 34, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 23, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                GlobalThis,
+                es(
+                    2020,
+                    &[
+                        (Chrome, 71, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 65, 0),
+                        (Safari, 12, 1),
+                        (Ios, 12, 2),
+                        (Node, 12, 0),
+                        (Deno, 1, 0),
+                        (Opera, 58, 0),
+                        (Samsung, 10, 0),
+                        (Electron, 5, 0),
+                    ],
+                ),
+            ),
+            (
+                AggregateError,
+                es(
+                    2021,
+                    &[
+                        (Chrome, 85, 0),
+                        (Edge, 85, 0),
+                        (Firefox, 79, 0),
+                        (Safari, 14, 0),
+                        (Ios, 14, 0),
+                        (Node, 15, 0),
+                        (Deno, 1, 2),
+                        (Opera, 71, 0),
+                        (Samsung, 14, 0),
+                        (Electron, 10, 0),
+                    ],
+                ),
+            ),
+            (
+                FinalizationRegistry,
+                es(
+                    2021,
+                    &[
+                        (Chrome, 84, 0),
+                        (Edge, 84, 0),
+                        (Firefox, 79, 0),
+                        (Safari, 14, 1),
+                        (Ios, 14, 5),
+                        (Node, 14, 6),
+                        (Deno, 1, 0),
+                        (Opera, 70, 0),
+                        (Samsung, 14, 0),
+                        (Electron, 10, 0),
+                    ],
+                ),
+            ),
+            (
+                WeakRef,
+                es(
+                    2021,
+                    &[
+                        (Chrome, 84, 0),
+                        (Edge, 84, 0),
+                        (Firefox, 79, 0),
+                        (Safari, 14, 1),
+                        (Ios, 14, 5),
+                        (Node, 14, 6),
+                        (Deno, 1, 0),
+                        (Opera, 70, 0),
+                        (Samsung, 14, 0),
+                        (Electron, 10, 0),
+                    ],
+                ),
+            ),
+            (
+                ArrayFrom,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 32, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                ArrayOf,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 25, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                MathSign,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 38, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 25, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 25, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                MathTrunc,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 38, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 25, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 25, 0),
+                        (Samsung, 3, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                NumberIsFinite,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 19, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 16, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 10),
+                        (Deno, 1, 0),
+                        (Opera, 15, 0),
+                        (Samsung, 1, 5),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                NumberIsInteger,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 34, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 16, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 21, 0),
+                        (Samsung, 2, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                NumberIsNaN,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 25, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 15, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 10),
+                        (Deno, 1, 0),
+                        (Opera, 15, 0),
+                        (Samsung, 1, 5),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                NumberIsSafeInteger,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 34, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 32, 0),
+                        (Safari, 10, 0),
+                        (Ios, 10, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 21, 0),
+                        (Samsung, 2, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                ObjectAssign,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 34, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                ObjectIs,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 19, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 22, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 10),
+                        (Deno, 1, 0),
+                        (Opera, 15, 0),
+                        (Samsung, 1, 5),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                ObjectSetPrototypeOf,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 34, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 31, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 0, 12),
+                        (Deno, 1, 0),
+                        (Opera, 21, 0),
+                        (Samsung, 2, 0),
+                        (Electron, 0, 20),
+                    ],
+                ),
+            ),
+            (
+                StringFromCodePoint,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 29, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                StringRaw,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 34, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                ObjectEntries,
+                es(
+                    2017,
+                    &[
+                        (Chrome, 54, 0),
+                        (Edge, 14, 0),
+                        (Firefox, 47, 0),
+                        (Safari, 10, 1),
+                        (Ios, 10, 3),
+                        (Node, 7, 0),
+                        (Deno, 1, 0),
+                        (Opera, 41, 0),
+                        (Samsung, 6, 0),
+                        (Electron, 1, 5),
+                    ],
+                ),
+            ),
+            (
+                ObjectGetOwnPropertyDescriptors,
+                es(
+                    2017,
+                    &[
+                        (Chrome, 54, 0),
+                        (Edge, 15, 0),
+                        (Firefox, 50, 0),
+                        (Safari, 10, 0),
+                        (Ios, 10, 0),
+                        (Node, 7, 0),
+                        (Deno, 1, 0),
+                        (Opera, 41, 0),
+                        (Samsung, 6, 0),
+                        (Electron, 1, 5),
+                    ],
+                ),
+            ),
+            (
+                ObjectValues,
+                es(
+                    2017,
+                    &[
+                        (Chrome, 54, 0),
+                        (Edge, 14, 0),
+                        (Firefox, 47, 0),
+                        (Safari, 10, 1),
+                        (Ios, 10, 3),
+                        (Node, 7, 0),
+                        (Deno, 1, 0),
+                        (Opera, 41, 0),
+                        (Samsung, 6, 0),
+                        (Electron, 1, 5),
+                    ],
+                ),
+            ),
+            (
+                ObjectFromEntries,
+                es(
+                    2019,
+                    &[
+                        (Chrome, 73, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 63, 0),
+                        (Safari, 12, 1),
+                        (Ios, 12, 2),
+                        (Node, 12, 0),
+                        (Deno, 1, 0),
+                        (Opera, 60, 0),
+                        (Samsung, 11, 0),
+                        (Electron, 5, 0),
+                    ],
+                ),
+            ),
+            (
+                PromiseAllSettled,
+                es(
+                    2020,
+                    &[
+                        (Chrome, 76, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 71, 0),
+                        (Safari, 13, 0),
+                        (Ios, 13, 0),
+                        (Node, 12, 9),
+                        (Deno, 1, 0),
+                        (Opera, 63, 0),
+                        (Samsung, 12, 0),
+                        (Electron, 6, 0),
+                    ],
+                ),
+            ),
+            (
+                PromiseAny,
+                es(
+                    2021,
+                    &[
+                        (Chrome, 85, 0),
+                        (Edge, 85, 0),
+                        (Firefox, 79, 0),
+                        (Safari, 14, 0),
+                        (Ios, 14, 0),
+                        (Node, 15, 0),
+                        (Deno, 1, 2),
+                        (Opera, 71, 0),
+                        (Samsung, 14, 0),
+                        (Electron, 10, 0),
+                    ],
+                ),
+            ),
+            (
+                ObjectHasOwn,
+                es(
+                    2022,
+                    &[
+                        (Chrome, 93, 0),
+                        (Edge, 93, 0),
+                        (Firefox, 92, 0),
+                        (Safari, 15, 4),
+                        (Ios, 15, 4),
+                        (Node, 16, 9),
+                        (Deno, 1, 13),
+                        (Opera, 79, 0),
+                        (Samsung, 17, 0),
+                        (Electron, 14, 0),
+                    ],
+                ),
+            ),
+            (
+                SymbolAsyncIterator,
+                es(
+                    2018,
+                    &[
+                        (Chrome, 63, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 57, 0),
+                        (Safari, 11, 1),
+                        (Ios, 11, 3),
+                        (Node, 10, 0),
+                        (Deno, 1, 0),
+                        (Opera, 50, 0),
+                        (Samsung, 8, 0),
+                        (Electron, 3, 0),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeCopyWithin,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 32, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeFill,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 31, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeFind,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 25, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeFindIndex,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 45, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 25, 0),
+                        (Safari, 8, 0),
+                        (Ios, 8, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 32, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 33),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeCodePointAt,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 29, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeEndsWith,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 17, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeIncludes,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 40, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeRepeat,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 24, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeStartsWith,
+                es(
+                    2015,
+                    &[
+                        (Chrome, 41, 0),
+                        (Edge, 12, 0),
+                        (Firefox, 17, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 4, 0),
+                        (Deno, 1, 0),
+                        (Opera, 28, 0),
+                        (Samsung, 4, 0),
+                        (Electron, 0, 22),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeIncludes,
+                es(
+                    2016,
+                    &[
+                        (Chrome, 47, 0),
+                        (Edge, 14, 0),
+                        (Firefox, 43, 0),
+                        (Safari, 9, 0),
+                        (Ios, 9, 0),
+                        (Node, 6, 0),
+                        (Deno, 1, 0),
+                        (Opera, 34, 0),
+                        (Samsung, 5, 0),
+                        (Electron, 0, 35),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypePadEnd,
+                es(
+                    2017,
+                    &[
+                        (Chrome, 57, 0),
+                        (Edge, 15, 0),
+                        (Firefox, 48, 0),
+                        (Safari, 10, 0),
+                        (Ios, 10, 0),
+                        (Node, 8, 0),
+                        (Deno, 1, 0),
+                        (Opera, 44, 0),
+                        (Samsung, 7, 0),
+                        (Electron, 1, 7),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypePadStart,
+                es(
+                    2017,
+                    &[
+                        (Chrome, 57, 0),
+                        (Edge, 15, 0),
+                        (Firefox, 48, 0),
+                        (Safari, 10, 0),
+                        (Ios, 10, 0),
+                        (Node, 8, 0),
+                        (Deno, 1, 0),
+                        (Opera, 44, 0),
+                        (Samsung, 7, 0),
+                        (Electron, 1, 7),
+                    ],
+                ),
+            ),
+            (
+                PromisePrototypeFinally,
+                es(
+                    2018,
+                    &[
+                        (Chrome, 63, 0),
+                        (Edge, 18, 0),
+                        (Firefox, 58, 0),
+                        (Safari, 11, 1),
+                        (Ios, 11, 3),
+                        (Node, 10, 0),
+                        (Deno, 1, 0),
+                        (Opera, 50, 0),
+                        (Samsung, 8, 0),
+                        (Electron, 3, 0),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeFlat,
+                es(
+                    2019,
+                    &[
+                        (Chrome, 69, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 62, 0),
+                        (Safari, 12, 0),
+                        (Ios, 12, 0),
+                        (Node, 11, 0),
+                        (Deno, 1, 0),
+                        (Opera, 56, 0),
+                        (Samsung, 10, 0),
+                        (Electron, 4, 0),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeFlatMap,
+                es(
+                    2019,
+                    &[
+                        (Chrome, 69, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 62, 0),
+                        (Safari, 12, 0),
+                        (Ios, 12, 0),
+                        (Node, 11, 0),
+                        (Deno, 1, 0),
+                        (Opera, 56, 0),
+                        (Samsung, 10, 0),
+                        (Electron, 4, 0),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeTrimEnd,
+                es(
+                    2019,
+                    &[
+                        (Chrome, 66, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 61, 0),
+                        (Safari, 12, 0),
+                        (Ios, 12, 0),
+                        (Node, 10, 0),
+                        (Deno, 1, 0),
+                        (Opera, 53, 0),
+                        (Samsung, 9, 0),
+                        (Electron, 3, 0),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeTrimStart,
+                es(
+                    2019,
+                    &[
+                        (Chrome, 66, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 61, 0),
+                        (Safari, 12, 0),
+                        (Ios, 12, 0),
+                        (Node, 10, 0),
+                        (Deno, 1, 0),
+                        (Opera, 53, 0),
+                        (Samsung, 9, 0),
+                        (Electron, 3, 0),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeMatchAll,
+                es(
+                    2020,
+                    &[
+                        (Chrome, 73, 0),
+                        (Edge, 79, 0),
+                        (Firefox, 67, 0),
+                        (Safari, 13, 0),
+                        (Ios, 13, 0),
+                        (Node, 12, 0),
+                        (Deno, 1, 0),
+                        (Opera, 60, 0),
+                        (Samsung, 11, 0),
+                        (Electron, 5, 0),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeReplaceAll,
+                es(
+                    2021,
+                    &[
+                        (Chrome, 85, 0),
+                        (Edge, 85, 0),
+                        (Firefox, 77, 0),
+                        (Safari, 13, 1),
+                        (Ios, 13, 4),
+                        (Node, 15, 0),
+                        (Deno, 1, 2),
+                        (Opera, 71, 0),
+                        (Samsung, 14, 0),
+                        (Electron, 10, 0),
+                    ],
+                ),
+            ),
+            (
+                ArrayPrototypeAt,
+                es(
+                    2022,
+                    &[
+                        (Chrome, 92, 0),
+                        (Edge, 92, 0),
+                        (Firefox, 90, 0),
+                        (Safari, 15, 4),
+                        (Ios, 15, 4),
+                        (Node, 16, 6),
+                        (Deno, 1, 12),
+                        (Opera, 78, 0),
+                        (Samsung, 16, 0),
+                        (Electron, 14, 0),
+                    ],
+                ),
+            ),
+            (
+                StringPrototypeAt,
+                es(
+                    2022,
+                    &[
+                        (Chrome, 92, 0),
+                        (Edge, 92, 0),
+                        (Firefox, 90, 0),
+                        (Safari, 15, 4),
+                        (Ios, 15, 4),
+                        (Node, 16, 6),
+                        (Deno, 1, 12),
+                        (Opera, 78, 0),
+                        (Samsung, 16, 0),
+                        (Electron, 14, 0),
+                    ],
+                ),
+            ),
+            (
+                QueueMicrotask,
+                web(&[
+                    (Chrome, 71, 0),
+                    (Edge, 79, 0),
+                    (Firefox, 69, 0),
+                    (Safari, 12, 1),
+                    (Ios, 12, 2),
+                    (Node, 11, 0),
+                    (Deno, 1, 0),
+                    (Opera, 58, 0),
+                    (Samsung, 10, 0),
+                    (Electron, 5, 0),
+                ]),
+            ),
+            (
+                AbortController,
+                web(&[
+                    (Chrome, 66, 0),
+                    (Edge, 16, 0),
+                    (Firefox, 57, 0),
+                    (Safari, 12, 1),
+                    (Ios, 12, 2),
+                    (Node, 15, 0),
+                    (Deno, 1, 0),
+                    (Opera, 53, 0),
+                    (Samsung, 9, 0),
+                    (Electron, 3, 0),
+                ]),
+            ),
+            (
+                StructuredClone,
+                web(&[
+                    (Chrome, 98, 0),
+                    (Edge, 98, 0),
+                    (Firefox, 94, 0),
+                    (Safari, 15, 4),
+                    (Ios, 15, 4),
+                    (Node, 17, 0),
+                    (Deno, 1, 14),
+                    (Opera, 84, 0),
+                    (Samsung, 18, 0),
+                    (Electron, 17, 0),
+                ]),
+            ),
+        ])
+    })
+}
+
+/// A built-in added in `edition`, available since the given `(engine, major, minor)`.
+fn es(edition: u16, engines: &[(Engine, u16, u16)]) -> EngineTargets {
+    let mut targets = versions(engines);
+    targets.insert(Engine::Es, Version(edition, 0, 0));
+    targets
+}
+
+/// A web platform API, available since the given `(engine, major, minor)`.
+fn web(engines: &[(Engine, u16, u16)]) -> EngineTargets {
+    versions(engines)
+}
+
+fn versions(engines: &[(Engine, u16, u16)]) -> EngineTargets {
+    EngineTargets::new(
+        engines.iter().map(|&(engine, major, minor)| (engine, Version(major, minor, 0))).collect(),
+    )
+}
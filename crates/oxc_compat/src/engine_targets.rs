@@ -14,6 +14,7 @@ use crate::{babel_targets::BabelTargets, es_target::ESVersion};
 
 use super::{
     Engine,
+    built_ins::{BuiltIn, built_ins},
     es_features::{ESFeature, features},
 };
 
@@ -74,19 +75,36 @@ impl EngineTargets {
     ///
     /// Returns `true` if the feature is NOT supported (needs transformation),
     /// `false` if the feature IS supported (can be used natively).
+    ///
+    /// Every target engine is checked, so the feature is considered unsupported
+    /// as soon as a single engine in the target matrix is below the minimum version.
+    /// Engines without compat data for the feature are ignored.
     pub fn has_feature(&self, feature: ESFeature) -> bool {
-        let feature_engine_targets = &features()[&feature];
-        for (engine, feature_version) in feature_engine_targets.iter() {
-            if let Some(target_version) = self.get(engine) {
+        self.is_below(&features()[&feature])
+    }
+
+    /// Check if the target engines provide the given built-in.
+    ///
+    /// Returns `true` if the built-in is NOT available (needs a polyfill),
+    /// `false` if the built-in IS available.
+    ///
+    /// Like [`EngineTargets::has_feature`], every target engine is checked,
+    /// and engines without compat data for the built-in are ignored.
+    pub fn has_built_in(&self, built_in: BuiltIn) -> bool {
+        self.is_below(&built_ins()[&built_in])
+    }
+
+    /// Any target engine is below the minimum version in `minimum`.
+    fn is_below(&self, minimum: &EngineTargets) -> bool {
+        minimum.iter().any(|(engine, minimum_version)| {
+            self.get(engine).is_some_and(|target_version| {
                 if *engine == Engine::Es {
-                    return target_version.0 < feature_version.0;
-                }
-                if target_version < feature_version {
-                    return true;
+                    target_version.0 < minimum_version.0
+                } else {
+                    target_version < minimum_version
                 }
-            }
-        }
-        false
+            })
+        })
     }
 
     /// Parses the value returned from `browserslist`.
@@ -112,6 +130,41 @@ impl EngineTargets {
         engine_targets
     }
 
+    /// Initialize from a map of engine names to minimum versions,
+    /// e.g. `{ chrome: "80", node: "14", safari: "13.1" }`.
+    ///
+    /// When multiple names resolve to the same engine (e.g. `chrome` and `and_chr`),
+    /// the lowest version is used.
+    ///
+    /// # Errors
+    ///
+    /// * Unknown engine name.
+    /// * Invalid version.
+    pub fn from_engine_versions<K: AsRef<str>, V: AsRef<str>>(
+        versions: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, String> {
+        let mut engine_targets = Self::default();
+        for (name, version) in versions {
+            let (name, version) = (name.as_ref(), version.as_ref());
+            let Ok(engine) = Engine::from_str(name) else {
+                return Err(format!("engine '{name}' is not supported."));
+            };
+            let Ok(version) = Version::from_str(version) else {
+                return Err(format!("Failed to parse `{version}` for `{name}`."));
+            };
+            engine_targets
+                .0
+                .entry(engine)
+                .and_modify(|v| {
+                    if version < *v {
+                        *v = version;
+                    }
+                })
+                .or_insert(version);
+        }
+        Ok(engine_targets)
+    }
+
     /// # Errors
     ///
     /// * When the query failed to parse.
@@ -162,3 +215,63 @@ fn test_displayed_value_is_parsable() {
     let parsed = EngineTargets::from_target(&s).unwrap();
     assert_eq!(target.0, parsed.0);
 }
+
+#[test]
+fn test_has_feature_checks_every_engine() {
+    // `es` supports optional chaining, but `chrome79` does not.
+    let target = EngineTargets::from_target("es2020,chrome79").unwrap();
+    assert!(target.has_feature(ESFeature::ES2020OptionalChaining));
+    assert!(!target.has_feature(ESFeature::ES2019OptionalCatchBinding));
+
+    // `chrome` supports optional chaining, but `es2019` does not.
+    let target = EngineTargets::from_target("es2019,chrome100").unwrap();
+    assert!(target.has_feature(ESFeature::ES2020OptionalChaining));
+}
+
+#[test]
+fn test_has_built_in() {
+    // Chrome 73 supports `Object.fromEntries`, but not `Promise.allSettled`.
+    let target = EngineTargets::from_target("chrome73").unwrap();
+    assert!(!target.has_built_in(BuiltIn::ObjectFromEntries));
+    assert!(target.has_built_in(BuiltIn::PromiseAllSettled));
+
+    // ECMAScript built-ins are checked against the edition.
+    let target = EngineTargets::from_target("es2019").unwrap();
+    assert!(!target.has_built_in(BuiltIn::ObjectFromEntries));
+    assert!(target.has_built_in(BuiltIn::PromiseAllSettled));
+
+    // Web platform APIs are not part of any edition.
+    assert!(!EngineTargets::from_target("es2015").unwrap().has_built_in(BuiltIn::StructuredClone));
+    assert!(
+        EngineTargets::from_target("es2022,chrome97")
+            .unwrap()
+            .has_built_in(BuiltIn::StructuredClone)
+    );
+}
+
+#[test]
+fn test_from_engine_versions() {
+    let target =
+        EngineTargets::from_engine_versions([("chrome", "80"), ("node", "14"), ("safari", "13.1")])
+            .unwrap();
+    assert_eq!(target[&Engine::Chrome], Version(80, 0, 0));
+    assert_eq!(target[&Engine::Node], Version(14, 0, 0));
+    assert_eq!(target[&Engine::Safari], Version(13, 1, 0));
+    // Safari 13.1 does not support logical assignment operators.
+    assert!(target.has_feature(ESFeature::ES2021LogicalAssignmentOperators));
+    assert!(!target.has_feature(ESFeature::ES2018ObjectRestSpread));
+
+    // Aliases of the same engine resolve to the lowest version.
+    let target =
+        EngineTargets::from_engine_versions([("chrome", "90"), ("and_chr", "80")]).unwrap();
+    assert_eq!(target[&Engine::Chrome], Version(80, 0, 0));
+
+    assert_eq!(
+        EngineTargets::from_engine_versions([("netscape", "4")]).unwrap_err(),
+        "engine 'netscape' is not supported."
+    );
+    assert_eq!(
+        EngineTargets::from_engine_versions([("chrome", "x")]).unwrap_err(),
+        "Failed to parse `x` for `chrome`."
+    );
+}
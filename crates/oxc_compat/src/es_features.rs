@@ -56,6 +56,7 @@ pub enum ESFeature {
     ES2026ExplicitResourceManagement,
     ES2020ExportNamespaceFrom,
     ES2020ArbitraryModuleNamespaceNames,
+    ES2020GlobalThis,
     ES2022TopLevelAwait,
 }
 pub fn features() -> &'static FxHashMap<ESFeature, EngineTargets> {
@@ -923,6 +924,23 @@ pub fn features() -> &'static FxHashMap<ESFeature, EngineTargets> {
                     (Es, Version(2020u16, 0, 0)),
                 ])),
             ),
+            (
+                ES2020GlobalThis,
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Samsung, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(12u16, 0u16, 0u16)),
+                    (Firefox, Version(65u16, 0u16, 0u16)),
+                    (Chrome, Version(71u16, 0u16, 0u16)),
+                    (Safari, Version(12u16, 1u16, 0u16)),
+                    (Ios, Version(12u16, 2u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (OperaMobile, Version(50u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Electron, Version(5u16, 0u16, 0u16)),
+                    (Opera, Version(58u16, 0u16, 0u16)),
+                    (Es, Version(2020u16, 0, 0)),
+                ])),
+            ),
             (
                 ES2022TopLevelAwait,
                 EngineTargets::new(FxHashMap::from_iter([
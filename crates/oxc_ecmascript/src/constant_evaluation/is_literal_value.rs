@@ -90,7 +90,7 @@ impl<'a> IsLiteralValue<'a, '_> for UnaryExpression<'a> {
                 can_convert_to_number_transparently(&self.argument, include_functions, ctx)
                     || matches!(self.argument, Expression::BigIntLiteral(_))
             }
-            UnaryOperator::Delete => false,
+            UnaryOperator::Delete | UnaryOperator::Throw => false,
         }
     }
 }
@@ -230,7 +230,7 @@ fn can_convert_to_number_transparently<'a>(
             UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
                 can_convert_to_number_transparently(&e.argument, include_functions, ctx)
             }
-            UnaryOperator::Delete => false,
+            UnaryOperator::Delete | UnaryOperator::Throw => false,
         },
         Expression::BinaryExpression(e) => match e.operator {
             BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
@@ -496,7 +496,7 @@ impl<'a> ConstantEvaluation<'a> for UnaryExpression<'a> {
                     .map(|v| (!v.to_int_32()) as f64)
                     .map(ConstantValue::Number),
             },
-            UnaryOperator::Delete => None,
+            UnaryOperator::Delete | UnaryOperator::Throw => None,
         }
     }
 }
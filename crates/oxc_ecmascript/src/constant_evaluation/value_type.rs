@@ -188,6 +188,8 @@ impl<'a> DetermineValueType<'a> for UnaryExpression<'a> {
             UnaryOperator::UnaryPlus => ValueType::Number,
             UnaryOperator::LogicalNot | UnaryOperator::Delete => ValueType::Boolean,
             UnaryOperator::Typeof => ValueType::String,
+            // `throw` never produces a value.
+            UnaryOperator::Throw => ValueType::Undetermined,
         }
     }
 }
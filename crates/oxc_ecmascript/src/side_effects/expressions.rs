@@ -88,7 +88,7 @@ impl<'a> MayHaveSideEffects<'a> for TemplateLiteral<'a> {
 impl<'a> MayHaveSideEffects<'a> for UnaryExpression<'a> {
     fn may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
         match self.operator {
-            UnaryOperator::Delete => true,
+            UnaryOperator::Delete | UnaryOperator::Throw => true,
             UnaryOperator::Void | UnaryOperator::LogicalNot => {
                 self.argument.may_have_side_effects(ctx)
             }
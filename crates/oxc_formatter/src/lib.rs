@@ -10,6 +10,7 @@ mod options;
 pub mod oxfmtrc;
 mod parentheses;
 mod service;
+mod text_edit;
 mod utils;
 mod write;
 
@@ -20,6 +21,7 @@ pub use crate::embedded_formatter::{EmbeddedFormatter, EmbeddedFormatterCallback
 pub use crate::ir_transform::options::*;
 pub use crate::options::*;
 pub use crate::service::*;
+pub use crate::text_edit::{TextEdit, compute_text_edits, compute_text_edits_with_cursor};
 use crate::{
     ast_nodes::{AstNode, AstNodes},
     formatter::{FormatContext, Formatted},
@@ -46,6 +48,25 @@ impl<'a> Formatter<'a> {
         formatted.print().unwrap().into_code()
     }
 
+    /// Formats the given AST `Program` and returns the edits which turn its source text
+    /// into the formatted string. See [`compute_text_edits`].
+    pub fn build_text_edits(self, program: &Program<'a>) -> Vec<TextEdit> {
+        let source_text = program.source_text;
+        compute_text_edits(source_text, &self.build(program))
+    }
+
+    /// Formats the given AST `Program` and returns the edits which turn its source text
+    /// into the formatted string, and the offset of `cursor` in the formatted string.
+    /// See [`compute_text_edits_with_cursor`].
+    pub fn build_text_edits_with_cursor(
+        self,
+        program: &Program<'a>,
+        cursor: u32,
+    ) -> (Vec<TextEdit>, u32) {
+        let source_text = program.source_text;
+        compute_text_edits_with_cursor(source_text, &self.build(program), cursor)
+    }
+
     #[inline]
     pub fn format(self, program: &'a Program<'a>) -> Formatted<'a> {
         self.format_impl(program, None)
@@ -0,0 +1,210 @@
+use std::ops::Range;
+
+use similar::{Algorithm, DiffOp, TextDiff};
+
+use oxc_span::Span;
+
+/// Replacement of the text at `span` of the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+/// Computes the edits which turn `source_text` into `formatted_text`.
+///
+/// The texts are first diffed line by line, then each changed group of lines is narrowed down
+/// to the part which actually differs. This keeps the unchanged parts of the document,
+/// and the cursor positions in them, untouched when the edits are applied by an editor.
+///
+/// The edits are sorted and don't overlap. Returns no edits if the texts are equal.
+pub fn compute_text_edits(source_text: &str, formatted_text: &str) -> Vec<TextEdit> {
+    compute_line_edits(source_text, formatted_text)
+        .into_iter()
+        .map(|(source, formatted)| create_edit(source, &formatted_text[formatted]))
+        .collect()
+}
+
+/// Like [`compute_text_edits`], but keeps the token enclosing `cursor` out of the edits.
+///
+/// `cursor` is a byte offset in `source_text`, the returned cursor is its offset
+/// in `formatted_text`. The changed group of lines containing it is diffed
+/// token by token, so the edits next to the cursor only replace the text between the tokens
+/// which are in both texts, e.g. formatting `foo(a,b|ar)` only inserts the space before `bar`.
+/// If the cursor is in a replaced part of the text, it's moved to the same offset
+/// in the replacement, or to its end.
+pub fn compute_text_edits_with_cursor(
+    source_text: &str,
+    formatted_text: &str,
+    cursor: u32,
+) -> (Vec<TextEdit>, u32) {
+    let mut edits = vec![];
+    for (source, formatted) in compute_line_edits(source_text, formatted_text) {
+        if source.start <= cursor as usize && cursor as usize <= source.end {
+            push_token_edits(source_text, formatted_text, source, formatted, &mut edits);
+        } else {
+            edits.push(create_edit(source, &formatted_text[formatted]));
+        }
+    }
+
+    let cursor = map_cursor(&edits, cursor as usize, formatted_text);
+    #[expect(clippy::cast_possible_truncation)]
+    (edits, cursor as u32)
+}
+
+/// Diffs the texts line by line, and returns the byte ranges of each changed group of lines,
+/// in `source_text` and in `formatted_text`, narrowed down to the part which differs.
+fn compute_line_edits(
+    source_text: &str,
+    formatted_text: &str,
+) -> Vec<(Range<usize>, Range<usize>)> {
+    if source_text == formatted_text {
+        return vec![];
+    }
+
+    let diff =
+        TextDiff::configure().algorithm(Algorithm::Myers).diff_lines(source_text, formatted_text);
+    let source_offsets = line_offsets(diff.old_slices());
+    let formatted_offsets = line_offsets(diff.new_slices());
+
+    let mut edits = vec![];
+    for op in diff.ops() {
+        if matches!(op, DiffOp::Equal { .. }) {
+            continue;
+        }
+        let (source_lines, formatted_lines) = (op.old_range(), op.new_range());
+        let source_start = source_offsets[source_lines.start];
+        let source_end = source_offsets[source_lines.end];
+        let formatted_start = formatted_offsets[formatted_lines.start];
+        let formatted_end = formatted_offsets[formatted_lines.end];
+        let source = &source_text[source_start..source_end];
+        let formatted = &formatted_text[formatted_start..formatted_end];
+
+        let prefix = common_prefix_len(source, formatted);
+        let suffix = common_suffix_len(&source[prefix..], &formatted[prefix..]);
+        edits.push((
+            source_start + prefix..source_end - suffix,
+            formatted_start + prefix..formatted_end - suffix,
+        ));
+    }
+    edits
+}
+
+/// Pushes the edits which turn `source` into `formatted`, keeping the tokens which are in both.
+/// Only whitespace and tokens which are not in both texts are replaced.
+fn push_token_edits(
+    source_text: &str,
+    formatted_text: &str,
+    source: Range<usize>,
+    formatted: Range<usize>,
+    edits: &mut Vec<TextEdit>,
+) {
+    let source_tokens = tokens(source_text, source.clone());
+    let formatted_tokens = tokens(formatted_text, formatted.clone());
+    let old = source_tokens.iter().map(|token| &source_text[token.clone()]).collect::<Vec<_>>();
+    let new =
+        formatted_tokens.iter().map(|token| &formatted_text[token.clone()]).collect::<Vec<_>>();
+    let diff = TextDiff::configure().algorithm(Algorithm::Myers).diff_slices(&old, &new);
+
+    let mut push_gap = |source: Range<usize>, formatted: Range<usize>| {
+        let (source_gap, formatted_gap) =
+            (&source_text[source.clone()], &formatted_text[formatted]);
+        if source_gap != formatted_gap {
+            let prefix = common_prefix_len(source_gap, formatted_gap);
+            let suffix = common_suffix_len(&source_gap[prefix..], &formatted_gap[prefix..]);
+            edits.push(create_edit(
+                source.start + prefix..source.end - suffix,
+                &formatted_gap[prefix..formatted_gap.len() - suffix],
+            ));
+        }
+    };
+    let (mut source_pos, mut formatted_pos) = (source.start, formatted.start);
+    for op in diff.ops() {
+        if let DiffOp::Equal { old_index, new_index, len } = *op {
+            for (source_token, formatted_token) in source_tokens[old_index..old_index + len]
+                .iter()
+                .zip(&formatted_tokens[new_index..new_index + len])
+            {
+                push_gap(source_pos..source_token.start, formatted_pos..formatted_token.start);
+                (source_pos, formatted_pos) = (source_token.end, formatted_token.end);
+            }
+        }
+    }
+    push_gap(source_pos..source.end, formatted_pos..formatted.end);
+}
+
+/// Byte ranges of the tokens in `range` of `text`, ignoring whitespace.
+/// A token is a run of identifier characters, or any other single character.
+fn tokens(text: &str, range: Range<usize>) -> Vec<Range<usize>> {
+    let is_identifier_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$');
+    let mut tokens: Vec<Range<usize>> = vec![];
+    let mut previous_is_identifier_char = false;
+    for (offset, c) in text[range.clone()].char_indices() {
+        let (start, end) = (range.start + offset, range.start + offset + c.len_utf8());
+        let is_identifier = is_identifier_char(c);
+        if is_identifier && previous_is_identifier_char {
+            tokens.last_mut().unwrap().end = end;
+        } else if !c.is_whitespace() {
+            tokens.push(start..end);
+        }
+        previous_is_identifier_char = is_identifier;
+    }
+    tokens
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn create_edit(source: Range<usize>, replacement: &str) -> TextEdit {
+    TextEdit {
+        span: Span::new(source.start as u32, source.end as u32),
+        replacement: replacement.to_string(),
+    }
+}
+
+/// Offset of `cursor` after applying `edits`. A cursor at the start of an edit stays in front of it.
+fn map_cursor(edits: &[TextEdit], cursor: usize, formatted_text: &str) -> usize {
+    let mut delta = 0isize;
+    for edit in edits {
+        let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+        if cursor <= start {
+            break;
+        }
+        if cursor < end {
+            let mut offset =
+                start.saturating_add_signed(delta) + (cursor - start).min(edit.replacement.len());
+            while !formatted_text.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            return offset;
+        }
+        #[expect(clippy::cast_possible_wrap)]
+        {
+            delta += edit.replacement.len() as isize - (end - start) as isize;
+        }
+    }
+    cursor.saturating_add_signed(delta)
+}
+
+/// Byte offsets of the start of each line, followed by the length of the text.
+fn line_offsets(lines: &[&str]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(lines.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+    for line in lines {
+        offset += line.len();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).take_while(|(a, b)| a == b).map(|(c, _)| c.len_utf8()).sum()
+}
+
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum()
+}
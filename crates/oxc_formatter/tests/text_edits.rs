@@ -0,0 +1,137 @@
+use cow_utils::CowUtils;
+use oxc_allocator::Allocator;
+use oxc_formatter::{
+    FormatOptions, Formatter, TextEdit, compute_text_edits, compute_text_edits_with_cursor,
+    get_parse_options,
+};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+fn apply(source_text: &str, edits: &[TextEdit]) -> String {
+    let mut result = String::new();
+    let mut last = 0;
+    for edit in edits {
+        assert!(last <= edit.span.start as usize, "edits must be sorted and not overlap");
+        result.push_str(&source_text[last..edit.span.start as usize]);
+        result.push_str(&edit.replacement);
+        last = edit.span.end as usize;
+    }
+    result.push_str(&source_text[last..]);
+    result
+}
+
+fn assert_edits(source_text: &str, formatted_text: &str, expected: &[(u32, u32, &str)]) {
+    let edits = compute_text_edits(source_text, formatted_text);
+    assert_eq!(apply(source_text, &edits), formatted_text);
+    let edits = edits
+        .iter()
+        .map(|edit| (edit.span.start, edit.span.end, edit.replacement.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(edits, expected);
+}
+
+/// `|` in `source_text` marks the cursor, which is expected at `|` in `formatted_text`.
+fn assert_edits_with_cursor(
+    source_text: &str,
+    formatted_text: &str,
+    expected: &[(u32, u32, &str)],
+) {
+    let cursor = u32::try_from(source_text.find('|').unwrap()).unwrap();
+    let expected_cursor = u32::try_from(formatted_text.find('|').unwrap()).unwrap();
+    let (source_text, formatted_text) =
+        (source_text.cow_replace('|', ""), formatted_text.cow_replace('|', ""));
+    let (edits, cursor) = compute_text_edits_with_cursor(&source_text, &formatted_text, cursor);
+    assert_eq!(apply(&source_text, &edits), formatted_text);
+    assert_eq!(cursor, expected_cursor);
+    let edits = edits
+        .iter()
+        .map(|edit| (edit.span.start, edit.span.end, edit.replacement.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(edits, expected);
+}
+
+#[test]
+fn no_change() {
+    assert_edits("", "", &[]);
+    assert_edits("let a = 1;\n", "let a = 1;\n", &[]);
+}
+
+#[test]
+fn narrows_changed_lines() {
+    assert_edits("abc", "axc", &[(1, 2, "x")]);
+    assert_edits("abc", "abxc", &[(2, 2, "x")]);
+    assert_edits("abc", "ac", &[(1, 2, "")]);
+    assert_edits("a😀b", "a😃b", &[(1, 5, "😃")]);
+    assert_edits("let a = 1\n", "let a = 1;\n", &[(9, 9, ";")]);
+    assert_edits("let a = 1;", "let a = 1;\n", &[(10, 10, "\n")]);
+}
+
+#[test]
+fn keeps_unchanged_regions() {
+    let unchanged = "const unchanged = true;\n".repeat(1000);
+    let source_text = format!("let a  =  1;\n{unchanged}let b =2\n{unchanged}");
+    let formatted_text = format!("let a = 1;\n{unchanged}let b = 2;\n{unchanged}");
+    let b = 13 + u32::try_from(unchanged.len()).unwrap();
+    assert_edits(&source_text, &formatted_text, &[(6, 9, "="), (b + 7, b + 8, " 2;")]);
+}
+
+#[test]
+fn joined_and_split_lines() {
+    assert_edits("foo(\n  a,\n  b,\n);\n", "foo(a, b);\n", &[(4, 15, "a, b")]);
+    assert_edits("foo(a, b);\n", "foo(\n  a,\n  b,\n);\n", &[(4, 8, "\n  a,\n  b,\n")]);
+}
+
+#[test]
+fn keeps_the_token_at_the_cursor() {
+    // Without a cursor, the whole differing part is replaced, including `bar`.
+    assert_edits("foo(a,bar)", "foo(a, bar);\n", &[(6, 10, " bar);\n")]);
+    assert_edits_with_cursor("foo(a,b|ar)", "foo(a, b|ar);\n", &[(6, 6, " "), (10, 10, ";\n")]);
+    assert_edits_with_cursor(
+        "let  a|=1",
+        "let a| = 1;\n",
+        &[(4, 5, ""), (6, 6, " "), (7, 7, " "), (8, 8, ";\n")],
+    );
+    // Only the group of lines containing the cursor is diffed by token.
+    assert_edits_with_cursor(
+        "foo(a,b)\nx|\nbar(c,d)\n",
+        "foo(a, b);\nx|\nbar(c, d);\n",
+        &[(6, 8, " b);"), (17, 19, " d);")],
+    );
+    assert_edits_with_cursor(
+        "foo(a,b)\nbar(c,|d)\n",
+        "foo(a, b);\nbar(c,| d);\n",
+        &[(6, 6, " "), (8, 8, ";"), (15, 15, " "), (17, 17, ";")],
+    );
+}
+
+#[test]
+fn moves_the_cursor_in_replaced_text() {
+    assert_edits_with_cursor("a  |  b", "a |b", &[(2, 5, "")]);
+    assert_edits_with_cursor("ab|c\n", "xy|zw\n", &[(0, 3, "xyzw")]);
+    assert_edits_with_cursor("x|;", "x|;", &[]);
+}
+
+#[test]
+fn formatter() {
+    let source_text = "\
+// comment
+const a = {b:1,
+  c: 2};
+
+function foo() { return a }
+";
+    let allocator = Allocator::new();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_options(get_parse_options())
+        .parse();
+    let formatted_text = Formatter::new(&allocator, FormatOptions::default()).build(&ret.program);
+    let edits = Formatter::new(&allocator, FormatOptions::default()).build_text_edits(&ret.program);
+    assert_eq!(apply(source_text, &edits), formatted_text);
+    // The comment and the blank line are left alone.
+    assert!(edits.iter().all(|edit| edit.span.start >= 11));
+    assert!(
+        !edits
+            .iter()
+            .any(|edit| edit.span == Span::new(0, u32::try_from(source_text.len()).unwrap()))
+    );
+}
@@ -0,0 +1,18 @@
+export type Labeled = [first: string, second: number];
+export type Optional = [a?: string, b?: number];
+export type UnlabeledOptional = [string, number?];
+export type Rest = [first: string, ...rest: number[]];
+export type UnlabeledRest = [string, ...boolean[]];
+export type Variadic<T extends unknown[]> = [...T, number];
+export type Concat<T extends unknown[], U extends unknown[]> = [...T, ...U];
+export type Nested<T> = [outer: [inner: T, ...others: T[]], ...Array<[key: string, value?: T]>];
+export type ReadonlyTuple = readonly [x: number, y?: number];
+export type Empty = [];
+
+export function args(...args: [name: string, age?: number, ...tags: string[]]): void {}
+export declare const pair: [key: string, value: unknown];
+
+export const defaults: [string, number] = ["a", 1];
+export function getPair<T>(value: T): [value: T, index: number] {
+  return [value, 0];
+}
@@ -604,6 +604,22 @@ pub fn is_callee<'a>(node: &AstNode<'a>, semantic: &Semantic<'a>) -> bool {
     parent.is_some_and(|node | matches!(node.kind(), AstKind::CallExpression(call_expr) if call_expr.callee.span().contains_inclusive(node.kind().span())))
 }
 
+/// Whether the `thisArg` of `f.call(thisArg)` or `f.apply(thisArg, args)`, which callers have
+/// checked to be `null`, `undefined` or the object `f` is read from, can be dropped when calling
+/// `f` directly: `undefined`, `void 0`, an identifier or `this`.
+///
+/// `null` can't, as `f` would be called with `this` set to `undefined` instead of `null` in strict
+/// mode code. Other expressions may have side effects when they are evaluated.
+pub fn is_removable_this_arg(this_arg: &Expression) -> bool {
+    match this_arg.without_parentheses() {
+        Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::Void && unary.argument.is_literal()
+        }
+        _ => false,
+    }
+}
+
 fn has_jsdoc_this_tag<'a>(semantic: &Semantic<'a>, node: &AstNode<'a>) -> bool {
     let Some(jsdocs) = get_function_nearest_jsdoc_node(node, semantic)
         .and_then(|node| semantic.jsdoc().get_all_by_node(semantic.nodes(), node))
@@ -88,6 +88,7 @@ pub(crate) mod eslint {
     pub mod no_debugger;
     pub mod no_delete_var;
     pub mod no_div_regex;
+    pub mod no_dupe_args;
     pub mod no_dupe_class_members;
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
@@ -135,6 +136,8 @@ pub(crate) mod eslint {
     pub mod no_nonoctal_decimal_escape;
     pub mod no_obj_calls;
     pub mod no_object_constructor;
+    pub mod no_octal;
+    pub mod no_octal_escape;
     pub mod no_param_reassign;
     pub mod no_plusplus;
     pub mod no_promise_executor_return;
@@ -160,6 +163,7 @@ pub(crate) mod eslint {
     pub mod no_undef;
     pub mod no_undefined;
     pub mod no_unexpected_multiline;
+    pub mod no_unmodified_loop_condition;
     pub mod no_unneeded_ternary;
     pub mod no_unreachable;
     pub mod no_unsafe_finally;
@@ -169,6 +173,7 @@ pub(crate) mod eslint {
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
     pub mod no_unused_vars;
+    pub mod no_useless_assignment;
     pub mod no_useless_backreference;
     pub mod no_useless_call;
     pub mod no_useless_catch;
@@ -586,12 +591,15 @@ pub(crate) mod oxc {
     pub mod no_accumulating_spread;
     pub mod no_async_await;
     pub mod no_async_endpoint_handlers;
+    pub mod no_async_in_array_methods;
     pub mod no_barrel_file;
     pub mod no_const_enum;
     pub mod no_map_spread;
     pub mod no_optional_chaining;
     pub mod no_rest_spread_properties;
     pub mod no_this_in_exported_function;
+    pub mod no_unnecessary_boolean_literal_compare;
+    pub mod no_unsafe_member_access_after_await;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
     pub mod uninvoked_array_callback;
@@ -750,7 +758,9 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_object_constructor,
     eslint::no_param_reassign,
     eslint::no_restricted_imports,
+    eslint::no_unmodified_loop_condition,
     eslint::no_unneeded_ternary,
+    eslint::no_useless_assignment,
     eslint::no_useless_backreference,
     eslint::no_useless_call,
     eslint::no_compare_neg_zero,
@@ -765,6 +775,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_debugger,
     eslint::no_delete_var,
     eslint::no_div_regex,
+    eslint::no_dupe_args,
     eslint::no_dupe_class_members,
     eslint::no_dupe_else_if,
     eslint::no_dupe_keys,
@@ -800,6 +811,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_new,
     eslint::no_nonoctal_decimal_escape,
     eslint::no_obj_calls,
+    eslint::no_octal,
+    eslint::no_octal_escape,
     eslint::no_plusplus,
     eslint::no_promise_executor_return,
     eslint::no_proto,
@@ -1035,12 +1048,15 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_accumulating_spread,
     oxc::no_async_await,
     oxc::no_async_endpoint_handlers,
+    oxc::no_async_in_array_methods,
     oxc::no_barrel_file,
     oxc::no_const_enum,
     oxc::no_map_spread,
     oxc::no_optional_chaining,
     oxc::no_rest_spread_properties,
     oxc::no_this_in_exported_function,
+    oxc::no_unnecessary_boolean_literal_compare,
+    oxc::no_unsafe_member_access_after_await,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
@@ -77,6 +77,16 @@ declare_oxc_lint!(
     /// for a property but never have a corresponding getter defined for it.
     /// Without a getter, you cannot read the property, so it ends up not being used.
     ///
+    /// Accessors are paired by key within each object literal, class body (separately for
+    /// static and instance members) and, with `enforceForTSTypes`, interface or type literal.
+    /// Computed keys are paired when their expressions are the same, e.g. `get [a]()` and
+    /// `set [a](v)`. Property descriptors passed to `Object.defineProperty`,
+    /// `Reflect.defineProperty`, `Object.defineProperties` and `Object.create` are checked
+    /// for their `get` and `set` properties.
+    ///
+    /// Accessors inherited from a superclass or prototype are not known, so a setter which
+    /// is paired with an inherited getter is still reported.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -381,8 +391,7 @@ impl AccessorPairs {
     }
 
     fn check_property_descriptor(&self, descriptor: &ObjectExpression, ctx: &LintContext) {
-        let mut has_get = false;
-        let mut has_set = false;
+        let mut get_span = None;
         let mut set_span = None;
 
         for prop in &descriptor.properties {
@@ -390,28 +399,30 @@ impl AccessorPairs {
                 continue;
             };
 
+            // `{ get() {} }` and `{ get: function() {} }`, not `{ get get() {} }` or `{ ["get"]() {} }`
+            if prop.kind != PropertyKind::Init || prop.computed {
+                continue;
+            }
+
             let Some(name) = prop.key.static_name() else {
                 continue;
             };
 
             match &*name {
-                "get" => has_get = true,
-                "set" => {
-                    has_set = true;
-                    set_span = Some(prop.key.span());
-                }
+                "get" => get_span = Some(prop.key.span()),
+                "set" => set_span = Some(prop.key.span()),
                 _ => {}
             }
         }
 
-        // Only report setWithoutGet for property descriptors
-        // (ESLint doesn't check getWithoutSet for Object.defineProperty)
-        if has_set
-            && !has_get
-            && self.set_without_get
-            && let Some(span) = set_span
-        {
-            ctx.diagnostic(setter_without_getter_diagnostic(span));
+        match (get_span, set_span) {
+            (None, Some(span)) if self.set_without_get => {
+                ctx.diagnostic(setter_without_getter_diagnostic(span));
+            }
+            (Some(span), None) if self.get_without_set => {
+                ctx.diagnostic(getter_without_setter_diagnostic(span));
+            }
+            _ => {}
         }
     }
 
@@ -750,6 +761,12 @@ fn test() {
             None,
         ),
         ("var o = {get: function() {}}", Some(serde_json::json!([{ "getWithoutSet": true }]))),
+        ("Object.defineProperty(obj, 'foo', {get: function() {}});", None),
+        (
+            "Object.defineProperty(obj, 'foo', {get() {}, set(value) {}});",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+        ("Object.defineProperty(obj, 'foo', {get set() {}, ['set']: function(value) {}});", None),
         ("var o = {[set]: function() {}}", None),
         (
             "var set = 'value'; Object.defineProperty(obj, 'foo', {[set]: function(value) {}});",
@@ -1370,6 +1387,18 @@ fn test() {
         ("(Reflect?.defineProperty)(obj, 'foo', {set: function(value) {}});", None),
         ("(Object?.defineProperties)(obj, {foo: {set: function(value) {}}});", None),
         ("(Object?.create)(null, {foo: {set: function(value) {}}});", None),
+        (
+            "Object.defineProperty(obj, 'foo', {get: function() {}});",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+        (
+            "Object.defineProperties(obj, {foo: {get() {}}, bar: {set(value) {}}});",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+        (
+            "Object.create(null, {foo: {get: function() {}, set: undefined}, bar: {set() {}}});",
+            None,
+        ),
         ("class A { set a(foo) {} }", None),
         ("class A { get a() {} set b(foo) {} }", Some(serde_json::json!([{}]))),
         (
@@ -62,6 +62,9 @@ declare_oxc_lint!(
     /// const n = -2;
     /// for (let i = 0; i < 10; i += n) {
     /// }
+    ///
+    /// for (let i = 0, j = 10; i < 10; i--, j++) {
+    /// }
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -80,6 +83,9 @@ declare_oxc_lint!(
     ///
     /// for (let i = MIN; i <= MAX; i -= 0) { // not increasing or decreasing
     /// }
+    ///
+    /// for (let i = 0, j = 10; i < j; i++, j--) {
+    /// }
     /// ```
     ForDirection,
     eslint,
@@ -109,40 +115,65 @@ impl Rule for ForDirection {
             return;
         };
 
-        let Some((counter, counter_position)) = extract_counter(&test.left, &test.right) else {
+        let Some(update_expr) = &for_loop.update else {
             return;
         };
 
-        let Some(expected_update_direction) =
-            get_expected_update_direction(test.operator, counter_position)
+        // Prefer the counter on the left of the test, falling back to the right one
+        // when only that one is updated, e.g. `for (; 0 < i; i--)`.
+        let counters = [(&test.left, CounterPosition::Left), (&test.right, CounterPosition::Right)];
+        let Some((counter_position, update)) =
+            counters.into_iter().find_map(|(counter, position)| {
+                let Expression::Identifier(counter) = counter else {
+                    return None;
+                };
+                find_counter_update(update_expr, counter).map(|update| (position, update))
+            })
         else {
             return;
         };
 
-        let Some(update_expr) = &for_loop.update else {
+        let Some(expected_update_direction) =
+            get_expected_update_direction(test.operator, counter_position)
+        else {
             return;
         };
 
-        let Some(update_direction) = get_update_direction(update_expr, counter) else {
+        let Some(update_direction) = get_update_direction(update) else {
             return;
         };
 
         if update_direction != expected_update_direction {
             ctx.diagnostic_with_dangerous_fix(
-                for_direction_diagnostic(test.span, get_update_span(update_expr)),
-                |fixer| apply_rule_fix(&fixer, update_expr),
+                for_direction_diagnostic(test.span, get_update_span(update)),
+                |fixer| apply_rule_fix(&fixer, update),
             );
         }
     }
 }
 
-fn extract_counter<'a>(
-    left: &'a Expression<'a>,
-    right: &'a Expression<'a>,
-) -> Option<(&'a IdentifierReference<'a>, CounterPosition)> {
-    match (left, right) {
-        (Expression::Identifier(counter), _) => Some((counter, CounterPosition::Left)),
-        (_, Expression::Identifier(counter)) => Some((counter, CounterPosition::Right)),
+/// Finds the expression in the update clause that updates `counter`,
+/// looking through comma separated updates such as `i++, j--`.
+fn find_counter_update<'a>(
+    update: &'a Expression<'a>,
+    counter: &IdentifierReference,
+) -> Option<&'a Expression<'a>> {
+    match update {
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.iter().find_map(|update| find_counter_update(update, counter))
+        }
+        Expression::UpdateExpression(update_expr) => match &update_expr.argument {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(id) if id.name == counter.name => {
+                Some(update)
+            }
+            _ => None,
+        },
+        Expression::AssignmentExpression(assign) => match &assign.left {
+            AssignmentTarget::AssignmentTargetIdentifier(id) if id.name == counter.name => {
+                Some(update)
+            }
+            _ => None,
+        },
         _ => None,
     }
 }
@@ -205,28 +236,16 @@ fn apply_rule_fix(fixer: &RuleFixer<'_, '_>, update: &Expression) -> RuleFix {
     fixer.replace(span, replacement)
 }
 
-fn get_update_direction(
-    update: &Expression,
-    counter: &IdentifierReference,
-) -> Option<UpdateDirection> {
+/// Direction of an update found by [`find_counter_update`], if it can be determined.
+fn get_update_direction(update: &Expression) -> Option<UpdateDirection> {
     match update {
         // match increment or decrement
-        Expression::UpdateExpression(update) => match &update.argument {
-            SimpleAssignmentTarget::AssignmentTargetIdentifier(id) if id.name == counter.name => {
-                Some(match update.operator {
-                    UpdateOperator::Increment => UpdateDirection::Forward,
-                    UpdateOperator::Decrement => UpdateDirection::Backward,
-                })
-            }
-            _ => None,
-        },
+        Expression::UpdateExpression(update) => Some(match update.operator {
+            UpdateOperator::Increment => UpdateDirection::Forward,
+            UpdateOperator::Decrement => UpdateDirection::Backward,
+        }),
         // match add assign or subtract assign
-        Expression::AssignmentExpression(assign) => match &assign.left {
-            AssignmentTarget::AssignmentTargetIdentifier(id) if id.name == counter.name => {
-                get_assignment_direction(assign)
-            }
-            _ => None,
-        },
+        Expression::AssignmentExpression(assign) => get_assignment_direction(assign),
         // can't determine other kinds of updates
         _ => None,
     }
@@ -313,6 +332,16 @@ fn test() {
         ("for(var i = 0; i === 10; i+=1){}", None),
         ("for(var i = 0; i == 10; i+=1){}", None),
         ("for(var i = 0; i != 10; i+=1){}", None),
+        // multiple loop variables
+        ("for(var i = 0, j = 10; i < j; i++, j--){}", None),
+        ("for(var i = 0, j = 10; j > i; j--, i++){}", None),
+        ("for(var i = 0, j = 0; i < 10; j--, i++){}", None),
+        ("for(var i = 10; 0 < i; j++, i--){}", None),
+        ("for(var i = 0; i < 10; j++, k--){}", None),
+        // counter only updated on the right side of the test
+        ("for(var i = 10; n < i; i--){}", None),
+        // unknown step of a compound update
+        ("for(var i = 0; i < n; i += step, j--){}", None),
     ];
 
     let fail = vec![
@@ -337,6 +366,13 @@ fn test() {
         ("for(var i = 10; i >= 0; i-=-1){}", None),
         // test if '+=', '-=' with counter 'i' on the right side of test condition
         ("for(var i = 0; 10 > i; i-=1){}", None),
+        // multiple loop variables
+        ("for(var i = 0, j = 10; i < 10; i--, j++){}", None),
+        ("for(var i = 0, j = 10; i <= j; j--, i--){}", None),
+        ("for(var i = 10; i > 0; j--, i+=1){}", None),
+        // counter only updated on the right side of the test
+        ("for(var i = 0; n > i; i--){}", None),
+        ("for(var i = 10; n <= i; i++){}", None),
     ];
 
     let fix = vec![
@@ -352,6 +388,13 @@ fn test() {
         // variables of different lengths
         ("for(var ii = 0; ii < 10; ii--){}", "for(var ii = 0; ii < 10; ii++){}", None),
         ("for(var ii = 10; ii > 0; ii+=1){}", "for(var ii = 10; ii > 0; ii-=1){}", None),
+        // multiple loop variables
+        (
+            "for(var i = 0, j = 0; i < 10; i--, j++){}",
+            "for(var i = 0, j = 0; i < 10; i++, j++){}",
+            None,
+        ),
+        ("for(var i = 0; n > i; i--){}", "for(var i = 0; n > i; i++){}", None),
     ];
 
     Tester::new(ForDirection::NAME, ForDirection::PLUGIN, pass, fail)
@@ -1,5 +1,3 @@
-use std::borrow::Cow;
-
 use oxc_allocator::Box;
 use oxc_ast::{
     AstKind,
@@ -11,7 +9,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{ContentEq, GetSpan, Span};
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -105,6 +103,11 @@ declare_oxc_lint!(
     /// While it is allowed to define the pair for a getter or a setter anywhere in an object or class definition,
     /// it’s considered a best practice to group accessor functions for the same property.
     ///
+    /// Accessors are paired by their keys: `a`, `'a'` and ``[`a`]`` are the same key.
+    /// Computed keys which aren't literals are compared by their expressions, so `[a.b]` and
+    /// `[(a . b)]` are the same key, but `[a]` and `a` are not.
+    /// Keys with more than one getter or setter are ignored.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -206,6 +209,7 @@ impl Rule for GroupedAccessorPairs {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::ObjectExpression(obj_expr) => {
+                let mut computed_keys = vec![];
                 let mut prop_map =
                     FxHashMap::<(String, bool), Vec<(usize, &Box<ObjectProperty>)>>::default();
                 let properties = &obj_expr.properties;
@@ -226,7 +230,8 @@ impl Rule for GroupedAccessorPairs {
                     // 2) var foo = { get a() {}, set ['a'](v) {} }
                     // in this example, a and ['a'] should be treated as a pair
                     // although the set access property is computed, but its key is Literal
-                    let (key_name, is_literal) = get_key_name_and_check_literal(ctx, &obj_prop.key);
+                    let (key_name, is_literal) =
+                        get_key_name_and_check_literal(&obj_prop.key, &mut computed_keys);
                     let is_computed = if is_literal { false } else { obj_prop.computed };
                     prop_map.entry((key_name, is_computed)).or_default().push((idx, obj_prop));
                 }
@@ -263,6 +268,7 @@ impl Rule for GroupedAccessorPairs {
             }
             AstKind::ClassBody(class_body) => {
                 let method_defines = &class_body.body;
+                let mut computed_keys = vec![];
                 let mut prop_map = FxHashMap::<
                     (String, bool, bool, bool),
                     Vec<(usize, &Box<MethodDefinition>)>,
@@ -279,7 +285,7 @@ impl Rule for GroupedAccessorPairs {
                         continue;
                     }
                     let (key_name, is_literal) =
-                        get_key_name_and_check_literal(ctx, &method_define.key);
+                        get_key_name_and_check_literal(&method_define.key, &mut computed_keys);
                     let is_computed = if is_literal { false } else { method_define.computed };
                     let is_private = matches!(method_define.key, PropertyKey::PrivateIdentifier(_));
                     // for Class we need to focus on whether the key is static or private
@@ -356,6 +362,7 @@ impl GroupedAccessorPairs {
     }
 
     fn check_ts_signatures<'a>(&self, signatures: &[TSSignature<'a>], ctx: &LintContext<'a>) {
+        let mut computed_keys = vec![];
         let mut prop_map =
             FxHashMap::<(String, bool), Vec<(usize, &Box<TSMethodSignature>)>>::default();
 
@@ -366,7 +373,8 @@ impl GroupedAccessorPairs {
             if !matches!(method_sig.kind, TSMethodSignatureKind::Get | TSMethodSignatureKind::Set) {
                 continue;
             }
-            let (key_name, is_literal) = get_key_name_and_check_literal(ctx, &method_sig.key);
+            let (key_name, is_literal) =
+                get_key_name_and_check_literal(&method_sig.key, &mut computed_keys);
             let is_computed = if is_literal { false } else { method_sig.computed };
             prop_map.entry((key_name, is_computed)).or_default().push((idx, method_sig));
         }
@@ -403,33 +411,25 @@ impl GroupedAccessorPairs {
     }
 }
 
-fn get_key_name_and_check_literal<'a>(
-    ctx: &LintContext<'a>,
-    prop_key: &PropertyKey<'a>,
+/// Computed keys which aren't literals are compared by their expressions rather than their
+/// source text, so that `[a.b]` and `[(a . b)]` are the same key. They are keyed by their
+/// position in `computed_keys`, which is only used for grouping, never in diagnostics.
+fn get_key_name_and_check_literal<'a, 'k>(
+    prop_key: &'k PropertyKey<'a>,
+    computed_keys: &mut Vec<&'k Expression<'a>>,
 ) -> (String, bool) {
-    let key_name = prop_key
-        .name()
-        .unwrap_or_else(|| {
-            Cow::Borrowed(prop_key.as_expression().unwrap().span().source_text(ctx.source_text()))
-        })
-        .to_string();
-    let is_literal =
-        if matches!(prop_key, PropertyKey::StaticIdentifier(_) | PropertyKey::PrivateIdentifier(_))
-        {
-            false
-        } else {
-            matches!(
-                prop_key.as_expression().unwrap(),
-                Expression::BooleanLiteral(_)
-                    | Expression::NullLiteral(_)
-                    | Expression::StringLiteral(_)
-                    | Expression::RegExpLiteral(_)
-                    | Expression::BigIntLiteral(_)
-                    | Expression::NumericLiteral(_)
-                    | Expression::TemplateLiteral(_)
-            )
-        };
-    (key_name, is_literal)
+    if let Some(key_name) = prop_key.name() {
+        return (key_name.into_owned(), !prop_key.is_identifier());
+    }
+    let expr = prop_key.as_expression().unwrap().get_inner_expression();
+    if let Expression::BooleanLiteral(lit) = expr {
+        return (lit.value.to_string(), true);
+    }
+    let idx = computed_keys.iter().position(|key| key.content_eq(expr)).unwrap_or_else(|| {
+        computed_keys.push(expr);
+        computed_keys.len() - 1
+    });
+    (idx.to_string(), false)
 }
 
 fn get_diagnostic_access_name(
@@ -644,6 +644,8 @@ fn test() {
             "type T = { set prop(value: any), get prop(): any }",
             Some(serde_json::json!(["setBeforeGet", { "enforceForTSTypes": true }])),
         ),
+        ("({ get [a.b](){}, c: 1, set [a.c](foo){} })", None),
+        ("class A { get [a](){} b(){} set [`${a}`](foo){} }", None),
     ];
 
     let fail = vec![
@@ -847,6 +849,10 @@ fn test() {
             "type T = { get a(): any, set a(value: any) }",
             Some(serde_json::json!(["setBeforeGet", { "enforceForTSTypes": true }])),
         ),
+        ("({ get [a.b](){}, c: 1, set [ a . b ](foo){} })", None),
+        ("({ get [a /* key */](){}, c: 1, set [(a)](foo){} })", None),
+        ("class A { get [a + b](){} c(){} set [a+b](foo){} }", None),
+        ("({ get [`${a}`](){}, c: 1, set [`${a}`](foo){} })", None),
     ];
 
     Tester::new(GroupedAccessorPairs::NAME, GroupedAccessorPairs::PLUGIN, pass, fail)
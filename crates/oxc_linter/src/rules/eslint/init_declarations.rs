@@ -59,7 +59,7 @@ declare_oxc_lint!(
     /// In JavaScript, variables can be assigned during declaration, or at any point afterwards using an assignment statement.
     /// For example, in the following code, foo is initialized during declaration, while bar is initialized later.
     ///
-    /// ### Examples
+    /// ```js
     /// var foo = 1;
     /// var bar;
     /// if (foo) {
@@ -67,6 +67,13 @@ declare_oxc_lint!(
     /// } else {
     ///     bar = 2;
     /// }
+    /// ```
+    ///
+    /// With `"never"`, declarations in the head of `for`, `for-in` and `for-of` loops are
+    /// reported as initialized, unless `ignoreForLoopInit` is set. `const`, `using` and
+    /// `await using` declarations must always be initialized, so are never reported.
+    ///
+    /// ### Examples
     ///
     /// Examples of incorrect code for the default "always" option:
     /// ```js
@@ -151,23 +158,23 @@ impl Rule for InitDeclarations {
                     return;
                 }
             }
+            // Declarations in the head of a `for`, `for-in` or `for-of` loop are
+            // initialized by the loop, eg: "for (var a; a < 2; a++)" a is initialized
+            let is_for_loop_init = match parent.kind() {
+                AstKind::ForInStatement(ForInStatement { left, .. })
+                | AstKind::ForOfStatement(ForOfStatement { left, .. }) => {
+                    matches!(left, ForStatementLeft::VariableDeclaration(left_node) if left_node.span == decl.span)
+                }
+                AstKind::ForStatement(ForStatement { init, .. }) => {
+                    matches!(init, Some(ForStatementInit::VariableDeclaration(init)) if init.span == decl.span)
+                }
+                _ => false,
+            };
             for v in &decl.declarations {
                 let BindingPattern::BindingIdentifier(identifier) = &v.id else {
                     continue;
                 };
-                let is_initialized = match parent.kind() {
-                    AstKind::ForInStatement(ForInStatement { left, .. })
-                    | AstKind::ForOfStatement(ForOfStatement { left, .. }) => {
-                        matches!(left, ForStatementLeft::VariableDeclaration(left_node) if left_node.span == decl.span)
-                    }
-                    // When eslint processes ForStatementInit statements
-                    // the default variable is initialized
-                    // eg: "for (var a; a < 2; a++)" a is initialized
-                    AstKind::ForStatement(ForStatement { init, .. }) => {
-                        matches!(init, Some(ForStatementInit::VariableDeclaration(init)) if init.span == decl.span)
-                    }
-                    _ => v.init.is_some(),
-                };
+                let is_initialized = is_for_loop_init || v.init.is_some();
 
                 match self.mode {
                     Mode::Always if !is_initialized => {
@@ -177,8 +184,11 @@ impl Rule for InitDeclarations {
                             identifier.name.as_str(),
                         ));
                     }
-                    Mode::Never if is_initialized && !self.ignore_for_loop_init => {
-                        if matches!(&v.kind, VariableDeclarationKind::Const) {
+                    Mode::Never
+                        if is_initialized && !(is_for_loop_init && self.ignore_for_loop_init) =>
+                    {
+                        // `const`, `using` and `await using` declarations must be initialized
+                        if matches!(&v.kind, VariableDeclarationKind::Const) || v.kind.is_using() {
                             continue;
                         }
                         ctx.diagnostic(init_declarations_diagnostic(
@@ -249,6 +259,10 @@ fn test() {
             "for (var a, b = 2; a < 100; a++) {}",
             Some(serde_json::json!(["never", { "ignoreForLoopInit": true }])),
         ),
+        ("using a = f();", Some(serde_json::json!(["never"]))),
+        ("async function f() { await using a = g(); }", Some(serde_json::json!(["never"]))),
+        ("for (const a of []) {}", Some(serde_json::json!(["always"]))),
+        ("let a; a = 1;", Some(serde_json::json!(["never", { "ignoreForLoopInit": true }]))),
         // typescript-eslint
         ("declare const foo: number;", Some(serde_json::json!(["always"]))),
         ("declare const foo: number;", Some(serde_json::json!(["never"]))),
@@ -364,6 +378,11 @@ fn test() {
         ("for (var foo in []) {}", Some(serde_json::json!(["never"]))),
         ("for (var foo of []) {}", Some(serde_json::json!(["never"]))), // { "ecmaVersion": 6 }
         ("for (var a, b = 2; a < 100; a++) {}", Some(serde_json::json!(["never"]))),
+        ("var foo = 1;", Some(serde_json::json!(["never", { "ignoreForLoopInit": true }]))),
+        (
+            "for (var i = 0; i < 1; i++) { let j = i; }",
+            Some(serde_json::json!(["never", { "ignoreForLoopInit": true }])),
+        ),
         // typescript-eslint
         ("let arr: string[] = ['arr', 'ar'];", Some(serde_json::json!(["never"]))),
         (
@@ -1,4 +1,7 @@
-use oxc_ast::{AstKind, ast::TSType};
+use oxc_ast::{
+    AstKind,
+    ast::{FormalParameters, TSType},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -33,6 +36,17 @@ pub struct MaxParamsConfig {
     /// For example `{ "countVoidThis": true }` would mean that having a function
     /// take a `this` parameter of type `void` is counted towards the maximum number of parameters.
     count_void_this: bool,
+    /// This option is for ignoring the `this` parameter entirely, whatever its type.
+    ///
+    /// For example `{ "ignoreThis": true }` would mean that `function foo(this: Foo, a, b, c) {}`
+    /// has three parameters. This takes precedence over `countVoidThis`.
+    ignore_this: bool,
+    /// This option is for counting the parameter properties of constructors, such as
+    /// `private a` in `constructor(private a) {}`.
+    ///
+    /// For example `{ "countParameterProperties": false }` would mean that only the plain
+    /// parameters of a constructor are counted, as parameter properties declare class members.
+    count_parameter_properties: bool,
 }
 
 impl std::ops::Deref for MaxParams {
@@ -45,7 +59,12 @@ impl std::ops::Deref for MaxParams {
 
 impl Default for MaxParamsConfig {
     fn default() -> Self {
-        Self { max: 3, count_void_this: false }
+        Self {
+            max: 3,
+            count_void_this: false,
+            ignore_this: false,
+            count_parameter_properties: true,
+        }
     }
 }
 
@@ -63,6 +82,11 @@ declare_oxc_lint!(
     /// adhere to a convention that caps the number of parameters a function
     /// can take.
     ///
+    /// Destructured and defaulted parameters count as one parameter each, as does
+    /// the rest parameter. The TypeScript `this` parameter is counted unless it is
+    /// of type `void`, see the `countVoidThis` and `ignoreThis` options. Overload
+    /// signatures and `declare function`s are checked like any other function.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -104,7 +128,7 @@ impl Rule for MaxParams {
             .and_then(serde_json::Number::as_u64)
             .and_then(|v| usize::try_from(v).ok())
         {
-            Self(Box::new(MaxParamsConfig { max, count_void_this: false }))
+            Self(Box::new(MaxParamsConfig { max, ..MaxParamsConfig::default() }))
         } else {
             serde_json::from_value::<DefaultRuleConfig<MaxParams>>(value)
                 .unwrap_or_default()
@@ -118,9 +142,10 @@ impl Rule for MaxParams {
                 if !function.is_declaration() & !function.is_expression() {
                     return;
                 }
-                let params = &function.params;
-                let mut real_len = params.items.len();
-                if let Some(this_params) = &function.this_param {
+                let mut real_len = self.count_params(&function.params);
+                if let Some(this_params) = &function.this_param
+                    && !self.ignore_this
+                {
                     let is_void_this = this_params
                         .type_annotation
                         .as_ref()
@@ -150,11 +175,11 @@ impl Rule for MaxParams {
                 }
             }
             AstKind::ArrowFunctionExpression(function) => {
-                if function.params.items.len() > self.max {
+                let real_len = self.count_params(&function.params);
+                if real_len > self.max {
                     let error_msg = format!(
                         "Arrow function has too many parameters ({}). Maximum allowed is {}.",
-                        function.params.items.len(),
-                        self.max
+                        real_len, self.max
                     );
                     let span = function.params.span;
                     ctx.diagnostic(max_params_diagnostic(&error_msg, span));
@@ -165,6 +190,17 @@ impl Rule for MaxParams {
     }
 }
 
+impl MaxParams {
+    /// Counts the parameters, including the rest parameter.
+    fn count_params(&self, params: &FormalParameters) -> usize {
+        if self.count_parameter_properties {
+            params.parameters_count()
+        } else {
+            params.parameters_count() - params.items.iter().filter(|p| p.has_modifier()).count()
+        }
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -192,6 +228,19 @@ fn test() {
             "const testE = function (this: any, a) {}",
             Some(serde_json::json!([{ "max": 2, "countVoidThis": true }])),
         ),
+        ("function test(a, ...b) {}", Some(serde_json::json!([2]))),
+        ("function test({ a, b, c }, [d, e] = [], f = 1) {}", None),
+        ("function test(this: Foo, a, b, c) {}", Some(serde_json::json!([{ "ignoreThis": true }]))),
+        (
+            "function test(this: void, a, b, c) {}",
+            Some(serde_json::json!([{ "countVoidThis": true, "ignoreThis": true }])),
+        ),
+        ("class Foo { constructor(private a, public b, readonly c) {} }", None),
+        (
+            "class Foo { constructor(private a, protected b, readonly c, override d, e) {} }",
+            Some(serde_json::json!([{ "max": 1, "countParameterProperties": false }])),
+        ),
+        ("function test(a, b): void; function test(a) {}", Some(serde_json::json!([2]))),
     ];
 
     let fail = vec![
@@ -232,6 +281,18 @@ fn test() {
             "const testE = function (this: any, a) {}",
             Some(serde_json::json!([{ "max": 1, "countVoidThis": false }])),
         ),
+        ("function test(a, b, ...c) {}", Some(serde_json::json!([2]))),
+        ("var test = (a, b, ...c) => {};", Some(serde_json::json!([2]))),
+        ("function test({ a }, [b], c = 1, ...d) {}", None),
+        ("function test(this: Foo, a, b, c) {}", None),
+        ("class Foo { constructor(private a, public b, readonly c, d) {} }", None),
+        (
+            "class Foo { constructor(private a, b, c) {} }",
+            Some(serde_json::json!([{ "max": 1, "countParameterProperties": false }])),
+        ),
+        ("function test(a, b, c): void; function test() {}", Some(serde_json::json!([2]))),
+        ("declare function test(a, b, c, d): void;", None),
+        ("class Foo { method(a, b, c, d): void; method() {} }", None),
     ];
 
     Tester::new(MaxParams::NAME, MaxParams::PLUGIN, pass, fail).test_and_snapshot();
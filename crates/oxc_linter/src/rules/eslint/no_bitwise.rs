@@ -1,8 +1,11 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, UnaryExpression},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
-use oxc_syntax::operator::BinaryOperator;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
@@ -44,6 +47,16 @@ pub struct NoBitwiseConfig {
     /// const b = a|0;
     /// ```
     int32_hint: bool,
+    /// When set to `true`, double bitwise NOT (`~~`) is allowed for integer truncation.
+    /// Other uses of `~`, including `~~~a`, are still reported.
+    ///
+    /// For example with `{ "allowDoubleBitwiseNot": true }` the following is permitted:
+    ///
+    /// ```javascript
+    /// const c = ~~a;
+    /// const d = ~(~a);
+    /// ```
+    allow_double_bitwise_not: bool,
 }
 
 impl std::ops::Deref for NoBitwise {
@@ -64,6 +77,9 @@ declare_oxc_lint!(
     /// The use of bitwise operators in JavaScript is very rare and often `&` or `|` is simply a mistyped `&&` or `||`,
     /// which will lead to unexpected behavior.
     ///
+    /// Type operators, such as `|` and `&` in TypeScript union and intersection types, are not
+    /// bitwise operators and are not reported.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -121,7 +137,7 @@ impl Rule for NoBitwise {
 
                 if unary_expr.operator.is_bitwise()
                     && !allowed_operator(&self.allow, op)
-                    && !is_int32_hint(self.int32_hint, node)
+                    && !(self.allow_double_bitwise_not && is_double_bitwise_not(node, ctx))
                 {
                     ctx.diagnostic(no_bitwise_diagnostic(op, unary_expr.span));
                 }
@@ -158,6 +174,46 @@ fn is_int32_hint(int32_hint: bool, node: &AstNode) -> bool {
     }
 }
 
+/// Either `~` of `~~a`, or of `~(~a)`, but not of `~~~a`.
+fn is_double_bitwise_not<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let AstKind::UnaryExpression(unary_expr) = node.kind() else { return false };
+    let parent = bitwise_not_parent(node, ctx);
+    if let Some(inner) = as_bitwise_not(&unary_expr.argument) {
+        // the outer `~`
+        parent.is_none() && as_bitwise_not(&inner.argument).is_none()
+    } else {
+        // the inner `~`
+        parent.is_some_and(|parent| bitwise_not_parent(parent, ctx).is_none())
+    }
+}
+
+/// The `~` `node` is the operand of, ignoring parentheses.
+fn bitwise_not_parent<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let parent = ctx
+        .nodes()
+        .ancestors(node.id())
+        .find(|ancestor| !matches!(ancestor.kind(), AstKind::ParenthesizedExpression(_)))?;
+    matches!(
+        parent.kind(),
+        AstKind::UnaryExpression(parent_expr) if parent_expr.operator == UnaryOperator::BitwiseNot
+    )
+    .then_some(parent)
+}
+
+fn as_bitwise_not<'a, 'b>(expr: &'b Expression<'a>) -> Option<&'b UnaryExpression<'a>> {
+    match expr.without_parentheses() {
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::BitwiseNot =>
+        {
+            Some(unary_expr)
+        }
+        _ => None,
+    }
+}
+
 #[test]
 fn test() {
     use serde_json::json;
@@ -179,6 +235,15 @@ fn test() {
         ("~1<<2 === -8", Some(json!([ { "allow": ["~", "<<"] }]))),
         ("a|0", Some(json!([ { "int32Hint": true }]))),
         ("a|0", Some(json!([ { "int32Hint": false, "allow": ["|"] }]))),
+        ("~~a", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("const b = ~~(a / 2);", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("const b = ~(~a);", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("const b = (~~a) + ~~b;", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("type A = B | C;", None),
+        ("type A = B & C;", None),
+        ("let a: string | number = 1;", None),
+        ("function f(a: B & { c: number } | null) {}", None),
+        ("type A = | B | C;", None),
     ];
 
     let fail = vec![
@@ -195,6 +260,14 @@ fn test() {
         ("a <<= b", None),
         ("a >>= b", None),
         ("a >>>= b", None),
+        ("~~a", None),
+        ("~a", Some(json!([ { "int32Hint": true }]))),
+        ("~~a", Some(json!([ { "int32Hint": true }]))),
+        ("~a", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("~~~a", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("~(~(~a))", Some(json!([ { "allowDoubleBitwiseNot": true }]))),
+        ("a|1", Some(json!([ { "int32Hint": true }]))),
+        ("let a: B | C = b | c;", None),
     ];
 
     Tester::new(NoBitwise::NAME, NoBitwise::PLUGIN, pass, fail).test_and_snapshot();
@@ -1,10 +1,10 @@
 use oxc_ast::{
     AstKind,
-    ast::{Statement, VariableDeclarationKind},
+    ast::{BindingIdentifier, Statement, VariableDeclarationKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -67,49 +67,80 @@ declare_oxc_lint!(
     /// ```
     NoCaseDeclarations,
     eslint,
-    pedantic
+    pedantic,
+    conditional_fix
 );
 
 impl Rule for NoCaseDeclarations {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        if let AstKind::SwitchCase(switch_case) = node.kind() {
-            let consequent = &switch_case.consequent;
+        let AstKind::SwitchCase(switch_case) = node.kind() else {
+            return;
+        };
+        let consequent = &switch_case.consequent;
 
-            for stmt in consequent {
-                match stmt {
-                    Statement::FunctionDeclaration(d) => {
-                        let start = d.span.start;
-                        let end = start + 8;
-                        ctx.diagnostic(no_case_declarations_diagnostic(Span::new(start, end)));
-                    }
-                    Statement::ClassDeclaration(d) => {
-                        let start = d.span.start;
-                        let end = start + 5;
-                        ctx.diagnostic(no_case_declarations_diagnostic(Span::new(start, end)));
-                    }
-                    Statement::VariableDeclaration(var) if var.kind.is_lexical() => {
-                        let start = var.span.start;
-                        let len = match var.kind {
-                            VariableDeclarationKind::Const | VariableDeclarationKind::Using => 5,
-                            VariableDeclarationKind::Let => 3,
-                            #[expect(clippy::cast_possible_truncation)]
-                            VariableDeclarationKind::AwaitUsing => {
-                                ctx.source_range(Span::new(start, var.declarations[0].span.start))
-                                    .trim_end()
-                                    .len() as u32
-                            }
-                            VariableDeclarationKind::Var => unreachable!(),
-                        };
-                        let end = start + len;
-                        ctx.diagnostic(no_case_declarations_diagnostic(Span::new(start, end)));
-                    }
-                    _ => {}
+        let mut is_first = true;
+        for stmt in consequent {
+            let span = match stmt {
+                Statement::FunctionDeclaration(d) => Span::sized(d.span.start, 8),
+                Statement::ClassDeclaration(d) => Span::sized(d.span.start, 5),
+                Statement::VariableDeclaration(var) if var.kind.is_lexical() => {
+                    let start = var.span.start;
+                    let len = match var.kind {
+                        VariableDeclarationKind::Const | VariableDeclarationKind::Using => 5,
+                        VariableDeclarationKind::Let => 3,
+                        #[expect(clippy::cast_possible_truncation)]
+                        VariableDeclarationKind::AwaitUsing => {
+                            ctx.source_range(Span::new(start, var.declarations[0].span.start))
+                                .trim_end()
+                                .len() as u32
+                        }
+                        VariableDeclarationKind::Var => unreachable!(),
+                    };
+                    Span::sized(start, len)
                 }
+                _ => continue,
+            };
+
+            // Only the first declaration carries the fix, which wraps the whole case body.
+            if !is_first {
+                ctx.diagnostic(no_case_declarations_diagnostic(span));
+                continue;
             }
+            is_first = false;
+
+            ctx.diagnostic_with_fix(no_case_declarations_diagnostic(span), |fixer| {
+                let (Some(first), Some(last)) = (consequent.first(), consequent.last()) else {
+                    return fixer.noop();
+                };
+                let body_span = Span::new(first.span().start, last.span().end);
+                // Wrapping the body in a block would break references from other cases.
+                if consequent.iter().any(|stmt| is_referenced_outside_of(stmt, body_span, ctx)) {
+                    return fixer.noop();
+                }
+                fixer.replace(body_span, format!("{{ {} }}", fixer.source_range(body_span)))
+            });
         }
     }
 }
 
+/// Whether any binding declared by `stmt` is referenced outside of `span`.
+fn is_referenced_outside_of(stmt: &Statement, span: Span, ctx: &LintContext) -> bool {
+    let is_outside = |ident: &BindingIdentifier| {
+        ctx.symbol_references(ident.symbol_id()).any(|reference| {
+            !span.contains_inclusive(ctx.nodes().get_node(reference.node_id()).span())
+        })
+    };
+    match stmt {
+        Statement::FunctionDeclaration(d) => d.id.as_ref().is_some_and(is_outside),
+        Statement::ClassDeclaration(d) => d.id.as_ref().is_some_and(is_outside),
+        Statement::VariableDeclaration(var) if var.kind.is_lexical() => var
+            .declarations
+            .iter()
+            .any(|decl| decl.id.get_binding_identifiers().iter().any(|ident| is_outside(ident))),
+        _ => false,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -122,6 +153,9 @@ fn test() {
             None,
         ),
         ("switch (a) { case 1: { class C {} break; } default: { class C {} break; } }", None),
+        ("switch (a) { case 1: var x = 1; break; default: x = 2; }", None),
+        ("switch (a) { case 1: case 2: { let x = 1; break; } }", None),
+        ("switch (a) { case 1: { let x = 1; } foo(); break; }", None),
     ];
 
     let fail = vec![
@@ -135,8 +169,36 @@ fn test() {
         ("switch (a) { default: class C {} break; }", None),
         ("switch (a) { default: using x = {}; break; }", None),
         ("switch (a) { default: await using x = {}; break; }", None),
+        ("switch (a) { case 1: case 2: let x = 1; break; }", None),
+        ("switch (a) { case 1: let x = 1; const y = 2; break; }", None),
+        ("switch (a) { case 1: let x = 1; break; case 2: x = 2; break; }", None),
+    ];
+
+    let fix = vec![
+        (
+            "switch (a) { case 1: let x = 1; break; }",
+            "switch (a) { case 1: { let x = 1; break; } }",
+        ),
+        (
+            "switch (a) { case 1: case 2: const x = 1; foo(x); break; default: bar(); }",
+            "switch (a) { case 1: case 2: { const x = 1; foo(x); break; } default: bar(); }",
+        ),
+        (
+            "switch (a) { case 1: let x = 1; const y = x; break; }",
+            "switch (a) { case 1: { let x = 1; const y = x; break; } }",
+        ),
+        (
+            "switch (a) { default: function f() {} f(); }",
+            "switch (a) { default: { function f() {} f(); } }",
+        ),
+        // `x` is used in another case, so it must stay visible to the whole switch.
+        (
+            "switch (a) { case 1: let x = 1; break; case 2: x = 2; break; }",
+            "switch (a) { case 1: let x = 1; break; case 2: x = 2; break; }",
+        ),
     ];
 
     Tester::new(NoCaseDeclarations::NAME, NoCaseDeclarations::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
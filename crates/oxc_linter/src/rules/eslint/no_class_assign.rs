@@ -6,11 +6,23 @@ use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule};
 
-fn no_class_assign_diagnostic(name: &str, decl_span: Span, assign_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("Unexpected re-assignment of class {name}")).with_labels([
-        decl_span.label(format!("{name} is declared as class here")),
-        assign_span.label(format!("{name} is re-assigned here")),
-    ])
+fn no_class_assign_diagnostic(
+    name: &str,
+    is_declaration: bool,
+    decl_span: Span,
+    assign_span: Span,
+) -> OxcDiagnostic {
+    let help = if is_declaration {
+        format!("Declare `{name}` with `let {name} = class {{}}` if it needs to be re-assigned.")
+    } else {
+        format!("The name `{name}` of a class expression is read-only.")
+    };
+    OxcDiagnostic::warn(format!("Unexpected re-assignment of class {name}"))
+        .with_help(help)
+        .with_labels([
+            decl_span.label(format!("{name} is declared as class here")),
+            assign_span.label(format!("{name} is re-assigned here")),
+        ])
 }
 
 #[derive(Debug, Default, Clone)]
@@ -59,6 +71,12 @@ declare_oxc_lint!(
     /// }
     /// ```
     ///
+    /// ```javascript
+    /// class A { }
+    /// A++;
+    /// for (A of list) { }
+    /// ```
+    ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
     /// let A = class A { }
@@ -80,6 +98,14 @@ declare_oxc_lint!(
     ///   }
     /// }
     /// ```
+    ///
+    /// ```javascript
+    /// class A { }
+    /// function b() {
+    ///   let A;
+    ///   A = 0; // `class A` is shadowed by the variable.
+    /// }
+    /// ```
     NoClassAssign,
     eslint,
     correctness
@@ -104,6 +130,7 @@ impl Rule for NoClassAssign {
             if reference.is_write() {
                 ctx.diagnostic(no_class_assign_diagnostic(
                     symbol_table.symbol_name(symbol_id),
+                    class.is_declaration(),
                     symbol_table.symbol_span(symbol_id),
                     ctx.semantic().reference_span(reference),
                 ));
@@ -136,6 +163,16 @@ fn test() {
         // Class expressions
         ("let A = class { }; A = 1;", None),
         ("let A = class B { }; A = 1;", None),
+        ("let A = class B { }; A++;", None),
+        // Shadowed in nested scopes
+        ("class A { } function foo() { let A; A = 1; }", None),
+        ("class A { } function foo(A) { A++; }", None),
+        ("class A { } { let A = 0; A += 1; }", None),
+        ("class A { } for (const A of list) { }", None),
+        ("class A { } const foo = (A) => { A = 1; };", None),
+        // Reads and member writes
+        ("class A { } A.b = 1; A[0] = 1; A.b++;", None),
+        ("class A { } foo(A.b, new A());", None),
     ];
 
     let fail = vec![
@@ -147,6 +184,21 @@ fn test() {
         ("let A = class A { b() { A = 0; } }", None),
         ("class A { } A = 0; A = 1;", None),
         ("if (foo) { class A {} A = 1; }", None),
+        // Update expressions and compound assignments
+        ("class A { } A++;", None),
+        ("class A { } --A;", None),
+        ("class A { } A += 1;", None),
+        ("class A { } A ??= 1;", None),
+        // Other assignment targets
+        ("class A { } [A] = [];", None),
+        ("class A { } for (A of list) { }", None),
+        ("class A { } for (A in obj) { }", None),
+        // Nested scopes
+        ("class A { } function foo() { A = 0; }", None),
+        ("class A { } const foo = () => { A++; };", None),
+        ("class A { } { class A { } A = 0; }", None),
+        ("export default class A { } A = 0;", None),
+        ("let B = class A { static { A = 0; } }", None),
     ];
 
     Tester::new(NoClassAssign::NAME, NoClassAssign::PLUGIN, pass, fail).test_and_snapshot();
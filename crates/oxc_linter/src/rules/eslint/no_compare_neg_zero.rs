@@ -1,4 +1,7 @@
-use oxc_ast::{AstKind, ast::Expression};
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, UnaryExpression},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -41,6 +44,12 @@ declare_oxc_lint!(
     /// }
     /// ```
     ///
+    /// ```javascript
+    /// if (x !== -0) {
+    ///     // doSomething()...
+    /// }
+    /// ```
+    ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
     /// if (x === 0) {
@@ -70,43 +79,42 @@ impl Rule for NoCompareNegZero {
         let AstKind::BinaryExpression(expr) = node.kind() else {
             return;
         };
-        if Self::should_check(expr.operator) {
-            let op = expr.operator.as_str();
-            let is_left_neg_zero = is_neg_zero(&expr.left);
-            let is_right_neg_zero = is_neg_zero(&expr.right);
-            if is_left_neg_zero || is_right_neg_zero {
-                if expr.operator == BinaryOperator::StrictEquality {
-                    ctx.diagnostic_with_suggestion(
-                        no_compare_neg_zero_diagnostic(op, expr.span),
-                        |fixer| {
-                            // replace `x === -0` with `Object.is(x, -0)`
-                            let value = if is_left_neg_zero {
-                                ctx.source_range(expr.right.span())
-                            } else {
-                                ctx.source_range(expr.left.span())
-                            };
-                            fixer.replace(expr.span, format!("Object.is({value}, -0)"))
-                        },
-                    );
-                } else {
-                    // <https://tc39.es/ecma262/#%E2%84%9D>
-                    // <https://tc39.es/ecma262/#sec-numeric-types-number-lessThan>
-                    // The mathematical value of +0𝔽 and -0𝔽 is the mathematical value 0.
-                    // It's safe to replace -0 with 0
-                    ctx.diagnostic_with_fix(
-                        no_compare_neg_zero_diagnostic(op, expr.span),
-                        |fixer| {
-                            let start = if is_left_neg_zero {
-                                expr.left.span().start
-                            } else {
-                                expr.right.span().start
-                            };
-                            let end = start + 1;
-                            let span = Span::new(start, end);
-                            fixer.delete(&span)
-                        },
-                    );
-                }
+        if !Self::should_check(expr.operator) {
+            return;
+        }
+        let (neg_zero, other) = match (as_neg_zero(&expr.left), as_neg_zero(&expr.right)) {
+            (Some(neg_zero), _) => (neg_zero, &expr.right),
+            (None, Some(neg_zero)) => (neg_zero, &expr.left),
+            (None, None) => return,
+        };
+        let op = expr.operator.as_str();
+        match expr.operator {
+            BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
+                ctx.diagnostic_with_suggestion(
+                    no_compare_neg_zero_diagnostic(op, expr.span),
+                    |fixer| {
+                        // replace `x === -0` with `Object.is(x, -0)`,
+                        // and `x !== -0` with `!Object.is(x, -0)`
+                        let not = if expr.operator == BinaryOperator::StrictInequality {
+                            "!"
+                        } else {
+                            ""
+                        };
+                        let value = ctx.source_range(other.span());
+                        fixer.replace(expr.span, format!("{not}Object.is({value}, -0)"))
+                    },
+                );
+            }
+            _ => {
+                // <https://tc39.es/ecma262/#%E2%84%9D>
+                // <https://tc39.es/ecma262/#sec-numeric-types-number-lessThan>
+                // The mathematical value of +0𝔽 and -0𝔽 is the mathematical value 0.
+                // It's safe to replace -0 with 0
+                ctx.diagnostic_with_fix(no_compare_neg_zero_diagnostic(op, expr.span), |fixer| {
+                    // Delete the `-`, and any whitespace or comments before the `0`.
+                    let span = Span::new(neg_zero.span.start, neg_zero.argument.span().start);
+                    fixer.delete(&span)
+                });
             }
         }
     }
@@ -118,18 +126,20 @@ impl NoCompareNegZero {
     }
 }
 
-fn is_neg_zero(expr: &Expression) -> bool {
+/// `-0`, `-0n` or `(-0)`
+fn as_neg_zero<'a, 'b>(expr: &'b Expression<'a>) -> Option<&'b UnaryExpression<'a>> {
     let Expression::UnaryExpression(unary) = expr.get_inner_expression() else {
-        return false;
+        return None;
     };
     if unary.operator != UnaryOperator::UnaryNegation {
-        return false;
+        return None;
     }
-    match &unary.argument {
+    let is_zero = match unary.argument.get_inner_expression() {
         Expression::NumericLiteral(number) => number.value == 0.0,
         Expression::BigIntLiteral(bigint) => bigint.is_zero(),
         _ => false,
-    }
+    };
+    is_zero.then_some(unary)
 }
 
 #[test]
@@ -164,6 +174,12 @@ fn test() {
         ("x !== 0", None),
         ("0 !== x", None),
         ("Object.is(x, -0)", None),
+        ("!Object.is(x, -0)", None),
+        ("x + -0", None),
+        ("-0 * x", None),
+        ("x === -0 + 1", None),
+        ("x === 0 - 0", None),
+        ("x in -0", None),
     ];
 
     let fail = vec![
@@ -181,6 +197,12 @@ fn test() {
         ("-0 <= x", None),
         // BigInt Literal
         ("-0n <= x", None),
+        ("x !== -0", None),
+        ("-0 != x", None),
+        ("x === (-0)", None),
+        ("x === -(0)", None),
+        ("x < - 0", None),
+        ("x === -0.0", None),
     ];
 
     let fix = vec![
@@ -197,6 +219,14 @@ fn test() {
         ("x <= -0", "x <= 0", None),
         ("-0 <= x", "0 <= x", None),
         ("-0n <= x", "0n <= x", None),
+        ("x !== -0", "!Object.is(x, -0)", None),
+        ("-0 !== x", "!Object.is(x, -0)", None),
+        ("x != -0", "x != 0", None),
+        ("x < (-0)", "x < (0)", None),
+        ("x < -(0)", "x < (0)", None),
+        ("x < - 0", "x < 0", None),
+        ("x < -/* zero */0", "x < 0", None),
+        ("a.b === -0", "Object.is(a.b, -0)", None),
     ];
 
     Tester::new(NoCompareNegZero::NAME, NoCompareNegZero::PLUGIN, pass, fail)
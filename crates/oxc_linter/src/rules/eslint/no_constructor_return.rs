@@ -10,7 +10,9 @@ use oxc_span::Span;
 use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_constructor_return_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Unexpected return statement in constructor.").with_label(span)
+    OxcDiagnostic::warn("Unexpected return statement in constructor.")
+        .with_help("Remove the returned value, use `return;` to exit the constructor early.")
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -26,6 +28,10 @@ declare_oxc_lint!(
     /// In JavaScript, returning a value in the constructor of a class may be a mistake.
     /// Forbidding this pattern prevents mistakes resulting from unfamiliarity with the language or a copy-paste error.
     ///
+    /// A returned primitive is silently ignored, while a returned object replaces the
+    /// instance created by `new`, so `this` and the class prototype are lost.
+    /// A bare `return;` to exit the constructor early is allowed.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -33,6 +39,10 @@ declare_oxc_lint!(
     /// class C {
     ///   constructor() { return 42; }
     /// }
+    ///
+    /// class D extends C {
+    ///   constructor() { super(); return {}; }
+    /// }
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -40,6 +50,14 @@ declare_oxc_lint!(
     /// class C {
     ///   constructor() { this.value = 42; }
     /// }
+    ///
+    /// class D extends C {
+    ///   constructor(value) {
+    ///     super();
+    ///     if (!value) return;
+    ///     this.value = value;
+    ///   }
+    /// }
     /// ```
     NoConstructorReturn,
     eslint,
@@ -102,11 +120,25 @@ fn test() {
         "class C { constructor() { this.fn = () => { return true } } }",
         "class C { constructor() { return } }",
         "class C { constructor() { { return } } }",
+        "class C extends B { constructor() { super(); return } }",
+        "class C extends B { constructor() { super(); this.fn = function () { return this } } }",
+        "class C { constructor() { class D { method() { return 1 } } } }",
+        "class C { constructor() { return } method() { return 1 } }",
+        "class C { fn = () => { return 1 } }",
+        "({ constructor() { return 1 } })",
+        "function C() { return {} }",
     ];
 
     let fail = vec![
         "class C { constructor() { return '' } }",
         "class C { constructor(a) { if (!a) { return '' } else { a() } } }",
+        "class C { constructor() { return undefined } }",
+        "class C { constructor() { return this } }",
+        "class C extends B { constructor() { super(); return {} } }",
+        "class C extends B { constructor() { return super() } }",
+        "const C = class { constructor() { for (;;) { try { return 1 } finally {} } } }",
+        "class C { constructor() { class D { constructor() { return 1 } } } }",
+        "class C { constructor() { this.fn = () => {}; return 1 } }",
     ];
 
     Tester::new(NoConstructorReturn::NAME, NoConstructorReturn::PLUGIN, pass, fail)
@@ -2,8 +2,14 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
 
 fn no_continue_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected use of `continue` statement.")
@@ -11,8 +17,29 @@ fn no_continue_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct NoContinue;
+fn no_labeled_continue_diagnostic(span: Span, label: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of labeled `continue` statement.")
+        .with_help(format!(
+            "Do not use the `continue` statement. Wrap the rest of the iteration of the loop labeled `{label}` in a condition instead."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoContinue {
+    /// If set to `true`, this rule ignores `continue` statements with a label, which continue
+    /// an outer loop, and are harder to replace with a condition.
+    /// Examples of **correct** code with this option set to `true`:
+    /// ```js
+    /// outer: for (const row of rows) {
+    ///     for (const cell of row) {
+    ///         if (!cell) continue outer;
+    ///     }
+    /// }
+    /// ```
+    allow_labeled: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -50,13 +77,31 @@ declare_oxc_lint!(
     /// ```
     NoContinue,
     eslint,
-    style
+    style,
+    config = NoContinue,
 );
 
 impl Rule for NoContinue {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoContinue>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        if let AstKind::ContinueStatement(continue_statement) = node.kind() {
-            ctx.diagnostic(no_continue_diagnostic(Span::sized(continue_statement.span.start, 8)));
+        let AstKind::ContinueStatement(continue_statement) = node.kind() else {
+            return;
+        };
+        let start = continue_statement.span.start;
+        match &continue_statement.label {
+            None => ctx.diagnostic(no_continue_diagnostic(Span::sized(start, 8))),
+            Some(label) if !self.allow_labeled => {
+                ctx.diagnostic(no_labeled_continue_diagnostic(
+                    Span::new(start, label.span.end),
+                    &label.name,
+                ));
+            }
+            Some(_) => {}
         }
     }
 }
@@ -66,15 +111,55 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "var sum = 0, i; for(i = 0; i < 10; i++){ if(i > 5) { sum += i; } }",
-        "var sum = 0, i = 0; while(i < 10) { if(i > 5) { sum += i; } i++; }",
+        ("var sum = 0, i; for(i = 0; i < 10; i++){ if(i > 5) { sum += i; } }", None),
+        ("var sum = 0, i = 0; while(i < 10) { if(i > 5) { sum += i; } i++; }", None),
+        ("for (const x of xs) { if (x) break; }", None),
+        (
+            "var sum = 0, i; myLabel: for(i = 0; i < 10; i++){ if(i <= 5) { continue myLabel; } sum += i; }",
+            Some(serde_json::json!([{ "allowLabeled": true }])),
+        ),
+        (
+            "outer: for (const row of rows) { for (const cell of row) { if (!cell) continue outer; } }",
+            Some(serde_json::json!([{ "allowLabeled": true }])),
+        ),
+        (
+            "loop: while (a) { switch (b) { case 1: continue loop; } }",
+            Some(serde_json::json!([{ "allowLabeled": true }])),
+        ),
     ];
 
     let fail = vec![
-        "var sum = 0, i; for(i = 0; i < 10; i++){ if(i <= 5) { continue; } sum += i; }",
-        "var sum = 0, i; myLabel: for(i = 0; i < 10; i++){ if(i <= 5) { continue myLabel; } sum += i; }",
-        "var sum = 0, i = 0; while(i < 10) { if(i <= 5) { i++; continue; } sum += i; i++; }",
-        "var sum = 0, i = 0; myLabel: while(i < 10) { if(i <= 5) { i++; continue myLabel; } sum += i; i++; }",
+        ("var sum = 0, i; for(i = 0; i < 10; i++){ if(i <= 5) { continue; } sum += i; }", None),
+        (
+            "var sum = 0, i; myLabel: for(i = 0; i < 10; i++){ if(i <= 5) { continue myLabel; } sum += i; }",
+            None,
+        ),
+        (
+            "var sum = 0, i = 0; while(i < 10) { if(i <= 5) { i++; continue; } sum += i; i++; }",
+            None,
+        ),
+        (
+            "var sum = 0, i = 0; myLabel: while(i < 10) { if(i <= 5) { i++; continue myLabel; } sum += i; i++; }",
+            None,
+        ),
+        // Continues the outer loop.
+        (
+            "outer: for (const row of rows) { for (const cell of row) { if (!cell) continue outer; } }",
+            None,
+        ),
+        // `continue` in a `switch` continues the enclosing loop.
+        ("while (a) { switch (b) { case 1: continue; default: break; } }", None),
+        ("loop: while (a) { switch (b) { case 1: continue loop; } }", None),
+        ("do { continue } while (a)", None),
+        // Only labeled `continue` statements are allowed.
+        (
+            "outer: for (const row of rows) { for (const cell of row) { if (!cell) continue; } }",
+            Some(serde_json::json!([{ "allowLabeled": true }])),
+        ),
+        (
+            "while (a) { switch (b) { case 1: continue; } }",
+            Some(serde_json::json!([{ "allowLabeled": true }])),
+        ),
     ];
 
     Tester::new(NoContinue::NAME, NoContinue::PLUGIN, pass, fail).test_and_snapshot();
@@ -9,8 +9,15 @@ use oxc_regular_expression::{
     visit::{Visit, walk},
 };
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::run_on_regex_node,
+};
 
 fn no_control_regex_diagnostic(control_chars: &[Character], span: Span) -> OxcDiagnostic {
     let count = control_chars.len();
@@ -81,8 +88,23 @@ fn no_control_regex_diagnostic(control_chars: &[Character], span: Span) -> OxcDi
     .with_help(help)
     .with_label(span)
 }
-#[derive(Debug, Default, Clone)]
-pub struct NoControlRegex;
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoControlRegex {
+    /// If set to `true`, control characters written as escape sequences (`\x00`, `\u001f`,
+    /// `\u{1f}`, `\0`) are treated as intentional, and only literal control characters in the
+    /// source are reported. Set it to `false` to also report escape sequences, like ESLint.
+    ///
+    /// Default: `true`
+    allow_escapes: bool,
+}
+
+impl Default for NoControlRegex {
+    fn default() -> Self {
+        Self { allow_escapes: true }
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -97,48 +119,72 @@ declare_oxc_lint!(
     /// regular expression containing elements that explicitly match these
     /// characters is most likely a mistake.
     ///
+    /// Control characters written as escape sequences, such as `\x00`, are usually intentional,
+    /// so only literal control characters are reported by default. Unlike in ESLint, set the
+    /// `allowEscapes` option to `false` to report escape sequences as well.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
-    /// var pattern1 = /\x00/;
-    /// var pattern2 = /\x0C/;
-    /// var pattern3 = /\x1F/;
-    /// var pattern4 = /\u000C/;
-    /// var pattern5 = /\u{C}/u;
-    /// var pattern6 = new RegExp("\x0C"); // raw U+000C character in the pattern
-    /// var pattern7 = new RegExp("\\x0C"); // \x0C pattern
+    /// var pattern1 = /<U+001F>/; // a raw U+001F character in the source
+    /// var pattern2 = new RegExp("<U+000C>"); // a raw U+000C character in the source
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
+    /// var pattern1 = /\x00/;
+    /// var pattern2 = /\u000C/;
+    /// var pattern3 = /\u{C}/u;
+    /// var pattern4 = new RegExp("\x0C");
+    /// var pattern5 = new RegExp("\\x0C"); // \x0C pattern
+    /// var pattern6 = /\t/;
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "allowEscapes": false }`:
+    /// ```javascript
+    /// var pattern1 = /\x00/;
+    /// var pattern2 = /\x1F/;
+    /// var pattern3 = /\u000C/;
+    /// var pattern4 = /\u{C}/u;
+    /// var pattern5 = new RegExp("\\x0C"); // \x0C pattern
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "allowEscapes": false }`:
+    /// ```javascript
     /// var pattern1 = /\x20/;
     /// var pattern2 = /\u0020/;
     /// var pattern3 = /\u{20}/u;
     /// var pattern4 = /\t/;
     /// var pattern5 = /\n/;
-    /// var pattern6 = new RegExp("\x20");
-    /// var pattern7 = new RegExp("\\t");
-    /// var pattern8 = new RegExp("\\n");
+    /// var pattern6 = new RegExp("\\t");
     /// ```
     NoControlRegex,
     eslint,
-    correctness
+    correctness,
+    config = NoControlRegex,
 );
 
 impl Rule for NoControlRegex {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoControlRegex>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         run_on_regex_node(node, ctx, |pattern, span| {
-            check_pattern(ctx, pattern, span);
+            check_pattern(ctx, pattern, span, self.allow_escapes);
         });
     }
 }
 
-fn check_pattern(context: &LintContext, pattern: &Pattern, span: Span) {
+fn check_pattern(context: &LintContext, pattern: &Pattern, span: Span, allow_escapes: bool) {
     let mut finder = ControlCharacterFinder {
         control_chars: Vec::new(),
         num_capture_groups: 0,
         source_text: context.source_text(),
+        allow_escapes,
     };
     finder.visit_pattern(pattern);
 
@@ -152,6 +198,7 @@ struct ControlCharacterFinder<'a> {
     control_chars: Vec<Character>,
     num_capture_groups: u32,
     source_text: &'a str,
+    allow_escapes: bool,
 }
 
 impl<'a> Visit<'a> for ControlCharacterFinder<'a> {
@@ -182,12 +229,21 @@ impl<'a> Visit<'a> for ControlCharacterFinder<'a> {
         // Control characters are in the range 0x00 to 0x1F
         if ch.value <= 0x1F {
             let text: &str = ch.span.source_text(self.source_text);
+            // A control character written literally in the source, e.g. a raw U+001F byte.
+            let is_literal = text.chars().next().is_some_and(|c| u32::from(c) == ch.value);
+            if self.allow_escapes {
+                if is_literal {
+                    self.control_chars.push(*ch);
+                }
+                return;
+            }
             let is_code_point_match = text
                 .trim_start_matches('\\')
                 .chars()
                 .nth(0)
                 .is_some_and(|c| c.to_digit(16) == Some(ch.value));
-            if is_code_point_match
+            if is_literal
+                || is_code_point_match
                 || text.starts_with("\\x")
                 || text.starts_with("\\\\x")
                 || text.starts_with("\\u")
@@ -209,18 +265,29 @@ mod tests {
     use super::*;
     use crate::tester::Tester;
 
+    /// Report escape sequences too, like ESLint.
+    fn report_escapes(cases: Vec<&str>) -> Vec<(&str, Option<serde_json::Value>)> {
+        let config = serde_json::json!([{ "allowEscapes": false }]);
+        cases.into_iter().map(|case| (case, Some(config.clone()))).collect()
+    }
+
     #[test] //
     fn test_hex_literals() {
         Tester::new(
             NoControlRegex::NAME,
             NoControlRegex::PLUGIN,
-            vec![
+            report_escapes(vec![
                 "x1f",                 // not a control sequence
                 r"new RegExp('\x20')", // control sequence in valid range
                 r"new RegExp('\xff')",
                 r"let r = /\xff/",
-            ],
-            vec![r"new RegExp('\x00')", r"/\x00/", r"new RegExp('\x1f')", r"/\x1f/"],
+            ]),
+            report_escapes(vec![
+                r"new RegExp('\x00')",
+                r"/\x00/",
+                r"new RegExp('\x1f')",
+                r"/\x1f/",
+            ]),
         )
         .test();
     }
@@ -230,13 +297,13 @@ mod tests {
         Tester::new(
             NoControlRegex::NAME,
             NoControlRegex::PLUGIN,
-            vec![
+            report_escapes(vec![
                 r"u00",    // not a control sequence
                 r"\u00ff", // in valid range
                 // multi byte unicode ctl
                 r"var re = /^([a-zªµºß-öø-ÿāăąćĉċčďđēĕėęěĝğġģĥħĩīĭįıĳĵķ-ĸĺļľŀłńņň-ŉŋōŏőœŕŗřśŝşšţťŧũūŭůűųŵŷźżž-ƀƃƅƈƌ-ƍƒƕƙ-ƛƞơƣƥƨƪ-ƫƭưƴƶƹ-ƺƽ-ƿǆǉǌǎǐǒǔǖǘǚǜ-ǝǟǡǣǥǧǩǫǭǯ-ǰǳǵǹǻǽǿȁȃȅȇȉȋȍȏȑȓȕȗșțȝȟȡȣȥȧȩȫȭȯȱȳ-ȹȼȿ-ɀɂɇɉɋɍɏ-ʓʕ-ʯͱͳͷͻ-ͽΐά-ώϐ-ϑϕ-ϗϙϛϝϟϡϣϥϧϩϫϭϯ-ϳϵϸϻ-ϼа-џѡѣѥѧѩѫѭѯѱѳѵѷѹѻѽѿҁҋҍҏґғҕҗҙқҝҟҡңҥҧҩҫҭүұҳҵҷҹһҽҿӂӄӆӈӊӌӎ-ӏӑӓӕӗәӛӝӟӡӣӥӧөӫӭӯӱӳӵӷӹӻӽӿԁԃԅԇԉԋԍԏԑԓԕԗԙԛԝԟԡԣա-ևᴀ-ᴫᵢ-ᵷᵹ-ᶚḁḃḅḇḉḋḍḏḑḓḕḗḙḛḝḟḡḣḥḧḩḫḭḯḱḳḵḷḹḻḽḿṁṃṅṇṉṋṍṏṑṓṕṗṙṛṝṟṡṣṥṧṩṫṭṯṱṳṵṷṹṻṽṿẁẃẅẇẉẋẍẏẑẓẕ-ẝẟạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹỻỽỿ-ἇἐ-ἕἠ-ἧἰ-ἷὀ-ὅὐ-ὗὠ-ὧὰώᾀ-ᾇᾐ-ᾗᾠ-ᾧᾰ-ᾴᾶ-ᾷιῂ-ῄῆ-ῇῐΐῖ-ῗῠ-ῧῲ-ῴῶ-ῷⁱⁿℊℎ-ℏℓℯℴℹℼ-ℽⅆ-ⅉⅎↄⰰ-ⱞⱡⱥ-ⱦⱨⱪⱬⱱⱳ-ⱴⱶ-ⱼⲁⲃⲅⲇⲉⲋⲍⲏⲑⲓⲕⲗⲙⲛⲝⲟⲡⲣⲥⲧⲩⲫⲭⲯⲱⲳⲵⲷⲹⲻⲽⲿⳁⳃⳅⳇⳉⳋⳍⳏⳑⳓⳕⳗⳙⳛⳝⳟⳡⳣ-ⳤⴀ-ⴥꙁꙃꙅꙇꙉꙋꙍꙏꙑꙓꙕꙗꙙꙛꙝꙟꙣꙥꙧꙩꙫꙭꚁꚃꚅꚇꚉꚋꚍꚏꚑꚓꚕꚗꜣꜥꜧꜩꜫꜭꜯ-ꜱꜳꜵꜷꜹꜻꜽꜿꝁꝃꝅꝇꝉꝋꝍꝏꝑꝓꝕꝗꝙꝛꝝꝟꝡꝣꝥꝧꝩꝫꝭꝯꝱ-ꝸꝺꝼꝿꞁꞃꞅꞇꞌﬀ-ﬆﬓ-ﬗａ-ｚ]|\ud801[\udc28-\udc4f]|\ud835[\udc1a-\udc33\udc4e-\udc54\udc56-\udc67\udc82-\udc9b\udcb6-\udcb9\udcbb\udcbd-\udcc3\udcc5-\udccf\udcea-\udd03\udd1e-\udd37\udd52-\udd6b\udd86-\udd9f\uddba-\uddd3\uddee-\ude07\ude22-\ude3b\ude56-\ude6f\ude8a-\udea5\udec2-\udeda\udedc-\udee1\udefc-\udf14\udf16-\udf1b\udf36-\udf4e\udf50-\udf55\udf70-\udf88\udf8a-\udf8f\udfaa-\udfc2\udfc4-\udfc9\udfcb])$/;",
-            ],
-            vec![
+            ]),
+            report_escapes(vec![
                 // regex literal
                 r"let r = /\u0000/",
                 r"let r = /\u000c/",
@@ -252,7 +319,7 @@ mod tests {
                 r"let r = new RegExp('\\u000c');",
                 r"let r = new RegExp('\\u000C');",
                 r"let r = new RegExp('\\u001f');",
-            ],
+            ]),
         )
         .test();
     }
@@ -262,37 +329,89 @@ mod tests {
         Tester::new(
             NoControlRegex::NAME,
             NoControlRegex::PLUGIN,
-            vec![
+            report_escapes(vec![
                 r"let r = /\u{0}/", // no unicode flag, this is valid
                 r"let r = /\u{ff}/u",
                 r"let r = /\u{00ff}/u",
                 r"let r = new RegExp('\\u{1F}', flags);", // flags are unknown
-            ],
-            vec![
+            ]),
+            report_escapes(vec![
                 r"let r = /\u{0}/u",
                 r"let r = new RegExp('\\u{0}', 'u');",
                 r"let r = /\u{c}/u",
                 r"let r = /\u{1F}/u",
                 r"let r = new RegExp('\\u{1F}', 'u');", // flags are known & contain u
+            ]),
+        )
+        .test();
+    }
+
+    #[test]
+    fn test_literal_control_characters() {
+        Tester::new(
+            NoControlRegex::NAME,
+            NoControlRegex::PLUGIN,
+            vec![
+                "var regex = /\u{7f}/;", // DEL is not a control character in this rule
+                r"var regex = /\t\n\r/;",
+                r"var regex = new RegExp('\t\n\r');",
+                "var regex = new RegExp(pattern);",
+            ],
+            vec![
+                "var regex = /\u{1f}/;",
+                "var regex = /a\u{0}b/;",
+                "var regex = /[\u{1}-\u{8}]/;",
+                "var regex = new RegExp('\u{1f}');",
+                "var regex = new RegExp(`\u{1b}`);",
             ],
         )
         .test();
     }
 
+    #[test]
+    fn test_allow_escapes() {
+        let config = || Some(serde_json::json!([{ "allowEscapes": true }]));
+        let pass = vec![
+            // Escapes are allowed by default.
+            (r"var regex = /\x00/;", None),
+            (r"var regex = /\u001f/;", None),
+            (r"var regex = /\u{1f}/u;", None),
+            (r"var regex = /\0/;", None),
+            (r"var regex = /[a-z]\1/;", None),
+            (r"var regex = new RegExp('\x1f');", None),
+            (r"var regex = new RegExp('\\x1f');", None),
+            (r"var regex = new RegExp('\\u{1F}', 'u');", None),
+            (r"var regex = /\x00/;", config()),
+            (r"var regex = /\t/;", config()),
+        ];
+        let fail = vec![
+            ("var regex = /\u{1f}/;", None),
+            ("var regex = new RegExp('a\u{0}b');", None),
+            ("var regex = /\u{1f}/;", config()),
+            ("var regex = new RegExp('a\u{0}b');", config()),
+            (r"var regex = /\x00/;", Some(serde_json::json!([{ "allowEscapes": false }]))),
+            (
+                r"var regex = new RegExp('\\x00');",
+                Some(serde_json::json!([{ "allowEscapes": false }])),
+            ),
+        ];
+        Tester::new(NoControlRegex::NAME, NoControlRegex::PLUGIN, pass, fail).test();
+    }
+
     #[test]
     fn test_capture_group_indexing() {
         // https://github.com/oxc-project/oxc/issues/6525
-        let pass = vec![
+        let pass = report_escapes(vec![
             r#"const filename = /filename[^;=\n]=((['"]).?\2|[^;\n]*)/;"#,
             r"const r = /([a-z])\1/;",
             r"const r = /\1([a-z])/;",
-        ];
-        let fail = vec![
+        ]);
+        let fail = report_escapes(vec![
             r"const r = /\0/;",
             r"const r = /[a-z]\1/;",
             r"const r = /([a-z])\2/;",
             r"const r = /([a-z])\0/;",
-        ];
+        ]);
         Tester::new(NoControlRegex::NAME, NoControlRegex::PLUGIN, pass, fail)
             .with_snapshot_suffix("capture-group-indexing")
             .test_and_snapshot();
@@ -305,7 +424,7 @@ mod tests {
         Tester::new(
             NoControlRegex::NAME,
             NoControlRegex::PLUGIN,
-            vec![
+            report_escapes(vec![
                 "var regex = /x1f/;",
                 r"var regex = /\\x1f/",
                 "var regex = new RegExp(\"x1f\");",
@@ -333,8 +452,8 @@ mod tests {
                 r"/^expected `string`\.\n {2}in Foo \(at (.*)[/\\]debug[/\\]test[/\\]browser[/\\]debug\.test\.js:[0-9]+\)$/",
                 r"/\f/",
                 r"/\v/",
-            ],
-            vec![
+            ]),
+            report_escapes(vec![
                 r"var regex = /\x1f/",
                 r"var regex = /\\\x1f\\x1e/",
                 r"var regex = /\\\x1fFOO\\x00/",
@@ -371,7 +490,7 @@ mod tests {
                 "new RegExp(('\\x1f'))",
                 "new RegExp((('\\x1f')))",
                 "new RegExp('\\x1f' as string)",
-            ],
+            ]),
         )
         .test_and_snapshot();
     }
@@ -7,7 +7,9 @@ use oxc_syntax::operator::UnaryOperator;
 use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_delete_var_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("variables should not be deleted").with_label(span)
+    OxcDiagnostic::warn("variables should not be deleted")
+        .with_help("Only properties of objects can be deleted, e.g. `delete obj.prop`.")
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -22,7 +24,12 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     ///
     /// Using the `delete` operator on a variable might lead to unexpected
-    /// behavior.
+    /// behavior. Variables can't be deleted, so `delete x` does nothing and
+    /// evaluates to `false` in sloppy mode, and is a syntax error in strict
+    /// mode code, such as modules and classes.
+    ///
+    /// Deleting a property of an object, including a global object such as
+    /// `window`, is allowed.
     ///
     /// ### Examples
     ///
@@ -38,6 +45,7 @@ declare_oxc_lint!(
     ///
     /// var y;
     /// delete y.prop;
+    /// delete window.x;
     /// ```
     NoDeleteVar,
     eslint,
@@ -49,7 +57,10 @@ impl Rule for NoDeleteVar {
         let AstKind::UnaryExpression(expr) = node.kind() else {
             return;
         };
-        if expr.operator == UnaryOperator::Delete && expr.argument.is_identifier_reference() {
+        // `delete (x)` deletes the variable too
+        if expr.operator == UnaryOperator::Delete
+            && expr.argument.without_parentheses().is_identifier_reference()
+        {
             ctx.diagnostic(no_delete_var_diagnostic(expr.span));
         }
     }
@@ -59,9 +70,29 @@ impl Rule for NoDeleteVar {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec![("delete x.prop;", None)];
+    let pass = vec![
+        ("delete x.prop;", None),
+        ("delete x[prop];", None),
+        ("delete window.x;", None),
+        ("delete globalThis.x;", None),
+        ("delete (x.prop);", None),
+        ("delete x?.prop;", None),
+        ("delete this.x;", None),
+        ("delete x();", None),
+        ("delete 1;", None),
+        ("typeof x;", None),
+        ("void x;", None),
+    ];
 
-    let fail = vec![("delete x", None)];
+    let fail = vec![
+        ("delete x", None),
+        ("var x; delete x;", None),
+        ("delete (x);", None),
+        ("delete ((x));", None),
+        ("function f(a) { return delete a; }", None),
+        ("if (delete x) {}", None),
+        ("delete undefined;", None),
+    ];
 
     Tester::new(NoDeleteVar::NAME, NoDeleteVar::PLUGIN, pass, fail).test_and_snapshot();
 }
@@ -0,0 +1,131 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::IsSimpleParameterList;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_dupe_args_diagnostic(name: &str, first: Span, duplicates: &[Span]) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Duplicate parameter '{name}'"))
+        .with_help("Rename or remove the duplicated parameter")
+        .with_labels(
+            std::iter::once(first.label("Parameter is first defined here"))
+                .chain(duplicates.iter().map(|span| span.label("and duplicated here"))),
+        )
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeArgs;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate parameter names in function definitions.
+    ///
+    /// Duplicate parameters are a syntax error in strict mode code, in arrow functions and
+    /// methods, and in functions with destructured, default or rest parameters, so they are
+    /// already reported by the parser there. This rule reports them in the remaining
+    /// functions of sloppy mode scripts.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a function has more than one parameter with the same name, the last one shadows
+    /// the others, so the arguments passed for the earlier parameters can only be accessed
+    /// through `arguments`. This is almost always a typo.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function foo(a, b, a) {
+    ///     console.log("value of the second a:", a);
+    /// }
+    ///
+    /// var bar = function (a, b, a) {
+    ///     console.log("value of the second a:", a);
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function foo(a, b, c) {
+    ///     console.log(a, b, c);
+    /// }
+    ///
+    /// var bar = function (a, b, c) {
+    ///     console.log(a, b, c);
+    /// };
+    /// ```
+    NoDupeArgs,
+    eslint,
+    correctness
+);
+
+impl Rule for NoDupeArgs {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // Other parameter lists, and arrow functions, can't have duplicates.
+        let AstKind::Function(func) = node.kind() else { return };
+        if !func.params.is_simple_parameter_list() {
+            return;
+        }
+        let bindings: Vec<(Atom<'a>, Span)> = func
+            .params
+            .items
+            .iter()
+            .filter_map(|param| param.pattern.get_binding_identifier())
+            .map(|ident| (ident.name, ident.span))
+            .collect();
+
+        for (i, (name, span)) in bindings.iter().enumerate() {
+            // Report each name once, at its first definition.
+            if bindings[..i].iter().any(|(other, _)| other == name) {
+                continue;
+            }
+            let duplicates: Vec<Span> = bindings[i + 1..]
+                .iter()
+                .filter(|(other, _)| other == name)
+                .map(|(_, span)| *span)
+                .collect();
+            if !duplicates.is_empty() {
+                ctx.diagnostic(no_dupe_args_diagnostic(name, *span, &duplicates));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function a(a, b, c){}",
+        "var a = function(a, b, c){}",
+        "function a({a, b}, {c, d}){}",
+        "function a([ , a]) {}",
+        "function foo([[a, b], [c, d]]) {}",
+        "function f(a, b = a, ...c) {}",
+        "const f = (a, { b: [c] }, ...d) => {};",
+        "function f(a) { function g(a) {} }",
+        "function f(a) { var a; }",
+        "class C { m(a, b) {} }",
+        "const o = { m(a, b) {}, set x(a) {} };",
+    ];
+
+    let fail = vec![
+        "function a(a, b, b) {}",
+        "function a(a, a, a) {}",
+        "function a(a, b, a) {}",
+        "function a(a, b, a, b) {}",
+        "var a = function(a, b, b) {}",
+        "var a = function(a, a, a) {}",
+        "var a = function(a, b, a) {}",
+        "var a = function(a, b, a, b) {}",
+        "function f(a, b) { return function g(b, b) {}; }",
+        "({ m: function (a, a) {} });",
+    ];
+
+    Tester::new(NoDupeArgs::NAME, NoDupeArgs::PLUGIN, pass, fail)
+        .change_rule_path_extension("cjs")
+        .test_and_snapshot();
+}
@@ -29,6 +29,10 @@ declare_oxc_lint!(
     /// Two identical test conditions in the same chain are almost always a mistake in the code. Unless there are side effects in the expressions,
     /// a duplicate will evaluate to the same true or false value as the identical expression earlier in the chain, meaning that its branch can never execute.
     ///
+    /// Conditions are compared structurally, ignoring formatting and comments.
+    /// A condition is also reported if it is covered by the `||` and `&&` operands of earlier conditions.
+    /// Conditions with side effects, e.g. `next()`, are reported too.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -254,6 +258,12 @@ fn test() {
         ("if (a) {} else if (b && (a || c)) {}", None),
         ("if (a) {} else if (b && (c || d && a)) {}", None),
         ("if (a && b && c) {} else if (a && b && (c || d)) {}", None),
+        ("if (a) {} else if (!a) {}", None),
+        ("if (!a) {} else if (a) {}", None),
+        ("if (a === b) {} else if (a !== b) {}", None),
+        ("if (a || b) {} else if (!a) {}", None),
+        ("if (a.b) {} else if (a?.b) {}", None),
+        ("if (a) {} else { foo(); } if (a) {}", None),
     ];
 
     let fail = vec![
@@ -312,6 +322,12 @@ fn test() {
         ("if (a) {} else if (a && a) {}", None),
         ("if (a && a) {} else if (a && a) {}", None),
         ("if (a && a) {} else if (a) {}", None),
+        ("if (!a) {} else if (!a) {}", None),
+        ("if (a++) {} else if (a++) {}", None),
+        ("if (next()) {} else if (next()) {}", None),
+        ("if (a.b.c) {} else if (d) {} else if (a.b.c) {}", None),
+        ("if (!a || b) {} else if (!a) {}", None),
+        ("if (a instanceof B) {} else if (a instanceof B && c) {}", None),
     ];
 
     Tester::new(NoDupeElseIf::NAME, NoDupeElseIf::PLUGIN, pass, fail).test_and_snapshot();
@@ -24,6 +24,10 @@ declare_oxc_lint!(
     /// If a switch statement has duplicate test expressions in case clauses,
     /// it is likely that a programmer copied a case clause but forgot to change the test expression.
     ///
+    /// Test expressions are compared structurally, so `case 0:` and `case 0x0:`, or `case a.b:`
+    /// and `case a['b']:`, are duplicates. Test expressions with side effects, such as `case f():`,
+    /// are also reported when repeated. Every duplicate after the first is reported.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -85,7 +89,7 @@ impl Rule for NoDuplicateCase {
         let mut previous_tests: Vec<&Expression<'_>> = vec![];
         for test in ss.cases.iter().filter_map(|c| c.test.as_ref()) {
             let test = test.without_parentheses();
-            if let Some(prev) = previous_tests.iter().find(|t| t.content_eq(test)) {
+            if let Some(prev) = previous_tests.iter().find(|t| is_same_case(t, test)) {
                 ctx.diagnostic(no_duplicate_case_diagnostic(prev.span(), test.span()));
             } else {
                 previous_tests.push(test);
@@ -94,6 +98,31 @@ impl Rule for NoDuplicateCase {
     }
 }
 
+/// Whether two case tests are structurally equal. Numbers are compared by value (`0` and `0x0`),
+/// as are strings and template literals without expressions (`'a'` and `` `a` ``),
+/// and `a.b` is equal to `a['b']`.
+fn is_same_case(a: &Expression<'_>, b: &Expression<'_>) -> bool {
+    if let (Some(a), Some(b)) = (static_string(a), static_string(b)) {
+        return a == b;
+    }
+    if let (Some(a), Some(b)) = (a.as_member_expression(), b.as_member_expression())
+        && let (Some(a_name), Some(b_name)) = (a.static_property_name(), b.static_property_name())
+    {
+        return a_name == b_name
+            && a.optional() == b.optional()
+            && a.object().without_parentheses().content_eq(b.object().without_parentheses());
+    }
+    a.content_eq(b)
+}
+
+fn static_string<'a>(expr: &Expression<'a>) -> Option<&'a str> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+        Expression::TemplateLiteral(lit) => lit.single_quasi().map(|quasi| quasi.as_str()),
+        _ => None,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -111,6 +140,12 @@ fn test() {
         "var a = [1,2]; switch(a.toString()){case ([1,2]).toString():break; case ([1]).toString():break; default:break;}",
         "switch(a) { case a: break; } switch(a) { case a: break; }",
         "switch(a) { case toString: break; }",
+        "switch (a) { case 'a': break; case a: break; }",
+        "switch (a) { case '1': break; case 1: break; case 1n: break; }",
+        "switch (a) { case `a${b}`: break; case `a${c}`: break; }",
+        "switch (a) { case b[c]: break; case b.c: break; }",
+        "switch (a) { case b.c: break; case b?.c: break; }",
+        "switch (a) { case b.c: break; case d['c']: break; }",
     ];
 
     let fail = vec![
@@ -132,6 +167,11 @@ fn test() {
         "var a = 1, p = {p: {p1: 1, p2: 1}}; switch (a) {case p.p.p1: break; case p. p // comment\n .p1: break; case p .p\n/* comment */\n.p1: break; default: break;}",
         "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(a + 1).p1: break; case f(a+1).p1: break; default: break;}",
         "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(\na + 1 // comment\n).p1: break; case f(a+1)\n.p1: break; default: break;}",
+        "switch (a) { case 0: break; case 0x0: break; case 0.0: break; }",
+        "switch (a) { case 'a': break; case `a`: break; case \"a\": break; }",
+        "switch (a) { case b.c: break; case b['c']: break; case (b)[`c`]: break; }",
+        "switch (a) { case f(): break; case g(): break; case f(): break; }",
+        "switch (a) { case [b][0]: break; case [b][0]: break; }",
     ];
 
     Tester::new(NoDuplicateCase::NAME, NoDuplicateCase::PLUGIN, pass, fail).test_and_snapshot();
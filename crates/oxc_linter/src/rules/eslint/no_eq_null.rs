@@ -1,12 +1,22 @@
 use std::fmt::Debug;
 
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryExpression, Expression},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
 
 fn no_eq_null_diagnostic(span: Span, suggested_operator: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn("Do not use `null` comparisons without type-checking operators.")
@@ -14,8 +24,26 @@ fn no_eq_null_diagnostic(span: Span, suggested_operator: &str) -> OxcDiagnostic
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct NoEqNull;
+fn no_eq_undefined_diagnostic(span: Span, operator: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not use `undefined` comparisons without type-checking operators.")
+        .with_help(format!("Use '{operator} null' to check for both `null` and `undefined`"))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoEqNull(NoEqNullMode);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoEqNullMode {
+    /// Disallow `== null` and `!= null`.
+    /// This is the default mode.
+    #[default]
+    Always,
+    /// Allow `== null` and `!= null` to check for both `null` and `undefined`,
+    /// and disallow `== undefined` and `!= undefined`, which should be written as such a check.
+    AllowNullCheck,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -28,6 +56,35 @@ declare_oxc_lint!(
     /// have unintended results as the comparison will evaluate to `true` when
     /// comparing to not just a `null`, but also an `undefined` value.
     ///
+    /// Some codebases use `x == null` on purpose, as a short check for both `null` and
+    /// `undefined`. The `"allow-null-check"` mode allows this idiom, and reports
+    /// `x == undefined` instead, which does the same check but reads like a check for
+    /// `undefined` alone.
+    ///
+    /// This rule only looks at comparisons with `null` (and `undefined` in the
+    /// `"allow-null-check"` mode). Use `eqeqeq` to require strict equality everywhere.
+    /// When both rules are enabled, configure them the same way: the default mode matches
+    /// `eqeqeq`'s default `{ "null": "always" }`, while `"allow-null-check"` matches
+    /// `eqeqeq`'s `"smart"` or `{ "null": "ignore" }`, so `x == null` isn't reported by
+    /// one rule and required by the other.
+    ///
+    /// ### Options
+    ///
+    /// - Type: `string`
+    /// - Default: `"always"`
+    ///
+    /// Possible values:
+    /// * `"always"` - disallow `== null` and `!= null`
+    /// * `"allow-null-check"` - allow `== null` and `!= null`, and disallow `== undefined`
+    ///   and `!= undefined`
+    ///
+    /// Example JSON configuration:
+    /// ```json
+    /// {
+    ///   "no-eq-null": ["error", "allow-null-check"]
+    /// }
+    /// ```
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -54,13 +111,53 @@ declare_oxc_lint!(
     ///     bar();
     /// }
     /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the `"allow-null-check"` option:
+    /// ```js
+    /// /* eslint no-eq-null: ["error", "allow-null-check"] */
+    /// if (foo == undefined) {
+    ///     bar();
+    /// }
+    /// if (undefined != baz) {
+    ///     bar();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the `"allow-null-check"` option:
+    /// ```js
+    /// /* eslint no-eq-null: ["error", "allow-null-check"] */
+    /// if (foo == null) {
+    ///     bar();
+    /// }
+    /// if (baz !== undefined) {
+    ///     bar();
+    /// }
+    /// ```
     NoEqNull,
     eslint,
     restriction,
-    fix_dangerous
+    fix_dangerous,
+    config = NoEqNullMode,
 );
 
+/// The `undefined` operand of `x == undefined` or `undefined != x`.
+fn undefined_operand<'a, 'b>(
+    binary_expression: &'b BinaryExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'b Expression<'a>> {
+    [&binary_expression.left, &binary_expression.right].into_iter().find(|operand| {
+        matches!(operand, Expression::Identifier(ident)
+            if ident.name == "undefined" && ctx.is_reference_to_global_variable(ident))
+    })
+}
+
 impl Rule for NoEqNull {
+    fn from_configuration(value: Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoEqNull>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::BinaryExpression(binary_expression) = node.kind() {
             let bad_operator = matches!(
@@ -68,6 +165,23 @@ impl Rule for NoEqNull {
                 BinaryOperator::Equality | BinaryOperator::Inequality
             );
 
+            if self.0 == NoEqNullMode::AllowNullCheck {
+                if !bad_operator {
+                    return;
+                }
+                if let Some(undefined) = undefined_operand(binary_expression, ctx) {
+                    // `x == undefined` is the same as `x == null`.
+                    ctx.diagnostic_with_fix(
+                        no_eq_undefined_diagnostic(
+                            binary_expression.span,
+                            binary_expression.operator.as_str(),
+                        ),
+                        |fixer| fixer.replace(undefined.span(), "null"),
+                    );
+                }
+                return;
+            }
+
             if binary_expression.right.is_literal()
                 & binary_expression.right.is_null()
                 & bad_operator
@@ -105,14 +219,40 @@ impl Rule for NoEqNull {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec!["if (x === null) { }", "if (null === f()) { }"];
+    let allow_null_check = Some(serde_json::json!(["allow-null-check"]));
+
+    let pass = vec![
+        ("if (x === null) { }", None),
+        ("if (null === f()) { }", None),
+        ("if (x == undefined) { }", None),
+        ("if (x === null) { }", Some(serde_json::json!(["always"]))),
+        ("if (x == null) { }", allow_null_check.clone()),
+        ("if (x != null) { }", allow_null_check.clone()),
+        ("do {} while (null == x)", allow_null_check.clone()),
+        ("if (x === undefined) { }", allow_null_check.clone()),
+        ("if (undefined !== x) { }", allow_null_check.clone()),
+        ("if (x == void 0) { }", allow_null_check.clone()),
+        ("function f(undefined) { return x == undefined; }", allow_null_check.clone()),
+        ("if (x.undefined == 1) { }", allow_null_check.clone()),
+    ];
 
-    let fail = vec!["if (x == null) { }", "if (x != null) { }", "do {} while (null == x)"];
+    let fail = vec![
+        ("if (x == null) { }", None),
+        ("if (x != null) { }", None),
+        ("do {} while (null == x)", None),
+        ("if (x == null) { }", Some(serde_json::json!(["always"]))),
+        ("if (x == undefined) { }", allow_null_check.clone()),
+        ("if (x != undefined) { }", allow_null_check.clone()),
+        ("if (undefined == f()) { }", allow_null_check.clone()),
+    ];
 
     let fix = vec![
-        ("if (x == null) { }", "if (x === null) { }"),
-        ("if (x != null) { }", "if (x !== null) { }"),
-        ("do {} while (null == x)", "do {} while (null === x)"),
+        ("if (x == null) { }", "if (x === null) { }", None),
+        ("if (x != null) { }", "if (x !== null) { }", None),
+        ("do {} while (null == x)", "do {} while (null === x)", None),
+        ("if (x == undefined) { }", "if (x == null) { }", allow_null_check.clone()),
+        ("if (x != undefined) { }", "if (x != null) { }", allow_null_check.clone()),
+        ("if (undefined == f()) { }", "if (null == f()) { }", allow_null_check),
     ];
 
     Tester::new(NoEqNull::NAME, NoEqNull::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
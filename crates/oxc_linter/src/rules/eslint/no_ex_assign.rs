@@ -35,6 +35,12 @@ declare_oxc_lint!(
     /// } catch (e) {
     ///     e = 10;
     /// }
+    ///
+    /// try {
+    ///     // code
+    /// } catch ({ message }) {
+    ///     message += "!";
+    /// }
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -82,6 +88,13 @@ fn test() {
         ("try { } catch (e) { three = 2 + 1; }", None),
         ("try { } catch ({e}) { this.something = 2; }", None),
         ("function foo() { try { } catch (e) { return false; } }", None),
+        ("try { } catch (e) { let a = e; a = 10; }", None),
+        ("try { } catch (e) { e.message = 'changed'; e[0] = 1; }", None),
+        ("try { } catch (e) { function foo(e) { e = 10; } }", None),
+        ("try { } catch (e) { { let e = 1; e = 2; } }", None),
+        ("try { } catch (e) { [1].forEach((e) => { e++; }); }", None),
+        ("try { } catch ({ message }) { const e = message; }", None),
+        ("try { } catch { e = 10; }", None),
     ];
 
     let fail = vec![
@@ -90,6 +103,19 @@ fn test() {
         ("try { } catch (ex) { [ex] = []; }", None),
         ("try { } catch (ex) { ({x: ex = 0} = {}); }", None),
         ("try { } catch ({message}) { message = 10; }", None),
+        ("try { } catch (e) { e++; }", None),
+        ("try { } catch (e) { --e; }", None),
+        ("try { } catch (e) { e += 'x'; }", None),
+        ("try { } catch (e) { e ??= 10; }", None),
+        ("try { } catch (e) { for (e of []); }", None),
+        ("try { } catch (e) { for (e in {}); }", None),
+        ("try { } catch (e) { ({ e } = {}); }", None),
+        ("try { } catch (e) { [...e] = []; }", None),
+        ("try { } catch (e) { function foo() { e = 10; } }", None),
+        ("try { } catch (e) { () => { e = 10; }; }", None),
+        ("try { } catch ([a, { b: [c] }]) { c = 10; }", None),
+        ("try { } catch ({ a: { b = 1 } }) { b++; }", None),
+        ("try { } catch ({ ...rest }) { rest = {}; }", None),
     ];
 
     Tester::new(NoExAssign::NAME, NoExAssign::PLUGIN, pass, fail).test_and_snapshot();
@@ -4,6 +4,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
 use oxc_span::{CompactStr, GetSpan};
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -65,6 +66,8 @@ declare_oxc_lint!(
     /// ```js
     /// Object.prototype.p = 0
     /// Object.defineProperty(Array.prototype, 'p', {value: 0})
+    /// Reflect.defineProperty(String.prototype, 'p', {value: 0})
+    /// ({ p: Number.prototype.p } = {})
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -144,7 +147,7 @@ fn get_define_property_call<'a>(
 ) -> Option<&'a AstNode<'a>> {
     for parent in ctx.nodes().ancestors(node.id()) {
         if let AstKind::CallExpression(call_expr) = parent.kind()
-            && is_define_property_call(call_expr)
+            && is_define_property_call(ctx, call_expr)
             && let Some(first_arg) = call_expr.arguments.first()
         {
             let arg_span = first_arg.span();
@@ -156,8 +159,9 @@ fn get_define_property_call<'a>(
     None
 }
 
-/// Checks if a given `CallExpression` is a call to `Object.defineProperty` or `Object.defineProperties`.
-fn is_define_property_call(call_expr: &CallExpression) -> bool {
+/// Checks if a given `CallExpression` is a call to `Object.defineProperty`, `Object.defineProperties`
+/// or `Reflect.defineProperty`, referencing the globals.
+fn is_define_property_call(ctx: &LintContext, call_expr: &CallExpression) -> bool {
     let callee = call_expr.callee.without_parentheses();
 
     let member_expression = if let Expression::ChainExpression(chain_expr) = callee {
@@ -167,11 +171,15 @@ fn is_define_property_call(call_expr: &CallExpression) -> bool {
     };
     match member_expression {
         Some(me) => {
-            let prop_name = me.static_property_name();
-            me.object()
-                .get_identifier_reference()
-                .is_some_and(|ident_ref| ident_ref.name == "Object")
-                && (prop_name == Some("defineProperty") || prop_name == Some("defineProperties"))
+            let Some(ident_ref) = me.object().get_identifier_reference() else { return false };
+            if !ident_ref.is_global_reference(ctx.scoping()) {
+                return false;
+            }
+            matches!(
+                (ident_ref.name.as_str(), me.static_property_name()),
+                ("Object", Some("defineProperty" | "defineProperties"))
+                    | ("Reflect", Some("defineProperty"))
+            )
         }
         _ => false,
     }
@@ -197,6 +205,20 @@ fn get_property_assignment<'a>(
             {
                 return Some(parent);
             }
+            // `Array.prototype.p++`, `[...Array.prototype.p] = []`
+            AstKind::UpdateExpression(_) | AstKind::AssignmentTargetRest(_) => return Some(parent),
+            // `[Array.prototype.p = 0] = []`
+            AstKind::AssignmentTargetWithDefault(target)
+                if target.binding.span().contains_inclusive(node.span()) =>
+            {
+                return Some(parent);
+            }
+            // `({ p: Array.prototype.p } = {})`
+            AstKind::AssignmentTargetPropertyProperty(property)
+                if property.binding.span().contains_inclusive(node.span()) =>
+            {
+                return Some(parent);
+            }
             AstKind::ComputedMemberExpression(computed_expr)
                 if computed_expr.object.span().contains_inclusive(node.span()) => {}
             AstKind::StaticMemberExpression(_) | AstKind::PrivateFieldExpression(_) => {}
@@ -279,6 +301,15 @@ fn test() {
         ("Object.x.defineProperty(Array.prototype, 'p', {value: 0})", None),
         ("Object['defineProperty']['x'](Array.prototype, 'p', {value: 0})", None),
         ("(Object?.x?.['prototype'])['p'] = 0", None),
+        ("function f(Object) { Object.defineProperty(Array.prototype, 'p', {value: 0}) }", None),
+        ("Reflect.defineProperties(Array.prototype, {p: {value: 0}})", None),
+        ("({ [Array.prototype.p]: x } = {})", None),
+        ("x = Array.prototype.p + 1", None),
+        ("Array.prototype.p = 0", Some(serde_json::json!([{ "exceptions": ["Array"] }]))),
+        (
+            "Object.defineProperty(String.prototype, 'p', {value: 0})",
+            Some(serde_json::json!([{ "exceptions": ["String"] }])),
+        ),
     ];
 
     let fail = vec![
@@ -308,6 +339,13 @@ fn test() {
         ("Array.prototype.p ||= 0", None), // { "ecmaVersion": 2021 },
         ("Array.prototype.p ??= 0", None), // { "ecmaVersion": 2021 }
         ("[Array.prototype.p] = [() => {}]", None),
+        ("Array.prototype.p++", None),
+        ("--Array.prototype['p']", None),
+        ("[Array.prototype.p = 0] = []", None),
+        ("[...Array.prototype.p] = []", None),
+        ("({ p: Array.prototype.p } = {})", None),
+        ("({ p: Array.prototype.p = 0 } = {})", None),
+        ("Reflect.defineProperty(Array.prototype, 'p', {value: 0})", None),
     ];
 
     Tester::new(NoExtendNative::NAME, NoExtendNative::PLUGIN, pass, fail).test_and_snapshot();
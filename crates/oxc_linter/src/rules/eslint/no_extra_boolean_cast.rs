@@ -95,7 +95,7 @@ impl Rule for NoExtraBooleanCast {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(expr)
-                if expr.callee.is_specific_id("Boolean")
+                if is_global_boolean(&expr.callee, ctx)
                     && is_flagged_ctx(node, ctx, self.enforce_for_inner_expressions) =>
             {
                 ctx.diagnostic_with_fix(no_extra_boolean_cast_diagnostic(expr.span), |fixer| {
@@ -166,7 +166,7 @@ fn is_flagged_ctx(node: &AstNode, ctx: &LintContext, enforce_for_logical_operand
 // Check if a node is in a context where its value would be coerced to a boolean at runtime
 fn is_bool_context(node: &AstNode, parent: Option<&AstNode>, ctx: &LintContext) -> bool {
     parent.is_some_and(|parent| {
-        (is_bool_fn_or_constructor_call(parent) && is_first_arg(node, parent))
+        (is_bool_fn_or_constructor_call(parent, ctx) && is_first_arg(node, parent))
             || is_inside_test_condition(node, ctx)
             || is_unary_negation(parent)
     })
@@ -183,10 +183,20 @@ fn is_logical_ctx(node: &AstNode, enforce_for_logical_operands: bool) -> bool {
     }
 }
 
-fn is_bool_fn_or_constructor_call(node: &AstNode) -> bool {
+fn is_bool_fn_or_constructor_call(node: &AstNode, ctx: &LintContext) -> bool {
     match node.kind() {
         AstKind::CallExpression(CallExpression { callee, .. })
-        | AstKind::NewExpression(NewExpression { callee, .. }) => callee.is_specific_id("Boolean"),
+        | AstKind::NewExpression(NewExpression { callee, .. }) => is_global_boolean(callee, ctx),
+        _ => false,
+    }
+}
+
+// `Boolean` which is not shadowed by a local binding
+fn is_global_boolean(callee: &Expression, ctx: &LintContext) -> bool {
+    match callee.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            ident.name == "Boolean" && ctx.is_reference_to_global_variable(ident)
+        }
         _ => false,
     }
 }
@@ -307,10 +317,15 @@ fn test() {
         ("if (!!foo ?? bar) {}", Some(json!([{ "enforceForLogicalOperands": true }]))),
         ("if (x.y()) {}", Some(json!([{ "enforceForLogicalOperands": true }]))),
         ("if (x.y()) {}", Some(json!([{ "enforceForLogicalOperands": false }]))),
+        ("if (Boolean(foo) === true) {}", None),
+        ("foo(!!bar)", None),
+        ("function foo(Boolean) { if (Boolean(bar)) {} }", None),
+        ("const Boolean = (x) => x; if (Boolean(!!foo)) {}", None),
     ];
 
     let fail = vec![
         ("if (!!foo) {}", None),
+        ("foo(Boolean(!!bar))", None),
         ("do {} while (!!foo)", None),
         ("while (!!foo) {}", None),
         ("!!foo ? bar : baz", None),
@@ -6,8 +6,14 @@ use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule};
 
-fn no_func_assign_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+fn no_func_assign_diagnostic(name: &str, is_declaration: bool, span: Span) -> OxcDiagnostic {
+    let help = if is_declaration {
+        format!("Declare `{name}` with `let` if it needs to be re-assigned.")
+    } else {
+        format!("The name `{name}` of a function expression is read-only.")
+    };
     OxcDiagnostic::warn(format!("'{name}' is a function."))
+        .with_help(help)
         .with_label(span.label(format!("{name} is re-assigned here")))
 }
 
@@ -27,6 +33,9 @@ declare_oxc_lint!(
     /// Overwriting/reassigning a function written as a FunctionDeclaration is often indicative of
     /// a mistake or issue.
     ///
+    /// Function declarations are hoisted, so a call before the reassignment still calls the
+    /// original function, while a call after it doesn't, even inside the function itself.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -82,6 +91,7 @@ impl Rule for NoFuncAssign {
             if reference.is_write() {
                 ctx.diagnostic(no_func_assign_diagnostic(
                     func_name,
+                    func.is_declaration(),
                     ctx.semantic().reference_span(reference),
                 ));
             }
@@ -101,6 +111,12 @@ fn test() {
         ("var foo = function() {}; foo = bar;", None),
         ("var foo = function() { foo = bar; };", None),
         ("import bar from 'bar'; function foo() { var foo = bar; }", None),
+        ("function foo() {} { let foo; foo = bar; }", None),
+        ("function foo() {} function bar() { let foo = 1; foo++; }", None),
+        ("function foo() {} var foo = bar;", None),
+        ("class foo {} foo = bar;", None),
+        ("function foo() {} foo(); foo.bar = 1; foo[0] = 1;", None),
+        ("function foo() {} for (const foo of bar) {}", None),
     ];
 
     let fail = vec![
@@ -113,6 +129,15 @@ fn test() {
         ("(function() { ({x: foo = 0} = bar); function foo() { }; })();", None),
         ("var a = function foo() { foo = 123; };", None),
         ("let a = function hello() { hello = 123;};", None),
+        ("function foo() {} foo++;", None),
+        ("function foo() {} foo += 1;", None),
+        ("function foo() {} foo ||= bar;", None),
+        ("function foo() {} for (foo in bar) {}", None),
+        ("function foo() {} for ([foo] of bar) {}", None),
+        ("function foo() {} ({ foo } = bar);", None),
+        ("function foo() { if (done) { foo = function () {}; } }", None),
+        ("function foo() {} function bar() { foo = 1; }", None),
+        ("function foo() {} foo = 1; foo = 2;", None),
     ];
 
     Tester::new(NoFuncAssign::NAME, NoFuncAssign::PLUGIN, pass, fail).test_and_snapshot();
@@ -19,8 +19,7 @@ pub struct NoInnerDeclarations {
     /// Determines what type of declarations to check.
     config: NoInnerDeclarationsConfig,
     /// Controls whether function declarations in nested blocks are allowed in strict mode (ES6+ behavior).
-    #[schemars(with = "BlockScopedFunctions")]
-    block_scoped_functions: Option<BlockScopedFunctions>,
+    block_scoped_functions: BlockScopedFunctions,
 }
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -54,6 +53,13 @@ declare_oxc_lint!(
     /// This is often undesirable due to variable hoisting, and moving declarations to the root of the program or function body can increase clarity.
     /// Note that block bindings (let, const) are not hoisted and therefore they are not affected by this rule.
     ///
+    /// In strict mode code, such as modules, classes and functions with a `"use strict"` directive,
+    /// function declarations in nested blocks are block scoped like `let`, so they are allowed by default.
+    /// In sloppy mode code, their semantics are implementation-defined and confusing: the function is
+    /// also hoisted to the enclosing function, but only assigned when the block is evaluated.
+    /// This also applies to function declarations which are the body of an `if` statement,
+    /// e.g. `if (test) function doSomething() {}`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -86,19 +92,14 @@ impl Rule for NoInnerDeclarations {
             },
         );
 
-        let block_scoped_functions = if value.is_array() && !value.is_null() {
-            value
-                .get(1)
-                .and_then(|v| v.get("blockScopedFunctions"))
-                .and_then(serde_json::Value::as_str)
-                .map(|value| match value {
-                    "disallow" => BlockScopedFunctions::Disallow,
-                    _ => BlockScopedFunctions::Allow,
-                })
-                .or(Some(BlockScopedFunctions::Allow))
-        } else {
-            None
-        };
+        let block_scoped_functions = value
+            .get(1)
+            .and_then(|v| v.get("blockScopedFunctions"))
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(BlockScopedFunctions::default, |value| match value {
+                "disallow" => BlockScopedFunctions::Disallow,
+                _ => BlockScopedFunctions::Allow,
+            });
 
         Self { config, block_scoped_functions }
     }
@@ -117,17 +118,14 @@ impl Rule for NoInnerDeclarations {
                     return;
                 }
 
-                if self.config == NoInnerDeclarationsConfig::Functions
-                    && let Some(block_scoped_functions) = self.block_scoped_functions
-                    && block_scoped_functions == BlockScopedFunctions::Allow
+                // Function declarations are block scoped in strict mode code.
+                // `node.scope_id()` is the scope which contains the function, so a `"use strict"`
+                // directive in the function's own body doesn't make it block scoped.
+                if self.block_scoped_functions == BlockScopedFunctions::Allow
+                    && (ctx.source_type().is_module()
+                        || ctx.scoping().scope_flags(node.scope_id()).is_strict_mode())
                 {
-                    let is_module = ctx.source_type().is_module();
-                    let scope_id = node.scope_id();
-                    let is_strict = ctx.scoping().scope_flags(scope_id).is_strict_mode();
-
-                    if is_module || is_strict {
-                        return;
-                    }
+                    return;
                 }
 
                 check_rule(node, ctx);
@@ -184,147 +182,265 @@ fn check_rule<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
 
 #[test]
 fn test() {
+    use std::path::PathBuf;
+
     use crate::tester::Tester;
 
+    // Sloppy mode script, in which function declarations in blocks are not block scoped.
+    let script = || Some(PathBuf::from("foo.cjs"));
+
     let pass = vec![
-        ("function doSomething() { }", None),
-        ("function doSomething() { function somethingElse() { } }", None),
-        ("(function() { function doSomething() { } }());", None),
-        ("if (test) { var fn = function() { }; }", None),
-        ("if (test) { var fn = function expr() { }; }", None),
-        ("function decl() { var fn = function expr() { }; }", None),
-        ("function decl(arg) { var fn; if (arg) { fn = function() { }; } }", None),
-        ("var x = {doSomething() {function doSomethingElse() {}}}", None), // { "ecmaVersion": 6 },
-        ("function decl(arg) { var fn; if (arg) { fn = function expr() { }; } }", None), // { "ecmaVersion": 6 },
-        ("function decl(arg) { var fn; if (arg) { fn = function expr() { }; } }", None),
-        ("if (test) { var foo; }", None),
-        ("if (test) { let x = 1; }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 6 },
-        ("if (test) { const x = 1; }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 6 },
-        ("if (test) { using x = 1; }", Some(serde_json::json!(["both"]))), // {				"ecmaVersion": 2026,				"sourceType": "module",			},
-        ("if (test) { await using x = 1; }", Some(serde_json::json!(["both"]))), // {				"ecmaVersion": 2026,				"sourceType": "module",			},
-        ("function doSomething() { while (test) { var foo; } }", None),
-        ("var foo;", Some(serde_json::json!(["both"]))),
-        ("var foo = 42;", Some(serde_json::json!(["both"]))),
-        ("function doSomething() { var foo; }", Some(serde_json::json!(["both"]))),
-        ("(function() { var foo; }());", Some(serde_json::json!(["both"]))),
-        ("foo(() => { function bar() { } });", None), // { "ecmaVersion": 6 },
-        ("var fn = () => {var foo;}", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 6 },
-        ("var x = {doSomething() {var foo;}}", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 6 },
-        ("export var foo;", Some(serde_json::json!(["both"]))), // { "sourceType": "module", "ecmaVersion": 6 },
-        ("export function bar() {}", Some(serde_json::json!(["both"]))), // { "sourceType": "module", "ecmaVersion": 6 },
-        ("export default function baz() {}", Some(serde_json::json!(["both"]))), // { "sourceType": "module", "ecmaVersion": 6 },
-        ("exports.foo = () => {}", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 6 },
-        ("exports.foo = function(){}", Some(serde_json::json!(["both"]))),
-        ("module.exports = function foo(){}", Some(serde_json::json!(["both"]))),
-        ("class C { method() { function foo() {} } }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 2022 },
-        ("class C { method() { var x; } }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 2022 },
-        ("class C { static { function foo() {} } }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 2022 },
-        ("class C { static { var x; } }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 2022 },
+        ("function doSomething() { }", None, None, None),
+        ("function doSomething() { function somethingElse() { } }", None, None, None),
+        ("(function() { function doSomething() { } }());", None, None, None),
+        ("if (test) { var fn = function() { }; }", None, None, None),
+        ("if (test) { var fn = function expr() { }; }", None, None, None),
+        ("function decl() { var fn = function expr() { }; }", None, None, None),
+        ("function decl(arg) { var fn; if (arg) { fn = function() { }; } }", None, None, None),
+        ("var x = {doSomething() {function doSomethingElse() {}}}", None, None, None), // { "ecmaVersion": 6 },
+        ("function decl(arg) { var fn; if (arg) { fn = function expr() { }; } }", None, None, None), // { "ecmaVersion": 6 },
+        ("function decl(arg) { var fn; if (arg) { fn = function expr() { }; } }", None, None, None),
+        ("if (test) { var foo; }", None, None, None),
+        ("if (test) { let x = 1; }", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 6 },
+        ("if (test) { const x = 1; }", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 6 },
+        ("if (test) { using x = 1; }", Some(serde_json::json!(["both"])), None, None), // {				"ecmaVersion": 2026,				"sourceType": "module",			},
+        ("if (test) { await using x = 1; }", Some(serde_json::json!(["both"])), None, None), // {				"ecmaVersion": 2026,				"sourceType": "module",			},
+        ("function doSomething() { while (test) { var foo; } }", None, None, None),
+        ("var foo;", Some(serde_json::json!(["both"])), None, None),
+        ("var foo = 42;", Some(serde_json::json!(["both"])), None, None),
+        ("function doSomething() { var foo; }", Some(serde_json::json!(["both"])), None, None),
+        ("(function() { var foo; }());", Some(serde_json::json!(["both"])), None, None),
+        ("foo(() => { function bar() { } });", None, None, None), // { "ecmaVersion": 6 },
+        ("var fn = () => {var foo;}", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 6 },
+        ("var x = {doSomething() {var foo;}}", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 6 },
+        ("export var foo;", Some(serde_json::json!(["both"])), None, None), // { "sourceType": "module", "ecmaVersion": 6 },
+        ("export function bar() {}", Some(serde_json::json!(["both"])), None, None), // { "sourceType": "module", "ecmaVersion": 6 },
+        ("export default function baz() {}", Some(serde_json::json!(["both"])), None, None), // { "sourceType": "module", "ecmaVersion": 6 },
+        ("exports.foo = () => {}", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 6 },
+        ("exports.foo = function(){}", Some(serde_json::json!(["both"])), None, None),
+        ("module.exports = function foo(){}", Some(serde_json::json!(["both"])), None, None),
+        (
+            "class C { method() { function foo() {} } }",
+            Some(serde_json::json!(["both"])),
+            None,
+            None,
+        ), // { "ecmaVersion": 2022 },
+        ("class C { method() { var x; } }", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 2022 },
+        ("class C { static { function foo() {} } }", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 2022 },
+        ("class C { static { var x; } }", Some(serde_json::json!(["both"])), None, None), // { "ecmaVersion": 2022 },
         (
             "'use strict'
 			 if (test) { function doSomething() { } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+            None,
+            None,
         ), // { "ecmaVersion": 2022 },
         (
             "'use strict'
 			 if (test) { function doSomething() { } }",
             Some(serde_json::json!(["functions"])),
+            None,
+            None,
         ), // { "ecmaVersion": 2022 },
         (
             "function foo() {'use strict'
 			 if (test) { function doSomething() { } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+            None,
+            None,
         ), // { "ecmaVersion": 6 },
         (
             "function foo() { { function bar() { } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+            None,
+            None,
         ), // { "ecmaVersion": 2022, "sourceType": "module" },
         (
             "class C { method() { if(test) { function somethingElse() { } } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+            None,
+            None,
         ), // { "ecmaVersion": 2022 },
         (
             "const C = class { method() { if(test) { function somethingElse() { } } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+            None,
+            None,
         ), // { "ecmaVersion": 2022 }
+        // ESLint only reports this for ES5, but code is always parsed as the latest version.
+        (
+            "'use strict'
+			 if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
+            None,
+            script(),
+        ),
+        // Function declarations in blocks are block scoped in modules and strict mode code by default.
+        ("if (test) { function doSomething() { } }", None, None, None),
+        ("if (test) function doSomething() { }", None, None, None),
+        ("if (test) { function doSomething() { } }", Some(serde_json::json!(["both"])), None, None),
+        ("'use strict'; if (test) { function doSomething() { } }", None, None, script()),
+        (
+            "function foo() { 'use strict'; { function bar() { } } }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ),
+        ("class C { m() { { function foo() {} } } }", None, None, script()),
+        ("if (test) { let foo; const bar = 1; class Baz {} }", None, None, script()),
+        ("switch (a) { case 1: var foo; }", None, None, script()),
+        ("for (var i = 0; i < 1; i++) {}", None, None, script()),
     ];
 
     let fail = vec![
-        ("if (test) { function doSomething() { } }", Some(serde_json::json!(["both"]))),
-        ("if (foo) var a; ", Some(serde_json::json!(["both"]))),
-        ("if (foo) /* some comments */ var a; ", Some(serde_json::json!(["both"]))),
-        ("if (foo){ function f(){ if(bar){ var a; } } }", Some(serde_json::json!(["both"]))),
-        ("if (foo) function f(){ if(bar) var a; }", Some(serde_json::json!(["both"]))),
-        ("if (foo) { var fn = function(){} } ", Some(serde_json::json!(["both"]))),
-        ("if (foo)  function f(){} ", None),
-        ("function bar() { if (foo) function f(){}; }", Some(serde_json::json!(["both"]))),
-        ("function bar() { if (foo) var a; }", Some(serde_json::json!(["both"]))),
-        ("if (foo) { var a; }", Some(serde_json::json!(["both"]))),
-        ("function doSomething() { do { function somethingElse() { } } while (test); }", None),
-        ("(function() { if (test) { function doSomething() { } } }());", None),
-        ("while (test) { var foo; }", Some(serde_json::json!(["both"]))),
+        (
+            "if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ),
+        ("if (foo) var a; ", Some(serde_json::json!(["both"])), None, script()),
+        ("if (foo) /* some comments */ var a; ", Some(serde_json::json!(["both"])), None, script()),
+        (
+            "if (foo){ function f(){ if(bar){ var a; } } }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ),
+        (
+            "if (foo) function f(){ if(bar) var a; }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ),
+        ("if (foo) { var fn = function(){} } ", Some(serde_json::json!(["both"])), None, script()),
+        ("if (foo)  function f(){} ", None, None, script()),
+        (
+            "function bar() { if (foo) function f(){}; }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ),
+        ("function bar() { if (foo) var a; }", Some(serde_json::json!(["both"])), None, script()),
+        ("if (foo) { var a; }", Some(serde_json::json!(["both"])), None, script()),
+        (
+            "function doSomething() { do { function somethingElse() { } } while (test); }",
+            None,
+            None,
+            script(),
+        ),
+        ("(function() { if (test) { function doSomething() { } } }());", None, None, script()),
+        ("while (test) { var foo; }", Some(serde_json::json!(["both"])), None, script()),
         (
             "function doSomething() { if (test) { var foo = 42; } }",
             Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ),
+        (
+            "(function() { if (test) { var foo; } }());",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
         ),
-        ("(function() { if (test) { var foo; } }());", Some(serde_json::json!(["both"]))),
         (
             "const doSomething = () => { if (test) { var foo = 42; } }",
             Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ), // { "ecmaVersion": 6 },
+        (
+            "class C { method() { if(test) { var foo; } } }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
         ), // { "ecmaVersion": 6 },
-        ("class C { method() { if(test) { var foo; } } }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 6 },
-        ("class C { static { if (test) { var foo; } } }", Some(serde_json::json!(["both"]))), // { "ecmaVersion": 2022 },
+        (
+            "class C { static { if (test) { var foo; } } }",
+            Some(serde_json::json!(["both"])),
+            None,
+            script(),
+        ), // { "ecmaVersion": 2022 },
         (
             "class C { static { if (test) { function foo() {} } } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 2022 },
         (
             "class C { static { if (test) { if (anotherTest) { var foo; } } } }",
             Some(serde_json::json!(["both"])),
+            None,
+            script(),
         ), // { "ecmaVersion": 2022 },
         (
             "if (test) { function doSomething() { } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 5 },
         (
             "if (test) { function doSomething() { } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 2022 },
         (
             "'use strict'
 			 if (test) { function doSomething() { } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 2022 },
         (
             "'use strict'
 			 if (test) { function doSomething() { } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
-        ), // { "ecmaVersion": 5 },
-        (
-            "'use strict'
-			 if (test) { function doSomething() { } }",
-            Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 5 },
         (
             "function foo() {'use strict'
 			 { function bar() { } } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 2022 },
         (
             "function foo() {'use strict'
 			 { function bar() { } } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 5 },
         (
             "function doSomething() { 'use strict'
 			 do { function somethingElse() { } } while (test); }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+            None,
+            script(),
         ), // { "ecmaVersion": 5 },
         (
             "{ function foo () {'use strict'
 			 console.log('foo called'); } }",
             Some(serde_json::json!(["both"])),
+            None,
+            script(),
         ), // { "ecmaVersion": 2022 }
+        ("if (test) function doSomething() { }", None, None, script()),
+        ("if (test) {} else function doSomething() { }", None, None, script()),
+        ("label: { function doSomething() { } }", None, None, script()),
+        ("switch (a) { case 1: function doSomething() { } }", None, None, script()),
+        ("switch (a) { case 1: var foo; }", Some(serde_json::json!(["both"])), None, script()),
+        ("try { function doSomething() { } } catch {}", None, None, script()),
+        // A `"use strict"` directive in the function itself doesn't make it block scoped.
+        ("{ function foo() { 'use strict'; } }", None, None, script()),
+        (
+            "if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["functions", { "blockScopedFunctions": "disallow" }])),
+            None,
+            None,
+        ),
     ];
 
     Tester::new(NoInnerDeclarations::NAME, NoInnerDeclarations::PLUGIN, pass, fail)
@@ -67,11 +67,10 @@ impl Rule for NoLabelVar {
             ctx.scoping().find_binding(node.scope_id(), &labeled_stmt.label.name)
         {
             let decl_span = ctx.scoping().symbol_span(symbol_id);
-            let label_decl = labeled_stmt.span.start;
             ctx.diagnostic(no_label_var_diagnostic(
                 &labeled_stmt.label.name,
                 decl_span,
-                Span::sized(label_decl, 1),
+                labeled_stmt.label.span,
             ));
         }
     }
@@ -84,12 +83,25 @@ fn test() {
     let pass = vec![
         "function bar() { q: for(;;) { break q; } } function foo () { var q = t; }",
         "function bar() { var x = foo; q: for(;;) { break q; } }",
+        "x: { let x = 1; break x; }",
+        "function foo() { x: for (;;) { break x; } } function bar() { let x; }",
+        "x: for (const y of z) { continue x; } { let x; }",
+        "x: while (true) { break x; } const c = { x: 1 }; c.x;",
+        "function foo() { x: for (;;) { const fn = function x() {}; break x; } }",
     ];
 
     let fail = vec![
         "var x = foo; function bar() { x: for(;;) { break x; } }",
         "function bar() { var x = foo; x: for(;;) { break x; } }",
         "function bar(x) { x: for(;;) { break x; } }",
+        "let x; x: { break x; }",
+        "x: while (true) { continue x; } var x;",
+        "function x() { x: for (;;) {} }",
+        "class x { m() { x: for (;;) { break x; } } }",
+        "import x from 'x'; x: for (;;) { break x; }",
+        "const x = 1; { a: { x: do { break x; } while (false) } }",
+        "try {} catch (x) { x: for (;;) { break x; } }",
+        "for (let x of y) { x: for (;;) { continue x; } }",
     ];
 
     Tester::new(NoLabelVar::NAME, NoLabelVar::PLUGIN, pass, fail).test_and_snapshot();
@@ -62,6 +62,10 @@ declare_oxc_lint!(
     /// The `break outer` statement ensures that this code will not result in an infinite loop because control is returned to the next statement after the `outer` label was applied. If this statement was changed to be just `break`, control would flow back to the outer `while` statement and an infinite loop would result.
     /// While convenient in some cases, labels tend to be used only rarely and are frowned upon by some as a remedial form of flow control that is more error prone and harder to understand.
     ///
+    /// Labels on blocks, `if` statements and other statements are always reported, even with the
+    /// `allowLoop` and `allowSwitch` options, as are `break` and `continue` statements to them.
+    /// A label is reported whether or not it is used.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -204,6 +208,31 @@ fn test() {
             Some(serde_json::json!([{ "allowLoop": true }])),
         ),
         ("A: switch (a) { case 0: break A; }", Some(serde_json::json!([{ "allowSwitch": true }]))),
+        // Breaking out of nested loops
+        (
+            "outer: for (const a of b) { for (const c of d) { if (c) break outer; } }",
+            Some(serde_json::json!([{ "allowLoop": true }])),
+        ),
+        (
+            "A: for (;;) { B: while (a) { if (b) continue A; break B; } }",
+            Some(serde_json::json!([{ "allowLoop": true }])),
+        ),
+        (
+            "A: while (a) { switch (b) { case 0: break A; } }",
+            Some(serde_json::json!([{ "allowLoop": true }])),
+        ),
+        // Unused labels
+        ("A: while (a) {}", Some(serde_json::json!([{ "allowLoop": true }]))),
+        ("A: switch (a) {}", Some(serde_json::json!([{ "allowSwitch": true }]))),
+        (
+            "A: while (a) { B: switch (b) { case 0: break B; default: continue A; } }",
+            Some(serde_json::json!([{ "allowLoop": true, "allowSwitch": true }])),
+        ),
+        // Labels don't cross function boundaries
+        (
+            "A: while (a) { function f() { while (b) { break; } } }",
+            Some(serde_json::json!([{ "allowLoop": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -249,6 +278,15 @@ fn test() {
             "A: for (var a in obj) { for (;;) { switch (a) { case 0: break A; } } }",
             Some(serde_json::json!([{ "allowSwitch": true }])),
         ),
+        // Labels on blocks are not allowed by either option
+        (
+            "A: { if (a) break A; }",
+            Some(serde_json::json!([{ "allowLoop": true, "allowSwitch": true }])),
+        ),
+        ("A: while (a) { B: { break B; } }", Some(serde_json::json!([{ "allowLoop": true }]))),
+        // Unused labels
+        ("A: { foo(); }", None),
+        ("A: B: while (a) {}", Some(serde_json::json!([{ "allowLoop": true }]))),
     ];
 
     Tester::new(NoLabels::NAME, NoLabels::PLUGIN, pass, fail).test_and_snapshot();
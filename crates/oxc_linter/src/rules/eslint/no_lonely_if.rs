@@ -1,9 +1,14 @@
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+};
 use oxc_ast::AstKind;
-use oxc_ast::ast::{IfStatement, Statement};
+use oxc_ast::ast::{BlockStatement, IfStatement, Statement};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 fn no_lonely_if_diagnostic(lonely_if: &IfStatement) -> OxcDiagnostic {
     let span = Span::sized(lonely_if.span.start, 2);
@@ -79,10 +84,17 @@ declare_oxc_lint!(
     ///   doSomething();
     /// }
     /// ```
+    ///
+    /// ### Fixer
+    ///
+    /// The `else` block is replaced with the inner `if` statement, keeping comments
+    /// in the block. It is not fixed if removing the braces would change the meaning
+    /// of the code, e.g. when the inner `if` ends with a statement without a semicolon
+    /// and the next line would continue it, or with a line comment.
     NoLonelyIf,
     eslint,
     pedantic,
-    pending
+    conditional_fix
 );
 
 impl Rule for NoLonelyIf {
@@ -105,7 +117,9 @@ impl Rule for NoLonelyIf {
 
         match only_stmt {
             Statement::IfStatement(lonely_if) => {
-                ctx.diagnostic(no_lonely_if_diagnostic(lonely_if));
+                ctx.diagnostic_with_fix(no_lonely_if_diagnostic(lonely_if), |fixer| {
+                    Self::fix(fixer, alternate_block, lonely_if, ctx)
+                });
             }
             Statement::BlockStatement(inner_block) => {
                 if let [Statement::IfStatement(lonely_if)] = inner_block.body.as_slice() {
@@ -117,6 +131,86 @@ impl Rule for NoLonelyIf {
     }
 }
 
+impl NoLonelyIf {
+    /// `else { if (b) {} }` -> `else if (b) {}`
+    fn fix<'a>(
+        fixer: RuleFixer<'_, 'a>,
+        block: &BlockStatement,
+        lonely_if: &IfStatement,
+        ctx: &LintContext<'a>,
+    ) -> RuleFix {
+        let inner_span = Span::new(block.span.start + 1, block.span.end - 1);
+        // A line comment after the inner `if` would comment out the code after the block.
+        if ctx.comments_range(lonely_if.span.end..inner_span.end).any(|comment| comment.is_line())
+            || Self::has_asi_hazard(fixer, block, lonely_if)
+        {
+            return fixer.noop();
+        }
+
+        let source_text = fixer.source_text();
+        let content = fixer.source_range(inner_span).trim();
+        // `else{ if (b) {} }` -> `else if (b) {}`
+        if source_text[..block.span.start as usize].ends_with(char::is_whitespace) {
+            fixer.replace(block.span, content.to_string())
+        } else {
+            fixer.replace(block.span, format!(" {content}"))
+        }
+    }
+
+    /// Whether the code after the `else` block would continue the last statement of the inner `if`
+    /// once the closing brace is removed, e.g. `else { if (a) b() } (c)`.
+    fn has_asi_hazard(
+        fixer: RuleFixer<'_, '_>,
+        block: &BlockStatement,
+        lonely_if: &IfStatement,
+    ) -> bool {
+        let mut last = lonely_if.alternate.as_ref().unwrap_or(&lonely_if.consequent);
+        loop {
+            last = match last {
+                Statement::IfStatement(if_stmt) => {
+                    if_stmt.alternate.as_ref().unwrap_or(&if_stmt.consequent)
+                }
+                Statement::WhileStatement(stmt) => &stmt.body,
+                Statement::ForStatement(stmt) => &stmt.body,
+                Statement::ForInStatement(stmt) => &stmt.body,
+                Statement::ForOfStatement(stmt) => &stmt.body,
+                Statement::LabeledStatement(stmt) => &stmt.body,
+                Statement::WithStatement(stmt) => &stmt.body,
+                _ => break,
+            };
+        }
+        if matches!(
+            last,
+            Statement::BlockStatement(_)
+                | Statement::FunctionDeclaration(_)
+                | Statement::ClassDeclaration(_)
+                | Statement::TryStatement(_)
+                | Statement::SwitchStatement(_)
+                | Statement::EmptyStatement(_)
+        ) {
+            return false;
+        }
+        let last_text = fixer.source_range(last.span());
+        if last_text.ends_with(';') {
+            return false;
+        }
+
+        let source_text = fixer.source_text();
+        let after = &source_text[block.span.end as usize..];
+        let next = after.trim_start();
+        if next.is_empty() {
+            return false;
+        }
+        let next_start = source_text.len() - next.len();
+        let same_line = !source_text[last.span().end as usize..next_start]
+            .contains(['\n', '\r', '\u{2028}', '\u{2029}']);
+        same_line
+            || next.starts_with(['(', '[', '/', '+', '`', '-'])
+            || last_text.ends_with("++")
+            || last_text.ends_with("--")
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -150,7 +244,7 @@ fn test() {
          } else {
            if (b) {
              bar();
-           } /* this comment will prevent this test case from being autofixed. */
+           } /* this comment is kept by the fix. */
          }",
         // No fix; removing the braces would cause a SyntaxError.
         "if (foo) {
@@ -178,7 +272,6 @@ fn test() {
          `template literal`;",
     ];
 
-    /* Pending
     let fix = vec![
         (
             "if (a) {
@@ -191,95 +284,118 @@ fn test() {
             "if (a) {
                foo();
              } else if (b) {
-               bar();
+                 bar();
+               }",
+        ),
+        (
+            "if (a) {
+               foo();
+             } /* comment */
+             else {
+               if (b) {
+                 bar();
+               }
              }",
-            None,
+            "if (a) {
+               foo();
+             } /* comment */
+             else if (b) {
+                 bar();
+               }",
         ),
         (
-        "if (a) {
-           foo();
-         } /* comment */
- else {
-           if (b) {
-             bar();
-           }
-         }",
-        "if (a) {
-           foo();
-    } /* comment */
- else {
-           if (b) {
-             bar();
-           }
-         }",
-            None,
+            "if (a) {
+               foo();
+             } else {
+               if ( /* this comment is ok */
+             b) {
+                 bar();
+               }
+             }",
+            "if (a) {
+               foo();
+             } else if ( /* this comment is ok */
+             b) {
+                 bar();
+               }",
         ),
+        // Comments in the `else` block are kept.
         (
-        "if (a) {
-           foo();
-         } else {
-    if ( /* this comment is ok */
- b) {
-             bar();
-           }
-         }",
-        "if (a) {
-           foo();
-    } else if ( /* this comment is ok */
- b) {
-           bar();
-         }",
-            None,
+            "if (a) { foo(); } else { /* otherwise */ if (b) { bar(); } }",
+            "if (a) { foo(); } else /* otherwise */ if (b) { bar(); }",
         ),
         (
-        "if (foo) {} else { if (bar) baz(); }",
-        "if (foo) {} else if (bar) baz();",
-            None,
+            "if (a) { foo(); } else {
+               // otherwise
+               if (b) { bar(); }
+             }",
+            "if (a) { foo(); } else // otherwise
+               if (b) { bar(); }",
         ),
         (
-        "if (foo) { } else { if (bar) baz(); } qux();",
-        "if (foo) { } else if (bar) baz(); qux();",
-            None,
+            "if (a) { foo(); } else { if (b) { bar(); } /* done */ }",
+            "if (a) { foo(); } else if (b) { bar(); } /* done */",
         ),
+        ("if (foo) {} else { if (bar) baz(); }", "if (foo) {} else if (bar) baz();"),
         (
-            "if (foo) { } else { if (bar) baz++; } foo;",
-            "if (foo) { } else if (bar) baz++; foo;",
-            None,
+            "if (foo) { } else { if (bar) baz(); } qux();",
+            "if (foo) { } else if (bar) baz(); qux();",
         ),
+        ("if (foo) { } else { if (bar) baz++; } foo;", "if (foo) { } else if (bar) baz++; foo;"),
+        ("if (foo) {} else{ if (bar) baz(); }", "if (foo) {} else if (bar) baz();"),
+        ("if (foo) {} else { if (bar) baz() }", "if (foo) {} else if (bar) baz()"),
+        ("if (foo) {} else { if (bar) baz() }\nqux();", "if (foo) {} else if (bar) baz()\nqux();"),
+        // The inner `if` can have its own `else`.
         (
-        "if (a) {
-           foo();
-         } else {
-           if (b) {
-             bar();
-           } else if (c) {
-             baz();
-           } else {
-             qux();
-           }
-         }",
-        "if (a) {
-           foo();
-         } else if (b) {
-           bar();
-         } else if (c) {
-           baz();
-         } else {
-           qux();
-         }",
-            None,
+            "if (a) {
+               foo();
+             } else {
+               if (b) {
+                 bar();
+               } else if (c) {
+                 baz();
+               } else {
+                 qux();
+               }
+             }",
+            "if (a) {
+               foo();
+             } else if (b) {
+                 bar();
+               } else if (c) {
+                 baz();
+               } else {
+                 qux();
+               }",
+        ),
+        ("if (a) {;} else { if (b) {;} else {;} }", "if (a) {;} else if (b) {;} else {;}"),
+        ("if (a) {;} else { if (b) {;} }", "if (a) {;} else if (b) {;}"),
+        ("if (foo) {} else { if (bar) baz(); }", "if (foo) {} else if (bar) baz();"),
+        ("if (foo) {} else { if (bar) baz(); } qux();", "if (foo) {} else if (bar) baz(); qux();"),
+        // Not fixed.
+        (
+            "if (foo) {} else { if (bar) baz() } qux();",
+            "if (foo) {} else { if (bar) baz() } qux();",
+        ),
+        (
+            "if (foo) {} else { if (bar) baz(); else qux() } quux();",
+            "if (foo) {} else { if (bar) baz(); else qux() } quux();",
+        ),
+        (
+            "if (foo) {} else { if (bar) while (x) baz()\n}\n(1, 2);",
+            "if (foo) {} else { if (bar) while (x) baz()\n}\n(1, 2);",
+        ),
+        (
+            "if (foo) {} else { if (bar) baz++\n}\nfoo;",
+            "if (foo) {} else { if (bar) baz++\n}\nfoo;",
         ),
-        ("if (a) {;} else { if (b) {;} }", "if (a) {;} else if (b) {;}", None),
-        ("if (foo) {} else { if (bar) baz(); }", "if (foo) {} else if (bar) baz();", None),
         (
-            "if (foo) {} else { if (bar) baz(); } qux();",
-            "if (foo) {} else if (bar) baz(); qux();",
-            None,
+            "if (foo) {} else { if (bar) baz(); // done\n} qux();",
+            "if (foo) {} else { if (bar) baz(); // done\n} qux();",
         ),
     ];
-    */
 
     Tester::new(NoLonelyIf::NAME, NoLonelyIf::PLUGIN, pass, fail)
-        //  .expect_fix(fix)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -74,6 +74,11 @@ declare_oxc_lint!(
     /// ```javascript
     /// var x = 0x1FFF_FFFF_FFF_FFF;
     /// ```
+    ///
+    /// BigInt literals can represent any integer, so they are never reported:
+    /// ```javascript
+    /// var x = 9007199254740993n;
+    /// ```
     NoLossOfPrecision,
     eslint,
     correctness
@@ -205,20 +210,36 @@ impl<'a> RawNum<'a> {
 }
 
 impl NoLossOfPrecision {
+    /// Binary, octal and hexadecimal literals are integers, which are stored exactly
+    /// if their significant bits, from the highest to the lowest set bit, fit in the 53 bit
+    /// significand of a double, and the value is below `2 ** 1024`.
     fn not_base_ten_loses_precision(node: &'_ NumericLiteral) -> bool {
-        let raw = node.raw.as_ref().unwrap().as_str().cow_replace('_', "");
-        let raw = raw.cow_to_ascii_uppercase();
-        #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        // AST always store number as f64, need a cast to format in bin/oct/hex
-        let value = node.value as u64;
-        let suffix = if raw.starts_with("0B") {
-            format!("{value:b}")
-        } else if raw.starts_with("0X") {
-            format!("{value:x}")
-        } else {
-            format!("{value:o}")
+        let raw = node.raw.as_ref().unwrap().as_str();
+        let (digits, bits_per_digit) = match raw.as_bytes().get(1) {
+            Some(b'b' | b'B') => (&raw[2..], 1),
+            Some(b'o' | b'O') => (&raw[2..], 3),
+            Some(b'x' | b'X') => (&raw[2..], 4),
+            // Legacy octal, e.g. `0777`
+            _ => (&raw[1..], 3),
         };
-        !raw.ends_with(&suffix.cow_to_ascii_uppercase().as_ref())
+
+        // Number of bits from the highest set bit to the end, and trailing zero bits.
+        let mut bit_len = 0;
+        let mut trailing_zeros = 0;
+        for digit in digits.chars().filter_map(|ch| ch.to_digit(16)) {
+            if bit_len == 0 {
+                bit_len = u32::BITS - digit.leading_zeros();
+            } else {
+                bit_len += bits_per_digit;
+            }
+            if digit == 0 {
+                trailing_zeros += bits_per_digit;
+            } else {
+                trailing_zeros = digit.trailing_zeros();
+            }
+        }
+
+        bit_len > 1024 || bit_len.saturating_sub(trailing_zeros) > 53
     }
 
     fn base_ten_loses_precision(node: &'_ NumericLiteral) -> bool {
@@ -516,6 +537,12 @@ fn test() {
         ("const a = 480.00", None),
         ("const a = -30.00", None),
         ("(1000000000000000128).toFixed(0)", None),
+        ("var x = 9007199254740993n", None),
+        ("var x = 0x20000000000001n", None),
+        ("var x = 0.123456789012345", None),
+        ("var x = 0x10000000000000000", None),
+        ("var x = 0xFFFFFFFFFFFFF800", None),
+        ("var x = 0b1000000000000000000000000000000000000000000000000000000000000000000000", None),
     ];
 
     let fail = vec![
@@ -573,6 +600,9 @@ fn test() {
         ("var x = 0x2_0000000000001", None),
         ("var x = 0X200000_0000000_1", None),
         ("var x = 1e18_446_744_073_709_551_615", None),
+        ("var x = 0.1234567890123456789", None),
+        ("var x = 0xFFFFFFFFFFFFFFFFF", None),
+        ("var x = 0x10000000000000001", None),
     ];
 
     Tester::new(NoLossOfPrecision::NAME, NoLossOfPrecision::PLUGIN, pass, fail).test_and_snapshot();
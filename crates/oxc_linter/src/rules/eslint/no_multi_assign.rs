@@ -79,6 +79,10 @@ declare_oxc_lint!(
     /// }
     ///
     /// a = b = "quux";
+    ///
+    /// a += b = c;
+    ///
+    /// for (var i = j = 0; i < 10; i++) {}
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -118,33 +122,33 @@ impl Rule for NoMultiAssign {
         match node.kind() {
             // e.g. `var a = b = c;`
             AstKind::VariableDeclarator(declarator) => {
-                let Some(Expression::AssignmentExpression(assign_expr)) = &declarator.init else {
-                    return;
-                };
-                ctx.diagnostic(no_multi_assign_diagnostic(assign_expr.span));
+                let Some(init) = &declarator.init else { return };
+                report_assignment(init, ctx);
             }
             // e.g. `class A { a = b = 1; }`
             AstKind::PropertyDefinition(prop_def) => {
-                let Some(Expression::AssignmentExpression(assign_expr)) = &prop_def.value else {
-                    return;
-                };
-                ctx.diagnostic(no_multi_assign_diagnostic(assign_expr.span));
+                let Some(value) = &prop_def.value else { return };
+                report_assignment(value, ctx);
             }
             // e.g. `let a; let b; a = b = 1;`
             AstKind::AssignmentExpression(parent_expr) => {
                 if self.ignore_non_declaration {
                     return;
                 }
-                let Expression::AssignmentExpression(expr) = &parent_expr.right else {
-                    return;
-                };
-                ctx.diagnostic(no_multi_assign_diagnostic(expr.span));
+                report_assignment(&parent_expr.right, ctx);
             }
             _ => {}
         }
     }
 }
 
+/// Report `expr` if it is an assignment, including a parenthesized one (`a = (b = c)`).
+fn report_assignment(expr: &Expression, ctx: &LintContext) {
+    if let Expression::AssignmentExpression(assign_expr) = expr.without_parentheses() {
+        ctx.diagnostic(no_multi_assign_diagnostic(assign_expr.span));
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -183,6 +187,11 @@ fn test() {
         ), // { "ecmaVersion": 6 },
         ("let a, b;a = b = 1", Some(serde_json::json!([{ "ignoreNonDeclaration": true }]))), // { "ecmaVersion": 6 },
         ("class C { [foo = 0] = 0 }", None), // { "ecmaVersion": 2022 }
+        ("a = b; c = d;", None),
+        ("a = (b, c = d);", None),
+        ("a = [b = c];", None),
+        ("function f(a = b = c) {}", None),
+        ("for (i = 0, j = 0;;) {}", None),
     ];
 
     let fail = vec![
@@ -221,6 +230,14 @@ fn test() {
             "class C { field = foo = 0 }",
             Some(serde_json::json!([{ "ignoreNonDeclaration": true }])),
         ), // { "ecmaVersion": 2022 }
+        ("a = (b = c);", None),
+        ("var a = (b = c);", None),
+        ("class C { field = (foo = 0) }", None),
+        ("a += b = 1;", None),
+        ("a = b += 1;", None),
+        ("a ||= b ??= c;", None),
+        ("for (var i = j = 0;;) {}", None),
+        ("for (i = j = 0;;) {}", None),
     ];
 
     Tester::new(NoMultiAssign::NAME, NoMultiAssign::PLUGIN, pass, fail).test_and_snapshot();
@@ -7,7 +7,11 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule};
 
 fn no_new_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Do not use 'new' for side effects.").with_label(span)
+    OxcDiagnostic::warn("Do not use 'new' for side effects.")
+        .with_help(
+            "Assign the created object to a variable, or call a function instead of a constructor.",
+        )
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -23,6 +27,10 @@ declare_oxc_lint!(
     /// Calling new without assigning or comparing it the reference is thrown away and in many
     /// cases the constructor can be replaced with a function.
     ///
+    /// Only `new` expressions which are the entire expression of a statement are reported.
+    /// If the side effects of the constructor are intended, the result can be discarded
+    /// explicitly with `void`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -37,6 +45,8 @@ declare_oxc_lint!(
     /// var a = new Date()
     ///
     /// (() => new Date())
+    ///
+    /// void new Worker("worker.js")
     /// ```
     NoNew,
     eslint,
@@ -76,9 +86,28 @@ fn test() {
         "var a = new Date()",
         "var a; if (a === new Date()) { a = false; }",
         "(() => new Date())",
+        "void new Date()",
+        "a = new Date()",
+        "foo(new Date())",
+        "new Date().getTime()",
+        "a, new Date()",
+        "function f() { return new Date() }",
+        "new Date() as Foo",
+        "throw new Error()",
     ];
 
-    let fail = vec!["new Date()", "(() => { new Date() })", "(new Date())", "((new Date()))"];
+    let fail = vec![
+        "new Date()",
+        "(() => { new Date() })",
+        "(new Date())",
+        "((new Date()))",
+        "new Date",
+        "new a.b.C(1, 2)",
+        "new (foo())()",
+        "new new Foo()()",
+        "function f() { new Date() }",
+        "if (a) new Date()",
+    ];
 
     Tester::new(NoNew::NAME, NoNew::PLUGIN, pass, fail).test_and_snapshot();
 }
@@ -1,3 +1,4 @@
+use cow_utils::CowUtils;
 use oxc_ast::{
     AstKind,
     ast::{Argument, Expression, NewExpression},
@@ -14,8 +15,11 @@ use crate::{
 };
 
 fn no_new_wrappers_diagnostic(builtin_name: &str, new_span: Span) -> OxcDiagnostic {
+    let primitive = builtin_name.cow_to_ascii_lowercase();
     OxcDiagnostic::warn(format!("Do not use `{builtin_name}` as a constructor"))
-        .with_help("Remove the `new` operator.")
+        .with_help(format!(
+            "Use a `{primitive}` primitive or call `{builtin_name}` without `new` to convert the value."
+        ))
         .with_label(new_span)
 }
 
@@ -127,6 +131,9 @@ fn test() {
     let pass = vec![
         "var a = new Object();",
         "var a = String('test'), b = String.fromCharCode(32);",
+        "var a = Number(x), b = Boolean(x);",
+        "var a = new Array(), b = new Date();",
+        "String.raw`foo`; new String.prototype.constructor('foo');",
         "function test(Number) { return new Number; }",
         r#"
             import String from "./string";
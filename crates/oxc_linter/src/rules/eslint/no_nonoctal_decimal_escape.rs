@@ -27,6 +27,11 @@ declare_oxc_lint!(
     ///
     /// ECMAScript specification treats \8 and \9 in string literals as a legacy feature
     ///
+    /// They are the same as `8` and `9`, so the fix removes the backslash. A suggestion escapes
+    /// the backslash instead, for strings which are meant to contain it.
+    /// Template literals and regular expressions are not checked, since `\8` and `\9` are
+    /// syntax errors in template literals and different escapes in regular expressions.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -43,12 +48,16 @@ declare_oxc_lint!(
     NoNonoctalDecimalEscape,
     eslint,
     correctness,
-    suggestion
+    fix_suggestion
 );
 
 impl Rule for NoNonoctalDecimalEscape {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StringLiteral(literal) = node.kind() {
+            // JSX attribute strings don't have escape sequences.
+            if matches!(ctx.nodes().parent_kind(node.id()), AstKind::JSXAttribute(_)) {
+                return;
+            }
             check_string(ctx, literal.span);
         }
     }
@@ -119,7 +128,9 @@ fn check_string(ctx: &LintContext<'_>, literal_span: Span) {
         let decimal_escape_str = decimal_escape.as_str();
         let digit = decimal_escape_str.chars().nth(1).unwrap();
 
-        let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
+        // Removing the backslash keeps the value, escaping it includes the backslash.
+        let fixer = RuleFixer::new(FixKind::SafeFix, ctx);
+        let suggester = RuleFixer::new(FixKind::Suggestion, ctx);
         let mut suggestions: Vec<RuleFix> = Vec::with_capacity(3);
 
         if let Some(prev_match) =
@@ -148,8 +159,9 @@ fn check_string(ctx: &LintContext<'_>, literal_span: Span) {
             let message = format!(
                 "Replace '{decimal_escape_str}' with '{replacement_digit}'. This maintains the current functionality."
             );
-            suggestions
-                .push(fixer.replace(decimal_escape_span, replacement_digit).with_message(message));
+            suggestions.push(
+                suggester.replace(decimal_escape_span, replacement_digit).with_message(message),
+            );
         } else {
             // "\8" -> "8"
             suggestions.push(
@@ -166,8 +178,9 @@ fn check_string(ctx: &LintContext<'_>, literal_span: Span) {
         let message = format!(
             "Replace '{decimal_escape_str}' with '{escaped_replacement}' to include the actual backslash character."
         );
-        suggestions
-            .push(fixer.replace(decimal_escape_span, escaped_replacement).with_message(message));
+        suggestions.push(
+            suggester.replace(decimal_escape_span, escaped_replacement).with_message(message),
+        );
 
         ctx.diagnostic_with_suggestions(
             no_nonoctal_decimal_escape_diagnostic(decimal_escape_str, decimal_escape_span),
@@ -223,6 +236,8 @@ fn test() {
         r"\u99999",
         r"'\\\n8'",
         r"'\\\n\\\\9'",
+        r#"<a b="\8" />"#,
+        r"tag`\8`",
     ];
 
     let fail = vec![
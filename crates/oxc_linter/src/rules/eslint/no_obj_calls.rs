@@ -5,9 +5,14 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNode, ScopeId};
-use oxc_span::Span;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
 
 const GLOBAL_THIS: &str = "globalThis";
 const NON_CALLABLE_GLOBALS: [&str; 5] = ["Atomics", "Intl", "JSON", "Math", "Reflect"];
@@ -18,8 +23,25 @@ fn no_obj_calls_diagnostic(obj_name: &str, span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-pub struct NoObjCalls;
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoObjCalls(Box<NoObjCallsConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoObjCallsConfig {
+    /// Additional global objects which are not callable, e.g. `["Temporal"]`.
+    ///
+    /// `Atomics`, `Intl`, `JSON`, `Math` and `Reflect` are always checked.
+    additional_globals: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoObjCalls {
+    type Target = NoObjCallsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -61,15 +83,17 @@ declare_oxc_lint!(
     /// let first = Atomics.load(sharedArray, 0);
     /// let segmenterFrom = Intl.Segmenter("fr", { granularity: "word" });
     /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "additionalGlobals": ["Temporal"] }`:
+    /// ```javascript
+    /// let now = Temporal();
+    /// ```
     NoObjCalls,
     eslint,
     correctness,
+    config = NoObjCallsConfig,
 );
 
-fn is_global_obj(s: &str) -> bool {
-    NON_CALLABLE_GLOBALS.contains(&s)
-}
-
 fn global_this_member<'a>(expr: &'a MemberExpression<'_>) -> Option<&'a str> {
     if expr.object().is_specific_id(GLOBAL_THIS) { expr.static_property_name() } else { None }
 }
@@ -122,41 +146,61 @@ fn resolve_global_binding<'a, 'b: 'a>(
 }
 
 impl Rule for NoObjCalls {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoObjCalls>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::NewExpression(expr) => check_callee(&expr.callee, expr.span, node, ctx),
-            AstKind::CallExpression(expr) => check_callee(&expr.callee, expr.span, node, ctx),
+            AstKind::NewExpression(expr) => self.check_callee(&expr.callee, expr.span, node, ctx),
+            AstKind::CallExpression(expr) => self.check_callee(&expr.callee, expr.span, node, ctx),
             _ => {}
         }
     }
 }
 
-fn check_callee<'a>(callee: &'a Expression, span: Span, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-    match callee {
-        Expression::Identifier(ident) => {
-            // handle new Math(), Math(), etc
-            if let Some(top_level_reference) = resolve_global_binding(ident, node.scope_id(), ctx)
-                && is_global_obj(top_level_reference)
-            {
-                ctx.diagnostic(no_obj_calls_diagnostic(ident.name.as_str(), span));
+impl NoObjCalls {
+    fn is_global_obj(&self, s: &str) -> bool {
+        NON_CALLABLE_GLOBALS.contains(&s) || self.additional_globals.iter().any(|name| name == s)
+    }
+
+    fn check_callee<'a>(
+        &self,
+        callee: &'a Expression,
+        span: Span,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        match callee {
+            Expression::Identifier(ident) => {
+                // handle new Math(), Math(), etc
+                if let Some(top_level_reference) =
+                    resolve_global_binding(ident, node.scope_id(), ctx)
+                    && self.is_global_obj(top_level_reference)
+                {
+                    ctx.diagnostic(no_obj_calls_diagnostic(ident.name.as_str(), span));
+                }
             }
-        }
 
-        match_member_expression!(Expression) => {
-            // handle new globalThis.Math(), globalThis.Math(), etc
-            if let Some(global_member) = global_this_member(callee.to_member_expression())
-                && is_global_obj(global_member)
-            {
-                ctx.diagnostic(no_obj_calls_diagnostic(global_member, span));
+            match_member_expression!(Expression) => {
+                // handle new globalThis.Math(), globalThis.Math(), etc
+                if let Some(global_member) = global_this_member(callee.to_member_expression())
+                    && self.is_global_obj(global_member)
+                {
+                    ctx.diagnostic(no_obj_calls_diagnostic(global_member, span));
+                }
             }
+            _ => {}
         }
-        _ => {}
     }
 }
 
 #[test]
 fn test() {
     use crate::tester::Tester;
+    use serde_json::json;
     // see: https://github.com/eslint/eslint/blob/v9.9.1/tests/lib/rules/no-obj-calls.js
 
     let pass = vec![
@@ -184,6 +228,14 @@ fn test() {
         getConfig();",
             None,
         ),
+        ("Math.max(1, 2)", None),
+        ("function f(JSON) { return new JSON(); }", None),
+        ("Temporal()", None),
+        ("Temporal.Now.instant()", Some(json!([{ "additionalGlobals": ["Temporal"] }]))),
+        (
+            "function f(Temporal) { Temporal(); }",
+            Some(json!([{ "additionalGlobals": ["Temporal"] }])),
+        ),
     ];
 
     let fail = vec![
@@ -207,6 +259,8 @@ fn test() {
         ("let j = JSON; j();", None),
         ("let a = JSON; let b = a; let c = b; b();", None),
         ("let m = globalThis.Math; new m();", None),
+        ("let now = Temporal();", Some(json!([{ "additionalGlobals": ["Temporal"] }]))),
+        ("new globalThis.Temporal()", Some(json!([{ "additionalGlobals": ["Temporal"] }]))),
     ];
 
     Tester::new(NoObjCalls::NAME, NoObjCalls::PLUGIN, pass, fail).test_and_snapshot();
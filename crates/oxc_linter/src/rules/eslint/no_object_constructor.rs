@@ -1,7 +1,10 @@
-use oxc_ast::{AstKind, ast::Expression};
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -11,6 +14,12 @@ fn no_object_constructor_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn no_object_constructor_nullish_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Disallow calls to the `Object` constructor with a nullish argument")
+        .with_help("Use object literal notation {} instead")
+        .with_label(span)
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NoObjectConstructor;
 
@@ -19,6 +28,9 @@ declare_oxc_lint!(
     ///
     /// Disallow calls to the Object constructor without an argument
     ///
+    /// Calls with a nullish argument (`null`, `undefined` or `void 0`) are reported too,
+    /// as they also create an empty object.
+    ///
     /// ### Why is this bad?
     ///
     /// Use of the Object constructor to construct a new empty object is generally discouraged in favor of object literal notation because of conciseness and because the Object global may be redefined. The exception is when the Object constructor is used to intentionally wrap a specified value which is passed as an argument.
@@ -29,6 +41,8 @@ declare_oxc_lint!(
     /// ```js
     /// Object();
     /// new Object();
+    /// Object(null);
+    /// new Object(undefined);
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -41,7 +55,7 @@ declare_oxc_lint!(
     NoObjectConstructor,
     eslint,
     pedantic,
-    pending
+    conditional_fix
 );
 
 impl Rule for NoObjectConstructor {
@@ -60,16 +74,59 @@ impl Rule for NoObjectConstructor {
             return;
         };
 
-        if ident.name == "Object"
-            && ctx.is_reference_to_global_variable(ident)
-            && arguments.is_empty()
-            && type_parameters.is_none()
+        if ident.name != "Object"
+            || !ctx.is_reference_to_global_variable(ident)
+            || type_parameters.is_some()
         {
+            return;
+        }
+
+        match arguments.as_slice() {
+            [] => {}
+            [arg] if is_nullish(arg, ctx) => {
+                // `Object(null)` creates an empty object too, but the argument may be
+                // intentional, so it is not removed.
+                ctx.diagnostic(no_object_constructor_nullish_diagnostic(span));
+                return;
+            }
+            _ => return,
+        }
+
+        // `Object();` -> `({});`, as `{` at the start of a statement is a block.
+        let needs_parens = ctx
+            .nodes()
+            .ancestors(node.id())
+            .take_while(|ancestor| ancestor.span().start == span.start)
+            .any(|ancestor| matches!(ancestor.kind(), AstKind::ExpressionStatement(_)));
+        if needs_parens && !follows_statement_end(span, ctx) {
+            // `foo()\nObject()` -> `foo()\n({})` would call `foo()` with `{}`.
             ctx.diagnostic(no_object_constructor_diagnostic(span));
+            return;
+        }
+        ctx.diagnostic_with_fix(no_object_constructor_diagnostic(span), |fixer| {
+            fixer.replace(span, if needs_parens { "({})" } else { "{}" })
+        });
+    }
+}
+
+/// `null`, `undefined` or `void 0`.
+fn is_nullish(arg: &Argument, ctx: &LintContext) -> bool {
+    match arg {
+        Argument::NullLiteral(_) => true,
+        Argument::Identifier(ident) => {
+            ident.name == "undefined" && ctx.is_reference_to_global_variable(ident)
         }
+        _ => arg.as_expression().is_some_and(Expression::is_void_0),
     }
 }
 
+/// The source text before `span` ends with a token after which `(` can't continue the
+/// previous statement: the start of the file, `;`, `{`, a label's `:` or an arrow's `=>`.
+fn follows_statement_end(span: Span, ctx: &LintContext) -> bool {
+    let before = ctx.source_range(Span::new(0, span.start)).trim_end();
+    before.is_empty() || before.ends_with([';', '{', ':']) || before.ends_with("=>")
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -81,6 +138,12 @@ fn test() {
         ("const createObject = Object => new Object()", None, None),
         ("var Object; new Object;", None, None),
         ("new Object()", None, Some(serde_json::json!({"globals": {"Object": "off"} }))),
+        ("Object(value ?? null)", None, None),
+        ("Object(null, x)", None, None),
+        ("Object(void x)", None, None),
+        ("var undefined = 1; Object(undefined)", None, None),
+        ("function f(Object) { return Object(null); }", None, None),
+        ("new Object<T>()", None, None),
     ];
 
     let fail = vec![
@@ -88,6 +151,9 @@ fn test() {
         ("Object()", None, None),
         ("const fn = () => Object();", None, None),
         ("Object() instanceof Object;", None, None),
+        ("x = Object(null);", None, None),
+        ("x = Object(undefined);", None, None),
+        ("x = new Object(void 0);", None, None),
         ("const obj = Object?.();", None, None),
         ("(new Object() instanceof Object);", None, None),
         // Semicolon required before `({})` to compensate for ASI
@@ -260,6 +326,24 @@ fn test() {
         ),
     ];
 
+    let fix = vec![
+        ("new Object", "({})"),
+        ("x = Object();", "x = {};"),
+        ("x = new Object();", "x = {};"),
+        ("const obj = Object?.();", "const obj = {};"),
+        ("const fn = () => Object();", "const fn = () => ({});"),
+        ("Object();", "({});"),
+        ("foo();Object();", "foo();({});"),
+        ("{ Object(); }", "{ ({}); }"),
+        ("foo: Object();", "foo: ({});"),
+        ("Object() instanceof Object;", "({}) instanceof Object;"),
+        ("(new Object() instanceof Object);", "({} instanceof Object);"),
+        ("foo()\nObject()", "foo()\nObject()"),
+        ("if (a) Object();", "if (a) Object();"),
+        ("x = Object(null);", "x = Object(null);"),
+    ];
+
     Tester::new(NoObjectConstructor::NAME, NoObjectConstructor::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -0,0 +1,95 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_octal_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Octal literals should not be used.")
+        .with_help("Use the `0o` prefix for octal numbers, or remove the leading zero.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoOctal;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow legacy octal literals, such as `071`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Numbers with a leading zero are octal numbers when all of their digits are below 8,
+    /// and decimal numbers otherwise, so `071` is `57` while `081` is `81`. This is confusing,
+    /// and these literals are deprecated and a syntax error in strict mode code.
+    ///
+    /// Decimal literals with a leading zero, such as `08`, are reported as well.
+    /// `0`, `0.5` and numbers with a `0x`, `0o` or `0b` prefix are allowed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var num = 071;
+    /// var result = 5 + 07;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var num = 0o71;
+    /// var num = 57;
+    /// var result = 5 + 0.7;
+    /// ```
+    NoOctal,
+    eslint,
+    restriction
+);
+
+impl Rule for NoOctal {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::NumericLiteral(literal) = node.kind() {
+            let raw = literal.span.source_text(ctx.source_text()).as_bytes();
+            if raw.len() > 1 && raw[0] == b'0' && raw[1].is_ascii_digit() {
+                ctx.diagnostic(no_octal_diagnostic(literal.span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var a = 'hello world';",
+        "0x1234",
+        "0X5;",
+        "a = 0;",
+        "0.1",
+        "0.5e1",
+        "0o17",
+        "0b101",
+        "0n",
+        "1_000",
+        "a = '071'",
+        "a = `0${1}`",
+    ];
+
+    let fail = vec![
+        "var a = 01234;",
+        "a = 1 + 01234;",
+        "00",
+        "08",
+        "09.1",
+        "09e1",
+        "09.1e1",
+        "018",
+        "019.1",
+        "019e1",
+        "019.1e1",
+    ];
+
+    Tester::new(NoOctal::NAME, NoOctal::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,149 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_octal_escape_diagnostic(sequence: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Don't use octal: '\\{sequence}'. Use '\\u....' instead."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoOctalEscape;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow octal escape sequences in string literals, such as `"\251"`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Octal escape sequences are deprecated, and a syntax error in strict mode code.
+    /// Unicode escape sequences, such as `"\u00A9"`, should be used instead.
+    ///
+    /// `"\0"` is not an octal escape, unless it is followed by a digit, e.g. `"\08"`.
+    /// Template literals are not checked, since octal escapes are a syntax error in them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var foo = "Copyright \251";
+    /// var bar = "\08";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var foo = "Copyright \u00A9";
+    /// var bar = "\0";
+    /// ```
+    NoOctalEscape,
+    eslint,
+    restriction
+);
+
+impl Rule for NoOctalEscape {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::StringLiteral(literal) = node.kind() {
+            // JSX attribute strings don't have escape sequences.
+            if matches!(ctx.nodes().parent_kind(node.id()), AstKind::JSXAttribute(_)) {
+                return;
+            }
+            let raw = literal.span.source_text(ctx.source_text());
+            if let Some((start, end)) = find_octal_escape(raw) {
+                #[expect(clippy::cast_possible_truncation)]
+                let span =
+                    Span::new(literal.span.start + start as u32, literal.span.start + end as u32);
+                ctx.diagnostic(no_octal_escape_diagnostic(&raw[start + 1..end], span));
+            }
+        }
+    }
+}
+
+/// Returns the byte range of the first octal escape sequence in `raw`, including the backslash.
+fn find_octal_escape(raw: &str) -> Option<(usize, usize)> {
+    let bytes = raw.as_bytes();
+    let is_octal = |i: usize| bytes.get(i).is_some_and(|b| matches!(b, b'0'..=b'7'));
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let Some(&first) = bytes.get(i + 1) else { break };
+        // At most three digits, the value of which is at most `\377`.
+        let max_len = match first {
+            b'0'..=b'3' => 3,
+            b'4'..=b'7' => 2,
+            _ => {
+                // Skip the escaped character, which may be a backslash.
+                i += 2;
+                continue;
+            }
+        };
+        let mut end = i + 2;
+        while end - start - 1 < max_len && is_octal(end) {
+            end += 1;
+        }
+        // `\0` is the null character, unless it is followed by a digit.
+        if first == b'0' && end == i + 2 && !bytes.get(end).is_some_and(u8::is_ascii_digit) {
+            i = end;
+            continue;
+        }
+        return Some((start, end));
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"var foo = "\x51";"#,
+        r#"var foo = "foo \\251 bar";"#,
+        r"var foo = /([abc]) \1/g;",
+        r"var foo = '\0';",
+        r"'\\0'",
+        r"'\8'",
+        r"'\9'",
+        r"'\\01'",
+        r"'\\\\01'",
+        r"'\0 \\1'",
+        r"'\u0000'",
+        r"'\x00'",
+        r"var foo = `\0`;",
+        r"'0\\1'",
+        r#"<div title="\01" />"#,
+    ];
+
+    let fail = vec![
+        r#"var foo = "foo \01 bar";"#,
+        r#"var foo = "foo \000 bar";"#,
+        r#"var foo = "foo \377 bar";"#,
+        r#"var foo = "foo \378 bar";"#,
+        r#"var foo = "foo \37a bar";"#,
+        r#"var foo = "foo \381 bar";"#,
+        r#"var foo = "foo \3a1 bar";"#,
+        r#"var foo = "foo \251 bar";"#,
+        r#"var foo = "foo \258 bar";"#,
+        r#"var foo = "foo \25a bar";"#,
+        r#"var foo = "\3s51";"#,
+        r#"var foo = "\77";"#,
+        r#"var foo = "\78";"#,
+        r#"var foo = "\5a";"#,
+        r#"var foo = "\751";"#,
+        r#"var foo = "foo \400 bar";"#,
+        r#"var foo = "foo \\\1 bar";"#,
+        r"var foo = '\08';",
+        r"var foo = '\09';",
+        r"var foo = '\0\1';",
+        r"var foo = 'a\0\1';",
+        r"var foo = '\1\0';",
+    ];
+
+    Tester::new(NoOctalEscape::NAME, NoOctalEscape::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -1,9 +1,18 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{CallExpression, Expression, MemberExpression, ObjectPropertyKind},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
 
 fn no_prototype_builtins_diagnostic(method_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -15,8 +24,21 @@ fn no_prototype_builtins_diagnostic(method_name: &str, span: Span) -> OxcDiagnos
     .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct NoPrototypeBuiltins;
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoPrototypeBuiltins {
+    /// Whether the fix for `hasOwnProperty` calls uses `Object.hasOwn`.
+    ///
+    /// `Object.hasOwn` was introduced in ES2022. Set this to `false` when targeting
+    /// environments without it, to fix to `Object.prototype.hasOwnProperty.call` instead.
+    prefer_object_has_own: bool,
+}
+
+impl Default for NoPrototypeBuiltins {
+    fn default() -> Self {
+        Self { prefer_object_has_own: true }
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -41,14 +63,32 @@ declare_oxc_lint!(
     /// var isPrototypeOfBar = foo.isPrototypeOf(bar);
     /// var barIsEnumerable = foo.propertyIsEnumerable("bar");
     /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var hasBarProperty = Object.hasOwn(foo, "bar");
+    /// var isPrototypeOfBar = Object.prototype.isPrototypeOf.call(foo, bar);
+    /// var barIsEnumerable = {}.propertyIsEnumerable.call(foo, "bar");
+    ///
+    /// // Literals always inherit from `Object.prototype`
+    /// var hasLength = [].hasOwnProperty("length");
+    /// ```
     NoPrototypeBuiltins,
     eslint,
-    pedantic
+    pedantic,
+    conditional_dangerous_fix,
+    config = NoPrototypeBuiltins,
 );
 
 const DISALLOWED_PROPS: &[&str; 3] = &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
 
 impl Rule for NoPrototypeBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoPrototypeBuiltins>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(expr) = node.kind() else {
             return;
@@ -59,9 +99,71 @@ impl Rule for NoPrototypeBuiltins {
         let Some(prop_name) = member_expr.static_property_name() else {
             return;
         };
-        if DISALLOWED_PROPS.contains(&prop_name) {
-            ctx.diagnostic(no_prototype_builtins_diagnostic(prop_name, member_expr.span()));
+        if !DISALLOWED_PROPS.contains(&prop_name)
+            || is_safe_receiver(member_expr.object(), prop_name)
+        {
+            return;
         }
+
+        let diagnostic = no_prototype_builtins_diagnostic(prop_name, member_expr.span());
+        if !Self::can_fix(node, expr, member_expr, ctx) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_dangerous_fix(diagnostic, |fixer| {
+            let receiver = fixer.source_range(member_expr.object().span());
+            let arguments = match (expr.arguments.first(), expr.arguments.last()) {
+                (Some(first), Some(last)) => {
+                    let arguments =
+                        fixer.source_range(Span::new(first.span().start, last.span().end));
+                    format!(", {arguments}")
+                }
+                _ => String::new(),
+            };
+            let callee = if prop_name == "hasOwnProperty" && self.prefer_object_has_own {
+                "Object.hasOwn".to_string()
+            } else {
+                format!("Object.prototype.{prop_name}.call")
+            };
+            fixer.replace(expr.span, format!("{callee}({receiver}{arguments})"))
+        });
+    }
+}
+
+impl NoPrototypeBuiltins {
+    /// The fix moves the receiver into the arguments, which is only possible for a plain call
+    /// (`foo?.hasOwnProperty(bar)` short-circuits, `super` can't be passed around) and when
+    /// `Object` refers to the global.
+    fn can_fix<'a>(
+        node: &AstNode<'a>,
+        call: &CallExpression<'a>,
+        member_expr: &MemberExpression<'a>,
+        ctx: &LintContext<'a>,
+    ) -> bool {
+        !call.optional
+            && matches!(
+                call.callee,
+                Expression::StaticMemberExpression(_) | Expression::ComputedMemberExpression(_)
+            )
+            && !matches!(member_expr.object(), Expression::Super(_))
+            && !matches!(ctx.nodes().parent_kind(node.id()), AstKind::ChainExpression(_))
+            && ctx.scoping().find_binding(node.scope_id(), "Object").is_none()
+            && !ctx.has_comments_between(call.span)
+    }
+}
+
+/// Array literals and object literals without a `__proto__` or a property shadowing the
+/// method always inherit the builtin from `Object.prototype`.
+fn is_safe_receiver(receiver: &Expression, prop_name: &str) -> bool {
+    match receiver.get_inner_expression() {
+        Expression::ArrayExpression(_) => true,
+        Expression::ObjectExpression(object) => object.properties.iter().all(|property| {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                return false;
+            };
+            property.key.static_name().is_some_and(|name| name != "__proto__" && name != prop_name)
+        }),
+        _ => false,
     }
 }
 
@@ -70,50 +172,88 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "Object.prototype.hasOwnProperty.call(foo, 'bar')",
-        "Object.prototype.isPrototypeOf.call(foo, 'bar')",
-        "Object.prototype.propertyIsEnumerable.call(foo, 'bar')",
-        "Object.prototype.hasOwnProperty.apply(foo, ['bar'])",
-        "Object.prototype.isPrototypeOf.apply(foo, ['bar'])",
-        "Object.prototype.propertyIsEnumerable.apply(foo, ['bar'])",
-        "foo.hasOwnProperty",
-        "foo.hasOwnProperty.bar()",
-        "foo(hasOwnProperty)",
-        "hasOwnProperty(foo, 'bar')",
-        "isPrototypeOf(foo, 'bar')",
-        "propertyIsEnumerable(foo, 'bar')",
-        "({}.hasOwnProperty.call(foo, 'bar'))",
-        "({}.isPrototypeOf.call(foo, 'bar'))",
-        "({}.propertyIsEnumerable.call(foo, 'bar'))",
-        "({}.hasOwnProperty.apply(foo, ['bar']))",
-        "({}.isPrototypeOf.apply(foo, ['bar']))",
-        "({}.propertyIsEnumerable.apply(foo, ['bar']))",
-        "foo[hasOwnProperty]('bar')",
-        "foo['HasOwnProperty']('bar')",
-        "foo[`isPrototypeOff`]('bar')",
-        "foo?.['propertyIsEnumerabl']('bar')",
-        "foo[1]('bar')",
-        "foo[null]('bar')",
-        "class C { #hasOwnProperty; foo() { obj.#hasOwnProperty('bar'); } }",
-        "foo['hasOwn' + 'Property']('bar')",
-        "foo[`hasOwnProperty${''}`]('bar')",
+        ("Object.prototype.hasOwnProperty.call(foo, 'bar')", None),
+        ("Object.prototype.isPrototypeOf.call(foo, 'bar')", None),
+        ("Object.prototype.propertyIsEnumerable.call(foo, 'bar')", None),
+        ("Object.prototype.hasOwnProperty.apply(foo, ['bar'])", None),
+        ("Object.prototype.isPrototypeOf.apply(foo, ['bar'])", None),
+        ("Object.prototype.propertyIsEnumerable.apply(foo, ['bar'])", None),
+        ("foo.hasOwnProperty", None),
+        ("foo.hasOwnProperty.bar()", None),
+        ("foo(hasOwnProperty)", None),
+        ("hasOwnProperty(foo, 'bar')", None),
+        ("isPrototypeOf(foo, 'bar')", None),
+        ("propertyIsEnumerable(foo, 'bar')", None),
+        ("({}.hasOwnProperty.call(foo, 'bar'))", None),
+        ("({}.isPrototypeOf.call(foo, 'bar'))", None),
+        ("({}.propertyIsEnumerable.call(foo, 'bar'))", None),
+        ("({}.hasOwnProperty.apply(foo, ['bar']))", None),
+        ("({}.isPrototypeOf.apply(foo, ['bar']))", None),
+        ("({}.propertyIsEnumerable.apply(foo, ['bar']))", None),
+        ("foo[hasOwnProperty]('bar')", None),
+        ("foo['HasOwnProperty']('bar')", None),
+        ("foo[`isPrototypeOff`]('bar')", None),
+        ("foo?.['propertyIsEnumerabl']('bar')", None),
+        ("foo[1]('bar')", None),
+        ("foo[null]('bar')", None),
+        ("class C { #hasOwnProperty; foo() { obj.#hasOwnProperty('bar'); } }", None),
+        ("foo['hasOwn' + 'Property']('bar')", None),
+        ("foo[`hasOwnProperty${''}`]('bar')", None),
+        ("[].hasOwnProperty('length')", None),
+        ("({}).hasOwnProperty('bar')", None),
+        ("({ a: 1, 'b': 2 }).propertyIsEnumerable('a')", None),
     ];
 
     let fail = vec![
-        "foo.hasOwnProperty('bar')",
-        "foo.isPrototypeOf('bar')",
-        "foo.propertyIsEnumerable('bar')",
-        "foo.bar.hasOwnProperty('bar')",
-        "foo.bar.baz.isPrototypeOf('bar')",
-        "foo['hasOwnProperty']('bar')",
-        "foo[`isPrototypeOf`]('bar').baz",
-        "foo.bar[\"propertyIsEnumerable\"]('baz')",
-        "foo?.hasOwnProperty('bar')",
-        "(foo?.hasOwnProperty)('bar')",
-        "foo?.['hasOwnProperty']('bar')",
-        "(foo?.[`hasOwnProperty`])('bar')",
+        ("foo.hasOwnProperty('bar')", None),
+        ("foo.isPrototypeOf('bar')", None),
+        ("foo.propertyIsEnumerable('bar')", None),
+        ("foo.bar.hasOwnProperty('bar')", None),
+        ("foo.bar.baz.isPrototypeOf('bar')", None),
+        ("foo['hasOwnProperty']('bar')", None),
+        ("foo[`isPrototypeOf`]('bar').baz", None),
+        ("foo.bar[\"propertyIsEnumerable\"]('baz')", None),
+        ("foo?.hasOwnProperty('bar')", None),
+        ("(foo?.hasOwnProperty)('bar')", None),
+        ("foo?.['hasOwnProperty']('bar')", None),
+        ("(foo?.[`hasOwnProperty`])('bar')", None),
+        ("({ hasOwnProperty() {} }).hasOwnProperty('bar')", None),
+        ("({ __proto__: null }).hasOwnProperty('bar')", None),
+        ("({ ...foo }).hasOwnProperty('bar')", None),
+        ("class C extends B { foo() { super.hasOwnProperty('bar'); } }", None),
+        ("function f(Object) { foo.hasOwnProperty('bar'); }", None),
+        ("foo.hasOwnProperty(/* key */ 'bar')", None),
+        ("foo.hasOwnProperty('bar')", Some(serde_json::json!([{ "preferObjectHasOwn": false }]))),
+    ];
+
+    let fix = vec![
+        ("foo.hasOwnProperty('bar')", "Object.hasOwn(foo, 'bar')", None),
+        ("foo.bar['hasOwnProperty'](baz)", "Object.hasOwn(foo.bar, baz)", None),
+        ("(a, b).hasOwnProperty(c)", "Object.hasOwn((a, b), c)", None),
+        ("foo.isPrototypeOf(bar)", "Object.prototype.isPrototypeOf.call(foo, bar)", None),
+        (
+            "foo.propertyIsEnumerable('bar', baz)",
+            "Object.prototype.propertyIsEnumerable.call(foo, 'bar', baz)",
+            None,
+        ),
+        ("foo.hasOwnProperty()", "Object.hasOwn(foo)", None),
+        (
+            "foo.hasOwnProperty('bar')",
+            "Object.prototype.hasOwnProperty.call(foo, 'bar')",
+            Some(serde_json::json!([{ "preferObjectHasOwn": false }])),
+        ),
+        // Not fixable
+        ("foo?.hasOwnProperty('bar')", "foo?.hasOwnProperty('bar')", None),
+        ("a?.b.hasOwnProperty('bar')", "a?.b.hasOwnProperty('bar')", None),
+        ("(foo.hasOwnProperty)('bar')", "(foo.hasOwnProperty)('bar')", None),
+        (
+            "function f(Object) { foo.hasOwnProperty('bar'); }",
+            "function f(Object) { foo.hasOwnProperty('bar'); }",
+            None,
+        ),
     ];
 
     Tester::new(NoPrototypeBuiltins::NAME, NoPrototypeBuiltins::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
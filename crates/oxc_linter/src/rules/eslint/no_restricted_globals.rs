@@ -1,9 +1,11 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
+use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
@@ -18,13 +20,48 @@ fn no_restricted_globals(global_name: &str, suffix: &str, span: Span) -> OxcDiag
     OxcDiagnostic::warn(warn_text).with_label(span)
 }
 
-#[derive(Debug, Default, Clone, JsonSchema)]
+/// The global objects checked by `checkGlobalObject`, in addition to `globalObjects`.
+const DEFAULT_GLOBAL_OBJECTS: [&str; 3] = ["globalThis", "self", "window"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedGlobals(Box<NoRestrictedGlobalsConfig>);
+
+#[derive(Debug, Default, Clone)]
+struct NoRestrictedGlobalsConfig {
+    /// Restricted global names, mapped to their custom messages.
+    restricted_globals: FxHashMap<String, String>,
+    check_global_object: bool,
+    global_objects: Vec<String>,
+}
+
+/// The options can be passed as a list of restricted globals,
+/// `["error", "event", { "name": "fdescribe", "message": "Do not commit fdescribe." }]`, or as an object,
+/// `["error", { "globals": ["event"], "checkGlobalObject": true }]`.
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase", default)]
-pub struct NoRestrictedGlobals {
-    /// Objects in the format
-    /// `{ "name": "event", "message": "Use local parameter instead." }`, which define what globals
-    /// are restricted from use.
-    restricted_globals: Box<FxHashMap<String, String>>,
+struct NoRestrictedGlobalsOptions {
+    /// The globals which are restricted from use.
+    globals: Vec<RestrictedGlobal>,
+    /// Also report accesses of the restricted globals as properties of a global object,
+    /// such as `window.event`, `globalThis.event` and `self.event`.
+    check_global_object: bool,
+    /// Names of additional global objects to check when `checkGlobalObject` is enabled,
+    /// such as `global` for Node.js.
+    global_objects: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RestrictedGlobal {
+    /// `"event"`
+    Name(String),
+    /// `{ "name": "event", "message": "Use local parameter instead." }`
+    Object {
+        name: String,
+        /// Custom message appended to the diagnostic.
+        #[serde(default)]
+        message: Option<String>,
+    },
 }
 
 declare_oxc_lint!(
@@ -41,6 +78,10 @@ declare_oxc_lint!(
     /// `event`, but using this variable has been considered as a bad practice for a long time. Restricting
     /// this will make sure this variable isn't used in browser code.
     ///
+    /// Only references to the globals are reported. Local variables, parameters and imports with a
+    /// restricted name are allowed. Accesses through a global object, such as `window.event`, are
+    /// reported only when `checkGlobalObject` is enabled.
+    ///
     /// ### Example
     ///
     /// If we have options:
@@ -56,52 +97,111 @@ declare_oxc_lint!(
     ///    console.log(event);    // Unexpected global variable 'event'. Use local parameter instead.
     /// }
     /// ```
+    ///
+    /// With `checkGlobalObject` enabled:
+    ///
+    /// ```json
+    /// "no-restricted-globals": ["error", { "globals": ["event"], "checkGlobalObject": true }]
+    /// ```
+    ///
+    /// The following patterns are considered problems too:
+    ///
+    /// ```javascript
+    /// function onClick() {
+    ///    console.log(window.event);
+    /// }
+    /// ```
     NoRestrictedGlobals,
     eslint,
     restriction,
-    config = NoRestrictedGlobals,
+    config = NoRestrictedGlobalsOptions,
 );
 
 impl Rule for NoRestrictedGlobals {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let list = match value {
-            Value::Array(arr) => arr.iter().fold(FxHashMap::default(), |mut acc, v| match v {
-                // "no-restricted-globals": ["error", "event"]
-                Value::String(name) => {
-                    acc.insert(name.clone(), String::new());
-                    acc
-                }
-                // "no-restricted-globals": ["error", { "name": "event", "message": "Use local parameter instead." }]
-                Value::Object(obj) => {
-                    let name = obj.get("name").and_then(Value::as_str).unwrap_or_default();
-                    let message = obj.get("message").and_then(Value::as_str).unwrap_or_default();
-                    acc.insert(name.to_string(), message.to_string());
-                    acc
-                }
-                _ => acc,
-            }),
-            _ => FxHashMap::default(),
+        let options = match value {
+            // "no-restricted-globals": ["error", { "globals": ["event"], "checkGlobalObject": true }]
+            Value::Array(arr)
+                if arr.len() == 1
+                    && arr[0].as_object().is_some_and(|o| o.contains_key("globals")) =>
+            {
+                serde_json::from_value::<NoRestrictedGlobalsOptions>(arr[0].clone())
+                    .unwrap_or_default()
+            }
+            // "no-restricted-globals": ["error", "event", { "name": "event", "message": "Use local parameter instead." }]
+            Value::Array(arr) => NoRestrictedGlobalsOptions {
+                globals: arr
+                    .into_iter()
+                    .filter_map(|v| serde_json::from_value::<RestrictedGlobal>(v).ok())
+                    .collect(),
+                ..NoRestrictedGlobalsOptions::default()
+            },
+            _ => NoRestrictedGlobalsOptions::default(),
         };
 
-        Self { restricted_globals: Box::new(list) }
+        let restricted_globals = options
+            .globals
+            .into_iter()
+            .map(|global| match global {
+                RestrictedGlobal::Name(name) => (name, String::new()),
+                RestrictedGlobal::Object { name, message } => (name, message.unwrap_or_default()),
+            })
+            .collect();
+
+        Self(Box::new(NoRestrictedGlobalsConfig {
+            restricted_globals,
+            check_global_object: options.check_global_object,
+            global_objects: options.global_objects,
+        }))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        if let AstKind::IdentifierReference(ident) = node.kind() {
-            let Some(message) = self.restricted_globals.get(ident.name.as_str()) else {
-                return;
-            };
+        match node.kind() {
+            AstKind::IdentifierReference(ident) => {
+                let Some(message) = self.0.restricted_globals.get(ident.name.as_str()) else {
+                    return;
+                };
 
-            if ctx.scoping().root_unresolved_references().contains_key(ident.name.as_str()) {
+                // Skip references to local bindings which shadow the global.
                 let reference = ctx.scoping().get_reference(ident.reference_id());
-                if !reference.is_type() {
+                if reference.symbol_id().is_none() && !reference.is_type() {
                     ctx.diagnostic(no_restricted_globals(&ident.name, message, ident.span));
                 }
             }
+            // `window.event`, `globalThis["event"]`
+            AstKind::StaticMemberExpression(_) | AstKind::ComputedMemberExpression(_)
+                if self.0.check_global_object =>
+            {
+                let Some(member) = node.kind().as_member_expression_kind() else {
+                    return;
+                };
+                let Some(name) = member.static_property_name() else {
+                    return;
+                };
+                let Some(message) = self.0.restricted_globals.get(name.as_str()) else {
+                    return;
+                };
+                if self.is_global_object(member.object(), ctx) {
+                    ctx.diagnostic(no_restricted_globals(&name, message, node.span()));
+                }
+            }
+            _ => {}
         }
     }
 }
 
+impl NoRestrictedGlobals {
+    fn is_global_object(&self, object: &Expression, ctx: &LintContext) -> bool {
+        let Expression::Identifier(ident) = object.without_parentheses() else {
+            return false;
+        };
+        let name = ident.name.as_str();
+        (DEFAULT_GLOBAL_OBJECTS.contains(&name)
+            || self.0.global_objects.iter().any(|global_object| global_object == name))
+            && ident.is_global_reference(ctx.scoping())
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -128,6 +228,42 @@ fn test() {
         ("function fn() { var foo; }", Some(serde_json::json!(["foo"])), None),
         ("foo.bar", Some(serde_json::json!(["bar"])), None),
         ("foo", Some(serde_json::json!([{ "name": "bar", "message": "Use baz instead." }])), None),
+        ("function f(event) { event; }", Some(serde_json::json!(["event"])), None),
+        ("try {} catch (event) { event; }", Some(serde_json::json!(["event"])), None),
+        ("{ let event; event; }", Some(serde_json::json!(["event"])), None),
+        ("function f() { event; } var event;", Some(serde_json::json!(["event"])), None),
+        ("window.event", Some(serde_json::json!(["event"])), None),
+        ("window.event", Some(serde_json::json!([{ "globals": ["event"] }])), None),
+        (
+            "foo.event",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "window.foo",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "window[event]",
+            Some(serde_json::json!([{ "globals": ["foo"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "function f(window) { window.event; }",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "global.event",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "let event; event;",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -199,6 +335,55 @@ fn test() {
             Some(serde_json::json!(["hasOwnProperty"])),
             None,
         ),
+        ("function f() { event; } function g(event) {}", Some(serde_json::json!(["event"])), None),
+        ("typeof event", Some(serde_json::json!(["event"])), None),
+        (
+            "fdescribe('suite', () => {});",
+            Some(
+                serde_json::json!([{ "name": "fdescribe", "message": "Do not commit fdescribe." }]),
+            ),
+            None,
+        ),
+        ("event", Some(serde_json::json!([{ "globals": ["event"] }])), None),
+        (
+            "event",
+            Some(
+                serde_json::json!([{ "globals": [{ "name": "event", "message": CUSTOM_MESSAGE }] }]),
+            ),
+            None,
+        ),
+        (
+            "window.event",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "globalThis['event']; self[`event`];",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "window.event = null; (window).event;",
+            Some(serde_json::json!([{ "globals": ["event"], "checkGlobalObject": true }])),
+            None,
+        ),
+        (
+            "function f(event) { window.event; }",
+            Some(serde_json::json!([{
+                "globals": [{ "name": "event", "message": "Use local parameter instead." }],
+                "checkGlobalObject": true,
+            }])),
+            None,
+        ),
+        (
+            "global.event",
+            Some(serde_json::json!([{
+                "globals": ["event"],
+                "checkGlobalObject": true,
+                "globalObjects": ["global"],
+            }])),
+            None,
+        ),
     ];
 
     Tester::new(NoRestrictedGlobals::NAME, NoRestrictedGlobals::PLUGIN, pass, fail)
@@ -1,9 +1,12 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, CallExpression, Expression, PropertyKind},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, ast_util::outermost_paren_parent, context::LintContext, rule::Rule};
 
 fn no_setter_return_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Setter cannot return a value").with_label(span)
@@ -17,6 +20,10 @@ declare_oxc_lint!(
     ///
     /// Setters cannot return values.
     ///
+    /// This includes `set` functions of property descriptors passed to
+    /// `Object.defineProperty`, `Reflect.defineProperty`, `Object.defineProperties`
+    /// and `Object.create`.
+    ///
     /// This rule can be disabled for TypeScript code, as the TypeScript compiler
     /// enforces this check.
     ///
@@ -35,6 +42,30 @@ declare_oxc_lint!(
     ///     return true;
     ///   }
     /// }
+    ///
+    /// Object.defineProperty(foo, "bar", {
+    ///   set(value) {
+    ///     return value;
+    ///   }
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// class URL {
+    ///   set origin(value) {
+    ///     this._origin = value;
+    ///   }
+    /// }
+    ///
+    /// Object.defineProperty(foo, "bar", {
+    ///   set(value) {
+    ///     if (!value) {
+    ///       return;
+    ///     }
+    ///     this._bar = value;
+    ///   }
+    /// });
     /// ```
     NoSetterReturn,
     eslint,
@@ -43,256 +74,406 @@ declare_oxc_lint!(
 
 impl Rule for NoSetterReturn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ReturnStatement(stmt) = node.kind() else {
-            return;
-        };
-        if stmt.argument.is_none() {
-            return;
+        match node.kind() {
+            AstKind::ReturnStatement(stmt) => {
+                if stmt.argument.is_none() {
+                    return;
+                }
+                let Some(scope_id) = ctx
+                    .scoping()
+                    .scope_ancestors(node.scope_id())
+                    .find(|&scope_id| ctx.scoping().scope_flags(scope_id).is_function())
+                else {
+                    return;
+                };
+                if ctx.scoping().scope_flags(scope_id).is_set_accessor()
+                    || is_property_descriptor_setter(
+                        ctx.nodes().get_node(ctx.scoping().get_node_id(scope_id)),
+                        ctx,
+                    )
+                {
+                    ctx.diagnostic(no_setter_return_diagnostic(stmt.span));
+                }
+            }
+            // `Object.defineProperty(foo, 'bar', { set: (val) => val })`
+            AstKind::ArrowFunctionExpression(arrow) if arrow.expression => {
+                if let Some(expr) = arrow.get_expression()
+                    && is_property_descriptor_setter(node, ctx)
+                {
+                    ctx.diagnostic(no_setter_return_diagnostic(expr.span()));
+                }
+            }
+            _ => {}
         }
+    }
+}
 
-        for scope_id in ctx.scoping().scope_ancestors(node.scope_id()) {
-            let flags = ctx.scoping().scope_flags(scope_id);
-            if flags.is_set_accessor() {
-                ctx.diagnostic(no_setter_return_diagnostic(stmt.span));
-            } else if flags.is_function() {
-                break;
+/// Whether `node` is a function used as the `set` property of a property descriptor:
+///
+/// * `Object.defineProperty(foo, 'bar', { set(val) {} })`
+/// * `Reflect.defineProperty(foo, 'bar', { set(val) {} })`
+/// * `Object.defineProperties(foo, { bar: { set(val) {} } })`
+/// * `Object.create(foo, { bar: { set(val) {} } })`
+fn is_property_descriptor_setter<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return false;
+    }
+    let Some(parent) = outermost_paren_parent(node, ctx.semantic()) else {
+        return false;
+    };
+    let AstKind::ObjectProperty(prop) = parent.kind() else {
+        return false;
+    };
+    if prop.kind != PropertyKind::Init
+        || !prop.key.is_specific_static_name("set")
+        || prop.value.without_parentheses().span() != node.span()
+    {
+        return false;
+    }
+    let descriptor = ctx.nodes().parent_node(parent.id());
+    let Some(descriptor_parent) = outermost_paren_parent(descriptor, ctx.semantic()) else {
+        return false;
+    };
+    match descriptor_parent.kind() {
+        AstKind::CallExpression(call) => is_global_method_call_argument(
+            call,
+            2,
+            descriptor.span(),
+            &[("Object", "defineProperty"), ("Reflect", "defineProperty")],
+            ctx,
+        ),
+        AstKind::ObjectProperty(prop) => {
+            if prop.value.without_parentheses().span() != descriptor.span() {
+                return false;
             }
+            let descriptors = ctx.nodes().parent_node(descriptor_parent.id());
+            let Some(AstKind::CallExpression(call)) =
+                outermost_paren_parent(descriptors, ctx.semantic()).map(AstNode::kind)
+            else {
+                return false;
+            };
+            is_global_method_call_argument(
+                call,
+                1,
+                descriptors.span(),
+                &[("Object", "defineProperties"), ("Object", "create")],
+                ctx,
+            )
         }
+        _ => false,
     }
 }
 
+/// Whether the argument at `index` of `call` spans `span`,
+/// and `call` calls one of the `methods` of a global object, e.g. `Object.create`.
+fn is_global_method_call_argument(
+    call: &CallExpression,
+    index: usize,
+    span: Span,
+    methods: &[(&str, &str)],
+    ctx: &LintContext,
+) -> bool {
+    if call
+        .arguments
+        .get(index)
+        .and_then(Argument::as_expression)
+        .is_none_or(|arg| arg.without_parentheses().span() != span)
+    {
+        return false;
+    }
+    let member = match call.callee.without_parentheses() {
+        Expression::ChainExpression(chain) => chain.expression.as_member_expression(),
+        callee => callee.as_member_expression(),
+    };
+    let Some(member) = member else {
+        return false;
+    };
+    let Expression::Identifier(object) = member.object().without_parentheses() else {
+        return false;
+    };
+    let Some(property) = member.static_property_name() else {
+        return false;
+    };
+    methods.iter().any(|&(object_name, method)| object.name == object_name && property == method)
+        && ctx.is_reference_to_global_variable(object)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("function foo() { return 1; }", None),
-        ("function set(val) { return 1; }", None),
-        ("var foo = function() { return 1; };", None),
-        ("var foo = function set() { return 1; };", None),
-        ("var set = function() { return 1; };", None),
-        ("var set = function set(val) { return 1; };", None),
-        ("var set = val => { return 1; };", None),
-        ("var set = val => 1;", None),
-        ("({ set a(val) { }}); function foo() { return 1; }", None),
-        ("({ set a(val) { }}); (function () { return 1; });", None),
-        ("({ set a(val) { }}); (() => { return 1; });", None),
-        ("({ set a(val) { }}); (() => 1);", None),
-        ("return 1;", None),
-        ("return 1;", None),
-        ("return 1; function foo(){ return 1; } return 1;", None),
+        ("function foo() { return 1; }", None, None),
+        ("function set(val) { return 1; }", None, None),
+        ("var foo = function() { return 1; };", None, None),
+        ("var foo = function set() { return 1; };", None, None),
+        ("var set = function() { return 1; };", None, None),
+        ("var set = function set(val) { return 1; };", None, None),
+        ("var set = val => { return 1; };", None, None),
+        ("var set = val => 1;", None, None),
+        ("({ set a(val) { }}); function foo() { return 1; }", None, None),
+        ("({ set a(val) { }}); (function () { return 1; });", None, None),
+        ("({ set a(val) { }}); (() => { return 1; });", None, None),
+        ("({ set a(val) { }}); (() => 1);", None, None),
+        ("return 1;", None, None),
+        ("return 1;", None, None),
+        ("return 1; function foo(){ return 1; } return 1;", None, None),
         (
             "function foo(){} return 1; var bar = function*(){ return 1; }; return 1; var baz = () => {}; return 1;",
             None,
+            None,
         ),
-        ("({ set foo(val) { return; } })", None),
-        ("({ set foo(val) { if (val) { return; } } })", None),
-        ("class A { set foo(val) { return; } }", None),
-        ("(class { set foo(val) { if (val) { return; } else { return; } return; } })", None),
-        ("class A { set foo(val) { try {} catch(e) { return; } } }", None),
-        ("({ get foo() { return 1; } })", None),
-        ("({ get set() { return 1; } })", None),
-        ("({ set(val) { return 1; } })", None),
-        ("({ set: function(val) { return 1; } })", None),
-        ("({ foo: function set(val) { return 1; } })", None),
-        ("({ set: function set(val) { return 1; } })", None),
-        ("({ set: (val) => { return 1; } })", None),
-        ("({ set: (val) => 1 })", None),
-        ("set = { foo(val) { return 1; } };", None),
-        ("class A { constructor(val) { return 1; } }", None),
-        ("class set { constructor(val) { return 1; } }", None),
-        ("class set { foo(val) { return 1; } }", None),
-        ("var set = class { foo(val) { return 1; } }", None),
-        ("(class set { foo(val) { return 1; } })", None),
-        ("class A { get foo() { return val; } }", None),
-        ("class A { get set() { return val; } }", None),
-        ("class A { set(val) { return 1; } }", None),
-        ("class A { static set(val) { return 1; } }", None),
-        ("({ set: set = function set(val) { return 1; } } = {})", None),
-        ("({ set: set = (val) => 1 } = {})", None),
-        ("class C { set; foo() { return 1; } }", None),
-        ("({ set foo(val) { function foo(val) { return 1; } } })", None),
-        ("({ set foo(val) { var foo = function(val) { return 1; } } })", None),
-        ("({ set foo(val) { var foo = (val) => { return 1; } } })", None),
-        ("({ set foo(val) { var foo = (val) => 1; } })", None),
-        ("({ set [function() { return 1; }](val) {} })", None),
-        ("({ set [() => { return 1; }](val) {} })", None),
-        ("({ set [() => 1](val) {} })", None),
-        ("({ set foo(val = function() { return 1; }) {} })", None),
-        ("({ set foo(val = v => 1) {} })", None),
-        ("(class { set foo(val) { function foo(val) { return 1; } } })", None),
-        ("(class { set foo(val) { var foo = function(val) { return 1; } } })", None),
-        ("(class { set foo(val) { var foo = (val) => { return 1; } } })", None),
-        ("(class { set foo(val) { var foo = (val) => 1; } })", None),
-        ("(class { set [function() { return 1; }](val) {} })", None),
-        ("(class { set [() => { return 1; }](val) {} })", None),
-        ("(class { set [() => 1](val) {} })", None),
-        ("(class { set foo(val = function() { return 1; }) {} })", None),
-        ("(class { set foo(val = (v) => 1) {} })", None),
-        ("Object.defineProperty(foo, 'bar', { set(val) { return; } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return; } } })", None),
+        ("({ set foo(val) { return; } })", None, None),
+        ("({ set foo(val) { if (val) { return; } } })", None, None),
+        ("class A { set foo(val) { return; } }", None, None),
+        ("(class { set foo(val) { if (val) { return; } else { return; } return; } })", None, None),
+        ("class A { set foo(val) { try {} catch(e) { return; } } }", None, None),
+        ("({ get foo() { return 1; } })", None, None),
+        ("({ get set() { return 1; } })", None, None),
+        ("({ set(val) { return 1; } })", None, None),
+        ("({ set: function(val) { return 1; } })", None, None),
+        ("({ foo: function set(val) { return 1; } })", None, None),
+        ("({ set: function set(val) { return 1; } })", None, None),
+        ("({ set: (val) => { return 1; } })", None, None),
+        ("({ set: (val) => 1 })", None, None),
+        ("set = { foo(val) { return 1; } };", None, None),
+        ("class A { constructor(val) { return 1; } }", None, None),
+        ("class set { constructor(val) { return 1; } }", None, None),
+        ("class set { foo(val) { return 1; } }", None, None),
+        ("var set = class { foo(val) { return 1; } }", None, None),
+        ("(class set { foo(val) { return 1; } })", None, None),
+        ("class A { get foo() { return val; } }", None, None),
+        ("class A { get set() { return val; } }", None, None),
+        ("class A { set(val) { return 1; } }", None, None),
+        ("class A { static set(val) { return 1; } }", None, None),
+        ("({ set: set = function set(val) { return 1; } } = {})", None, None),
+        ("({ set: set = (val) => 1 } = {})", None, None),
+        ("class C { set; foo() { return 1; } }", None, None),
+        ("({ set foo(val) { function foo(val) { return 1; } } })", None, None),
+        ("({ set foo(val) { var foo = function(val) { return 1; } } })", None, None),
+        ("({ set foo(val) { var foo = (val) => { return 1; } } })", None, None),
+        ("({ set foo(val) { var foo = (val) => 1; } })", None, None),
+        ("({ set [function() { return 1; }](val) {} })", None, None),
+        ("({ set [() => { return 1; }](val) {} })", None, None),
+        ("({ set [() => 1](val) {} })", None, None),
+        ("({ set foo(val = function() { return 1; }) {} })", None, None),
+        ("({ set foo(val = v => 1) {} })", None, None),
+        ("(class { set foo(val) { function foo(val) { return 1; } } })", None, None),
+        ("(class { set foo(val) { var foo = function(val) { return 1; } } })", None, None),
+        ("(class { set foo(val) { var foo = (val) => { return 1; } } })", None, None),
+        ("(class { set foo(val) { var foo = (val) => 1; } })", None, None),
+        ("(class { set [function() { return 1; }](val) {} })", None, None),
+        ("(class { set [() => { return 1; }](val) {} })", None, None),
+        ("(class { set [() => 1](val) {} })", None, None),
+        ("(class { set foo(val = function() { return 1; }) {} })", None, None),
+        ("(class { set foo(val = (v) => 1) {} })", None, None),
+        ("Object.defineProperty(foo, 'bar', { set(val) { return; } })", None, None),
+        ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return; } } })", None, None),
         (
             "Object.defineProperties(foo, { bar: { set(val) { try { return; } catch(e){} } } })",
             None,
+            None,
+        ),
+        ("Object.create(foo, { bar: { set: function(val) { return; } } })", None, None),
+        ("x = { set(val) { return 1; } }", None, None),
+        ("x = { foo: { set(val) { return 1; } } }", None, None),
+        ("Object.defineProperty(foo, 'bar', { value(val) { return 1; } })", None, None),
+        (
+            "Reflect.defineProperty(foo, 'bar', { value: function set(val) { return 1; } })",
+            None,
+            None,
+        ),
+        ("Object.defineProperties(foo, { bar: { [set](val) { return 1; } } })", None, None),
+        ("Object.create(foo, { bar: { 'set ': function(val) { return 1; } } })", None, None),
+        ("Object.defineProperty(foo, 'bar', { [`set `]: (val) => { return 1; } })", None, None),
+        ("Reflect.defineProperty(foo, 'bar', { Set(val) { return 1; } })", None, None),
+        ("Object.defineProperties(foo, { bar: { value: (val) => 1 } })", None, None),
+        ("Object.create(foo, { set: { value: function(val) { return 1; } } })", None, None),
+        ("Object.defineProperty(foo, 'bar', { baz(val) { return 1; } })", None, None),
+        ("Reflect.defineProperty(foo, 'bar', { get(val) { return 1; } })", None, None),
+        ("Object.create(foo, { set: function(val) { return 1; } })", None, None),
+        ("Object.defineProperty(foo, { set: (val) => 1 })", None, None),
+        (
+            "Object.defineProperty(foo, 'bar', { set(val) { function foo() { return 1; } } })",
+            None,
+            None,
         ),
-        ("Object.create(foo, { bar: { set: function(val) { return; } } })", None),
-        ("x = { set(val) { return 1; } }", None),
-        ("x = { foo: { set(val) { return 1; } } }", None),
-        ("Object.defineProperty(foo, 'bar', { value(val) { return 1; } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { value: function set(val) { return 1; } })", None),
-        ("Object.defineProperties(foo, { bar: { [set](val) { return 1; } } })", None),
-        ("Object.create(foo, { bar: { 'set ': function(val) { return 1; } } })", None),
-        ("Object.defineProperty(foo, 'bar', { [`set `]: (val) => { return 1; } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { Set(val) { return 1; } })", None),
-        ("Object.defineProperties(foo, { bar: { value: (val) => 1 } })", None),
-        ("Object.create(foo, { set: { value: function(val) { return 1; } } })", None),
-        ("Object.defineProperty(foo, 'bar', { baz(val) { return 1; } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { get(val) { return 1; } })", None),
-        ("Object.create(foo, { set: function(val) { return 1; } })", None),
-        ("Object.defineProperty(foo, { set: (val) => 1 })", None),
-        ("Object.defineProperty(foo, 'bar', { set(val) { function foo() { return 1; } } })", None),
         (
             "Reflect.defineProperty(foo, 'bar', { set(val) { var foo = function() { return 1; } } })",
             None,
+            None,
         ),
-        ("Object.defineProperties(foo, { bar: { set(val) { () => { return 1 }; } } })", None),
-        ("Object.create(foo, { bar: { set: (val) => { (val) => 1; } } })", None),
-        ("Object.defineProperty(foo, 'bar', 'baz', { set(val) { return 1; } })", None),
-        ("Object.defineProperty(foo, { set(val) { return 1; } }, 'bar')", None),
-        ("Object.defineProperty({ set(val) { return 1; } }, foo, 'bar')", None),
-        ("Reflect.defineProperty(foo, 'bar', 'baz', { set(val) { return 1; } })", None),
-        ("Reflect.defineProperty(foo, { set(val) { return 1; } }, 'bar')", None),
-        ("Reflect.defineProperty({ set(val) { return 1; } }, foo, 'bar')", None),
-        ("Object.defineProperties(foo, bar, { baz: { set(val) { return 1; } } })", None),
-        ("Object.defineProperties({ bar: { set(val) { return 1; } } }, foo)", None),
-        ("Object.create(foo, bar, { baz: { set(val) { return 1; } } })", None),
-        ("Object.create({ bar: { set(val) { return 1; } } }, foo)", None),
-        ("Object.DefineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        ("Reflect.DefineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
+        ("Object.defineProperties(foo, { bar: { set(val) { () => { return 1 }; } } })", None, None),
+        ("Object.create(foo, { bar: { set: (val) => { (val) => 1; } } })", None, None),
+        ("Object.defineProperty(foo, 'bar', 'baz', { set(val) { return 1; } })", None, None),
+        ("Object.defineProperty(foo, { set(val) { return 1; } }, 'bar')", None, None),
+        ("Object.defineProperty({ set(val) { return 1; } }, foo, 'bar')", None, None),
+        ("Reflect.defineProperty(foo, 'bar', 'baz', { set(val) { return 1; } })", None, None),
+        ("Reflect.defineProperty(foo, { set(val) { return 1; } }, 'bar')", None, None),
+        ("Reflect.defineProperty({ set(val) { return 1; } }, foo, 'bar')", None, None),
+        ("Object.defineProperties(foo, bar, { baz: { set(val) { return 1; } } })", None, None),
+        ("Object.defineProperties({ bar: { set(val) { return 1; } } }, foo)", None, None),
+        ("Object.create(foo, bar, { baz: { set(val) { return 1; } } })", None, None),
+        ("Object.create({ bar: { set(val) { return 1; } } }, foo)", None, None),
+        ("Object.DefineProperty(foo, 'bar', { set(val) { return 1; } })", None, None),
+        ("Reflect.DefineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None, None),
         (
             "Object.DefineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
             None,
+            None,
         ),
-        ("Object.Create(foo, { bar: { set: function(val) { return 1; } } })", None),
-        ("object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        ("reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
+        ("Object.Create(foo, { bar: { set: function(val) { return 1; } } })", None, None),
+        ("object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None, None),
+        ("reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None, None),
         (
             "Reflect.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
             None,
+            None,
+        ),
+        ("object.create(foo, { bar: { set: function(val) { return 1; } } })", None, None),
+        (
+            "Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })",
+            None,
+            Some(serde_json::json!({ "globals": { "Reflect": "off" } })),
         ),
-        ("object.create(foo, { bar: { set: function(val) { return 1; } } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
         (
             "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
             None,
+            Some(serde_json::json!({ "globals": { "Object": "off" } })),
         ),
         (
             "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
             None,
+            Some(serde_json::json!({ "globals": { "Object": "off" } })),
         ),
-        ("let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None, None),
         (
             "function f() { Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } }); var Reflect;}",
             None,
+            None,
         ),
         (
             "function f(Object) { Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } }) }",
             None,
+            None,
         ),
         (
             "if (x) { const Object = getObject(); Object.create(foo, { bar: { set: function(val) { return 1; } } }) }",
             None,
+            None,
         ),
         (
             "x = function Object() { Object.defineProperty(foo, 'bar', { set(val) { return 1; } }) }",
             None,
+            None,
         ),
     ];
 
     let fail = vec![
-        ("({ set a(val){ return val + 1; } })", None),
-        ("({ set a(val) { return 1; } })", None),
-        ("class A { set a(val) { return 1; } }", None),
-        ("class A { static set a(val) { return 1; } }", None),
-        ("(class { set a(val) { return 1; } })", None),
-        ("({ set a(val) { return val; } })", None),
-        ("class A { set a(val) { return undefined; } }", None),
-        ("(class { set a(val) { return null; } })", None),
-        ("({ set a(val) { return x + y; } })", None),
-        ("class A { set a(val) { return foo(); } }", None),
-        ("(class { set a(val) { return this._a; } })", None),
-        ("({ set a(val) { return this.a; } })", None),
-        ("({ set a(val) { if (foo) { return 1; }; } })", None),
-        ("class A { set a(val) { try { return 1; } catch(e) {} } }", None),
-        ("(class { set a(val) { while (foo){ if (bar) break; else return 1; } } })", None),
-        ("({ set a(val) { return 1; }, set b(val) { return 1; } })", None),
-        ("class A { set a(val) { return 1; } set b(val) { return 1; } }", None),
-        ("(class { set a(val) { return 1; } static set b(val) { return 1; } })", None),
-        ("({ set a(val) { if(val) { return 1; } else { return 2 }; } })", None),
+        ("({ set a(val){ return val + 1; } })", None, None),
+        ("({ set a(val) { return 1; } })", None, None),
+        ("class A { set a(val) { return 1; } }", None, None),
+        ("class A { static set a(val) { return 1; } }", None, None),
+        ("(class { set a(val) { return 1; } })", None, None),
+        ("({ set a(val) { return val; } })", None, None),
+        ("class A { set a(val) { return undefined; } }", None, None),
+        ("(class { set a(val) { return null; } })", None, None),
+        ("({ set a(val) { return x + y; } })", None, None),
+        ("class A { set a(val) { return foo(); } }", None, None),
+        ("(class { set a(val) { return this._a; } })", None, None),
+        ("({ set a(val) { return this.a; } })", None, None),
+        ("({ set a(val) { if (foo) { return 1; }; } })", None, None),
+        ("class A { set a(val) { try { return 1; } catch(e) {} } }", None, None),
+        ("(class { set a(val) { while (foo){ if (bar) break; else return 1; } } })", None, None),
+        ("({ set a(val) { return 1; }, set b(val) { return 1; } })", None, None),
+        ("class A { set a(val) { return 1; } set b(val) { return 1; } }", None, None),
+        ("(class { set a(val) { return 1; } static set b(val) { return 1; } })", None, None),
+        ("({ set a(val) { if(val) { return 1; } else { return 2 }; } })", None, None),
         (
             "class A { set a(val) { switch(val) { case 1: return x; case 2: return y; default: return z } } }",
             None,
+            None,
         ),
         (
             "(class { static set a(val) { if (val > 0) { this._val = val; return val; } return false; } })",
             None,
+            None,
         ),
-        ("({ set a(val) { if(val) { return 1; } else { return; }; } })", None),
+        ("({ set a(val) { if(val) { return 1; } else { return; }; } })", None, None),
         (
             "class A { set a(val) { switch(val) { case 1: return x; case 2: return; default: return z } } }",
             None,
+            None,
         ),
         (
             "(class { static set a(val) { if (val > 0) { this._val = val; return; } return false; } })",
             None,
+            None,
+        ),
+        ("({ set a(val) { function b(){} return b(); } })", None, None),
+        ("class A { set a(val) { return () => {}; } }", None, None),
+        ("(class { set a(val) { function b(){ return 1; } return 2; } })", None, None),
+        ("({ set a(val) { function b(){ return; } return 1; } })", None, None),
+        ("class A { set a(val) { var x = function() { return 1; }; return 2; } }", None, None),
+        ("(class { set a(val) { var x = () => { return; }; return 2; } })", None, None),
+        ("function f(){}; ({ set a(val) { return 1; } });", None, None),
+        ("x = function f(){}; class A { set a(val) { return 1; } };", None, None),
+        ("x = () => {}; A = class { set a(val) { return 1; } };", None, None),
+        ("return; ({ set a(val) { return 1; } }); return 2;", None, None),
+        ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None, None),
+        ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None, None),
+        ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None, None),
+        ("Object.create(null, { baz: { set(val) { return 1; } } })", None, None),
+        ("Object.defineProperty(foo, 'bar', { set: val => val })", None, None),
+        ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None, None),
+        ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None, None),
+        ("Object.create({}, { baz: { set: val => this._val } })", None, None),
+        (
+            "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
+            None,
+            None,
+        ),
+        (
+            "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
+            None,
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
+            None,
+            None,
+        ),
+        (
+            "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
+            None,
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
+            None,
+            None,
+        ),
+        (
+            "Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })",
+            None,
+            None,
+        ),
+        (
+            "Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })",
+            None,
+            None,
+        ),
+        ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None, None),
+        ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None, None),
+        ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None, None),
+        (
+            "Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })",
+            None,
+            None,
         ),
-        ("({ set a(val) { function b(){} return b(); } })", None),
-        ("class A { set a(val) { return () => {}; } }", None),
-        ("(class { set a(val) { function b(){ return 1; } return 2; } })", None),
-        ("({ set a(val) { function b(){ return; } return 1; } })", None),
-        ("class A { set a(val) { var x = function() { return 1; }; return 2; } }", None),
-        ("(class { set a(val) { var x = () => { return; }; return 2; } })", None),
-        ("function f(){}; ({ set a(val) { return 1; } });", None),
-        ("x = function f(){}; class A { set a(val) { return 1; } };", None),
-        ("x = () => {}; A = class { set a(val) { return 1; } };", None),
-        ("return; ({ set a(val) { return 1; } }); return 2;", None),
-        // ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
-        // ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
-        // ("Object.create({}, { baz: { set: val => this._val } })", None),
-        // (
-        // "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
-        // None,
-        // ),
-        // (
-        // "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
-        // None,
-        // ),
-        // ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
-        // ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
-        // ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
-        // ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
-        // ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None, None),
+        ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None, None),
+        ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None, None),
     ];
 
     Tester::new(NoSetterReturn::NAME, NoSetterReturn::PLUGIN, pass, fail)
@@ -50,6 +50,11 @@ declare_oxc_lint!(
     /// ```javascript
     /// var colors = [ "red", "blue", ];
     /// ```
+    ///
+    /// // holes in destructuring patterns skip elements and are not reported
+    /// ```javascript
+    /// var [ first, , third ] = colors;
+    /// ```
     NoSparseArrays,
     eslint,
     correctness
@@ -108,11 +113,27 @@ impl Rule for NoSparseArrays {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec!["var a = [ 1, 2, ]", "var a = [];"];
+    let pass = vec![
+        "var a = [ 1, 2, ]",
+        "var a = [];",
+        "var a = [ 1, undefined, 2 ];",
+        "var [ a, , b ] = c;",
+        "let [ , , a ] = c;",
+        "[ a, , b ] = c;",
+        "for (const [ , a ] of b) {}",
+        "for ([ , a ] of b) {}",
+        "function f([ a, , b ]) {}",
+        "const { a: [ , b ] } = c;",
+        "[ a, [ , b ] ] = c;",
+    ];
 
     let fail = vec![
         "var a = [,];",
         "var a = [ 1,, 2];",
+        "var a = [ [ 1,, 2 ] ];",
+        "var [ a ] = [ 1,, 2 ];",
+        "[ a, b ] = [ , 1 ];",
+        "f([ , a ]);",
         "var a = [ 1,,,, 2];",
         "var a = [ 1,,,,,,,,,,,,,,,,,,,,,,,,,,,,,,, 2];",
         "var a = [ 1, , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , , ,  2];",
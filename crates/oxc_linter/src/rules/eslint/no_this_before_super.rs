@@ -1,6 +1,6 @@
 use oxc_ast::{
     AstKind, AstType,
-    ast::{Argument, Expression, MethodDefinitionKind},
+    ast::{Argument, CallExpression, Expression, MethodDefinitionKind},
 };
 use oxc_cfg::{
     BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind,
@@ -8,7 +8,7 @@ use oxc_cfg::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::NodeId;
+use oxc_semantic::{NodeId, ScopeId};
 use oxc_span::{GetSpan, Span};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -36,6 +36,12 @@ declare_oxc_lint!(
     /// In the constructor of derived classes, if `this`/`super` are used before `super()` calls,
     /// it raises a `ReferenceError`.
     ///
+    /// Default values of the constructor's parameters are evaluated before its body,
+    /// so they can't use `this`/`super` either.
+    /// Arrow functions capture `this` of the constructor, so calling an arrow function
+    /// which uses `this`/`super` before `super()` is reported too. Other nested functions
+    /// have their own `this`, and are ignored.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -47,6 +53,38 @@ declare_oxc_lint!(
     ///         super();
     ///     }
     /// }
+    ///
+    /// class A2 extends B {
+    ///     constructor(a = this.b) {
+    ///         super();
+    ///     }
+    /// }
+    ///
+    /// class A3 extends B {
+    ///     constructor() {
+    ///         const init = () => this.init();
+    ///         init();
+    ///         super();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// class A1 extends B {
+    ///     constructor() {
+    ///         super();
+    ///         this.a = 0;
+    ///     }
+    /// }
+    ///
+    /// class A2 extends B {
+    ///     constructor() {
+    ///         const init = () => this.init();
+    ///         super();
+    ///         init();
+    ///     }
+    /// }
     /// ```
     NoThisBeforeSuper,
     eslint,
@@ -70,23 +108,34 @@ impl Rule for NoThisBeforeSuper {
     fn run_once(&self, ctx: &LintContext) {
         let cfg = ctx.cfg();
 
+        // constructors of derived classes
+        let wanted_nodes = ctx
+            .nodes()
+            .iter()
+            .filter(|node| {
+                matches!(node.kind(), AstKind::Function(_))
+                    && Self::is_wanted_node(node, ctx).unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        if wanted_nodes.is_empty() {
+            return;
+        }
+        let this_arrows = Self::arrows_using_this(ctx);
+
         // first pass -> find super calls and local violations
-        let mut wanted_nodes = Vec::new();
         let mut basic_blocks_with_super_called = FxHashSet::<BlockNodeId>::default();
         let mut basic_blocks_with_local_violations =
             FxHashMap::<BlockNodeId, Vec<NodeId>>::default();
         for node in ctx.nodes() {
             match node.kind() {
-                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
-                    if Self::is_wanted_node(node, ctx).unwrap_or_default() {
-                        wanted_nodes.push(node);
-                    }
-                }
                 AstKind::Super(_) => {
                     let basic_block_id = ctx.nodes().cfg_id(node.id());
                     if let AstKind::CallExpression(call_expr) = ctx.nodes().parent_kind(node.id()) {
-                        let has_this_or_super_in_args =
-                            Self::contains_this_or_super_in_args(&call_expr.arguments);
+                        let has_this_or_super_in_args = Self::contains_this_or_super_in_args(
+                            &call_expr.arguments,
+                            &this_arrows,
+                            ctx,
+                        );
 
                         if !has_this_or_super_in_args {
                             basic_blocks_with_super_called.insert(basic_block_id);
@@ -99,6 +148,17 @@ impl Rule for NoThisBeforeSuper {
                             .push(node.id());
                     }
                 }
+                AstKind::CallExpression(call_expr)
+                    if Self::calls_arrow_using_this(call_expr, &this_arrows, ctx) =>
+                {
+                    let basic_block_id = ctx.nodes().cfg_id(node.id());
+                    if !basic_blocks_with_super_called.contains(&basic_block_id) {
+                        basic_blocks_with_local_violations
+                            .entry(basic_block_id)
+                            .or_default()
+                            .push(node.id());
+                    }
+                }
                 AstKind::ThisExpression(_) => {
                     let basic_block_id = ctx.nodes().cfg_id(node.id());
                     if !basic_blocks_with_super_called.contains(&basic_block_id) {
@@ -163,6 +223,85 @@ impl NoThisBeforeSuper {
         Some(false)
     }
 
+    /// Collects the scopes of arrow functions which use `this`/`super` when they are called,
+    /// either directly or by calling another such arrow function.
+    ///
+    /// Nodes are visited in reverse order, so nested arrow functions are visited before the
+    /// arrow functions which call them. Arrow functions called through variables may be
+    /// declared later, so this is repeated until no more arrow functions are found.
+    fn arrows_using_this(ctx: &LintContext<'_>) -> FxHashSet<ScopeId> {
+        let mut this_arrows = FxHashSet::default();
+        loop {
+            let len = this_arrows.len();
+            for index in (0..ctx.nodes().len()).rev() {
+                let node = ctx.nodes().get_node(NodeId::from_usize(index));
+                let uses_this = match node.kind() {
+                    AstKind::ThisExpression(_) => true,
+                    // `super()` in an arrow function is a call of the super constructor.
+                    AstKind::Super(_) => {
+                        !matches!(ctx.nodes().parent_kind(node.id()), AstKind::CallExpression(_))
+                    }
+                    AstKind::CallExpression(call_expr) => {
+                        Self::calls_arrow_using_this(call_expr, &this_arrows, ctx)
+                    }
+                    _ => false,
+                };
+                if !uses_this {
+                    continue;
+                }
+                let boundary = ctx.nodes().ancestor_kinds(node.id()).find(|kind| {
+                    matches!(
+                        kind,
+                        AstKind::Function(_)
+                            | AstKind::ArrowFunctionExpression(_)
+                            | AstKind::PropertyDefinition(_)
+                            | AstKind::AccessorProperty(_)
+                            | AstKind::StaticBlock(_)
+                    )
+                });
+                if let Some(AstKind::ArrowFunctionExpression(arrow)) = boundary {
+                    this_arrows.insert(arrow.scope_id());
+                }
+            }
+            if this_arrows.len() == len {
+                return this_arrows;
+            }
+        }
+    }
+
+    /// Whether `call_expr` calls an arrow function in `this_arrows`, either immediately
+    /// (`(() => this.a)()`) or through a variable which is never re-assigned (`f()`).
+    fn calls_arrow_using_this(
+        call_expr: &CallExpression,
+        this_arrows: &FxHashSet<ScopeId>,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        let arrow = match call_expr.callee.without_parentheses() {
+            Expression::ArrowFunctionExpression(arrow) => arrow,
+            Expression::Identifier(ident) => {
+                let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+                else {
+                    return false;
+                };
+                if ctx.scoping().symbol_is_mutated(symbol_id) {
+                    return false;
+                }
+                let declaration = ctx.scoping().symbol_declaration(symbol_id);
+                let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(declaration) else {
+                    return false;
+                };
+                let Some(Expression::ArrowFunctionExpression(arrow)) =
+                    declarator.init.as_ref().map(Expression::without_parentheses)
+                else {
+                    return false;
+                };
+                arrow
+            }
+            _ => return false,
+        };
+        this_arrows.contains(&arrow.scope_id())
+    }
+
     fn analyze(
         cfg: &ControlFlowGraph,
         id: BlockNodeId,
@@ -257,15 +396,23 @@ impl NoThisBeforeSuper {
         })
     }
 
-    fn contains_this_or_super(arg: &Argument) -> bool {
+    fn contains_this_or_super(
+        arg: &Argument,
+        this_arrows: &FxHashSet<ScopeId>,
+        ctx: &LintContext<'_>,
+    ) -> bool {
         match arg {
             Argument::Super(_) | Argument::ThisExpression(_) => true,
             Argument::CallExpression(call_expr) => {
-                matches!(&call_expr.callee, Expression::Super(_) | Expression::ThisExpression(_))
+                Self::calls_arrow_using_this(call_expr, this_arrows, ctx)
+                    || matches!(
+                        &call_expr.callee,
+                        Expression::Super(_) | Expression::ThisExpression(_)
+                    )
                     || matches!(&call_expr.callee,
                     Expression::StaticMemberExpression(static_member) if
                     matches!(static_member.object, Expression::Super(_) | Expression::ThisExpression(_)))
-                    || Self::contains_this_or_super_in_args(&call_expr.arguments)
+                    || Self::contains_this_or_super_in_args(&call_expr.arguments, this_arrows, ctx)
             }
             Argument::StaticMemberExpression(call_expr) => {
                 matches!(&call_expr.object, Expression::Super(_) | Expression::ThisExpression(_))
@@ -274,8 +421,12 @@ impl NoThisBeforeSuper {
         }
     }
 
-    fn contains_this_or_super_in_args(args: &[Argument]) -> bool {
-        args.iter().any(|arg| Self::contains_this_or_super(arg))
+    fn contains_this_or_super_in_args(
+        args: &[Argument],
+        this_arrows: &FxHashSet<ScopeId>,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        args.iter().any(|arg| Self::contains_this_or_super(arg, this_arrows, ctx))
     }
 }
 
@@ -401,6 +552,23 @@ fn test() {
         ("class C extends B { field = this.foo(); }", None),
         ("class C extends B { field = this.foo(); constructor() { super(); } }", None),
         ("class C extends B { field = this.foo(); constructor() { } }", None), // < in this case, initializers are never evaluated.
+        // arrow functions which use `this` can be called after `super()`.
+        ("class A extends B { constructor() { const f = () => this.a; super(); f(); } }", None),
+        ("class A extends B { constructor() { super(); (() => this.a)(); } }", None),
+        ("class A extends B { constructor() { (() => () => this.a)(); super(); } }", None),
+        (
+            "class A extends B { constructor() { (() => function() { this.a(); })(); super(); } }",
+            None,
+        ),
+        (
+            "class A extends B { constructor() { const f = function() { this.a(); }; f(); super(); } }",
+            None,
+        ),
+        ("class A extends B { constructor(a = () => this.b) { super(); } }", None),
+        (
+            "class A extends B { constructor() { let f = () => this.a; f = g; f(); super(); } }",
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -428,8 +596,28 @@ fn test() {
             "class A extends B { constructor() { class C extends D { constructor() { this.e(); super(); } } super(); this.f(); } }",
             None,
         ),
+        // disallows `this`/`super` in default values of parameters.
+        ("class A extends B { constructor(a = this.b) { super(); } }", None),
+        ("class A extends B { constructor(a = super.b) { super(); } }", None),
+        ("class A extends B { constructor({ a } = this) { super(); } }", None),
+        // disallows calling arrow functions which use `this`/`super` before `super()`.
+        ("class A extends B { constructor() { (() => this.a)(); super(); } }", None),
+        ("class A extends B { constructor() { (() => { super.a(); })(); super(); } }", None),
+        ("class A extends B { constructor() { const f = () => this.a; f(); super(); } }", None),
+        (
+            "class A extends B { constructor() { const f = () => this.a; const g = () => f(); g(); super(); } }",
+            None,
+        ),
+        ("class A extends B { constructor() { super((() => this)()); } }", None),
+        ("class A extends B { constructor() { const f = () => this; super(f()); } }", None),
         // multi code path.
         ("class A extends B { constructor() { if (a) super(); this.a(); } }", None),
+        (
+            "class A extends B { constructor() { if (a) { super(); } else { this.a(); } super(); } }",
+            None,
+        ),
+        ("class A extends B { constructor() { a ? super() : this.a(); } }", None),
+        ("class A extends B { constructor() { a && super(); this.a(); } }", None),
         ("class A extends B { constructor() { try { super(); } finally { this.a; } } }", None),
         ("class A extends B { constructor() { try { super(); } catch (err) { } this.a; } }", None),
         ("class A extends B { constructor() { foo &&= super().a; this.c(); } }", None),
@@ -1,6 +1,7 @@
 use oxc_ast::{AstKind, ast::BindingPattern};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
 use oxc_span::Span;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
@@ -28,6 +29,11 @@ declare_oxc_lint!(
     /// This rule flags let or var declarations that are never assigned a value but are still read or used in the code.
     /// Since these variables will always be undefined, their usage is likely a programming mistake.
     ///
+    /// Any write counts as an assignment, including destructuring assignments (`({ a } = obj)`),
+    /// `for-in`/`for-of` loops (`for (a of list)`) and writes in other functions.
+    /// Variables redeclared with an initializer (`var a; var a = 1;`) or as a parameter,
+    /// and `declare` variables, are not reported.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -86,6 +92,16 @@ impl Rule for NoUnassignedVars {
             return;
         };
         let symbol_id = ident.symbol_id();
+        // `var a; var a = 1;` and `function f(a) { var a; }` assign `a` in another declaration.
+        let scoping = ctx.scoping();
+        if !is_uninitialized_declarator(scoping.symbol_declaration(symbol_id), ctx)
+            || scoping
+                .symbol_redeclarations(symbol_id)
+                .iter()
+                .any(|redeclaration| !is_uninitialized_declarator(redeclaration.declaration, ctx))
+        {
+            return;
+        }
         let mut has_read = false;
         for reference in ctx.symbol_references(symbol_id) {
             if reference.is_write() {
@@ -101,6 +117,19 @@ impl Rule for NoUnassignedVars {
     }
 }
 
+/// A declarator without an initializer, which isn't the left side of a `for-in` or `for-of` loop.
+fn is_uninitialized_declarator(node_id: NodeId, ctx: &LintContext) -> bool {
+    let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(node_id) else {
+        return false;
+    };
+    let declaration = ctx.nodes().parent_id(node_id);
+    declarator.init.is_none()
+        && !matches!(
+            ctx.nodes().parent_kind(declaration),
+            AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)
+        )
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -132,6 +161,21 @@ fn test() {
         				}
         			",
         "for (let p of pathToRemove) { p.remove() }",
+        "let a; ({ a } = obj); log(a);",
+        "let a; ({ b: a = 1 } = obj); log(a);",
+        "let a, b; [a, ...b] = arr; log(a, b);",
+        "let a; for (a of arr) log(a);",
+        "let a; for ({ a } of arr) log(a);",
+        "let a; a ??= 1; log(a);",
+        "let a; a++; log(a);",
+        "let a; function set() { a = 1; } log(a);",
+        "var a; var a = 1; log(a);",
+        "var a; for (var a of arr); log(a);",
+        "var a; function f() {} var a = f; log(a);",
+        "function f(a) { var a; log(a); }",
+        "function f() { var a; log(a); var a = 1; }",
+        "declare var d: number; log(d);",
+        "declare global { var g: number; } log(g);",
     ];
 
     let fail = vec![
@@ -152,6 +196,10 @@ fn test() {
 							let y: string;
 							console.log(y);
 						",
+        "let a; ({ a: b } = obj); log(a);",
+        "let a; ({ [a]: b } = obj);",
+        "var a; var b = 1; log(a, b);",
+        "var a; { let a = 1; } log(a);",
     ];
 
     Tester::new(NoUnassignedVars::NAME, NoUnassignedVars::PLUGIN, pass, fail).test_and_snapshot();
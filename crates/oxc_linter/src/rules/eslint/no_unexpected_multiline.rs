@@ -3,6 +3,7 @@ use oxc_ast::{AstKind, ast::BinaryOperator};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::line_terminator::is_line_terminator;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -186,10 +187,7 @@ impl Rule for NoUnexpectedMultiline {
                 let span = Span::new(binary_expr.left.span().end, parent_binary_expr.span().end);
                 let src = ctx.source_range(span);
 
-                let Some(newline) = memchr(b'\n', src.as_bytes()) else {
-                    return;
-                };
-                let Some(first_slash) = memchr(b'/', src.as_bytes()) else {
+                let Some(first_slash) = find_outside_comments(ctx, span, b'/') else {
                     return;
                 };
                 let Some(second_slash) = memrchr(b'/', src.as_bytes()) else {
@@ -210,7 +208,7 @@ impl Rule for NoUnexpectedMultiline {
                     })
                     .collect::<String>();
 
-                if newline < parent_binary_expr.left.span().start as usize
+                if src[..first_slash].chars().any(is_line_terminator)
 					// The identifier name should look like it was an attempt to use a regex
 					&& is_regex_flag(ident_name.as_str())
 					// if it was a regex attempt, the second slash should be before the identifier
@@ -247,13 +245,28 @@ fn is_regex_flag(str: &str) -> bool {
 /// Returns `None` if the character is not found at all or has no proceeding newline. Otherwise,
 /// returns the byte offset of the target character with respect to the start of the span.
 ///
-/// Newlines do not have to be directly before the target character, but can be anywhere before it.
+/// Newlines do not have to be directly before the target character, but can be anywhere before it,
+/// including in comments.
 fn has_newline_before(ctx: &LintContext, span: Span, c: u8) -> Option<u32> {
-    let src = ctx.source_range(span).as_bytes();
-    let target = memchr(c, src)?;
-    let newline = memchr(b'\n', src)?;
+    let target = find_outside_comments(ctx, span, c)?;
+    let before = &ctx.source_range(span)[..target];
 
-    (newline < target).then(|| u32::try_from(target).unwrap())
+    before.chars().any(is_line_terminator).then(|| u32::try_from(target).unwrap())
+}
+
+/// Find the first occurrence of `c` within a snippet of source text which is not in a comment,
+/// e.g. the parenthesis in `foo // (\n(bar)`. Returns the byte offset with respect to the start
+/// of the span.
+fn find_outside_comments(ctx: &LintContext, span: Span, c: u8) -> Option<usize> {
+    let src = ctx.source_range(span).as_bytes();
+    let mut target = memchr(c, src)?;
+    while let Some(comment) =
+        ctx.semantic().get_comment_at(span.start + u32::try_from(target).unwrap())
+    {
+        let after = (comment.span.end - span.start) as usize;
+        target = after + memchr(c, src.get(after..)?)?;
+    }
+    Some(target)
 }
 
 #[test]
@@ -348,6 +361,10 @@ fn test() {
             a: string
             b: number
         }>();",
+        "foo // (\n;(bar)",
+        "foo /* [ */\n;[bar]",
+        "foo // `\n;`bar`",
+        "foo /*\n*/;(bar)",
         "const foo = bar<{
             [key: string | number]: string
         }>();",
@@ -386,10 +403,21 @@ fn test() {
         "const x = aaaa<\n  test\n>/*\ntest\n*/`foo`", // {                "parser": require("../../fixtures/parsers/typescript-parsers/tagged-template-with-generic/tagged-template-with-generic-and-comment")            },
         "class C { field1 = obj\n[field2]; }",         // { "ecmaVersion": 2022 },
         "class C { field1 = function() {}\n[field2]; }", // { "ecmaVersion": 2022 }
+        "var a = b // (x)\n(x || y).doSomething()",
+        "var a = b /* [a] */\n[a, b, c].forEach(doSomething)",
+        "let x = tag // `\n`hello`",
+        "var a = b /*\n*/(x || y).doSomething()",
+        "
+			foo // a / b
+			/ bar /g.test(baz)
+		",
     ];
 
     // TODO: add more fixer tests
-    let fix = vec![("var a = b\n(x || y).doSomething()", "var a = b\n;(x || y).doSomething()")];
+    let fix = vec![
+        ("var a = b\n(x || y).doSomething()", "var a = b\n;(x || y).doSomething()"),
+        ("var a = b // (x)\n(x || y).doSomething()", "var a = b // (x)\n;(x || y).doSomething()"),
+    ];
 
     Tester::new(NoUnexpectedMultiline::NAME, NoUnexpectedMultiline::PLUGIN, pass, fail)
         .expect_fix(fix)
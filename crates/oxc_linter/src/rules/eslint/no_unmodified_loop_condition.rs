@@ -0,0 +1,346 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BinaryExpression, BindingIdentifier, CallExpression, Class,
+        ComputedMemberExpression, ConditionalExpression, Function, IdentifierReference,
+        NewExpression, PrivateFieldExpression, StaticMemberExpression, TaggedTemplateExpression,
+        YieldExpression,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ReferenceId, ScopeFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unmodified_loop_condition_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is not modified in this loop."))
+        .with_help("Modify the variable in the loop, or the loop will never end or never run.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnmodifiedLoopCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unmodified loop conditions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables in a loop condition often are modified in the loop.
+    /// If not, it's possibly a mistake, as the loop either never runs or never ends.
+    ///
+    /// Parts of the condition with a function call, a `new`, a `yield` or a member access
+    /// may change their value without any variable being modified, so they are ignored.
+    /// Variables in a comparison such as `a < b` are only reported if none of them are modified.
+    ///
+    /// A variable counts as modified in the loop if it's assigned in the loop, or in a function
+    /// declaration which is referenced in the loop. A `break` in the loop doesn't make its condition
+    /// change, so it doesn't prevent the report.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// let node = something;
+    /// while (node) {
+    ///     doSomething(node);
+    /// }
+    /// node = other;
+    ///
+    /// for (let j = 0; j < count; ++i) {
+    ///     doSomething(items[j]);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// while (node) {
+    ///     doSomething(node);
+    ///     node = node.parent;
+    /// }
+    ///
+    /// for (let j = 0; j < count; ++j) {
+    ///     doSomething(items[j]);
+    /// }
+    ///
+    /// // OK, the result of `hasNext()` may change.
+    /// while (node !== root && hasNext()) {
+    ///     doSomething(node);
+    /// }
+    /// ```
+    NoUnmodifiedLoopCondition,
+    eslint,
+    suspicious
+);
+
+impl Rule for NoUnmodifiedLoopCondition {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (test, init_span) = match node.kind() {
+            AstKind::WhileStatement(stmt) => (&stmt.test, None),
+            AstKind::DoWhileStatement(stmt) => (&stmt.test, None),
+            AstKind::ForStatement(stmt) => {
+                let Some(test) = &stmt.test else {
+                    return;
+                };
+                (test, stmt.init.as_ref().map(GetSpan::span))
+            }
+            _ => return,
+        };
+        // The initializer of a `for` loop only runs once.
+        let loop_span = node.span();
+        let is_in_loop = |span: Span| {
+            loop_span.contains_inclusive(span)
+                && !init_span.is_some_and(|init_span| init_span.contains_inclusive(span))
+        };
+
+        let mut collector = ConditionCollector::default();
+        collector.visit_expression(test);
+
+        let conditions = collector
+            .references
+            .into_iter()
+            .filter_map(|condition| {
+                let symbol_id = ctx.scoping().get_reference(condition.reference_id).symbol_id()?;
+                let modified = is_modified(symbol_id, &is_in_loop, ctx);
+                Some((condition, symbol_id, modified))
+            })
+            .collect::<Vec<_>>();
+
+        for (condition, symbol_id, modified) in &conditions {
+            if *modified {
+                continue;
+            }
+            // `a < b` may change if either `a` or `b` is modified.
+            if let Some(group) = condition.group
+                && conditions
+                    .iter()
+                    .any(|(other, _, modified)| *modified && other.group == Some(group))
+            {
+                continue;
+            }
+            ctx.diagnostic(no_unmodified_loop_condition_diagnostic(
+                ctx.scoping().symbol_name(*symbol_id),
+                condition.span,
+            ));
+        }
+    }
+}
+
+fn is_modified(symbol_id: SymbolId, is_in_loop: &impl Fn(Span) -> bool, ctx: &LintContext) -> bool {
+    let scoping = ctx.scoping();
+    // e.g. `while (x) { var x = next(); }`
+    if is_in_loop(scoping.symbol_span(symbol_id)) {
+        return true;
+    }
+    scoping.get_resolved_references(symbol_id).filter(|reference| reference.is_write()).any(
+        |reference| {
+            is_in_loop(ctx.nodes().get_node(reference.node_id()).span())
+                // e.g. `while (x) { next(); } function next() { x = x.next; }`
+                || enclosing_function_declaration(reference.node_id(), ctx).is_some_and(
+                    |function_id| {
+                        scoping.get_resolved_references(function_id).any(|reference| {
+                            is_in_loop(ctx.nodes().get_node(reference.node_id()).span())
+                        })
+                    },
+                )
+        },
+    )
+}
+
+fn enclosing_function_declaration(node_id: NodeId, ctx: &LintContext) -> Option<SymbolId> {
+    ctx.nodes()
+        .ancestors(node_id)
+        .find_map(|node| match node.kind() {
+            AstKind::Function(function) if function.is_declaration() => Some(function.id.as_ref()),
+            _ => None,
+        })
+        .flatten()
+        .map(BindingIdentifier::symbol_id)
+}
+
+struct Condition {
+    reference_id: ReferenceId,
+    span: Span,
+    /// The outermost binary or conditional expression containing the reference.
+    group: Option<Span>,
+}
+
+/// Collects the references in a loop condition, except those in function calls,
+/// member expressions etc., which may change without the variable being modified.
+#[derive(Default)]
+struct ConditionCollector {
+    references: Vec<Condition>,
+    group: Option<Span>,
+}
+
+impl ConditionCollector {
+    fn visit_group(&mut self, span: Span, walk: impl FnOnce(&mut Self)) {
+        let is_outermost = self.group.is_none();
+        if is_outermost {
+            self.group = Some(span);
+        }
+        walk(self);
+        if is_outermost {
+            self.group = None;
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ConditionCollector {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.push(Condition {
+            reference_id: ident.reference_id(),
+            span: ident.span,
+            group: self.group,
+        });
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        let mut finder = DynamicExpressionFinder::default();
+        finder.visit_binary_expression(expr);
+        if !finder.found {
+            self.visit_group(expr.span, |collector| walk::walk_binary_expression(collector, expr));
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpression<'a>) {
+        let mut finder = DynamicExpressionFinder::default();
+        finder.visit_conditional_expression(expr);
+        if !finder.found {
+            self.visit_group(expr.span, |collector| {
+                walk::walk_conditional_expression(collector, expr);
+            });
+        }
+    }
+
+    fn visit_call_expression(&mut self, _: &CallExpression<'a>) {}
+
+    fn visit_new_expression(&mut self, _: &NewExpression<'a>) {}
+
+    fn visit_yield_expression(&mut self, _: &YieldExpression<'a>) {}
+
+    fn visit_static_member_expression(&mut self, _: &StaticMemberExpression<'a>) {}
+
+    fn visit_computed_member_expression(&mut self, _: &ComputedMemberExpression<'a>) {}
+
+    fn visit_private_field_expression(&mut self, _: &PrivateFieldExpression<'a>) {}
+
+    fn visit_function(&mut self, _: &Function<'a>, _: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _: &Class<'a>) {}
+}
+
+/// Finds expressions whose value may change without any variable being modified.
+#[derive(Default)]
+struct DynamicExpressionFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicExpressionFinder {
+    fn visit_call_expression(&mut self, _: &CallExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_new_expression(&mut self, _: &NewExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _: &TaggedTemplateExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_yield_expression(&mut self, _: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_static_member_expression(&mut self, _: &StaticMemberExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_computed_member_expression(&mut self, _: &ComputedMemberExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_private_field_expression(&mut self, _: &PrivateFieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _: &Function<'a>, _: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = 0; while (foo) { ++foo; }",
+        "var foo = 0; while (foo) { foo += 1; }",
+        "var foo = 0; while (foo++) { }",
+        "var foo = 0; while (foo = next()) { }",
+        "var foo = 0; while (ok(foo)) { }",
+        "var foo = 0, bar = 0; while (++foo < bar) { }",
+        "var foo = 0, obj = {}; while (foo === obj.bar) { }",
+        "var foo = 0, f = {}, bar = {}; while (foo === f(bar)) { }",
+        "var foo = 0, f = {}; while (foo === f()) { }",
+        "var foo = 0, tag = 0; while (foo === tag`abc`) { }",
+        "function* foo() { var foo = 0; while (yield foo) { } }",
+        "function* foo() { var foo = 0; while (foo === (yield)) { } }",
+        "var foo = 0; while (foo.ok) { }",
+        "var foo = 0; while (foo?.ok) { }",
+        "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+        "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? bar : baz) { foo += 1; }",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; ++bar; }",
+        "var foo = 0, bar = 0; while (foo || bar) { ++foo; ++bar; }",
+        "var foo = 0; do { ++foo; } while (foo);",
+        "var foo = 0; do { } while (foo++);",
+        "for (var foo = 0; foo; ++foo) { }",
+        "for (var foo = 0; foo;) { ++foo }",
+        "var foo = 0, bar = 0; for (bar; foo;) { ++foo }",
+        "var foo; if (foo) { }",
+        "var a = [1, 2, 3]; var len = a.length; for (var i = 0; i < len - 1; i++) {}",
+        // Globals may be modified anywhere.
+        "while (ready) { }",
+        // Modified by a closure in the loop.
+        "let foo = true; while (foo) { items.forEach(() => { foo = false; }); }",
+        "let foo = true; while (foo) { f(); } function f() { foo = false; }",
+        "while (foo) { var foo = next(); }",
+        "for (;;) { }",
+        "let foo = () => true; while (foo()) { }",
+    ];
+
+    let fail = vec![
+        "var foo = 0; while (foo) { } foo = 1;",
+        "var foo = 0; while (!foo) { } foo = 1;",
+        "var foo = 0; while (foo != null) { } foo = 1;",
+        "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+        "var a, b, c; while (a < c && b < c) { ++a; } foo = 1;",
+        "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+        "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+        "var foo; do { } while (foo);",
+        "for (var foo = 0; foo < 10; ) { } foo = 1;",
+        "for (let i = 0; i < 10; ) { }",
+        "for (let i = 0, j = 0; i < 10; j++) { }",
+        // `break` doesn't change the condition.
+        "let done = false; while (!done) { if (check()) break; }",
+        // The function is never called in the loop.
+        "let foo = true; while (foo) { } function f() { foo = false; }",
+        "let foo = true, bar = 0; while (foo && bar < next()) { }",
+    ];
+
+    Tester::new(NoUnmodifiedLoopCondition::NAME, NoUnmodifiedLoopCondition::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
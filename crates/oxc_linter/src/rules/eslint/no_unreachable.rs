@@ -305,6 +305,19 @@ fn test() {
             b();
         }
         ",
+        // `finally` completions
+        "function foo() { try { return 1; } catch (e) { b(); } finally { c(); } x = 2; }",
+        "function foo() { try { a(); } finally { if (b) throw e; } x = 2; }",
+        "function foo() { try { throw a; } catch (e) { } finally { c(); } x = 2; }",
+        "function foo() { try { try { a(); } finally { throw e; } } catch { } c(); }",
+        "function foo() { for (;;) { try { break; } finally { c(); } } x = 2; }",
+        "function foo() { while (x) { try { continue; } finally { c(); } } x = 2; }",
+        "function foo() { try { return a(); } finally { cleanup(); } }",
+        "function foo() { try { a(); } catch { b(); } finally { } x = 2; }",
+        "function foo() { try { a(); } catch { return; } finally { } x = 2; }",
+        "function foo() { try { a(); } finally { try { throw e; } catch { } } x = 2; }",
+        "function foo() { try { a(); } finally { function f() { throw e; } } x = 2; }",
+        "function foo() { try { a(); } finally { c(); } x = 2; }",
     ];
 
     let fail = vec![
@@ -351,6 +364,17 @@ fn test() {
         "function foo() { var x = 1; while (true) { } x = 2; }",
         //[{ messageId: "unreachableCode", type: "ExpressionStatement" }]
         "function foo() { var x = 1; do { } while (true); x = 2; }",
+        // `finally` completions
+        "function foo() { try { a(); } finally { throw e; } x = 2; }",
+        "function foo() { try { a(); } catch { b(); } finally { throw e; } x = 2; }",
+        "function foo() { try { a(); } catch { } finally { return; } x = 2; }",
+        "function foo() { try { return 1; } catch { return 2; } finally { c(); } x = 2; }",
+        "function foo() { try { throw a; } finally { c(); } x = 2; }",
+        "function foo() { try { a(); } finally { throw e; c(); } }",
+        "function foo() { try { try { a(); } finally { throw e; } b(); } catch { } c(); }",
+        "function foo() { while (x) { try { a(); } finally { continue; } b(); } }",
+        "function foo() { while (x) { try { a(); } finally { break; } b(); } }",
+        "function foo() { try { a(); } finally { if (x) { throw e; } else { return; } } x = 2; }",
     ];
 
     Tester::new(NoUnreachable::NAME, NoUnreachable::PLUGIN, pass, fail).test_and_snapshot();
@@ -45,6 +45,35 @@ declare_oxc_lint!(
     /// })();
     ///
     /// // > 3
+    ///
+    /// a: for (const x of xs) {
+    ///     try {
+    ///         return x;
+    ///     } finally {
+    ///         continue a;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// (() => {
+    ///     try {
+    ///         return 1;
+    ///     } finally {
+    ///         console.log("done");
+    ///     }
+    /// })();
+    ///
+    /// try {
+    ///     work();
+    /// } finally {
+    ///     // Control flow which stays inside the `finally` block is fine.
+    ///     for (const cleanup of cleanups) {
+    ///         if (!cleanup) continue;
+    ///         cleanup();
+    ///     }
+    /// }
     /// ```
     NoUnsafeFinally,
     eslint,
@@ -55,10 +84,12 @@ impl Rule for NoUnsafeFinally {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let sentinel_node_type = match node.kind() {
             AstKind::BreakStatement(stmt) if stmt.label.is_none() => SentinelNodeType::Break,
-            AstKind::ContinueStatement(_) => SentinelNodeType::Continue,
+            AstKind::ContinueStatement(stmt) if stmt.label.is_none() => SentinelNodeType::Continue,
+            // Labeled `break` and `continue` are only safe if the label is inside the `finally` block.
             AstKind::ReturnStatement(_)
             | AstKind::ThrowStatement(_)
-            | AstKind::BreakStatement(_) => SentinelNodeType::ReturnThrow,
+            | AstKind::BreakStatement(_)
+            | AstKind::ContinueStatement(_) => SentinelNodeType::ReturnThrow,
             _ => return,
         };
 
@@ -169,6 +200,21 @@ fn test() {
             "var foo = function() { try { return 1; } finally { class bar { constructor() {} static ehm() { return 'Hola!'; } } } };",
             None,
         ),
+        ("var foo = function() { try {} finally { a: while (true) { continue a; } } }", None),
+        (
+            "var foo = function() { try {} finally { a: while (true) { while (true) { continue a; } } } }",
+            None,
+        ),
+        ("var foo = function() { try {} finally { a: { break a; } } }", None),
+        ("var foo = function() { try {} finally { for (const x of xs) { if (x) break; } } }", None),
+        (
+            "var foo = function() { try {} finally { try {} finally { while (true) break; } } }",
+            None,
+        ),
+        (
+            "var foo = function() { try {} finally { async function* a() { for await (const x of y) { return; } } } }",
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -223,6 +269,18 @@ fn test() {
             "var foo = function() { a: switch (true) { case true: try {} finally { switch (true) { case true: break a; } } } }",
             None,
         ),
+        ("var foo = function() { a: while (true) try {} finally { continue a; } }", None),
+        (
+            "var foo = function() { a: while (true) try {} finally { while (true) { continue a; } } }",
+            None,
+        ),
+        (
+            "var foo = function() { a: while (true) try {} finally { b: while (true) { continue a; } } }",
+            None,
+        ),
+        ("var foo = function() { try {} finally { try { throw 1; } finally {} } }", None),
+        ("var foo = function() { try {} finally { try { return 1; } catch (e) {} } }", None),
+        ("var foo = function() { try {} catch (e) {} finally { if (e) { throw e; } } }", None),
     ];
 
     Tester::new(NoUnsafeFinally::NAME, NoUnsafeFinally::PLUGIN, pass, fail).test_and_snapshot();
@@ -0,0 +1,363 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentOperator, AssignmentTarget, BindingPattern, BreakStatement, ContinueStatement,
+        Expression, Statement, VariableDeclarationKind,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_useless_assignment_diagnostic(
+    name: &str,
+    span: Span,
+    has_side_effects: bool,
+) -> OxcDiagnostic {
+    let help = if has_side_effects {
+        "Remove the assignment, but keep evaluating the right-hand side if its side effects are needed."
+    } else {
+        "Remove the assignment."
+    };
+    OxcDiagnostic::warn(format!("The value assigned to `{name}` is never read."))
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessAssignment;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable assignments when the value is not used.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A value which is assigned to a variable, but overwritten or never read afterwards,
+    /// is a dead store. It is either unnecessary, or a sign of a bug, such as reading the wrong
+    /// variable.
+    ///
+    /// The analysis is conservative, and only reports assignments which are clearly dead:
+    /// those overwritten later in the same block, with no reads, `break` or `continue` in
+    /// between, and those with no reads after them at all.
+    /// Only `let` and `var` variables declared in a function or block are checked.
+    /// Variables read or written by closures, or in loops, `try` blocks, `with` statements,
+    /// or scopes containing a direct `eval` are never reported. Variables which are never
+    /// read are left to `no-unused-vars`.
+    ///
+    /// When the assigned value has side effects, such as a function call, only the write
+    /// is useless, and the expression may need to be kept.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function fn1() {
+    ///     let v = 'used';
+    ///     doSomething(v);
+    ///     v = 'unused';
+    /// }
+    ///
+    /// function fn2() {
+    ///     let v = compute();
+    ///     v = 'used';
+    ///     doSomething(v);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function fn1() {
+    ///     let v = 'used';
+    ///     doSomething(v);
+    /// }
+    ///
+    /// function fn2() {
+    ///     let v = 'used';
+    ///     if (condition) {
+    ///         v = 'other';
+    ///     }
+    ///     doSomething(v);
+    /// }
+    /// ```
+    NoUselessAssignment,
+    eslint,
+    nursery
+);
+
+impl Rule for NoUselessAssignment {
+    fn run_once(&self, ctx: &LintContext) {
+        for symbol_id in ctx.scoping().symbol_ids() {
+            check_symbol(symbol_id, ctx);
+        }
+    }
+}
+
+/// An assignment of a value to the variable, in a `let`/`var` declaration or an
+/// assignment statement.
+struct Write<'a> {
+    /// The identifier being written.
+    target: Span,
+    /// The assigned value.
+    value: &'a Expression<'a>,
+    /// The declaration or expression statement.
+    statement: NodeId,
+    /// End of the write, reads after this position read its value.
+    end: u32,
+}
+
+fn check_symbol(symbol_id: SymbolId, ctx: &LintContext<'_>) {
+    let scoping = ctx.scoping();
+    let scope_id = scoping.symbol_scope_id(symbol_id);
+    // Top level variables may be read by other scripts or modules.
+    if scope_id == scoping.root_scope_id()
+        || scoping.scope_flags(scope_id).contains_direct_eval()
+        || !scoping.symbol_redeclarations(symbol_id).is_empty()
+    {
+        return;
+    }
+    let declaration = ctx.nodes().get_node(scoping.symbol_declaration(symbol_id));
+    let AstKind::VariableDeclarator(declarator) = declaration.kind() else { return };
+    if !matches!(declarator.kind, VariableDeclarationKind::Var | VariableDeclarationKind::Let)
+        || !matches!(declarator.id, BindingPattern::BindingIdentifier(_))
+    {
+        return;
+    }
+
+    let function_scope = enclosing_function_scope(scope_id, ctx);
+    let mut reads = vec![];
+    let mut writes = vec![];
+    if let Some(init) = &declarator.init {
+        writes.push(Write {
+            target: declarator.id.span(),
+            value: init,
+            statement: ctx.nodes().parent_id(declaration.id()),
+            end: declarator.span.end,
+        });
+    }
+    for reference in scoping.get_resolved_references(symbol_id) {
+        let node = ctx.nodes().get_node(reference.node_id());
+        // Closures may read or write the variable at any time.
+        if enclosing_function_scope(node.scope_id(), ctx) != function_scope {
+            return;
+        }
+        if reference.is_read() {
+            reads.push(node.span().start);
+        } else if let Some(write) = as_assignment_statement(node, ctx) {
+            writes.push(write);
+        }
+    }
+    if reads.is_empty() {
+        return;
+    }
+
+    let scope_node = scoping.get_node_id(scope_id);
+    let name = scoping.symbol_name(symbol_id);
+    for (i, write) in writes.iter().enumerate() {
+        let Some(in_try) = check_ancestors(write.statement, scope_node, ctx) else { continue };
+        let is_overwritten = || {
+            if in_try {
+                return false;
+            }
+            // The next write in the same block. Writes are in source order, except that
+            // the declaration is first.
+            let Some(next) =
+                writes[i + 1..].iter().filter(|next| next.end > write.end).find(|next| {
+                    ctx.nodes().parent_id(next.statement) == ctx.nodes().parent_id(write.statement)
+                })
+            else {
+                return false;
+            };
+            !reads.iter().any(|&read| write.end < read && read < next.end)
+                && !has_jump_between(write.statement, next.statement, ctx)
+        };
+        if reads.iter().all(|&read| read < write.end) || is_overwritten() {
+            let has_side_effects = !matches!(
+                write.value.get_inner_expression(),
+                Expression::NullLiteral(_)
+                    | Expression::BooleanLiteral(_)
+                    | Expression::NumericLiteral(_)
+                    | Expression::BigIntLiteral(_)
+                    | Expression::StringLiteral(_)
+                    | Expression::Identifier(_)
+                    | Expression::FunctionExpression(_)
+                    | Expression::ArrowFunctionExpression(_)
+            );
+            ctx.diagnostic(no_useless_assignment_diagnostic(name, write.target, has_side_effects));
+        }
+    }
+}
+
+/// `x = value;`
+fn as_assignment_statement<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Write<'a>> {
+    let AstKind::AssignmentExpression(assignment) = ctx.nodes().parent_kind(node.id()) else {
+        return None;
+    };
+    if assignment.operator != AssignmentOperator::Assign
+        || !matches!(&assignment.left, AssignmentTarget::AssignmentTargetIdentifier(ident) if ident.span == node.span())
+    {
+        return None;
+    }
+    let statement = ctx.nodes().parent_node(ctx.nodes().parent_id(node.id()));
+    let AstKind::ExpressionStatement(expr_stmt) = statement.kind() else { return None };
+    Some(Write {
+        target: node.span(),
+        value: &assignment.right,
+        statement: statement.id(),
+        end: expr_stmt.span.end,
+    })
+}
+
+/// The closest function, or other scope with its own `var` declarations.
+fn enclosing_function_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scoping = ctx.scoping();
+    scoping
+        .scope_ancestors(scope_id)
+        .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+        .unwrap_or_else(|| scoping.root_scope_id())
+}
+
+/// Checks the statements containing a write, up to the scope which declares the variable.
+///
+/// Returns `None` if the write is in a loop or a `with` statement, where its value may be read
+/// in the next iteration, or is unknown. Otherwise, returns whether the write is in a `try` block,
+/// where its value may be read by the `catch` or `finally` clause.
+fn check_ancestors(statement: NodeId, scope_node: NodeId, ctx: &LintContext) -> Option<bool> {
+    let mut in_try = false;
+    let mut child = statement;
+    for node in ctx.nodes().ancestors(statement) {
+        match node.kind() {
+            AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::DoWhileStatement(_)
+            | AstKind::WithStatement(_) => return None,
+            AstKind::TryStatement(try_stmt)
+                if try_stmt.block.span == ctx.nodes().get_node(child).span() =>
+            {
+                in_try = true;
+            }
+            _ => {}
+        }
+        if node.id() == scope_node {
+            break;
+        }
+        child = node.id();
+    }
+    Some(in_try)
+}
+
+/// Whether a `break` or `continue` statement may skip the statements between `from` and `to`,
+/// which are in the same block.
+fn has_jump_between(from: NodeId, to: NodeId, ctx: &LintContext) -> bool {
+    let start = ctx.nodes().get_node(from).span().end;
+    let end = ctx.nodes().get_node(to).span().start;
+    let Some(statements) = block_statements(ctx.nodes().parent_kind(from)) else {
+        return true;
+    };
+    let mut finder = JumpFinder { found: false };
+    for statement in statements {
+        let span = statement.span();
+        if start <= span.start && span.end <= end {
+            finder.visit_statement(statement);
+        }
+    }
+    finder.found
+}
+
+fn block_statements(kind: AstKind<'_>) -> Option<&[Statement<'_>]> {
+    match kind {
+        AstKind::FunctionBody(body) => Some(&body.statements),
+        AstKind::BlockStatement(block) => Some(&block.body),
+        AstKind::StaticBlock(block) => Some(&block.body),
+        AstKind::SwitchCase(case) => Some(&case.consequent),
+        AstKind::Program(program) => Some(&program.body),
+        _ => None,
+    }
+}
+
+struct JumpFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for JumpFinder {
+    fn visit_break_statement(&mut self, _it: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _it: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function f() { let v = 'used'; doSomething(v); }",
+        "function f() { let v = 'used'; if (c) { v = 'other'; } doSomething(v); }",
+        "function f() { let v; v = 1; doSomething(v); }",
+        "function f() { let v = 1; v = v + 1; doSomething(v); }",
+        "function f() { let v = 1; v = f(v); doSomething(v); }",
+        "function f() { let v = 1; v += 1; doSomething(v); }",
+        "function f() { let v = 1; v++; return v; }",
+        "function f() { let v = 1; if (c) v = 2; else v = 3; return v; }",
+        "function f() { let a = 1, b = a; return b; }",
+        // Never read, reported by no-unused-vars.
+        "function f() { let v = 1; v = 2; }",
+        // Global and top level variables.
+        "let v = 1; v = 2; use(v);",
+        "v = 1; v = 2; use(v);",
+        "const v = 1; use(v);",
+        // Loops.
+        "function f() { let v = 0; for (let i = 0; i < n; i++) { use(v); v = i; } }",
+        "function f() { let v = 0; while (c) { use(v); v = 1; } }",
+        "function f() { for (let i = 0; i < n; i++) { i = 5; } }",
+        "function f() { var v; for (const x of xs) { if (v) use(v); v = x; } }",
+        // Closures.
+        "function f() { let v = 1; const g = () => v; v = 2; g(); }",
+        "function f() { let v = 1; function g() { v = 3; } v = 2; g(); return v; }",
+        "function f() { let v = 1; setTimeout(() => use(v)); }",
+        // `try`, `with` and `eval`.
+        "function f() { let v = 1; try { v = 2; g(); v = 3; } catch { use(v); } use(v); }",
+        "function f() { let v = 1; try { g(); } finally { use(v); } }",
+        "function f(o) { let v = 1; with (o) { v = 2; } return v; }",
+        "function f() { let v = 1; v = 2; eval('use(v)'); return v; }",
+        // Jumps.
+        "function f() { let v = 1; a: { v = 2; if (c) break a; v = 3; } return v; }",
+        "function f() { let v = 0; for (const x of xs) { v = 1; if (x) continue; v = 2; } }",
+        // Destructuring and other writes.
+        "function f() { let v = 1; [v] = arr; use(v); }",
+        "function f() { let [a, b] = arr; a = 1; use(a, b); }",
+        // Redeclarations.
+        "function f() { var v = 1; var v = 2; use(v); }",
+        // Exported bindings.
+        "export let v = 1; v = 2;",
+    ];
+
+    let fail = vec![
+        "function f() { let v = 'used'; doSomething(v); v = 'unused'; }",
+        "function f() { let v = 'unused'; v = 'used'; doSomething(v); }",
+        "function f() { let v = compute(); v = 'used'; doSomething(v); }",
+        "function f() { let v; v = 1; v = 2; return v; }",
+        "function f() { var v = 1; use(v); v = 2; return; }",
+        "function f() { let v = 1; if (c) { v = 2; } else { v = 3; } use(v); v = 4; }",
+        "function f() { let v = 1; if (c) { let w = v; use(w); w = 2; } }",
+        "function f() { let v = 1; g(); v = 2; use(v); }",
+        "function f() { let v = 0; for (const x of xs) { let w = x; w = w * 2; use(w); w = 0; } }",
+        "() => { let v = 1; v = g(); return v; }",
+        "class A { static { let v = 1; v = 2; use(v); } }",
+        "function f() { let v = 1; try { g(); } catch { v = 2; v = 3; } use(v); }",
+        "function f() { let v = 1; switch (c) { case 1: v = 2; v = 3; break; } use(v); }",
+    ];
+
+    Tester::new(NoUselessAssignment::NAME, NoUselessAssignment::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
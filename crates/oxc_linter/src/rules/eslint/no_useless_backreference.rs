@@ -14,13 +14,20 @@ fn no_useless_backreference_diagnostic(
     problem: &Problem,
     back_reference: &str,
     group: &str,
+    other_groups: usize,
 ) -> OxcDiagnostic {
+    // Duplicate named groups, e.g. `/(?<a>x)|(?<a>y)/`.
+    let group = match other_groups {
+        0 => format!("'{group}'"),
+        1 => format!("'{group}' and another group"),
+        n => format!("'{group}' and other {n} groups"),
+    };
     match problem {
-        Problem::Nested =>OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group '{group}' from within that group.")).with_label(span),
-        Problem::Disjunctive => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group '{group}' which is in another alternative.")).with_label(span),
-        Problem::Forward => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group '{group}' which appears later in the pattern.")).with_label(span),
-        Problem::Backward => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group '{group}' which appears before in the same lookbehind.")).with_label(span),
-        Problem::IntoNegativeLookaround => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group '{group}' which is in a negative lookaround.")).with_label(span),
+        Problem::Nested =>OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group {group} from within that group.")).with_label(span),
+        Problem::Disjunctive => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group {group} which is in another alternative.")).with_label(span),
+        Problem::Forward => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group {group} which appears later in the pattern.")).with_label(span),
+        Problem::Backward => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group {group} which appears before in the same lookbehind.")).with_label(span),
+        Problem::IntoNegativeLookaround => OxcDiagnostic::warn(format!("Backreference '{back_reference}' will be ignored. It references group {group} which is in a negative lookaround.")).with_label(span),
     }
 }
 
@@ -72,16 +79,52 @@ impl Rule for NoUselessBackreference {
             collector.visit_pattern(pattern);
 
             for bref in &collector.backrefs {
-                if let Some((problem, cap_group_span, bref_group_span)) =
-                    problems_for_backref(bref, &collector.nodes, &collector.groups)
-                {
-                    ctx.diagnostic(no_useless_backreference_diagnostic(
-                        bref.span,
-                        &problem,
-                        ctx.source_range(bref_group_span),
-                        ctx.source_range(cap_group_span),
-                    ));
+                let cap_groups: Vec<_> = match bref.target {
+                    BackRefInfoTarget::Index(i) => {
+                        collector.groups.get(i as usize - 1).into_iter().collect()
+                    }
+                    BackRefInfoTarget::Name(cap_group_name) => collector
+                        .groups
+                        .iter()
+                        .filter(|g| g.name.is_some_and(|n| n == cap_group_name))
+                        .collect(),
+                };
+                debug_assert!(
+                    !cap_groups.is_empty(),
+                    "a backreference must have a corresponding capture group, else it is an octal escape"
+                );
+
+                // A backreference to duplicate named groups is only useless if it is useless
+                // for each of the groups.
+                let Some(problems) = cap_groups
+                    .iter()
+                    .map(|cap_group| {
+                        problem_for_backref(bref, cap_group, &collector.nodes)
+                            .map(|problem| (problem, cap_group.span))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                // Prefer the groups in the same alternative as the backreference.
+                let mut problems_to_report: Vec<_> = problems
+                    .iter()
+                    .filter(|(problem, _)| !matches!(problem, Problem::Disjunctive))
+                    .collect();
+                if problems_to_report.is_empty() {
+                    problems_to_report = problems.iter().collect();
                 }
+                let Some(((problem, cap_group_span), others)) = problems_to_report.split_first()
+                else {
+                    continue;
+                };
+                ctx.diagnostic(no_useless_backreference_diagnostic(
+                    bref.span,
+                    problem,
+                    ctx.source_range(bref.span),
+                    ctx.source_range(*cap_group_span),
+                    others.len(),
+                ));
             }
         });
     }
@@ -179,8 +222,8 @@ impl<'ast> Visit<'ast> for RegexCollector<'ast> {
     }
 }
 
-/// Determines if a backreference is useless — meaning it references a capturing
-/// group that hasn't (and cannot) participate at the time it's evaluated.
+/// Determines if a backreference is useless for one of the groups it references — meaning it
+/// references a capturing group that hasn't (and cannot) participate at the time it's evaluated.
 ///
 /// Returns the reason as a `Problem` if one is found; otherwise returns `None`.
 ///
@@ -194,40 +237,22 @@ impl<'ast> Visit<'ast> for RegexCollector<'ast> {
 ///   `/ ((a)|\1b) /`
 ///   ↳ Backref and group are in different alternatives — only one branch runs, so the group can't be matched.
 ///
-/// - `Problem::Backward`:
+/// - `Problem::Forward`:
 ///   `/ \1(a) /`
 ///   ↳ Backref appears before the group. Left-to-right matching means the group hasn’t matched yet.
 ///
-/// - `Problem::Forward`:
-///   `/ (?<=\1(a)) /`
-///   ↳ In a lookbehind (right-to-left match), the group appears *after* the reference — invalid.
+/// - `Problem::Backward`:
+///   `/ (?<=(a)\1) /`
+///   ↳ In a lookbehind (right-to-left match), the group appears *before* the reference — invalid.
 ///
 /// - `Problem::IntoNegativeLookaround`:
 ///   `/ \1(?!(a)) /`
 ///   ↳ Group is inside a negative lookaround — by definition, it can never successfully match.
-fn problems_for_backref<'a>(
-    bref: &'a BackRefInfo<'a>,
+fn problem_for_backref<'a>(
+    bref: &BackRefInfo<'a>,
+    cap_group: &GroupInfo<'a>,
     nodes: &IndexVec<RegexNodeId, RegExpAstKind<'a>>,
-    groups: &'a [GroupInfo<'a>],
-) -> Option<(
-    Problem,
-    /* span of the backreference */ Span,
-    /* span of the capture group */ Span,
-)> {
-    let cap_group = match bref.target {
-        BackRefInfoTarget::Index(i) => groups.get(i as usize - 1),
-        BackRefInfoTarget::Name(cap_group_name) => {
-            groups.iter().find(|g| g.name.is_some_and(|n| n == cap_group_name))
-        }
-    };
-    let Some(cap_group) = cap_group else {
-        debug_assert!(
-            false,
-            "a backreference must have a corresponding capture group, else it is an octal escape"
-        );
-        return None;
-    };
-
+) -> Option<Problem> {
     // Problem::Nested
     // In this scenario, the backreference appears *inside* the group it refers to.
     // e.g. `/(a\1)/`
@@ -240,7 +265,7 @@ fn problems_for_backref<'a>(
     // While this does not cause a syntax error, the reference serves no purpose
     // and is almost certainly a logic mistake or misunderstanding of how regex works.
     if cap_group.span.contains_inclusive(bref.span) {
-        return Some((Problem::Nested, cap_group.span, bref.span));
+        return Some(Problem::Nested);
     }
 
     let index_of_lowest_common_ancestor = {
@@ -284,11 +309,11 @@ fn problems_for_backref<'a>(
     // the backreference is never reached; and if the backreference is evaluated, the group didn't match.
     // Therefore, the backreference is always empty — it's unreachable in practice.
     if group_cut.first().is_some_and(|id| matches!(nodes[*id], RegExpAstKind::Alternative(_))) {
-        return Some((Problem::Disjunctive, cap_group.span, bref.span));
+        return Some(Problem::Disjunctive);
     }
 
-    // Problem::Backward
-    // A "forward" reference during left-to-right matching (normal regex mode).
+    // Problem::Forward
+    // A forward reference during left-to-right matching (normal regex mode).
     // e.g. `/\1(a)/`
     //      ^^ backreference
     //         ^^^ capture group
@@ -297,19 +322,19 @@ fn problems_for_backref<'a>(
     // the capture group has not matched anything by the time `\1` is evaluated.
     // So the backreference resolves to the empty string and is effectively useless.
     if !is_matching_backwards && bref.span.end <= cap_group.span.start {
-        return Some((Problem::Backward, cap_group.span, bref.span));
+        return Some(Problem::Forward);
     }
 
-    // Problem::Forward
-    // A forward reference inside a lookbehind (right-to-left matching).
-    // e.g. `/(?<=\1(a))/`
-    //            ^^ backreference
-    //              ^^^ capture group
+    // Problem::Backward
+    // A backward reference inside a lookbehind (right-to-left matching).
+    // e.g. `/(?<=(a)\1)/`
+    //            ^^^ capture group
+    //               ^^ backreference
     // Lookbehinds are evaluated from right to left.
-    // In this direction, the group appears *after* the backreference, so it has not matched yet
+    // In this direction, the group is matched *after* the backreference, so it has not matched yet
     // when `\1` is evaluated. As a result, the backreference resolves to the empty string.
     if is_matching_backwards && cap_group.span.end <= bref.span.start {
-        return Some((Problem::Forward, cap_group.span, bref.span));
+        return Some(Problem::Backward);
     }
 
     // Problem::IntoNegativeLookaround
@@ -329,7 +354,7 @@ fn problems_for_backref<'a>(
         ),
         _ => false,
     }) {
-        return Some((Problem::IntoNegativeLookaround, cap_group.span, bref.span));
+        return Some(Problem::IntoNegativeLookaround);
     }
 
     None
@@ -443,6 +468,9 @@ fn test() {
         r"new RegExp('([[A--B]])\\1', 'v')",
         r"new RegExp('[[]\\1](a)', 'v')",
         r"/((?<foo>bar)\k<foo>|(?<foo>baz))/",
+        // The backreference is useful for the second group.
+        r"/(?<foo>a)|(?<foo>b)\k<foo>/",
+        r"/(?:(?<foo>a)|(?<foo>b))\k<foo>/",
     ];
 
     let fail = vec![
@@ -536,6 +564,13 @@ fn test() {
         r"/((?<foo>bar)|(?<foo>baz\k<foo>)|(?<foo>qux\k<foo>))/",
         r"/(?<=((?<foo>bar)|(?<foo>baz))\k<foo>)/",
         r"/((?!(?<foo>bar))|(?!(?<foo>baz)))\k<foo>/",
+        // Useless for each of the groups.
+        r"/(?<foo>a)|(?<foo>b)|\k<foo>/",
+        r"/\k<foo>(?:(?<foo>a)|(?<foo>b)|(?<foo>c))/",
+        r"/(?:(?<foo>a)|\k<foo>(?<foo>b))(?<bar>c)\k<bar>/",
+        // Numbered backreferences to named groups.
+        r"/\1(?<foo>a)/",
+        r"/(?<foo>a\1)/",
     ];
 
     Tester::new(NoUselessBackreference::NAME, NoUselessBackreference::PLUGIN, pass, fail)
@@ -1,14 +1,15 @@
+use crate::{AstNode, ast_util::is_removable_this_arg, context::LintContext, rule::Rule};
 use oxc_ast::{
     AstKind,
-    ast::{CallExpression, ChainElement, Expression, MemberExpression},
+    ast::{
+        Argument, ArrayExpressionElement, CallExpression, ChainElement, Expression,
+        MemberExpression,
+    },
     match_member_expression,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::ContentEq;
-use oxc_span::Span;
-
-use crate::{AstNode, context::LintContext, rule::Rule};
+use oxc_span::{ContentEq, GetSpan, Span};
 
 fn no_useless_call_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Avoid unnecessary use of .{name}()"))
@@ -31,6 +32,12 @@ declare_oxc_lint!(
     /// This rule compares code statically to check whether or not thisArg is changed.
     /// So if the code about thisArg is a dynamic expression, this rule cannot judge correctly.
     ///
+    /// The fix replaces the call with a normal function invocation, spreading the elements of
+    /// the array passed to `.apply()`. It's a suggestion rather than a fix when thisArg is
+    /// `null`, as a direct call passes `undefined` as `this` in strict mode code, or when thisArg
+    /// is an expression other than `undefined`, `void 0`, an identifier or `this`, whose side
+    /// effects would be dropped. There's no fix for arrays with holes.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -64,7 +71,8 @@ declare_oxc_lint!(
     /// ```
     NoUselessCall,
     eslint,
-    perf
+    perf,
+    fix_suggestion
 );
 
 impl Rule for NoUselessCall {
@@ -86,12 +94,61 @@ impl Rule for NoUselessCall {
         let Some(this_arg) = first_arg.as_expression() else { return };
 
         if validate_this_argument(this_arg, applied) {
-            ctx.diagnostic(no_useless_call_diagnostic(
-                callee.static_property_name().unwrap(),
-                call_expr.span,
-            ));
+            let diagnostic =
+                no_useless_call_diagnostic(callee.static_property_name().unwrap(), call_expr.span);
+            let fix_kind = if is_removable_this_arg(this_arg) {
+                FixKind::SafeFix
+            } else {
+                FixKind::Suggestion
+            };
+            ctx.diagnostic_with_fix_of_kind(diagnostic, fix_kind, |fixer| {
+                match direct_call_text(call_expr, ctx) {
+                    Some(text) => fixer.replace(call_expr.span, text),
+                    None => fixer.noop(),
+                }
+            });
+        }
+    }
+}
+
+/// `foo.call(null, 1, 2)` -> `foo(1, 2)`, `foo.apply(null, [1, 2])` -> `foo(1, 2)`
+fn direct_call_text(call_expr: &CallExpression, ctx: &LintContext) -> Option<String> {
+    // `foo.call?.(null)` and `(foo?.call)(null)` throw when `foo` is nullish,
+    // `foo?.(null)` doesn't.
+    if call_expr.optional {
+        return None;
+    }
+    let callee = call_expr.callee.as_member_expression()?;
+    let applied = callee.object().span();
+
+    // The arguments which are kept, without the brackets of the array passed to `.apply()`.
+    let args = if callee.static_property_name() == Some("call") {
+        let args = &call_expr.arguments[1..];
+        args.first().map(|first| Span::new(first.span().start, args.last().unwrap().span().end))
+    } else {
+        let Some(Argument::ArrayExpression(array)) = call_expr.arguments.get(1) else {
+            return None;
+        };
+        // `foo(1, , 2)` is a syntax error.
+        if array.elements.iter().any(ArrayExpressionElement::is_elision) {
+            return None;
         }
+        (!array.elements.is_empty()).then(|| Span::new(array.span.start + 1, array.span.end - 1))
+    };
+
+    // Comments outside of the arguments which are kept would be removed.
+    let removed = match args {
+        Some(args) => [Span::new(applied.end, args.start), Span::new(args.end, call_expr.span.end)],
+        None => [Span::new(applied.end, call_expr.span.end), Span::empty(call_expr.span.end)],
+    };
+    if removed.into_iter().any(|span| ctx.has_comments_between(span)) {
+        return None;
     }
+
+    let source_text = ctx.source_text();
+    let optional = if callee.optional() { "?." } else { "" };
+    let args = args.map_or("", |args| args.source_text(source_text).trim());
+    Some(format!("{}{optional}({args})", applied.source_text(source_text)))
 }
 
 fn validate_this_argument(this_arg: &Expression, applied: &Expression) -> bool {
@@ -211,5 +268,42 @@ fn test() {
         "obj.foo?.bar.call(obj.foo, 1, 2);", // { "ecmaVersion": 2020 }
     ];
 
-    Tester::new(NoUselessCall::NAME, NoUselessCall::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.call(undefined, 1, 2);", "foo(1, 2);", None, FixKind::SafeFix),
+        ("foo.call(void 0, 1, 2);", "foo(1, 2);", None, FixKind::SafeFix),
+        ("foo.call(null);", "foo();", None, FixKind::Suggestion),
+        ("obj.foo.call(obj, 1, 2);", "obj.foo(1, 2);", None, FixKind::SafeFix),
+        ("this.foo.call(this, a);", "this.foo(a);", None, FixKind::SafeFix),
+        ("foo.apply(null, [1, 2]);", "foo(1, 2);", None, FixKind::Suggestion),
+        ("foo.apply(null, [ ]);", "foo();", None, FixKind::Suggestion),
+        ("foo.apply(undefined, [a, ...b]);", "foo(a, ...b);", None, FixKind::SafeFix),
+        ("foo.apply(null, [a, /* b */ b,]);", "foo(a, /* b */ b,);", None, FixKind::Suggestion),
+        ("obj.foo.apply(obj, [1, 2]);", "obj.foo(1, 2);", None, FixKind::SafeFix),
+        ("foo?.call(undefined, 1, 2);", "foo?.(1, 2);", None, FixKind::SafeFix),
+        ("obj?.foo.call(obj, 1, 2);", "obj?.foo(1, 2);", None, FixKind::SafeFix),
+        ("(obj?.foo).call(obj, 1, 2);", "(obj?.foo)(1, 2);", None, FixKind::SafeFix),
+        // thisArg is no longer evaluated twice.
+        ("a.b.c.foo.call(a.b.c, 1, 2);", "a.b.c.foo(1, 2);", None, FixKind::Suggestion),
+        (
+            "a.b(x, y).c.foo.apply(a.b(x, y).c, [1, 2]);",
+            "a.b(x, y).c.foo(1, 2);",
+            None,
+            FixKind::Suggestion,
+        ),
+        ("[].concat.apply([ ], [1, 2]);", "[].concat(1, 2);", None, FixKind::Suggestion),
+        // Not fixed.
+        ("foo.apply(null, [1, , 2]);", "foo.apply(null, [1, , 2]);", None, FixKind::Suggestion),
+        (
+            "foo.call(null /* this */, 1);",
+            "foo.call(null /* this */, 1);",
+            None,
+            FixKind::Suggestion,
+        ),
+        ("foo.call?.(undefined, 1, 2);", "foo.call?.(undefined, 1, 2);", None, FixKind::SafeFix),
+        ("(foo?.call)(undefined, 1, 2);", "(foo?.call)(undefined, 1, 2);", None, FixKind::SafeFix),
+    ];
+
+    Tester::new(NoUselessCall::NAME, NoUselessCall::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -113,7 +113,7 @@ declare_oxc_lint!(
     NoUselessComputedKey,
     eslint,
     style,
-    pending,
+    conditional_fix,
     config = NoUselessComputedKey,
 );
 
@@ -133,6 +133,7 @@ impl Rule for NoUselessComputedKey {
                     check_computed_class_member(
                         ctx,
                         property.key.span(),
+                        property.key.span(),
                         expr,
                         false,
                         &[],
@@ -144,7 +145,15 @@ impl Rule for NoUselessComputedKey {
                 if let Some(expr) =
                     binding_prop.key.as_expression().map(Expression::get_inner_expression)
                 {
-                    check_computed_class_member(ctx, binding_prop.span, expr, false, &[], &[]);
+                    check_computed_class_member(
+                        ctx,
+                        binding_prop.span,
+                        binding_prop.key.span(),
+                        expr,
+                        false,
+                        &[],
+                        &[],
+                    );
                 }
             }
             AstKind::PropertyDefinition(prop_def)
@@ -156,6 +165,7 @@ impl Rule for NoUselessComputedKey {
                     check_computed_class_member(
                         ctx,
                         prop_def.key.span(),
+                        prop_def.key.span(),
                         expr,
                         prop_def.r#static,
                         &["prototype", "constructor"],
@@ -172,6 +182,7 @@ impl Rule for NoUselessComputedKey {
                     check_computed_class_member(
                         ctx,
                         method_def.span,
+                        method_def.key.span(),
                         expr,
                         method_def.r#static,
                         &["prototype"],
@@ -187,12 +198,13 @@ impl Rule for NoUselessComputedKey {
 fn check_computed_class_member(
     ctx: &LintContext<'_>,
     span: Span,
+    key_span: Span,
     expr: &Expression,
     is_static: bool,
     allow_static: &[&str],
     allow_non_static: &[&str],
 ) {
-    match expr {
+    let raw = match expr {
         Expression::StringLiteral(lit) => {
             let key_name = lit.value.as_str();
             let allowed = if is_static {
@@ -200,15 +212,48 @@ fn check_computed_class_member(
             } else {
                 allow_non_static.contains(&key_name)
             };
-            if !allowed {
-                ctx.diagnostic(no_useless_computed_key_diagnostic(span, lit.raw));
+            if allowed {
+                return;
             }
+            lit.raw
         }
-        Expression::NumericLiteral(number_lit) => {
-            ctx.diagnostic(no_useless_computed_key_diagnostic(span, number_lit.raw));
+        Expression::NumericLiteral(number_lit) => number_lit.raw,
+        _ => return,
+    };
+
+    let diagnostic = no_useless_computed_key_diagnostic(span, raw);
+    match (raw, computed_key_brackets(ctx, key_span)) {
+        (Some(raw), Some(brackets)) => {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                // Insert a space to avoid merging tokens, e.g. `get[2]() {}` into `get2() {}`.
+                let needs_space = ctx.source_text()[..brackets.start as usize]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+                    && raw.chars().next().is_some_and(|c| c.is_ascii_alphanumeric());
+                let replacement = if needs_space { format!(" {raw}") } else { raw.to_string() };
+                fixer.replace(brackets, replacement)
+            });
         }
-        _ => {}
+        _ => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// Span of the computed key including its square brackets, e.g. `[ 'x' ]`.
+///
+/// Returns `None` if there is anything other than whitespace between the key and its brackets,
+/// which can only be comments.
+fn computed_key_brackets(ctx: &LintContext<'_>, key_span: Span) -> Option<Span> {
+    let source_text = ctx.source_text();
+    let before = source_text[..key_span.start as usize].trim_end();
+    let after = source_text[key_span.end as usize..].trim_start();
+    if !before.ends_with('[') || !after.starts_with(']') {
+        return None;
     }
+    let start = before.len() - 1;
+    let end = source_text.len() - after.len() + 1;
+    let brackets = Span::new(u32::try_from(start).ok()?, u32::try_from(end).ok()?);
+    (!ctx.has_comments_between(brackets)).then_some(brackets)
 }
 
 #[test]
@@ -277,6 +322,9 @@ fn test() {
             Some(serde_json::json!([{ "enforceForClassMembers": true }])),
         ),
         ("({ [99999999999999999n]: 0 })", None), // { "ecmaVersion": 2020 }
+        ("({ [Symbol.iterator]: 0 })", None),
+        ("class Foo { [Symbol.iterator]() {} }", None),
+        ("({ [`x`]: 0 })", None),
     ];
 
     let fail = vec![
@@ -347,6 +395,50 @@ fn test() {
         ("(class { ['prototype'] })", None),
     ];
 
+    let fix = vec![
+        ("({ ['0']: 0 })", "({ '0': 0 })", None),
+        ("var { ['0']: a } = obj", "var { '0': a } = obj", None),
+        ("({ ['0+1,234']: 0 })", "({ '0+1,234': 0 })", None),
+        ("({ [0]: 0 })", "({ 0: 0 })", None),
+        ("({ ['x']: 0 })", "({ 'x': 0 })", None),
+        ("({ [ 'x' ]: 0 })", "({ 'x': 0 })", None),
+        ("var { ['__proto__']: a } = obj", "var { '__proto__': a } = obj", None),
+        ("({ ['x']() {} })", "({ 'x'() {} })", None),
+        ("({ [('x')]: 0 })", "({ 'x': 0 })", None),
+        ("({ *['x']() {} })", "({ *'x'() {} })", None),
+        ("({ async ['x']() {} })", "({ async 'x'() {} })", None),
+        ("({ get[.2]() {} })", "({ get.2() {} })", None),
+        ("({ get[2]() {} })", "({ get 2() {} })", None),
+        ("({ set[2](value) {} })", "({ set 2(value) {} })", None),
+        ("({ async[2]() {} })", "({ async 2() {} })", None),
+        ("({ get['foo']() {} })", "({ get'foo'() {} })", None),
+        ("({ async*[2]() {} })", "({ async*2() {} })", None),
+        ("({ ['constructor']: 1 })", "({ 'constructor': 1 })", None),
+        ("class Foo { ['x']() {} }", "class Foo { 'x'() {} }", None),
+        ("class Foo { static[0] }", "class Foo { static 0 }", None),
+        ("class Foo { ['0'] = 0 }", "class Foo { '0' = 0 }", None),
+        (
+            "class Foo { static ['constructor']() {} }",
+            "class Foo { static 'constructor'() {} }",
+            None,
+        ),
+        ("class Foo { ['prototype']() {} }", "class Foo { 'prototype'() {} }", None),
+        ("(class { ['__proto__'] })", "(class { '__proto__' })", None),
+        // Comments prevent a fix
+        (
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+            None,
+        ),
+        (
+            "({ ['x' /* this comment also prevents a fix */]: 0 })",
+            "({ ['x' /* this comment also prevents a fix */]: 0 })",
+            None,
+        ),
+        ("({ [(/* comment */ 'x')]: 0 })", "({ [(/* comment */ 'x')]: 0 })", None),
+    ];
+
     Tester::new(NoUselessComputedKey::NAME, NoUselessComputedKey::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -39,20 +39,26 @@ declare_oxc_lint!(
     /// var foo = 'a' + 'b' + 'c';
     /// ```
     ///
+    /// Unlike ESLint, concatenations split over multiple lines are reported as well,
+    /// but the literals are only merged by a suggestion, as long strings may be split on purpose:
+    /// ```javascript
+    /// var foo = 'a'
+    ///     + 'b'
+    ///     + 'c';
+    /// ```
+    ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
     /// var foo = 'a' + bar;
     /// ```
     ///
-    /// // when the string concatenation is multiline
     /// ```javascript
-    /// var foo = 'a'
-    ///     + 'b'
-    ///     + 'c';
+    /// var foo = 'abc';
     /// ```
     NoUselessConcat,
     eslint,
-    suspicious
+    suspicious,
+    conditional_fix_suggestion
 );
 
 impl Rule for NoUselessConcat {
@@ -68,18 +74,119 @@ impl Rule for NoUselessConcat {
         let left = get_left(binary_expr);
         let right = get_right(binary_expr);
 
-        if left.is_string_literal() && right.is_string_literal() {
-            let left_span = left.span();
-            let right_span = right.span();
-            let span = Span::new(left_span.end, right_span.start);
-            let source_text = span.source_text(ctx.source_text());
-            if source_text.chars().any(is_line_terminator) {
-                return;
-            }
-            let span = Span::new(left_span.start, right_span.end);
+        if !left.is_string_literal() || !right.is_string_literal() {
+            return;
+        }
+        let span = Span::new(left.span().start, right.span().end);
+        let between = Span::new(left.span().end, right.span().start);
+        let merged = if ctx.semantic().has_comments_between(between) {
+            None
+        } else {
+            merge_literals(left, right, ctx)
+        };
+        let Some(merged) = merged else {
             ctx.diagnostic(no_useless_concat_diagnostic(span));
+            return;
+        };
+        // Splitting a long string over multiple lines may be intentional,
+        // so only suggest to merge the literals.
+        if ctx.source_range(between).chars().any(is_line_terminator) {
+            ctx.diagnostic_with_suggestion(no_useless_concat_diagnostic(span), |fixer| {
+                fixer.replace(span, merged)
+            });
+        } else {
+            ctx.diagnostic_with_fix(no_useless_concat_diagnostic(span), |fixer| {
+                fixer.replace(span, merged)
+            });
+        }
+    }
+}
+
+/// Merges two adjacent string or template literals into one.
+///
+/// Returns `None` if a string literal contains an escape sequence which isn't allowed
+/// in the template literal it's merged with, e.g. `'\1'`.
+fn merge_literals(left: &Expression, right: &Expression, ctx: &LintContext) -> Option<String> {
+    let left_raw = ctx.source_range(left.span());
+    let right_raw = ctx.source_range(right.span());
+    let left_content = &left_raw[1..left_raw.len() - 1];
+    let right_content = &right_raw[1..right_raw.len() - 1];
+    if let (Expression::StringLiteral(_), Expression::StringLiteral(_)) = (left, right) {
+        let quote = left_raw.chars().next()?;
+        let mut merged = String::from(&left_raw[..left_raw.len() - 1]);
+        push_escaped(&mut merged, right_content, |c, _| c == quote);
+        merged.push(quote);
+        return Some(merged);
+    }
+    let mut merged = String::from("`");
+    for (expr, content) in [(left, left_content), (right, right_content)] {
+        // `${` must not be formed across the two literals.
+        if content.starts_with('{') && ends_with_unescaped_dollar(&merged) {
+            merged.push('\\');
+        }
+        if matches!(expr, Expression::TemplateLiteral(_)) {
+            merged.push_str(content);
+        } else {
+            if has_octal_escape(content) {
+                return None;
+            }
+            push_escaped(&mut merged, content, |c, next| {
+                c == '`' || (c == '$' && next == Some('{'))
+            });
+        }
+    }
+    merged.push('`');
+    Some(merged)
+}
+
+/// Pushes the raw `content` of a literal, adding a `\` before each unescaped character for which
+/// `needs_escape(char, next_char)` returns `true`.
+fn push_escaped(
+    merged: &mut String,
+    content: &str,
+    needs_escape: impl Fn(char, Option<char>) -> bool,
+) {
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            merged.push(c);
+            if let Some(escaped) = chars.next() {
+                merged.push(escaped);
+            }
+            continue;
+        }
+        if needs_escape(c, chars.peek().copied()) {
+            merged.push('\\');
+        }
+        merged.push(c);
+    }
+}
+
+fn ends_with_unescaped_dollar(s: &str) -> bool {
+    let Some(before) = s.strip_suffix('$') else {
+        return false;
+    };
+    before.chars().rev().take_while(|&c| c == '\\').count() % 2 == 0
+}
+
+/// `\1` or `\08`, which are only allowed in string literals in sloppy mode.
+fn has_octal_escape(content: &str) -> bool {
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+        match chars.next() {
+            Some('0') => {
+                if chars.peek().is_some_and(char::is_ascii_digit) {
+                    return true;
+                }
+            }
+            Some('1'..='9') => return true,
+            _ => {}
         }
     }
+    false
 }
 
 fn get_left<'a>(expr: &'a BinaryExpression<'a>) -> &'a Expression<'a> {
@@ -120,19 +227,13 @@ fn test() {
         "var a = 1 - 2;",
         "var a = foo + bar;",
         "var a = 'foo' + bar;",
-        "var foo = 'foo' +
-        'bar';",
         "var string = (number + 1) + 'px';",
         "'a' + 1",
         "1 + '1'",
         "1 + `1`",
         "`1` + 1",
         "(1 + +2) + `b`",
-        "
-          'a'
-          + 'b'
-          + 'c'
-        ",
+        "'a' + (1 + 'b')",
     ];
 
     let fail = vec![
@@ -151,7 +252,45 @@ fn test() {
         + 'd'
         ",
         "'a' + 'b' + 'c' + 'd' + 'e' + foo",
+        "var foo = 'foo' +
+        'bar';",
+        "
+          'a'
+          + 'b'
+          + 'c'
+        ",
+        "'a' + /* b */ 'b'",
+        "'\\1' + `b`",
+    ];
+
+    let fix = vec![
+        ("'a' + 'b'", "'ab'", None),
+        ("\"a\" + 'b'", "\"ab\"", None),
+        ("foo + 'a' + 'b'", "foo + 'ab'", None),
+        ("'a' + 'b' + foo", "'ab' + foo", None),
+        ("(foo + 'a') + ('b' + 'c')", "(foo + 'a') + ('bc')", None),
+        ("'a' + \"'b'\"", "'a\\'b\\''", None),
+        ("\"a\" + '\\'b\"'", "\"a\\'b\\\"\"", None),
+        ("'a\\\\' + 'b'", "'a\\\\b'", None),
+        ("'a' + '\\n'", "'a\\n'", None),
+        ("`a` + 'b'", "`ab`", None),
+        ("'a' + `b`", "`ab`", None),
+        ("`a${x}` + `b${y}`", "`a${x}b${y}`", None),
+        ("'`a`' + `b`", "`\\`a\\`b`", None),
+        ("'${a}' + `b`", "`\\${a}b`", None),
+        ("'$a' + `b`", "`$ab`", None),
+        ("`a$` + '{b}'", "`a$\\{b}`", None),
+        ("`a$` + `{b}`", "`a$\\{b}`", None),
+        ("'a$' + `{b}`", "`a$\\{b}`", None),
+        ("`a\\$` + '{b}'", "`a\\${b}`", None),
+        ("'\\0' + `b`", "`\\0b`", None),
+        ("1 + 'a' + 'b'", "1 + 'ab'", None),
+        ("'a' +\n'b'", "'ab'", None),
+        ("'a' + /* b */ 'b'", "'a' + /* b */ 'b'", None),
+        ("'\\1' + `b`", "'\\1' + `b`", None),
     ];
 
-    Tester::new(NoUselessConcat::NAME, NoUselessConcat::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoUselessConcat::NAME, NoUselessConcat::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -1,7 +1,7 @@
 use oxc_ast::{
     AstKind,
     ast::{
-        Argument, BindingPattern, CallExpression, Expression, FormalParameter, FormalParameterRest,
+        Argument, BindingPattern, CallExpression, Expression, FormalParameterRest,
         FormalParameters, FunctionBody, MethodDefinition, Statement, TSAccessibility,
     },
 };
@@ -51,6 +51,10 @@ declare_oxc_lint!(
     /// such, it is unnecessary to provide an empty constructor or one that
     /// simply delegates into its parent class.
     ///
+    /// Constructors are not reported when they declare parameter properties
+    /// (`constructor(private x: number)`), have decorated parameters, which
+    /// are used for dependency injection, or are `private` or `protected`.
+    ///
     /// ::: warning
     /// Caveat: This lint rule will report on constructors whose sole purpose is to change visibility of a parent constructor.
     /// This is because the rule does not have type information to determine if the parent constructor is public, protected, or private.
@@ -119,6 +123,19 @@ impl Rule for NoUselessConstructor {
         }) {
             return;
         }
+        // allow constructors with parameter properties since they actually declare
+        // class members, and constructors with decorated parameters, which are
+        // used for dependency injection.
+        if !constructor.decorators.is_empty()
+            || constructor
+                .value
+                .params
+                .items
+                .iter()
+                .any(|param| param.has_modifier() || !param.decorators.is_empty())
+        {
+            return;
+        }
 
         let class = ctx.nodes().ancestors(node.id()).find_map(|parent| parent.kind().as_class());
         debug_assert!(class.is_some(), "Found a constructor outside of a class definition");
@@ -147,12 +164,6 @@ fn lint_empty_constructor<'a>(
         return;
     }
 
-    // allow constructors with parameter properties since they actually declare
-    // class members
-    if constructor.value.params.items.iter().any(FormalParameter::has_modifier) {
-        return;
-    }
-
     ctx.diagnostic_with_fix(no_empty_constructor(constructor.span), |fixer| {
         fixer.delete_range(constructor.span)
     });
@@ -171,7 +182,6 @@ fn lint_redundant_super_call<'a>(
     let super_args = &super_call.arguments;
 
     if is_only_simple_params(params)
-        && !is_overriding(params)
         && (is_spread_arguments(super_args) || is_passing_through(params, super_args))
     {
         ctx.diagnostic_with_fix(
@@ -181,10 +191,6 @@ fn lint_redundant_super_call<'a>(
     }
 }
 
-fn is_overriding(params: &FormalParameters) -> bool {
-    params.items.iter().any(|param| param.r#override)
-}
-
 /// Check if a function body only contains a single `super()` call. Ignores directives.
 ///
 /// Returns the call expression if the body contains a single `super()` call, otherwise [`None`].
@@ -281,6 +287,12 @@ fn test() {
         "class A { constructor(readonly x: number) {} }",
         "class A { constructor(private readonly x: number) {} }",
         "class A extends B { constructor(override x: number) { super(x); } }",
+        "class A extends B { constructor(private x: number) { super(x); } }",
+        "class A extends B { constructor(readonly x: number, ...y: any[]) { super(x, ...y); } }",
+        "class A { constructor(@Inject(Foo) foo: Foo) {} }",
+        "class A extends B { constructor(@Inject(Foo) foo: Foo) { super(foo); } }",
+        "class A extends B { protected constructor() { super(); } }",
+        "class A extends B { private constructor(...args) { super(...args); } }",
         "
         class A {
             protected foo: number | undefined;
@@ -341,6 +353,7 @@ fn test() {
         "class A extends B { constructor(a, b, ...c) { super(a, b, ...c); } }",
         // ts
         "class A { public constructor(){} }",
+        "class A extends B { public constructor(foo: string) { super(foo); } }",
     ];
 
     let fix = vec![
@@ -349,6 +362,10 @@ fn test() {
             "class A extends B { constructor() { super(); } foo() { bar(); } }",
             "class A extends B {  foo() { bar(); } }",
         ),
+        (
+            "class A extends B { constructor(a, ...b) { super(a, ...b); } }",
+            "class A extends B {  }",
+        ),
     ];
 
     Tester::new(NoUselessConstructor::NAME, NoUselessConstructor::PLUGIN, pass, fail)
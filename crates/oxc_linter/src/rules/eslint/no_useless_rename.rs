@@ -1,6 +1,6 @@
 use oxc_ast::{
     AstKind,
-    ast::{AssignmentTargetProperty, BindingPattern},
+    ast::{AssignmentTargetMaybeDefault, AssignmentTargetProperty, BindingPattern, Expression},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -49,6 +49,9 @@ declare_oxc_lint!(
     ///
     /// Disallow renaming import, export, and destructured assignments to the same name.
     ///
+    /// Renamed destructured properties are fixed to the shorthand form, keeping the default value:
+    /// `const { a: a = 1 } = o` becomes `const { a = 1 } = o`. Computed keys are never reported.
+    ///
     /// ### Why is this bad?
     ///
     /// It is unnecessary to rename a variable to the same name.
@@ -59,6 +62,7 @@ declare_oxc_lint!(
     /// ```javascript
     /// import { foo as foo } from 'foo';
     /// const { bar: bar } = obj;
+    /// const { qux: qux = 1 } = obj;
     /// export { baz as baz };
     /// ```
     ///
@@ -66,11 +70,13 @@ declare_oxc_lint!(
     /// ```javascript
     /// import { foo } from 'foo';
     /// const { bar: renamed } = obj;
+    /// const { qux = 1, quux: renamed = 1 } = obj;
     /// export { baz };
     /// ```
     NoUselessRename,
     eslint,
     correctness,
+    conditional_fix,
     config = NoUselessRenameConfig,
 );
 
@@ -97,21 +103,23 @@ impl Rule for NoUselessRename {
                         continue;
                     };
 
-                    let renamed_key = match &property.value {
+                    let (renamed_key, init) = match &property.value {
                         BindingPattern::AssignmentPattern(assignment_pattern) => {
                             match &assignment_pattern.left {
                                 BindingPattern::BindingIdentifier(binding_ident) => {
-                                    binding_ident.name
+                                    (binding_ident.name, Some(&assignment_pattern.right))
                                 }
                                 _ => continue,
                             }
                         }
-                        BindingPattern::BindingIdentifier(binding_ident) => binding_ident.name,
+                        BindingPattern::BindingIdentifier(binding_ident) => {
+                            (binding_ident.name, None)
+                        }
                         _ => continue,
                     };
 
                     if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                        report_destructuring(property.span, &renamed_key, init, ctx);
                     }
                 }
             }
@@ -126,6 +134,9 @@ impl Rule for NoUselessRename {
                     else {
                         continue;
                     };
+                    if property.computed {
+                        continue;
+                    }
                     let Some(key) = property.name.static_name() else {
                         continue;
                     };
@@ -133,8 +144,14 @@ impl Rule for NoUselessRename {
                     else {
                         continue;
                     };
+                    let init = match &property.binding {
+                        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                            Some(&target.init)
+                        }
+                        _ => None,
+                    };
                     if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                        report_destructuring(property.span, &renamed_key, init, ctx);
                     }
                 }
             }
@@ -163,6 +180,31 @@ impl Rule for NoUselessRename {
     }
 }
 
+/// Reports a destructured property renamed to the same name, fixing it to the shorthand form,
+/// e.g. `{ a: a = 1 }` -> `{ a = 1 }`.
+///
+/// No fix is offered if there are comments before the default value, which would be removed.
+fn report_destructuring<'a>(
+    span: Span,
+    name: &str,
+    init: Option<&Expression<'a>>,
+    ctx: &LintContext<'a>,
+) {
+    let diagnostic = no_useless_rename_diagnostic(span);
+    let removed = Span::new(span.start, init.map_or(span.end, |init| init.span().start));
+    if ctx.has_comments_between(removed) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let replacement = match init {
+            Some(init) => format!("{name} = {}", ctx.source_range(init.span())),
+            None => name.to_string(),
+        };
+        fixer.replace(span, replacement)
+    });
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -248,6 +290,11 @@ fn test() {
             Some(serde_json::json!([{ "ignoreExport": true }])),
         ),
         (r"const { ...foo } = bar;", None),
+        (r"let {a: b = 1} = obj;", None),
+        (r"let {['a']: a = 1} = obj;", None),
+        (r"({['a']: a} = obj);", None),
+        (r"({['a']: a = 1} = obj);", None),
+        (r"let {a: {a: b} = {}} = obj;", None),
     ];
 
     let fail = vec![
@@ -392,5 +439,26 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoUselessRename::NAME, NoUselessRename::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"let {foo: foo} = obj;", r"let {foo} = obj;"),
+        (r"let {a: a = 1} = obj;", r"let {a = 1} = obj;"),
+        (r"let {'a': a = 1, b: c = 2} = obj;", r"let {a = 1, b: c = 2} = obj;"),
+        (r"let {\u0061: a = 1} = obj;", r"let {a = 1} = obj;"),
+        (r"let {a: {b: b = 1} = {}} = obj;", r"let {a: {b = 1} = {}} = obj;"),
+        (r"function func({foo: foo = 1}) {}", r"function func({foo = 1}) {}"),
+        (r"({foo: foo} = obj);", r"({foo} = obj);"),
+        (r"({foo: (foo)} = obj);", r"({foo} = obj);"),
+        (r"({foo: foo = a} = obj);", r"({foo = a} = obj);"),
+        (r"({foo: (foo) = a} = obj);", r"({foo = a} = obj);"),
+        (r"let {foo: foo = (a, b)} = obj;", r"let {foo = (a, b)} = obj;"),
+        (r"({foo: foo = (1/* comment */)} = {});", r"({foo = (1/* comment */)} = {});"),
+        (r"({foo: foo = /* comment */ 1} = {});", r"({foo: foo = /* comment */ 1} = {});"),
+        (r"({/* comment */foo: foo = 1} = {});", r"({/* comment */foo = 1} = {});"),
+        (r"({foo: /**/foo} = {});", r"({foo: /**/foo} = {});"),
+        (r"import {foo as foo} from 'foo';", r"import {foo as foo} from 'foo';"),
+    ];
+
+    Tester::new(NoUselessRename::NAME, NoUselessRename::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
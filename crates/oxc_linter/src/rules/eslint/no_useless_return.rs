@@ -68,7 +68,7 @@ declare_oxc_lint!(
     NoUselessReturn,
     eslint,
     pedantic,
-    pending
+    conditional_fix
 );
 
 impl Rule for NoUselessReturn {
@@ -82,7 +82,14 @@ impl Rule for NoUselessReturn {
         }
 
         if Self::is_useless_return(node, ctx) {
-            ctx.diagnostic(no_useless_return_diagnostic(ret.span));
+            ctx.diagnostic_with_fix(no_useless_return_diagnostic(ret.span), |fixer| {
+                // A return which is the body of e.g. an `if` can't be removed,
+                // and removing it would remove comments in it.
+                if !Self::is_removable(node, ctx) || ctx.has_comments_between(ret.span) {
+                    return fixer.noop();
+                }
+                fixer.delete(ret)
+            });
         }
     }
 }
@@ -263,6 +270,17 @@ impl NoUselessReturn {
         false
     }
 
+    /// The return is in a statement list, so it can be removed without replacing it.
+    fn is_removable(return_node: &AstNode, ctx: &LintContext) -> bool {
+        matches!(
+            ctx.nodes().parent_kind(return_node.id()),
+            AstKind::FunctionBody(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::SwitchCase(_)
+        )
+    }
+
     /// Check if a span is contained in the last statement of a statement list
     #[inline]
     fn is_span_in_last_statement(
@@ -604,5 +622,37 @@ fn test() {
     // - "foo(); return;" // { "parserOptions": { "ecmaFeatures": { "globalReturn": true } } }
     // - "if (foo) { bar(); return; } else { baz(); }" // { "parserOptions": { "ecmaFeatures": { "globalReturn": true } } }
 
-    Tester::new(NoUselessReturn::NAME, NoUselessReturn::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("function foo() { return; }", "function foo() {  }"),
+        ("function foo() { doSomething(); return; }", "function foo() { doSomething();  }"),
+        (
+            "function foo() { if (condition) { bar(); return; } else { baz(); } }",
+            "function foo() { if (condition) { bar();  } else { baz(); } }",
+        ),
+        (
+            "function foo() { switch (bar) { case 1: doSomething(); default: doSomethingElse(); return; } }",
+            "function foo() { switch (bar) { case 1: doSomething(); default: doSomethingElse();  } }",
+        ),
+        (
+            "function foo() { try { foo(); return; } catch (err) { return 5; } }",
+            "function foo() { try { foo();  } catch (err) { return 5; } }",
+        ),
+        ("() => { return; }", "() => {  }"),
+        ("class Foo { bar() { return; } }", "class Foo { bar() {  } }"),
+        ("function foo() { label: { return; } }", "function foo() { label: {  } }"),
+        // Not the body of a statement list.
+        ("function foo() { if (foo) return; }", "function foo() { if (foo) return; }"),
+        ("function foo() { with (obj) return; }", "function foo() { with (obj) return; }"),
+        // Comments inside the return.
+        ("function foo() { return /* done */; }", "function foo() { return /* done */; }"),
+        // A return after a loop.
+        (
+            "function foo() { for (const x of xs) { if (x) {} } return; }",
+            "function foo() { for (const x of xs) { if (x) {} }  }",
+        ),
+    ];
+
+    Tester::new(NoUselessReturn::NAME, NoUselessReturn::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
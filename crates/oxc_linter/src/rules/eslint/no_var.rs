@@ -1,9 +1,13 @@
 use oxc_ast::{
     AstKind,
-    ast::{BindingPattern, VariableDeclarationKind},
+    ast::{
+        BindingPattern, ForInStatement, ForOfStatement, ForStatement, VariableDeclaration,
+        VariableDeclarationKind,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeId;
 use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
@@ -33,6 +37,13 @@ declare_oxc_lint!(
     ///
     /// Using `var` in an ES2015 environment triggers this error
     ///
+    /// The fix replaces `var` with `const` if the variables are never reassigned, and with `let`
+    /// otherwise. It is skipped if this could change the behavior of the code: when a variable is
+    /// redeclared, used outside of its block or before its declaration, used in another function
+    /// (which could be called before the declaration) other than its own initializer, declared at
+    /// the top level of a script, or the declaration is not in a block (`if (a) var b;`,
+    /// `case 0: var b;`).
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -71,15 +82,7 @@ impl Rule for NoVar {
             let var_start = dec.span.start + var_offset;
             let var_keyword_span = Span::sized(var_start, 3);
             ctx.diagnostic_with_fix(no_var_diagnostic(var_keyword_span), |fixer| {
-                let parent_span = ctx.nodes().parent_kind(node.id()).span();
-                if dec.declarations.iter().any(|decl| {
-                    decl.id.get_binding_identifiers().iter().any(|ident| {
-                        ctx.symbol_references(ident.symbol_id()).any(|id| {
-                            !parent_span
-                                .contains_inclusive(ctx.nodes().get_node(id.node_id()).span())
-                        })
-                    })
-                }) {
+                if !can_fix(node, dec, ctx) {
                     return fixer.noop();
                 }
 
@@ -119,6 +122,92 @@ impl Rule for NoVar {
     }
 }
 
+/// Whether replacing `var` with `let` or `const` keeps the behavior of the code.
+fn can_fix<'a>(node: &AstNode<'a>, dec: &VariableDeclaration<'a>, ctx: &LintContext<'a>) -> bool {
+    let parent = ctx.nodes().parent_node(node.id());
+    // `for (var a of b)`, whose variable is assigned in each iteration
+    let is_loop_assignee = match parent.kind() {
+        AstKind::ForInStatement(ForInStatement { left, .. })
+        | AstKind::ForOfStatement(ForOfStatement { left, .. }) => left.span() == dec.span,
+        _ => false,
+    };
+    let is_for_init = matches!(parent.kind(), AstKind::ForStatement(ForStatement { init: Some(init), .. }) if init.span() == dec.span);
+    // `if (a) let b = 1;` and `case 0: let b = 1;` are syntax errors, or not scoped to the case
+    if !is_loop_assignee
+        && !is_for_init
+        && !matches!(
+            parent.kind(),
+            AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::TSModuleBlock(_)
+        )
+    {
+        return false;
+    }
+
+    let scoping = ctx.scoping();
+    let parent_span = parent.kind().span();
+    let in_loop = ctx
+        .nodes()
+        .ancestor_kinds(node.id())
+        .take_while(|kind| !kind.is_function_like())
+        .any(AstKind::is_iteration_statement);
+    // `for (;;) { var a; }` keeps the value of `a` from the previous iteration, `let a;` doesn't
+    if in_loop && !is_loop_assignee && dec.declarations.iter().any(|decl| decl.init.is_none()) {
+        return false;
+    }
+
+    dec.declarations.iter().all(|decl| {
+        decl.id.get_binding_identifiers().iter().all(|ident| {
+            let symbol_id = ident.symbol_id();
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            // `let let` is a syntax error, redeclaring a `let` is an error,
+            // and a top-level `var` in a script is a property of the global object
+            if ident.name == "let"
+                || !scoping.symbol_redeclarations(symbol_id).is_empty()
+                || (scope_id == scoping.root_scope_id() && !ctx.source_type().is_module())
+            {
+                return false;
+            }
+            ctx.symbol_references(symbol_id).all(|reference| {
+                let ref_node = ctx.nodes().get_node(reference.node_id());
+                let ref_span = ref_node.span();
+                // used outside of the block
+                if !parent_span.contains_inclusive(ref_span) {
+                    return false;
+                }
+                // `var foo = function () { foo(); }`
+                let in_own_function_init = decl.init.as_ref().is_some_and(|init| {
+                    init.without_parentheses().is_function()
+                        && init.span().contains_inclusive(ref_span)
+                });
+                // used before the declaration, or in its own initializer, which would be in the TDZ
+                if ref_span.start < ident.span.start
+                    || (decl.span.contains_inclusive(ref_span) && !in_own_function_init)
+                {
+                    return false;
+                }
+                // other functions can be called before the declaration, e.g.
+                // `var a = f(); function f() { return a; }`,
+                // and closures in a loop capture a new binding in each iteration
+                (in_own_function_init && !in_loop)
+                    || enclosing_function_scope(ref_node.scope_id(), ctx) == scope_id
+            })
+        })
+    })
+}
+
+/// The closest function, or other scope with its own `var` declarations.
+fn enclosing_function_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scoping = ctx.scoping();
+    scoping
+        .scope_ancestors(scope_id)
+        .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+        .unwrap_or_else(|| scoping.root_scope_id())
+}
+
 fn is_written_to(binding_pat: &BindingPattern, ctx: &LintContext) -> bool {
     match &binding_pat {
         BindingPattern::BindingIdentifier(binding_ident) => ctx
@@ -222,7 +311,57 @@ fn test() {
             "function play(index: number) { if (index > 1) { var a = undefined } else { var a = undefined } console.log(a) }",
         ),
         ("declare var foo = 2;", "declare let foo = 2;"),
+        // loops
+        ("for (var a of b) { console.log(a); }", "for (let a of b) { console.log(a); }"),
+        (
+            "for (var i = 0; i < list.length; ++i) { foo(i) }",
+            "for (let i = 0; i < list.length; ++i) { foo(i) }",
+        ),
+        (
+            "for (let a of b) { var c = 1; console.log(c); }",
+            "for (let a of b) { const c = 1; console.log(c); }",
+        ),
+        ("for (var a of b) { arr.push(() => a); }", "for (var a of b) { arr.push(() => a); }"),
+        (
+            "for (let a of b) { var c; console.log(c); c = 'hello'; }",
+            "for (let a of b) { var c; console.log(c); c = 'hello'; }",
+        ),
+        ("for (var i = 0; i < 10; ++i) {} i;", "for (var i = 0; i < 10; ++i) {} i;"),
+        (
+            "for (const a of b) { function f() { var c; return c; } }",
+            "for (const a of b) { function f() { let c; return c; } }",
+        ),
+        // redeclarations
+        ("var a, b, c; var a;", "var a, b, c; var a;"),
+        ("var a; if (b) { var a; }", "var a; if (b) { var a; }"),
+        // not in a block
+        ("if (foo) var bar = 1;", "if (foo) var bar = 1;"),
+        ("switch (a) { case 0: var b = 1 }", "switch (a) { case 0: var b = 1 }"),
+        // used outside of the block, or before the declaration
+        ("if (foo) { var a, b, c; } a;", "if (foo) { var a, b, c; } a;"),
+        ("function foo() { a } var a = 1; foo()", "function foo() { a } var a = 1; foo()"),
+        ("var a = b, b = 1", "var a = b, b = 1"),
+        ("var {a = a} = {}", "var {a = a} = {}"),
+        ("var foo = bar(function () { foo(); });", "var foo = bar(function () { foo(); });"),
+        ("var foo = function () { foo() };", "const foo = function () { foo() };"),
+        ("var foo = () => foo();", "const foo = () => foo();"),
+        ("var a = f(); function f() { return a; }", "var a = f(); function f() { return a; }"),
+        ("var a = 1; function f() { return a; }", "var a = 1; function f() { return a; }"),
+        ("var a = 1; const f = () => a;", "var a = 1; const f = () => a;"),
+        // `let` is not a valid name for a `let` declaration
+        ("function foo() { var let; }", "function foo() { var let; }"),
     ];
 
     Tester::new(NoVar::NAME, NoVar::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
+
+    // In scripts, a top-level `var` is a property of the global object.
+    let fail = vec![("var foo = 1", None), ("function foo() { var bar = 1 }", None)];
+    let fix = vec![
+        ("var foo = 1", "var foo = 1"),
+        ("function foo() { var bar = 1 }", "function foo() { const bar = 1 }"),
+    ];
+    Tester::new(NoVar::NAME, NoVar::PLUGIN, vec![], fail)
+        .change_rule_path_extension("cjs")
+        .expect_fix(fix)
+        .test();
 }
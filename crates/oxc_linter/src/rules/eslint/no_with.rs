@@ -26,6 +26,11 @@ declare_oxc_lint!(
     /// variable inside the block actually refers to.
     ///
     /// It is generally considered a bad practice and is forbidden in strict mode.
+    /// Engines also can't optimize the code inside a `with` statement, as any
+    /// variable lookup may resolve to a property of the object at runtime.
+    ///
+    /// As `with` statements are a syntax error in strict mode code, including ES modules
+    /// and classes, this rule is only useful for sloppy mode scripts.
     ///
     /// This rule is not necessary in TypeScript code if `alwaysStrict` is enabled.
     ///
@@ -37,6 +42,12 @@ declare_oxc_lint!(
     ///     r = Math.sqrt(x * x + y * y); // is r a member of point?
     /// }
     /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const { x, y } = point;
+    /// r = Math.sqrt(x * x + y * y);
+    /// ```
     NoWith,
     eslint,
     correctness
@@ -68,7 +79,15 @@ fn test() {
         "const { with: w } = { with: 4 }; w;",
     ];
 
-    let fail = vec!["with(foo) { bar() }"];
+    let fail = vec![
+        "with(foo) { bar() }",
+        "with (foo) bar();",
+        "/* with */ with (foo) {}",
+        "label: with (foo) {}",
+        "with (foo) { with (bar) { baz(); } }",
+        "function f() { with (foo) { return bar; } }",
+        "if (a) with (foo) {} else with (bar) {}",
+    ];
 
     Tester::new(NoWith::NAME, NoWith::PLUGIN, pass, fail).test_and_snapshot();
 }
@@ -2,7 +2,9 @@ use std::cmp::max;
 
 use oxc_allocator::Box;
 use oxc_ast::AstKind;
-use oxc_ast::ast::{Expression, ObjectExpression, ObjectPropertyKind, PropertyKind};
+use oxc_ast::ast::{
+    Expression, IdentifierReference, ObjectExpression, ObjectPropertyKind, PropertyKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -87,21 +89,16 @@ impl Rule for PreferObjectSpread {
             return;
         };
 
-        let unresolved_references = ctx.scoping().root_unresolved_references();
-
+        // `Object` must be the global, not a variable which shadows it.
         match callee.object().get_inner_expression() {
             Expression::Identifier(ident) => {
-                if ident.name != "Object"
-                    || !unresolved_references.contains_key(ident.name.as_str())
-                {
+                if ident.name != "Object" || !is_global_reference(ident, ctx) {
                     return;
                 }
             }
             Expression::StaticMemberExpression(member_expr) => {
                 if let Expression::Identifier(ident) = member_expr.object.get_inner_expression() {
-                    if ident.name != "globalThis"
-                        || !unresolved_references.contains_key(ident.name.as_str())
-                    {
+                    if ident.name != "globalThis" || !is_global_reference(ident, ctx) {
                         return;
                     }
                 } else {
@@ -218,6 +215,12 @@ impl Rule for PreferObjectSpread {
     }
 }
 
+/// `ident` itself is unresolved, even if the same name is shadowed elsewhere in the file.
+fn is_global_reference(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+        && ctx.is_reference_to_global_variable(ident)
+}
+
 fn has_get_or_set_property(obj_expr: &ObjectExpression) -> bool {
     obj_expr.properties.iter().any(|p| {
         let ObjectPropertyKind::ObjectProperty(p) = p else {
@@ -392,6 +395,14 @@ fn test() {
         const Object = {};
         Object.assign({}, foo);
         ",
+        "
+        function f(Object) { return Object.assign({}, foo); }
+        Object.keys(foo);
+        ",
+        "
+        { const globalThis = {}; globalThis.Object.assign({}, foo); }
+        globalThis.foo;
+        ",
         // "
         // Object = {};
         // Object.assign({}, foo);
@@ -598,6 +609,7 @@ fn test() {
         "Object.assign({ get a() {}, set b(val) {} })",
         "const obj = Object.assign<{}, Record<string, string[]>>({}, getObject());", // {                "parser": require("../../fixtures/parsers/typescript-parsers/object-assign-with-generic/object-assign-with-generic-1")            },
         "Object.assign<{}, A>({}, foo);", // {                "parser": require("../../fixtures/parsers/typescript-parsers/object-assign-with-generic/object-assign-with-generic-2")            }
+        "function f(Object) { return Object; } Object.assign({}, foo);",
     ];
 
     let fix = vec![
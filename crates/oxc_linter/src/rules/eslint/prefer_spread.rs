@@ -1,12 +1,16 @@
+use crate::{
+    AstNode,
+    ast_util::{is_method_call, is_removable_this_arg},
+    context::LintContext,
+    rule::Rule,
+};
 use oxc_ast::{
     AstKind,
     ast::{CallExpression, ChainElement, Expression, MemberExpression, match_member_expression},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{ContentEq, Span};
-
-use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
+use oxc_span::{ContentEq, GetSpan, Span};
 
 fn eslint_prefer_spread_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Require spread operators instead of .apply()").with_label(span)
@@ -36,6 +40,13 @@ declare_oxc_lint!(
     /// Math.max(...args);
     /// ```
     ///
+    /// The fix replaces the call with a normal function invocation, spreading the arguments.
+    /// It's a suggestion rather than a fix, as `.apply()` also accepts array-like objects,
+    /// `null` and `undefined`, while spreading them throws, unless the arguments are `arguments`.
+    /// It's also a suggestion when thisArg is `null`, which a direct call replaces with
+    /// `undefined` in strict mode code, or an expression other than `undefined`, `void 0`,
+    /// an identifier or `this`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -64,7 +75,8 @@ declare_oxc_lint!(
     /// ```
     PreferSpread,
     eslint,
-    style
+    style,
+    fix_suggestion
 );
 
 impl Rule for PreferSpread {
@@ -130,7 +142,51 @@ fn check_eslint_prefer_spread(call_expr: &CallExpression, ctx: &LintContext) {
         return;
     }
 
-    ctx.diagnostic(eslint_prefer_spread_diagnostic(call_expr.span));
+    let fix_kind =
+        if is_removable_this_arg(args0) && args[1].as_expression().is_some_and(is_arguments) {
+            FixKind::SafeFix
+        } else {
+            FixKind::Suggestion
+        };
+    ctx.diagnostic_with_fix_of_kind(
+        eslint_prefer_spread_diagnostic(call_expr.span),
+        fix_kind,
+        |fixer| match spread_call_text(call_expr, ctx) {
+            Some(text) => fixer.replace(call_expr.span, text),
+            None => fixer.noop(),
+        },
+    );
+}
+
+/// `arguments`, which is iterable, unlike other array-like objects.
+fn is_arguments(expr: &Expression) -> bool {
+    matches!(expr.without_parentheses(), Expression::Identifier(ident) if ident.name == "arguments")
+}
+
+/// `foo.apply(null, args)` -> `foo(...args)`
+fn spread_call_text(call_expr: &CallExpression, ctx: &LintContext) -> Option<String> {
+    // `foo.apply?.(null, args)` and `(foo?.apply)(null, args)` throw when `foo` is nullish,
+    // `foo?.(...args)` doesn't.
+    if call_expr.optional {
+        return None;
+    }
+    let callee = call_expr.callee.as_member_expression()?;
+    let applied = callee.object().span();
+    let args = call_expr.arguments[1].span();
+
+    // Comments outside of the arguments which are kept would be removed.
+    let removed = [Span::new(applied.end, args.start), Span::new(args.end, call_expr.span.end)];
+    if removed.into_iter().any(|span| ctx.has_comments_between(span)) {
+        return None;
+    }
+
+    let source_text = ctx.source_text();
+    let optional = if callee.optional() { "?." } else { "" };
+    Some(format!(
+        "{}{optional}(...{})",
+        applied.source_text(source_text),
+        args.source_text(source_text)
+    ))
 }
 
 fn as_member_expression_without_chain_expression<'a>(
@@ -192,5 +248,55 @@ fn test() {
         "class C { #foo; foo() { obj.#foo.apply(obj, args); } }",
     ];
 
-    Tester::new(PreferSpread::NAME, PreferSpread::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.apply(undefined, arguments);", "foo(...arguments);", None, FixKind::SafeFix),
+        ("foo.apply(void 0, arguments);", "foo(...arguments);", None, FixKind::SafeFix),
+        ("obj.foo.apply(obj, arguments);", "obj.foo(...arguments);", None, FixKind::SafeFix),
+        ("this.foo.apply(this, arguments);", "this.foo(...arguments);", None, FixKind::SafeFix),
+        ("foo.apply(null, arguments);", "foo(...arguments);", None, FixKind::Suggestion),
+        // `args` may be an array-like object, `null` or `undefined`, which can't be spread.
+        ("foo.apply(null, args);", "foo(...args);", None, FixKind::Suggestion),
+        ("Math.max.apply(Math, numbers);", "Math.max(...numbers);", None, FixKind::Suggestion),
+        ("foo.apply(null, a.b(c));", "foo(...a.b(c));", None, FixKind::Suggestion),
+        ("foo.apply(null, a ? b : c);", "foo(...a ? b : c);", None, FixKind::Suggestion),
+        ("foo?.apply(undefined, args);", "foo?.(...args);", None, FixKind::Suggestion),
+        ("(obj?.foo).apply(obj, args);", "(obj?.foo)(...args);", None, FixKind::Suggestion),
+        // thisArg is no longer evaluated twice.
+        (
+            "a.b.c.foo.apply(a.b.c, arguments);",
+            "a.b.c.foo(...arguments);",
+            None,
+            FixKind::Suggestion,
+        ),
+        ("[].concat.apply([ ], args);", "[].concat(...args);", None, FixKind::Suggestion),
+        // Not fixed.
+        (
+            "foo.apply(null /* this */, args);",
+            "foo.apply(null /* this */, args);",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "foo.apply(null, args /* args */);",
+            "foo.apply(null, args /* args */);",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "foo.apply?.(undefined, args);",
+            "foo.apply?.(undefined, args);",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "(foo?.apply)(undefined, args);",
+            "(foo?.apply)(undefined, args);",
+            None,
+            FixKind::Suggestion,
+        ),
+    ];
+
+    Tester::new(PreferSpread::NAME, PreferSpread::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -1,7 +1,10 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{AssignmentTarget, BindingPattern, CallExpression, PropertyKey},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -9,7 +12,9 @@ use crate::{AstNode, context::LintContext, rule::Rule};
 pub struct SymbolDescription;
 
 fn symbol_description_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Expected Symbol to have a description.").with_label(span)
+    OxcDiagnostic::warn("Expected Symbol to have a description.")
+        .with_help("Pass a description to `Symbol`, which is shown when the symbol is logged.")
+        .with_label(span)
 }
 
 declare_oxc_lint!(
@@ -47,9 +52,16 @@ declare_oxc_lint!(
     /// ```javascript
     /// var foo = Symbol("some description");
     /// ```
+    ///
+    /// Only calls of the global `Symbol` are checked. `Symbol.for("key")` always has a
+    /// description, and `new Symbol()` throws a `TypeError`, so neither is reported.
+    ///
+    /// When the symbol is assigned to a variable or a property, the suggested fix uses
+    /// its name as the description.
     SymbolDescription,
     eslint,
     pedantic,
+    suggestion
 );
 
 impl Rule for SymbolDescription {
@@ -62,12 +74,72 @@ impl Rule for SymbolDescription {
             return;
         };
 
-        if ident.name == "Symbol"
-            && call_expr.arguments.is_empty()
-            && ctx.scoping().root_unresolved_references().contains_key(ident.name.as_str())
+        if ident.name != "Symbol"
+            || !call_expr.arguments.is_empty()
+            || ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some()
         {
-            ctx.diagnostic(symbol_description_diagnostic(call_expr.span));
+            return;
         }
+
+        let diagnostic = symbol_description_diagnostic(call_expr.span);
+        let Some(name) = assigned_name(node, ctx) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            // `const foo = Symbol()` -> `const foo = Symbol("foo")`
+            let arguments = arguments_span(call_expr, ctx);
+            fixer
+                .replace(arguments, format!("({})", serde_json::to_string(name).unwrap()))
+                .with_message(format!("Add the description `{name}`"))
+        });
+    }
+}
+
+/// `( )` in `Symbol?.( )`
+fn arguments_span(call_expr: &CallExpression, ctx: &LintContext) -> Span {
+    let start = call_expr
+        .type_arguments
+        .as_ref()
+        .map_or(call_expr.callee.span().end, |type_arguments| type_arguments.span.end);
+    let span = Span::new(start, call_expr.span.end);
+    let open_paren = ctx.source_range(span).find('(').unwrap_or_default();
+    Span::new(start + u32::try_from(open_paren).unwrap(), span.end)
+}
+
+/// The name of the variable or property the symbol is assigned to.
+fn assigned_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let parent = ctx.nodes().ancestors(node.id()).find(|ancestor| {
+        !matches!(
+            ancestor.kind(),
+            AstKind::ParenthesizedExpression(_) | AstKind::ChainExpression(_)
+        )
+    })?;
+    match parent.kind() {
+        // `const foo = Symbol()`
+        AstKind::VariableDeclarator(decl) => match &decl.id {
+            BindingPattern::BindingIdentifier(ident) => Some(ident.name.as_str()),
+            _ => None,
+        },
+        // `{ foo: Symbol() }`
+        AstKind::ObjectProperty(prop) if !prop.computed => property_key_name(&prop.key),
+        // `class A { foo = Symbol() }`
+        AstKind::PropertyDefinition(prop) if !prop.computed => property_key_name(&prop.key),
+        // `foo = Symbol()`, `this.foo = Symbol()`
+        AstKind::AssignmentExpression(assign) => match &assign.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.as_str()),
+            AssignmentTarget::StaticMemberExpression(member) => Some(member.property.name.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn property_key_name<'a>(key: &PropertyKey<'a>) -> Option<&'a str> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+        PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+        _ => None,
     }
 }
 
@@ -82,9 +154,41 @@ fn test() {
         "Symbol(); var Symbol = function () {};",
         "function bar() { var Symbol = function () {}; Symbol(); }",
         "function bar(Symbol) { Symbol(); }",
+        r#"Symbol.for("key");"#,
+        "new Symbol();",
+        r#"Symbol("Foo"); function bar() { var Symbol = function () {}; Symbol(); }"#,
+        "import { Symbol } from './polyfill'; Symbol();",
+        "Symbol.iterator;",
+    ];
+
+    let fail = vec![
+        "Symbol();",
+        "Symbol(); Symbol = function () {};",
+        "function bar() { return Symbol(); }",
+        "const foo = Symbol();",
+        "const foo = (Symbol());",
+        "const obj = { foo: Symbol(), 'bar-baz': Symbol() };",
+        "class A { foo = Symbol(); }",
+        "this.foo = Symbol();",
+        "foo = Symbol();",
+        "Symbol?.();",
     ];
 
-    let fail = vec!["Symbol();", "Symbol(); Symbol = function () {};"];
+    let fix = vec![
+        ("const foo = Symbol();", r#"const foo = Symbol("foo");"#),
+        ("const foo = Symbol( );", r#"const foo = Symbol("foo");"#),
+        (
+            "const obj = { 'bar-baz': Symbol() };",
+            r#"const obj = { 'bar-baz': Symbol("bar-baz") };"#,
+        ),
+        ("class A { foo = Symbol(); }", r#"class A { foo = Symbol("foo"); }"#),
+        ("this.foo = Symbol();", r#"this.foo = Symbol("foo");"#),
+        ("foo = Symbol();", r#"foo = Symbol("foo");"#),
+        ("const foo = Symbol?.();", r#"const foo = Symbol?.("foo");"#),
+        ("Symbol();", "Symbol();"),
+    ];
 
-    Tester::new(SymbolDescription::NAME, SymbolDescription::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(SymbolDescription::NAME, SymbolDescription::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
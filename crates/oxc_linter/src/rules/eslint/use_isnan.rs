@@ -17,7 +17,7 @@ use crate::{
 
 fn comparison_with_na_n(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Requires calls to `isNaN()` when checking for NaN")
-        .with_help("Use the `isNaN` function to compare with NaN.")
+        .with_help("Use `Number.isNaN()` to check for NaN.")
         .with_label(span)
 }
 
@@ -83,6 +83,23 @@ declare_oxc_lint!(
     /// foo === NaN;
     /// foo <= NaN;
     /// foo > NaN;
+    ///
+    /// switch (foo) {
+    ///   case NaN:
+    ///     break;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// Number.isNaN(foo);
+    /// !Number.isNaN(foo);
+    ///
+    /// // `x !== x` is only true for NaN.
+    /// if (foo !== foo) {}
+    ///
+    /// // `includes` finds NaN, unlike `indexOf`.
+    /// [NaN].includes(NaN);
     /// ```
     UseIsnan,
     eslint,
@@ -95,33 +112,37 @@ impl Rule for UseIsnan {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BinaryExpression(expr) if expr.operator.is_compare() => {
-                if is_nan_identifier(&expr.left) {
+                if is_nan_identifier(&expr.left, ctx) {
                     ctx.diagnostic(comparison_with_na_n(expr.left.span()));
                 }
-                if is_nan_identifier(&expr.right) {
+                if is_nan_identifier(&expr.right, ctx) {
                     ctx.diagnostic(comparison_with_na_n(expr.right.span()));
                 }
             }
             AstKind::BinaryExpression(expr) if expr.operator.is_equality() => {
-                if is_nan_identifier(&expr.left) {
-                    ctx.diagnostic_with_fix(comparison_with_na_n(expr.left.span()), |fixer| {
-                        fixer.replace(expr.span, make_equality_fix(true, expr, ctx))
-                    });
-                }
-                if is_nan_identifier(&expr.right) {
-                    ctx.diagnostic_with_fix(comparison_with_na_n(expr.right.span()), |fixer| {
-                        fixer.replace(expr.span, make_equality_fix(false, expr, ctx))
-                    });
+                for (nan_on_left, operand) in [(true, &expr.left), (false, &expr.right)] {
+                    if !is_nan_identifier(operand, ctx) {
+                        continue;
+                    }
+                    let diagnostic = comparison_with_na_n(operand.span());
+                    // `Number.isNaN` can't be used if `Number` is shadowed.
+                    if ctx.scoping().find_binding(node.scope_id(), "Number").is_none() {
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fixer.replace(expr.span, make_equality_fix(nan_on_left, expr, ctx))
+                        });
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
                 }
             }
             AstKind::SwitchCase(case) if self.enforce_for_switch_case => {
                 let Some(test) = &case.test else { return };
-                if is_nan_identifier(test) {
+                if is_nan_identifier(test, ctx) {
                     ctx.diagnostic(case_na_n(test.span()));
                 }
             }
             AstKind::SwitchStatement(switch) if self.enforce_for_switch_case => {
-                if is_nan_identifier(&switch.discriminant) {
+                if is_nan_identifier(&switch.discriminant, ctx) {
                     ctx.diagnostic(switch_na_n(switch.discriminant.span()));
                 }
             }
@@ -134,7 +155,7 @@ impl Rule for UseIsnan {
                 // NaN
                 let Some(method) = is_target_callee(&call.callee) else { return };
                 if let Some(expr) = call.arguments[0].as_expression()
-                    && is_nan_identifier(expr)
+                    && is_nan_identifier(expr, ctx)
                 {
                     ctx.diagnostic(index_of_na_n(method, expr.span()));
                 }
@@ -150,8 +171,23 @@ impl Rule for UseIsnan {
     }
 }
 
-fn is_nan_identifier<'a>(expr: &'a Expression<'a>) -> bool {
-    expr.is_specific_id("NaN") || expr.is_specific_member_access("Number", "NaN")
+/// `NaN` or `Number.NaN`, where `NaN` or `Number` refer to the global.
+fn is_nan_identifier<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    let expr = expr.get_inner_expression();
+    if let Expression::Identifier(ident) = expr {
+        return ident.name == "NaN" && ctx.is_reference_to_global_variable(ident);
+    }
+    if !expr.is_specific_member_access("Number", "NaN") {
+        return false;
+    }
+    let member = match expr {
+        Expression::ChainExpression(chain) => chain.expression.as_member_expression(),
+        expr => expr.as_member_expression(),
+    };
+    member.is_some_and(|member| match member.object().get_inner_expression() {
+        Expression::Identifier(ident) => ctx.is_reference_to_global_variable(ident),
+        _ => false,
+    })
 }
 
 /// If callee is calling the `indexOf` or `lastIndexOf` function.
@@ -192,7 +228,7 @@ fn make_equality_fix<'a>(
         _ => unreachable!(),
     };
 
-    format!("{maybe_bang}isNaN({non_nan})")
+    format!("{maybe_bang}Number.isNaN({non_nan})")
 }
 
 #[test]
@@ -376,6 +412,22 @@ fn test() {
         ),
         ("foo.indexOf(...Number.NaN)", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
         ("foo.lastIndexOf(Number.NaN())", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
+        // `includes` uses SameValueZero, which finds `NaN`.
+        ("foo.includes(NaN)", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
+        ("foo.includes(Number.NaN)", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
+        // The `x !== x` idiom.
+        ("if (x !== x) {}", None),
+        // Shadowed `NaN` and `Number`.
+        ("function f(NaN) { return x === NaN; }", None),
+        ("let Number = { NaN: 1 }; x === Number.NaN;", None),
+        (
+            "function f(NaN) { switch (x) { case NaN: break; } }",
+            Some(serde_json::json!([{ "enforceForSwitchCase": true }])),
+        ),
+        (
+            "function f(NaN) { foo.indexOf(NaN); }",
+            Some(serde_json::json!([{ "enforceForIndexOf": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -524,21 +576,30 @@ fn test() {
         ("foo.indexOf?.(Number.NaN)", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
         ("foo?.indexOf(Number.NaN)", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
         ("(foo?.indexOf)(Number.NaN)", Some(serde_json::json!([{ "enforceForIndexOf": true }]))),
+        ("x === (NaN);", None),
+        ("function f(Number) { return x === NaN; }", None),
     ];
 
     let fix = vec![
-        ("1 == NaN", "isNaN(1)", None),
-        ("1 === NaN", "isNaN(1)", None),
-        ("1 != NaN", "!isNaN(1)", None),
-        ("1 !== NaN", "!isNaN(1)", None),
-        ("NaN == 'foo'", "isNaN('foo')", None),
-        ("NaN === 'foo'", "isNaN('foo')", None),
-        ("NaN != 'foo'", "!isNaN('foo')", None),
-        ("NaN !== 'foo'", "!isNaN('foo')", None),
-        ("1 == Number.NaN", "isNaN(1)", None),
-        ("1 === Number.NaN", "isNaN(1)", None),
-        ("1 != Number.NaN", "!isNaN(1)", None),
-        ("1 !== Number.NaN", "!isNaN(1)", None),
+        ("1 == NaN", "Number.isNaN(1)", None),
+        ("1 === NaN", "Number.isNaN(1)", None),
+        ("1 != NaN", "!Number.isNaN(1)", None),
+        ("1 !== NaN", "!Number.isNaN(1)", None),
+        ("NaN == 'foo'", "Number.isNaN('foo')", None),
+        ("NaN === 'foo'", "Number.isNaN('foo')", None),
+        ("NaN != 'foo'", "!Number.isNaN('foo')", None),
+        ("NaN !== 'foo'", "!Number.isNaN('foo')", None),
+        ("1 == Number.NaN", "Number.isNaN(1)", None),
+        ("1 === Number.NaN", "Number.isNaN(1)", None),
+        ("1 != Number.NaN", "!Number.isNaN(1)", None),
+        ("1 !== Number.NaN", "!Number.isNaN(1)", None),
+        ("x === (NaN)", "Number.isNaN(x)", None),
+        ("if (a.b !== NaN) {}", "if (!Number.isNaN(a.b)) {}", None),
+        (
+            "function f(Number) { return x === NaN; }",
+            "function f(Number) { return x === NaN; }",
+            None,
+        ),
     ];
 
     Tester::new(UseIsnan::NAME, UseIsnan::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
@@ -1,8 +1,14 @@
+use std::path::{Component, Path, PathBuf};
+
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{AstNode, ast_util::is_global_require_call, context::LintContext, rule::Rule};
 
 fn no_self_import_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("A module importing itself is not allowed").with_label(span)
@@ -24,11 +30,17 @@ declare_oxc_lint!(
     ///
     /// ### Examples
     ///
+    /// Static imports and re-exports are checked with the module resolver of the import plugin.
+    /// `require()` calls and dynamic `import()`s with a relative specifier are resolved
+    /// against the path of the current file, with or without the file extension.
+    ///
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
     /// // foo.js
     /// import foo from './foo.js';  // Incorrect: module imports itself
+    /// export * from './foo';       // Incorrect: module re-exports itself
     /// const foo = require('./foo'); // Incorrect: module imports itself
+    /// import('./foo.js');          // Incorrect: module imports itself
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -56,6 +68,75 @@ impl Rule for NoSelfImport {
             }
         }
     }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (span, specifier) = match node.kind() {
+            // `import('./foo')`
+            AstKind::ImportExpression(import) => {
+                let Expression::StringLiteral(source) = &import.source else { return };
+                (source.span, source.value.as_str())
+            }
+            // `require('./foo')`
+            AstKind::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+                let Some(Argument::StringLiteral(source)) = call.arguments.first() else {
+                    return;
+                };
+                (source.span, source.value.as_str())
+            }
+            _ => return,
+        };
+        if is_self_specifier(specifier, ctx.file_path()) {
+            ctx.diagnostic(no_self_import_diagnostic(span));
+        }
+    }
+}
+
+/// Resolves a relative `specifier` against the directory of `path`, without accessing the
+/// file system, the same way the module resolver would, and checks whether it resolves to
+/// `path` itself:
+///
+/// * `./foo.js` and `./foo` in `foo.js`, but not `./foo.ts`, which is another file.
+/// * `./foo.js` in `foo.ts`, as TypeScript maps JavaScript extensions to TypeScript ones.
+/// * `.` and `../dir` in `dir/index.js`.
+fn is_self_specifier(specifier: &str, path: &Path) -> bool {
+    let is_relative = matches!(specifier, "." | "..")
+        || specifier.starts_with("./")
+        || specifier.starts_with("../");
+    let Some(dir) = path.parent().filter(|_| is_relative) else {
+        return false;
+    };
+
+    let mut target = PathBuf::new();
+    for component in dir.join(specifier).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                target.pop();
+            }
+            component => target.push(component),
+        }
+    }
+
+    // `./foo.js`, or `./foo`, which the resolver completes with the extension of the file
+    if target == path || target == path.with_extension("") {
+        return true;
+    }
+    // `./foo.js` in `foo.ts`
+    let is_typescript_counterpart = matches!(
+        (
+            target.extension().and_then(|ext| ext.to_str()),
+            path.extension().and_then(|ext| ext.to_str()),
+        ),
+        (Some("js"), Some("ts" | "tsx"))
+            | (Some("jsx"), Some("tsx"))
+            | (Some("mjs"), Some("mts"))
+            | (Some("cjs"), Some("cts"))
+    );
+    if is_typescript_counterpart && target.with_extension("") == path.with_extension("") {
+        return true;
+    }
+    // `.` in `index.js`
+    path.file_stem().is_some_and(|stem| stem == "index") && dir == target
 }
 
 #[test]
@@ -83,8 +164,15 @@ fn test() {
 
         let fail = vec![
             "import bar from './no-self-import'",
-            // "var bar = require('./no-self-import')",
-            // "var bar = require('./no-self-import.js')",
+            "import bar from './no-self-import.js'",
+            "import './no-self-import'",
+            "export * from './no-self-import'",
+            "export { bar } from './no-self-import.js'",
+            "import('./no-self-import')",
+            "import('./no-self-import.js')",
+            "var bar = require('./no-self-import')",
+            "var bar = require('./no-self-import.js')",
+            "var bar = require('../import/no-self-import')",
         ];
 
         Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
@@ -93,37 +181,73 @@ fn test() {
             .test();
     }
 
-    // {
-    // let pass = vec!["var bar = require('./bar')"];
-    // let fail = vec![];
-
-    // Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
-    // .with_import_plugin(true)
-    // .change_rule_path("bar/index.js")
-    // .test();
-    // }
-
-    // {
-    // let pass = vec![];
-    // let fail = vec![
-    // "var bar = require('.')",
-    // "var bar = require('./')",
-    // "var bar = require('././././')",
-    // ];
-
-    // Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
-    // .with_import_plugin(true)
-    // .change_rule_path("index.js")
-    // .test();
-    // }
-
-    // {
-    // let pass = vec![];
-    // let fail = vec!["var bar = require('../no-self-import-folder')"];
-
-    // Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
-    // .with_import_plugin(true)
-    // .change_rule_path("no-self-import-folder/index.js")
-    // .test();
-    // }
+    {
+        let pass = vec!["var bar = require('./bar')", "import('./bar')"];
+        let fail = vec![];
+
+        Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("bar/index.js")
+            .test();
+    }
+
+    {
+        let pass = vec![];
+        let fail = vec![
+            "var bar = require('.')",
+            "var bar = require('./')",
+            "var bar = require('././././')",
+            "import('./index.js')",
+        ];
+
+        Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("index.js")
+            .test();
+    }
+
+    {
+        let pass = vec![];
+        let fail = vec![
+            "var bar = require('../no-self-import-folder')",
+            "import('../no-self-import-folder/index')",
+        ];
+
+        Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-self-import-folder/index.js")
+            .test();
+    }
+
+    {
+        let pass = vec![
+            "var data = require('./no-self-import.json')",
+            "import('./no-self-import.mjs')",
+            "import('./no-self-import.d.ts')",
+        ];
+        let fail = vec![
+            "import('./no-self-import.js')",
+            "import('./no-self-import.ts')",
+            "var bar = require('./no-self-import')",
+        ];
+
+        Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-self-import.ts")
+            .test();
+    }
+
+    {
+        let pass = vec![
+            "import('./no-self-import.ts')",
+            "var bar = require('./no-self-import.jsx')",
+            "var bar = require('./no-self-import.cjs')",
+        ];
+        let fail = vec!["import('./no-self-import.js')"];
+
+        Tester::new(NoSelfImport::NAME, NoSelfImport::PLUGIN, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-self-import.js")
+            .test();
+    }
 }
@@ -0,0 +1,216 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, MemberExpression, VariableDeclarationKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn ignored_promises_diagnostic(method: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Promises returned by the async callback of `{method}` are ignored"))
+        .with_help(if method == "forEach" {
+            "Rejections are not handled, and the loop doesn't wait for the callbacks. Use a `for...of` loop with `await`, or `await Promise.all(array.map(async ...))`."
+        } else {
+            "Rejections are not handled. Wait for the promises with `await Promise.all(...)`."
+        })
+        .with_label(span.label("This callback is async"))
+}
+
+fn always_truthy_diagnostic(method: &str, span: Span) -> OxcDiagnostic {
+    let consequence = match method {
+        "filter" => "so `filter` keeps every element",
+        "find" => "so `find` always returns the first element",
+        "findLast" => "so `findLast` always returns the last element",
+        "findIndex" => "so `findIndex` always returns the first index",
+        "findLastIndex" => "so `findLastIndex` always returns the last index",
+        "some" => "so `some` returns `true` for any non-empty array",
+        _ => "so `every` always returns `true`",
+    };
+    OxcDiagnostic::warn(format!(
+        "The async callback of `{method}` returns a promise, which is always truthy, {consequence}"
+    ))
+    .with_help(format!("Wait for the results first, e.g. with `await Promise.all(array.map(async ...))`, and then call `{method}` with a synchronous callback."))
+    .with_label(span.label("This callback is async"))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAsyncInArrayMethods;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow async callbacks in array methods which don't handle the returned promises.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An async function always returns a promise, and these array methods don't wait for it:
+    ///
+    /// * `forEach` ignores the promises, so rejections are not handled, and the
+    ///   code after the loop runs before the callbacks are done.
+    /// * `filter`, `find`, `findLast`, `findIndex`, `findLastIndex`, `some` and `every` use the
+    ///   promise as a condition, which is always truthy. `filter` keeps every element.
+    /// * `map` and `flatMap` return an array of promises, which is a mistake if it's unused.
+    ///   Passing it to `Promise.all` or storing it is fine.
+    ///
+    /// Async callbacks of `reduce` and `reduceRight` are not reported, as the callback can await
+    /// the previous result, which is a promise too.
+    ///
+    /// Callbacks are recognized as async when they are async functions, or references to
+    /// async function declarations or `const` variables initialized with an async function.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// users.forEach(async (user) => {
+    ///   await save(user);
+    /// });
+    ///
+    /// const active = users.filter(async (user) => await isActive(user));
+    ///
+    /// users.map(async (user) => await save(user));
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// for (const user of users) {
+    ///   await save(user);
+    /// }
+    ///
+    /// await Promise.all(users.map(async (user) => await save(user)));
+    ///
+    /// const flags = await Promise.all(users.map(isActive));
+    /// const active = users.filter((_, i) => flags[i]);
+    /// ```
+    NoAsyncInArrayMethods,
+    oxc,
+    suspicious
+);
+
+impl Rule for NoAsyncInArrayMethods {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(method) =
+            call.callee.get_member_expr().and_then(MemberExpression::static_property_name)
+        else {
+            return;
+        };
+        let Some(callback) = call.arguments.first().and_then(Argument::as_expression) else {
+            return;
+        };
+
+        match method {
+            "forEach" => {}
+            "map" | "flatMap" => {
+                if !is_result_unused(node, ctx) {
+                    return;
+                }
+            }
+            "filter" | "find" | "findLast" | "findIndex" | "findLastIndex" | "some" | "every" => {
+                if let Some(span) = async_function_span(callback, ctx) {
+                    ctx.diagnostic(always_truthy_diagnostic(method, span));
+                }
+                return;
+            }
+            _ => return,
+        }
+        if let Some(span) = async_function_span(callback, ctx) {
+            ctx.diagnostic(ignored_promises_diagnostic(method, span));
+        }
+    }
+}
+
+/// `array.map(...);`
+fn is_result_unused(node: &AstNode, ctx: &LintContext) -> bool {
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        match ancestor.kind() {
+            AstKind::ExpressionStatement(_) => return true,
+            AstKind::ParenthesizedExpression(_) | AstKind::ChainExpression(_) => {}
+            AstKind::UnaryExpression(unary) if unary.operator.is_void() => return true,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// The span of the async function `expr` is, or refers to.
+fn async_function_span(expr: &Expression, ctx: &LintContext) -> Option<Span> {
+    if is_async_function(expr) {
+        return Some(expr.without_parentheses().span());
+    }
+    let Expression::Identifier(ident) = expr.without_parentheses() else {
+        return None;
+    };
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+    let is_async = match ctx.semantic().symbol_declaration(symbol_id).kind() {
+        AstKind::Function(func) => func.r#async && !func.generator,
+        // `const save = async () => {}`
+        AstKind::VariableDeclarator(decl) => {
+            decl.kind == VariableDeclarationKind::Const
+                && decl.init.as_ref().is_some_and(is_async_function)
+        }
+        _ => false,
+    };
+    is_async.then_some(ident.span)
+}
+
+/// Async generators return async iterators, not promises.
+fn is_async_function(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+        Expression::FunctionExpression(func) => func.r#async && !func.generator,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "arr.forEach((x) => console.log(x));",
+        "arr.forEach(function (x) { console.log(x); });",
+        "await Promise.all(arr.map(async (x) => await save(x)));",
+        "const promises = arr.map(async (x) => await save(x));",
+        "function f() { return arr.map(async (x) => await save(x)); }",
+        "Promise.allSettled(arr.flatMap(async (x) => [await load(x)]));",
+        "const results = arr.filter((x) => x.active);",
+        "arr.reduce(async (acc, x) => (await acc) + x, Promise.resolve(0));",
+        "arr.reduceRight(async (acc, x) => { await acc; return save(x); }, Promise.resolve());",
+        "arr.forEach(async function* (x) {});",
+        "function f() {} arr.forEach(f);",
+        "let f = async () => {}; f = () => {}; arr.forEach(f);",
+        "arr.forEach(callback);",
+        "arr.then(async (x) => x);",
+        "arr[method](async (x) => x);",
+        "arr.forEach(...callbacks);",
+    ];
+
+    let fail = vec![
+        "arr.forEach(async (x) => { await save(x); });",
+        "arr.forEach(async function (x) { await save(x); });",
+        "arr?.forEach(async (x) => await save(x));",
+        "arr['forEach'](async (x) => await save(x));",
+        "arr.forEach((async (x) => await save(x)));",
+        "arr.map(async (x) => await save(x));",
+        "void arr.map(async (x) => await save(x));",
+        "(arr.flatMap(async (x) => [await load(x)]));",
+        "const active = arr.filter(async (x) => await isActive(x));",
+        "const first = arr.find(async (x) => await isActive(x));",
+        "const last = arr.findLast(async (x) => await isActive(x));",
+        "const index = arr.findIndex(async (x) => await isActive(x));",
+        "const index = arr.findLastIndex(async (x) => await isActive(x));",
+        "if (arr.some(async (x) => await isActive(x))) {}",
+        "if (arr.every(async (x) => await isActive(x))) {}",
+        "async function save(x) {} arr.forEach(save);",
+        "const isActive = async (x) => x.active; arr.filter(isActive);",
+        "const save = async function (x) {}; arr.forEach(save);",
+    ];
+
+    Tester::new(NoAsyncInArrayMethods::NAME, NoAsyncInArrayMethods::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
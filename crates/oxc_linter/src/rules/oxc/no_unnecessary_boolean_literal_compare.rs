@@ -0,0 +1,272 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryExpression, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unnecessary_boolean_literal_compare_diagnostic(span: Span, negated: bool) -> OxcDiagnostic {
+    let help = if negated {
+        "Negate the expression instead of comparing it to a boolean literal."
+    } else {
+        "Use the expression directly instead of comparing it to a boolean literal."
+    };
+    OxcDiagnostic::warn("Unnecessary comparison of a boolean expression to a boolean literal.")
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryBooleanLiteralCompare;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows comparisons of boolean expressions to boolean literals, e.g. `a < b === true`.
+    ///
+    /// This is a syntactic version of `typescript/no-unnecessary-boolean-literal-compare`, which
+    /// doesn't need type information. An expression is only known to be a boolean if it is:
+    ///
+    /// * A negation (`!x`), a comparison (`a === b`, `a < b`, `a in b`, `a instanceof b`)
+    ///   or a `delete` expression.
+    /// * A call of the global `Boolean`, `isNaN` or `isFinite`, or of `Array.isArray`, `Object.is`,
+    ///   `Object.hasOwn`, `Object.isExtensible`, `Object.isFrozen`, `Object.isSealed`,
+    ///   `Number.isFinite`, `Number.isInteger`, `Number.isNaN` or `Number.isSafeInteger`.
+    /// * `a && b`, `a || b` or `c ? a : b` where `a` and `b` are booleans.
+    ///
+    /// Other expressions, e.g. variables, may be `null`, `undefined` or not booleans at all,
+    /// and are never reported.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Comparing a boolean to `true` or `false` is redundant, and makes the code harder to read.
+    /// As both sides are booleans, `==` and `===` behave the same.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// if (items.length > 0 === true) {}
+    /// if (Array.isArray(value) === false) {}
+    /// const missing = !(key in map) == true;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// if (items.length > 0) {}
+    /// if (!Array.isArray(value)) {}
+    /// const missing = !(key in map);
+    /// // `isReady` may be `null` or `undefined`.
+    /// if (isReady === false) {}
+    /// ```
+    NoUnnecessaryBooleanLiteralCompare,
+    oxc,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoUnnecessaryBooleanLiteralCompare {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(expr) = node.kind() else {
+            return;
+        };
+        let is_equality = match expr.operator {
+            BinaryOperator::Equality | BinaryOperator::StrictEquality => true,
+            BinaryOperator::Inequality | BinaryOperator::StrictInequality => false,
+            _ => return,
+        };
+        let Some((literal, operand)) = boolean_literal_and_operand(expr) else {
+            return;
+        };
+        let operand = operand.without_parentheses();
+        if !is_boolean_expression(operand, ctx) {
+            return;
+        }
+        // `x === true` and `x !== false` -> `x`, `x === false` and `x !== true` -> `!x`
+        let negated = literal != is_equality;
+        let diagnostic = no_unnecessary_boolean_literal_compare_diagnostic(expr.span, negated);
+        if ctx.has_comments_between(expr.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let text = ctx.source_range(operand.span());
+            let replacement = match (negated, needs_parentheses(operand, negated)) {
+                (false, false) => text.to_string(),
+                (false, true) => format!("({text})"),
+                (true, false) => format!("!{text}"),
+                (true, true) => format!("!({text})"),
+            };
+            fixer.replace(expr.span, replacement)
+        });
+    }
+}
+
+/// The value of the boolean literal, and the other side of the comparison.
+fn boolean_literal_and_operand<'e, 'a>(
+    expr: &'e BinaryExpression<'a>,
+) -> Option<(bool, &'e Expression<'a>)> {
+    match (expr.left.without_parentheses(), expr.right.without_parentheses()) {
+        (Expression::BooleanLiteral(_), Expression::BooleanLiteral(_)) => None,
+        (Expression::BooleanLiteral(literal), _) => Some((literal.value, &expr.right)),
+        (_, Expression::BooleanLiteral(literal)) => Some((literal.value, &expr.left)),
+        _ => None,
+    }
+}
+
+fn is_boolean_expression(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.without_parentheses() {
+        Expression::UnaryExpression(unary) => {
+            matches!(unary.operator, UnaryOperator::LogicalNot | UnaryOperator::Delete)
+        }
+        Expression::BinaryExpression(binary) => {
+            binary.operator.is_equality()
+                || binary.operator.is_compare()
+                || binary.operator.is_relational()
+        }
+        Expression::PrivateInExpression(_) => true,
+        Expression::LogicalExpression(logical) => {
+            logical.operator != LogicalOperator::Coalesce
+                && is_boolean_expression(&logical.left, ctx)
+                && is_boolean_expression(&logical.right, ctx)
+        }
+        Expression::ConditionalExpression(conditional) => {
+            is_boolean_expression(&conditional.consequent, ctx)
+                && is_boolean_expression(&conditional.alternate, ctx)
+        }
+        Expression::CallExpression(call) => {
+            !call.optional && is_boolean_function(&call.callee, ctx)
+        }
+        _ => false,
+    }
+}
+
+/// Global functions which always return a boolean.
+fn is_boolean_function(callee: &Expression, ctx: &LintContext) -> bool {
+    match callee.without_parentheses() {
+        Expression::Identifier(ident) => {
+            matches!(ident.name.as_str(), "Boolean" | "isNaN" | "isFinite")
+                && ctx.is_reference_to_global_variable(ident)
+        }
+        Expression::StaticMemberExpression(member) => {
+            let Expression::Identifier(object) = &member.object else {
+                return false;
+            };
+            let is_boolean_method = match object.name.as_str() {
+                "Array" => member.property.name == "isArray",
+                "Object" => matches!(
+                    member.property.name.as_str(),
+                    "is" | "hasOwn" | "isExtensible" | "isFrozen" | "isSealed"
+                ),
+                "Number" => matches!(
+                    member.property.name.as_str(),
+                    "isFinite" | "isInteger" | "isNaN" | "isSafeInteger"
+                ),
+                _ => false,
+            };
+            !member.optional && is_boolean_method && ctx.is_reference_to_global_variable(object)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` must be parenthesized to replace the comparison, or to be negated.
+fn needs_parentheses(expr: &Expression, negated: bool) -> bool {
+    match expr {
+        // `a === b === true` -> `a === b`, but `a === b === false` -> `!(a === b)`
+        Expression::BinaryExpression(_) | Expression::PrivateInExpression(_) => negated,
+        Expression::LogicalExpression(_) | Expression::ConditionalExpression(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "x === true",
+        "x == false",
+        "isReady === false",
+        "x?.y === true",
+        "f() === true",
+        "a ?? b === true",
+        "(a ?? b) === true",
+        "(a && b) === true",
+        "(a || !b) === false",
+        "(c ? a : b) === true",
+        "(c ? !a : b) === true",
+        "Boolean?.(x) === true",
+        "Array?.isArray(x) === true",
+        "Array.from(x) === true",
+        "new Boolean(x) === true",
+        "!x === 1",
+        "!x === 'true'",
+        "!x < true",
+        "true === false",
+        "var Boolean; Boolean(x) === true",
+        "function f(Array) { return Array.isArray(x) === true; }",
+    ];
+
+    let fail = vec![
+        "!x === true",
+        "!x == true",
+        "!x !== false",
+        "!x === false",
+        "!x !== true",
+        "true === !x",
+        "(!x) === (true)",
+        "a < b === true",
+        "a === b == false",
+        "key in map !== true",
+        "x instanceof Foo === true",
+        "class C { #x; f() { return #x in this === true; } }",
+        "delete a.b === true",
+        "Boolean(x) === true",
+        "Array.isArray(x) === false",
+        "Object.is(a, b) === true",
+        "Number.isNaN(x) !== true",
+        "isFinite(x) === true",
+        "(!a && b < c) === true",
+        "(a > b || !c) === false",
+        "(c ? !a : b > 1) === true",
+        "!x /* comment */ === true",
+    ];
+
+    let fix = vec![
+        ("!x === true", "!x"),
+        ("!x == true", "!x"),
+        ("!x !== false", "!x"),
+        ("!x === false", "!!x"),
+        ("!x !== true", "!!x"),
+        ("true === !x", "!x"),
+        ("(!x) === (true)", "!x"),
+        ("if (a < b === true) {}", "if (a < b) {}"),
+        ("if (a < b === false) {}", "if (!(a < b)) {}"),
+        ("a === b == false", "!(a === b)"),
+        ("key in map !== true", "!(key in map)"),
+        (
+            "class C { #x; f() { return #x in this === false; } }",
+            "class C { #x; f() { return !(#x in this); } }",
+        ),
+        ("Boolean(x) === true", "Boolean(x)"),
+        ("if (Array.isArray(x) === false) {}", "if (!Array.isArray(x)) {}"),
+        ("x = (!a && b < c) === true", "x = (!a && b < c)"),
+        ("x = (a > b || !c) === false", "x = !(a > b || !c)"),
+        ("x = (c ? !a : b > 1) === true", "x = (c ? !a : b > 1)"),
+        ("!x /* comment */ === true", "!x /* comment */ === true"),
+    ];
+
+    Tester::new(
+        NoUnnecessaryBooleanLiteralCompare::NAME,
+        NoUnnecessaryBooleanLiteralCompare::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
@@ -0,0 +1,449 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, AssignmentExpression, AssignmentTarget, AwaitExpression, Class,
+        Expression, ForOfStatement, Function, IdentifierReference, SimpleAssignmentTarget,
+        UpdateExpression, VariableDeclarator, YieldExpression,
+    },
+};
+use oxc_ast_visit::{
+    Visit,
+    walk::{walk_assignment_target, walk_expression, walk_for_of_statement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::FxHashMap;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unsafe_member_access_after_await_diagnostic(
+    name: &str,
+    write_span: Span,
+    await_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is written with a value read before an `await`."))
+        .with_help(format!(
+            "`{name}` may have been changed while the function was suspended. Read it again after the `await`."
+        ))
+        .with_labels([
+            await_span.label("The function is suspended here"),
+            write_span.primary_label(format!("`{name}` is written here")),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeMemberAccessAfterAwait;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows writing a variable or a member of an object from outside of an async function
+    /// with a value which was read from it before an `await`, without reading it again after the `await`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// While an async function is suspended at an `await`, other code can run and change
+    /// the variables and objects it shares with the function. A value read before the `await`
+    /// may be stale afterwards, and writing a value which was computed from it loses the changes
+    /// made in the meantime.
+    ///
+    /// This rule is a heuristic. To avoid false positives, it only checks:
+    ///
+    /// * Variables declared outside of the function, and their members, e.g. `counter` or `this.count`.
+    ///   Variables declared in the function, including parameters, can't be changed by other code.
+    /// * Writes whose value is computed from a read before an `await`, either directly,
+    ///   e.g. `total += await getAmount()`, or through a variable declared in the function.
+    ///   Values which are only read are never reported.
+    ///
+    /// Code is checked in source order, so branches and loops are not taken into account.
+    /// Nested functions are checked separately.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// let total = 0;
+    /// async function add(item) {
+    ///   total += await getPrice(item);
+    /// }
+    ///
+    /// async function increment() {
+    ///   const count = store.count;
+    ///   await save(count + 1);
+    ///   store.count = count + 1;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// let total = 0;
+    /// async function add(item) {
+    ///   const price = await getPrice(item);
+    ///   total += price;
+    /// }
+    ///
+    /// async function increment() {
+    ///   await save();
+    ///   store.count = store.count + 1;
+    /// }
+    ///
+    /// async function update() {
+    ///   const count = store.count;
+    ///   await save(count + 1);
+    ///   if (store.count === count) {
+    ///     store.count = count + 1;
+    ///   }
+    /// }
+    /// ```
+    NoUnsafeMemberAccessAfterAwait,
+    oxc,
+    suspicious,
+);
+
+impl Rule for NoUnsafeMemberAccessAfterAwait {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::FunctionBody(body) = node.kind() else {
+            return;
+        };
+        let function_span = match ctx.nodes().parent_kind(node.id()) {
+            AstKind::Function(func) if func.r#async => func.span,
+            AstKind::ArrowFunctionExpression(arrow) if arrow.r#async => arrow.span,
+            _ => return,
+        };
+        let mut finder = StaleWriteFinder {
+            ctx,
+            function_span,
+            suspensions: vec![],
+            last_reads: FxHashMap::default(),
+            locals: FxHashMap::default(),
+            frames: vec![],
+        };
+        finder.visit_function_body(body);
+    }
+}
+
+/// A variable from outside of the function, or a static member path of it, e.g. `a.b.c`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key<'a> {
+    root: Root<'a>,
+    /// Member path, e.g. `.b.c`, or empty for the variable itself.
+    path: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Root<'a> {
+    Symbol(SymbolId),
+    Global(Atom<'a>),
+    This,
+}
+
+impl Key<'_> {
+    /// Returns `true` if `self` is `other` or a member of it.
+    fn is_within(&self, other: &Self) -> bool {
+        self.root == other.root
+            && self
+                .path
+                .strip_prefix(other.path.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    }
+
+    /// `a.b.c` -> `a`, `a.b`, `a.b.c`
+    fn prefixes(&self) -> impl Iterator<Item = Self> + '_ {
+        self.path
+            .match_indices('.')
+            .map(|(i, _)| i)
+            .chain([self.path.len()])
+            .map(|end| Key { root: self.root, path: self.path[..end].to_string() })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Read<'a> {
+    key: Key<'a>,
+    /// Number of suspensions before the read.
+    epoch: usize,
+}
+
+struct StaleWriteFinder<'s, 'a> {
+    ctx: &'s LintContext<'a>,
+    function_span: Span,
+    /// Spans of `await` expressions, in the order they are evaluated.
+    suspensions: Vec<Span>,
+    /// Epoch of the last read of each key.
+    last_reads: FxHashMap<Key<'a>, usize>,
+    /// Reads which the values of variables declared in the function are computed from.
+    locals: FxHashMap<SymbolId, Vec<Read<'a>>>,
+    /// Reads of the expressions being evaluated.
+    frames: Vec<Vec<Read<'a>>>,
+}
+
+impl<'a> StaleWriteFinder<'_, 'a> {
+    fn epoch(&self) -> usize {
+        self.suspensions.len()
+    }
+
+    fn suspend(&mut self, span: Span) {
+        self.suspensions.push(span);
+    }
+
+    fn symbol_of(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+    }
+
+    fn is_local(&self, symbol_id: SymbolId) -> bool {
+        self.function_span.contains_inclusive(self.ctx.scoping().symbol_span(symbol_id))
+    }
+
+    fn identifier_key(&self, ident: &IdentifierReference<'a>) -> Option<Key<'a>> {
+        let root = match self.symbol_of(ident) {
+            Some(symbol_id) if self.is_local(symbol_id) => return None,
+            Some(symbol_id) => Root::Symbol(symbol_id),
+            None => Root::Global(ident.name),
+        };
+        Some(Key { root, path: String::new() })
+    }
+
+    fn expression_key(&self, expr: &Expression<'a>) -> Option<Key<'a>> {
+        match expr.without_parentheses() {
+            Expression::Identifier(ident) => self.identifier_key(ident),
+            Expression::ThisExpression(_) => Some(Key { root: Root::This, path: String::new() }),
+            Expression::StaticMemberExpression(member) => {
+                let mut key = self.expression_key(&member.object)?;
+                key.path.push('.');
+                key.path.push_str(&member.property.name);
+                Some(key)
+            }
+            _ => None,
+        }
+    }
+
+    fn simple_target_key(&self, target: &SimpleAssignmentTarget<'a>) -> Option<Key<'a>> {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => self.identifier_key(ident),
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let mut key = self.expression_key(&member.object)?;
+                key.path.push('.');
+                key.path.push_str(&member.property.name);
+                Some(key)
+            }
+            _ => None,
+        }
+    }
+
+    fn read(&mut self, key: Key<'a>) {
+        let epoch = self.epoch();
+        for prefix in key.prefixes() {
+            self.last_reads.insert(prefix, epoch);
+        }
+        if let Some(frame) = self.frames.last_mut() {
+            frame.push(Read { key, epoch });
+        }
+    }
+
+    fn read_local(&mut self, symbol_id: SymbolId) {
+        if let (Some(reads), Some(frame)) = (self.locals.get(&symbol_id), self.frames.last_mut()) {
+            frame.extend(reads.iter().cloned());
+        }
+    }
+
+    /// Collects the reads of the expressions evaluated by `f`.
+    fn collect_reads(&mut self, f: impl FnOnce(&mut Self)) -> Vec<Read<'a>> {
+        self.frames.push(vec![]);
+        f(self);
+        let reads = self.frames.pop().unwrap();
+        if let Some(frame) = self.frames.last_mut() {
+            frame.extend(reads.iter().cloned());
+        }
+        reads
+    }
+
+    fn check_write(&self, key: &Key<'a>, reads: &[Read<'a>], expr: &AssignmentExpression<'a>) {
+        let epoch = self.epoch();
+        if self.last_reads.get(key) == Some(&epoch) {
+            return;
+        }
+        let Some(stale) = reads.iter().find(|read| read.epoch < epoch && read.key.is_within(key))
+        else {
+            return;
+        };
+        let name = expr.left.span().source_text(self.ctx.source_text());
+        self.ctx.diagnostic(no_unsafe_member_access_after_await_diagnostic(
+            name,
+            expr.span,
+            self.suspensions[stale.epoch],
+        ));
+    }
+}
+
+impl<'a> Visit<'a> for StaleWriteFinder<'_, 'a> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if let Some(key) = self.expression_key(expr) {
+            self.read(key);
+            return;
+        }
+        walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        match self.symbol_of(ident) {
+            Some(symbol_id) if self.is_local(symbol_id) => self.read_local(symbol_id),
+            _ => {
+                if let Some(key) = self.identifier_key(ident) {
+                    self.read(key);
+                }
+            }
+        }
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        let simple_target = expr.left.as_simple_assignment_target();
+        let key = simple_target.and_then(|target| self.simple_target_key(target));
+        let local = match &expr.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.symbol_of(ident).filter(|symbol_id| self.is_local(*symbol_id))
+            }
+            _ => None,
+        };
+        match &expr.left {
+            AssignmentTarget::AssignmentTargetIdentifier(_) => {}
+            AssignmentTarget::StaticMemberExpression(member) => {
+                self.visit_expression(&member.object);
+            }
+            left => walk_assignment_target(self, left),
+        }
+
+        let reads = self.collect_reads(|finder| {
+            // `a += b` reads `a` before `b` is evaluated.
+            if expr.operator != AssignmentOperator::Assign {
+                if let Some(key) = &key {
+                    finder.read(key.clone());
+                } else if let Some(symbol_id) = local {
+                    finder.read_local(symbol_id);
+                }
+            }
+            finder.visit_expression(&expr.right);
+        });
+
+        if let Some(key) = &key {
+            self.check_write(key, &reads, expr);
+        } else if let Some(symbol_id) = local {
+            self.locals.insert(symbol_id, reads);
+        }
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        // `a++` reads and writes `a` without suspending.
+        if let Some(key) = self.simple_target_key(&expr.argument) {
+            self.read(key);
+            return;
+        }
+        self.visit_simple_assignment_target(&expr.argument);
+    }
+
+    fn visit_variable_declarator(&mut self, decl: &VariableDeclarator<'a>) {
+        let reads = self.collect_reads(|finder| {
+            if let Some(init) = &decl.init {
+                finder.visit_expression(init);
+            }
+        });
+        self.visit_binding_pattern(&decl.id);
+        decl.id.bound_names(&mut |ident| {
+            self.locals.insert(ident.symbol_id(), reads.clone());
+        });
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        self.visit_expression(&expr.argument);
+        self.suspend(expr.span);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if let Some(argument) = &expr.argument {
+            self.visit_expression(argument);
+        }
+        self.suspend(expr.span);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if !stmt.r#await {
+            walk_for_of_statement(self, stmt);
+            return;
+        }
+        self.visit_expression(&stmt.right);
+        self.suspend(stmt.right.span());
+        self.visit_for_statement_left(&stmt.left);
+        self.visit_statement(&stmt.body);
+    }
+
+    // Nested functions are checked separately.
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "let total = 0; async function f(item) { const price = await getPrice(item); total += price; }",
+        "async function f() { await save(); store.count = store.count + 1; }",
+        "async function f() { await save(); store.count++; }",
+        // Read again after the `await`.
+        "async function f() { const count = store.count; await save(); if (store.count === count) { store.count = count + 1; } }",
+        "let total = 0; async function f() { total = total + 1; await save(); }",
+        // Values which are only read.
+        "async function f() { const count = store.count; await save(); log(count); }",
+        // Written values which don't depend on earlier reads.
+        "async function f() { if (state.loading) return; state.loading = true; await load(); state.loading = false; }",
+        "async function f() { const count = store.count; await save(); store.other = count; }",
+        // Variables declared in the function.
+        "async function f() { let n = 0; const c = n; await x; n = c + 1; }",
+        "async function f(obj) { const c = obj.n; await x; obj.n = c + 1; }",
+        "async function f() { let local = 0; local += await x; }",
+        // Local consts.
+        "async function f() { const data = await load(); const copy = data.value; await save(); data.value = copy; }",
+        // Not async.
+        "function f() { const count = store.count; save(); store.count = count + 1; }",
+        "let total = 0; function* f() { total += yield x; }",
+        // Nested functions are separate.
+        "async function f() { const count = store.count; await save(); const g = () => { store.count = count + 1; }; }",
+        "async function f() { const count = store.count; setTimeout(async () => { await x; }); store.count = count + 1; }",
+        // Reads of a member of the object are not reads of another member.
+        "async function f() { const c = store; await save(); store.count = c.count + 1; }",
+        // The value is read after the `await` in the same expression.
+        "let total = 0; async function f() { total = (await getAmount()) + total; }",
+        "async () => { await x; store.count = store.count + 1; }",
+    ];
+
+    let fail = vec![
+        "let total = 0; async function f(item) { total += await getPrice(item); }",
+        "let total = 0; async function f(item) { total = total + await getPrice(item); }",
+        "async function f() { const count = store.count; await save(count + 1); store.count = count + 1; }",
+        "async function f() { const count = store.nested.count; await save(); store.nested.count = count; }",
+        // The whole object is replaced.
+        "async function f() { const count = store.count; await save(); store = { count: count + 1 }; }",
+        "class C { async inc() { const count = this.count; await save(); this.count = count + 1; } }",
+        "const inc = async () => { const count = counter.value; await delay(); counter.value = count + 1; };",
+        "const inc = async () => counter.value = counter.value + await delay();",
+        // Through several variables declared in the function.
+        "async function f() { const a = store.count; let b = a * 2; b = b + 1; await save(); store.count = b; }",
+        "async function f() { const count = store.count; for await (const x of xs) {} store.count = count + 1; }",
+        "let total = 0; async function* f() { const t = total; yield; total = t + 1; }",
+        "async function f() { const count = window.count; await save(); window.count = count + 1; }",
+    ];
+
+    Tester::new(
+        NoUnsafeMemberAccessAfterAwait::NAME,
+        NoUnsafeMemberAccessAfterAwait::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -4,7 +4,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::Span;
 
 use crate::{
     AstNode,
@@ -64,12 +64,17 @@ declare_oxc_lint!(
     /// import { type A as AA, type B as BB } from 'mod';
     /// ```
     ///
+    /// Imports with a value specifier, a default import or a namespace import are
+    /// not reported, as they are kept at runtime anyway.
+    ///
     /// Examples of **correct** code for this rule:
     /// ```ts
     /// import type { A } from 'mod';
     /// import type { A as AA } from 'mod';
     /// import type { A, B } from 'mod';
     /// import type { A as AA, B as BB } from 'mod';
+    /// import { type A, B } from 'mod';
+    /// import C, { type A } from 'mod';
     /// ```
     NoImportTypeSideEffects,
     typescript,
@@ -87,7 +92,8 @@ impl Rule for NoImportTypeSideEffects {
             return;
         }
 
-        let Some(specifiers) = &import_decl.specifiers else {
+        // `import {} from 'mod'` is a side effect import already.
+        let Some(specifiers) = import_decl.specifiers.as_ref().filter(|s| !s.is_empty()) else {
             return;
         };
 
@@ -119,10 +125,12 @@ impl Rule for NoImportTypeSideEffects {
                 for specifier in type_specifiers {
                     // import { type    A } from 'foo.js'
                     //          ^^^^^^^^
-                    fix.push(Fix::delete(Span::new(
-                        specifier.span.start,
-                        specifier.imported.span().start,
-                    )));
+                    // Comments after `type` are kept.
+                    let after_type = Span::new(specifier.span.start + 4, specifier.span.end);
+                    let rest = ctx.source_range(after_type);
+                    #[expect(clippy::cast_possible_truncation)]
+                    let end = after_type.start + (rest.len() - rest.trim_start().len()) as u32;
+                    fix.push(Fix::delete(Span::new(specifier.span.start, end)));
                 }
 
                 fix.with_message("Convert to top-level type import")
@@ -152,6 +160,9 @@ fn test() {
         "import T, { type U } from 'mod';",
         "import type * as T from 'mod';",
         "import 'mod';",
+        "import {} from 'mod';",
+        "import type {} from 'mod';",
+        "import { type A, default as B } from 'mod';",
     ];
 
     let fail = vec![
@@ -159,6 +170,9 @@ fn test() {
         "import { type A as AA } from 'mod';",
         "import { type A, type B } from 'mod';",
         "import { type A as AA, type B as BB } from 'mod';",
+        "import { type default as A } from 'mod';",
+        "import { type A, type B } from 'mod' with { type: 'json' };",
+        "import {\n  type A,\n  type B,\n} from 'mod';",
     ];
 
     let fix = vec![
@@ -170,6 +184,18 @@ fn test() {
             "import type { A as AA, B as BB } from 'mod';",
             None,
         ),
+        (
+            "import { type default as A } from 'mod';",
+            "import type { default as A } from 'mod';",
+            None,
+        ),
+        (
+            "import {\n  type A,\n  type B,\n} from 'mod';",
+            "import type {\n  A,\n  B,\n} from 'mod';",
+            None,
+        ),
+        ("import { type /* A */ A } from 'mod';", "import type { /* A */ A } from 'mod';", None),
+        ("import { type\n  A } from 'mod';", "import type { A } from 'mod';", None),
     ];
     Tester::new(NoImportTypeSideEffects::NAME, NoImportTypeSideEffects::PLUGIN, pass, fail)
         .expect_fix(fix)
@@ -3,7 +3,7 @@ use oxc_ast::{
     AstBuilder, AstKind,
     ast::{
         ArrayExpression, ArrayExpressionElement, CallExpression, Expression, NewExpression,
-        ObjectExpression, ObjectPropertyKind, SpreadElement,
+        ObjectExpression, ObjectPropertyKind, PropertyKind, SpreadElement,
     },
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -83,6 +83,11 @@ declare_oxc_lint!(
     ///   - Spread an object literal as properties of an object literal
     ///   - Use spread syntax to clone an array created inline
     ///
+    /// Spreads of object literals with getters, setters or a `__proto__` property are
+    /// not reported, as they are not copied as-is. Holes in spread array literals
+    /// become `void 0` when fixed, e.g. `foo(...[a, , b])` is fixed to `foo(a, void 0, b)`, as
+    /// `undefined` could be shadowed.
+    ///
     /// ### Why is this bad?
     ///
     /// The following builtins accept an iterable, so it's unnecessary to
@@ -198,6 +203,11 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -
         AstKind::ObjectExpression(inner_obj) => {
             // { ...{ } }
             if let AstKind::ObjectExpression(outer_obj) = parent_parent.kind() {
+                // `{ ...{ get a() {} } }` calls the getter, and `{ ...{ __proto__: a } }`
+                // doesn't copy the prototype, so the spread isn't useless
+                if has_accessor_or_proto(inner_obj) {
+                    return false;
+                }
                 ctx.diagnostic_with_fix(spread_in_list(span, "object"), |fixer| {
                     fix_by_removing_object_spread(fixer, spread_elem, inner_obj, outer_obj)
                 });
@@ -222,7 +232,21 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -
                 }) && call_expr_args_span.contains_inclusive(array_expr.span)
                 {
                     // compute replacer before the closure so we don't capture `ctx` by reference inside the fixer closure
-                    let replacer = if let Some(first) = array_expr.elements.first() {
+                    let replacer = if array_expr
+                        .elements
+                        .iter()
+                        .any(ArrayExpressionElement::is_elision)
+                    {
+                        // `foo(...[a, , b])` -> `foo(a, void 0, b)`, as `foo(a, , b)` is a syntax error
+                        array_expr
+                            .elements
+                            .iter()
+                            .map(|el| {
+                                if el.is_elision() { "void 0" } else { ctx.source_range(el.span()) }
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    } else if let Some(first) = array_expr.elements.first() {
                         let mut snippet_span = first.span();
                         if array_expr.elements.len() != 1 {
                             let last = array_expr.elements.last().unwrap();
@@ -255,9 +279,10 @@ fn diagnose_array_in_array_spread<'a>(
     outer_array: &'a ArrayExpression<'a>,
     inner_array: &ArrayExpression<'a>,
 ) {
+    let has_holes = inner_array.elements.iter().any(ArrayExpressionElement::is_elision);
     match outer_array.elements.len() {
         0 => unreachable!(),
-        1 => {
+        1 if !has_holes => {
             ctx.diagnostic_with_fix(diagnostic, |fixer| {
                 fixer.replace_with(&outer_array.span, inner_array)
             });
@@ -278,6 +303,7 @@ fn diagnose_array_in_array_spread<'a>(
             }
 
             // [ ...[a, b, c], ...[d, e, f] ] -> [a, b, c, d, e, f]
+            // Holes are spread as `undefined`: [ ...[a, , b] ] -> [a, void 0, b]
             ctx.diagnostic_with_fix(diagnostic, |fixer| {
                 let mut codegen = fixer.codegen();
                 codegen.print_ascii_byte(b'[');
@@ -286,11 +312,7 @@ fn diagnose_array_in_array_spread<'a>(
                 let n = elements.len();
                 for (i, el) in elements.into_iter().enumerate() {
                     match el {
-                        ArrayExpressionElement::Elision(_) => {
-                            if i == n - 1 {
-                                codegen.print_ascii_byte(b',');
-                            }
-                        }
+                        ArrayExpressionElement::Elision(_) => codegen.print_str("void 0"),
                         ArrayExpressionElement::SpreadElement(spread) => {
                             codegen.print_str("...");
                             codegen.print_expression(&spread.argument);
@@ -492,6 +514,21 @@ fn fix_by_removing_object_spread<'a>(
     fixer.replace(outer_obj.span, codegen.into_source_text())
 }
 
+/// Checks if `obj` has a getter, a setter or a `__proto__: value` property, which are
+/// not copied as-is by a spread.
+fn has_accessor_or_proto(obj: &ObjectExpression) -> bool {
+    obj.properties.iter().any(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop) => {
+            prop.kind != PropertyKind::Init
+                || (!prop.computed
+                    && !prop.shorthand
+                    && !prop.method
+                    && prop.key.static_name().is_some_and(|name| name == "__proto__"))
+        }
+        ObjectPropertyKind::SpreadProperty(_) => false,
+    })
+}
+
 /// Checks if `node` is `[...(expr)]`
 fn as_single_array_spread<'a, 's>(node: &'s ArrayExpression<'a>) -> Option<&'s SpreadElement<'a>> {
     if node.elements.len() != 1 {
@@ -604,6 +641,10 @@ fn test() {
         r"const obj = { ...obj, ...(addFoo ? { foo: 'foo' } : {}) }",
         r"<Button {...(isLoading ? { data: undefined } : { data: dataFromApi })} />",
         r"const obj = { ...(foo ? getObjectInOpaqueManner() : { a: 2 }) }",
+        r"const obj = { a, ...{ get b() { return 1; } } }",
+        r"const obj = { a, ...{ set b(v) {} } }",
+        r"const obj = { ...{ __proto__: null, a } }",
+        r"const obj = { ...{ '__proto__': proto } }",
         "[...arr.reduce((set, b) => set.add(b), new Set())]",
         "[...arr.reduce((set, b) => set.add(b), new Set(iter))]",
         // NOTE: we may want to consider this a violation in the future
@@ -745,7 +786,8 @@ fn test() {
         ("[...[1,2,3], ...[4,5,6]]", "[1, 2, 3, 4, 5, 6]"),
         ("[...[1,2,3], ...x]", "[...[1,2,3], ...x]"),
         ("[...[...[1,2,3]]]", "[...[1,2,3]]"),
-        ("const array = [...[a, , b,]]", "const array = [a, , b,]"),
+        ("const array = [...[a, , b,]]", "const array = [a, void 0, b]"),
+        ("const array = [...[,]]", "const array = [void 0]"),
         // object literals
         ("const obj = { a, ...{ b, c } }", "const obj = ({\n\ta,\n\tb,\n\tc\n})"),
         ("const obj = { a, ...{ b, c, } }", "const obj = ({\n\ta,\n\tb,\n\tc\n})"),
@@ -770,7 +812,15 @@ fn test() {
         // Issue: <https://github.com/oxc-project/oxc/issues/8115>
         ("setupServer(...[...importHandlers])", "setupServer(...importHandlers)"),
         ("setupServer(...[1, 2, 3])", "setupServer(1, 2, 3)"),
-        ("[...[1,2,,,],...[3,4,,,]]", "[1, 2, , , 3, 4, , ,]"),
+        ("[...[1,2,,,],...[3,4,,,]]", "[1, 2, void 0, void 0, 3, 4, void 0, void 0]"),
+        ("foo(...[a, , b,])", "foo(a, void 0, b)"),
+        ("new Foo(...[,])", "new Foo(void 0)"),
+        ("foo(x, ...[a, , ...b])", "foo(x, a, void 0, ...b)"),
+        ("function f(undefined) { g(...[a, , b]); }", "function f(undefined) { g(a, void 0, b); }"),
+        (
+            "const obj = { ...{ [__proto__]: a, __proto__, b() {} } }",
+            "const obj = ({\n\t[__proto__]: a,\n\t__proto__,\n\tb() {}\n})",
+        ),
         ("[...[...foo], ...[...bar]]", "[...foo, ...bar]"),
         ("S={...{ }}", "S=({})"),
         (
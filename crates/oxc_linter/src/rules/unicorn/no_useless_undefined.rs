@@ -52,6 +52,20 @@ declare_oxc_lint!(
     ///
     /// `undefined` is the default value for new variables, parameters, return statements, etc… so specifying it doesn't make any difference.
     ///
+    /// The rule reports `undefined` in `return undefined`, `yield undefined`,
+    /// `() => undefined`, `let foo = undefined`, default values (`function foo(bar = undefined)`,
+    /// `const { foo = undefined } = bar`), and trailing arguments (`foo(bar, undefined)`).
+    /// Arguments and arrow function bodies can be ignored with the options below.
+    ///
+    /// It doesn't report:
+    /// - `const foo = undefined`, as a `const` declaration must have an initializer.
+    /// - `undefined` followed by other arguments, e.g. `foo(undefined, bar)`, which is needed
+    ///   to pass the later arguments.
+    /// - Comparisons like `foo === undefined`, and `void 0`.
+    /// - A local variable named `undefined`, which may have another value.
+    /// - Arguments of functions which treat an `undefined` argument differently from a missing
+    ///   one, e.g. `map.set(key, undefined)` or `expect(foo).toBe(undefined)`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -128,13 +142,13 @@ fn is_function_bind_call(call_expr: &CallExpression) -> bool {
     !call_expr.optional && is_method_call(call_expr, None, Some(&["bind"]), None, None)
 }
 
-fn is_undefined(arg: &Argument) -> bool {
+fn is_undefined(arg: &Argument, ctx: &LintContext) -> bool {
     if !arg.is_expression() {
         return false;
     }
     let expr: &Expression = arg.to_expression();
-    if let Expression::Identifier(_) = expr {
-        return expr.is_undefined();
+    if let Expression::Identifier(ident) = expr {
+        return expr.is_undefined() && ctx.is_reference_to_global_variable(ident);
     }
     false
 }
@@ -161,7 +175,8 @@ impl Rule for NoUselessUndefined {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IdentifierReference(undefined_literal)
-                if undefined_literal.name == "undefined" =>
+                if undefined_literal.name == "undefined"
+                    && ctx.is_reference_to_global_variable(undefined_literal) =>
             {
                 let mut parent_node: &AstNode<'a> = node;
                 loop {
@@ -310,7 +325,7 @@ impl Rule for NoUselessUndefined {
                 let mut undefined_args_spans = Vec::new();
                 for i in (0..arguments.len()).rev() {
                     let arg = &arguments[i];
-                    if is_undefined(arg) {
+                    if is_undefined(arg, ctx) {
                         let span = arg.span();
                         undefined_args_spans.insert(0, span);
                     } else {
@@ -363,6 +378,16 @@ fn test() {
         (r"foo();", None),
         (r"foo(bar,);", None),
         (r"foo(undefined, bar);", None),
+        (r"foo(undefined, undefined, bar);", None),
+        (r"function foo() {return void 0;}", None),
+        (r"let foo = void 0;", None),
+        (r"foo(bar, void 0);", None),
+        (r"if (foo === undefined) {}", None),
+        (r"if (foo == undefined) {}", None),
+        (r"const isMissing = foo !== undefined;", None),
+        (r"function foo(undefined) {return undefined;}", None),
+        (r"function foo(undefined) {bar(baz, undefined);}", None),
+        (r"function foo(undefined) {let bar = undefined;}", None),
         (r"const {foo} = {};", None),
         (r"function foo({bar} = {}) {}", None),
         (r"function foo(bar) {}", None),
@@ -567,6 +592,8 @@ fn test() {
             None,
         ),
         (r"function foo([bar = undefined] = []) {}", None),
+        (r"function foo(undefined) {} let bar = undefined;", None),
+        (r"function foo() {return undefined;} function bar(undefined) {}", None),
         (
             r"
 			foo(
@@ -13,6 +13,7 @@ impl<'a> PeepholeOptimizations {
     ///
     /// `foo['bar']` -> `foo.bar`
     /// `foo?.['bar']` -> `foo?.bar`
+    /// `foo['0']` -> `foo[0]`
     pub fn convert_to_dotted_properties(expr: &mut MemberExpression<'a>, ctx: &Ctx<'a, '_>) {
         let MemberExpression::ComputedMemberExpression(e) = expr else { return };
         let Expression::StringLiteral(s) = &e.expression else { return };
@@ -271,4 +272,21 @@ mod test {
         test("x['-2147483648']", "x[-2147483648]");
         test_same("x['-2147483649']");
     }
+
+    #[test]
+    fn test_convert_to_dotted_properties_special_keys() {
+        // Member access to `__proto__` is the same regardless of the syntax used,
+        // unlike `{ __proto__: x }` vs `{ ['__proto__']: x }` in object literals.
+        test("x['__proto__']", "x.__proto__");
+        test("x['__proto__'] = y", "x.__proto__ = y");
+        test("x['constructor']['prototype']", "x.constructor.prototype");
+        // Reserved words are valid property names in ES5+.
+        test("x['class'] = x['if'] + x['this']", "x.class = x.if + x.this");
+        test("x['let']['await']['yield']", "x.let.await.yield");
+        // Assignment targets and update expressions are converted as well.
+        test("x['y'] = 1", "x.y = 1");
+        test("x['y']++", "x.y++");
+        test("[x['y']] = z", "[x.y] = z");
+        test("delete x['y']", "delete x.y");
+    }
 }
@@ -1082,6 +1082,7 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_simple_unary_expression(&mut self, lhs_span: u32) -> Expression<'a> {
         match self.cur_kind() {
             kind if kind.is_unary_operator() => self.parse_unary_expression(),
+            Kind::Throw if self.options.allow_throw_expressions => self.parse_unary_expression(),
             Kind::LAngle => {
                 if self.source_type.is_jsx() {
                     return self.parse_jsx_expression();
@@ -1493,6 +1494,7 @@ impl<'a> ParserImpl<'a> {
         match self.cur_kind() {
             kind if kind.is_unary_operator() => false,
             Kind::Await => false,
+            Kind::Throw if self.options.allow_throw_expressions => false,
             Kind::LAngle => {
                 if !self.source_type.is_jsx() {
                     return false;
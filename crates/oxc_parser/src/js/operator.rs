@@ -65,6 +65,7 @@ pub fn map_unary_operator(kind: Kind) -> UnaryOperator {
         Kind::Typeof => UnaryOperator::Typeof,
         Kind::Void => UnaryOperator::Void,
         Kind::Delete => UnaryOperator::Delete,
+        Kind::Throw => UnaryOperator::Throw,
         _ => unreachable!("Unary Operator: {kind:?}"),
     }
 }
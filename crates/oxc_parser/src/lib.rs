@@ -221,6 +221,17 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Allow `throw` in expression position, e.g. `const x = a ?? throw new Error()`.
+    /// See: [proposal-throw-expressions](https://github.com/tc39/proposal-throw-expressions).
+    ///
+    /// Throw expressions are represented as [`UnaryExpression`]s with [`UnaryOperator::Throw`].
+    ///
+    /// Default: `false`
+    ///
+    /// [`UnaryExpression`]: oxc_ast::ast::UnaryExpression
+    /// [`UnaryOperator::Throw`]: oxc_ast::ast::UnaryOperator::Throw
+    pub allow_throw_expressions: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +242,7 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            allow_throw_expressions: false,
         }
     }
 }
@@ -590,7 +602,7 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{CommentKind, Expression, Statement, UnaryOperator};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -711,6 +723,45 @@ mod test {
         }
     }
 
+    #[test]
+    fn throw_expressions() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { allow_throw_expressions: true, ..ParseOptions::default() };
+        let sources = [
+            "const x = a ?? throw new Error()",
+            "const f = () => throw new Error()",
+            "a ? b : throw c",
+            "function f(x = throw new TypeError()) {}",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+        {
+            // `throw` at the start of a statement is still a `ThrowStatement`.
+            let source = "throw e";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(matches!(ret.program.body.first(), Some(Statement::ThrowStatement(_))));
+        }
+        {
+            let source = "x = throw e";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            let Some(Statement::ExpressionStatement(expr_stmt)) = ret.program.body.first() else {
+                panic!("Expected ExpressionStatement");
+            };
+            let Expression::AssignmentExpression(assign) = &expr_stmt.expression else {
+                panic!("Expected AssignmentExpression");
+            };
+            assert!(matches!(
+                &assign.right,
+                Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::Throw
+            ));
+        }
+    }
+
     #[test]
     fn comments() {
         let allocator = Allocator::default();
@@ -1643,23 +1643,20 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             }
             if let Some(finally_block_end_ix) = finally_block_end_ix {
                 let after_try_statement_block_ix = cfg.new_basic_block_normal();
-                if catch_block_end_ix.is_some() {
-                    cfg.add_edge(
-                        finally_block_end_ix,
-                        after_try_statement_block_ix,
-                        EdgeType::Normal,
-                    );
+                // The code after the `try` statement only runs if the `try` or `catch` block
+                // completes normally, e.g. not if both of them `return`.
+                let is_unreachable = cfg.basic_block(after_try_block_graph_ix).is_unreachable()
+                    && catch_block_end_ix.is_none_or(|catch_block_end_ix| {
+                        cfg.basic_block(catch_block_end_ix).is_unreachable()
+                    });
+                let edge_type = if is_unreachable {
+                    EdgeType::Unreachable
+                } else if catch_block_end_ix.is_some() {
+                    EdgeType::Normal
                 } else {
-                    cfg.add_edge(
-                        finally_block_end_ix,
-                        after_try_statement_block_ix,
-                        if cfg.basic_block(after_try_block_graph_ix).is_unreachable() {
-                            EdgeType::Unreachable
-                        } else {
-                            EdgeType::Join
-                        },
-                    );
-                }
+                    EdgeType::Join
+                };
+                cfg.add_edge(finally_block_end_ix, after_try_statement_block_ix, edge_type);
             }
         });
         /* cfg */
@@ -0,0 +1,10 @@
+function foo() {
+    try {
+        return a();
+    } catch (e) {
+        return b();
+    } finally {
+        c();
+    }
+    d();
+}
@@ -172,6 +172,7 @@ impl ESTree for UnaryOperator {
             Self::Typeof => JsonSafeString("typeof").serialize(serializer),
             Self::Void => JsonSafeString("void").serialize(serializer),
             Self::Delete => JsonSafeString("delete").serialize(serializer),
+            Self::Throw => JsonSafeString("throw").serialize(serializer),
         }
     }
 }
@@ -519,6 +519,12 @@ pub enum UnaryOperator {
     /// `delete`
     #[estree(rename = "delete")]
     Delete = 6,
+    /// `throw`
+    ///
+    /// Stage 2 [throw expressions](https://github.com/tc39/proposal-throw-expressions) proposal.
+    /// Only produced when parsing with `ParseOptions::allow_throw_expressions`.
+    #[estree(rename = "throw")]
+    Throw = 7,
 }
 
 impl UnaryOperator {
@@ -554,9 +560,14 @@ impl UnaryOperator {
         self == Self::Delete
     }
 
+    /// Returns `true` if this is the [`throw`](UnaryOperator::Throw) operator.
+    pub fn is_throw(self) -> bool {
+        self == Self::Throw
+    }
+
     /// Returns `true` if this operator is a keyword instead of punctuation.
     pub fn is_keyword(self) -> bool {
-        matches!(self, Self::Typeof | Self::Void | Self::Delete)
+        matches!(self, Self::Typeof | Self::Void | Self::Delete | Self::Throw)
     }
 
     /// Get the string representation of this operator as it appears in source code.
@@ -569,6 +580,7 @@ impl UnaryOperator {
             Self::Typeof => "typeof",
             Self::Void => "void",
             Self::Delete => "delete",
+            Self::Throw => "throw",
         }
     }
 }
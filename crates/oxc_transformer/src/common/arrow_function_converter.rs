@@ -87,14 +87,17 @@
 //! The Implementation based on
 //! <https://github.com/babel/babel/blob/d20b314c14533ab86351ecf6ca6b7296b66a57b3/packages/babel-traverse/src/path/conversion.ts#L170-L247>
 
+use std::mem;
+
 use compact_str::CompactString;
 use indexmap::IndexMap;
 use rustc_hash::{FxBuildHasher, FxHashSet};
 
 use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
-use oxc_ast_visit::{VisitMut, walk_mut::walk_expression};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut::walk_expression};
 use oxc_data_structures::stack::{NonEmptyStack, SparseStack};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::{ReferenceFlags, SymbolId};
 use oxc_span::{GetSpan, SPAN};
 use oxc_syntax::{
@@ -103,8 +106,16 @@ use oxc_syntax::{
 };
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
 
-use crate::{EnvOptions, utils::ast_builder::wrap_expression_in_arrow_function_iife};
-use crate::{context::TraverseCtx, state::TransformState};
+use crate::{
+    EnvOptions,
+    utils::ast_builder::{
+        wrap_expression_in_arrow_function_iife, wrap_expression_in_function_called_with_this,
+    },
+};
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 type FxIndexMap<K, V> = IndexMap<K, V, FxBuildHasher>;
 
@@ -137,7 +148,8 @@ struct SuperMethodInfo<'a> {
     is_computed: bool,
 }
 
-pub struct ArrowFunctionConverter<'a> {
+pub struct ArrowFunctionConverter<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
     mode: ArrowFunctionConverterMode,
     this_var_stack: SparseStack<BoundIdentifier<'a>>,
     arguments_var_stack: SparseStack<BoundIdentifier<'a>>,
@@ -148,10 +160,17 @@ pub struct ArrowFunctionConverter<'a> {
     // Only using `FxIndexMap` for predictable iteration order to match Babel's output.
     super_methods_stack: NonEmptyStack<FxIndexMap<SuperMethodKey<'a>, SuperMethodInfo<'a>>>,
     super_needs_transform_stack: NonEmptyStack<bool>,
+    new_target_var_stack: SparseStack<BoundIdentifier<'a>>,
+    /// Whether the arrow function, or an arrow function nested in it, uses `super`
+    /// or an `arguments` binding which can't be captured. Such arrow functions can't be
+    /// converted to function expressions, and an error is reported.
+    unconvertible_arrow_stack: NonEmptyStack<bool>,
+    /// Set on exiting an arrow function which can't be converted, and read in `exit_expression`.
+    is_unconvertible_arrow: bool,
 }
 
-impl ArrowFunctionConverter<'_> {
-    pub fn new(env: &EnvOptions) -> Self {
+impl<'a, 'ctx> ArrowFunctionConverter<'a, 'ctx> {
+    pub fn new(env: &EnvOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         let mode = if env.es2015.arrow_function.is_some() {
             ArrowFunctionConverterMode::Enabled
         } else if env.es2017.async_to_generator || env.es2018.async_generator_functions {
@@ -161,6 +180,7 @@ impl ArrowFunctionConverter<'_> {
         };
         // `SparseStack`s are created with 1 empty entry, for `Program`
         Self {
+            ctx,
             mode,
             this_var_stack: SparseStack::new(),
             arguments_var_stack: SparseStack::new(),
@@ -169,11 +189,14 @@ impl ArrowFunctionConverter<'_> {
             renamed_arguments_symbol_ids: FxHashSet::default(),
             super_methods_stack: NonEmptyStack::new(FxIndexMap::default()),
             super_needs_transform_stack: NonEmptyStack::new(false),
+            new_target_var_stack: SparseStack::new(),
+            unconvertible_arrow_stack: NonEmptyStack::new(false),
+            is_unconvertible_arrow: false,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
+impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a, '_> {
     // Note: No visitors for `TSModuleBlock` because `this` is not legal in TS module blocks.
     // <https://www.typescriptlang.org/play/?#code/HYQwtgpgzgDiDGEAEAxA9mpBvAsAKCSXjWCgBckANJAXiQAoBKWgPiTIAsBLKAbnwC++fGDQATAK4AbZACEQAJ2z5CxUhWp0mrdtz6D8QA>
 
@@ -190,6 +213,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
             &mut program.body,
             this_var,
             arguments_var,
+            // `new.target` is not allowed at the top level
+            None,
             // `super()` Only allowed in class constructor
             None,
             ctx,
@@ -208,6 +233,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
         debug_assert!(self.super_methods_stack.first().is_empty());
         debug_assert!(self.super_needs_transform_stack.is_exhausted());
         debug_assert!(self.super_needs_transform_stack.first() == &false);
+        debug_assert!(self.new_target_var_stack.is_exhausted());
+        debug_assert!(self.new_target_var_stack.first().is_none());
+        debug_assert!(self.unconvertible_arrow_stack.is_exhausted());
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -219,6 +247,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
         self.arguments_var_stack.push(None);
         self.constructor_super_stack.push(false);
 
+        if !self.is_async_only() {
+            self.new_target_var_stack.push(None);
+            self.arguments_needs_transform_stack.push(false);
+            self.unconvertible_arrow_stack.push(false);
+        }
+
         if Self::is_class_method_like_ancestor(ctx.parent()) {
             self.super_methods_stack.push(FxIndexMap::default());
             self.super_needs_transform_stack.push(func.r#async);
@@ -252,11 +286,20 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
             self.super_methods_stack.pop()
         });
 
+        let new_target_var = if self.is_async_only() {
+            None
+        } else {
+            self.arguments_needs_transform_stack.pop();
+            self.unconvertible_arrow_stack.pop();
+            self.new_target_var_stack.pop()
+        };
+
         self.insert_variable_statement_at_the_top_of_statements(
             scope_id,
             &mut body.statements,
             this_var,
             arguments_var,
+            new_target_var,
             super_methods,
             ctx,
         );
@@ -278,6 +321,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
             }
             self.super_needs_transform_stack
                 .push(arrow.r#async || *self.super_needs_transform_stack.last());
+        } else if !self.is_disabled() {
+            self.arguments_needs_transform_stack.push(true);
+            self.unconvertible_arrow_stack.push(false);
         }
     }
 
@@ -295,12 +341,21 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
                     &mut arrow.body.statements,
                     this_var,
                     None,
+                    None,
                     Some(super_methods),
                     ctx,
                 );
             }
 
             self.super_needs_transform_stack.pop();
+        } else if !self.is_disabled() {
+            self.arguments_needs_transform_stack.pop();
+            // An enclosing arrow function can't be converted either, as `super` or `arguments`
+            // would then refer to the function's own.
+            self.is_unconvertible_arrow = self.unconvertible_arrow_stack.pop();
+            if self.is_unconvertible_arrow {
+                *self.unconvertible_arrow_stack.last_mut() = true;
+            }
         }
     }
 
@@ -344,6 +399,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
             this_var,
             // `arguments` is not allowed to be used in static blocks
             None,
+            // `new.target` in static blocks is replaced with `void 0`
+            None,
             Some(super_methods),
             ctx,
         );
@@ -390,10 +447,23 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
             Expression::ThisExpression(this) => {
                 self.get_this_identifier(this.span, ctx).map(Expression::Identifier)
             }
-            Expression::Super(_) => {
+            Expression::Super(sup) => {
                 *self.constructor_super_stack.last_mut() = true;
+                if !self.is_async_only() && *self.arguments_needs_transform_stack.last() {
+                    // The function an arrow function is converted to would have its own `super`
+                    *self.unconvertible_arrow_stack.last_mut() = true;
+                    self.ctx.error(
+                        OxcDiagnostic::error(
+                            "`super` in arrow functions is not available in the configured target environment.",
+                        )
+                        .with_label(sup.span),
+                    );
+                }
                 return;
             }
+            Expression::MetaProperty(meta) if !self.is_async_only() && meta.meta.name == "new" => {
+                self.get_new_target_expression(meta.span, ctx)
+            }
             Expression::CallExpression(call) => self.transform_call_expression_for_super(call, ctx),
             Expression::AssignmentExpression(assignment) => {
                 self.transform_assignment_expression_for_super(assignment, ctx)
@@ -422,6 +492,25 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
                     // }
                     // ```
                     Some(wrap_expression_in_arrow_function_iife(expr.take_in(ctx.ast), ctx))
+                } else if !self.is_async_only()
+                    && Self::is_outermost_arrow_in_class_property_value(ctx)
+                    && ArrowThisUsageFinder::needs_wrapper(arrow)
+                {
+                    // `this` in a class property value refers to the class instance (or the class
+                    // for a static property), but there is no function to capture it in.
+                    // Wrap the arrow function in a function which is called with `this`,
+                    // so `_this = this` can be inserted into it.
+                    //
+                    // ```js
+                    // class A {
+                    //   static prop = () => this;
+                    // }
+                    // // to
+                    // class A {
+                    //   static prop = function() { return () => this; }.call(this);
+                    // }
+                    // ```
+                    Some(wrap_expression_in_function_called_with_this(expr.take_in(ctx.ast), ctx))
                 } else {
                     return;
                 }
@@ -440,9 +529,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
         }
 
         if let Expression::ArrowFunctionExpression(arrow_function_expr) = expr {
+            let is_unconvertible = mem::take(&mut self.is_unconvertible_arrow);
             // TODO: Here should return early as long as the async-to-generator plugin is enabled,
             // but currently we don't know which plugin is enabled.
-            if self.is_async_only() || arrow_function_expr.r#async {
+            if self.is_async_only() || arrow_function_expr.r#async || is_unconvertible {
                 return;
             }
 
@@ -479,14 +569,15 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
     ) {
         // Do this check here rather than in `transform_binding_identifier_for_arguments`
         // so that the fast path for "no transform required" doesn't require a function call
+        // An `arguments` binding in an arrow function is local to the function it's converted to
         let arguments_needs_transform = *self.arguments_needs_transform_stack.last();
-        if arguments_needs_transform {
+        if arguments_needs_transform && self.is_async_only() {
             self.transform_binding_identifier_for_arguments(ident, ctx);
         }
     }
 }
 
-impl<'a> ArrowFunctionConverter<'a> {
+impl<'a> ArrowFunctionConverter<'a, '_> {
     /// Check if arrow function conversion is disabled
     fn is_disabled(&self) -> bool {
         self.mode == ArrowFunctionConverterMode::Disabled
@@ -511,18 +602,7 @@ impl<'a> ArrowFunctionConverter<'a> {
         // scope. But this does not match output for some of Babel's test cases.
         // <https://github.com/oxc-project/oxc/pull/5840>
         let this_var = self.this_var_stack.last_or_init(|| {
-            let target_scope_id = ctx
-                .scoping()
-                .scope_ancestors(arrow_scope_id)
-                // Skip arrow function scope
-                .skip(1)
-                .find(|&scope_id| {
-                    let scope_flags = ctx.scoping().scope_flags(scope_id);
-                    scope_flags.intersects(
-                        ScopeFlags::Function | ScopeFlags::Top | ScopeFlags::ClassStaticBlock,
-                    ) && !scope_flags.contains(ScopeFlags::Arrow)
-                })
-                .unwrap();
+            let target_scope_id = Self::get_target_scope_id(arrow_scope_id, ctx);
             ctx.generate_uid("this", target_scope_id, SymbolFlags::FunctionScopedVariable)
         });
         // TODO: Add `BoundIdentifier::create_spanned_read_reference_boxed` method (and friends)
@@ -531,6 +611,43 @@ impl<'a> ArrowFunctionConverter<'a> {
         Some(ctx.ast.alloc(this_var.create_spanned_read_reference(span, ctx)))
     }
 
+    /// Find the scope which `this` in the arrow function refers to, where `_this` is inserted.
+    /// Either a non-arrow function, a class static block, or the program.
+    fn get_target_scope_id(arrow_scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        ctx.scoping()
+            .scope_ancestors(arrow_scope_id)
+            // Skip arrow function scope
+            .skip(1)
+            .find(|&scope_id| {
+                let scope_flags = ctx.scoping().scope_flags(scope_id);
+                scope_flags.intersects(
+                    ScopeFlags::Function | ScopeFlags::Top | ScopeFlags::ClassStaticBlock,
+                ) && !scope_flags.contains(ScopeFlags::Arrow)
+            })
+            .unwrap()
+    }
+
+    /// `new.target` -> `_newtarget`, if in an arrow function.
+    ///
+    /// `var _newtarget = new.target;` is inserted by
+    /// [`Self::insert_variable_statement_at_the_top_of_statements`].
+    fn get_new_target_expression(
+        &mut self,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let arrow_scope_id = self.get_scope_id_from_this_affected_block(ctx)?;
+        let target_scope_id = Self::get_target_scope_id(arrow_scope_id, ctx);
+        if !ctx.scoping().scope_flags(target_scope_id).contains(ScopeFlags::Function) {
+            // `new.target` is `undefined` in class static blocks
+            return Some(ctx.ast.void_0(span));
+        }
+        let new_target_var = self.new_target_var_stack.last_or_init(|| {
+            ctx.generate_uid("newtarget", target_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        Some(new_target_var.create_spanned_read_expression(span, ctx))
+    }
+
     /// Traverses upward through ancestor nodes to find the `ScopeId` of the block
     /// that potential affects the `this` expression.
     fn get_scope_id_from_this_affected_block(&self, ctx: &TraverseCtx<'a>) -> Option<ScopeId> {
@@ -679,6 +796,27 @@ impl<'a> ArrowFunctionConverter<'a> {
         }
     }
 
+    /// Check whether the current arrow function is in a class property initializer,
+    /// and not nested in another function or arrow function.
+    /// e.g. `() => x` in `class C { prop = foo(() => x); }`
+    fn is_outermost_arrow_in_class_property_value(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::PropertyDefinitionValue(_) | Ancestor::AccessorPropertyValue(_) => {
+                    return true;
+                }
+                Ancestor::ProgramBody(_)
+                | Ancestor::FunctionParams(_)
+                | Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionParams(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        unreachable!()
+    }
+
     /// Check whether currently in a class property initializer.
     /// e.g. `x` in `class C { prop = [foo(x)]; }`
     fn in_class_property_definition_value(ctx: &TraverseCtx<'a>) -> bool {
@@ -1041,6 +1179,13 @@ impl<'a> ArrowFunctionConverter<'a> {
         let reference_id = ident.reference_id();
         let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id();
 
+        if let Some(symbol_id) = symbol_id
+            && !self.is_async_only()
+        {
+            self.transform_identifier_reference_for_arguments_binding(ident, symbol_id, ctx);
+            return;
+        }
+
         let binding = self.arguments_var_stack.last_or_init(|| {
             if let Some(symbol_id) = symbol_id {
                 let arguments_name = ctx.generate_uid_name("arguments");
@@ -1069,6 +1214,97 @@ impl<'a> ArrowFunctionConverter<'a> {
         ident.name = binding.name;
     }
 
+    /// Transform a reference to an `arguments` binding in an arrow function which is converted
+    /// to a function expression.
+    ///
+    /// A binding defined in the arrow function is unaffected by converting it. One defined outside
+    /// of it would be shadowed by the function's own `arguments`, so it's captured in the enclosing
+    /// function instead, if it's a parameter of that function which is never reassigned:
+    ///
+    /// ```js
+    /// function f(arguments) { return () => arguments; }
+    /// // to
+    /// function f(arguments) { var _arguments = arguments; return function() { return _arguments; }; }
+    /// ```
+    ///
+    /// A binding outside of the enclosing function is shadowed by the enclosing function's own
+    /// `arguments`, which is captured like an unbound `arguments`.
+    ///
+    /// Otherwise, the value at the top of the function could differ from the binding's,
+    /// so the arrow function is not converted, and an error is reported.
+    fn transform_identifier_reference_for_arguments_binding(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let symbol_scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+        let mut arrow_scope_id = None;
+        for scope_id in ctx.scoping().scope_ancestors(ctx.current_scope_id()) {
+            if scope_id == symbol_scope_id {
+                return;
+            }
+            if ctx.scoping().scope_flags(scope_id).contains(ScopeFlags::Arrow) {
+                arrow_scope_id = Some(scope_id);
+                break;
+            }
+        }
+        let Some(arrow_scope_id) = arrow_scope_id else { return };
+
+        let target_scope_id = Self::get_target_scope_id(arrow_scope_id, ctx);
+        let is_capturable = if target_scope_id == symbol_scope_id {
+            Self::is_unmodified_parameter_of_enclosing_function(symbol_id, ctx)
+        } else {
+            ctx.scoping().scope_flags(target_scope_id).contains(ScopeFlags::Function)
+                && ctx.scoping().scope_ancestors(target_scope_id).any(|id| id == symbol_scope_id)
+        };
+        if !is_capturable {
+            *self.unconvertible_arrow_stack.last_mut() = true;
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Arrow functions which use an `arguments` binding of an outer scope are not available in the configured target environment.",
+                )
+                .with_label(ident.span),
+            );
+            return;
+        }
+
+        let binding = self.arguments_var_stack.last_or_init(|| {
+            ctx.generate_uid("arguments", target_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        let reference_id = ident.reference_id();
+        ctx.scoping_mut().get_reference_mut(reference_id).set_symbol_id(binding.symbol_id);
+        ctx.scoping_mut().delete_resolved_reference(symbol_id, reference_id);
+        ctx.scoping_mut().add_resolved_reference(binding.symbol_id, reference_id);
+        ident.name = binding.name;
+    }
+
+    /// Check whether the binding is a parameter of the function enclosing the current
+    /// arrow function, which is never reassigned.
+    fn is_unmodified_parameter_of_enclosing_function(
+        symbol_id: SymbolId,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        if ctx.scoping().symbol_is_mutated(symbol_id) {
+            return false;
+        }
+        let symbol_span = ctx.scoping().symbol_span(symbol_id);
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::FunctionBody(func) => {
+                    return func.params().span.contains_inclusive(symbol_span);
+                }
+                Ancestor::FunctionParams(_)
+                | Ancestor::ProgramBody(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::PropertyDefinitionValue(_)
+                | Ancestor::AccessorPropertyValue(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Transform the binding identifier for `arguments` if it's affected after transformation.
     ///
     /// The main work is to rename the `arguments` binding identifier to a new name.
@@ -1110,6 +1346,25 @@ impl<'a> ArrowFunctionConverter<'a> {
 
         Self::adjust_binding_scope(target_scope_id, &arguments_var, ctx);
 
+        // A parameter named `arguments`,
+        // see [`Self::transform_identifier_reference_for_arguments_binding`].
+        if let Some(symbol_id) = ctx.scoping().get_binding(target_scope_id, "arguments") {
+            let init = ctx.create_bound_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                symbol_id,
+                ReferenceFlags::Read,
+            );
+            return Some(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                arguments_var.create_binding_pattern(ctx),
+                NONE,
+                Some(init),
+                false,
+            ));
+        }
+
         let mut init =
             ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
 
@@ -1146,6 +1401,7 @@ impl<'a> ArrowFunctionConverter<'a> {
         statements: &mut ArenaVec<'a, Statement<'a>>,
         this_var: Option<BoundIdentifier<'a>>,
         arguments_var: Option<BoundIdentifier<'a>>,
+        new_target_var: Option<BoundIdentifier<'a>>,
         super_methods: Option<FxIndexMap<SuperMethodKey, SuperMethodInfo<'a>>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -1153,8 +1409,10 @@ impl<'a> ArrowFunctionConverter<'a> {
         let arguments = self.create_arguments_var_declarator(target_scope_id, arguments_var, ctx);
 
         let super_method_count = super_methods.as_ref().map_or(0, FxIndexMap::len);
-        let declarations_count =
-            usize::from(arguments.is_some()) + super_method_count + usize::from(this_var.is_some());
+        let declarations_count = usize::from(arguments.is_some())
+            + usize::from(new_target_var.is_some())
+            + super_method_count
+            + usize::from(this_var.is_some());
 
         // Exit if no declarations to be inserted
         if declarations_count == 0 {
@@ -1167,6 +1425,23 @@ impl<'a> ArrowFunctionConverter<'a> {
             declarations.push(arguments);
         }
 
+        // `_newtarget = new.target;`
+        if let Some(new_target_var) = new_target_var {
+            let new_target = ctx.ast.expression_meta_property(
+                SPAN,
+                ctx.ast.identifier_name(SPAN, "new"),
+                ctx.ast.identifier_name(SPAN, "target"),
+            );
+            declarations.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                new_target_var.create_binding_pattern(ctx),
+                NONE,
+                Some(new_target),
+                false,
+            ));
+        }
+
         // `_superprop_getSomething = () => super.something;`
         // `_superprop_setSomething = _value => super.something = _value;`
         // `_superprop_set = (_prop, _value) => super[_prop] = _value;`
@@ -1339,3 +1614,40 @@ impl<'a> ConstructorBodyThisAfterSuperInserter<'a, '_> {
         )
     }
 }
+
+/// Visitor for finding whether an arrow function in a class property value uses `this`
+/// or `new.target`, which needs a function to be captured in, and doesn't use `super`.
+#[derive(Default)]
+struct ArrowThisUsageFinder {
+    uses_this: bool,
+    uses_super: bool,
+}
+
+impl ArrowThisUsageFinder {
+    fn needs_wrapper(arrow: &ArrowFunctionExpression<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_arrow_function_expression(arrow);
+        finder.uses_this && !finder.uses_super
+    }
+}
+
+impl<'a> Visit<'a> for ArrowThisUsageFinder {
+    fn visit_this_expression(&mut self, _this: &ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if meta.meta.name == "new" {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_super(&mut self, _super: &Super) {
+        self.uses_super = true;
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {
+        // Functions have their own `this`
+    }
+}
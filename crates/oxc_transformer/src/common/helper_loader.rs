@@ -169,6 +169,12 @@ pub enum Helper {
     DecorateMetadata,
     UsingCtx,
     TaggedTemplateLiteral,
+    ToConsumableArray,
+    SlicedToArray,
+    ToArray,
+    Construct,
+    GlobalThis,
+    PromiseFinally,
 }
 
 impl Helper {
@@ -203,6 +209,12 @@ impl Helper {
             Self::DecorateMetadata => "decorateMetadata",
             Self::UsingCtx => "usingCtx",
             Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::Construct => "construct",
+            Self::GlobalThis => "globalThis",
+            Self::PromiseFinally => "promiseFinally",
         }
     }
 
@@ -25,6 +25,10 @@
 //!     Atom::from("React"),
 //!     symbol_id
 //! );
+//!
+//! // ESM: import 'polyfill';
+//! // CJS: require('polyfill');
+//! self.ctx.module_imports.add_side_effect_import(Atom::from("polyfill"));
 //! ```
 //!
 //! > NOTE: Using `import` or `require` is determined by [`TransformCtx::source_type`].
@@ -118,6 +122,16 @@ impl<'a> ModuleImportsStore<'a> {
         self.add_import(source, Import::Named(NamedImport { imported, local }), front);
     }
 
+    /// Add side-effect-only `import` or `require` to top of program.
+    ///
+    /// * `import 'source';` or
+    /// * `require('source');`
+    ///
+    /// Does nothing if an import from `source` has already been added.
+    pub fn add_side_effect_import(&self, source: Atom<'a>) {
+        self.imports.borrow_mut().entry(source).or_default();
+    }
+
     /// Returns `true` if no imports have been scheduled for insertion.
     pub fn is_empty(&self) -> bool {
         self.imports.borrow().is_empty()
@@ -194,6 +208,18 @@ impl<'a> ModuleImportsStore<'a> {
         names: Vec<Import<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Statement<'a> {
+        // `import 'source';`
+        if names.is_empty() {
+            return Statement::from(ctx.ast.module_declaration_import_declaration(
+                SPAN,
+                None,
+                ctx.ast.string_literal(SPAN, source, None),
+                None,
+                NONE,
+                ImportOrExportKind::Value,
+            ));
+        }
+
         let specifiers = ctx.ast.vec_from_iter(names.into_iter().map(|import| match import {
             Import::Named(import) => {
                 ImportDeclarationSpecifier::ImportSpecifier(ctx.ast.alloc_import_specifier(
@@ -237,6 +263,11 @@ impl<'a> ModuleImportsStore<'a> {
             let arg = Argument::from(ctx.ast.expression_string_literal(SPAN, source, None));
             ctx.ast.vec1(arg)
         };
+        // `require('source');`
+        if names.is_empty() {
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
+            return ctx.ast.statement_expression(SPAN, call);
+        }
         let Some(Import::Default(local)) = names.into_iter().next() else { unreachable!() };
         let id = local.create_binding_pattern(ctx);
         let var_kind = VariableDeclarationKind::Var;
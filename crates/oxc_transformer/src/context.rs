@@ -59,6 +59,10 @@ impl TransformCtx<'_> {
             .file_stem() // omit file extension
             .map_or_else(|| String::from("unknown"), |name| name.to_string_lossy().to_string());
 
+        let mut assumptions = options.assumptions;
+        // `useDefineForClassFields: false` assigns class fields
+        assumptions.set_public_class_fields |= !options.typescript.use_define_for_class_fields;
+
         Self {
             errors: RefCell::new(vec![]),
             filename,
@@ -66,7 +70,7 @@ impl TransformCtx<'_> {
             source_type: SourceType::default(),
             source_text: "",
             module: options.env.module,
-            assumptions: options.assumptions,
+            assumptions,
             helper_loader: HelperLoaderStore::new(&options.helper_loader),
             module_imports: ModuleImportsStore::new(),
             var_declarations: VarDeclarationsStore::new(),
@@ -9,18 +9,20 @@
 //! Implementation is incomplete at present. Still TODO:
 //!
 //! * `spec` option.
-//! * Handle `arguments` in arrow functions.
-//! * Handle `new.target` in arrow functions.
 //! * Handle arrow function in function params (`function f(g = () => this) {}`).
 //!   Babel gets this wrong: <https://babeljs.io/repl#?code_lz=GYVwdgxgLglg9mABMOcAUAPRBeRaCUOAfIlABYwDOhA3gL5A&presets=&externalPlugins=%40babel%2Fplugin-transform-arrow-functions%407.24.7>
-//! * Error on arrow functions in class properties.
-//!   <https://babeljs.io/repl#?code_lz=MYGwhgzhAEDC0G8BQ1oDMD2HoF5oAoBKXAPmgBcALASwgG4kBfJIA&presets=&externalPlugins=%40babel%2Fplugin-transform-arrow-functions%407.24.7>
-//!   or we can support it:
-//!   `class C { x = () => this; }`
-//!   -> `class C { x = (function(_this) { return () => _this; })(this); }`
-//! * Error on `super` in arrow functions.
+//! * Arrow functions which use `super` are not converted, as the function would have its own
+//!   `super`, and an error is reported. Nor are arrow functions which use an outer `arguments`
+//!   binding that can't be captured, i.e. one which is not an unmodified parameter of the enclosing
+//!   function. Babel moves `super` into helper arrow functions instead:
 //!   <https://babeljs.io/repl#?code_lz=MYGwhgzhAEBiD29oG8C-AoUkYCEwCdoBTADwBciA7AExgSWXWmgFsiyALeagCgEoUTZtHzsArvkrR-0ALwA-aBDEAHIvgB0AM0QBuIRgxA&presets=&externalPlugins=%40babel%2Fplugin-transform-arrow-functions%407.24.7>
 //!
+//! `this`, `arguments` and `new.target` are only captured (`var _this = this;`) when they are
+//! used in an arrow function, in a single declaration at the top of the enclosing function. Arrow functions in class property values which use `this`
+//! are wrapped in a function which is called with `this`:
+//! `class C { x = () => this; }`
+//! -> `class C { x = function() { var _this = this; return function() { return _this; }; }.call(this); }`
+//!
 //! ## Example
 //!
 //! Input:
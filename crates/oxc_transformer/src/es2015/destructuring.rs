@@ -0,0 +1,291 @@
+//! ES2015 Destructuring
+//!
+//! This plugin flattens array and object patterns of variable declarations
+//! into one declarator per binding.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! The initializer is evaluated once, into a temp var, followed by the elements and properties
+//! in source order, so defaults can read the bindings declared before them and computed keys
+//! are evaluated after the initializer. Array patterns read the iterable with the
+//! `slicedToArray` helper, or the `toArray` helper if they have a rest element, which
+//! convert any iterable to an array.
+//!
+//! ## Missing features
+//!
+//! * Patterns of params, `catch` clauses, `for...in`/`for...of` heads and assignment expressions
+//!   are not transformed.
+//! * Exported declarations (`export const [a] = b;`) are not transformed, as the temp vars
+//!   would be exported too.
+//! * Object rest elements are left to the ES2018 object rest spread plugin, declarators which
+//!   still contain one are not transformed.
+//! * `loose` and `useBuiltIns` options.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var [a, , b = a, ...c] = d;
+//! const { x, [key]: { y } } = obj;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _d = babelHelpers.toArray(d),
+//!   a = _d[0],
+//!   _d$ = _d[2],
+//!   b = _d$ === void 0 ? a : _d$,
+//!   c = _d.slice(3);
+//! const _obj = obj,
+//!   x = _obj.x,
+//!   _obj$key = _obj[key],
+//!   y = _obj$key.y;
+//! ```
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring binding patterns: <https://tc39.es/ecma262/#sec-destructuring-binding-patterns>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring<'a, '_> {
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.declarations.iter().any(Self::is_transformable) {
+            return;
+        }
+        if matches!(ctx.parent(), Ancestor::ExportNamedDeclarationDeclaration(_)) {
+            return;
+        }
+
+        let kind = decl.kind;
+        let scope_id =
+            if kind.is_var() { ctx.current_hoist_scope_id() } else { ctx.current_scope_id() };
+        let mut declarations = ctx.ast.vec_with_capacity(decl.declarations.len());
+        for mut declarator in decl.declarations.take_in(ctx.ast) {
+            if Self::is_transformable(&declarator) {
+                let init = declarator.init.take().unwrap();
+                let pattern = declarator.id.take_in(ctx.ast);
+                let mut flattener = Flattener { ctx: self.ctx, kind, scope_id, declarations };
+                flattener.flatten(pattern, init, ctx);
+                declarations = flattener.declarations;
+            } else {
+                declarations.push(declarator);
+            }
+        }
+        decl.declarations = declarations;
+    }
+}
+
+impl<'a> Destructuring<'a, '_> {
+    /// `const [a] = b` or `const { a } = b`, without an object rest element.
+    fn is_transformable(declarator: &VariableDeclarator<'a>) -> bool {
+        declarator.init.is_some()
+            && !declarator.id.is_binding_identifier()
+            && !has_object_rest(&declarator.id)
+    }
+}
+
+/// Collects the flattened declarators of a declaration.
+struct Flattener<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    kind: VariableDeclarationKind,
+    scope_id: ScopeId,
+    declarations: ArenaVec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a> Flattener<'a, '_> {
+    /// Declare the bindings of `pattern`, destructured from `init`.
+    #[expect(clippy::cast_precision_loss)]
+    fn flatten(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern {
+            BindingPattern::BindingIdentifier(_) => self.push(pattern, init, ctx),
+            // `a = 1` -> `_ref = init, a = _ref === void 0 ? 1 : _ref`
+            BindingPattern::AssignmentPattern(assign) => {
+                let AssignmentPattern { left, right, .. } = assign.unbox();
+                let temp = self.generate_temp(&init, ctx);
+                self.push(temp.create_binding_pattern(ctx), init, ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    temp.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let value = ctx.ast.expression_conditional(
+                    SPAN,
+                    test,
+                    right,
+                    temp.create_read_expression(ctx),
+                );
+                self.flatten(left, value, ctx);
+            }
+            // `[a, , b] = c` -> `_c = babelHelpers.slicedToArray(c, 3), a = _c[0], b = _c[2]`
+            // `[a, ...b] = c` -> `_c = babelHelpers.toArray(c), a = _c[0], b = _c.slice(1)`
+            BindingPattern::ArrayPattern(array) => {
+                let ArrayPattern { elements, rest, .. } = array.unbox();
+                let len = elements.len();
+                let temp = self.generate_temp(&init, ctx);
+                let read = if rest.is_some() {
+                    self.ctx.helper_call_expr(
+                        Helper::ToArray,
+                        SPAN,
+                        ctx.ast.vec1(Argument::from(init)),
+                        ctx,
+                    )
+                } else {
+                    let len = ctx.ast.expression_numeric_literal(
+                        SPAN,
+                        len as f64,
+                        None,
+                        NumberBase::Decimal,
+                    );
+                    self.ctx.helper_call_expr(
+                        Helper::SlicedToArray,
+                        SPAN,
+                        ctx.ast.vec_from_array([Argument::from(init), Argument::from(len)]),
+                        ctx,
+                    )
+                };
+                self.push(temp.create_binding_pattern(ctx), read, ctx);
+                for (index, element) in elements.into_iter().enumerate() {
+                    let Some(element) = element else { continue };
+                    let index = ctx.ast.expression_numeric_literal(
+                        SPAN,
+                        index as f64,
+                        None,
+                        NumberBase::Decimal,
+                    );
+                    let value = Expression::from(ctx.ast.member_expression_computed(
+                        SPAN,
+                        temp.create_read_expression(ctx),
+                        index,
+                        false,
+                    ));
+                    self.flatten(element, value, ctx);
+                }
+                if let Some(rest) = rest {
+                    let callee = ctx.ast.member_expression_static(
+                        SPAN,
+                        temp.create_read_expression(ctx),
+                        ctx.ast.identifier_name(SPAN, "slice"),
+                        false,
+                    );
+                    let start = ctx.ast.expression_numeric_literal(
+                        SPAN,
+                        len as f64,
+                        None,
+                        NumberBase::Decimal,
+                    );
+                    let value = ctx.ast.expression_call(
+                        SPAN,
+                        Expression::from(callee),
+                        NONE,
+                        ctx.ast.vec1(Argument::from(start)),
+                        false,
+                    );
+                    self.flatten(rest.unbox().argument, value, ctx);
+                }
+            }
+            // `{ a, [b]: c } = d` -> `_d = d, a = _d.a, c = _d[b]`
+            // `{} = d` -> `_d = babelHelpers.objectDestructuringEmpty(d)`
+            BindingPattern::ObjectPattern(object) => {
+                let ObjectPattern { properties, .. } = object.unbox();
+                let temp = self.generate_temp(&init, ctx);
+                let read = if properties.is_empty() {
+                    self.ctx.helper_call_expr(
+                        Helper::ObjectDestructuringEmpty,
+                        SPAN,
+                        ctx.ast.vec1(Argument::from(init)),
+                        ctx,
+                    )
+                } else {
+                    init
+                };
+                self.push(temp.create_binding_pattern(ctx), read, ctx);
+                for property in properties {
+                    let BindingProperty { key, value: pattern, .. } = property;
+                    let object = temp.create_read_expression(ctx);
+                    let value = match key {
+                        PropertyKey::StaticIdentifier(ident) => Expression::from(
+                            ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false),
+                        ),
+                        key => Expression::from(ctx.ast.member_expression_computed(
+                            SPAN,
+                            object,
+                            key.into_expression(),
+                            false,
+                        )),
+                    };
+                    self.flatten(pattern, value, ctx);
+                }
+            }
+        }
+    }
+
+    /// Create a temp var, named after `node`.
+    fn generate_temp(
+        &self,
+        node: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let flags = match self.kind {
+            VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+            VariableDeclarationKind::Const => {
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+            }
+            _ => SymbolFlags::BlockScopedVariable,
+        };
+        ctx.generate_uid_based_on_node(node, self.scope_id, flags)
+    }
+
+    fn push(&mut self, id: BindingPattern<'a>, init: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let declarator = ctx.ast.variable_declarator(SPAN, self.kind, id, NONE, Some(init), false);
+        self.declarations.push(declarator);
+    }
+}
+
+/// `{ ...a }`, at any depth of `pattern`.
+fn has_object_rest(pattern: &BindingPattern<'_>) -> bool {
+    match pattern {
+        BindingPattern::BindingIdentifier(_) => false,
+        BindingPattern::AssignmentPattern(assign) => has_object_rest(&assign.left),
+        BindingPattern::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(has_object_rest)
+                || array.rest.as_ref().is_some_and(|rest| has_object_rest(&rest.argument))
+        }
+        BindingPattern::ObjectPattern(object) => {
+            object.rest.is_some()
+                || object.properties.iter().any(|property| has_object_rest(&property.value))
+        }
+    }
+}
@@ -1,29 +1,63 @@
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::{context::TransformCtx, state::TransformState};
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 mod arrow_functions;
+mod destructuring;
 mod options;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+use destructuring::Destructuring;
 pub use options::ES2015Options;
+use spread::Spread;
+use template_literals::TemplateLiterals;
 
 pub struct ES2015<'a, 'ctx> {
-    #[expect(unused)]
     options: ES2015Options,
 
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    template_literals: TemplateLiterals<'a, 'ctx>,
+    spread: Spread<'a, 'ctx>,
+    destructuring: Destructuring<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            template_literals: TemplateLiterals::new(ctx),
+            spread: Spread::new(ctx),
+            destructuring: Destructuring::new(ctx),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.template_literals {
+            self.template_literals.enter_expression(expr, ctx);
+        }
+        if self.options.spread {
+            self.spread.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring {
+            self.destructuring.enter_variable_declaration(decl, ctx);
+        }
+    }
+}
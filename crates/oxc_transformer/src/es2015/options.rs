@@ -7,4 +7,22 @@ use super::ArrowFunctionsOptions;
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    /// Lower tagged templates to calls of the tag with a cached template object.
+    ///
+    /// Untagged template literals are not transformed yet.
+    #[serde(skip)]
+    pub template_literals: bool,
+
+    /// Lower spread arguments of calls and `new` expressions.
+    ///
+    /// Spread elements of array literals are not transformed yet.
+    #[serde(skip)]
+    pub spread: bool,
+
+    /// Flatten array and object patterns of variable declarations.
+    ///
+    /// Patterns of params, `catch` clauses, loop heads and assignments are not transformed yet.
+    #[serde(skip)]
+    pub destructuring: bool,
 }
@@ -0,0 +1,201 @@
+//! ES2015 Spread
+//!
+//! This plugin transforms spread arguments of calls (`f(...args)`) and `new` expressions
+//! (`new C(...args)`) to `Function.prototype.apply` and the `construct` helper.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! The receiver of a method call is evaluated only once, and passed as `this` to `apply`.
+//! Other calls are passed `void 0` as `this`, like calls without spread arguments.
+//! Arguments mixing spread and non-spread elements are joined with `Array.prototype.concat`.
+//!
+//! ## Missing features
+//!
+//! * Spread elements of array literals (`[...a]`) are not transformed.
+//! * `super(...args)` is not transformed, as it requires lowering the class.
+//! * Calls in optional chains (`a?.b(...c)`) are only transformed after optional chaining is lowered.
+//! * `loose` option.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! f(...args);
+//! obj.method(a, ...b, c);
+//! getObj().method(...args);
+//! new C(...args);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _getObj;
+//! f.apply(void 0, babelHelpers.toConsumableArray(args));
+//! obj.method.apply(obj, [a].concat(babelHelpers.toConsumableArray(b), [c]));
+//! (_getObj = getObj()).method.apply(_getObj, babelHelpers.toConsumableArray(args));
+//! babelHelpers.construct(C, babelHelpers.toConsumableArray(args));
+//! ```
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-spread>
+//! * `Function.prototype.apply`: <https://tc39.es/ecma262/#sec-function.prototype.apply>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::CallExpression(call)
+                if has_spread(&call.arguments) && !matches!(call.callee, Expression::Super(_)) =>
+            {
+                *expr = self.transform_call_expression(call, ctx);
+            }
+            Expression::NewExpression(new_expr) if has_spread(&new_expr.arguments) => {
+                *expr = self.transform_new_expression(new_expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread<'a, '_> {
+    /// `f(...args)` -> `f.apply(void 0, babelHelpers.toConsumableArray(args))`
+    /// `obj.f(...args)` -> `obj.f.apply(obj, babelHelpers.toConsumableArray(args))`
+    fn transform_call_expression(
+        &self,
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = self.transform_arguments(call.arguments.take_in(ctx.ast), ctx);
+
+        let mut callee = call.callee.take_in(ctx.ast);
+        // `(obj.f)(...args)` calls `f` with `obj` as `this` too
+        while let Expression::ParenthesizedExpression(paren) = callee {
+            callee = paren.unbox().expression;
+        }
+        let (callee, this) = match callee {
+            Expression::StaticMemberExpression(mut member) => {
+                let this = self.take_receiver(&mut member.object, ctx);
+                (Expression::StaticMemberExpression(member), this)
+            }
+            Expression::ComputedMemberExpression(mut member) => {
+                let this = self.take_receiver(&mut member.object, ctx);
+                (Expression::ComputedMemberExpression(member), this)
+            }
+            Expression::PrivateFieldExpression(mut member) => {
+                let this = self.take_receiver(&mut member.object, ctx);
+                (Expression::PrivateFieldExpression(member), this)
+            }
+            callee => (callee, ctx.ast.void_0(SPAN)),
+        };
+
+        let apply = ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, "apply"),
+            false,
+        );
+        let arguments = ctx.ast.vec_from_array([Argument::from(this), Argument::from(arguments)]);
+        ctx.ast.expression_call(call.span, Expression::from(apply), NONE, arguments, false)
+    }
+
+    /// `new C(...args)` -> `babelHelpers.construct(C, babelHelpers.toConsumableArray(args))`
+    fn transform_new_expression(
+        &self,
+        new_expr: &mut NewExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = self.transform_arguments(new_expr.arguments.take_in(ctx.ast), ctx);
+        let callee = new_expr.callee.take_in(ctx.ast);
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]);
+        self.ctx.helper_call_expr(Helper::Construct, new_expr.span, arguments, ctx)
+    }
+
+    /// Returns the receiver to pass as `this` to `apply`, replacing `object` with
+    /// an assignment to a temp var if it may have side effects.
+    ///
+    /// `super.f(...args)` -> `super.f.apply(this, ...)`
+    fn take_receiver(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if matches!(object, Expression::Super(_)) {
+            return ctx.ast.expression_this(SPAN);
+        }
+        let (assignment, reference) =
+            self.ctx.duplicate_expression(object.take_in(ctx.ast), false, ctx);
+        *object = assignment;
+        reference
+    }
+
+    /// Transforms the arguments to an array.
+    ///
+    /// * `(...a)` -> `babelHelpers.toConsumableArray(a)`
+    /// * `(a, ...b, c)` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    fn transform_arguments(
+        &self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut parts = vec![];
+        let mut elements = ctx.ast.vec();
+        for argument in arguments {
+            if let Argument::SpreadElement(spread) = argument {
+                if !elements.is_empty() {
+                    parts.push(ctx.ast.expression_array(SPAN, elements));
+                    elements = ctx.ast.vec();
+                }
+                let arguments = ctx.ast.vec1(Argument::from(spread.unbox().argument));
+                parts.push(self.ctx.helper_call_expr(
+                    Helper::ToConsumableArray,
+                    SPAN,
+                    arguments,
+                    ctx,
+                ));
+            } else {
+                elements.push(ArrayExpressionElement::from(argument.into_expression()));
+            }
+        }
+        if !elements.is_empty() {
+            parts.push(ctx.ast.expression_array(SPAN, elements));
+        }
+
+        let mut parts = parts.into_iter();
+        let first = parts.next().unwrap();
+        if parts.len() == 0 {
+            return first;
+        }
+        let concat = ctx.ast.member_expression_static(
+            SPAN,
+            first,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        );
+        let arguments = ctx.ast.vec_from_iter(parts.map(Argument::from));
+        ctx.ast.expression_call(SPAN, Expression::from(concat), NONE, arguments, false)
+    }
+}
+
+fn has_spread(arguments: &[Argument<'_>]) -> bool {
+    arguments.iter().any(Argument::is_spread)
+}
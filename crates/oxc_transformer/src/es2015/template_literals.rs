@@ -0,0 +1,71 @@
+//! ES2015 Template Literals
+//!
+//! This plugin transforms tagged template expressions (`` tag`...` ``) to calls of the tag,
+//! for engines which don't support template literals, or which don't cache the template object
+//! per call site, as required by the spec.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! The template object is created once with the [`Helper::TaggedTemplateLiteral`] helper,
+//! and cached in a top level variable, so the tag receives the same object on every evaluation
+//! of the same call site. A second array with the raw strings is only passed if they differ from
+//! the cooked strings. Cooked strings of quasis with invalid escape sequences are `void 0`.
+//!
+//! ## Missing features
+//!
+//! * Untagged template literals (`` `a${b}` ``) are not transformed.
+//! * `loose` option.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! tag`a${b}c`;
+//! String.raw`\n\unicode`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject, _templateObject2;
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "c"])), b);
+//! String.raw(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["\n", void 0], ["\\n", "\\unicode"])));
+//! ```
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template objects: <https://tc39.es/ecma262/#sec-gettemplateobject>
+//!
+//! [`Helper::TaggedTemplateLiteral`]: crate::common::helper_loader::Helper::TaggedTemplateLiteral
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    plugins::TaggedTemplateTransform,
+    state::TransformState,
+};
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    tagged_template: TaggedTemplateTransform<'a, 'ctx>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { tagged_template: TaggedTemplateTransform::new(ctx) }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::TaggedTemplateExpression(_)) {
+            return;
+        }
+        let Expression::TaggedTemplateExpression(tagged) = expr.take_in(ctx.ast) else {
+            unreachable!();
+        };
+        *expr = self.tagged_template.transform_tagged_template_impl(tagged.unbox(), ctx);
+    }
+}
@@ -142,6 +142,9 @@ impl<'a> AsyncGeneratorFunctions<'a, '_> {
                 } else {
                     statements.push(stmt_body.take_in(ctx.ast));
                 }
+            } else {
+                // for await (const x of xs) use(x);
+                statements.push(stmt_body.take_in(ctx.ast));
             }
             statements
         };
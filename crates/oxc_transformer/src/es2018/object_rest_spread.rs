@@ -18,6 +18,12 @@
 //! var y = _objectSpread({}, x, { c: 3 });
 //! ```
 //!
+//! ## Missing features
+//!
+//! * Defaults which depend on bindings of a nested pattern with a rest element, e.g. `b` in
+//!   `var { a: { b, ...c }, d = b } = e`, read the binding before it is initialized,
+//!   as the nested pattern is destructured after its parent pattern.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-object-rest-spread](https://babeljs.io/docs/babel-plugin-transform-object-rest-spread).
@@ -33,11 +39,12 @@ use serde::Deserialize;
 
 use oxc_allocator::{Box as ArenaBox, GetAddress, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_ecmascript::{BoundNames, ToJsString, WithoutGlobalReferenceInformation};
-use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
 use oxc_span::{GetSpan, SPAN};
-use oxc_traverse::{Ancestor, MaybeBoundIdentifier, Traverse};
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
 
 use crate::{
     common::helper_loader::Helper,
@@ -208,7 +215,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
 
         let kind = VariableDeclarationKind::Var;
         let symbol_flags = kind_to_symbol_flags(kind);
-        let scope_id = ctx.current_scope_id();
+        let scope_id = ctx.current_hoist_scope_id();
         let mut reference_builder =
             ReferenceBuilder::new(&mut assign_expr.right, symbol_flags, scope_id, true, ctx);
         let state = State::new(kind, symbol_flags, scope_id);
@@ -221,23 +228,19 @@ impl<'a> ObjectRestSpread<'a, '_> {
 
         let data = Self::walk_assignment_target(&mut assign_expr.left, &mut new_decls, state, ctx);
 
-        // Insert `var _foo` before this statement.
-        if !new_decls.is_empty() {
-            for node in ctx.ancestors() {
-                if let Ancestor::ExpressionStatementExpression(decl) = node {
-                    let kind = VariableDeclarationKind::Var;
-                    let declaration = ctx.ast.alloc_variable_declaration(
-                        SPAN,
-                        kind,
-                        ctx.ast.vec_from_iter(new_decls),
-                        false,
-                    );
-                    let statement = Statement::VariableDeclaration(declaration);
-                    self.ctx.statement_injector.insert_before(&decl.address(), statement);
-                    break;
-                }
-            }
-        }
+        // Computed keys are evaluated after the right-hand side, and only when the assignment is,
+        // so `_key = key()` is assigned in the sequence below instead of in the declaration.
+        let key_assignments = new_decls
+            .iter_mut()
+            .filter_map(|decl| {
+                let init = decl.init.take()?;
+                let BindingPattern::BindingIdentifier(ident) = &decl.id else { unreachable!() };
+                let target = BoundIdentifier::from_binding_ident(ident).create_write_target(ctx);
+                Some(ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, init))
+            })
+            .collect::<Vec<_>>();
+
+        self.insert_var_declarators(new_decls, ctx);
 
         // Make an sequence expression.
         let mut expressions = ctx.ast.vec();
@@ -253,6 +256,9 @@ impl<'a> ObjectRestSpread<'a, '_> {
             ));
         }
 
+        // Insert `_key = key()`
+        expressions.extend(key_assignments);
+
         // Insert `{} = _foo`
         expressions.push(ctx.ast.expression_assignment(
             SPAN,
@@ -392,8 +398,36 @@ impl<'a> ObjectRestSpread<'a, '_> {
         let mut decls = vec![];
         let mut exprs = vec![];
         Self::recursive_walk_assignment_target(&mut assign_expr.left, &mut decls, &mut exprs, ctx);
-        for node in ctx.ancestors() {
-            if let Ancestor::ExpressionStatementExpression(decl) = node {
+        self.insert_var_declarators(decls, ctx);
+        let mut expressions = ctx.ast.vec1(expr.take_in(ctx.ast));
+        expressions.extend(exprs);
+        *expr = ctx.ast.expression_sequence(SPAN, expressions);
+    }
+
+    /// Insert `var _foo` before the expression statement which contains the assignment,
+    /// or at the top of the enclosing function if the assignment is in another statement,
+    /// e.g. `return ({ ...y } = foo)`, or the expression statement isn't in a statement list.
+    fn insert_var_declarators(&self, decls: Vec<VariableDeclarator<'a>>, ctx: &TraverseCtx<'a>) {
+        if decls.is_empty() {
+            return;
+        }
+        let mut ancestors = ctx.ancestors();
+        while let Some(node) = ancestors.next() {
+            if let Ancestor::ExpressionStatementExpression(stmt) = node {
+                // `if (x) ({ ...y } = foo);` has no statement list to insert into.
+                if !matches!(
+                    ancestors.next(),
+                    Some(
+                        Ancestor::ProgramBody(_)
+                            | Ancestor::FunctionBodyStatements(_)
+                            | Ancestor::BlockStatementBody(_)
+                            | Ancestor::SwitchCaseConsequent(_)
+                            | Ancestor::StaticBlockBody(_)
+                            | Ancestor::TSModuleBlockBody(_)
+                    )
+                ) {
+                    break;
+                }
                 let kind = VariableDeclarationKind::Var;
                 let declaration = ctx.ast.alloc_variable_declaration(
                     SPAN,
@@ -402,13 +436,16 @@ impl<'a> ObjectRestSpread<'a, '_> {
                     false,
                 );
                 let statement = Statement::VariableDeclaration(declaration);
-                self.ctx.statement_injector.insert_before(&decl.address(), statement);
+                self.ctx.statement_injector.insert_before(&stmt.address(), statement);
+                return;
+            }
+            if node.is_parent_of_statement() {
                 break;
             }
         }
-        let mut expressions = ctx.ast.vec1(expr.take_in(ctx.ast));
-        expressions.extend(exprs);
-        *expr = ctx.ast.expression_sequence(SPAN, expressions);
+        for decl in decls {
+            self.ctx.var_declarations.insert_var_declarator(decl, ctx);
+        }
     }
 
     fn recursive_walk_assignment_target(
@@ -437,7 +474,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
                 if t.rest.is_none() {
                     return;
                 }
-                let scope_id = ctx.scoping.current_scope_id();
+                let scope_id = ctx.current_hoist_scope_id();
                 let flags = SymbolFlags::FunctionScopedVariable;
                 let bound_identifier = ctx.generate_uid("ref", scope_id, flags);
                 let id = bound_identifier.create_binding_pattern(ctx);
@@ -544,49 +581,119 @@ impl<'a> ObjectRestSpread<'a, '_> {
     fn transform_function(func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         let scope_id = func.scope_id();
         let Some(body) = func.body.as_mut() else { return };
-        for param in &mut func.params.items {
-            if Self::has_nested_object_rest(&param.pattern) {
-                Self::replace_rest_element(
-                    VariableDeclarationKind::Var,
-                    &mut param.pattern,
-                    &mut body.statements,
-                    scope_id,
-                    ctx,
-                );
-            }
-        }
+        Self::transform_params(&mut func.params, &mut body.statements, scope_id, ctx);
     }
 
     // Transform `(...x) => {}`.
     fn transform_arrow(arrow: &mut ArrowFunctionExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !arrow.params.items.iter().any(|param| Self::has_nested_object_rest(&param.pattern)) {
+            return;
+        }
+        // `({ ...args }) => { args }`
+        if arrow.expression {
+            arrow.expression = false;
+
+            debug_assert!(arrow.body.statements.len() == 1);
+
+            let Statement::ExpressionStatement(stmt) = arrow.body.statements.pop().unwrap() else {
+                unreachable!(
+                    "`arrow.expression` is true, which means it has only one ExpressionStatement."
+                );
+            };
+            let return_stmt = ctx.ast.statement_return(stmt.span, Some(stmt.unbox().expression));
+            arrow.body.statements.push(return_stmt);
+        }
         let scope_id = arrow.scope_id();
-        for param in &mut arrow.params.items {
-            if Self::has_nested_object_rest(&param.pattern) {
-                // `({ ...args }) => { args }`
-                if arrow.expression {
-                    arrow.expression = false;
-
-                    debug_assert!(arrow.body.statements.len() == 1);
-
-                    let Statement::ExpressionStatement(stmt) = arrow.body.statements.pop().unwrap()
-                    else {
-                        unreachable!(
-                            "`arrow.expression` is true, which means it has only one ExpressionStatement."
-                        );
-                    };
-                    let return_stmt =
-                        ctx.ast.statement_return(stmt.span, Some(stmt.unbox().expression));
-                    arrow.body.statements.push(return_stmt);
-                }
+        Self::transform_params(&mut arrow.params, &mut arrow.body.statements, scope_id, ctx);
+    }
+
+    /// Move the object patterns with a rest of params to the body.
+    ///
+    /// Params can't reference bindings declared in the body, so a later param which references
+    /// a binding moved to the body is moved to the body too, after the object patterns.
+    ///
+    /// `function f({ ...a }, b = a) {}`
+    /// -> `function f(_ref, _b = void 0) { let a = ..._ref; let b = _b === void 0 ? a : _b; }`
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let original_len = body.len();
+        let mut moved_symbols = vec![];
+        let mut dependent_params = vec![];
+        for param in &mut params.items {
+            if !moved_symbols.is_empty() && references_symbols(param, &moved_symbols, ctx) {
+                let decl = Self::move_param_to_body(param, scope_id, ctx);
+                decl.bound_names(&mut |ident| moved_symbols.push(ident.symbol_id()));
+                dependent_params.push(Statement::VariableDeclaration(ctx.ast.alloc(decl)));
+            } else if Self::has_nested_object_rest(&param.pattern) {
+                let len = body.len();
                 Self::replace_rest_element(
                     VariableDeclarationKind::Var,
                     &mut param.pattern,
-                    &mut arrow.body.statements,
+                    body,
                     scope_id,
                     ctx,
                 );
+                // Declarations are inserted at the start of the body.
+                for stmt in &body[..body.len() - len] {
+                    if let Statement::VariableDeclaration(decl) = stmt {
+                        decl.bound_names(&mut |ident| moved_symbols.push(ident.symbol_id()));
+                    }
+                }
             }
         }
+        let index = body.len() - original_len;
+        body.splice(index..index, dependent_params);
+    }
+
+    /// Replace the param with a temp var, and return the declaration of the param's bindings.
+    ///
+    /// * `b` -> `_b`, and returns `let b = _b`.
+    /// * `b = a` -> `_b = void 0`, and returns `let b = _b === void 0 ? a : _b`.
+    ///   The default is kept, so that the `length` of the function doesn't change.
+    ///
+    /// The declaration is `var b` instead if the body redeclares the param, e.g. `var b;`.
+    fn move_param_to_body(
+        param: &mut FormalParameter<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> VariableDeclaration<'a> {
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let binding = ctx.generate_uid_based_on_node(&param.pattern, scope_id, flags);
+        let id = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+        // `b = a` -> `_b = void 0`, keeping the `length` of the function
+        let init = if let Some(initializer) = &mut param.initializer {
+            let default = mem::replace(initializer.as_mut(), ctx.ast.void_0(SPAN));
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                binding.create_read_expression(ctx),
+                BinaryOperator::StrictEquality,
+                ctx.ast.void_0(SPAN),
+            );
+            let value = binding.create_read_expression(ctx);
+            ctx.ast.expression_conditional(SPAN, test, default, value)
+        } else {
+            binding.create_read_expression(ctx)
+        };
+        let mut is_redeclared = false;
+        id.bound_names(&mut |ident| {
+            is_redeclared |= !ctx.scoping().symbol_redeclarations(ident.symbol_id()).is_empty();
+        });
+        let kind =
+            if is_redeclared { VariableDeclarationKind::Var } else { VariableDeclarationKind::Let };
+        let declarations =
+            ctx.ast.vec1(ctx.ast.variable_declarator(SPAN, kind, id, NONE, Some(init), false));
+        let decl = ctx.ast.variable_declaration(SPAN, kind, declarations, false);
+        if !is_redeclared {
+            decl.bound_names(&mut |ident| {
+                *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                    SymbolFlags::BlockScopedVariable;
+            });
+        }
+        decl
     }
 
     // Transform `try {} catch ({...x}) {}`.
@@ -1206,3 +1313,30 @@ impl<'a> ReferenceBuilder<'a> {
         self.expr.take().unwrap_or_else(|| self.maybe_bound_identifier.create_read_expression(ctx))
     }
 }
+
+/// Returns `true` if `param` contains a reference to any of `symbols`,
+/// e.g. in a default value or a computed key.
+fn references_symbols(
+    param: &FormalParameter<'_>,
+    symbols: &[SymbolId],
+    ctx: &TraverseCtx<'_>,
+) -> bool {
+    struct ReferencesSymbols<'s> {
+        symbols: &'s [SymbolId],
+        scoping: &'s Scoping,
+        found: bool,
+    }
+
+    impl<'a> Visit<'a> for ReferencesSymbols<'_> {
+        fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+            let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
+            if symbol_id.is_some_and(|symbol_id| self.symbols.contains(&symbol_id)) {
+                self.found = true;
+            }
+        }
+    }
+
+    let mut visitor = ReferencesSymbols { symbols, scoping: ctx.scoping(), found: false };
+    visitor.visit_formal_parameter(param);
+    visitor.found
+}
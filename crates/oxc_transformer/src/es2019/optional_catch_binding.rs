@@ -3,6 +3,9 @@
 //! This plugin transforms catch clause without parameter to add a parameter
 //! called `unused` in catch clause.
 //!
+//! The parameter is given a unique name (`_unused`, `_unused2`, ...), so it never shadows
+//! a binding referenced in the catch body, including the parameters of nested catch clauses.
+//!
 //! > This plugin is included in `preset-env`, in ES2019
 //!
 //! ## Example
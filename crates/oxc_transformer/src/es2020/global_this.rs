@@ -0,0 +1,65 @@
+//! ES2020: `globalThis`
+//!
+//! This plugin replaces references to the global `globalThis` with a call of the
+//! [`Helper::GlobalThis`] helper, for engines which don't support `globalThis`.
+//!
+//! The helper returns `globalThis` if it is available, and otherwise detects the global object
+//! with `self`, `window` and `global`, falling back to `Function("return this")()`.
+//! It doesn't rely on the value of `this` in its own scope, so it also works in ES modules
+//! and strict mode code, where `this` is `undefined` at the top level.
+//!
+//! References to a local binding named `globalThis` are not replaced.
+//! Assignments to `globalThis` itself (`globalThis = value`) are not replaced either.
+//! When usage polyfills are enabled, `globalThis` is polyfilled by them instead.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! globalThis.fetch = fetch;
+//! if (typeof globalThis.process !== "undefined") {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! babelHelpers.globalThis().fetch = fetch;
+//! if (typeof babelHelpers.globalThis().process !== "undefined") {}
+//! ```
+//!
+//! ## References:
+//! * `globalThis` TC39 proposal: <https://github.com/tc39/proposal-global>
+//!
+//! [`Helper::GlobalThis`]: crate::common::helper_loader::Helper::GlobalThis
+
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct GlobalThis<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> GlobalThis<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for GlobalThis<'a, '_> {
+    // `globalThis` -> `babelHelpers.globalThis()`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::Identifier(ident) = expr else { return };
+        if ident.name != "globalThis" || !ident.is_global_reference(ctx.scoping()) {
+            return;
+        }
+        let span = ident.span;
+        ctx.delete_reference_for_identifier(ident);
+        *expr = self.ctx.helper_call_expr(Helper::GlobalThis, span, ctx.ast.vec(), ctx);
+    }
+}
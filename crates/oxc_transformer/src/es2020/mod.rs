@@ -1,6 +1,8 @@
 use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_traverse::Traverse;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::{Ancestor, Traverse};
 
 use crate::{
     context::{TransformCtx, TraverseCtx},
@@ -8,10 +10,12 @@ use crate::{
 };
 
 mod export_namespace_from;
+mod global_this;
 mod nullish_coalescing_operator;
 mod optional_chaining;
 mod options;
 use export_namespace_from::ExportNamespaceFrom;
+use global_this::GlobalThis;
 use nullish_coalescing_operator::NullishCoalescingOperator;
 pub use optional_chaining::OptionalChaining;
 pub use options::ES2020Options;
@@ -22,6 +26,7 @@ pub struct ES2020<'a, 'ctx> {
 
     // Plugins
     export_namespace_from: ExportNamespaceFrom<'a, 'ctx>,
+    global_this: GlobalThis<'a, 'ctx>,
     nullish_coalescing_operator: NullishCoalescingOperator<'a, 'ctx>,
     optional_chaining: OptionalChaining<'a, 'ctx>,
 }
@@ -32,6 +37,7 @@ impl<'a, 'ctx> ES2020<'a, 'ctx> {
             ctx,
             options,
             export_namespace_from: ExportNamespaceFrom::new(ctx),
+            global_this: GlobalThis::new(ctx),
             nullish_coalescing_operator: NullishCoalescingOperator::new(ctx),
             optional_chaining: OptionalChaining::new(ctx),
         }
@@ -46,6 +52,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2020<'a, '_> {
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.big_int {
+            self.report_big_int_global(expr, ctx);
+        }
+
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.enter_expression(expr, ctx);
         }
@@ -53,6 +63,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2020<'a, '_> {
         if self.options.optional_chaining {
             self.optional_chaining.enter_expression(expr, ctx);
         }
+
+        if self.options.global_this {
+            self.global_this.enter_expression(expr, ctx);
+        }
     }
 
     fn enter_formal_parameters(
@@ -140,3 +154,65 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2020<'a, '_> {
         }
     }
 }
+
+impl<'a> ES2020<'a, '_> {
+    /// Report references to the `BigInt` globals, e.g. `BigInt(1)` or `BigInt.asIntN(8, x)`,
+    /// which can't be polyfilled, as the operators on big integers can't be.
+    ///
+    /// `typeof BigInt` is not reported, as it is used to detect support for big integers,
+    /// and neither are references guarded by it, e.g.
+    /// `typeof BigInt === 'function' ? BigInt(x) : x` or `if (typeof BigInt !== 'undefined') {}`.
+    fn report_big_int_global(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let Expression::Identifier(ident) = expr else { return };
+        if !matches!(ident.name.as_str(), "BigInt" | "BigInt64Array" | "BigUint64Array")
+            || !ident.is_global_reference(ctx.scoping())
+        {
+            return;
+        }
+        if let Ancestor::UnaryExpressionArgument(unary) = ctx.parent()
+            && *unary.operator() == UnaryOperator::Typeof
+        {
+            return;
+        }
+        let is_guarded = ctx.ancestors().any(|ancestor| {
+            let test = match ancestor {
+                Ancestor::ConditionalExpressionConsequent(cond) => cond.test(),
+                Ancestor::ConditionalExpressionAlternate(cond) => cond.test(),
+                Ancestor::IfStatementConsequent(stmt) => stmt.test(),
+                Ancestor::IfStatementAlternate(stmt) => stmt.test(),
+                Ancestor::LogicalExpressionRight(logical) => logical.left(),
+                _ => return false,
+            };
+            let mut finder = TypeofFinder { name: &ident.name, found: false };
+            finder.visit_expression(test);
+            finder.found
+        });
+        if is_guarded {
+            return;
+        }
+        let warning = OxcDiagnostic::warn(format!(
+            "`{}` is not available in the configured target environment.",
+            ident.name
+        ))
+        .with_label(ident.span);
+        self.ctx.error(warning);
+    }
+}
+
+/// Finds `typeof name`.
+struct TypeofFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for TypeofFinder<'_> {
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Typeof
+            && let Expression::Identifier(ident) = expr.argument.without_parentheses()
+            && ident.name == self.name
+        {
+            self.found = true;
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+}
@@ -100,7 +100,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for OptionalChaining<'a, '_> {
             Expression::ChainExpression(_) => self.transform_chain_expression(expr, ctx),
             Expression::UnaryExpression(unary_expr)
                 if unary_expr.operator == UnaryOperator::Delete
-                    && matches!(unary_expr.argument, Expression::ChainExpression(_)) =>
+                    && matches!(
+                        unary_expr.argument.without_parentheses(),
+                        Expression::ChainExpression(_)
+                    ) =>
             {
                 self.transform_update_expression(expr, ctx);
             }
@@ -297,6 +300,13 @@ impl<'a> OptionalChaining<'a, '_> {
         expr: &mut Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        // Parentheses don't change the reference which is deleted,
+        // so `delete (foo?.bar)` deletes `foo.bar` just like `delete foo?.bar`.
+        if let Expression::UnaryExpression(unary_expr) = expr {
+            while let Expression::ParenthesizedExpression(paren) = &mut unary_expr.argument {
+                unary_expr.argument = paren.expression.take_in(ctx.ast);
+            }
+        }
         *expr = if self.is_inside_function_parameter {
             // Same as the above `transform_chain_expression` explanation
             wrap_expression_in_arrow_function_iife(expr.take_in(ctx.ast), ctx)
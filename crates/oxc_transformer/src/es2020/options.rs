@@ -17,4 +17,7 @@ pub struct ES2020Options {
 
     #[serde(skip)]
     pub arbitrary_module_namespace_names: bool,
+
+    #[serde(skip)]
+    pub global_this: bool,
 }
@@ -14,19 +14,27 @@
 //!
 //! a &&= b;
 //! obj.a.b &&= c;
+//!
+//! obj[key()] &&= d;
 //! ```
 //!
 //! Output:
 //! ```js
-//! var _obj$a, _obj$a2;
+//! var _obj$a, _obj$a2, _obj, _key;
 //!
 //! a || (a = b);
 //! (_obj$a = obj.a).b || (_obj$a.b = c);
 //!
 //! a && (a = b);
 //! (_obj$a2 = obj.a).b && (_obj$a2.b = c);
+//!
+//! (_obj = obj)[_key = key()] && (_obj[_key] = d);
 //! ```
 //!
+//! The object of a member target, and the key of a computed member target, are evaluated once,
+//! with temp vars for any which may have side effects or be mutated.
+//! Private fields are the same, e.g. `this.#a &&= b` -> `this.#a && (this.#a = b)`.
+//!
 //! ### With Nullish Coalescing
 //!
 //! > While using the [nullish-coalescing-operator](https://github.com/oxc-project/oxc/blob/main/crates/oxc_transformer/src/es2020/nullish_coalescing_operator.rs) plugin (included in `preset-env``)
@@ -103,7 +111,6 @@ impl<'a> LogicalAssignmentOperators<'a, '_> {
         //               ^     ^ assign_target
         //               ^ left_expr
 
-        // TODO: Add tests, cover private identifier
         let (left_expr, assign_target) = match &mut assignment_expr.left {
             // `a &&= c` -> `a && (a = c)`
             AssignmentTarget::AssignmentTargetIdentifier(ident) => {
@@ -118,9 +125,10 @@ impl<'a> LogicalAssignmentOperators<'a, '_> {
             AssignmentTarget::ComputedMemberExpression(computed_expr) => {
                 self.convert_computed_member_expression(computed_expr, ctx)
             }
-            // TODO
-            #[expect(clippy::match_same_arms)]
-            AssignmentTarget::PrivateFieldExpression(_) => return,
+            // `a.#b &&= c` -> `var _a; (_a = a).#b && (_a.#b = c)`
+            AssignmentTarget::PrivateFieldExpression(private_expr) => {
+                self.convert_private_field_expression(private_expr, ctx)
+            }
             // All other are TypeScript syntax.
 
             // It is a Syntax Error if AssignmentTargetType of LeftHandSideExpression is not simple.
@@ -177,6 +185,32 @@ impl<'a> LogicalAssignmentOperators<'a, '_> {
         (left_expr, assign_target)
     }
 
+    fn convert_private_field_expression(
+        &self,
+        private_expr: &mut PrivateFieldExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, AssignmentTarget<'a>) {
+        let object = private_expr.object.take_in(ctx.ast);
+        let (object, object_ref) = self.ctx.duplicate_expression(object, true, ctx);
+
+        let left_expr = Expression::from(ctx.ast.member_expression_private_field_expression(
+            private_expr.span,
+            object,
+            private_expr.field.clone(),
+            false,
+        ));
+
+        let assign_target =
+            AssignmentTarget::from(ctx.ast.member_expression_private_field_expression(
+                private_expr.span,
+                object_ref,
+                private_expr.field.clone(),
+                false,
+            ));
+
+        (left_expr, assign_target)
+    }
+
     fn convert_computed_member_expression(
         &self,
         computed_expr: &mut ComputedMemberExpression<'a>,
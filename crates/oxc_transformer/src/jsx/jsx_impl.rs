@@ -566,6 +566,9 @@ impl<'a> JsxImpl<'a, '_> {
 
         // The key prop in `<div key={true} />`
         let mut key_prop = None;
+        // `__self` and `__source` props which are already present, and must not be added again
+        let mut has_self_prop = false;
+        let mut has_source_prop = false;
 
         // The object properties for the second argument of `React.createElement`
         let mut properties = ctx.ast.vec();
@@ -588,6 +591,7 @@ impl<'a> JsxImpl<'a, '_> {
                                     && self.options.jsx_self_plugin
                                     && ident.name == "__self" =>
                             {
+                                has_self_prop = true;
                                 self.jsx_self.report_error(ident.span);
                             }
                             JSXAttributeName::Identifier(ident)
@@ -595,6 +599,7 @@ impl<'a> JsxImpl<'a, '_> {
                                     && self.options.jsx_source_plugin
                                     && ident.name == "__source" =>
                             {
+                                has_source_prop = true;
                                 self.jsx_source.report_error(ident.span);
                             }
                             JSXAttributeName::Identifier(ident) if ident.name == "key" => {
@@ -723,11 +728,14 @@ impl<'a> JsxImpl<'a, '_> {
         } else {
             // React.createElement's second argument
             if is_element {
-                if self.options.jsx_self_plugin && JsxSelf::can_add_self_attribute(ctx) {
+                if self.options.jsx_self_plugin
+                    && !has_self_prop
+                    && JsxSelf::can_add_self_attribute(ctx)
+                {
                     properties.push(JsxSelf::get_object_property_kind_for_jsx_plugin(ctx));
                 }
 
-                if self.options.jsx_source_plugin {
+                if self.options.jsx_source_plugin && !has_source_prop {
                     let (line, column) = self.jsx_source.get_line_column(span.start);
                     properties.push(
                         self.jsx_source.get_object_property_kind_for_jsx_plugin(line, column, ctx),
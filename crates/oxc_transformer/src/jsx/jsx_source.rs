@@ -132,9 +132,6 @@ impl<'a> JsxSource<'a, '_> {
         }
 
         let key = ctx.ast.jsx_attribute_name_identifier(SPAN, SOURCE);
-        // TODO: We shouldn't calculate line + column from scratch each time as it's expensive.
-        // Build a table of byte indexes of each line's start on first usage, and save it.
-        // Then calculate line and column from that.
         let (line, column) = self.get_line_column(elem.span.start);
         let object = self.get_source_object(line, column, ctx);
         let value =
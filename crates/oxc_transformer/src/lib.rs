@@ -53,6 +53,7 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use proposals::Proposals;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -77,7 +78,16 @@ pub use crate::{
         ESFeature, ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
-    plugins::{PluginsOptions, StyledComponentsOptions},
+    plugins::{
+        AtToIndexOptions, CompiledEnum, CompiledEnumMember, ConstantResolver,
+        DestructureMemberChainsOptions, DirectEvalOptions, ExpandExportStarOptions,
+        ExplicitRadixOptions, ExportNamesResolver, ExportedConstant, FeatureGuardsOptions,
+        GlobResolver, ImportMetaGlobOptions, InlineImportedConstantsOptions, JsonParseOptions,
+        MODULE_FACTORY_RUNTIME, ModuleFactoryOptions, NormalizeUndefinedOptions, PluginsOptions,
+        RenameMap, StyledComponentsOptions, SwitchLookupOptions, TernaryToEarlyReturnOptions,
+        ThenToCatchOptions, UndefinedStyle, UsagePolyfillsOptions, WellKnownSymbolsOptions,
+        collect_constant_exports, recognize_compiled_enum, rename_top_level_bindings,
+    },
     proposals::ProposalOptions,
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
@@ -100,7 +110,6 @@ pub struct Transformer<'a> {
     plugins: PluginsOptions,
     jsx: JsxOptions,
     env: EnvOptions,
-    #[expect(dead_code)]
     proposals: ProposalOptions,
 }
 
@@ -114,7 +123,7 @@ impl<'a> Transformer<'a> {
             decorator: options.decorator,
             plugins: options.plugins.clone(),
             jsx: options.jsx.clone(),
-            env: options.env,
+            env: options.env.clone(),
             proposals: options.proposals,
         }
     }
@@ -139,20 +148,32 @@ impl<'a> Transformer<'a> {
             );
         }
 
+        // `globalThis` is polyfilled instead, if usage polyfills are enabled.
+        if self.plugins.usage_polyfills.is_some() {
+            self.env.es2020.global_this = false;
+        }
+
+        // The JSX development transforms add `this` and the source location to each element.
+        if self.jsx.development || self.jsx.jsx_self_plugin || self.jsx.jsx_source_plugin {
+            self.plugins.react_constant_elements = false;
+        }
+
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
-            plugins: Plugins::new(self.plugins, &self.ctx),
+            plugins: Plugins::new(self.plugins, &self.env, &self.ctx),
             x0_typescript: program
                 .source_type
                 .is_typescript()
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
             x1_jsx: Jsx::new(self.jsx, self.env.es2018.object_rest_spread, ast_builder, &self.ctx),
+            proposals: Proposals::new(self.proposals),
             x2_es2026: ES2026::new(self.env.es2026, &self.ctx),
             x2_es2022: ES2022::new(
                 self.env.es2022,
                 !self.typescript.allow_declare_fields
-                    || self.typescript.remove_class_fields_without_initializer,
+                    || self.typescript.remove_class_fields_without_initializer
+                    || !self.typescript.use_define_for_class_fields,
                 &self.ctx,
             ),
             x2_es2021: ES2021::new(self.env.es2021, &self.ctx),
@@ -179,6 +200,7 @@ struct TransformerImpl<'a, 'ctx> {
     decorator: Decorator<'a, 'ctx>,
     plugins: Plugins<'a, 'ctx>,
     x1_jsx: Jsx<'a, 'ctx>,
+    proposals: Proposals,
     x2_es2026: ES2026<'a, 'ctx>,
     x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
@@ -187,7 +209,6 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
     common: Common<'a, 'ctx>,
@@ -205,6 +226,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.decorator.exit_program(program, ctx);
+        self.plugins.exit_program(program, ctx);
         self.x1_jsx.exit_program(program, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_program(program, ctx);
@@ -213,6 +235,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2020.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        // Last, so the statements inserted by the other transforms are wrapped too.
+        self.plugins.exit_program_after_all(program, ctx);
     }
 
     // ALPHASORT
@@ -234,6 +258,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
     }
 
     fn enter_variable_declarator(
@@ -289,6 +314,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.plugins.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -305,6 +331,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x1_jsx.enter_call_expression(expr, ctx);
     }
 
+    fn enter_template_literal(&mut self, lit: &mut TemplateLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.plugins.enter_template_literal(lit, ctx);
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.plugins.enter_static_member_expression(expr, ctx);
+    }
+
     fn enter_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_chain_element(element, ctx);
@@ -350,17 +388,20 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_expression(expr, ctx);
         }
         self.plugins.enter_expression(expr, ctx);
+        self.proposals.enter_expression(expr, ctx);
         self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
+        self.x3_es2015.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.exit_expression(expr, ctx);
         self.x1_jsx.exit_expression(expr, ctx);
+        self.plugins.exit_expression(expr, ctx);
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
@@ -546,6 +587,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
+        self.plugins.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -604,6 +646,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.plugins.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -1,9 +1,17 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
-    plugins::StyledComponentsOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::ArrowFunctionsOptions,
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
+    plugins::{
+        AtToIndexOptions, DestructureMemberChainsOptions, DirectEvalOptions, ExplicitRadixOptions,
+        FeatureGuardsOptions, JsonParseOptions, ModuleFactoryOptions, NormalizeUndefinedOptions,
+        StyledComponentsOptions, SwitchLookupOptions, TernaryToEarlyReturnOptions,
+        ThenToCatchOptions, UsagePolyfillsOptions, WellKnownSymbolsOptions,
+    },
 };
 
 use super::PluginPresetEntries;
@@ -51,8 +59,13 @@ pub struct BabelPlugins {
     pub match_indices: bool,
     /// Enables plugin to transform the RegExp literal has `v` flag
     pub set_notation: bool,
+    // ES5
+    pub property_mutators: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub template_literals: bool,
+    pub spread: bool,
+    pub destructuring: bool,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -73,11 +86,41 @@ pub struct BabelPlugins {
     pub class_properties: Option<ClassPropertiesOptions>,
     // ES2026
     pub explicit_resource_management: bool,
+    // Proposals
+    pub throw_expressions: bool,
     // Decorator
     pub legacy_decorator: Option<DecoratorOptions>,
     // Built-in plugins
     pub styled_components: Option<StyledComponentsOptions>,
     pub tagged_template_escape: bool,
+    pub hoist_constant_expressions: bool,
+    pub well_known_symbols: Option<WellKnownSymbolsOptions>,
+    pub json_parse: Option<JsonParseOptions>,
+    pub lazy_top_level_await: bool,
+    pub usage_polyfills: Option<UsagePolyfillsOptions>,
+    pub destructure_member_chains: Option<DestructureMemberChainsOptions>,
+    pub feature_guards: Option<FeatureGuardsOptions>,
+    pub at_to_index: Option<AtToIndexOptions>,
+    pub promise_finally: bool,
+    pub react_constant_elements: bool,
+    pub dynamic_requires: bool,
+    pub split_re_exports: bool,
+    pub direct_eval: Option<DirectEvalOptions>,
+    pub module_factory: Option<ModuleFactoryOptions>,
+    pub switch_lookup: Option<SwitchLookupOptions>,
+    pub hoist_loop_guards: bool,
+    pub normalize_template_line_endings: bool,
+    pub normalize_undefined: Option<NormalizeUndefinedOptions>,
+    pub explicit_radix: Option<ExplicitRadixOptions>,
+    pub ternary_to_early_return: Option<TernaryToEarlyReturnOptions>,
+    pub array_from_length: bool,
+    pub json_parse_to_literal: bool,
+    pub then_to_catch: Option<ThenToCatchOptions>,
+    pub coalesce_template_literals: bool,
+    pub namespace_to_named_imports: bool,
+    pub dedupe_export_names: bool,
+    pub object_iteration_to_for_in: bool,
+    pub react_component_display_name: bool,
 }
 
 impl TryFrom<PluginPresetEntries> for BabelPlugins {
@@ -125,6 +168,7 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
+                "transform-react-constant-elements" => p.react_constant_elements = true,
                 "transform-modules-commonjs" => p.modules_commonjs = true,
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
@@ -134,12 +178,16 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-unicode-property-regex" => p.unicode_property_escapes = true,
                 "esbuild-regexp-match-indices" => p.match_indices = true,
                 "transform-unicode-sets-regex" => p.set_notation = true,
+                "transform-property-mutators" => p.property_mutators = true,
                 "transform-arrow-functions" => {
                     p.arrow_function = entry
                         .value::<ArrowFunctionsOptions>()
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-template-literals" => p.template_literals = true,
+                "transform-spread" => p.spread = true,
+                "transform-destructuring" => p.destructuring = true,
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -167,6 +215,7 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         entry.value::<DecoratorOptions>().map_err(|err| p.errors.push(err)).ok();
                 }
                 "transform-explicit-resource-management" => p.explicit_resource_management = true,
+                "proposal-throw-expressions" => p.throw_expressions = true,
                 "styled-components" => {
                     p.styled_components = entry
                         .value::<StyledComponentsOptions>()
@@ -176,6 +225,88 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "tagged-template-transform" => {
                     p.tagged_template_escape = true;
                 }
+                "hoist-constant-expressions" => p.hoist_constant_expressions = true,
+                "well-known-symbols" => {
+                    p.well_known_symbols = entry
+                        .value::<WellKnownSymbolsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "json-parse" => {
+                    p.json_parse =
+                        entry.value::<JsonParseOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "lazy-top-level-await" => p.lazy_top_level_await = true,
+                "usage-polyfills" => {
+                    p.usage_polyfills = entry
+                        .value::<UsagePolyfillsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "destructure-member-chains" => {
+                    p.destructure_member_chains = entry
+                        .value::<DestructureMemberChainsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "feature-guards" => {
+                    p.feature_guards = entry
+                        .value::<FeatureGuardsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "promise-finally" => p.promise_finally = true,
+                "dynamic-requires" => p.dynamic_requires = true,
+                "split-re-exports" => p.split_re_exports = true,
+                "at-to-index" => {
+                    p.at_to_index =
+                        entry.value::<AtToIndexOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "direct-eval" => {
+                    p.direct_eval =
+                        entry.value::<DirectEvalOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "module-factory" => {
+                    p.module_factory = entry
+                        .value::<ModuleFactoryOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "switch-lookup" => {
+                    p.switch_lookup =
+                        entry.value::<SwitchLookupOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "hoist-loop-guards" => p.hoist_loop_guards = true,
+                "normalize-template-line-endings" => p.normalize_template_line_endings = true,
+                "normalize-undefined" => {
+                    p.normalize_undefined = entry
+                        .value::<NormalizeUndefinedOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "explicit-radix" => {
+                    p.explicit_radix = entry
+                        .value::<ExplicitRadixOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "ternary-to-early-return" => {
+                    p.ternary_to_early_return = entry
+                        .value::<TernaryToEarlyReturnOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "array-from-length" => p.array_from_length = true,
+                "json-parse-to-literal" => p.json_parse_to_literal = true,
+                "then-to-catch" => {
+                    p.then_to_catch =
+                        entry.value::<ThenToCatchOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "coalesce-template-literals" => p.coalesce_template_literals = true,
+                "namespace-to-named-imports" => p.namespace_to_named_imports = true,
+                "dedupe-export-names" => p.dedupe_export_names = true,
+                "object-iteration-to-for-in" => p.object_iteration_to_for_in = true,
+                "react-component-display-name" => p.react_component_display_name = true,
                 s => p.unsupported.push(s.to_string()),
             }
         }
@@ -1,3 +1,4 @@
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use crate::{
@@ -14,9 +15,9 @@ use crate::{
 };
 
 use super::{Module, babel::BabelEnvOptions};
-use oxc_compat::{ESFeature, EngineTargets};
+use oxc_compat::{ESFeature, Engine, EngineTargets, Version};
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "BabelEnvOptions")]
 pub struct EnvOptions {
     /// Specify what module code is generated.
@@ -41,6 +42,9 @@ pub struct EnvOptions {
     pub es2022: ES2022Options,
 
     pub es2026: ES2026Options,
+
+    /// The target engines, for looking up the availability of built-ins.
+    pub targets: EngineTargets,
 }
 
 impl EnvOptions {
@@ -68,6 +72,9 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                template_literals: true,
+                spread: true,
+                destructuring: true,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -83,6 +90,7 @@ impl EnvOptions {
                 big_int: false,
                 optional_chaining: true,
                 arbitrary_module_namespace_names: false,
+                global_this: true,
             },
             es2021: ES2021Options { logical_assignment_operators: true },
             es2022: ES2022Options {
@@ -92,6 +100,8 @@ impl EnvOptions {
                 top_level_await: false,
             },
             es2026: ES2026Options { explicit_resource_management: true },
+            // Below every edition, so that all ECMAScript built-ins are unavailable.
+            targets: EngineTargets::new(FxHashMap::from_iter([(Engine::Es, Version(5, 0, 0))])),
         }
     }
 
@@ -119,6 +129,21 @@ impl EnvOptions {
     pub fn from_target_list<S: AsRef<str>>(list: &[S]) -> Result<Self, String> {
         EngineTargets::from_target_list(list).map(Self::from)
     }
+
+    /// Initialize from a map of engine names to minimum versions,
+    /// e.g. `{ chrome: "80", node: "14", safari: "13" }`.
+    ///
+    /// A transform is enabled when any of the engines does not support the feature.
+    ///
+    /// # Errors
+    ///
+    /// * Unknown engine name.
+    /// * Invalid version.
+    pub fn from_engine_versions<K: AsRef<str>, V: AsRef<str>>(
+        versions: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, String> {
+        EngineTargets::from_engine_versions(versions).map(Self::from)
+    }
 }
 
 impl From<BabelEnvOptions> for EnvOptions {
@@ -145,6 +170,9 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                template_literals: o.has_feature(ES2015TemplateLiterals),
+                spread: o.has_feature(ES2015Spread),
+                destructuring: o.has_feature(ES2015Destructuring),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -164,6 +192,7 @@ impl From<EngineTargets> for EnvOptions {
                 optional_chaining: o.has_feature(ES2020OptionalChaining),
                 arbitrary_module_namespace_names: o
                     .has_feature(ES2020ArbitraryModuleNamespaceNames),
+                global_this: o.has_feature(ES2020GlobalThis),
             },
             es2021: ES2021Options {
                 logical_assignment_operators: o.has_feature(ES2021LogicalAssignmentOperators),
@@ -176,6 +205,7 @@ impl From<EngineTargets> for EnvOptions {
             es2026: ES2026Options {
                 explicit_resource_management: o.has_feature(ES2026ExplicitResourceManagement),
             },
+            targets: o,
         }
     }
 }
@@ -86,10 +86,78 @@ impl TransformOptions {
                 ..JsxOptions::default()
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
-            proposals: ProposalOptions::default(),
+            proposals: ProposalOptions { throw_expressions: true },
             plugins: PluginsOptions {
                 styled_components: Some(StyledComponentsOptions::default()),
                 tagged_template_transform: true,
+                // Changes object identity, which is observable.
+                hoist_constant_expressions: false,
+                // Only reports or imports polyfills, nothing to transform.
+                well_known_symbols: None,
+                // Only pays off for huge literals, and makes the output harder to read.
+                json_parse: None,
+                // Changes the shape of the module's exports.
+                lazy_top_level_await: false,
+                // Needs a resolver for the exported names of other modules.
+                expand_export_star: None,
+                // Experimental, for migration tooling which ingests compiled JavaScript.
+                reconstruct_ts_enums: false,
+                // Needs a resolver for the modules matched by the glob patterns.
+                import_meta_glob: None,
+                // Only imports polyfills, nothing to transform.
+                usage_polyfills: None,
+                // Changes when the required modules are evaluated relative to the module's own declarations.
+                hoist_requires: false,
+                // Changes the code's structure, and assumes getters are free of side effects.
+                destructure_member_chains: None,
+                // Needs the globals which are available in the target environment.
+                feature_guards: None,
+                // Only needed for ES5 engines, and not included in `preset-env`.
+                property_mutators: false,
+                // Assumes the receivers of `.at()` are arrays or strings.
+                at_to_index: None,
+                // Assumes the receivers of `.finally()` are promises.
+                promise_finally: false,
+                // Changes the identity of elements, and is not included in `preset-react`.
+                react_constant_elements: false,
+                // Changes when the required modules are evaluated, and what `require` evaluates to.
+                dynamic_requires: false,
+                // Only useful for tools which analyze the exports of a module statement by statement.
+                split_re_exports: false,
+                // Reports every direct `eval` call, and converting them changes the scope of the evaluated code.
+                direct_eval: None,
+                // Changes the output into a module factory, which needs the module registry runtime.
+                module_factory: None,
+                // Needs a resolver for the constants exported by other modules.
+                inline_imported_constants: None,
+                // Only pays off for large `switch` statements, and makes the output harder to read.
+                switch_lookup: None,
+                // Stops running the header of a loop whose guard is false, which assumes the header is free of side effects.
+                hoist_loop_guards: false,
+                // Only changes the line endings of the output, for build targets which require LF.
+                normalize_template_line_endings: false,
+                // Only changes how `undefined` is written, in either direction.
+                normalize_undefined: None,
+                // Changes the result of `parseInt` for strings starting with `0x`.
+                explicit_radix: None,
+                // Only changes how chained ternaries are written.
+                ternary_to_early_return: None,
+                // Assumes the length passed to `Array` is a valid array length.
+                array_from_length: false,
+                // The reverse of `json_parse`, which parses faster.
+                json_parse_to_literal: false,
+                // Assumes the receiver of `then` is a promise with a `catch` method.
+                then_to_catch: None,
+                // Only changes how template literals are written.
+                coalesce_template_literals: false,
+                // Assumes every property read from a namespace is exported by the module.
+                namespace_to_named_imports: false,
+                // Reports an error for names which are exported more than once.
+                dedupe_export_names: false,
+                // Convert `for...of` loops over `Object.entries`, `Object.keys` and `Object.values` to `for...in` loops.
+                object_iteration_to_for_in: false,
+                //
+                react_component_display_name: false,
             },
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
@@ -182,7 +250,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx_options
         };
 
-        let env = options.presets.env.unwrap_or_default();
+        let env = options.presets.env.clone().unwrap_or_default();
 
         let module = Module::try_from(&options.plugins).unwrap_or_else(|_| {
             options.presets.env.as_ref().map(|env| env.module).unwrap_or_default()
@@ -204,6 +272,9 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            template_literals: options.plugins.template_literals || env.es2015.template_literals,
+            spread: options.plugins.spread || env.es2015.spread,
+            destructuring: options.plugins.destructuring || env.es2015.destructuring,
         };
 
         let es2016 = ES2016Options {
@@ -237,6 +308,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 || env.es2020.nullish_coalescing_operator,
             big_int: env.es2020.big_int,
             arbitrary_module_namespace_names: env.es2020.arbitrary_module_namespace_names,
+            global_this: env.es2020.global_this,
         };
 
         let es2021 = ES2021Options {
@@ -268,6 +340,35 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             plugins.styled_components = Some(styled_components.clone());
         }
         plugins.tagged_template_transform = options.plugins.tagged_template_escape;
+        plugins.hoist_constant_expressions = options.plugins.hoist_constant_expressions;
+        plugins.well_known_symbols.clone_from(&options.plugins.well_known_symbols);
+        plugins.usage_polyfills.clone_from(&options.plugins.usage_polyfills);
+        plugins.destructure_member_chains.clone_from(&options.plugins.destructure_member_chains);
+        plugins.feature_guards.clone_from(&options.plugins.feature_guards);
+        plugins.json_parse.clone_from(&options.plugins.json_parse);
+        plugins.lazy_top_level_await = options.plugins.lazy_top_level_await;
+        plugins.property_mutators = options.plugins.property_mutators;
+        plugins.at_to_index.clone_from(&options.plugins.at_to_index);
+        plugins.promise_finally = options.plugins.promise_finally;
+        plugins.react_constant_elements = options.plugins.react_constant_elements;
+        plugins.dynamic_requires = options.plugins.dynamic_requires;
+        plugins.split_re_exports = options.plugins.split_re_exports;
+        plugins.direct_eval = options.plugins.direct_eval;
+        plugins.module_factory.clone_from(&options.plugins.module_factory);
+        plugins.switch_lookup.clone_from(&options.plugins.switch_lookup);
+        plugins.hoist_loop_guards = options.plugins.hoist_loop_guards;
+        plugins.normalize_template_line_endings = options.plugins.normalize_template_line_endings;
+        plugins.normalize_undefined.clone_from(&options.plugins.normalize_undefined);
+        plugins.explicit_radix.clone_from(&options.plugins.explicit_radix);
+        plugins.ternary_to_early_return.clone_from(&options.plugins.ternary_to_early_return);
+        plugins.array_from_length = options.plugins.array_from_length;
+        plugins.json_parse_to_literal = options.plugins.json_parse_to_literal;
+        plugins.then_to_catch.clone_from(&options.plugins.then_to_catch);
+        plugins.coalesce_template_literals = options.plugins.coalesce_template_literals;
+        plugins.namespace_to_named_imports = options.plugins.namespace_to_named_imports;
+        plugins.dedupe_export_names = options.plugins.dedupe_export_names;
+        plugins.object_iteration_to_for_in = options.plugins.object_iteration_to_for_in;
+        plugins.react_component_display_name = options.plugins.react_component_display_name;
 
         Ok(Self {
             cwd: options.cwd.clone().unwrap_or_default(),
@@ -289,8 +390,9 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2026: ES2026Options {
                     explicit_resource_management: options.plugins.explicit_resource_management,
                 },
+                targets: env.targets,
             },
-            proposals: ProposalOptions::default(),
+            proposals: ProposalOptions { throw_expressions: options.plugins.throw_expressions },
             helper_loader,
             plugins,
         })
@@ -0,0 +1,162 @@
+//! Array From Length
+//!
+//! This plugin converts the `Array(n).fill().map(fn)` idiom for creating an array of `n` computed
+//! elements to the clearer `Array.from({ length: n }, fn)`.
+//!
+//! Only this exact shape is converted:
+//!
+//! * `Array(n)` or `new Array(n)`, referencing the global `Array`, with a single argument.
+//!   `Array(n)` throws for lengths which aren't valid, and creates `[n]` when `n` isn't a number,
+//!   while `Array.from` doesn't, so this assumes `n` is a valid length.
+//!   Literals which aren't valid lengths, such as `Array("3")`, `Array(-1)` or `Array(2 ** 32)`,
+//!   are left untouched.
+//! * `.fill()`, `.fill(undefined)` or `.fill(void 0)`, so that all elements are `undefined`.
+//!   Filling with any other value changes the elements passed to the callback.
+//! * `.map(fn)` or `.map(fn, thisArg)`, where `fn` is an arrow function with at most two parameters.
+//!   `Array.from` passes the element and its index to the callback, but not the array.
+//!
+//! Methods chained after `.map(fn)` are kept, as `Array.from` returns an array as well.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const rows = Array(n).fill().map((_, i) => i * 2);
+//! ```
+//!
+//! Output:
+//! ```js
+//! const rows = Array.from({ length: n }, (_, i) => i * 2);
+//! ```
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::IsGlobalReference;
+use oxc_span::SPAN;
+use oxc_syntax::operator::UnaryOperator;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct ArrayFromLength;
+
+impl ArrayFromLength {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ArrayFromLength {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(map_call) = expr else { return };
+        if !Self::is_fill_map(map_call, ctx) {
+            return;
+        }
+        let span = map_call.span;
+        let Expression::StaticMemberExpression(map) = &mut map_call.callee else { unreachable!() };
+        let Expression::CallExpression(fill_call) = &mut map.object else { unreachable!() };
+        // `fill(undefined)`
+        if let Some(Argument::Identifier(ident)) = fill_call.arguments.first() {
+            ctx.delete_reference_for_identifier(ident);
+        }
+        let Expression::StaticMemberExpression(fill) = &mut fill_call.callee else {
+            unreachable!()
+        };
+        let (array, length) = match &mut fill.object {
+            Expression::CallExpression(call) => (call.callee.take_in(ctx.ast), &mut call.arguments),
+            Expression::NewExpression(new) => (new.callee.take_in(ctx.ast), &mut new.arguments),
+            _ => unreachable!(),
+        };
+        let length = length.pop().unwrap().into_expression();
+
+        // `{ length: n }`
+        let key = ctx.ast.property_key_static_identifier(SPAN, "length");
+        let property = ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            length,
+            false,
+            false,
+            false,
+        );
+        let object = ctx.ast.expression_object(SPAN, ctx.ast.vec1(property));
+
+        let mut arguments = ctx.ast.vec_with_capacity(map_call.arguments.len() + 1);
+        arguments.push(Argument::from(object));
+        arguments.extend(map_call.arguments.drain(..));
+
+        let property = ctx.ast.identifier_name(SPAN, "from");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, array, property, false));
+        *expr = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+    }
+}
+
+impl<'a> ArrayFromLength {
+    /// `Array(n).fill().map((_, i) => i)`
+    fn is_fill_map(map_call: &CallExpression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Some(fill_call) = Self::method_call_object(map_call, "map") else { return false };
+        if !matches!(map_call.arguments.len(), 1 | 2)
+            || map_call.arguments.iter().any(Argument::is_spread)
+            || !matches!(&map_call.arguments[0], Argument::ArrowFunctionExpression(arrow)
+                if arrow.params.rest.is_none() && arrow.params.items.len() <= 2)
+        {
+            return false;
+        }
+
+        let Expression::CallExpression(fill_call) = fill_call else { return false };
+        let Some(array) = Self::method_call_object(fill_call, "fill") else { return false };
+        if !match fill_call.arguments.as_slice() {
+            [] => true,
+            [Argument::Identifier(_)] => fill_call.arguments[0]
+                .to_expression()
+                .is_global_reference_name("undefined", ctx.scoping()),
+            [Argument::UnaryExpression(unary)] => {
+                unary.operator == UnaryOperator::Void && unary.argument.is_number_0()
+            }
+            _ => false,
+        } {
+            return false;
+        }
+
+        let (callee, arguments) = match array {
+            Expression::CallExpression(call) if !call.optional => (&call.callee, &call.arguments),
+            Expression::NewExpression(new) => (&new.callee, &new.arguments),
+            _ => return false,
+        };
+        callee.is_global_reference_name("Array", ctx.scoping())
+            && arguments.len() == 1
+            && arguments[0].as_expression().is_some_and(Self::is_possible_length)
+    }
+
+    /// The object of `object.method(...)`, without optional chaining.
+    fn method_call_object<'b>(
+        call: &'b CallExpression<'a>,
+        method: &str,
+    ) -> Option<&'b Expression<'a>> {
+        let Expression::StaticMemberExpression(member) = &call.callee else { return None };
+        (!call.optional && !member.optional && member.property.name == method)
+            .then_some(&member.object)
+    }
+
+    /// Whether `expr` may be a valid array length, i.e. isn't a literal of any other value.
+    /// Valid lengths are integers between `0` and `2 ** 32 - 1`.
+    fn is_possible_length(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::NumericLiteral(lit) => {
+                lit.value.fract() == 0.0 && (0.0..=f64::from(u32::MAX)).contains(&lit.value)
+            }
+            Expression::StringLiteral(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::ObjectExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::UnaryExpression(_) => false,
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! `.at()` to Index
+//!
+//! This plugin transforms `.at()` calls with integer literal indices to member expressions,
+//! for engines which don't support `Array.prototype.at` and `String.prototype.at` (ES2022).
+//!
+//! > This plugin is not included in `preset-env`.
+//!
+//! * `x.at(0)` -> `x[0]`
+//! * `x.at(-1)` -> `x[x.length - 1]`. A receiver which may have side effects is evaluated once,
+//!   by assigning it to a temp var.
+//!
+//! The receiver is assumed to be an array or a string, as the type of the receiver is not known
+//! in general. A receiver with its own `at` method, e.g. a typed array in an engine without
+//! `TypedArray.prototype.at`, or an object of a user-defined class, would behave differently.
+//! This is why the plugin is opt-in.
+//!
+//! Calls with other arguments (e.g. `x.at(i)`) are not transformed. If the `polyfill` option is set,
+//! a polyfill of `at` is imported for them instead, like the usage polyfills plugin.
+//!
+//! `super.at(0)` is not transformed, nor are calls which are called themselves (`x.at(0)()`),
+//! as `x[0]()` would call the element with `x` as `this`. Calls in optional chains (`x?.at(0)`) are only transformed
+//! after optional chaining is lowered.
+//!
+//! ## Example
+//!
+//! Input (targeting `chrome 80`):
+//! ```js
+//! const first = list.at(0);
+//! const last = getList().at(-1);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _getList;
+//! const first = list[0];
+//! const last = (_getList = getList())[_getList.length - 1];
+//! ```
+//!
+//! ## References
+//!
+//! * `Array.prototype.at`: <https://tc39.es/ecma262/#sec-array.prototype.at>
+//! * `String.prototype.at`: <https://tc39.es/ecma262/#sec-string.prototype.at>
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_compat::BuiltIn;
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    options::EnvOptions,
+    plugins::built_ins::BuiltIns,
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct AtToIndexOptions {
+    /// Module to import for `.at()` calls which can't be transformed.
+    ///
+    /// `{name}` is replaced with the `core-js` module name of the method,
+    /// i.e. `es.array.at` or `es.string.at-alternative`.
+    ///
+    /// Default: `None`, which doesn't import anything.
+    pub polyfill: Option<String>,
+}
+
+/// Replaced with the module name in [`AtToIndexOptions::polyfill`].
+const NAME_PLACEHOLDER: &str = "{name}";
+
+const ARRAY_AT: &str = "es.array.at";
+const STRING_AT: &str = "es.string.at-alternative";
+
+/// Largest integer which is represented exactly, `2 ** 53 - 1`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+pub struct AtToIndex<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: AtToIndexOptions,
+    /// `.at()` is not available in the target environment.
+    enabled: bool,
+    /// Sources which have already been imported.
+    imported: FxHashSet<String>,
+}
+
+impl<'a, 'ctx> AtToIndex<'a, 'ctx> {
+    pub fn new(options: AtToIndexOptions, env: &EnvOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let built_ins = BuiltIns::new(env);
+        let enabled = built_ins.is_unavailable(BuiltIn::ArrayPrototypeAt)
+            || built_ins.is_unavailable(BuiltIn::StringPrototypeAt);
+        Self { ctx, options, enabled, imported: FxHashSet::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for AtToIndex<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.enabled {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional || call.arguments.len() != 1 || call.arguments[0].is_spread() {
+            return;
+        }
+        let Expression::StaticMemberExpression(member) = &call.callee else { return };
+        if member.optional
+            || member.property.name != "at"
+            || matches!(member.object, Expression::Super(_))
+            || Self::is_callee(ctx)
+        {
+            return;
+        }
+
+        let index = call.arguments[0].to_expression();
+        let negative = match index.without_parentheses() {
+            Expression::NumericLiteral(lit) if is_index(lit.value) => false,
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    && matches!(
+                        unary.argument.without_parentheses(),
+                        Expression::NumericLiteral(lit) if is_index(lit.value)
+                    ) =>
+            {
+                true
+            }
+            _ => {
+                let receiver = member.object.without_parentheses();
+                self.import_polyfill(receiver, ctx);
+                return;
+            }
+        };
+
+        let span = call.span;
+        let Expression::StaticMemberExpression(member) = call.callee.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let object = member.unbox().object;
+        let index = call.arguments.pop().unwrap().into_expression().into_inner_expression();
+        *expr = if negative {
+            Self::transform_negative_index(object, index, span, self.ctx, ctx)
+        } else {
+            // `x.at(0)` -> `x[0]`
+            Expression::from(ctx.ast.member_expression_computed(span, object, index, false))
+        };
+    }
+}
+
+impl<'a> AtToIndex<'a, '_> {
+    /// The current expression is the callee of a call or the tag of a tagged template,
+    /// e.g. `x.at(0)()` or `(x.at(0))```.
+    fn is_callee(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ParenthesizedExpressionExpression(_) => {}
+                Ancestor::CallExpressionCallee(_) | Ancestor::TaggedTemplateExpressionTag(_) => {
+                    return true;
+                }
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    /// `x.at(-1)` -> `x[x.length - 1]`
+    /// `f().at(-1)` -> `(_f = f())[_f.length - 1]`
+    fn transform_negative_index(
+        object: Expression<'a>,
+        index: Expression<'a>,
+        span: Span,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Expression::UnaryExpression(unary) = index else { unreachable!() };
+        let value = unary.unbox().argument.into_inner_expression();
+        let is_zero = matches!(&value, Expression::NumericLiteral(lit) if lit.value == 0.0);
+        if is_zero {
+            // `x.at(-0)` -> `x[0]`
+            return Expression::from(
+                ctx.ast.member_expression_computed(span, object, value, false),
+            );
+        }
+
+        let (object, reference) = transform_ctx.duplicate_expression(object, false, ctx);
+        let length = ctx.ast.member_expression_static(
+            SPAN,
+            reference,
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        );
+        let index = ctx.ast.expression_binary(
+            SPAN,
+            Expression::from(length),
+            BinaryOperator::Subtraction,
+            value,
+        );
+        Expression::from(ctx.ast.member_expression_computed(span, object, index, false))
+    }
+
+    /// Import the polyfill of `at` for the type of `receiver`, or both if the type is not known.
+    fn import_polyfill(&mut self, receiver: &Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let modules: &[&'static str] = match receiver {
+            Expression::ArrayExpression(_) => &[ARRAY_AT],
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => &[STRING_AT],
+            _ => &[ARRAY_AT, STRING_AT],
+        };
+        let Some(polyfill) = &self.options.polyfill else { return };
+        for module in modules {
+            let source = match polyfill.split_once(NAME_PLACEHOLDER) {
+                Some((before, after)) => format!("{before}{module}{after}"),
+                None => polyfill.clone(),
+            };
+            if self.imported.insert(source.clone()) {
+                self.ctx.module_imports.add_side_effect_import(ctx.ast.atom(&source));
+            }
+        }
+    }
+}
+
+/// A non-negative integer, which is printed as an integer literal.
+fn is_index(value: f64) -> bool {
+    value.fract() == 0.0 && (0.0..=MAX_SAFE_INTEGER).contains(&value)
+}
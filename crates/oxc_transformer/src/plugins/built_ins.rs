@@ -0,0 +1,25 @@
+//! Availability of built-ins in the target environment.
+//!
+//! Shared by the plugins which polyfill built-ins or rewrite their usage.
+//!
+//! Built-ins, including web platform APIs, are looked up per built-in in the compat data
+//! of the target engines.
+
+use oxc_compat::{BuiltIn, EngineTargets};
+
+use crate::options::EnvOptions;
+
+pub struct BuiltIns {
+    targets: EngineTargets,
+}
+
+impl BuiltIns {
+    pub fn new(env: &EnvOptions) -> Self {
+        Self { targets: env.targets.clone() }
+    }
+
+    /// `built_in` is not available in the target environment.
+    pub fn is_unavailable(&self, built_in: BuiltIn) -> bool {
+        self.targets.has_built_in(built_in)
+    }
+}
@@ -0,0 +1,180 @@
+//! Coalesce Template Literals
+//!
+//! This plugin folds literal interpolations of template literals into the surrounding quasis,
+//! and replaces template literals without interpolations by string literals.
+//! It normalizes template literals after other transforms have replaced an interpolation
+//! with a literal, e.g. after an imported constant is inlined.
+//!
+//! * Strings, numbers, bigints, booleans and `null` are folded, with the value they are
+//!   converted to, e.g. `` `a${1}b` `` -> `"a1b"`.
+//! * Nested template literals are flattened, e.g. `` `a${`b${c}`}d` `` -> `` `ab${c}d` ``.
+//! * Folded strings are escaped in the raw text of the quasi: `` ` ``, `\`, `${`,
+//!   and line endings, which would be normalized to `\n` if written as-is.
+//!
+//! Tagged templates are left untouched, as the tag receives the quasis and the interpolations
+//! separately. So are template literals with lone surrogates, and template literals which
+//! are the expression of an expression statement, which would be a directive as a string literal
+//! (`` `use strict`; ``).
+//!
+//! ## Example
+//!
+//! Input, after `MAX_ITEMS` and `PROTOCOL` have been inlined:
+//! ```js
+//! const message = `Expected at most ${100} items, got ${count}.`;
+//! const url = `${"https"}://example.com`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! const message = `Expected at most 100 items, got ${count}.`;
+//! const url = "https://example.com";
+//! ```
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ecmascript::{ToJsString, WithoutGlobalReferenceInformation};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct CoalesceTemplateLiterals;
+
+impl CoalesceTemplateLiterals {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for CoalesceTemplateLiterals {
+    // Nested template literals are coalesced first, so they can be flattened into this one.
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(lit) = expr else { return };
+        if !Self::is_coalescable(lit) {
+            return;
+        }
+        if lit.expressions.iter().any(Self::is_foldable) {
+            Self::fold_expressions(lit, ctx);
+        }
+        if lit.expressions.is_empty()
+            && !matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_))
+        {
+            let value = lit.quasis[0].value.cooked.unwrap();
+            *expr = ctx.ast.expression_string_literal(lit.span, value, None);
+        }
+    }
+}
+
+impl<'a> CoalesceTemplateLiterals {
+    /// All quasis have a cooked value, which only template literals with no tag can be relied on
+    /// to have, and no lone surrogates.
+    fn is_coalescable(lit: &TemplateLiteral<'a>) -> bool {
+        lit.quasis.iter().all(|quasi| quasi.value.cooked.is_some() && !quasi.lone_surrogates)
+    }
+
+    fn is_foldable(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::StringLiteral(lit) => !lit.lone_surrogates,
+            Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => true,
+            Expression::TemplateLiteral(lit) => Self::is_coalescable(lit),
+            _ => false,
+        }
+    }
+
+    /// `` `a${1}b${`c${d}`}e` `` -> `` `a1bc${d}e` ``
+    fn fold_expressions(lit: &mut TemplateLiteral<'a>, ctx: &TraverseCtx<'a>) {
+        let mut quasis = ctx.ast.vec();
+        let mut expressions = ctx.ast.vec();
+        let mut builder = QuasiBuilder::default();
+        let mut old_quasis = lit.quasis.take_in(ctx.ast).into_iter();
+        builder.push_quasi(&old_quasis.next().unwrap());
+        for (expr, quasi) in lit.expressions.take_in(ctx.ast).into_iter().zip(old_quasis) {
+            match expr {
+                Expression::TemplateLiteral(mut nested) if Self::is_coalescable(&nested) => {
+                    let mut nested_quasis = nested.quasis.take_in(ctx.ast).into_iter();
+                    builder.push_quasi(&nested_quasis.next().unwrap());
+                    for (nested_expr, nested_quasi) in
+                        nested.expressions.take_in(ctx.ast).into_iter().zip(nested_quasis)
+                    {
+                        quasis.push(builder.finish(false, ctx));
+                        expressions.push(nested_expr);
+                        builder.push_quasi(&nested_quasi);
+                    }
+                }
+                expr if Self::is_foldable(&expr) => {
+                    let value = expr.to_js_string(&WithoutGlobalReferenceInformation {}).unwrap();
+                    builder.push_str(&value);
+                }
+                expr => {
+                    quasis.push(builder.finish(false, ctx));
+                    expressions.push(expr);
+                }
+            }
+            builder.push_quasi(&quasi);
+        }
+        quasis.push(builder.finish(true, ctx));
+        lit.quasis = quasis;
+        lit.expressions = expressions;
+    }
+}
+
+/// Builds the raw text and the cooked value of a quasi.
+#[derive(Default)]
+struct QuasiBuilder {
+    raw: String,
+    cooked: String,
+}
+
+impl QuasiBuilder {
+    fn push_quasi(&mut self, quasi: &TemplateElement) {
+        self.push_raw(&quasi.value.raw);
+        self.cooked.push_str(quasi.value.cooked.as_ref().unwrap());
+    }
+
+    /// Push the raw text of a quasi. `$` followed by `{` would start an interpolation,
+    /// so a `{` following a `$` is escaped, here and in [`Self::push_str`].
+    fn push_raw(&mut self, raw: &str) {
+        if raw.starts_with('{') && self.ends_with_unescaped_dollar() {
+            self.raw.push('\\');
+        }
+        self.raw.push_str(raw);
+    }
+
+    /// Push a string value, escaped.
+    fn push_str(&mut self, value: &str) {
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '`' => self.raw.push_str("\\`"),
+                '\\' => self.raw.push_str("\\\\"),
+                '\n' => self.raw.push_str("\\n"),
+                '\r' => self.raw.push_str("\\r"),
+                '\u{2028}' => self.raw.push_str("\\u2028"),
+                '\u{2029}' => self.raw.push_str("\\u2029"),
+                '$' if chars.peek() == Some(&'{') => self.raw.push_str("\\$"),
+                '{' if self.ends_with_unescaped_dollar() => self.raw.push_str("\\{"),
+                c => self.raw.push(c),
+            }
+        }
+        self.cooked.push_str(value);
+    }
+
+    fn ends_with_unescaped_dollar(&self) -> bool {
+        let Some(rest) = self.raw.strip_suffix('$') else { return false };
+        let backslashes = rest.len() - rest.trim_end_matches('\\').len();
+        backslashes % 2 == 0
+    }
+
+    fn finish<'a>(&mut self, tail: bool, ctx: &TraverseCtx<'a>) -> TemplateElement<'a> {
+        let value = TemplateElementValue {
+            raw: ctx.ast.atom(&self.raw),
+            cooked: Some(ctx.ast.atom(&self.cooked)),
+        };
+        self.raw.clear();
+        self.cooked.clear();
+        ctx.ast.template_element(SPAN, value, tail)
+    }
+}
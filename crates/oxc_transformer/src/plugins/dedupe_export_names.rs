@@ -0,0 +1,238 @@
+//! Dedupe Export Names
+//!
+//! This plugin checks that no name is exported more than once, after other transforms
+//! have rewritten the exports of a module, e.g. after `export *` is expanded.
+//! Exporting a name twice is a syntax error.
+//!
+//! * An export which exports the same thing as an earlier export of the same name is removed,
+//!   e.g. `export { x }` after `export { x }` or `export const x = 1`,
+//!   or `export { x } from "m"` after `export { x } from "m"`.
+//! * Otherwise, an error is reported on both exports, e.g. for `export { x as y }` and
+//!   `export { z as y }`, or for `export default x` and `export { x as default }`.
+//!
+//! Only export specifiers and `export * as ns from "m"` can be removed. Declarations, and
+//! `export default`, are never removed, so an export which duplicates one of them is removed
+//! instead. `export default expr` exports the value of `expr`, not a binding, so it is always
+//! reported when it is duplicated.
+//!
+//! Type-only exports (`export type { T }`, `export { type T }`, `export interface T {}`) are
+//! ignored, as a type and a value can share a name. `export * from "m"` doesn't export
+//! any name by itself, and is ignored too.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! export const a = 1;
+//! export { a, b } from "./m";
+//! export { b } from "./m";
+//! ```
+//!
+//! Output, with an error for `a`, which is exported both by `export const a` and by the re-export
+//! of `a` from `./m`:
+//! ```js
+//! export const a = 1;
+//! export { a, b } from "./m";
+//! ```
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_span::{GetSpan, Span};
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+fn duplicate_export(name: &str, span: Span, previous_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Duplicated export '{name}'"))
+        .with_labels([
+            previous_span.label("It has already been exported here"),
+            span.label("It cannot be exported again here"),
+        ])
+        .with_help("Rename or remove one of the exports")
+}
+
+/// What an export exports.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportedValue<'a> {
+    /// `export { x }` or `export const x = 1`
+    Local(Atom<'a>),
+    /// `export { x } from "m"`
+    ReExport { source: Atom<'a>, imported: Atom<'a> },
+    /// `export * as ns from "m"`
+    Namespace(Atom<'a>),
+    /// `export default expr`, which is not the same as any other export.
+    Default,
+}
+
+/// Where an export is, in the body of the program.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportLocation {
+    /// A declaration, or `export default`, which can't be removed.
+    Declaration,
+    /// The specifier at the index in the `export { ... }` statement at the index.
+    Specifier(usize, usize),
+    /// The `export * as ns` statement at the index.
+    Statement(usize),
+}
+
+struct Export<'a> {
+    name: Atom<'a>,
+    value: ExportedValue<'a>,
+    span: Span,
+    location: ExportLocation,
+}
+
+pub struct DedupeExportNames<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> DedupeExportNames<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> DedupeExportNames<'a, '_> {
+    /// Runs after all other transforms, instead of in `exit_program`,
+    /// so the exports which they rewrite are checked.
+    pub fn transform_program(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+
+        let exports = Self::collect_exports(program);
+        let mut first_exports: FxHashMap<Atom<'a>, &Export<'a>> = FxHashMap::default();
+        let mut removed = vec![];
+        for export in &exports {
+            let Some(&first) = first_exports.get(&export.name) else {
+                first_exports.insert(export.name, export);
+                continue;
+            };
+            if first.value == export.value && export.value != ExportedValue::Default {
+                if export.location != ExportLocation::Declaration {
+                    removed.push(export.location);
+                    continue;
+                }
+                if first.location != ExportLocation::Declaration {
+                    // `export { x }; export const x = 1;`, keep the declaration
+                    removed.push(first.location);
+                    first_exports.insert(export.name, export);
+                    continue;
+                }
+            }
+            self.ctx.error(duplicate_export(&export.name, export.span, first.span));
+        }
+
+        if removed.is_empty() {
+            return;
+        }
+        // Remove from the end, so the indexes of the other exports are not affected.
+        removed.sort_unstable_by_key(|location| match *location {
+            ExportLocation::Specifier(stmt, specifier) => (stmt, specifier),
+            ExportLocation::Statement(stmt) => (stmt, 0),
+            ExportLocation::Declaration => unreachable!(),
+        });
+        for location in removed.into_iter().rev() {
+            match location {
+                ExportLocation::Specifier(index, specifier_index) => {
+                    let Statement::ExportNamedDeclaration(decl) = &mut program.body[index] else {
+                        unreachable!()
+                    };
+                    let specifier = decl.specifiers.remove(specifier_index);
+                    if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                        ctx.delete_reference_for_identifier(ident);
+                    }
+                    // `export { x }` -> removed, but `export {}` is left untouched
+                    if decl.specifiers.is_empty() {
+                        program.body.remove(index);
+                    }
+                }
+                ExportLocation::Statement(index) => {
+                    program.body.remove(index);
+                }
+                ExportLocation::Declaration => unreachable!(),
+            }
+        }
+    }
+
+    /// The value exports of the program, in source order.
+    fn collect_exports(program: &Program<'a>) -> Vec<Export<'a>> {
+        let mut exports = vec![];
+        for (index, stmt) in program.body.iter().enumerate() {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(declaration) = &decl.declaration {
+                        if declaration.is_typescript_syntax() {
+                            continue;
+                        }
+                        declaration.bound_names(&mut |ident| {
+                            exports.push(Export {
+                                name: ident.name,
+                                value: ExportedValue::Local(ident.name),
+                                span: ident.span,
+                                location: ExportLocation::Declaration,
+                            });
+                        });
+                    }
+                    for (specifier_index, specifier) in decl.specifiers.iter().enumerate() {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let value = match &decl.source {
+                            Some(source) => ExportedValue::ReExport {
+                                source: source.value,
+                                imported: specifier.local.name(),
+                            },
+                            None => ExportedValue::Local(specifier.local.name()),
+                        };
+                        exports.push(Export {
+                            name: specifier.exported.name(),
+                            value,
+                            span: specifier.exported.span(),
+                            location: ExportLocation::Specifier(index, specifier_index),
+                        });
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let value = match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => func
+                            .id
+                            .as_ref()
+                            .map_or(ExportedValue::Default, |id| ExportedValue::Local(id.name)),
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => class
+                            .id
+                            .as_ref()
+                            .map_or(ExportedValue::Default, |id| ExportedValue::Local(id.name)),
+                        _ => ExportedValue::Default,
+                    };
+                    exports.push(Export {
+                        name: Atom::from("default"),
+                        value,
+                        span: decl.span,
+                        location: ExportLocation::Declaration,
+                    });
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(exported) = &decl.exported {
+                        exports.push(Export {
+                            name: exported.name(),
+                            value: ExportedValue::Namespace(decl.source.value),
+                            span: exported.span(),
+                            location: ExportLocation::Statement(index),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        exports
+    }
+}
@@ -0,0 +1,495 @@
+//! Destructure Member Chains
+//!
+//! This plugin replaces repeated reads of properties of the same object with a destructuring
+//! declaration, e.g. `config.server.port` and `config.server.host` with
+//! `const { port, host } = config.server;`.
+//!
+//! This is an opt-in modernization for readability. The destructuring reads each property once,
+//! right before the first statement which reads one of them, so only reads which can't observe
+//! a difference are replaced:
+//!
+//! * The object must be a binding which is never reassigned, or a chain of static properties of
+//!   one, e.g. `config` or `config.server`. Computed (`config[key]`) and optional
+//!   (`config?.server`) accesses are left untouched.
+//! * The first read must be evaluated whenever its statement is, e.g. not in a branch of an `if`
+//!   or on the right side of `&&`. The statement must not have side effects before the read.
+//! * The group of reads ends at the first code which may mutate the object: calls, `new`,
+//!   assignments to or `delete` of properties, `await`, `yield`, classes and iteration (which may
+//!   run a generator). Loops containing any of those end the group before the loop.
+//! * Reads in nested functions are never replaced, as the functions may run after a mutation.
+//!   Arrow functions with an expression body are left untouched.
+//! * Method calls (`config.server.start()`), assignment targets and `delete` operands are not
+//!   reads, replacing them would change `this` or the object which is written.
+//! * The property name must be a valid identifier which isn't used anywhere else in the program.
+//! * The object must not be declared by the statement with the first read, e.g.
+//!   `const config = {}, url = config.host + config.port;`.
+//!
+//! Getters and other implicitly called code, like `valueOf`, are assumed to be free
+//! of side effects.
+//!
+//! Only objects which are read at least [`DestructureMemberChainsOptions::threshold`] times
+//! are destructured.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function listen(config) {
+//!   const url = config.server.host + ":" + config.server.port;
+//!   start(url, config.server.port);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function listen(config) {
+//!   const { host, port } = config.server;
+//!   const url = host + ":" + port;
+//!   start(url, port);
+//! }
+//! ```
+
+use std::mem;
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{AstKind, NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_syntax::keyword::is_reserved_keyword_or_global_object;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DestructureMemberChainsOptions {
+    /// Minimum number of reads of properties of the same object for them to be destructured.
+    ///
+    /// Default: `2`
+    pub threshold: usize,
+}
+
+impl Default for DestructureMemberChainsOptions {
+    fn default() -> Self {
+        Self { threshold: 2 }
+    }
+}
+
+pub struct DestructureMemberChains<'a> {
+    options: DestructureMemberChainsOptions,
+    /// Property reads to replace, by span, with the binding destructured from their object.
+    replacements: FxHashMap<Span, BoundIdentifier<'a>>,
+}
+
+impl DestructureMemberChains<'_> {
+    pub fn new(options: DestructureMemberChainsOptions) -> Self {
+        Self { options, replacements: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DestructureMemberChains<'a> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `() => config.a + config.b` has no room for a declaration.
+        if let Ancestor::ArrowFunctionExpressionBody(arrow) = ctx.ancestor(1)
+            && *arrow.expression()
+        {
+            return;
+        }
+
+        let mut collector = EventCollector::new(ctx.scoping(), &self.replacements);
+        for (index, stmt) in stmts.iter().enumerate() {
+            collector.statement = index;
+            collector.visit_statement(stmt);
+        }
+        let groups = Self::group_accesses(collector.events);
+
+        let mut declarations = vec![];
+        for mut group in groups {
+            if group.accesses.len() < self.options.threshold {
+                continue;
+            }
+            // `const config = {}, url = config.host + config.port;` can't read `config` before
+            // the statement which declares it.
+            let symbol_span = ctx.scoping().symbol_span(group.base.symbol_id);
+            if stmts[group.statement].span().contains_inclusive(symbol_span) {
+                continue;
+            }
+            let mut properties: Vec<Atom<'a>> = vec![];
+            for (property, _) in &group.accesses {
+                if !properties.contains(property) {
+                    properties.push(*property);
+                }
+            }
+            properties.retain(|property| Self::is_available_name(property, ctx));
+            group.accesses.retain(|(property, _)| properties.contains(property));
+            if properties.is_empty() || group.accesses.len() < self.options.threshold {
+                continue;
+            }
+
+            let scope_id = ctx.current_scope_id();
+            let bindings = properties
+                .iter()
+                .map(|&property| {
+                    let binding = ctx.generate_binding(
+                        property,
+                        scope_id,
+                        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+                    );
+                    (property, binding)
+                })
+                .collect::<Vec<_>>();
+            for (property, span) in group.accesses {
+                let (_, binding) = bindings.iter().find(|(name, _)| *name == property).unwrap();
+                self.replacements.insert(span, binding.clone());
+            }
+            declarations
+                .push((group.statement, Self::create_declaration(&group.base, &bindings, ctx)));
+        }
+
+        // Insert from the back, so the indexes of the remaining declarations stay valid.
+        for (index, declaration) in declarations.into_iter().rev() {
+            stmts.insert(index, declaration);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::StaticMemberExpression(member) = expr else { return };
+        let Some(binding) = self.replacements.remove(&member.span) else { return };
+        let mut object = &member.object;
+        while let Expression::StaticMemberExpression(member) = object {
+            object = &member.object;
+        }
+        if let Expression::Identifier(ident) = object {
+            ctx.delete_reference_for_identifier(ident);
+        }
+        *expr = binding.create_read_expression(ctx);
+    }
+}
+
+impl<'a> DestructureMemberChains<'a> {
+    /// Groups the reads of properties of the same object, which can be replaced by one declaration.
+    fn group_accesses(events: Vec<Event<'a>>) -> Vec<Group<'a>> {
+        let mut groups = vec![];
+        let mut open: Vec<Group<'a>> = vec![];
+        let mut side_effect_statement = None;
+        for event in events {
+            match event {
+                Event::SideEffect { statement } => {
+                    groups.append(&mut open);
+                    side_effect_statement = Some(statement);
+                }
+                Event::Access { base, property, span, statement, unconditional } => {
+                    if let Some(group) = open.iter_mut().find(|group| group.base == base) {
+                        group.accesses.push((property, span));
+                    } else if unconditional && side_effect_statement != Some(statement) {
+                        open.push(Group { base, statement, accesses: vec![(property, span)] });
+                    }
+                }
+            }
+        }
+        groups.append(&mut open);
+        groups.sort_by_key(|group| group.statement);
+        groups
+    }
+
+    /// A name for a binding which doesn't shadow, and isn't shadowed by, any other binding.
+    fn is_available_name(name: &str, ctx: &TraverseCtx<'a>) -> bool {
+        !is_reserved_keyword_or_global_object(name)
+            && !matches!(name, "arguments" | "eval")
+            && !ctx.scoping().root_unresolved_references().contains_key(name)
+            && !ctx.scoping().symbol_names().any(|symbol_name| symbol_name == name)
+    }
+
+    /// `const { port, host } = config.server;`
+    fn create_declaration(
+        base: &Base<'a>,
+        bindings: &[(Atom<'a>, BoundIdentifier<'a>)],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut init =
+            ctx.create_bound_ident_expr(SPAN, base.name, base.symbol_id, ReferenceFlags::Read);
+        for &property in &base.path {
+            init = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                init,
+                ctx.ast.identifier_name(SPAN, property),
+                false,
+            ));
+        }
+        let properties = ctx.ast.vec_from_iter(bindings.iter().map(|(property, binding)| {
+            ctx.ast.binding_property(
+                SPAN,
+                ctx.ast.property_key_static_identifier(SPAN, *property),
+                binding.create_binding_pattern(ctx),
+                true,
+                false,
+            )
+        }));
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Const,
+            ctx.ast.binding_pattern_object_pattern(SPAN, properties, NONE),
+            NONE,
+            Some(init),
+            false,
+        );
+        Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Const,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+}
+
+/// An object reached through static properties of a binding, e.g. `config.server`.
+#[derive(PartialEq, Eq)]
+struct Base<'a> {
+    symbol_id: SymbolId,
+    name: Atom<'a>,
+    path: Vec<Atom<'a>>,
+}
+
+/// Reads of properties of `base`, destructured right before `statement`.
+struct Group<'a> {
+    base: Base<'a>,
+    statement: usize,
+    accesses: Vec<(Atom<'a>, Span)>,
+}
+
+enum Event<'a> {
+    /// A read of `base.property`.
+    Access { base: Base<'a>, property: Atom<'a>, span: Span, statement: usize, unconditional: bool },
+    /// Code which may mutate an object.
+    SideEffect { statement: usize },
+}
+
+/// Collects the property reads and side effects of a statement list, in evaluation order.
+struct EventCollector<'a, 'b> {
+    scoping: &'b Scoping,
+    /// Reads which are already replaced by the destructuring of an outer statement list.
+    replacements: &'b FxHashMap<Span, BoundIdentifier<'a>>,
+    events: Vec<Event<'a>>,
+    /// Index of the statement being visited.
+    statement: usize,
+    /// Number of ancestors below the statement which may not evaluate the current node.
+    conditional_depth: usize,
+    /// The next expression is the callee of a call or the tag of a tagged template.
+    is_callee: bool,
+}
+
+impl<'a, 'b> EventCollector<'a, 'b> {
+    fn new(scoping: &'b Scoping, replacements: &'b FxHashMap<Span, BoundIdentifier<'a>>) -> Self {
+        Self {
+            scoping,
+            replacements,
+            events: vec![],
+            statement: 0,
+            conditional_depth: 0,
+            is_callee: false,
+        }
+    }
+
+    fn push_side_effect(&mut self) {
+        self.events.push(Event::SideEffect { statement: self.statement });
+    }
+
+    /// `config.server.port` -> (`config`, `[server]`)
+    fn to_base(&self, member: &StaticMemberExpression<'a>) -> Option<Base<'a>> {
+        if member.optional || member.span.is_empty() {
+            return None;
+        }
+        let mut path = vec![];
+        let mut object = &member.object;
+        let ident = loop {
+            match object {
+                Expression::StaticMemberExpression(member) if !member.optional => {
+                    path.push(member.property.name);
+                    object = &member.object;
+                }
+                Expression::Identifier(ident) => break ident,
+                _ => return None,
+            }
+        };
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id()?;
+        if self.scoping.symbol_is_mutated(symbol_id) {
+            return None;
+        }
+        path.reverse();
+        Some(Base { symbol_id, name: ident.name, path })
+    }
+
+    /// Nodes which always evaluate their children when they are evaluated themselves.
+    fn is_unconditional(kind: AstKind<'a>) -> bool {
+        match kind {
+            AstKind::AssignmentExpression(expr) => !expr.operator.is_logical(),
+            AstKind::ExpressionStatement(_)
+            | AstKind::VariableDeclaration(_)
+            | AstKind::VariableDeclarator(_)
+            | AstKind::ReturnStatement(_)
+            | AstKind::ExportNamedDeclaration(_)
+            | AstKind::ExportDefaultDeclaration(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::SequenceExpression(_)
+            | AstKind::BinaryExpression(_)
+            | AstKind::UnaryExpression(_)
+            | AstKind::ArrayExpression(_)
+            | AstKind::ObjectExpression(_)
+            | AstKind::ObjectProperty(_)
+            | AstKind::SpreadElement(_)
+            | AstKind::TemplateLiteral(_)
+            | AstKind::StaticMemberExpression(_)
+            | AstKind::ComputedMemberExpression(_)
+            | AstKind::CallExpression(_)
+            | AstKind::NewExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_)
+            | AstKind::TSTypeAssertion(_) => true,
+            _ => false,
+        }
+    }
+
+    /// A side effect anywhere in a loop body may happen before any read in the next iteration.
+    fn visit_loop(&mut self, walk: impl FnOnce(&mut Self)) {
+        let start = self.events.len();
+        walk(self);
+        if self.events[start..].iter().any(|event| matches!(event, Event::SideEffect { .. })) {
+            self.events.insert(start, Event::SideEffect { statement: self.statement });
+        }
+    }
+}
+
+impl<'a> Visit<'a> for EventCollector<'a, '_> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::ArrayPattern(_) | AstKind::ArrayAssignmentTarget(_) => self.push_side_effect(),
+            AstKind::VariableDeclaration(decl) if decl.kind.is_using() => self.push_side_effect(),
+            _ => {}
+        }
+        if !Self::is_unconditional(kind) {
+            self.conditional_depth += 1;
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if !Self::is_unconditional(kind) {
+            self.conditional_depth -= 1;
+        }
+        if matches!(
+            kind,
+            AstKind::CallExpression(_)
+                | AstKind::NewExpression(_)
+                | AstKind::TaggedTemplateExpression(_)
+                | AstKind::ImportExpression(_)
+                | AstKind::AwaitExpression(_)
+                | AstKind::YieldExpression(_)
+                | AstKind::SpreadElement(_)
+        ) {
+            self.push_side_effect();
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        let is_callee = mem::take(&mut self.is_callee);
+        match expr {
+            Expression::StaticMemberExpression(member) => {
+                if self.replacements.contains_key(&member.span) {
+                    return;
+                }
+                let Some(base) = self.to_base(member) else {
+                    walk::walk_expression(self, expr);
+                    return;
+                };
+                if is_callee {
+                    // `config.server.start()` -> only `config.server` is a read.
+                    self.visit_expression(&member.object);
+                } else {
+                    self.events.push(Event::Access {
+                        base,
+                        property: member.property.name,
+                        span: member.span,
+                        statement: self.statement,
+                        unconditional: self.conditional_depth == 0,
+                    });
+                }
+            }
+            // `(config.server.start)()` still calls `start` with `this` set to `config.server`.
+            Expression::ParenthesizedExpression(_)
+            | Expression::TSAsExpression(_)
+            | Expression::TSSatisfiesExpression(_)
+            | Expression::TSNonNullExpression(_)
+            | Expression::TSTypeAssertion(_) => {
+                self.is_callee = is_callee;
+                walk::walk_expression(self, expr);
+            }
+            _ => walk::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        self.is_callee = true;
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &TaggedTemplateExpression<'a>) {
+        self.is_callee = true;
+        walk::walk_tagged_template_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        if target.is_member_expression() {
+            self.push_side_effect();
+        } else {
+            walk::walk_simple_assignment_target(self, target);
+        }
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator.is_delete() {
+            self.push_side_effect();
+        } else {
+            walk::walk_unary_expression(self, expr);
+        }
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.visit_loop(|visitor| walk::walk_do_while_statement(visitor, stmt));
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.visit_loop(|visitor| walk::walk_while_statement(visitor, stmt));
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.visit_loop(|visitor| walk::walk_for_statement(visitor, stmt));
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.visit_loop(|visitor| walk::walk_for_in_statement(visitor, stmt));
+    }
+
+    fn visit_for_of_statement(&mut self, _stmt: &ForOfStatement<'a>) {
+        self.push_side_effect();
+    }
+
+    fn visit_class(&mut self, _class: &Class<'a>) {
+        self.push_side_effect();
+    }
+
+    fn visit_with_statement(&mut self, _stmt: &WithStatement<'a>) {
+        self.push_side_effect();
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
@@ -0,0 +1,108 @@
+//! Direct `eval`
+//!
+//! This plugin reports direct `eval` calls, and optionally converts them to indirect `eval` calls.
+//!
+//! A direct `eval` call (`eval(code)`) can read and write every variable in scope, so minifiers
+//! and bundlers can't rename, inline or remove any of them, or move the code to another scope.
+//! An indirect `eval` call (`(0, eval)(code)`) evaluates the code in the global scope instead.
+//!
+//! * Without options, a warning is reported for each direct `eval` call, and nothing is transformed.
+//! * With `indirect: true`, direct `eval` calls are converted to indirect `eval` calls, and a warning
+//!   is reported for each converted call, as the code can't access local variables anymore.
+//!
+//! Only calls of the global `eval` are direct `eval` calls. Calls of a shadowed `eval`
+//! (`function f(eval) { eval(x) }`), optional calls (`eval?.(x)`) and calls of a member
+//! (`window.eval(x)`, `globalThis.eval(x)`) are indirect already, and are left unchanged.
+//!
+//! ## Example
+//!
+//! Input (with `indirect: true`):
+//! ```js
+//! function run(code) {
+//!   return eval(code);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function run(code) {
+//!   return (0, eval)(code);
+//! }
+//! ```
+//!
+//! ## References
+//!
+//! * Direct `eval`: <https://tc39.es/ecma262/#sec-function-calls-runtime-semantics-evaluation>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{SPAN, Span};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DirectEvalOptions {
+    /// Convert direct `eval` calls to indirect `eval` calls, which evaluate the code
+    /// in the global scope.
+    ///
+    /// Default: `false`, which only reports direct `eval` calls.
+    pub indirect: bool,
+}
+
+fn direct_eval(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Direct `eval` prevents optimizing the variables in scope.")
+        .with_label(span)
+        .with_help(
+            "Use an indirect `eval` call (`(0, eval)(code)`) if the code doesn't need local variables.",
+        )
+}
+
+fn converted_to_indirect_eval(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Direct `eval` was converted to indirect `eval`, which evaluates the code in the global scope.",
+    )
+    .with_label(span)
+}
+
+pub struct DirectEval<'a, 'ctx> {
+    options: DirectEvalOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> DirectEval<'a, 'ctx> {
+    pub fn new(options: DirectEvalOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DirectEval<'a, '_> {
+    // `eval(x)` -> `(0, eval)(x)`
+    fn enter_call_expression(&mut self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `(eval)(x)` is a direct `eval` call too.
+        if call.optional
+            || !call.callee.without_parentheses().is_global_reference_name("eval", ctx.scoping())
+        {
+            return;
+        }
+
+        if !self.options.indirect {
+            self.ctx.error(direct_eval(call.span));
+            return;
+        }
+
+        self.ctx.error(converted_to_indirect_eval(call.span));
+        let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+        let callee = call.callee.take_in(ctx.ast);
+        call.callee = ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_array([zero, callee]));
+    }
+}
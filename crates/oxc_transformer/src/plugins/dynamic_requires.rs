@@ -0,0 +1,162 @@
+//! Dynamic Requires
+//!
+//! This plugin converts the `require` calls of an ES module which can't become import
+//! declarations, e.g. conditional requires or requires with a dynamic specifier,
+//! to `await import()`.
+//!
+//! > This plugin is only applied to ES modules. CommonJS scripts are left unchanged.
+//!
+//! * `cond ? require("a") : require("b")` -> `cond ? await import("a") : await import("b")`
+//! * `` require(`./locale/${lang}.js`) `` -> `` await import(`./locale/${lang}.js`) ``
+//! * `if (cond) require("a");` -> `if (cond) await import("a");`
+//!
+//! A `require` call is converted at the top level of the module, where top-level `await` is
+//! available, and in the body of an async function. Anywhere else, e.g. in a non-async function,
+//! in parameters or in class field initializers, a warning is reported and the call is left
+//! unchanged.
+//!
+//! `await import()` evaluates to the module namespace object, so destructuring the result
+//! (`const { a } = cond ? require("a") : require("b")`) reads the named exports of the module.
+//! For a CommonJS dependency, the value of `module.exports` is its `default` export.
+//!
+//! Static `require` statements at the top level (`const a = require("a");`) are not converted,
+//! as they can be converted to import declarations instead. `require` calls with a shadowed
+//! `require`, or without exactly one argument, are left unchanged.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const fs = require("fs");
+//! const impl = process.env.NODE_ENV === "production" ? require("./prod.js") : require("./dev.js");
+//! async function load(lang) {
+//!   return require(`./locale/${lang}.js`);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! const fs = require("fs");
+//! const impl = process.env.NODE_ENV === "production" ? await import("./prod.js") : await import("./dev.js");
+//! async function load(lang) {
+//!   return await import(`./locale/${lang}.js`);
+//! }
+//! ```
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::Span;
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+fn require_not_converted(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "`require` call was not converted to `import()`, because `await` is not allowed here.",
+    )
+    .with_label(span)
+    .with_help("Move the `require` call to an async function, or to the top level of the module.")
+}
+
+pub struct DynamicRequires<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// The program is an ES module.
+    is_module: bool,
+}
+
+impl<'a, 'ctx> DynamicRequires<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, is_module: false }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DynamicRequires<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+    }
+
+    // `require(x)` -> `await import(x)`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional
+            || call.arguments.len() != 1
+            || call.arguments[0].is_spread()
+            || !call.callee.is_global_reference_name("require", ctx.scoping())
+            || Self::is_top_level_require_statement(call, ctx)
+        {
+            return;
+        }
+        if !Self::is_await_allowed(ctx) {
+            self.ctx.error(require_not_converted(call.span));
+            return;
+        }
+
+        if let Expression::Identifier(ident) = &call.callee {
+            ctx.delete_reference_for_identifier(ident);
+        }
+        let span = call.span;
+        let source = call.arguments.pop().unwrap().into_expression();
+        let import = ctx.ast.expression_import(span, source, None, None);
+        *expr = ctx.ast.expression_await(span, import);
+    }
+}
+
+impl<'a> DynamicRequires<'a, '_> {
+    /// `require("m");`, `const m = require("m");` or `export const m = require("m").a;`
+    /// at the top level, with a static specifier.
+    fn is_top_level_require_statement(call: &CallExpression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let is_static = match &call.arguments[0] {
+            Argument::StringLiteral(_) => true,
+            Argument::TemplateLiteral(lit) => lit.is_no_substitution_template(),
+            _ => false,
+        };
+        if !is_static {
+            return false;
+        }
+
+        let mut ancestors = ctx.ancestors();
+        let mut parent = ancestors.next();
+        if matches!(parent, Some(Ancestor::StaticMemberExpressionObject(_))) {
+            parent = ancestors.next();
+        }
+        match parent {
+            Some(Ancestor::ExpressionStatementExpression(_)) => {}
+            Some(Ancestor::VariableDeclaratorInit(_)) => {
+                if !matches!(ancestors.next(), Some(Ancestor::VariableDeclarationDeclarations(_))) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+        let mut statement_parent = ancestors.next();
+        if matches!(statement_parent, Some(Ancestor::ExportNamedDeclarationDeclaration(_))) {
+            statement_parent = ancestors.next();
+        }
+        matches!(statement_parent, Some(Ancestor::ProgramBody(_)))
+    }
+
+    /// `await` is allowed at the top level of a module and in the body of an async function.
+    fn is_await_allowed(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::FunctionBody(func) => return *func.r#async(),
+                Ancestor::ArrowFunctionExpressionBody(func) => return *func.r#async(),
+                Ancestor::FunctionParams(_)
+                | Ancestor::ArrowFunctionExpressionParams(_)
+                | Ancestor::PropertyDefinitionValue(_)
+                | Ancestor::AccessorPropertyValue(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleDeclarationBody(_) => return false,
+                _ => {}
+            }
+        }
+        true
+    }
+}
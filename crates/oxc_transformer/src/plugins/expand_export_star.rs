@@ -0,0 +1,213 @@
+//! Expand Export Star
+//!
+//! This plugin rewrites `export * from "./m"` into explicit named re-exports,
+//! using a caller provided resolver to look up the names exported by `./m`.
+//!
+//! `export *` is opaque to tools which only look at a single module, e.g. for tree shaking.
+//! Listing the re-exported names makes them statically visible.
+//!
+//! The expansion follows the semantics of `export *`:
+//!
+//! * `default` is never re-exported.
+//! * Names which the module exports itself take precedence over names from `export *`.
+//! * `export *` of the same module more than once is expanded once.
+//!
+//! Names provided by `export *` of more than one module are either ambiguous, and not exported,
+//! or re-export the same binding. This is only known after resolving the bindings,
+//! so `export *` which provide such names are left untouched.
+//!
+//! `export * as ns from "./m"` and `export type * from "./m"` are left untouched.
+//! If the resolver can't resolve any `export *` of a module, none of them are expanded,
+//! because the names from the unknown module could make the other names ambiguous.
+//!
+//! ## Example
+//!
+//! Input, with `./m` exporting `a`, `b` and `default`:
+//! ```js
+//! export * from "./m";
+//! export const b = 1;
+//! ```
+//!
+//! Output:
+//! ```js
+//! export { a } from "./m";
+//! export const b = 1;
+//! ```
+
+use std::{fmt, sync::Arc};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_span::SPAN;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+/// Returns the names exported by the module with the given specifier,
+/// or `None` if they are not known.
+pub type ExportNamesResolver = Arc<dyn Fn(&str) -> Option<Vec<String>> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ExpandExportStarOptions {
+    pub resolver: ExportNamesResolver,
+}
+
+impl ExpandExportStarOptions {
+    pub fn new(resolver: ExportNamesResolver) -> Self {
+        Self { resolver }
+    }
+}
+
+impl fmt::Debug for ExpandExportStarOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpandExportStarOptions").finish_non_exhaustive()
+    }
+}
+
+pub struct ExpandExportStar {
+    options: ExpandExportStarOptions,
+}
+
+impl ExpandExportStar {
+    pub fn new(options: ExpandExportStarOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ExpandExportStar {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_star = |decl: &ExportAllDeclaration<'a>| {
+            decl.exported.is_none() && decl.export_kind.is_value()
+        };
+
+        // Resolve the names of every module re-exported by `export *`, in order.
+        // `export *` of the same module more than once re-exports the same names.
+        let mut resolved = vec![];
+        for stmt in &program.body {
+            if let Statement::ExportAllDeclaration(decl) = stmt
+                && is_star(decl)
+                && !resolved.iter().any(|(source, _)| *source == decl.source.value)
+            {
+                let Some(names) = (self.options.resolver)(&decl.source.value) else {
+                    return;
+                };
+                resolved.push((decl.source.value, names));
+            }
+        }
+        if resolved.is_empty() {
+            return;
+        }
+
+        let local_names = Self::collect_local_export_names(program);
+        let resolved = resolved
+            .iter()
+            .map(|(source, names)| {
+                // A module can't export the same name twice, but don't trust the resolver on that.
+                let mut seen = FxHashSet::default();
+                let names = names
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|name| {
+                        *name != "default" && !local_names.contains(name) && seen.insert(*name)
+                    })
+                    .collect::<Vec<_>>();
+                (*source, names)
+            })
+            .collect::<Vec<_>>();
+
+        let mut counts = FxHashMap::<&str, usize>::default();
+        for (_, names) in &resolved {
+            for name in names {
+                *counts.entry(name).or_default() += 1;
+            }
+        }
+        // Only expand modules which don't share any name with another module.
+        let mut expandable = resolved
+            .into_iter()
+            .filter(|(_, names)| names.iter().all(|name| counts[name] == 1))
+            .collect::<FxHashMap<_, _>>();
+        if expandable.is_empty() {
+            return;
+        }
+
+        let mut expanded = FxHashSet::default();
+        program.body.retain_mut(|stmt| {
+            let Statement::ExportAllDeclaration(decl) = stmt else { return true };
+            if !is_star(decl) {
+                return true;
+            }
+            // The names are already re-exported by a previous `export *` of the same module.
+            if expanded.contains(&decl.source.value) {
+                return false;
+            }
+            let Some(names) = expandable.remove(&decl.source.value) else { return true };
+            expanded.insert(decl.source.value);
+
+            let specifiers = ctx.ast.vec_from_iter(names.into_iter().map(|name| {
+                let name = ctx.ast.atom(name);
+                ctx.ast.export_specifier(
+                    SPAN,
+                    Self::create_module_export_name(name, ctx),
+                    Self::create_module_export_name(name, ctx),
+                    ImportOrExportKind::Value,
+                )
+            }));
+
+            // `export * from "./m"` -> `export { a, b } from "./m"`
+            let (span, source, with_clause) =
+                (decl.span, decl.source.clone(), decl.with_clause.take());
+            *stmt = Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+                span,
+                None,
+                specifiers,
+                Some(source),
+                ImportOrExportKind::Value,
+                with_clause,
+            ));
+            true
+        });
+    }
+}
+
+impl<'a> ExpandExportStar {
+    /// Names exported by the module itself, which shadow names from `export *`.
+    fn collect_local_export_names(program: &Program<'a>) -> FxHashSet<&'a str> {
+        let mut names = FxHashSet::default();
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        declaration.bound_names(&mut |ident| {
+                            names.insert(ident.name.as_str());
+                        });
+                    }
+                    for specifier in &decl.specifiers {
+                        names.insert(specifier.exported.name().as_str());
+                    }
+                }
+                Statement::ExportDefaultDeclaration(_) => {
+                    names.insert("default");
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    if let Some(exported) = &decl.exported {
+                        names.insert(exported.name().as_str());
+                    }
+                }
+                _ => {}
+            }
+        }
+        names
+    }
+
+    /// `a` or `"a-b"`
+    fn create_module_export_name(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> ModuleExportName<'a> {
+        if is_identifier_name(&name) {
+            ctx.ast.module_export_name_identifier_name(SPAN, name)
+        } else {
+            ctx.ast.module_export_name_string_literal(SPAN, name, None)
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Explicit Radix
+//!
+//! This plugin adds an explicit radix to `parseInt` calls which don't specify one,
+//! so that the string is always parsed in base [`ExplicitRadixOptions::radix`].
+//!
+//! Without a radix, `parseInt` parses strings starting with `0x` as hexadecimal,
+//! and some legacy engines parse strings starting with `0` as octal.
+//! Note that this changes the result for such strings, e.g. `parseInt("0x10")` is `16`,
+//! but `parseInt("0x10", 10)` is `0`, so this is an opt-in transform for code which expects
+//! decimal input.
+//!
+//! Only calls to the global `parseInt` and `Number.parseInt` with a single argument are changed.
+//! Calls which already specify a radix, calls with a spread argument, and calls to a local
+//! variable named `parseInt` or `Number` are left untouched.
+//! `parseFloat` always parses decimal strings, so it has no radix.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const a = parseInt(input);
+//! const b = Number.parseInt(input);
+//! ```
+//!
+//! Output:
+//! ```js
+//! const a = parseInt(input, 10);
+//! const b = Number.parseInt(input, 10);
+//! ```
+
+use serde::{Deserialize, Deserializer, de::Error};
+
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::SPAN;
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExplicitRadixOptions {
+    /// Radix added to calls which don't specify one, between `2` and `36`.
+    ///
+    /// Default: `10`
+    #[serde(deserialize_with = "deserialize_radix")]
+    pub radix: u32,
+}
+
+impl Default for ExplicitRadixOptions {
+    fn default() -> Self {
+        Self { radix: 10 }
+    }
+}
+
+fn deserialize_radix<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let radix = u32::deserialize(deserializer)?;
+    if (2..=36).contains(&radix) {
+        Ok(radix)
+    } else {
+        Err(D::Error::custom(format!("radix must be between 2 and 36, got {radix}")))
+    }
+}
+
+pub struct ExplicitRadix {
+    options: ExplicitRadixOptions,
+}
+
+impl ExplicitRadix {
+    pub fn new(options: ExplicitRadixOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ExplicitRadix {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if call.arguments.len() != 1
+            || call.arguments[0].is_spread()
+            || !Self::is_parse_int(&call.callee, ctx)
+        {
+            return;
+        }
+        let radix = ctx.ast.expression_numeric_literal(
+            SPAN,
+            f64::from(self.options.radix),
+            None,
+            NumberBase::Decimal,
+        );
+        call.arguments.push(Argument::from(radix));
+    }
+}
+
+impl<'a> ExplicitRadix {
+    /// `parseInt` or `Number.parseInt`, referencing the globals.
+    fn is_parse_int(callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let callee = callee.without_parentheses();
+        match callee {
+            Expression::Identifier(_) => callee.is_global_reference_name("parseInt", ctx.scoping()),
+            Expression::StaticMemberExpression(member) => {
+                member.property.name == "parseInt"
+                    && member.object.is_global_reference_name("Number", ctx.scoping())
+            }
+            _ => false,
+        }
+    }
+}
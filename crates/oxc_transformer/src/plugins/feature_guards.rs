@@ -0,0 +1,170 @@
+//! Feature Guards
+//!
+//! This plugin folds feature detection guards of globals, such as `typeof BigInt !== "undefined"`,
+//! to `true` or `false` when it is known whether the global is available in the target environment,
+//! so that dead code elimination (e.g. by the minifier) can remove the unused branch.
+//!
+//! The availability of each global is configured with [`FeatureGuardsOptions::features`].
+//! `BigInt` is also known to be unavailable if the target environment doesn't support BigInt literals.
+//!
+//! Folded:
+//!
+//! * `typeof X === "undefined"` and `typeof X !== "undefined"`, with `==`, `!=` or swapped operands.
+//! * `typeof X === "function"` etc. when `X` is unavailable, as its type must be `"undefined"`.
+//! * `X` can be a global identifier or a member path of one, e.g. `Intl.Segmenter`.
+//!   Shadowed globals are not folded.
+//! * Combined guards, once their operands are folded: `!true` -> `false`, `true && x` -> `x`
+//!   and `false || x` -> `x`.
+//!
+//! Branches are never removed, so they stay consistent with the guard even if they use the feature,
+//! e.g. `false && BigInt(1)` keeps the reference to `BigInt` until dead code elimination removes it.
+//!
+//! ## Example
+//!
+//! Options:
+//! ```json
+//! { "features": { "BigInt": true, "Intl.Segmenter": false } }
+//! ```
+//!
+//! Input:
+//! ```js
+//! const big = typeof BigInt !== "undefined" ? BigInt(value) : value;
+//! if (typeof Intl !== "undefined" && typeof Intl.Segmenter === "function") {
+//!   segment(text);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! const big = true ? BigInt(value) : value;
+//! if (typeof Intl !== "undefined" && false) {
+//!   segment(text);
+//! }
+//! ```
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, options::EnvOptions, state::TransformState};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct FeatureGuardsOptions {
+    /// Whether each global is available in the target environment,
+    /// keyed by its name or member path, e.g. `{ "BigInt": true, "Intl.Segmenter": false }`.
+    ///
+    /// Guards of globals which are not listed are not folded.
+    ///
+    /// Default: `{}`
+    pub features: FxHashMap<String, bool>,
+}
+
+pub struct FeatureGuards {
+    features: FxHashMap<String, bool>,
+}
+
+impl FeatureGuards {
+    pub fn new(options: FeatureGuardsOptions, env: &EnvOptions) -> Self {
+        let mut features = options.features;
+        if env.es2020.big_int {
+            features.entry("BigInt".to_string()).or_insert(false);
+        }
+        Self { features }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for FeatureGuards {
+    // Operands are folded before the expressions which contain them.
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::BinaryExpression(binary) => {
+                if let Some(value) = self.fold_typeof_comparison(binary, ctx) {
+                    *expr = ctx.ast.expression_boolean_literal(binary.span, value);
+                }
+            }
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                if let Expression::BooleanLiteral(lit) = &unary.argument {
+                    *expr = ctx.ast.expression_boolean_literal(unary.span, !lit.value);
+                }
+            }
+            Expression::LogicalExpression(logical) => {
+                let Expression::BooleanLiteral(lit) = &logical.left else { return };
+                // `true && x` -> `x`, `false || x` -> `x`
+                let folds = match logical.operator {
+                    LogicalOperator::And => lit.value,
+                    LogicalOperator::Or => !lit.value,
+                    LogicalOperator::Coalesce => false,
+                };
+                if folds {
+                    *expr = logical.right.take_in(ctx.ast);
+                }
+            }
+            // `(true)` -> `true`
+            Expression::ParenthesizedExpression(paren) => {
+                if let Expression::BooleanLiteral(lit) = &paren.expression {
+                    *expr = ctx.ast.expression_boolean_literal(paren.span, lit.value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl FeatureGuards {
+    /// Returns the value of `typeof X === "type"` if the availability of `X` is known.
+    fn fold_typeof_comparison(
+        &self,
+        binary: &BinaryExpression<'_>,
+        ctx: &mut TraverseCtx<'_>,
+    ) -> Option<bool> {
+        let negated = match binary.operator {
+            BinaryOperator::Equality | BinaryOperator::StrictEquality => false,
+            BinaryOperator::Inequality | BinaryOperator::StrictInequality => true,
+            _ => return None,
+        };
+        let (unary, ty) = match (&binary.left, &binary.right) {
+            (Expression::UnaryExpression(unary), Expression::StringLiteral(ty))
+            | (Expression::StringLiteral(ty), Expression::UnaryExpression(unary))
+                if unary.operator == UnaryOperator::Typeof =>
+            {
+                (unary, ty)
+            }
+            _ => return None,
+        };
+        let (path, root) = feature_path(&unary.argument, ctx)?;
+        let available = *self.features.get(&path)?;
+        let equal = match (available, ty.value == "undefined") {
+            (false, is_undefined) => is_undefined,
+            (true, true) => false,
+            // The type of an available global is not known.
+            (true, false) => return None,
+        };
+        ctx.delete_reference_for_identifier(root);
+        Some(equal != negated)
+    }
+}
+
+/// `Intl.Segmenter` -> `("Intl.Segmenter", Intl)`, if `Intl` is a global reference.
+fn feature_path<'e, 'a>(
+    expr: &'e Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Option<(String, &'e IdentifierReference<'a>)> {
+    match expr {
+        Expression::Identifier(ident) if ident.is_global_reference(ctx.scoping()) => {
+            Some((ident.name.to_string(), ident))
+        }
+        Expression::StaticMemberExpression(member) => {
+            let (mut path, root) = feature_path(&member.object, ctx)?;
+            path.push('.');
+            path.push_str(&member.property.name);
+            Some((path, root))
+        }
+        _ => None,
+    }
+}
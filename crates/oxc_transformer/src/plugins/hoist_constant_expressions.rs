@@ -0,0 +1,184 @@
+//! Hoist Constant Expressions
+//!
+//! This plugin hoists constant expressions which are recreated on every call of a function
+//! up to module scope, so they are only created once.
+//!
+//! This is an opt-in performance optimization. Hoisting changes object identity
+//! (every call now observes the same object), so only expressions which can't be observably
+//! mutated are hoisted:
+//!
+//! * Regular expression literals without the `g` or `y` flag, which are only used as the receiver
+//!   of a `test` or `exec` call, e.g. `/^\d+$/.test(s)`. Those flags make `test` and `exec`
+//!   stateful through `lastIndex`. A literal which is used in any other way (assigned, passed
+//!   as an argument, or its properties read or written) could be mutated or compared,
+//!   so it is not hoisted.
+//! * `Object.freeze([...])` and `Object.freeze({...})` where every element or property value
+//!   is a primitive literal.
+//!
+//! Expressions which are already at the top level, or which are inside a class body
+//! outside of any function, are left untouched.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function isDigits(s) {
+//!   return /^\d+$/.test(s);
+//! }
+//! function keys() {
+//!   return Object.freeze(["a", "b"]);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _regex = /^\d+$/;
+//! var _frozen = Object.freeze(["a", "b"]);
+//! function isDigits(s) {
+//!   return _regex.test(s);
+//! }
+//! function keys() {
+//!   return _frozen;
+//! }
+//! ```
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{IsGlobalReference, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct HoistConstantExpressions<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for HoistConstantExpressions<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_constant_expression(expr, ctx);
+    }
+}
+
+impl<'a, 'ctx> HoistConstantExpressions<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+
+    fn hoist_constant_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+
+        // Only hoist out of functions, top level expressions are evaluated once anyway.
+        if ctx.current_hoist_scope_id() == ctx.scoping().root_scope_id() {
+            return;
+        }
+
+        if let Some(regexp) = Self::stateless_regexp_receiver(call) {
+            self.hoist(regexp, "regex", ctx);
+        } else if Self::is_frozen_literal(call, ctx) {
+            self.hoist(expr, "frozen", ctx);
+        }
+    }
+
+    /// Replace `expr` with a reference to a module scope variable initialized to `expr`.
+    fn hoist(&self, expr: &mut Expression<'a>, name: &str, ctx: &mut TraverseCtx<'a>) {
+        let binding = ctx.generate_uid(
+            name,
+            ctx.scoping().root_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let init = expr.take_in(ctx.ast);
+        *expr = binding.create_read_expression(ctx);
+
+        // `var _regex = /.../;` or `var _frozen = Object.freeze(...);`
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            Some(init),
+            false,
+        );
+        let stmt = Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        ));
+        self.ctx.top_level_statements.insert_statement(stmt);
+    }
+
+    /// The regular expression literal of `/.../.test(...)` or `/.../.exec(...)`,
+    /// without the `g` or `y` flag.
+    ///
+    /// `lastIndex` is only read and written by `g` and `y` regular expressions, and neither method
+    /// exposes the receiver, so the literal can't be observed by the caller.
+    fn stateless_regexp_receiver<'b>(
+        call: &'b mut CallExpression<'a>,
+    ) -> Option<&'b mut Expression<'a>> {
+        let Expression::StaticMemberExpression(callee) = &mut call.callee else {
+            return None;
+        };
+        if call.optional
+            || callee.optional
+            || !matches!(callee.property.name.as_str(), "test" | "exec")
+        {
+            return None;
+        }
+        let Expression::RegExpLiteral(regexp) = callee.object.without_parentheses() else {
+            return None;
+        };
+        if regexp.regex.flags.intersects(RegExpFlags::G | RegExpFlags::Y) {
+            return None;
+        }
+        Some(&mut callee.object)
+    }
+
+    /// `Object.freeze([...])` or `Object.freeze({...})` containing only primitive literals.
+    fn is_frozen_literal(call: &CallExpression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::StaticMemberExpression(callee) = &call.callee else {
+            return false;
+        };
+        if call.optional
+            || callee.optional
+            || callee.property.name != "freeze"
+            || !callee.object.is_global_reference_name("Object", ctx.scoping())
+        {
+            return false;
+        }
+        let [argument] = call.arguments.as_slice() else {
+            return false;
+        };
+        match argument {
+            Argument::ArrayExpression(array) => array
+                .elements
+                .iter()
+                .all(|element| element.as_expression().is_some_and(Self::is_primitive_literal)),
+            Argument::ObjectExpression(object) => object.properties.iter().all(|property| {
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    return false;
+                };
+                property.kind == PropertyKind::Init
+                    && !property.method
+                    && !property.shorthand
+                    && !property.computed
+                    && Self::is_primitive_literal(&property.value)
+            }),
+            _ => false,
+        }
+    }
+
+    fn is_primitive_literal(expr: &Expression<'a>) -> bool {
+        matches!(
+            expr,
+            Expression::StringLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::BigIntLiteral(_)
+                | Expression::BooleanLiteral(_)
+                | Expression::NullLiteral(_)
+        )
+    }
+}
@@ -0,0 +1,367 @@
+//! Hoist Loop Guards
+//!
+//! This plugin hoists an `if` which wraps the whole body of a loop out of the loop, when its
+//! condition is the same in every iteration, so it's evaluated once instead of on every iteration.
+//!
+//! This is an opt-in performance optimization. When the condition is false, the loop no longer
+//! runs at all, so the checks are strict, and anything else leaves the loop untouched:
+//!
+//! * The body of the loop is a single `if` statement without `else`, e.g.
+//!   `for (...) { if (cond) { ... } }`. `break` and `continue` in the `if` still apply to the
+//!   loop, which keeps its label.
+//! * The condition is loop-invariant and free of side effects: it only consists of literals,
+//!   variables, `!`, `typeof`, `void`, `===`, `!==`, `&&`, `||` and `??`.
+//!   Every variable is declared before the loop and is never reassigned. Variables declared
+//!   with `let`, `const` or `class` are declared in the same function as the loop, so they are
+//!   initialized when the loop starts. Imports are live bindings, and are not hoisted.
+//!   Variables which may be reassigned without an assignment are not hoisted either: variables
+//!   in scopes with a direct `eval`, and variables of sloppy mode functions with simple
+//!   params which use `arguments`, whose elements are aliases of the params.
+//! * The loop is a `for`, `while` or `do...while` loop, whose header doesn't have side effects,
+//!   since it no longer runs when the condition is false. A `for` loop only declares `let`
+//!   variables, and only updates them. The header may read variables and properties, and apply
+//!   operators to them, which is assumed not to call getters or `valueOf`, as minifiers do.
+//! * The loop has a test, which is not a literal. Loops like `while (true)` only end by
+//!   `break`, and would end immediately instead of never when the condition is false.
+//!   Other loops which would never end when the condition is false, because only the body
+//!   changes what the test depends on, end immediately as well, as if the loop didn't hang.
+//!
+//! Nested loops are converted from the inside out, so an invariant condition is hoisted out of
+//! all of them.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function update(items, enabled) {
+//!   for (let i = 0; i < items.length; i++) {
+//!     if (enabled) {
+//!       items[i].update();
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function update(items, enabled) {
+//!   if (enabled) for (let i = 0; i < items.length; i++) {
+//!     items[i].update();
+//!   }
+//! }
+//! ```
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_ecmascript::IsSimpleParameterList;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags};
+use oxc_span::{SPAN, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct HoistLoopGuards {
+    /// Scopes of sloppy mode functions with simple params which use `arguments`.
+    mapped_arguments_scopes: FxHashSet<ScopeId>,
+}
+
+impl HoistLoopGuards {
+    pub fn new() -> Self {
+        Self { mapped_arguments_scopes: FxHashSet::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for HoistLoopGuards {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut finder = MappedArgumentsFinder {
+            scoping: ctx.scoping(),
+            functions: vec![],
+            scopes: FxHashSet::default(),
+        };
+        finder.visit_program(program);
+        self.mapped_arguments_scopes = finder.scopes;
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // A labeled loop is converted at its outermost label, `continue label` needs the label
+        // directly on the loop.
+        if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            return;
+        }
+        self.hoist_guard(stmt, ctx);
+    }
+}
+
+impl<'a> HoistLoopGuards {
+    /// `for (...) { if (cond) { ... } }` -> `if (cond) for (...) { ... }`
+    fn hoist_guard(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut loop_stmt = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            loop_stmt = &mut labeled.body;
+        }
+        let (span, body) = match loop_stmt {
+            Statement::ForStatement(for_stmt) if Self::is_pure_for_header(for_stmt, ctx) => {
+                (for_stmt.span, &mut for_stmt.body)
+            }
+            Statement::WhileStatement(while_stmt) if Self::is_pure_test(&while_stmt.test, ctx) => {
+                (while_stmt.span, &mut while_stmt.body)
+            }
+            Statement::DoWhileStatement(do_while) if Self::is_pure_test(&do_while.test, ctx) => {
+                (do_while.span, &mut do_while.body)
+            }
+            _ => return,
+        };
+
+        let guard = match &*body {
+            Statement::IfStatement(if_stmt) => if_stmt,
+            Statement::BlockStatement(block) => match block.body.as_slice() {
+                [Statement::IfStatement(if_stmt)] => if_stmt,
+                _ => return,
+            },
+            _ => return,
+        };
+        // `if (cond) function f() {}` is only allowed in sloppy mode, and has its own scope.
+        if guard.alternate.is_some()
+            || matches!(guard.consequent, Statement::FunctionDeclaration(_))
+            || !self.is_invariant(&guard.test, span, ctx)
+        {
+            return;
+        }
+
+        let test = match body {
+            Statement::IfStatement(if_stmt) => {
+                let IfStatement { test, consequent, .. } = if_stmt.take_in(ctx.ast);
+                *body = consequent;
+                test
+            }
+            Statement::BlockStatement(block) => {
+                let Some(Statement::IfStatement(if_stmt)) = block.body.pop() else {
+                    unreachable!()
+                };
+                let IfStatement { test, consequent, .. } = if_stmt.unbox();
+                if let Statement::BlockStatement(consequent) = consequent {
+                    // `{ if (cond) { ... } }` -> `{ ... }`, without the outer block.
+                    let block_scope_id = block.scope_id();
+                    let parent_scope_id = ctx.scoping().scope_parent_id(block_scope_id);
+                    ctx.scoping_mut()
+                        .change_scope_parent_id(consequent.scope_id(), parent_scope_id);
+                    ctx.scoping_mut().delete_scope(block_scope_id);
+                    *body = Statement::BlockStatement(consequent);
+                } else {
+                    block.body.push(consequent);
+                }
+                test
+            }
+            _ => unreachable!(),
+        };
+
+        let loop_stmt = stmt.take_in(ctx.ast);
+        *stmt = ctx.ast.statement_if(SPAN, test, loop_stmt, None);
+    }
+
+    /// `for (let i = 0; i < items.length; i++)`
+    fn is_pure_for_header(for_stmt: &ForStatement<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let is_pure_init = match &for_stmt.init {
+            None => true,
+            Some(ForStatementInit::VariableDeclaration(decl)) => {
+                decl.kind == VariableDeclarationKind::Let
+                    && decl.declarations.iter().all(|declarator| {
+                        matches!(declarator.id, BindingPattern::BindingIdentifier(_))
+                            && declarator
+                                .init
+                                .as_ref()
+                                .is_none_or(|init| Self::is_pure_value(init, ctx))
+                    })
+            }
+            // Assigns to variables declared outside of the loop.
+            Some(_) => false,
+        };
+        let Some(test) = &for_stmt.test else { return false };
+        is_pure_init
+            && Self::is_pure_test(test, ctx)
+            && for_stmt
+                .update
+                .as_ref()
+                .is_none_or(|update| Self::is_pure_update(update, for_stmt.scope_id(), ctx))
+    }
+
+    /// `i < items.length`, which is not a literal.
+    fn is_pure_test(test: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        !test.without_parentheses().is_literal() && Self::is_pure_value(test, ctx)
+    }
+
+    /// `i++`, `i += 2` or `i = i * 2`, which only updates variables declared by the loop.
+    fn is_pure_update(update: &Expression<'a>, scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> bool {
+        let is_loop_variable = |ident: &IdentifierReference<'a>| {
+            ctx.scoping()
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .is_some_and(|symbol_id| ctx.scoping().symbol_scope_id(symbol_id) == scope_id)
+        };
+        match update.without_parentheses() {
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    is_loop_variable(ident)
+                }
+                _ => false,
+            },
+            Expression::AssignmentExpression(assign) => match &assign.left {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    is_loop_variable(ident) && Self::is_pure_value(&assign.right, ctx)
+                }
+                _ => false,
+            },
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.iter().all(|expr| Self::is_pure_update(expr, scope_id, ctx))
+            }
+            _ => false,
+        }
+    }
+
+    /// Reads variables and properties, and applies operators to them.
+    fn is_pure_value(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::ThisExpression(_) => true,
+            Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+            // An unresolved reference throws if the global doesn't exist.
+            Expression::Identifier(ident) => {
+                ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some()
+            }
+            Expression::StaticMemberExpression(member) => {
+                !member.optional && Self::is_pure_value(&member.object, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                !member.optional
+                    && Self::is_pure_value(&member.object, ctx)
+                    && Self::is_pure_value(&member.expression, ctx)
+            }
+            Expression::ParenthesizedExpression(paren) => {
+                Self::is_pure_value(&paren.expression, ctx)
+            }
+            Expression::UnaryExpression(unary) => {
+                unary.operator != UnaryOperator::Delete && Self::is_pure_value(&unary.argument, ctx)
+            }
+            // `in` and `instanceof` throw for primitive right operands.
+            Expression::BinaryExpression(binary) => {
+                !matches!(binary.operator, BinaryOperator::In | BinaryOperator::Instanceof)
+                    && Self::is_pure_value(&binary.left, ctx)
+                    && Self::is_pure_value(&binary.right, ctx)
+            }
+            Expression::LogicalExpression(logical) => {
+                Self::is_pure_value(&logical.left, ctx) && Self::is_pure_value(&logical.right, ctx)
+            }
+            Expression::ConditionalExpression(cond) => {
+                Self::is_pure_value(&cond.test, ctx)
+                    && Self::is_pure_value(&cond.consequent, ctx)
+                    && Self::is_pure_value(&cond.alternate, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    /// The value of `expr` is the same in every iteration of the loop at `loop_span`,
+    /// and evaluating it has no side effects.
+    fn is_invariant(&self, expr: &Expression<'a>, loop_span: Span, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_) => true,
+            Expression::Identifier(ident) => self.is_invariant_reference(ident, loop_span, ctx),
+            Expression::ParenthesizedExpression(paren) => {
+                self.is_invariant(&paren.expression, loop_span, ctx)
+            }
+            Expression::UnaryExpression(unary) => {
+                matches!(
+                    unary.operator,
+                    UnaryOperator::LogicalNot | UnaryOperator::Typeof | UnaryOperator::Void
+                ) && self.is_invariant(&unary.argument, loop_span, ctx)
+            }
+            // `==` and `!=` can convert objects to primitives, which calls `valueOf`.
+            Expression::BinaryExpression(binary) => {
+                matches!(
+                    binary.operator,
+                    BinaryOperator::StrictEquality | BinaryOperator::StrictInequality
+                ) && self.is_invariant(&binary.left, loop_span, ctx)
+                    && self.is_invariant(&binary.right, loop_span, ctx)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_invariant(&logical.left, loop_span, ctx)
+                    && self.is_invariant(&logical.right, loop_span, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    /// A variable which is declared before the loop, initialized when the loop starts,
+    /// and never reassigned.
+    fn is_invariant_reference(
+        &self,
+        ident: &IdentifierReference<'a>,
+        loop_span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let scoping = ctx.scoping();
+        let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return false;
+        };
+        let flags = scoping.symbol_flags(symbol_id);
+        let symbol_scope_id = scoping.symbol_scope_id(symbol_id);
+        if flags.contains(SymbolFlags::Import)
+            || scoping.symbol_is_mutated(symbol_id)
+            || scoping.symbol_span(symbol_id).end > loop_span.start
+            // `eval("x = 1")`, in this scope or a nested one
+            || scoping.scope_flags(symbol_scope_id).contains_direct_eval()
+            // `arguments[0] = 1` reassigns the first param
+            || self.mapped_arguments_scopes.contains(&symbol_scope_id)
+        {
+            return false;
+        }
+        if flags.intersects(SymbolFlags::BlockScopedVariable | SymbolFlags::Class) {
+            // In another function, the loop could run before the declaration is initialized.
+            let hoist_scope_id = scoping
+                .scope_ancestors(symbol_scope_id)
+                .find(|&scope_id| scoping.scope_flags(scope_id).is_var());
+            return hoist_scope_id == Some(ctx.current_hoist_scope_id());
+        }
+        true
+    }
+}
+
+/// Finds the sloppy mode functions with simple params which use `arguments`.
+struct MappedArgumentsFinder<'s> {
+    scoping: &'s Scoping,
+    /// Enclosing functions, `None` if their `arguments` isn't mapped to the params.
+    functions: Vec<Option<ScopeId>>,
+    scopes: FxHashSet<ScopeId>,
+}
+
+impl<'a> Visit<'a> for MappedArgumentsFinder<'_> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let scope_id = func.scope_id();
+        let is_mapped = func.params.is_simple_parameter_list()
+            && !self.scoping.scope_flags(scope_id).is_strict_mode();
+        self.functions.push(is_mapped.then_some(scope_id));
+        walk::walk_function(self, func, flags);
+        self.functions.pop();
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments"
+            && self.scoping.get_reference(ident.reference_id()).symbol_id().is_none()
+            && let Some(Some(scope_id)) = self.functions.last()
+        {
+            self.scopes.insert(*scope_id);
+        }
+    }
+}
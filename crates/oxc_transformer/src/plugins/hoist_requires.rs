@@ -0,0 +1,166 @@
+//! Hoist Requires
+//!
+//! This plugin moves the static top level `require` calls of a CommonJS module to the top of the
+//! module, in the same way as ES module imports are hoisted.
+//!
+//! Moving a `require` call changes when the required module is evaluated, so a `require` is only
+//! moved if every statement it's moved over is free of side effects:
+//!
+//! * Function declarations.
+//! * Variable declarations which are uninitialized, or are initialized with a literal,
+//!   a function or an arrow function.
+//! * Other `require` statements, which keep their relative order.
+//!
+//! A `require` statement is either `require("m")`, or a variable declaration where every
+//! declarator is initialized with `require("m")` or `require("m").name`.
+//!
+//! Only the statements before the first other statement are considered.
+//! `require` calls with a non-literal specifier, nested in conditions or functions,
+//! or with a shadowed `require` are never moved.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! "use strict";
+//! function main() {}
+//! const fs = require("fs");
+//! const { join } = require("path");
+//! main();
+//! const os = require("os");
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//! const fs = require("fs");
+//! const { join } = require("path");
+//! function main() {}
+//! main();
+//! const os = require("os");
+//! ```
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct HoistRequires;
+
+impl HoistRequires {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for HoistRequires {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if program.source_type.is_module() {
+            return;
+        }
+
+        // Find the `require` statements which only follow side-effect-free statements.
+        let mut seen_other = false;
+        let mut needs_move = false;
+        // Names which are initialized by the statements moved over,
+        // e.g. `var a = 1; var a = require("a");` must keep its order.
+        let mut initialized_names = FxHashSet::default();
+        let mut end = 0;
+        for stmt in &program.body {
+            if Self::is_require_statement(stmt, ctx) {
+                let mut is_redeclared = false;
+                if let Statement::VariableDeclaration(decl) = stmt {
+                    decl.bound_names(&mut |ident| {
+                        is_redeclared |= initialized_names.contains(ident.name.as_str());
+                    });
+                }
+                if is_redeclared {
+                    break;
+                }
+                needs_move |= seen_other;
+            } else if Self::is_side_effect_free(stmt) {
+                seen_other = true;
+                if let Statement::VariableDeclaration(decl) = stmt {
+                    for declarator in decl.declarations.iter().filter(|d| d.init.is_some()) {
+                        declarator.id.bound_names(&mut |ident| {
+                            initialized_names.insert(ident.name.as_str());
+                        });
+                    }
+                }
+            } else {
+                break;
+            }
+            end += 1;
+        }
+        if !needs_move {
+            return;
+        }
+
+        let mut requires = ctx.ast.vec();
+        let mut others = ctx.ast.vec();
+        for stmt in program.body.drain(..end) {
+            if Self::is_require_statement(&stmt, ctx) {
+                requires.push(stmt);
+            } else {
+                others.push(stmt);
+            }
+        }
+        program.body.splice(0..0, requires.into_iter().chain(others));
+    }
+}
+
+impl<'a> HoistRequires {
+    /// `require("m");`, `const m = require("m");` or `const { a } = require("m").b;`
+    fn is_require_statement(stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match stmt {
+            Statement::ExpressionStatement(stmt) => Self::is_static_require(&stmt.expression, ctx),
+            Statement::VariableDeclaration(decl) => {
+                !decl.kind.is_using()
+                    && decl.declarations.iter().all(|declarator| {
+                        declarator.init.as_ref().is_some_and(|init| {
+                            let init = match init {
+                                Expression::StaticMemberExpression(member) => &member.object,
+                                init => init,
+                            };
+                            Self::is_static_require(init, ctx)
+                        })
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// `require("m")` or `` require(`m`) ``, with a global `require`
+    fn is_static_require(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::CallExpression(call) = expr else { return false };
+        let [argument] = call.arguments.as_slice() else { return false };
+        let is_static = match argument {
+            Argument::StringLiteral(_) => true,
+            Argument::TemplateLiteral(lit) => lit.is_no_substitution_template(),
+            _ => false,
+        };
+        is_static && call.callee.is_global_reference_name("require", ctx.scoping())
+    }
+
+    fn is_side_effect_free(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::EmptyStatement(_) | Statement::FunctionDeclaration(_) => true,
+            Statement::VariableDeclaration(decl) => {
+                !decl.kind.is_using()
+                    && decl.declarations.iter().all(|declarator| {
+                        matches!(declarator.id, BindingPattern::BindingIdentifier(_))
+                            && declarator.init.as_ref().is_none_or(|init| {
+                                init.is_literal()
+                                    || init.is_no_substitution_template()
+                                    || init.is_function()
+                            })
+                    })
+            }
+            _ => false,
+        }
+    }
+}
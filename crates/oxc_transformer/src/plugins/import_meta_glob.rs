@@ -0,0 +1,333 @@
+//! Import Meta Glob
+//!
+//! This plugin expands Vite's `import.meta.glob` macro into an object literal,
+//! using a caller provided resolver to look up the modules matched by the glob patterns.
+//! See <https://vite.dev/guide/features.html#glob-import>.
+//!
+//! Each matched path becomes a key of the object, and the value is:
+//!
+//! * a function which imports the module, by default.
+//! * the module namespace itself, with `{ eager: true }`.
+//!   The module is imported at the top of the file.
+//!
+//! With `{ import: "name" }`, only the export `name` of each module is used.
+//!
+//! Patterns prefixed with `!` are negated, and are passed to the resolver separately,
+//! without the `!`.
+//!
+//! A call is left untouched if the patterns are not string literals, if it has any other option
+//! (e.g. `query` or `exhaustive`), if it has no pattern which isn't negated,
+//! or if the resolver can't resolve it.
+//!
+//! ## Example
+//!
+//! Input, with `./dir/*.js` matching `./dir/a.js` and `./dir/b.js`:
+//! ```js
+//! const lazy = import.meta.glob("./dir/*.js");
+//! const eager = import.meta.glob("./dir/*.js", { eager: true, import: "default" });
+//! ```
+//!
+//! Output:
+//! ```js
+//! import _glob from "./dir/a.js";
+//! import _glob2 from "./dir/b.js";
+//! const lazy = {
+//!   "./dir/a.js": () => import("./dir/a.js"),
+//!   "./dir/b.js": () => import("./dir/b.js"),
+//! };
+//! const eager = { "./dir/a.js": _glob, "./dir/b.js": _glob2 };
+//! ```
+
+use std::{fmt, sync::Arc};
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+/// Returns the paths of the modules matched by the given patterns and negated patterns,
+/// or `None` if they are not known.
+///
+/// The paths are used as both the keys of the object and the import specifiers, in order.
+pub type GlobResolver = Arc<dyn Fn(&[&str], &[&str]) -> Option<Vec<String>> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ImportMetaGlobOptions {
+    pub resolver: GlobResolver,
+}
+
+impl ImportMetaGlobOptions {
+    pub fn new(resolver: GlobResolver) -> Self {
+        Self { resolver }
+    }
+}
+
+impl fmt::Debug for ImportMetaGlobOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImportMetaGlobOptions").finish_non_exhaustive()
+    }
+}
+
+/// Options of an `import.meta.glob` call.
+#[derive(Default)]
+struct GlobOptions<'a> {
+    eager: bool,
+    import: Option<Atom<'a>>,
+}
+
+pub struct ImportMetaGlob<'a, 'ctx> {
+    options: ImportMetaGlobOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ImportMetaGlob<'a, 'ctx> {
+    pub fn new(options: ImportMetaGlobOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ImportMetaGlob<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if !Self::is_import_meta_glob(&call.callee) {
+            return;
+        }
+        let Some((patterns, options)) = Self::parse_arguments(&call.arguments) else {
+            return;
+        };
+        let (negated, patterns): (Vec<_>, Vec<_>) =
+            patterns.into_iter().partition(|pattern| pattern.starts_with('!'));
+        if patterns.is_empty() {
+            return;
+        }
+        let negated = negated.iter().map(|pattern| &pattern[1..]).collect::<Vec<_>>();
+        let Some(paths) = (self.options.resolver)(&patterns, &negated) else {
+            return;
+        };
+
+        let properties = ctx.ast.vec_from_iter(paths.iter().map(|path| {
+            let path = ctx.ast.atom(path);
+            let value = if options.eager {
+                self.create_eager_import(path, options.import, ctx)
+            } else {
+                Self::create_lazy_import(path, options.import, ctx)
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                PropertyKey::StringLiteral(ctx.ast.alloc_string_literal(SPAN, path, None)),
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        *expr = ctx.ast.expression_object(call.span, properties);
+    }
+}
+
+impl<'a> ImportMetaGlob<'a, '_> {
+    /// `import.meta.glob`
+    fn is_import_meta_glob(callee: &Expression<'a>) -> bool {
+        let Expression::StaticMemberExpression(member) = callee else { return false };
+        member.property.name == "glob"
+            && matches!(&member.object, Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta")
+    }
+
+    /// `("./dir/*.js")`, `(["./dir/*.js", "!./dir/b.js"])` or `("./dir/*.js", { eager: true })`
+    fn parse_arguments(arguments: &[Argument<'a>]) -> Option<(Vec<&'a str>, GlobOptions<'a>)> {
+        let (patterns, options) = match arguments {
+            [patterns] => (patterns, None),
+            [patterns, options] => (patterns, Some(options)),
+            _ => return None,
+        };
+
+        let patterns = match patterns.as_expression()? {
+            Expression::ArrayExpression(array) => array
+                .elements
+                .iter()
+                .map(|element| element.as_expression().and_then(Self::string_value))
+                .collect::<Option<Vec<_>>>()?,
+            pattern => vec![Self::string_value(pattern)?],
+        };
+
+        let mut glob_options = GlobOptions::default();
+        if let Some(options) = options {
+            let Expression::ObjectExpression(object) = options.as_expression()? else {
+                return None;
+            };
+            for property in &object.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    return None;
+                };
+                if property.kind != PropertyKind::Init || property.method || property.computed {
+                    return None;
+                }
+                match (property.key.static_name()?.as_ref(), &property.value) {
+                    ("eager", Expression::BooleanLiteral(lit)) => glob_options.eager = lit.value,
+                    ("import", value) => {
+                        glob_options.import = Some(Self::string_value(value)?.into());
+                    }
+                    _ => return None,
+                }
+            }
+        }
+
+        Some((patterns, glob_options))
+    }
+
+    /// `"./dir/*.js"` or `` `./dir/*.js` ``
+    fn string_value(expr: &Expression<'a>) -> Option<&'a str> {
+        match expr {
+            Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+            Expression::TemplateLiteral(lit) => lit.single_quasi().map(|quasi| quasi.as_str()),
+            _ => None,
+        }
+    }
+
+    /// `() => import("./dir/a.js")` or `() => import("./dir/a.js").then((m) => m.name)`
+    fn create_lazy_import(
+        path: Atom<'a>,
+        import: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let mut body = ctx.ast.expression_import(
+            SPAN,
+            ctx.ast.expression_string_literal(SPAN, path, None),
+            None,
+            None,
+        );
+        if let Some(import) = import {
+            // `(m) => m.name`
+            let callback_scope_id =
+                ctx.create_child_scope(scope_id, ScopeFlags::Arrow | ScopeFlags::Function);
+            let binding = ctx.generate_binding(
+                Atom::from("m"),
+                callback_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let object = binding.create_read_expression(ctx);
+            let member = if is_identifier_name(&import) {
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    ctx.ast.identifier_name(SPAN, import),
+                    false,
+                ))
+            } else {
+                Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    object,
+                    ctx.ast.expression_string_literal(SPAN, import, None),
+                    false,
+                ))
+            };
+            let param = ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                binding.create_binding_pattern(ctx),
+                NONE,
+                NONE,
+                false,
+                None,
+                false,
+                false,
+            );
+            let callback = Self::create_arrow(ctx.ast.vec1(param), member, callback_scope_id, ctx);
+
+            // `import("./dir/a.js").then(callback)`
+            let then = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                body,
+                ctx.ast.identifier_name(SPAN, "then"),
+                false,
+            ));
+            body = ctx.ast.expression_call(
+                SPAN,
+                then,
+                NONE,
+                ctx.ast.vec1(Argument::from(callback)),
+                false,
+            );
+        }
+        Self::create_arrow(ctx.ast.vec(), body, scope_id, ctx)
+    }
+
+    /// `(params) => body`
+    fn create_arrow(
+        params: oxc_allocator::Vec<'a, FormalParameter<'a>>,
+        body: Expression<'a>,
+        scope_id: oxc_semantic::ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(body.span(), body)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// Add `import * as _glob from "./dir/a.js"`, `import _glob from "./dir/a.js"`
+    /// or `import { name as _glob } from "./dir/a.js"` to the top of the file,
+    /// and return `_glob`.
+    fn create_eager_import(
+        &self,
+        path: Atom<'a>,
+        import: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = ctx.generate_uid_in_root_scope("glob", SymbolFlags::Import);
+        let local = binding.create_binding_identifier(ctx);
+        let specifier = match import {
+            None => ImportDeclarationSpecifier::ImportNamespaceSpecifier(
+                ctx.ast.alloc_import_namespace_specifier(SPAN, local),
+            ),
+            Some(import) if import == "default" => {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(
+                    ctx.ast.alloc_import_default_specifier(SPAN, local),
+                )
+            }
+            Some(import) => {
+                let imported = if is_identifier_name(&import) {
+                    ctx.ast.module_export_name_identifier_name(SPAN, import)
+                } else {
+                    ctx.ast.module_export_name_string_literal(SPAN, import, None)
+                };
+                ImportDeclarationSpecifier::ImportSpecifier(ctx.ast.alloc_import_specifier(
+                    SPAN,
+                    imported,
+                    local,
+                    ImportOrExportKind::Value,
+                ))
+            }
+        };
+        self.ctx.top_level_statements.insert_statement(Statement::from(
+            ctx.ast.module_declaration_import_declaration(
+                SPAN,
+                Some(ctx.ast.vec1(specifier)),
+                ctx.ast.string_literal(SPAN, path, None),
+                None,
+                NONE,
+                ImportOrExportKind::Value,
+            ),
+        ));
+        binding.create_read_expression(ctx)
+    }
+}
@@ -0,0 +1,316 @@
+//! Inline Imported Constants
+//!
+//! This plugin replaces references to imported constants with their values, for whole-program
+//! optimization with a known module graph. A caller provided resolver looks up the value of a name
+//! exported by another module.
+//!
+//! * Only primitives are inlined: numbers, strings, booleans, `null` and `undefined`.
+//!   Strings longer than [`InlineImportedConstantsOptions::max_string_length`] are not inlined,
+//!   because duplicating them at every use site makes the output bigger.
+//! * `import { MAX } from "./config"` and `import MAX from "./config"` are resolved by the
+//!   exported name, `MAX` and `default`. Namespace imports and type-only imports are not inlined.
+//! * Imports which are also re-exported, e.g. `export { MAX }`, are kept for the re-export.
+//! * Import specifiers whose references were all inlined are removed. The import declaration is
+//!   kept as `import "./config"`, so the module is still evaluated for its side effects.
+//!
+//! The resolver must only return values of exported bindings which are never reassigned,
+//! because an import is a live binding. [`collect_constant_exports`] collects those values from
+//! the exporting module. Re-exports, e.g. `export { MAX } from "./defaults"`, are not collected,
+//! the resolver has to follow them to the module which declares `MAX`.
+//!
+//! ## Example
+//!
+//! Input, with `./config` exporting `const MAX = 100`:
+//! ```js
+//! import { MAX, load } from "./config";
+//! if (items.length > MAX) load();
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { load } from "./config";
+//! if (items.length > 100) load();
+//! ```
+
+use std::{fmt, sync::Arc};
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{Scoping, SymbolId};
+use oxc_span::Span;
+use oxc_syntax::{number::NumberBase, operator::UnaryOperator};
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+/// The value of an exported constant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportedConstant {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl ExportedConstant {
+    /// The value of a literal, e.g. `100`, `-1`, `"a"`, `` `a` ``, `true`, `null` or `void 0`.
+    pub fn from_expression(expr: &Expression) -> Option<Self> {
+        match expr.without_parentheses() {
+            Expression::NumericLiteral(lit) => Some(Self::Number(lit.value)),
+            Expression::StringLiteral(lit) => Some(Self::String(lit.value.to_string())),
+            Expression::TemplateLiteral(lit) => {
+                lit.single_quasi().map(|value| Self::String(value.to_string()))
+            }
+            Expression::BooleanLiteral(lit) => Some(Self::Boolean(lit.value)),
+            Expression::NullLiteral(_) => Some(Self::Null),
+            Expression::UnaryExpression(unary) => match (&unary.operator, &unary.argument) {
+                (UnaryOperator::UnaryNegation, Expression::NumericLiteral(lit)) => {
+                    Some(Self::Number(-lit.value))
+                }
+                (UnaryOperator::Void, Expression::NumericLiteral(_)) => Some(Self::Undefined),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns the value of the constant exported by the module with the given specifier,
+/// by its exported name, or `None` if it is not a known constant.
+pub type ConstantResolver = Arc<dyn Fn(&str, &str) -> Option<ExportedConstant> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct InlineImportedConstantsOptions {
+    pub resolver: ConstantResolver,
+    /// Strings with more characters are not inlined.
+    ///
+    /// Default: `32`
+    pub max_string_length: usize,
+}
+
+impl InlineImportedConstantsOptions {
+    pub fn new(resolver: ConstantResolver) -> Self {
+        Self { resolver, max_string_length: 32 }
+    }
+}
+
+impl fmt::Debug for InlineImportedConstantsOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InlineImportedConstantsOptions")
+            .field("max_string_length", &self.max_string_length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Collect the constants exported by a module, which a [`ConstantResolver`] can return.
+///
+/// An exported binding is a constant if it is initialized with a literal, see
+/// [`ExportedConstant::from_expression`], and never reassigned. `export default` and re-exports
+/// from other modules are not collected.
+pub fn collect_constant_exports(
+    program: &Program,
+    scoping: &Scoping,
+) -> Vec<(String, ExportedConstant)> {
+    // The values of the constant top-level bindings.
+    let mut values = FxHashMap::<SymbolId, ExportedConstant>::default();
+    for stmt in &program.body {
+        let decl = match stmt {
+            Statement::VariableDeclaration(decl) => decl,
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => decl,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        for declarator in &decl.declarations {
+            let BindingPattern::BindingIdentifier(ident) = &declarator.id else {
+                continue;
+            };
+            let Some(value) = declarator.init.as_ref().and_then(ExportedConstant::from_expression)
+            else {
+                continue;
+            };
+            let symbol_id = ident.symbol_id();
+            let is_reassigned = scoping
+                .get_resolved_references(symbol_id)
+                .any(|reference| reference.flags().is_write());
+            if !is_reassigned {
+                values.insert(symbol_id, value);
+            }
+        }
+    }
+
+    let mut constants = vec![];
+    for stmt in &program.body {
+        let Statement::ExportNamedDeclaration(export) = stmt else { continue };
+        if export.source.is_some() || export.export_kind.is_type() {
+            continue;
+        }
+        if let Some(declaration) = &export.declaration {
+            // `export const MAX = 100;`
+            declaration.bound_names(&mut |ident| {
+                if let Some(value) = values.get(&ident.symbol_id()) {
+                    constants.push((ident.name.to_string(), value.clone()));
+                }
+            });
+        }
+        // `export { MAX as LIMIT };`
+        for specifier in &export.specifiers {
+            let ModuleExportName::IdentifierReference(local) = &specifier.local else { continue };
+            let symbol_id = scoping.get_reference(local.reference_id()).symbol_id();
+            if let Some(value) = symbol_id.and_then(|symbol_id| values.get(&symbol_id)) {
+                constants.push((specifier.exported.name().to_string(), value.clone()));
+            }
+        }
+    }
+    constants
+}
+
+pub struct InlineImportedConstants {
+    options: InlineImportedConstantsOptions,
+    /// The values of the imported bindings which are inlined.
+    constants: FxHashMap<SymbolId, ExportedConstant>,
+}
+
+impl InlineImportedConstants {
+    pub fn new(options: InlineImportedConstantsOptions) -> Self {
+        Self { options, constants: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for InlineImportedConstants {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                let (local, imported) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        if specifier.import_kind.is_type() {
+                            continue;
+                        }
+                        (&specifier.local, specifier.imported.name())
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (&specifier.local, Atom::from("default"))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => continue,
+                };
+                let Some(value) = (self.options.resolver)(&decl.source.value, &imported) else {
+                    continue;
+                };
+                if self.is_inlinable(&value) {
+                    self.constants.insert(local.symbol_id(), value);
+                }
+            }
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.constants.is_empty() {
+            return;
+        }
+        for stmt in &mut program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &mut decl.specifiers else { continue };
+            if specifiers.is_empty() {
+                continue;
+            }
+            specifiers.retain(|specifier| {
+                let symbol_id = specifier.local().symbol_id();
+                !self.constants.contains_key(&symbol_id)
+                    || !ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty()
+            });
+            if specifiers.is_empty() {
+                // `import { MAX } from "./config"` -> `import "./config"`
+                decl.specifiers = None;
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.constants.is_empty() {
+            return;
+        }
+        match expr {
+            Expression::Identifier(ident) => {
+                let reference_id = ident.reference_id();
+                let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+                    return;
+                };
+                let Some(value) = self.constants.get(&symbol_id) else { return };
+                // `MAX` -> `100`
+                *expr = Self::create_literal(value, ident.span, ctx);
+                ctx.scoping_mut().delete_resolved_reference(symbol_id, reference_id);
+            }
+            // `{ MAX }` -> `{ MAX: 100 }`
+            Expression::ObjectExpression(object) => {
+                for property in &mut object.properties {
+                    if let ObjectPropertyKind::ObjectProperty(property) = property
+                        && property.shorthand
+                        && self.is_constant_reference(&property.value, ctx)
+                    {
+                        property.shorthand = false;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> InlineImportedConstants {
+    fn is_inlinable(&self, value: &ExportedConstant) -> bool {
+        match value {
+            // `NaN` and `Infinity` could be shadowed at the use site.
+            ExportedConstant::Number(value) => value.is_finite(),
+            ExportedConstant::String(value) => {
+                value.chars().count() <= self.options.max_string_length
+            }
+            _ => true,
+        }
+    }
+
+    fn is_constant_reference(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        ctx.scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.constants.contains_key(&symbol_id))
+    }
+
+    fn create_literal(
+        value: &ExportedConstant,
+        span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match value {
+            ExportedConstant::Number(value) => {
+                let literal = ctx.ast.expression_numeric_literal(
+                    span,
+                    value.abs(),
+                    None,
+                    NumberBase::Decimal,
+                );
+                if value.is_sign_negative() {
+                    // `-1`
+                    ctx.ast.expression_unary(span, UnaryOperator::UnaryNegation, literal)
+                } else {
+                    literal
+                }
+            }
+            ExportedConstant::String(value) => {
+                ctx.ast.expression_string_literal(span, ctx.ast.atom(value), None)
+            }
+            ExportedConstant::Boolean(value) => ctx.ast.expression_boolean_literal(span, *value),
+            ExportedConstant::Null => ctx.ast.expression_null_literal(span),
+            ExportedConstant::Undefined => ctx.ast.void_0(span),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! JSON Parse
+//!
+//! This plugin replaces large object and array literals which only contain JSON values
+//! with a `JSON.parse` call.
+//!
+//! JavaScript engines parse a JSON string considerably faster than the equivalent object literal,
+//! because the JSON grammar is much simpler than the JavaScript one.
+//! See <https://v8.dev/blog/cost-of-javascript-2019#json>.
+//!
+//! This is an opt-in performance optimization, only worth it for literals of roughly 10 kB
+//! or more. Only literals whose serialized JSON is at least [`JsonParseOptions::threshold`]
+//! bytes long are replaced.
+//!
+//! A literal is left untouched if it contains anything that can't be represented in JSON,
+//! e.g. functions, `undefined`, identifiers, spread elements, array holes, computed keys,
+//! or a `__proto__` key (which sets the prototype in an object literal,
+//! but creates an own property in `JSON.parse`).
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const data = { "name": "oxc", tags: ["parser", "transformer"], stars: 10000 };
+//! ```
+//!
+//! Output:
+//! ```js
+//! const data = JSON.parse("{\"name\":\"oxc\",\"tags\":[\"parser\",\"transformer\"],\"stars\":10000}");
+//! ```
+
+use std::borrow::Cow;
+
+use serde::Deserialize;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{number::ToJsString, reference::ReferenceFlags};
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsonParseOptions {
+    /// Minimum length of the serialized JSON, in bytes, for a literal to be replaced.
+    ///
+    /// Default: `10240`
+    pub threshold: usize,
+}
+
+impl Default for JsonParseOptions {
+    fn default() -> Self {
+        Self { threshold: 10 * 1024 }
+    }
+}
+
+pub struct JsonParse {
+    options: JsonParseOptions,
+}
+
+impl JsonParse {
+    pub fn new(options: JsonParseOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for JsonParse {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::ObjectExpression(_) | Expression::ArrayExpression(_)) {
+            return;
+        }
+
+        let mut json = String::new();
+        if !write_json(expr, &mut json) || json.len() < self.options.threshold {
+            return;
+        }
+
+        // `JSON` is shadowed, e.g. `function f(JSON) { return { a: 1 } }`.
+        if ctx.scoping().find_binding(ctx.current_scope_id(), "JSON").is_some() {
+            return;
+        }
+
+        // `JSON.parse("...")`
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("JSON"), ReferenceFlags::Read),
+            ctx.ast.identifier_name(SPAN, "parse"),
+            false,
+        ));
+        let argument = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&json), None);
+        *expr = ctx.ast.expression_call(
+            expr.span(),
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(argument)),
+            false,
+        );
+    }
+}
+
+/// Serialize `expr` as JSON into `out`.
+///
+/// Returns `false` if `expr` is not a JSON value, in which case `out` contains garbage.
+fn write_json(expr: &Expression<'_>, out: &mut String) -> bool {
+    match expr {
+        Expression::NullLiteral(_) => out.push_str("null"),
+        Expression::BooleanLiteral(lit) => out.push_str(if lit.value { "true" } else { "false" }),
+        Expression::NumericLiteral(lit) => return write_json_number(lit.value, out),
+        // `-1`
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            let Expression::NumericLiteral(lit) = &unary.argument else {
+                return false;
+            };
+            out.push('-');
+            return write_json_number(lit.value, out);
+        }
+        Expression::StringLiteral(lit) => {
+            if lit.lone_surrogates {
+                return false;
+            }
+            write_json_string(&lit.value, out);
+        }
+        Expression::ArrayExpression(array) => {
+            out.push('[');
+            for (i, element) in array.elements.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                // Holes and spread elements can't be represented.
+                let Some(element) = element.as_expression() else {
+                    return false;
+                };
+                if !write_json(element, out) {
+                    return false;
+                }
+            }
+            out.push(']');
+        }
+        Expression::ObjectExpression(object) => {
+            out.push('{');
+            for (i, property) in object.properties.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    return false;
+                };
+                if property.kind != PropertyKind::Init
+                    || property.method
+                    || property.shorthand
+                    || property.computed
+                {
+                    return false;
+                }
+                let key: Cow<str> = match &property.key {
+                    PropertyKey::StaticIdentifier(ident) => ident.name.as_str().into(),
+                    PropertyKey::StringLiteral(lit) if !lit.lone_surrogates => {
+                        lit.value.as_str().into()
+                    }
+                    PropertyKey::NumericLiteral(lit) => lit.value.to_js_string().into(),
+                    _ => return false,
+                };
+                if key == "__proto__" {
+                    return false;
+                }
+                write_json_string(&key, out);
+                out.push(':');
+                if !write_json(&property.value, out) {
+                    return false;
+                }
+            }
+            out.push('}');
+        }
+        _ => return false,
+    }
+    true
+}
+
+fn write_json_number(value: f64, out: &mut String) -> bool {
+    // `1e999` is `Infinity`, which JSON can't represent.
+    if !value.is_finite() {
+        return false;
+    }
+    out.push_str(&value.to_js_string());
+    true
+}
+
+fn write_json_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                out.push_str("\\u00");
+                out.push(char::from_digit(u32::from(c) >> 4, 16).unwrap());
+                out.push(char::from_digit(u32::from(c) & 0xF, 16).unwrap());
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -0,0 +1,305 @@
+//! JSON Parse to Literal
+//!
+//! This plugin replaces `JSON.parse` calls with a string literal argument by the equivalent
+//! object or array literal, which is easier to read. It is the reverse of the JSON Parse plugin,
+//! so enabling both has no effect on objects large enough to be replaced by that plugin.
+//!
+//! Only calls to the global `JSON.parse` with a single string literal argument are replaced.
+//! Calls with a reviver, and calls whose argument is not valid JSON, are left untouched,
+//! as are strings containing numbers too large to be represented, or lone surrogates.
+//!
+//! Keys which are identifier names are written as identifiers, other keys as string literals.
+//! A `__proto__` key is written as a computed key, as `__proto__: value` sets the prototype in
+//! an object literal, but `JSON.parse` creates an own property.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const data = JSON.parse('{"name":"oxc","tags":["parser"],"content-type":null}');
+//! ```
+//!
+//! Output:
+//! ```js
+//! const data = { name: "oxc", tags: ["parser"], "content-type": null };
+//! ```
+
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::SPAN;
+use oxc_syntax::{identifier::is_identifier_name, number::NumberBase};
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct JsonParseToLiteral;
+
+impl JsonParseToLiteral {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for JsonParseToLiteral {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional || !Self::is_json_parse(&call.callee, ctx) {
+            return;
+        }
+        let [Argument::StringLiteral(lit)] = call.arguments.as_slice() else { return };
+        if lit.lone_surrogates {
+            return;
+        }
+        let mut parser = JsonParser { source: lit.value.as_str(), pos: 0, ctx };
+        let Some(value) = parser.parse() else { return };
+
+        let Expression::StaticMemberExpression(member) = call.callee.without_parentheses() else {
+            unreachable!()
+        };
+        let Expression::Identifier(ident) = &member.object else { unreachable!() };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = value;
+    }
+}
+
+impl<'a> JsonParseToLiteral {
+    /// `JSON.parse`, referencing the global `JSON`.
+    fn is_json_parse(callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::StaticMemberExpression(member) = callee.without_parentheses() else {
+            return false;
+        };
+        !member.optional
+            && member.property.name == "parse"
+            && member.object.is_global_reference_name("JSON", ctx.scoping())
+    }
+}
+
+/// Parses a JSON string into the equivalent expression.
+///
+/// All methods return `None` if the string is not valid JSON, or can't be represented.
+struct JsonParser<'s, 'a, 'c> {
+    source: &'s str,
+    pos: usize,
+    ctx: &'c TraverseCtx<'a>,
+}
+
+impl<'a> JsonParser<'_, 'a, '_> {
+    fn parse(&mut self) -> Option<Expression<'a>> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        (self.pos == self.source.len()).then_some(value)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        let matched = self.peek() == Some(b);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let matched = self.source[self.pos..].starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+        matched
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<Expression<'a>> {
+        let ast = self.ctx.ast;
+        self.skip_whitespace();
+        let value = match self.peek()? {
+            b'{' => self.parse_object()?,
+            b'[' => self.parse_array()?,
+            b'"' => {
+                let value = self.parse_string()?;
+                ast.expression_string_literal(SPAN, ast.atom(&value), None)
+            }
+            b'-' | b'0'..=b'9' => self.parse_number()?,
+            _ if self.eat_str("true") => ast.expression_boolean_literal(SPAN, true),
+            _ if self.eat_str("false") => ast.expression_boolean_literal(SPAN, false),
+            _ if self.eat_str("null") => ast.expression_null_literal(SPAN),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn parse_object(&mut self) -> Option<Expression<'a>> {
+        let ast = self.ctx.ast;
+        self.pos += 1;
+        let mut properties = ast.vec();
+        self.skip_whitespace();
+        if !self.eat(b'}') {
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some(b'"') {
+                    return None;
+                }
+                let key = self.parse_string()?;
+                self.skip_whitespace();
+                if !self.eat(b':') {
+                    return None;
+                }
+                let value = self.parse_value()?;
+                let (key, computed) = if key == "__proto__" {
+                    let key = ast.expression_string_literal(SPAN, ast.atom(&key), None);
+                    (PropertyKey::from(key), true)
+                } else if is_identifier_name(&key) {
+                    (ast.property_key_static_identifier(SPAN, ast.atom(&key)), false)
+                } else {
+                    let key = ast.expression_string_literal(SPAN, ast.atom(&key), None);
+                    (PropertyKey::from(key), false)
+                };
+                properties.push(ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    false,
+                    false,
+                    computed,
+                ));
+                self.skip_whitespace();
+                if self.eat(b'}') {
+                    break;
+                }
+                if !self.eat(b',') {
+                    return None;
+                }
+            }
+        }
+        Some(ast.expression_object(SPAN, properties))
+    }
+
+    fn parse_array(&mut self) -> Option<Expression<'a>> {
+        let ast = self.ctx.ast;
+        self.pos += 1;
+        let mut elements = ast.vec();
+        self.skip_whitespace();
+        if !self.eat(b']') {
+            loop {
+                elements.push(ArrayExpressionElement::from(self.parse_value()?));
+                self.skip_whitespace();
+                if self.eat(b']') {
+                    break;
+                }
+                if !self.eat(b',') {
+                    return None;
+                }
+            }
+        }
+        Some(ast.expression_array(SPAN, elements))
+    }
+
+    /// `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`
+    fn parse_number(&mut self) -> Option<Expression<'a>> {
+        let negative = self.eat(b'-');
+        let digits_start = self.pos;
+        if !self.eat(b'0') && self.eat_digits() == 0 {
+            return None;
+        }
+        if self.eat(b'.') && self.eat_digits() == 0 {
+            return None;
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if !self.eat(b'+') {
+                self.eat(b'-');
+            }
+            if self.eat_digits() == 0 {
+                return None;
+            }
+        }
+        let value = self.source[digits_start..self.pos].parse::<f64>().ok()?;
+        // `1e999` is `Infinity`, which can't be written as a literal.
+        if !value.is_finite() {
+            return None;
+        }
+        let ast = self.ctx.ast;
+        let number = ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal);
+        Some(if negative {
+            ast.expression_unary(SPAN, UnaryOperator::UnaryNegation, number)
+        } else {
+            number
+        })
+    }
+
+    fn eat_digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        self.pos += 1;
+        let mut value = String::new();
+        loop {
+            let c = self.source[self.pos..].chars().next()?;
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Some(value),
+                '\\' => {
+                    let escaped = match self.peek()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            self.pos += 1;
+                            value.push(self.parse_unicode_escape()?);
+                            continue;
+                        }
+                        _ => return None,
+                    };
+                    self.pos += 1;
+                    value.push(escaped);
+                }
+                c if c < ' ' => return None,
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// The `XXXX` of `\uXXXX`, followed by a second `\uXXXX` for a surrogate pair.
+    fn parse_unicode_escape(&mut self) -> Option<char> {
+        let high = self.parse_hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            // Lone low surrogates are rejected by `char::from_u32`.
+            return char::from_u32(high);
+        }
+        if !self.eat_str("\\u") {
+            return None;
+        }
+        let low = self.parse_hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return None;
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+    }
+
+    fn parse_hex4(&mut self) -> Option<u32> {
+        let hex = self.source.get(self.pos..self.pos + 4)?;
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += 4;
+        u32::from_str_radix(hex, 16).ok()
+    }
+}
@@ -0,0 +1,653 @@
+//! Lazy Top Level Await
+//!
+//! This plugin moves the top level code of a module which uses top-level `await` into an
+//! async initializer, so the module itself no longer contains a top-level `await`.
+//! Exported bindings which are assigned by the moved code are replaced with async getters,
+//! which wait for the initializer before returning the value.
+//!
+//! This is an opt-in plugin for library builds which need synchronously evaluated modules,
+//! e.g. for environments without support for top-level `await`.
+//! It changes the shape of the module's exports: consumers must call and await
+//! the getters instead of reading the bindings directly.
+//!
+//! * Imports, re-exports (`export * from`, `export { x } from`) and function declarations
+//!   stay at the top level. Exported functions are exported as is.
+//! * Variable and class declarations are hoisted to a top level `var`, and assigned inside the
+//!   initializer.
+//! * `export default <expression>` is assigned to a `_default` binding.
+//!
+//! The initializer is started when the module is evaluated, and at most once.
+//! Its body runs in a later microtask, so in a circular dependency the other modules in the cycle
+//! finish their own evaluation first, and a getter called by them during evaluation receives
+//! the same pending promise. Two modules which await each other's getters during initialization
+//! never settle, the same as with native top-level `await`.
+//!
+//! Modules with top-level `using` declarations, or with `var` declarations nested in
+//! blocks or loops at the top level, are left unchanged.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import { load } from "./load.js";
+//! export * from "./utils.js";
+//! const config = await load();
+//! export const value = config.value;
+//! export default config;
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { load } from "./load.js";
+//! export * from "./utils.js";
+//! var config, value, _default, _promise;
+//! function _init() {
+//!   return _promise || (_promise = Promise.resolve().then(async () => {
+//!     config = await load();
+//!     value = config.value;
+//!     _default = config;
+//!   }));
+//! }
+//! async function _getValue() {
+//!   await _init();
+//!   return value;
+//! }
+//! async function _getDefault() {
+//!   await _init();
+//!   return _default;
+//! }
+//! export { _getValue as value, _getDefault as default };
+//! _init();
+//! ```
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{SPAN, Span};
+use oxc_syntax::reference::ReferenceFlags;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct LazyTopLevelAwait<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> LazyTopLevelAwait<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for LazyTopLevelAwait<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() || !TopLevelAwaitFinder::find(program) {
+            return;
+        }
+        if !Self::is_supported(program, ctx) {
+            self.ctx.error(OxcDiagnostic::warn(
+                "Top-level await was not deferred, because the module contains top-level `using` declarations or nested `var` declarations.",
+            ));
+            return;
+        }
+        Self::transform_program(program, ctx);
+    }
+}
+
+impl<'a> LazyTopLevelAwait<'a, '_> {
+    /// Nested `var` declarations would become local to the initializer,
+    /// and `using` declarations can't be hoisted.
+    fn is_supported(program: &Program<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let mut top_level_vars = FxHashSet::default();
+        for stmt in &program.body {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if decl.kind.is_using() {
+                return false;
+            }
+            decl.bound_names(&mut |ident| {
+                top_level_vars.insert(ident.symbol_id());
+            });
+        }
+
+        let scoping = ctx.scoping();
+        !scoping.iter_bindings_in(scoping.root_scope_id()).any(|symbol_id| {
+            scoping.symbol_flags(symbol_id).contains(SymbolFlags::FunctionScopedVariable)
+                && !top_level_vars.contains(&symbol_id)
+        })
+    }
+
+    fn transform_program(program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut body = ctx.ast.vec();
+        let mut init_body = ctx.ast.vec();
+        let mut hoisted = Hoisted::default();
+        // Exported name and local binding of exports which are moved into the initializer.
+        let mut exports = vec![];
+
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(_)
+                | Statement::ExportAllDeclaration(_)
+                | Statement::FunctionDeclaration(_) => body.push(stmt),
+                Statement::ExportNamedDeclaration(mut export) => match export.declaration.take() {
+                    Some(Declaration::VariableDeclaration(decl)) => {
+                        decl.bound_names(&mut |ident| {
+                            exports.push((ident.name, BoundIdentifier::from_binding_ident(ident)));
+                        });
+                        Self::move_variable_declaration(decl, &mut init_body, &mut hoisted, ctx);
+                    }
+                    Some(Declaration::ClassDeclaration(class)) => {
+                        let binding = Self::move_class(class, &mut init_body, &mut hoisted, ctx);
+                        exports.push((binding.name, binding));
+                    }
+                    declaration => {
+                        export.declaration = declaration;
+                        body.push(Statement::ExportNamedDeclaration(export));
+                    }
+                },
+                Statement::ExportDefaultDeclaration(mut export) => {
+                    match export.declaration.take_in(ctx.ast) {
+                        declaration @ (ExportDefaultDeclarationKind::FunctionDeclaration(_)
+                        | ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)) => {
+                            export.declaration = declaration;
+                            body.push(Statement::ExportDefaultDeclaration(export));
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class)
+                            if class.id.is_some() =>
+                        {
+                            let binding =
+                                Self::move_class(class, &mut init_body, &mut hoisted, ctx);
+                            exports.push((Atom::from("default"), binding));
+                        }
+                        declaration => {
+                            let expr = match declaration {
+                                ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                                    class.r#type = ClassType::ClassExpression;
+                                    Expression::ClassExpression(class)
+                                }
+                                declaration => declaration.into_expression(),
+                            };
+                            let binding = ctx.generate_uid_in_root_scope(
+                                "default",
+                                SymbolFlags::FunctionScopedVariable,
+                            );
+                            // `_default = expr`
+                            let expr = ctx.ast.expression_assignment(
+                                export.span,
+                                AssignmentOperator::Assign,
+                                binding.create_write_target(ctx),
+                                expr,
+                            );
+                            init_body.push(ctx.ast.statement_expression(export.span, expr));
+                            hoisted.push(binding.clone(), SPAN);
+                            exports.push((Atom::from("default"), binding));
+                        }
+                    }
+                }
+                Statement::VariableDeclaration(decl) => {
+                    Self::move_variable_declaration(decl, &mut init_body, &mut hoisted, ctx);
+                }
+                Statement::ClassDeclaration(class) => {
+                    Self::move_class(class, &mut init_body, &mut hoisted, ctx);
+                }
+                stmt if stmt.is_typescript_syntax() => body.push(stmt),
+                stmt => init_body.push(stmt),
+            }
+        }
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let promise =
+            ctx.generate_uid_in_root_scope("promise", SymbolFlags::FunctionScopedVariable);
+        let init = ctx.generate_uid_in_root_scope("init", SymbolFlags::Function);
+
+        // `var a, b, _promise;`
+        hoisted.push(promise.clone(), SPAN);
+        let declarations = ctx.ast.vec_from_iter(hoisted.bindings.iter().map(|(binding, span)| {
+            ctx.ast.variable_declarator(
+                *span,
+                VariableDeclarationKind::Var,
+                ctx.ast.binding_pattern_binding_identifier_with_symbol_id(
+                    *span,
+                    binding.name,
+                    binding.symbol_id,
+                ),
+                NONE,
+                None,
+                false,
+            )
+        }));
+        body.push(Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        )));
+
+        body.push(Self::create_init_function(&init, &promise, init_body, root_scope_id, ctx));
+
+        // Replace local exports of moved bindings with their getters,
+        // e.g. `export { a, a as b }` -> `export { _getA as a, _getA as b }`.
+        let mut getters = Getters::default();
+        for stmt in &mut body {
+            let Statement::ExportNamedDeclaration(export) = stmt else { continue };
+            if export.source.is_some() {
+                continue;
+            }
+            for specifier in &mut export.specifiers {
+                let ModuleExportName::IdentifierReference(ident) = &mut specifier.local else {
+                    continue;
+                };
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                let Some(binding) = symbol_id.and_then(|symbol_id| hoisted.get(symbol_id)) else {
+                    continue;
+                };
+                let getter = getters.get_or_create(binding, ctx);
+                ctx.delete_reference_for_identifier(ident);
+                *ident = getter.create_spanned_read_reference(ident.span, ctx);
+            }
+        }
+
+        // `export { _getA as a, _getDefault as default }`
+        let specifiers = ctx.ast.vec_from_iter(exports.into_iter().map(|(exported, binding)| {
+            let getter = getters.get_or_create(&binding, ctx);
+            ctx.ast.export_specifier(
+                SPAN,
+                ModuleExportName::IdentifierReference(getter.create_read_reference(ctx)),
+                ctx.ast.module_export_name_identifier_name(SPAN, exported),
+                ImportOrExportKind::Value,
+            )
+        }));
+
+        for (binding, getter) in getters.bindings {
+            body.push(Self::create_getter_function(&getter, &binding, &init, root_scope_id, ctx));
+        }
+        if !specifiers.is_empty() {
+            body.push(Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+                SPAN,
+                None,
+                specifiers,
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            )));
+        }
+
+        // `_init();`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            init.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        body.push(ctx.ast.statement_expression(SPAN, call));
+
+        program.body = body;
+    }
+
+    /// `const { a, b = 1 } = obj;` -> `({ a, b = 1 } = obj);`, hoisting `a` and `b`.
+    fn move_variable_declaration(
+        decl: ArenaBox<'a, VariableDeclaration<'a>>,
+        init_body: &mut ArenaVec<'a, Statement<'a>>,
+        hoisted: &mut Hoisted<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for declarator in decl.unbox().declarations {
+            declarator.id.bound_names(&mut |ident| {
+                *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                    SymbolFlags::FunctionScopedVariable;
+                hoisted.push(BoundIdentifier::from_binding_ident(ident), ident.span);
+            });
+            let Some(init) = declarator.init else { continue };
+            let target = Self::create_assignment_target(declarator.id, ctx);
+            let expr = ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            );
+            init_body.push(ctx.ast.statement_expression(declarator.span, expr));
+        }
+    }
+
+    /// `class Foo {}` -> `Foo = class {};`, hoisting `Foo`.
+    fn move_class(
+        mut class: ArenaBox<'a, Class<'a>>,
+        init_body: &mut ArenaVec<'a, Statement<'a>>,
+        hoisted: &mut Hoisted<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let id = class.id.take().expect("ClassDeclaration should have an id");
+        *ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) = SymbolFlags::FunctionScopedVariable;
+        let binding = BoundIdentifier::from_binding_ident(&id);
+
+        let span = class.span;
+        class.r#type = ClassType::ClassExpression;
+        let expr = ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            binding.create_spanned_write_target(id.span, ctx),
+            Expression::ClassExpression(class),
+        );
+        init_body.push(ctx.ast.statement_expression(span, expr));
+        hoisted.push(binding.clone(), id.span);
+        binding
+    }
+
+    fn create_assignment_target(
+        pattern: BindingPattern<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        match pattern {
+            BindingPattern::BindingIdentifier(ident) => BoundIdentifier::from_binding_ident(&ident)
+                .create_spanned_write_target(ident.span, ctx),
+            BindingPattern::ObjectPattern(pattern) => {
+                let ObjectPattern { span, properties, rest } = pattern.unbox();
+                let mut targets = ctx.ast.vec_with_capacity(properties.len());
+                for property in properties {
+                    targets.push(Self::create_assignment_target_property(property, ctx));
+                }
+                let rest = rest.map(|rest| Self::create_assignment_target_rest(rest, ctx));
+                AssignmentTarget::ObjectAssignmentTarget(
+                    ctx.ast.alloc_object_assignment_target(span, targets, rest),
+                )
+            }
+            BindingPattern::ArrayPattern(pattern) => {
+                let ArrayPattern { span, elements, rest } = pattern.unbox();
+                let mut targets = ctx.ast.vec_with_capacity(elements.len());
+                for element in elements {
+                    targets.push(
+                        element.map(|element| {
+                            Self::create_assignment_target_maybe_default(element, ctx)
+                        }),
+                    );
+                }
+                let rest = rest.map(|rest| Self::create_assignment_target_rest(rest, ctx));
+                AssignmentTarget::ArrayAssignmentTarget(
+                    ctx.ast.alloc_array_assignment_target(span, targets, rest),
+                )
+            }
+            BindingPattern::AssignmentPattern(_) => {
+                unreachable!("AssignmentPattern is only valid as a destructuring element")
+            }
+        }
+    }
+
+    fn create_assignment_target_maybe_default(
+        pattern: BindingPattern<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTargetMaybeDefault<'a> {
+        if let BindingPattern::AssignmentPattern(pattern) = pattern {
+            let AssignmentPattern { span, left, right } = pattern.unbox();
+            let binding = Self::create_assignment_target(left, ctx);
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                ctx.ast.alloc_assignment_target_with_default(span, binding, right),
+            )
+        } else {
+            AssignmentTargetMaybeDefault::from(Self::create_assignment_target(pattern, ctx))
+        }
+    }
+
+    fn create_assignment_target_property(
+        property: BindingProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTargetProperty<'a> {
+        let BindingProperty { span, key, value, shorthand, computed } = property;
+        if shorthand {
+            // `{ a }` or `{ a = 1 }`
+            let (ident, init) = match value {
+                BindingPattern::BindingIdentifier(ident) => (ident, None),
+                BindingPattern::AssignmentPattern(pattern) => {
+                    let AssignmentPattern { left, right, .. } = pattern.unbox();
+                    let BindingPattern::BindingIdentifier(ident) = left else {
+                        unreachable!("shorthand property value should be an identifier")
+                    };
+                    (ident, Some(right))
+                }
+                _ => unreachable!("shorthand property value should be an identifier"),
+            };
+            let reference = BoundIdentifier::from_binding_ident(&ident)
+                .create_spanned_write_reference(ident.span, ctx);
+            ctx.ast.assignment_target_property_assignment_target_property_identifier(
+                span, reference, init,
+            )
+        } else {
+            let binding = Self::create_assignment_target_maybe_default(value, ctx);
+            ctx.ast.assignment_target_property_assignment_target_property_property(
+                span, key, binding, computed,
+            )
+        }
+    }
+
+    fn create_assignment_target_rest(
+        rest: ArenaBox<'a, BindingRestElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, AssignmentTargetRest<'a>> {
+        let BindingRestElement { span, argument } = rest.unbox();
+        let target = Self::create_assignment_target(argument, ctx);
+        ctx.ast.alloc_assignment_target_rest(span, target)
+    }
+
+    /// ```js
+    /// function _init() {
+    ///   return _promise || (_promise = Promise.resolve().then(async () => { ... }));
+    /// }
+    /// ```
+    fn create_init_function(
+        init: &BoundIdentifier<'a>,
+        promise: &BoundIdentifier<'a>,
+        init_body: ArenaVec<'a, Statement<'a>>,
+        root_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let function_scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+        let arrow_scope_id =
+            ctx.insert_scope_below_statements(&init_body, ScopeFlags::Arrow | ScopeFlags::Function);
+        ctx.scoping_mut().change_scope_parent_id(arrow_scope_id, Some(function_scope_id));
+
+        // `async () => { ... }`
+        let arrow = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            false,
+            true,
+            NONE,
+            ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::ArrowFormalParameters,
+                ctx.ast.vec(),
+                NONE,
+            ),
+            NONE,
+            ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), init_body),
+            arrow_scope_id,
+            false,
+            false,
+        );
+
+        // `Promise.resolve().then(async () => { ... })`
+        let resolve = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read),
+            ctx.ast.identifier_name(SPAN, "resolve"),
+            false,
+        ));
+        let resolve = ctx.ast.expression_call(SPAN, resolve, NONE, ctx.ast.vec(), false);
+        let then = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            resolve,
+            ctx.ast.identifier_name(SPAN, "then"),
+            false,
+        ));
+        let then =
+            ctx.ast.expression_call(SPAN, then, NONE, ctx.ast.vec1(Argument::from(arrow)), false);
+
+        // `_promise || (_promise = ...)`
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            promise.create_write_target(ctx),
+            then,
+        );
+        let expr = ctx.ast.expression_logical(
+            SPAN,
+            promise.create_read_expression(ctx),
+            LogicalOperator::Or,
+            ctx.ast.expression_parenthesized(SPAN, assignment),
+        );
+
+        let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(expr)));
+        Self::create_function(init, false, stmts, function_scope_id, ctx)
+    }
+
+    /// ```js
+    /// async function _getA() {
+    ///   await _init();
+    ///   return a;
+    /// }
+    /// ```
+    fn create_getter_function(
+        getter: &BoundIdentifier<'a>,
+        binding: &BoundIdentifier<'a>,
+        init: &BoundIdentifier<'a>,
+        root_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+        let call = ctx.ast.expression_call(
+            SPAN,
+            init.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let stmts = ctx.ast.vec_from_array([
+            ctx.ast.statement_expression(SPAN, ctx.ast.expression_await(SPAN, call)),
+            ctx.ast.statement_return(SPAN, Some(binding.create_read_expression(ctx))),
+        ]);
+        Self::create_function(getter, true, stmts, scope_id, ctx)
+    }
+
+    fn create_function(
+        id: &BoundIdentifier<'a>,
+        r#async: bool,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        Statement::FunctionDeclaration(ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionDeclaration,
+            Some(id.create_binding_identifier(ctx)),
+            false,
+            r#async,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts)),
+            scope_id,
+        ))
+    }
+}
+
+/// Bindings hoisted out of the initializer, in declaration order.
+#[derive(Default)]
+struct Hoisted<'a> {
+    bindings: Vec<(BoundIdentifier<'a>, Span)>,
+    symbols: FxHashMap<SymbolId, usize>,
+}
+
+impl<'a> Hoisted<'a> {
+    fn push(&mut self, binding: BoundIdentifier<'a>, span: Span) {
+        if !self.symbols.contains_key(&binding.symbol_id) {
+            self.symbols.insert(binding.symbol_id, self.bindings.len());
+            self.bindings.push((binding, span));
+        }
+    }
+
+    fn get(&self, symbol_id: SymbolId) -> Option<&BoundIdentifier<'a>> {
+        self.symbols.get(&symbol_id).map(|&index| &self.bindings[index].0)
+    }
+}
+
+/// Getter functions of exported bindings, in creation order.
+#[derive(Default)]
+struct Getters<'a> {
+    bindings: Vec<(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+}
+
+impl<'a> Getters<'a> {
+    fn get_or_create(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some((_, getter)) =
+            self.bindings.iter().find(|(existing, _)| existing.symbol_id == binding.symbol_id)
+        {
+            return getter.clone();
+        }
+        // `value` -> `_getValue`, `_default` -> `_getDefault`
+        let name = binding.name.trim_start_matches('_');
+        let mut chars = name.chars();
+        let name = match chars.next() {
+            Some(first) => format!("get{}{}", first.to_ascii_uppercase(), chars.as_str()),
+            None => String::from("get"),
+        };
+        let getter = ctx.generate_uid_in_root_scope(&name, SymbolFlags::Function);
+        self.bindings.push((binding.clone(), getter.clone()));
+        getter
+    }
+}
+
+/// Finds `await` outside of functions.
+pub(super) struct TopLevelAwaitFinder {
+    found: bool,
+}
+
+impl TopLevelAwaitFinder {
+    pub(super) fn find(program: &Program<'_>) -> bool {
+        let mut finder = Self { found: false };
+        finder.visit_statements(&program.body);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for TopLevelAwaitFinder {
+    fn visit_await_expression(&mut self, _it: &AwaitExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        if it.r#await {
+            self.found = true;
+        } else {
+            walk::walk_for_of_statement(self, it);
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
@@ -1,16 +1,95 @@
+mod array_from_length;
+mod at_to_index;
+mod built_ins;
+mod coalesce_template_literals;
+mod dedupe_export_names;
+mod destructure_member_chains;
+mod direct_eval;
+mod dynamic_requires;
+mod expand_export_star;
+mod explicit_radix;
+mod feature_guards;
+mod hoist_constant_expressions;
+mod hoist_loop_guards;
+mod hoist_requires;
+mod import_meta_glob;
+mod inline_imported_constants;
+mod json_parse;
+mod json_parse_to_literal;
+mod lazy_top_level_await;
+mod module_factory;
+mod namespace_to_named_imports;
+mod normalize_template_line_endings;
+mod normalize_undefined;
+mod object_iteration_to_for_in;
 mod options;
+mod promise_finally;
+mod property_mutators;
+mod react_component_display_name;
+mod react_constant_elements;
+mod reconstruct_ts_enums;
+mod rename_top_level_bindings;
+mod split_re_exports;
 mod styled_components;
+mod switch_lookup;
 mod tagged_template_transform;
+mod ternary_to_early_return;
+mod then_to_catch;
+mod usage_polyfills;
+mod well_known_symbols;
 
+pub use at_to_index::AtToIndexOptions;
+pub use destructure_member_chains::DestructureMemberChainsOptions;
+pub use direct_eval::DirectEvalOptions;
+pub use expand_export_star::{ExpandExportStarOptions, ExportNamesResolver};
+pub use explicit_radix::ExplicitRadixOptions;
+pub use feature_guards::FeatureGuardsOptions;
+pub use import_meta_glob::{GlobResolver, ImportMetaGlobOptions};
+pub use inline_imported_constants::{
+    ConstantResolver, ExportedConstant, InlineImportedConstantsOptions, collect_constant_exports,
+};
+pub use json_parse::JsonParseOptions;
+pub use module_factory::{MODULE_FACTORY_RUNTIME, ModuleFactoryOptions};
+pub use normalize_undefined::{NormalizeUndefinedOptions, UndefinedStyle};
 pub use options::PluginsOptions;
+use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
+pub use reconstruct_ts_enums::{CompiledEnum, CompiledEnumMember, recognize_compiled_enum};
+pub use rename_top_level_bindings::{RenameMap, rename_top_level_bindings};
 pub use styled_components::StyledComponentsOptions;
+pub use switch_lookup::SwitchLookupOptions;
+pub use tagged_template_transform::TaggedTemplateTransform;
+pub use ternary_to_early_return::TernaryToEarlyReturnOptions;
+pub use then_to_catch::ThenToCatchOptions;
+pub use usage_polyfills::UsagePolyfillsOptions;
+pub use well_known_symbols::WellKnownSymbolsOptions;
 
 use crate::{
     context::{TransformCtx, TraverseCtx},
+    options::EnvOptions,
     plugins::{
-        styled_components::StyledComponents, tagged_template_transform::TaggedTemplateTransform,
+        array_from_length::ArrayFromLength, at_to_index::AtToIndex,
+        coalesce_template_literals::CoalesceTemplateLiterals,
+        dedupe_export_names::DedupeExportNames, destructure_member_chains::DestructureMemberChains,
+        direct_eval::DirectEval, dynamic_requires::DynamicRequires,
+        expand_export_star::ExpandExportStar, explicit_radix::ExplicitRadix,
+        feature_guards::FeatureGuards, hoist_constant_expressions::HoistConstantExpressions,
+        hoist_loop_guards::HoistLoopGuards, hoist_requires::HoistRequires,
+        import_meta_glob::ImportMetaGlob, inline_imported_constants::InlineImportedConstants,
+        json_parse::JsonParse, json_parse_to_literal::JsonParseToLiteral,
+        lazy_top_level_await::LazyTopLevelAwait, module_factory::ModuleFactory,
+        namespace_to_named_imports::NamespaceToNamedImports,
+        normalize_template_line_endings::NormalizeTemplateLineEndings,
+        normalize_undefined::NormalizeUndefined,
+        object_iteration_to_for_in::ObjectIterationToForIn, promise_finally::PromiseFinally,
+        property_mutators::PropertyMutators,
+        react_component_display_name::ReactComponentDisplayName,
+        react_constant_elements::ReactConstantElements, reconstruct_ts_enums::ReconstructTsEnums,
+        split_re_exports::SplitReExports, styled_components::StyledComponents,
+        switch_lookup::SwitchLookup, ternary_to_early_return::TernaryToEarlyReturn,
+        then_to_catch::ThenToCatch, usage_polyfills::UsagePolyfills,
+        well_known_symbols::WellKnownSymbols,
     },
     state::TransformState,
 };
@@ -18,10 +97,44 @@ use crate::{
 pub struct Plugins<'a, 'ctx> {
     styled_components: Option<StyledComponents<'a, 'ctx>>,
     tagged_template_escape: Option<TaggedTemplateTransform<'a, 'ctx>>,
+    hoist_constant_expressions: Option<HoistConstantExpressions<'a, 'ctx>>,
+    well_known_symbols: Option<WellKnownSymbols<'a, 'ctx>>,
+    json_parse: Option<JsonParse>,
+    lazy_top_level_await: Option<LazyTopLevelAwait<'a, 'ctx>>,
+    expand_export_star: Option<ExpandExportStar>,
+    reconstruct_ts_enums: Option<ReconstructTsEnums>,
+    import_meta_glob: Option<ImportMetaGlob<'a, 'ctx>>,
+    usage_polyfills: Option<UsagePolyfills<'a, 'ctx>>,
+    hoist_requires: Option<HoistRequires>,
+    destructure_member_chains: Option<DestructureMemberChains<'a>>,
+    feature_guards: Option<FeatureGuards>,
+    property_mutators: Option<PropertyMutators<'a, 'ctx>>,
+    at_to_index: Option<AtToIndex<'a, 'ctx>>,
+    promise_finally: Option<PromiseFinally<'a, 'ctx>>,
+    react_constant_elements: Option<ReactConstantElements<'a>>,
+    dynamic_requires: Option<DynamicRequires<'a, 'ctx>>,
+    split_re_exports: Option<SplitReExports>,
+    direct_eval: Option<DirectEval<'a, 'ctx>>,
+    module_factory: Option<ModuleFactory<'a, 'ctx>>,
+    inline_imported_constants: Option<InlineImportedConstants>,
+    switch_lookup: Option<SwitchLookup<'a, 'ctx>>,
+    hoist_loop_guards: Option<HoistLoopGuards>,
+    normalize_template_line_endings: Option<NormalizeTemplateLineEndings>,
+    normalize_undefined: Option<NormalizeUndefined>,
+    explicit_radix: Option<ExplicitRadix>,
+    ternary_to_early_return: Option<TernaryToEarlyReturn>,
+    array_from_length: Option<ArrayFromLength>,
+    json_parse_to_literal: Option<JsonParseToLiteral>,
+    then_to_catch: Option<ThenToCatch>,
+    coalesce_template_literals: Option<CoalesceTemplateLiterals>,
+    namespace_to_named_imports: Option<NamespaceToNamedImports<'a>>,
+    dedupe_export_names: Option<DedupeExportNames<'a, 'ctx>>,
+    object_iteration_to_for_in: Option<ObjectIterationToForIn>,
+    react_component_display_name: Option<ReactComponentDisplayName<'a, 'ctx>>,
 }
 
 impl<'a, 'ctx> Plugins<'a, 'ctx> {
-    pub fn new(options: PluginsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(options: PluginsOptions, env: &EnvOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             styled_components: options
                 .styled_components
@@ -31,6 +144,75 @@ impl<'a, 'ctx> Plugins<'a, 'ctx> {
             } else {
                 None
             },
+            hoist_constant_expressions: options
+                .hoist_constant_expressions
+                .then(|| HoistConstantExpressions::new(ctx)),
+            well_known_symbols: options
+                .well_known_symbols
+                .map(|options| WellKnownSymbols::new(options, env, ctx)),
+            json_parse: options.json_parse.map(JsonParse::new),
+            lazy_top_level_await: options.lazy_top_level_await.then(|| LazyTopLevelAwait::new(ctx)),
+            expand_export_star: options.expand_export_star.map(ExpandExportStar::new),
+            reconstruct_ts_enums: options.reconstruct_ts_enums.then(ReconstructTsEnums::new),
+            import_meta_glob: options
+                .import_meta_glob
+                .map(|options| ImportMetaGlob::new(options, ctx)),
+            usage_polyfills: options
+                .usage_polyfills
+                .map(|options| UsagePolyfills::new(options, env, ctx)),
+            hoist_requires: options.hoist_requires.then(HoistRequires::new),
+            destructure_member_chains: options
+                .destructure_member_chains
+                .map(DestructureMemberChains::new),
+            feature_guards: options.feature_guards.map(|options| FeatureGuards::new(options, env)),
+            property_mutators: options.property_mutators.then(|| PropertyMutators::new(ctx)),
+            at_to_index: options.at_to_index.map(|options| AtToIndex::new(options, env, ctx)),
+            promise_finally: options.promise_finally.then(|| PromiseFinally::new(env, ctx)),
+            react_constant_elements: options
+                .react_constant_elements
+                .then(ReactConstantElements::new),
+            dynamic_requires: options.dynamic_requires.then(|| DynamicRequires::new(ctx)),
+            split_re_exports: options.split_re_exports.then(SplitReExports::new),
+            direct_eval: options.direct_eval.map(|options| DirectEval::new(options, ctx)),
+            module_factory: options.module_factory.map(|options| ModuleFactory::new(options, ctx)),
+            inline_imported_constants: options
+                .inline_imported_constants
+                .map(InlineImportedConstants::new),
+            switch_lookup: options.switch_lookup.map(|options| SwitchLookup::new(options, ctx)),
+            hoist_loop_guards: options.hoist_loop_guards.then(HoistLoopGuards::new),
+            normalize_template_line_endings: options
+                .normalize_template_line_endings
+                .then(NormalizeTemplateLineEndings::new),
+            normalize_undefined: options.normalize_undefined.map(NormalizeUndefined::new),
+            explicit_radix: options.explicit_radix.map(ExplicitRadix::new),
+            ternary_to_early_return: options.ternary_to_early_return.map(TernaryToEarlyReturn::new),
+            array_from_length: options.array_from_length.then(ArrayFromLength::new),
+            json_parse_to_literal: options.json_parse_to_literal.then(JsonParseToLiteral::new),
+            then_to_catch: options.then_to_catch.map(ThenToCatch::new),
+            coalesce_template_literals: options
+                .coalesce_template_literals
+                .then(CoalesceTemplateLiterals::new),
+            namespace_to_named_imports: options
+                .namespace_to_named_imports
+                .then(NamespaceToNamedImports::new),
+            dedupe_export_names: options.dedupe_export_names.then(|| DedupeExportNames::new(ctx)),
+            object_iteration_to_for_in: options
+                .object_iteration_to_for_in
+                .then(ObjectIterationToForIn::new),
+            react_component_display_name: options
+                .react_component_display_name
+                .then(|| ReactComponentDisplayName::new(ctx)),
+        }
+    }
+
+    /// Runs after the `exit_program` of all other transforms.
+    pub fn exit_program_after_all(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Before `ModuleFactory`, which converts the exports to assignments.
+        if let Some(dedupe_export_names) = &self.dedupe_export_names {
+            dedupe_export_names.transform_program(node, ctx);
+        }
+        if let Some(module_factory) = &mut self.module_factory {
+            module_factory.transform_program(node, ctx);
         }
     }
 }
@@ -40,6 +222,88 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_program(node, ctx);
         }
+        if let Some(lazy_top_level_await) = &mut self.lazy_top_level_await {
+            lazy_top_level_await.enter_program(node, ctx);
+        }
+        if let Some(expand_export_star) = &mut self.expand_export_star {
+            expand_export_star.enter_program(node, ctx);
+        }
+        // After `export *` is expanded, to split the expanded re-exports.
+        if let Some(split_re_exports) = &mut self.split_re_exports {
+            split_re_exports.enter_program(node, ctx);
+        }
+        if let Some(hoist_requires) = &mut self.hoist_requires {
+            hoist_requires.enter_program(node, ctx);
+        }
+        if let Some(dynamic_requires) = &mut self.dynamic_requires {
+            dynamic_requires.enter_program(node, ctx);
+        }
+        if let Some(inline_imported_constants) = &mut self.inline_imported_constants {
+            inline_imported_constants.enter_program(node, ctx);
+        }
+        if let Some(namespace_to_named_imports) = &mut self.namespace_to_named_imports {
+            namespace_to_named_imports.enter_program(node, ctx);
+        }
+        if let Some(react_component_display_name) = &mut self.react_component_display_name {
+            react_component_display_name.enter_program(node, ctx);
+        }
+        if let Some(hoist_loop_guards) = &mut self.hoist_loop_guards {
+            hoist_loop_guards.enter_program(node, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(react_constant_elements) = &mut self.react_constant_elements {
+            react_constant_elements.exit_program(node, ctx);
+        }
+        if let Some(inline_imported_constants) = &mut self.inline_imported_constants {
+            inline_imported_constants.exit_program(node, ctx);
+        }
+    }
+
+    fn enter_statements(
+        &mut self,
+        node: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(reconstruct_ts_enums) = &mut self.reconstruct_ts_enums {
+            reconstruct_ts_enums.enter_statements(node, ctx);
+        }
+        if let Some(destructure_member_chains) = &mut self.destructure_member_chains {
+            destructure_member_chains.enter_statements(node, ctx);
+        }
+        if let Some(switch_lookup) = &mut self.switch_lookup {
+            switch_lookup.enter_statements(node, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, node: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(hoist_loop_guards) = &mut self.hoist_loop_guards {
+            hoist_loop_guards.exit_statement(node, ctx);
+        }
+        if let Some(object_iteration_to_for_in) = &mut self.object_iteration_to_for_in {
+            object_iteration_to_for_in.exit_statement(node, ctx);
+        }
+    }
+
+    fn enter_template_literal(
+        &mut self,
+        node: &mut TemplateLiteral<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(normalize_template_line_endings) = &mut self.normalize_template_line_endings {
+            normalize_template_line_endings.enter_template_literal(node, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        node: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(usage_polyfills) = &mut self.usage_polyfills {
+            usage_polyfills.enter_identifier_reference(node, ctx);
+        }
     }
 
     fn enter_variable_declarator(
@@ -50,6 +314,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_variable_declarator(node, ctx);
         }
+        if let Some(ternary_to_early_return) = &mut self.ternary_to_early_return {
+            ternary_to_early_return.enter_variable_declarator(node, ctx);
+        }
     }
 
     fn enter_expression(
@@ -63,11 +330,87 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(tagged_template_escape) = &mut self.tagged_template_escape {
             tagged_template_escape.enter_expression(node, ctx);
         }
+        if let Some(json_parse) = &mut self.json_parse {
+            json_parse.enter_expression(node, ctx);
+        }
+        if let Some(import_meta_glob) = &mut self.import_meta_glob {
+            import_meta_glob.enter_expression(node, ctx);
+        }
+        if let Some(at_to_index) = &mut self.at_to_index {
+            at_to_index.enter_expression(node, ctx);
+        }
+        if let Some(react_constant_elements) = &mut self.react_constant_elements {
+            react_constant_elements.enter_expression(node, ctx);
+        }
+        if let Some(destructure_member_chains) = &mut self.destructure_member_chains {
+            destructure_member_chains.enter_expression(node, ctx);
+        }
+        if let Some(dynamic_requires) = &mut self.dynamic_requires {
+            dynamic_requires.enter_expression(node, ctx);
+        }
+        if let Some(inline_imported_constants) = &mut self.inline_imported_constants {
+            inline_imported_constants.enter_expression(node, ctx);
+        }
+        if let Some(normalize_undefined) = &mut self.normalize_undefined {
+            normalize_undefined.enter_expression(node, ctx);
+        }
+        if let Some(explicit_radix) = &mut self.explicit_radix {
+            explicit_radix.enter_expression(node, ctx);
+        }
+        if let Some(array_from_length) = &mut self.array_from_length {
+            array_from_length.enter_expression(node, ctx);
+        }
+        if let Some(json_parse_to_literal) = &mut self.json_parse_to_literal {
+            json_parse_to_literal.enter_expression(node, ctx);
+        }
+        if let Some(then_to_catch) = &mut self.then_to_catch {
+            then_to_catch.enter_expression(node, ctx);
+        }
+        if let Some(namespace_to_named_imports) = &mut self.namespace_to_named_imports {
+            namespace_to_named_imports.enter_expression(node, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(hoist_constant_expressions) = &mut self.hoist_constant_expressions {
+            hoist_constant_expressions.exit_expression(node, ctx);
+        }
+        if let Some(feature_guards) = &mut self.feature_guards {
+            feature_guards.exit_expression(node, ctx);
+        }
+        if let Some(property_mutators) = &mut self.property_mutators {
+            property_mutators.exit_expression(node, ctx);
+        }
+        if let Some(promise_finally) = &mut self.promise_finally {
+            promise_finally.exit_expression(node, ctx);
+        }
+        if let Some(react_constant_elements) = &mut self.react_constant_elements {
+            react_constant_elements.exit_expression(node, ctx);
+        }
+        if let Some(coalesce_template_literals) = &mut self.coalesce_template_literals {
+            coalesce_template_literals.exit_expression(node, ctx);
+        }
     }
 
     fn enter_call_expression(&mut self, node: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_call_expression(node, ctx);
         }
+        if let Some(direct_eval) = &mut self.direct_eval {
+            direct_eval.enter_call_expression(node, ctx);
+        }
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        node: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(well_known_symbols) = &mut self.well_known_symbols {
+            well_known_symbols.enter_static_member_expression(node, ctx);
+        }
+        if let Some(usage_polyfills) = &mut self.usage_polyfills {
+            usage_polyfills.enter_static_member_expression(node, ctx);
+        }
     }
 }
@@ -0,0 +1,710 @@
+//! Module Factory
+//!
+//! This plugin wraps the code of an ES module in a factory function, which is registered in a
+//! module registry by the module's id, for simple bundlers which concatenate modules into one script.
+//! The runtime of the registry is [`MODULE_FACTORY_RUNTIME`], which must be included once at the
+//! start of the bundle, before the wrapped modules.
+//!
+//! * `import { a } from "./a.js"` -> `var _a = __exports("./a.js");` and `__require("./a.js");`,
+//!   and each reference to `a` is replaced with `_a.a`. Reading the exports object on each access keeps the bindings live.
+//!   Calls of imported functions keep `this` undefined: `a()` -> `(0, _a.a)()`.
+//! * `import * as ns from "./a.js"` and `import "./a.js"` require the module the same way.
+//! * Exports are defined as getters on the exports object, at the start of the factory,
+//!   so they are live bindings too: `export let count = 0;` -> `__export(_exports, { count: () => count });`.
+//! * `export default <expression>` is assigned to a `_default` variable. Anonymous default
+//!   functions and classes are named `_default`.
+//! * `export { a } from "./a.js"` and `export * as ns from "./a.js"` define getters which read the
+//!   other module's exports. `export * from "./a.js"` copies the getters of the other module's exports
+//!   with `__exportStar`, except `default` and the names exported by the module itself.
+//!
+//! `__exports` returns the exports object of a module without evaluating it, and `__require`
+//! evaluates the module on the first call, at most once, so modules which are never required
+//! are not evaluated.
+//! Like ES modules, the imported modules are evaluated before the code of the importing module,
+//! in the order of the import declarations.
+//!
+//! The bindings of all exports objects are initialized before any module is required, so in a
+//! circular dependency, a module which is still being evaluated can read the exports of the
+//! module which required it. All its getters are already defined, so its function declarations
+//! can be called, and reading a `let`, `const` or `class` binding which is not initialized yet
+//! throws a `ReferenceError`, the same as with ES modules.
+//!
+//! The transform runs after all other transforms, so the imports of helpers and of the JSX runtime
+//! which other transforms add are wrapped too. CommonJS scripts are left unchanged.
+//!
+//! ## Missing features
+//!
+//! * Modules with top-level `await`. They would need an async factory, so a warning is reported
+//!   and the module is left unchanged.
+//! * Dynamic `import()` and `import.meta` are left unchanged.
+//! * Import specifiers are used as module ids as is. Resolve them before the transform,
+//!   so they match the ids of the modules in the bundle.
+//!
+//! ## Example
+//!
+//! Input (with `id: "./main.js"`):
+//! ```js
+//! import { count, increment } from "./counter.js";
+//! export const before = count;
+//! increment();
+//! export default count;
+//! ```
+//!
+//! Output:
+//! ```js
+//! __define("./main.js", function(_exports) {
+//!   "use strict";
+//!   __export(_exports, {
+//!     before: () => before,
+//!     default: () => _default
+//!   });
+//!   var _counter = __exports("./counter.js");
+//!   __require("./counter.js");
+//!   const before = _counter.count;
+//!   (0, _counter.increment)();
+//!   var _default = _counter.count;
+//! });
+//! ```
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{SPAN, Span};
+use oxc_syntax::{identifier::is_identifier_name, number::NumberBase, reference::ReferenceFlags};
+use oxc_traverse::{BoundIdentifier, ast_operations::to_identifier};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    plugins::lazy_top_level_await::TopLevelAwaitFinder,
+};
+
+/// The runtime of the module registry, which defines `__define`, `__exports`, `__require`,
+/// `__export` and `__exportStar`.
+///
+/// It must be included once at the start of the bundle, before the modules wrapped by this plugin.
+pub const MODULE_FACTORY_RUNTIME: &str = r#"var __modules = {};
+var __cache = {};
+var __evaluated = {};
+function __define(id, factory) {
+  __modules[id] = factory;
+}
+function __exports(id) {
+  if (id in __cache) return __cache[id];
+  if (!(id in __modules)) throw new Error("Cannot find module '" + id + "'");
+  var exports = __cache[id] = Object.create(null);
+  Object.defineProperty(exports, Symbol.toStringTag, { value: "Module" });
+  return exports;
+}
+function __require(id) {
+  var exports = __exports(id);
+  if (!(id in __evaluated)) {
+    __evaluated[id] = true;
+    __modules[id](exports);
+  }
+  return exports;
+}
+function __export(exports, getters) {
+  for (var name in getters) {
+    Object.defineProperty(exports, name, { enumerable: true, get: getters[name] });
+  }
+}
+function __exportStar(exports, module) {
+  Object.keys(module).forEach(function (name) {
+    if (name === "default" || Object.prototype.hasOwnProperty.call(exports, name)) return;
+    Object.defineProperty(exports, name, { enumerable: true, get: function () { return module[name]; } });
+  });
+}
+"#;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModuleFactoryOptions {
+    /// The id the module is registered by, which other modules pass to `__require`.
+    ///
+    /// Default: the path of the source file.
+    pub id: Option<String>,
+}
+
+fn top_level_await_not_supported() -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Module was not wrapped in a module factory, because it contains top-level `await`.",
+    )
+}
+
+pub struct ModuleFactory<'a, 'ctx> {
+    options: ModuleFactoryOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ModuleFactory<'a, 'ctx> {
+    pub fn new(options: ModuleFactoryOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+/// An imported binding, which is read from the exports object of the imported module.
+struct Import<'a> {
+    /// The binding of the exports object of the imported module.
+    module: BoundIdentifier<'a>,
+    /// The imported name, or `None` for a namespace import.
+    name: Option<Atom<'a>>,
+}
+
+/// The imported modules, in the order of the import and re-export declarations.
+struct Dependencies<'a> {
+    /// The bindings of the exports objects, by module id.
+    modules: FxHashMap<Atom<'a>, BoundIdentifier<'a>>,
+    /// `var _a = __exports("./a.js");`
+    bindings: ArenaVec<'a, Statement<'a>>,
+    /// The modules which are already required.
+    required: FxHashSet<Atom<'a>>,
+    /// `__require("./a.js");`
+    requires: ArenaVec<'a, Statement<'a>>,
+}
+
+impl<'a> Dependencies<'a> {
+    fn new(ctx: &TraverseCtx<'a>) -> Self {
+        Self {
+            modules: FxHashMap::default(),
+            bindings: ctx.ast.vec(),
+            required: FxHashSet::default(),
+            requires: ctx.ast.vec(),
+        }
+    }
+
+    /// `__require("./a.js");`, unless the module is already required.
+    fn require(&mut self, source: Atom<'a>, span: Span, ctx: &mut TraverseCtx<'a>) {
+        if self.required.insert(source) {
+            let require = ModuleFactory::create_require(source, ctx);
+            self.requires.push(ctx.ast.statement_expression(span, require));
+        }
+    }
+
+    /// The binding of the exports object of the module `source`, which is required too.
+    fn get_module(
+        &mut self,
+        source: Atom<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        self.require(source, span, ctx);
+        if let Some(module) = self.modules.get(&source) {
+            return module.clone();
+        }
+        // `"./utils/format.js"` -> `_format`
+        let file_name = source.rsplit('/').next().unwrap_or_default();
+        let stem = file_name.split('.').next().unwrap_or_default();
+        let name =
+            to_identifier(if stem.is_empty() { source.to_string() } else { stem.to_string() });
+        let module = ctx.generate_uid_in_root_scope(&name, SymbolFlags::FunctionScopedVariable);
+
+        // `var _a = __exports("./a.js");`
+        let arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, source, None)));
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            module.create_binding_pattern(ctx),
+            NONE,
+            Some(ModuleFactory::create_runtime_call("__exports", arguments, ctx)),
+            false,
+        );
+        self.bindings.push(Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+        self.modules.insert(source, module.clone());
+        module
+    }
+}
+
+impl<'a> ModuleFactory<'a, '_> {
+    /// Runs after all other transforms, instead of in `exit_program`.
+    pub fn transform_program(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+        if TopLevelAwaitFinder::find(program) {
+            self.ctx.error(top_level_await_not_supported());
+            return;
+        }
+
+        let exports =
+            ctx.generate_uid_in_root_scope("exports", SymbolFlags::FunctionScopedVariable);
+        let mut dependencies = Dependencies::new(ctx);
+        let mut imports = FxHashMap::<SymbolId, Import<'a>>::default();
+        let mut getters = vec![];
+        let mut body = ctx.ast.vec();
+
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let ImportDeclaration { span, source, specifiers, .. } = decl.unbox();
+                    let Some(specifiers) = specifiers.filter(|specifiers| !specifiers.is_empty())
+                    else {
+                        // `import "./a.js"` -> `__require("./a.js");`
+                        dependencies.require(source.value, span, ctx);
+                        continue;
+                    };
+                    let module = dependencies.get_module(source.value, span, ctx);
+                    for specifier in specifiers {
+                        let (local, name) = match &specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                (&specifier.local, Some(specifier.imported.name()))
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                (&specifier.local, Some(Atom::from("default")))
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                                (&specifier.local, None)
+                            }
+                        };
+                        imports.insert(local.symbol_id(), Import { module: module.clone(), name });
+                    }
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    let ExportAllDeclaration { span, exported, source, .. } = decl.unbox();
+                    if let Some(exported) = exported {
+                        // `export * as ns from "./a.js"` -> `ns: () => _a`
+                        let module = dependencies.get_module(source.value, span, ctx);
+                        getters.push((exported.name(), module.create_read_expression(ctx)));
+                    } else {
+                        // `export * from "./a.js"` -> `__exportStar(_exports, __require("./a.js"));`
+                        let require = Self::create_require(source.value, ctx);
+                        let arguments = ctx.ast.vec_from_array([
+                            Argument::from(exports.create_read_expression(ctx)),
+                            Argument::from(require),
+                        ]);
+                        let call = Self::create_runtime_call("__exportStar", arguments, ctx);
+                        dependencies.required.insert(source.value);
+                        dependencies.requires.push(ctx.ast.statement_expression(span, call));
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    let ExportNamedDeclaration { span, declaration, specifiers, source, .. } =
+                        decl.unbox();
+                    if let Some(declaration) = declaration {
+                        // `export const a = 1;` -> `a: () => a`
+                        declaration.bound_names(&mut |ident| {
+                            let binding = BoundIdentifier::from_binding_ident(ident);
+                            getters.push((ident.name, binding.create_read_expression(ctx)));
+                        });
+                        body.push(Statement::from(declaration));
+                    } else if let Some(source) = source {
+                        // `export { a as b } from "./a.js"` -> `b: () => _a.a`
+                        if specifiers.is_empty() {
+                            continue;
+                        }
+                        let module = dependencies.get_module(source.value, span, ctx);
+                        for specifier in specifiers {
+                            let object = module.create_read_expression(ctx);
+                            let value = Self::create_member(object, specifier.local.name(), ctx);
+                            getters.push((specifier.exported.name(), value));
+                        }
+                    } else {
+                        // `export { a as b }` -> `b: () => a`
+                        for specifier in specifiers {
+                            let ModuleExportName::IdentifierReference(local) = specifier.local
+                            else {
+                                continue;
+                            };
+                            getters.push((
+                                specifier.exported.name(),
+                                Expression::Identifier(ctx.ast.alloc(local)),
+                            ));
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let ExportDefaultDeclaration { span, declaration } = decl.unbox();
+                    let binding = match declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                            let binding = Self::get_or_create_default_name(
+                                &mut func.id,
+                                SymbolFlags::Function,
+                                ctx,
+                            );
+                            func.r#type = FunctionType::FunctionDeclaration;
+                            body.push(Statement::FunctionDeclaration(func));
+                            binding
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                            let binding = Self::get_or_create_default_name(
+                                &mut class.id,
+                                SymbolFlags::Class,
+                                ctx,
+                            );
+                            class.r#type = ClassType::ClassDeclaration;
+                            body.push(Statement::ClassDeclaration(class));
+                            binding
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => continue,
+                        declaration => {
+                            // `export default a + b;` -> `var _default = a + b;`
+                            let binding = ctx.generate_uid_in_root_scope(
+                                "default",
+                                SymbolFlags::FunctionScopedVariable,
+                            );
+                            let declarator = ctx.ast.variable_declarator(
+                                span,
+                                VariableDeclarationKind::Var,
+                                binding.create_binding_pattern(ctx),
+                                NONE,
+                                Some(declaration.into_expression()),
+                                false,
+                            );
+                            body.push(Statement::from(ctx.ast.declaration_variable(
+                                span,
+                                VariableDeclarationKind::Var,
+                                ctx.ast.vec1(declarator),
+                                false,
+                            )));
+                            binding
+                        }
+                    };
+                    getters.push((Atom::from("default"), binding.create_read_expression(ctx)));
+                }
+                stmt => body.push(stmt),
+            }
+        }
+
+        let Dependencies { bindings, requires, .. } = dependencies;
+        let mut stmts = ctx.ast.vec_with_capacity(bindings.len() + requires.len() + body.len() + 1);
+        if !getters.is_empty() {
+            stmts.push(Self::create_export_getters(&exports, getters, ctx));
+        }
+        stmts.extend(bindings);
+        stmts.extend(requires);
+        stmts.extend(body);
+
+        ImportReferenceReplacer::new(&imports, ctx).visit_statements(&mut stmts);
+
+        let scope_id = ctx
+            .insert_scope_below_statements(&stmts, ScopeFlags::Function | ScopeFlags::StrictMode);
+        Self::move_bindings_to_scope(scope_id, ctx);
+
+        let factory = Self::create_factory(&exports, stmts, scope_id, ctx);
+        let id = self
+            .options
+            .id
+            .clone()
+            .unwrap_or_else(|| self.ctx.source_path.to_string_lossy().into_owned());
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&id), None)),
+            Argument::from(factory),
+        ]);
+        let define = Self::create_runtime_call("__define", arguments, ctx);
+        program.body.push(ctx.ast.statement_expression(SPAN, define));
+    }
+
+    /// `export default function () {}` -> `function _default() {}`
+    fn get_or_create_default_name(
+        id: &mut Option<BindingIdentifier<'a>>,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(id) = id {
+            return BoundIdentifier::from_binding_ident(id);
+        }
+        let binding = ctx.generate_uid_in_root_scope("default", flags);
+        *id = Some(binding.create_binding_identifier(ctx));
+        binding
+    }
+
+    /// ```js
+    /// __export(_exports, {
+    ///   a: () => a,
+    ///   b: () => _b.b
+    /// });
+    /// ```
+    fn create_export_getters(
+        exports: &BoundIdentifier<'a>,
+        getters: Vec<(Atom<'a>, Expression<'a>)>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut properties = ctx.ast.vec_with_capacity(getters.len());
+        for (name, value) in getters {
+            let scope_id = ctx.create_child_scope(
+                ctx.scoping().root_scope_id(),
+                ScopeFlags::Arrow | ScopeFlags::Function | ScopeFlags::StrictMode,
+            );
+            let getter = Self::create_arrow(value, scope_id, ctx);
+            // `{ __proto__: x }` would set the prototype of the object, `{ ["__proto__"]: x }` doesn't.
+            let computed = name == "__proto__";
+            let key = if is_identifier_name(&name) && !computed {
+                ctx.ast.property_key_static_identifier(SPAN, name)
+            } else {
+                PropertyKey::StringLiteral(ctx.ast.alloc_string_literal(SPAN, name, None))
+            };
+            properties.push(ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                getter,
+                false,
+                false,
+                computed,
+            ));
+        }
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(exports.create_read_expression(ctx)),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        let call = Self::create_runtime_call("__export", arguments, ctx);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+
+    /// `() => value`
+    fn create_arrow(
+        value: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, value)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// `function(_exports) { "use strict"; ... }`
+    fn create_factory(
+        exports: &BoundIdentifier<'a>,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            exports.create_binding_pattern(ctx),
+            NONE,
+            NONE,
+            false,
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let use_strict = ctx.ast.directive(
+            SPAN,
+            ctx.ast.string_literal(SPAN, "use strict", None),
+            Atom::from("use strict"),
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec1(use_strict), stmts);
+        Expression::FunctionExpression(ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        ))
+    }
+
+    /// The top level bindings are declared in the factory now.
+    fn move_bindings_to_scope(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let bindings = ctx
+            .scoping()
+            .get_bindings(root_scope_id)
+            .iter()
+            .map(|(name, &symbol_id)| (name.to_string(), symbol_id))
+            .collect::<Vec<_>>();
+        let scoping = ctx.scoping_mut();
+        for (name, symbol_id) in bindings {
+            scoping.move_binding(root_scope_id, scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, scope_id);
+        }
+    }
+
+    /// `__require("./a.js")`
+    fn create_require(source: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, source, None)));
+        Self::create_runtime_call("__require", arguments, ctx)
+    }
+
+    fn create_runtime_call(
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `object.name`, or `object["name"]` if `name` isn't an identifier.
+    fn create_member(
+        object: Expression<'a>,
+        name: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if is_identifier_name(&name) {
+            Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                object,
+                ctx.ast.identifier_name(SPAN, name),
+                false,
+            ))
+        } else {
+            Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                object,
+                ctx.ast.expression_string_literal(SPAN, name, None),
+                false,
+            ))
+        }
+    }
+}
+
+/// Replaces the references to imported bindings with reads of the imported module's exports object.
+struct ImportReferenceReplacer<'a, 'b> {
+    imports: &'b FxHashMap<SymbolId, Import<'a>>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> ImportReferenceReplacer<'a, 'b> {
+    fn new(imports: &'b FxHashMap<SymbolId, Import<'a>>, ctx: &'b mut TraverseCtx<'a>) -> Self {
+        Self { imports, ctx }
+    }
+
+    fn get_import(&self, ident: &IdentifierReference<'a>) -> Option<&'b Import<'a>> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.imports.get(&symbol_id)
+    }
+
+    /// `a` -> `_a.a`, `ns` -> `_a`
+    fn create_import_expression(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        import: &Import<'a>,
+    ) -> Expression<'a> {
+        self.ctx.delete_reference_for_identifier(ident);
+        let object = import.module.create_spanned_read_expression(ident.span, self.ctx);
+        match import.name {
+            Some(name) => ModuleFactory::create_member(object, name, self.ctx),
+            None => object,
+        }
+    }
+
+    /// `a` -> `(0, _a.a)`, so `this` is undefined when `a` is called.
+    fn create_callee(&mut self, callee: &mut Expression<'a>) {
+        let Expression::Identifier(ident) = callee else { return };
+        let Some(import) = self.get_import(ident) else { return };
+        if import.name.is_none() {
+            return;
+        }
+        let member = self.create_import_expression(ident, import);
+        let zero = self.ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+        *callee =
+            self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, member]));
+    }
+
+    /// `A` -> `_a.A`, `ns` -> `_a` in JSX element names.
+    fn create_jsx_member_expression(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        import: &Import<'a>,
+    ) -> Result<JSXMemberExpression<'a>, IdentifierReference<'a>> {
+        self.ctx.delete_reference_for_identifier(ident);
+        let module = import.module.create_spanned_read_reference(ident.span, self.ctx);
+        let Some(name) = import.name else { return Err(module) };
+        let object = JSXMemberExpressionObject::IdentifierReference(self.ctx.ast.alloc(module));
+        let property = self.ctx.ast.jsx_identifier(ident.span, name);
+        Ok(self.ctx.ast.jsx_member_expression(ident.span, object, property))
+    }
+}
+
+impl<'a> VisitMut<'a> for ImportReferenceReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && let Some(import) = self.get_import(ident)
+        {
+            *expr = self.create_import_expression(ident, import);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        self.create_callee(&mut call.callee);
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        self.create_callee(&mut expr.tag);
+        walk_mut::walk_tagged_template_expression(self, expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // `{ a }` -> `{ a: _a.a }`
+        if prop.shorthand
+            && let Expression::Identifier(ident) = &prop.value
+            && self.get_import(ident).is_some()
+        {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &mut JSXElementName<'a>) {
+        // `<A />` -> `<_a.A />`
+        if let JSXElementName::IdentifierReference(ident) = name
+            && let Some(import) = self.get_import(ident)
+        {
+            *name = match self.create_jsx_member_expression(ident, import) {
+                Ok(member) => JSXElementName::MemberExpression(self.ctx.ast.alloc(member)),
+                Err(module) => JSXElementName::IdentifierReference(self.ctx.ast.alloc(module)),
+            };
+            return;
+        }
+        walk_mut::walk_jsx_element_name(self, name);
+    }
+
+    fn visit_jsx_member_expression_object(&mut self, object: &mut JSXMemberExpressionObject<'a>) {
+        // `<A.B />` -> `<_a.A.B />`
+        if let JSXMemberExpressionObject::IdentifierReference(ident) = object
+            && let Some(import) = self.get_import(ident)
+        {
+            *object = match self.create_jsx_member_expression(ident, import) {
+                Ok(member) => {
+                    JSXMemberExpressionObject::MemberExpression(self.ctx.ast.alloc(member))
+                }
+                Err(module) => {
+                    JSXMemberExpressionObject::IdentifierReference(self.ctx.ast.alloc(module))
+                }
+            };
+            return;
+        }
+        walk_mut::walk_jsx_member_expression_object(self, object);
+    }
+}
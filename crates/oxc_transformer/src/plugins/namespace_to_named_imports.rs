@@ -0,0 +1,209 @@
+//! Namespace to Named Imports
+//!
+//! This plugin replaces a namespace import by named imports of the properties which are read
+//! from it, e.g. `import * as utils from "m"; utils.foo()` with `import { foo } from "m"; foo()`.
+//! Bundlers can remove the unused exports of a module which is only imported by name, but
+//! not always of a module whose namespace object is imported.
+//!
+//! A namespace import is only replaced if the namespace is only used as the object of static
+//! member expressions, e.g. `utils.foo`. It is left untouched if it is used in any other way:
+//!
+//! * Computed member expressions, e.g. `utils[name]`, which may read any export.
+//! * The namespace used as a value, e.g. `call(utils)`, `export { utils }` or
+//!   `Object.keys(utils)`, or in a type, e.g. `utils.Options` in TypeScript.
+//! * Assignments to properties, `delete` operands, and optional member expressions
+//!   (`utils?.foo`).
+//!
+//! Each property is imported with its own name, unless the name is a reserved word or is used
+//! anywhere else in the program, in which case a unique name is generated, e.g. `_foo`.
+//! Only top-level value imports are replaced, not `import type * as` or `import defer * as`.
+//!
+//! Every property which is read must be exported by the module, as importing a name which
+//! isn't exported is a syntax error, while reading it from the namespace returns `undefined`.
+//! Calls of exported functions, e.g. `utils.foo()`, are called with `undefined` as `this`
+//! instead of the namespace object.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import * as utils from "./utils";
+//! utils.log(utils.format(value));
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { log, format } from "./utils";
+//! log(format(value));
+//! ```
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::{Scoping, SymbolFlags, SymbolId};
+use oxc_span::{SPAN, Span};
+use oxc_syntax::{keyword::is_reserved_keyword_or_global_object, operator::UnaryOperator};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct NamespaceToNamedImports<'a> {
+    /// Property reads to replace, by span, with the binding imported by name.
+    replacements: FxHashMap<Span, BoundIdentifier<'a>>,
+}
+
+impl NamespaceToNamedImports<'_> {
+    pub fn new() -> Self {
+        Self { replacements: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for NamespaceToNamedImports<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut collector = NamespaceUseCollector::new(ctx.scoping());
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() || decl.phase.is_some() {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                if let ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) = specifier {
+                    collector.namespaces.insert(specifier.local.symbol_id(), Some(vec![]));
+                }
+            }
+        }
+        if collector.namespaces.is_empty() {
+            return;
+        }
+        collector.visit_program(program);
+        let namespaces = collector.namespaces;
+
+        for stmt in &mut program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &mut decl.specifiers else { continue };
+            let Some(index) = specifiers.iter().position(|specifier| {
+                matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+            }) else {
+                continue;
+            };
+            let symbol_id = specifiers[index].local().symbol_id();
+            let Some(Some(uses)) = namespaces.get(&symbol_id) else { continue };
+            if uses.is_empty() {
+                continue;
+            }
+
+            let mut bindings: Vec<(Atom<'a>, BoundIdentifier<'a>)> = vec![];
+            for &(property, span) in uses {
+                let binding = if let Some((_, binding)) =
+                    bindings.iter().find(|(name, _)| *name == property)
+                {
+                    binding.clone()
+                } else {
+                    let binding = Self::create_binding(property, ctx);
+                    bindings.push((property, binding.clone()));
+                    binding
+                };
+                self.replacements.insert(span, binding);
+            }
+
+            // `import * as utils from "m"` -> `import { foo, bar as _bar } from "m"`
+            let named = bindings.iter().map(|(property, binding)| {
+                ctx.ast.import_declaration_specifier_import_specifier(
+                    SPAN,
+                    ctx.ast.module_export_name_identifier_name(SPAN, *property),
+                    binding.create_binding_identifier(ctx),
+                    ImportOrExportKind::Value,
+                )
+            });
+            specifiers.splice(index..=index, named);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.replacements.is_empty() {
+            return;
+        }
+        let Expression::StaticMemberExpression(member) = expr else { return };
+        let Some(binding) = self.replacements.remove(&member.span) else { return };
+        if let Expression::Identifier(ident) = &member.object {
+            ctx.delete_reference_for_identifier(ident);
+        }
+        // `utils.foo` -> `foo`
+        *expr = binding.create_read_expression(ctx);
+    }
+}
+
+impl<'a> NamespaceToNamedImports<'a> {
+    /// A binding for the imported `property`, named after it if the name is available.
+    fn create_binding(property: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        if Self::is_available_name(&property, ctx) {
+            ctx.generate_binding(property, ctx.scoping().root_scope_id(), SymbolFlags::Import)
+        } else {
+            ctx.generate_uid_in_root_scope(&property, SymbolFlags::Import)
+        }
+    }
+
+    /// A name for a binding which doesn't shadow, and isn't shadowed by, any other binding.
+    fn is_available_name(name: &str, ctx: &TraverseCtx<'a>) -> bool {
+        !is_reserved_keyword_or_global_object(name)
+            && !matches!(name, "arguments" | "eval")
+            && !ctx.scoping().root_unresolved_references().contains_key(name)
+            && !ctx.scoping().symbol_names().any(|symbol_name| symbol_name == name)
+    }
+}
+
+/// Collects the properties read from each namespace, in source order.
+struct NamespaceUseCollector<'a, 'b> {
+    scoping: &'b Scoping,
+    /// The property reads of each namespace, or `None` if it is used in any other way.
+    namespaces: FxHashMap<SymbolId, Option<Vec<(Atom<'a>, Span)>>>,
+}
+
+impl<'b> NamespaceUseCollector<'_, 'b> {
+    fn new(scoping: &'b Scoping) -> Self {
+        Self { scoping, namespaces: FxHashMap::default() }
+    }
+
+    fn namespace_of(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id()?;
+        self.namespaces.contains_key(&symbol_id).then_some(symbol_id)
+    }
+}
+
+impl<'a> Visit<'a> for NamespaceUseCollector<'a, '_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        // `utils.foo`
+        if let Expression::StaticMemberExpression(member) = expr
+            && !member.optional
+            && !member.span.is_empty()
+            && let Expression::Identifier(ident) = &member.object
+            && let Some(symbol_id) = self.namespace_of(ident)
+        {
+            if let Some(Some(uses)) = self.namespaces.get_mut(&symbol_id) {
+                uses.push((member.property.name, member.span));
+            }
+            return;
+        }
+        walk::walk_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        // `delete utils.foo` throws, but `delete foo` is a syntax error.
+        if expr.operator == UnaryOperator::Delete
+            && let Expression::StaticMemberExpression(member) = expr.argument.without_parentheses()
+            && let Expression::Identifier(ident) = &member.object
+            && let Some(symbol_id) = self.namespace_of(ident)
+        {
+            self.namespaces.insert(symbol_id, None);
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.namespace_of(ident) {
+            self.namespaces.insert(symbol_id, None);
+        }
+    }
+}
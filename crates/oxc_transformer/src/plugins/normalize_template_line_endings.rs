@@ -0,0 +1,79 @@
+//! Normalize Template Line Endings
+//!
+//! This plugin replaces `\r\n` and lone `\r` line endings inside the quasis of template literals
+//! with `\n`, for build targets which require LF line endings in the output.
+//!
+//! Line endings inside template literals are always normalized to `\n` in their values,
+//! including the strings of the `raw` array passed to tags, so this doesn't change any values.
+//! The parser already normalizes the raw text of most template literals, but keeps it
+//! for tagged templates with invalid escapes, e.g. ``String.raw`\unicode<CRLF>` ``,
+//! and ASTs which don't come from the parser may contain `\r` as well.
+//!
+//! Escapes such as `\r\n` are left untouched, as are the interpolations.
+//! The cooked value is only normalized when the quasi has no escapes, otherwise a `\r`
+//! in the cooked value may come from an escape.
+//!
+//! ## Example
+//!
+//! Input, with CRLF line endings:
+//! ```js
+//! tag`\unicode
+//! ${value}\r\n`;
+//! ```
+//!
+//! Output, with LF line endings:
+//! ```js
+//! tag`\unicode
+//! ${value}\r\n`;
+//! ```
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct NormalizeTemplateLineEndings;
+
+impl NormalizeTemplateLineEndings {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for NormalizeTemplateLineEndings {
+    fn enter_template_literal(&mut self, lit: &mut TemplateLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        for quasi in &mut lit.quasis {
+            Self::normalize_quasi(quasi, ctx);
+        }
+    }
+}
+
+impl<'a> NormalizeTemplateLineEndings {
+    fn normalize_quasi(quasi: &mut TemplateElement<'a>, ctx: &TraverseCtx<'a>) {
+        let value = &mut quasi.value;
+        if !value.raw.contains('\r') {
+            return;
+        }
+        let raw = ctx.ast.atom(&normalize_line_endings(&value.raw));
+        // Without escapes, the cooked value is the same as the raw value.
+        if value.cooked.is_some() && !value.raw.contains('\\') {
+            value.cooked = Some(raw);
+        }
+        value.raw = raw;
+    }
+}
+
+/// Replace `\r\n` and lone `\r` with `\n`.
+fn normalize_line_endings(s: &str) -> String {
+    let mut normalized = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' {
+            chars.next_if_eq(&'\n');
+            normalized.push('\n');
+        } else {
+            normalized.push(c);
+        }
+    }
+    normalized
+}
@@ -0,0 +1,175 @@
+//! Normalize Undefined
+//!
+//! This plugin converts between the two ways of writing `undefined`, in either direction:
+//!
+//! * [`UndefinedStyle::Void0`] replaces references to the global `undefined` with `void 0`,
+//!   which is shorter and can't be shadowed, as minifiers do.
+//!   `undefined` declared as a local variable, and assignments to `undefined`, are left untouched.
+//!   So is `delete undefined`, which is `false`, while `delete void 0` is `true`.
+//! * [`UndefinedStyle::Undefined`] replaces `void 0`, and `void` of any other literal, with
+//!   `undefined`, which is easier to read. `void` of any other expression is left untouched,
+//!   as the expression has to be evaluated, and so is `void 0` where a local variable named
+//!   `undefined` is in scope, or which is the operand of `delete`.
+//!
+//! Neither is changed inside the body of a `with` statement, where `undefined` may refer to a
+//! property of the object.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! if (value === undefined) value = void 0;
+//! ```
+//!
+//! Output, with `style: "void0"`:
+//! ```js
+//! if (value === void 0) value = void 0;
+//! ```
+//!
+//! Output, with `style: "undefined"`:
+//! ```js
+//! if (value === undefined) value = undefined;
+//! ```
+
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_syntax::{operator::UnaryOperator, reference::ReferenceFlags};
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UndefinedStyle {
+    /// `void 0`
+    #[default]
+    Void0,
+    /// `undefined`
+    Undefined,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NormalizeUndefinedOptions {
+    /// How `undefined` is written in the output.
+    ///
+    /// Default: `"void0"`
+    pub style: UndefinedStyle,
+}
+
+pub struct NormalizeUndefined {
+    options: NormalizeUndefinedOptions,
+}
+
+impl NormalizeUndefined {
+    pub fn new(options: NormalizeUndefinedOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for NormalizeUndefined {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(
+            expr,
+            Expression::Identifier(_)
+                | Expression::ObjectExpression(_)
+                | Expression::UnaryExpression(_)
+        ) || Self::is_delete_operand(ctx)
+            || Self::is_in_with_body(ctx)
+        {
+            return;
+        }
+        match self.options.style {
+            UndefinedStyle::Void0 => Self::to_void_0(expr, ctx),
+            UndefinedStyle::Undefined => Self::to_undefined(expr, ctx),
+        }
+    }
+}
+
+impl<'a> NormalizeUndefined {
+    /// `undefined` -> `void 0`
+    fn to_void_0(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::Identifier(ident) if Self::is_global_undefined(ident, ctx) => {
+                let span = ident.span;
+                ctx.delete_reference_for_identifier(ident);
+                *expr = ctx.ast.void_0(span);
+            }
+            // `{ undefined }` -> `{ undefined: void 0 }`
+            Expression::ObjectExpression(object) => {
+                for property in &mut object.properties {
+                    if let ObjectPropertyKind::ObjectProperty(property) = property
+                        && property.shorthand
+                        && let Expression::Identifier(ident) = &property.value
+                        && Self::is_global_undefined(ident, ctx)
+                    {
+                        property.shorthand = false;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `void 0` -> `undefined`
+    fn to_undefined(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UnaryExpression(unary) = expr else { return };
+        if unary.operator != UnaryOperator::Void
+            || !Self::is_literal(&unary.argument)
+            || ctx.scoping().find_binding(ctx.current_scope_id(), "undefined").is_some()
+        {
+            return;
+        }
+        *expr = ctx.create_unbound_ident_expr(
+            unary.span,
+            ctx.ast.atom("undefined"),
+            ReferenceFlags::Read,
+        );
+    }
+
+    /// `delete undefined` or `delete (void 0)`
+    fn is_delete_operand(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ParenthesizedExpressionExpression(_) => {}
+                Ancestor::UnaryExpressionArgument(unary) => {
+                    return *unary.operator() == UnaryOperator::Delete;
+                }
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    /// `with (obj) { undefined }`, where `undefined` may be `obj.undefined`.
+    fn is_in_with_body(ctx: &TraverseCtx<'a>) -> bool {
+        ctx.ancestors().any(|ancestor| matches!(ancestor, Ancestor::WithStatementBody(_)))
+    }
+
+    /// A read of the global `undefined`. Only `Expression::Identifier` is a read,
+    /// assignment targets are a different kind of node.
+    fn is_global_undefined(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ident.name == "undefined"
+            && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+    }
+
+    /// `0`, `"a"`, `true`, `null`, `-1` or `void 0`, whose evaluation has no side effects.
+    fn is_literal(expr: &Expression<'a>) -> bool {
+        match expr.without_parentheses() {
+            Expression::NumericLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::BigIntLiteral(_) => true,
+            Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+            Expression::UnaryExpression(unary) => {
+                matches!(
+                    unary.operator,
+                    UnaryOperator::UnaryNegation | UnaryOperator::LogicalNot | UnaryOperator::Void
+                ) && Self::is_literal(&unary.argument)
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Object Iteration to For-In
+//!
+//! This plugin converts a `for...of` loop over `Object.entries(obj)`, `Object.keys(obj)` or
+//! `Object.values(obj)` to a `for...in` loop over `obj`, which doesn't allocate an array of
+//! the keys or entries first.
+//!
+//! This is an opt-in performance optimization. `for...in` also visits the enumerable properties
+//! which are inherited from the prototype chain, so each key is checked with
+//! `Object.prototype.hasOwnProperty`, and the values are read with `obj[key]`. Both skip
+//! symbol keys, and visit the own keys in the same order. The loop is only converted when:
+//!
+//! * `Object` is the global `Object`.
+//! * `obj` is a variable which is never reassigned.
+//! * The loop declares its variables with `let` or `const`, and they are plain
+//!   identifiers: `[key, value]` or `[key]` for `Object.entries`, `key` for `Object.keys`, and
+//!   `value` for `Object.values`. `for await`, defaults, holes and rest elements are left alone.
+//!   A `var` key would be visible after the loop, and could hold an inherited key which was
+//!   skipped by the `hasOwnProperty` check.
+//! * The body of the loop only reads properties of `obj`, e.g. `obj.x` or `obj[key]`, and
+//!   doesn't use `obj` in any other way. `Object.entries` takes a snapshot of the properties
+//!   before the loop starts, while `for...in` reads the value of each property when it gets to it,
+//!   and doesn't visit properties which were deleted in the meantime, so assigning to or deleting
+//!   a property of `obj`, calling a method of `obj`, or passing `obj` to a function, could change
+//!   which properties are visited.
+//! * The body doesn't declare a variable named `Object`, nor with the name of `obj` or the
+//!   loop variables.
+//!
+//! It is assumed that:
+//!
+//! * `obj` is not `null` or `undefined`. `Object.entries(null)` throws, while `for...in` over
+//!   `null` doesn't run.
+//! * Nothing else changes the properties of `obj` during the loop, e.g. a getter, or a function
+//!   called in the body which reaches `obj` through another variable.
+//! * `Object.prototype.hasOwnProperty` is not overwritten.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const [key, value] of Object.entries(obj)) {
+//!   console.log(key, value);
+//! }
+//! for (const value of Object.values(obj)) {
+//!   total += value;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! for (const key in obj) {
+//!   if (!Object.prototype.hasOwnProperty.call(obj, key)) continue;
+//!   const value = obj[key];
+//!   console.log(key, value);
+//! }
+//! for (const _key in obj) {
+//!   if (!Object.prototype.hasOwnProperty.call(obj, _key)) continue;
+//!   const value = obj[_key];
+//!   total += value;
+//! }
+//! ```
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::{IsGlobalReference, ScopeFlags, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::operator::UnaryOperator;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::{create_member_callee, create_property_access, create_prototype_member},
+};
+
+pub struct ObjectIterationToForIn;
+
+impl ObjectIterationToForIn {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ObjectIterationToForIn {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ForOfStatement(for_of) = stmt else { return };
+        if !Self::is_convertible(for_of, ctx) {
+            return;
+        }
+        let Statement::ForOfStatement(for_of) = stmt.take_in(ctx.ast) else { unreachable!() };
+        *stmt = Self::convert(for_of, ctx);
+    }
+}
+
+impl<'a> ObjectIterationToForIn {
+    /// `for (const [key, value] of Object.entries(obj))`, which meets the conditions listed
+    /// in the module docs.
+    fn is_convertible(for_of: &ForOfStatement<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let scoping = ctx.scoping();
+        if for_of.r#await {
+            return false;
+        }
+        let ForStatementLeft::VariableDeclaration(decl) = &for_of.left else { return false };
+        if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const)
+            || decl.declarations.len() != 1
+        {
+            return false;
+        }
+        let Some((method, object)) = Self::match_iteration(&for_of.right, ctx) else {
+            return false;
+        };
+        let Some(symbol_id) = scoping.get_reference(object.reference_id()).symbol_id() else {
+            return false;
+        };
+        if scoping.symbol_is_mutated(symbol_id) {
+            return false;
+        }
+
+        let (key, value) = match (method, &decl.declarations[0].id) {
+            ("keys", BindingPattern::BindingIdentifier(key)) => (Some(key), None),
+            ("values", BindingPattern::BindingIdentifier(value)) => (None, Some(value)),
+            ("entries", BindingPattern::ArrayPattern(pattern)) if pattern.rest.is_none() => {
+                match pattern.elements.as_slice() {
+                    [Some(BindingPattern::BindingIdentifier(key))] => (Some(key), None),
+                    [
+                        Some(BindingPattern::BindingIdentifier(key)),
+                        Some(BindingPattern::BindingIdentifier(value)),
+                    ] => (Some(key), Some(value)),
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+
+        // The generated code refers to `Object`, `obj` and the loop variables in the body.
+        let names = [Some("Object"), Some(object.name.as_str())];
+        let loop_names = [key.map(|key| key.name.as_str()), value.map(|value| value.name.as_str())];
+        if loop_names.iter().flatten().any(|name| names.contains(&Some(name))) {
+            return false;
+        }
+        if let Statement::BlockStatement(block) = &for_of.body {
+            let bindings = scoping.get_bindings(block.scope_id());
+            if names.iter().chain(&loop_names).flatten().any(|&name| bindings.contains_key(name)) {
+                return false;
+            }
+        }
+
+        let mut finder = ObjectUsageFinder { symbol_id, scoping, escapes: false };
+        finder.visit_statement(&for_of.body);
+        !finder.escapes
+    }
+
+    /// `Object.entries(obj)` -> `("entries", obj)`
+    fn match_iteration<'e>(
+        expr: &'e Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(&'e str, &'e IdentifierReference<'a>)> {
+        let Expression::CallExpression(call) = expr else { return None };
+        let Expression::StaticMemberExpression(callee) = &call.callee else { return None };
+        if call.optional
+            || callee.optional
+            || !callee.object.is_global_reference_name("Object", ctx.scoping())
+        {
+            return None;
+        }
+        let method = callee.property.name.as_str();
+        if !matches!(method, "entries" | "keys" | "values") {
+            return None;
+        }
+        match call.arguments.as_slice() {
+            [Argument::Identifier(object)] => Some((method, object)),
+            _ => None,
+        }
+    }
+
+    /// `for (const [key, value] of Object.entries(obj)) body` ->
+    /// `for (const key in obj) { if (!Object.prototype.hasOwnProperty.call(obj, key)) continue; const value = obj[key]; body }`
+    fn convert(
+        for_of: ArenaBox<'a, ForOfStatement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = for_of.scope_id();
+        let ForOfStatement { span, left, right, body, .. } = for_of.unbox();
+        let ForStatementLeft::VariableDeclaration(mut decl) = left else { unreachable!() };
+        let kind = decl.kind;
+        let declarator = decl.declarations.pop().unwrap();
+
+        let Expression::CallExpression(call) = right else { unreachable!() };
+        let CallExpression { callee, mut arguments, .. } = call.unbox();
+        let Expression::StaticMemberExpression(callee) = callee else { unreachable!() };
+        let StaticMemberExpression { object: object_ctor, property, .. } = callee.unbox();
+        let Some(Argument::Identifier(object)) = arguments.pop() else { unreachable!() };
+        let object_symbol_id = ctx.scoping().get_reference(object.reference_id()).symbol_id();
+        let object_binding = BoundIdentifier::new(object.name, object_symbol_id.unwrap());
+
+        let (key, value) = match declarator.id {
+            BindingPattern::BindingIdentifier(ident) if property.name == "values" => {
+                (None, Some(ident.unbox()))
+            }
+            BindingPattern::BindingIdentifier(ident) => (Some(ident.unbox()), None),
+            BindingPattern::ArrayPattern(pattern) => {
+                let mut elements = pattern.unbox().elements.into_iter().map(|element| {
+                    let Some(BindingPattern::BindingIdentifier(ident)) = element else {
+                        unreachable!()
+                    };
+                    ident.unbox()
+                });
+                (elements.next(), elements.next())
+            }
+            _ => unreachable!(),
+        };
+
+        // `const key` or `const _key`
+        let (key_binding, left_kind, key_pattern) = if let Some(key) = key {
+            let binding = BoundIdentifier::from_binding_ident(&key);
+            (binding, kind, BindingPattern::BindingIdentifier(ctx.ast.alloc(key)))
+        } else {
+            let flags = SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+            let binding = ctx.generate_uid("key", scope_id, flags);
+            let pattern = binding.create_binding_pattern(ctx);
+            (binding, VariableDeclarationKind::Const, pattern)
+        };
+        let declarator =
+            ctx.ast.variable_declarator(SPAN, left_kind, key_pattern, NONE, None, false);
+        let left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            decl.span,
+            left_kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ));
+
+        let mut body = body;
+        let block_scope_id = if let Statement::BlockStatement(block) = &body {
+            block.scope_id()
+        } else {
+            let block_scope_id = ctx.insert_scope_below_statement_from_scope_id(
+                &body,
+                scope_id,
+                ScopeFlags::empty(),
+            );
+            body = ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(body), block_scope_id);
+            block_scope_id
+        };
+
+        // `if (!Object.prototype.hasOwnProperty.call(obj, key)) continue;`
+        let has_own_property = create_property_access(
+            SPAN,
+            create_prototype_member(object_ctor, ctx),
+            "hasOwnProperty",
+            ctx,
+        );
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(object_binding.create_read_expression(ctx)),
+            Argument::from(key_binding.create_read_expression(ctx)),
+        ]);
+        let has_own_call = ctx.ast.expression_call(
+            SPAN,
+            create_member_callee(has_own_property, "call", ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let test = ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, has_own_call);
+        let mut stmts =
+            vec![ctx.ast.statement_if(SPAN, test, ctx.ast.statement_continue(SPAN, None), None)];
+
+        // `const value = obj[key];`
+        if let Some(value) = value {
+            ctx.scoping_mut().move_binding(scope_id, block_scope_id, &value.name);
+            ctx.scoping_mut().set_symbol_scope_id(value.symbol_id(), block_scope_id);
+            let init = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                object_binding.create_read_expression(ctx),
+                key_binding.create_read_expression(ctx),
+                false,
+            ));
+            let declarator = ctx.ast.variable_declarator(
+                value.span,
+                kind,
+                BindingPattern::BindingIdentifier(ctx.ast.alloc(value)),
+                NONE,
+                Some(init),
+                false,
+            );
+            stmts.push(Statement::from(ctx.ast.declaration_variable(
+                SPAN,
+                kind,
+                ctx.ast.vec1(declarator),
+                false,
+            )));
+        }
+
+        let Statement::BlockStatement(block) = &mut body else { unreachable!() };
+        block.body.splice(0..0, stmts);
+
+        ctx.ast.statement_for_in_with_scope_id(
+            span,
+            left,
+            Expression::Identifier(object),
+            body,
+            scope_id,
+        )
+    }
+}
+
+/// Finds uses of a variable in a loop body, other than reading its properties.
+struct ObjectUsageFinder<'s> {
+    symbol_id: SymbolId,
+    scoping: &'s Scoping,
+    escapes: bool,
+}
+
+impl ObjectUsageFinder<'_> {
+    /// `obj.x` or `obj[x]`
+    fn is_property_of_object(&self, expr: &Expression) -> bool {
+        let object = match expr.without_parentheses() {
+            Expression::StaticMemberExpression(member) => &member.object,
+            Expression::ComputedMemberExpression(member) => &member.object,
+            _ => return false,
+        };
+        matches!(object, Expression::Identifier(ident)
+            if self.scoping.get_reference(ident.reference_id()).symbol_id() == Some(self.symbol_id))
+    }
+}
+
+impl<'a> Visit<'a> for ObjectUsageFinder<'_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        match expr {
+            // Reading `obj.x`.
+            Expression::StaticMemberExpression(member)
+                if !member.optional && self.is_property_of_object(expr) => {}
+            Expression::ComputedMemberExpression(member)
+                if !member.optional && self.is_property_of_object(expr) =>
+            {
+                self.visit_expression(&member.expression);
+            }
+            _ => walk::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.scoping.get_reference(ident.reference_id()).symbol_id() == Some(self.symbol_id) {
+            self.escapes = true;
+        }
+    }
+
+    // `obj.method()` is called with `obj` as `this`.
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if self.is_property_of_object(&call.callee) {
+            self.escapes = true;
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &TaggedTemplateExpression<'a>) {
+        if self.is_property_of_object(&expr.tag) {
+            self.escapes = true;
+        }
+        walk::walk_tagged_template_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete && self.is_property_of_object(&expr.argument) {
+            self.escapes = true;
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+}
@@ -1,7 +1,48 @@
-use super::StyledComponentsOptions;
+use super::{
+    AtToIndexOptions, DestructureMemberChainsOptions, DirectEvalOptions, ExpandExportStarOptions,
+    ExplicitRadixOptions, FeatureGuardsOptions, ImportMetaGlobOptions,
+    InlineImportedConstantsOptions, JsonParseOptions, ModuleFactoryOptions,
+    NormalizeUndefinedOptions, StyledComponentsOptions, SwitchLookupOptions,
+    TernaryToEarlyReturnOptions, ThenToCatchOptions, UsagePolyfillsOptions,
+    WellKnownSymbolsOptions,
+};
 
 #[derive(Default, Debug, Clone)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
     pub tagged_template_transform: bool,
+    pub hoist_constant_expressions: bool,
+    pub well_known_symbols: Option<WellKnownSymbolsOptions>,
+    pub json_parse: Option<JsonParseOptions>,
+    pub lazy_top_level_await: bool,
+    pub expand_export_star: Option<ExpandExportStarOptions>,
+    pub reconstruct_ts_enums: bool,
+    pub import_meta_glob: Option<ImportMetaGlobOptions>,
+    pub usage_polyfills: Option<UsagePolyfillsOptions>,
+    pub hoist_requires: bool,
+    pub destructure_member_chains: Option<DestructureMemberChainsOptions>,
+    pub feature_guards: Option<FeatureGuardsOptions>,
+    pub property_mutators: bool,
+    pub at_to_index: Option<AtToIndexOptions>,
+    pub promise_finally: bool,
+    pub react_constant_elements: bool,
+    pub dynamic_requires: bool,
+    pub split_re_exports: bool,
+    pub direct_eval: Option<DirectEvalOptions>,
+    pub module_factory: Option<ModuleFactoryOptions>,
+    pub inline_imported_constants: Option<InlineImportedConstantsOptions>,
+    pub switch_lookup: Option<SwitchLookupOptions>,
+    pub hoist_loop_guards: bool,
+    pub normalize_template_line_endings: bool,
+    pub normalize_undefined: Option<NormalizeUndefinedOptions>,
+    pub explicit_radix: Option<ExplicitRadixOptions>,
+    pub ternary_to_early_return: Option<TernaryToEarlyReturnOptions>,
+    pub array_from_length: bool,
+    pub json_parse_to_literal: bool,
+    pub then_to_catch: Option<ThenToCatchOptions>,
+    pub coalesce_template_literals: bool,
+    pub namespace_to_named_imports: bool,
+    pub dedupe_export_names: bool,
+    pub object_iteration_to_for_in: bool,
+    pub react_component_display_name: bool,
 }
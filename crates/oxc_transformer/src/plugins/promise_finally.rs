@@ -0,0 +1,107 @@
+//! Promise Finally
+//!
+//! This plugin transforms `Promise.prototype.finally` calls (`p.finally(fn)`) to
+//! the `promiseFinally` helper, for engines which don't support `Promise.prototype.finally` (ES2018).
+//!
+//! > This plugin is not included in `preset-env`.
+//!
+//! The helper passes both callbacks of `then` to the promise, and calls `fn` once in whichever of them
+//! runs, like `Promise.prototype.finally`:
+//!
+//! * The promise returned by `fn` is awaited before the returned promise settles.
+//! * The returned promise settles with the value or reason of the original promise,
+//!   unless `fn` throws or returns a rejected promise.
+//! * If `fn` is not a function, it is passed to `then` as is.
+//!
+//! The receiver is evaluated before `fn`, like in the original call.
+//!
+//! The receiver is assumed to be a promise, as the type of the receiver is not known in general.
+//! Calls of other `finally` methods would be broken, which is why the plugin is opt-in.
+//! `super.finally(fn)` and calls with more than one argument are not transformed. Calls in optional
+//! chains (`p?.finally(fn)`) are only transformed after optional chaining is lowered.
+//!
+//! ## Example
+//!
+//! Input (targeting `chrome 60`):
+//! ```js
+//! fetchData().then(render).finally(() => hideSpinner());
+//! ```
+//!
+//! Output:
+//! ```js
+//! babelHelpers.promiseFinally(fetchData().then(render), () => hideSpinner());
+//! ```
+//!
+//! ## References
+//!
+//! * `Promise.prototype.finally`: <https://tc39.es/ecma262/#sec-promise.prototype.finally>
+//! * TC39 proposal: <https://github.com/tc39/proposal-promise-finally>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_compat::BuiltIn;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    options::EnvOptions,
+    plugins::built_ins::BuiltIns,
+    state::TransformState,
+};
+
+pub struct PromiseFinally<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `Promise.prototype.finally` is not available in the target environment.
+    enabled: bool,
+}
+
+impl<'a, 'ctx> PromiseFinally<'a, 'ctx> {
+    pub fn new(env: &EnvOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let enabled = BuiltIns::new(env).is_unavailable(BuiltIn::PromisePrototypeFinally);
+        Self { ctx, enabled }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for PromiseFinally<'a, '_> {
+    // Inner calls are transformed first, so `p.finally(a).finally(b)` is transformed
+    // to `promiseFinally(promiseFinally(p, a), b)`.
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.enabled {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional
+            || call.arguments.len() > 1
+            || call.arguments.iter().any(Argument::is_spread)
+        {
+            return;
+        }
+        let Expression::StaticMemberExpression(member) = &call.callee else { return };
+        if member.optional
+            || member.property.name != "finally"
+            || matches!(member.object, Expression::Super(_))
+        {
+            return;
+        }
+
+        *expr = self.transform_call_expression(call, ctx);
+    }
+}
+
+impl<'a> PromiseFinally<'a, '_> {
+    /// `p.finally(fn)` -> `babelHelpers.promiseFinally(p, fn)`
+    fn transform_call_expression(
+        &self,
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Expression::StaticMemberExpression(member) = call.callee.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let promise = member.unbox().object;
+        let mut arguments = call.arguments.take_in(ctx.ast);
+        arguments.insert(0, Argument::from(promise));
+        self.ctx.helper_call_expr(Helper::PromiseFinally, call.span, arguments, ctx)
+    }
+}
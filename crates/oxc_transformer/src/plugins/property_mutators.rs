@@ -0,0 +1,398 @@
+//! Property Mutators
+//!
+//! This plugin transforms getters and setters in object literals (`{ get x() {} }`)
+//! to `Object.defineProperties` and `Object.defineProperty` calls, for ES5 engines
+//! which support property descriptors, but not the accessor syntax.
+//!
+//! > This plugin is not included in `preset-env`.
+//!
+//! Properties before the first accessor or computed key stay in the object literal. From there on,
+//! properties are defined in their original order, so the order of keys and the evaluation order of
+//! values and computed keys are preserved:
+//!
+//! * Runs of accessors with static keys are defined with one `Object.defineProperties` call.
+//!   A getter and a setter of the same key in one run are merged into one descriptor.
+//! * Accessors and other properties with computed keys are defined with `Object.defineProperty`.
+//! * Runs of other properties and spreads are copied with the `objectSpread2` helper.
+//!
+//! Shorthand properties and methods of the transformed object are converted to `a: a`
+//! and `m: function() {}`, so no ES2015 object literal syntax is left.
+//! Objects with a method or an accessor which uses `super` are not transformed, as `super`
+//! is not valid in the function expressions they are defined with.
+//!
+//! Merging an accessor into an existing accessor of the same key keeps the existing getter or setter,
+//! as `Object.defineProperty` only updates the attributes present in the descriptor.
+//!
+//! ## Missing features
+//!
+//! * `__proto__: value` properties after the first accessor are moved to the object literal,
+//!   as they set the prototype of the object, so their value is evaluated earlier.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   a: 1,
+//!   get x() { return this._x; },
+//!   set x(v) { this._x = v; },
+//!   b: 2,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = babelHelpers.objectSpread2(Object.defineProperties({ a: 1 }, {
+//!   x: {
+//!     get: function() { return this._x; },
+//!     set: function(v) { this._x = v; },
+//!     configurable: true,
+//!     enumerable: true
+//!   }
+//! }), { b: 2 });
+//! ```
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-property-mutators>
+//! * `Object.defineProperties`: <https://tc39.es/ecma262/#sec-object.defineproperties>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{reference::ReferenceFlags, scope::ScopeFlags};
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct PropertyMutators<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> PropertyMutators<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+/// Getter and setter of a static key, to be defined by `Object.defineProperties`.
+struct Accessor<'a> {
+    name: String,
+    key: PropertyKey<'a>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for PropertyMutators<'a, '_> {
+    // Nested objects are transformed first.
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj_expr) = expr else { return };
+        if !obj_expr.properties.iter().any(is_accessor) || SuperFinder::uses_super(obj_expr) {
+            return;
+        }
+        let first = obj_expr
+            .properties
+            .iter()
+            .position(|prop| is_accessor(prop) || is_computed(prop))
+            .unwrap();
+        *expr = self.transform_object_expression(obj_expr, first, ctx);
+    }
+}
+
+impl<'a> PropertyMutators<'a, '_> {
+    fn transform_object_expression(
+        &self,
+        obj_expr: &mut ObjectExpression<'a>,
+        first: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut properties = obj_expr.properties.take_in(ctx.ast);
+        for prop in &mut properties {
+            if let ObjectPropertyKind::ObjectProperty(prop) = prop {
+                prop.shorthand = false;
+                prop.method = false;
+            }
+        }
+        let rest = properties.split_off(first);
+        let (protos, rest): (Vec<_>, Vec<_>) = rest.into_iter().partition(is_proto_setter);
+        properties.extend(protos);
+        let mut object = ctx.ast.expression_object(SPAN, properties);
+
+        let mut accessors = vec![];
+        let mut data = ctx.ast.vec();
+        let mut sources = vec![];
+        for prop in rest {
+            match prop {
+                ObjectPropertyKind::ObjectProperty(prop) if is_accessor_kind(prop.kind) => {
+                    Self::flush_sources(&mut object, &mut data, &mut sources, self.ctx, ctx);
+                    let mut prop = prop.unbox();
+                    if prop.computed {
+                        Self::flush_accessors(&mut object, &mut accessors, ctx);
+                        let key = prop.key.into_expression();
+                        let descriptor =
+                            Self::create_descriptor(prop.kind, prop.value.take_in(ctx.ast), ctx);
+                        let arguments = ctx.ast.vec_from_array([
+                            Argument::from(object),
+                            Argument::from(key),
+                            Argument::from(descriptor),
+                        ]);
+                        object = Self::create_object_method_call("defineProperty", arguments, ctx);
+                    } else {
+                        Self::push_accessor(&mut accessors, prop);
+                    }
+                }
+                ObjectPropertyKind::ObjectProperty(prop) if prop.computed => {
+                    Self::flush_accessors(&mut object, &mut accessors, ctx);
+                    Self::flush_sources(&mut object, &mut data, &mut sources, self.ctx, ctx);
+                    let prop = prop.unbox();
+                    let descriptor = Self::create_data_descriptor(prop.value, ctx);
+                    let arguments = ctx.ast.vec_from_array([
+                        Argument::from(object),
+                        Argument::from(prop.key.into_expression()),
+                        Argument::from(descriptor),
+                    ]);
+                    object = Self::create_object_method_call("defineProperty", arguments, ctx);
+                }
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    Self::flush_accessors(&mut object, &mut accessors, ctx);
+                    data.push(ObjectPropertyKind::ObjectProperty(prop));
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    Self::flush_accessors(&mut object, &mut accessors, ctx);
+                    if !data.is_empty() {
+                        let data = data.take_in(ctx.ast);
+                        sources.push(Argument::from(ctx.ast.expression_object(SPAN, data)));
+                    }
+                    sources.push(Argument::from(spread.unbox().argument));
+                }
+            }
+        }
+        Self::flush_accessors(&mut object, &mut accessors, ctx);
+        Self::flush_sources(&mut object, &mut data, &mut sources, self.ctx, ctx);
+
+        if let Expression::CallExpression(call) = &mut object {
+            call.span = obj_expr.span;
+        }
+        object
+    }
+
+    /// Add a getter or setter of a static key, merging it with the accessor of the same key.
+    fn push_accessor(accessors: &mut Vec<Accessor<'a>>, prop: ObjectProperty<'a>) {
+        let name = prop.key.static_name().unwrap_or_default().into_owned();
+        let index =
+            accessors.iter().position(|accessor| accessor.name == name).unwrap_or_else(|| {
+                accessors.push(Accessor { name, key: prop.key, get: None, set: None });
+                accessors.len() - 1
+            });
+        let accessor = &mut accessors[index];
+        if prop.kind == PropertyKind::Get {
+            accessor.get = Some(prop.value);
+        } else {
+            accessor.set = Some(prop.value);
+        }
+    }
+
+    /// `Object.defineProperties(object, { x: { get: function() {}, configurable: true, enumerable: true } })`
+    fn flush_accessors(
+        object: &mut Expression<'a>,
+        accessors: &mut Vec<Accessor<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if accessors.is_empty() {
+            return;
+        }
+        let descriptors = ctx.ast.vec_from_iter(accessors.drain(..).map(|accessor| {
+            let mut descriptor = ctx.ast.vec();
+            if let Some(get) = accessor.get {
+                descriptor.push(Self::create_property("get", get, ctx));
+            }
+            if let Some(set) = accessor.set {
+                descriptor.push(Self::create_property("set", set, ctx));
+            }
+            let descriptor = Self::complete_descriptor(descriptor, ctx);
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                accessor.key,
+                descriptor,
+                false,
+                false,
+                false,
+            )
+        }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(object.take_in(ctx.ast)),
+            Argument::from(ctx.ast.expression_object(SPAN, descriptors)),
+        ]);
+        *object = Self::create_object_method_call("defineProperties", arguments, ctx);
+    }
+
+    /// `babelHelpers.objectSpread2(object, { a: 1 }, spread)`
+    fn flush_sources(
+        object: &mut Expression<'a>,
+        data: &mut ArenaVec<'a, ObjectPropertyKind<'a>>,
+        sources: &mut Vec<Argument<'a>>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !data.is_empty() {
+            let data = data.take_in(ctx.ast);
+            sources.push(Argument::from(ctx.ast.expression_object(SPAN, data)));
+        }
+        if sources.is_empty() {
+            return;
+        }
+        let mut arguments = ctx.ast.vec_with_capacity(sources.len() + 1);
+        arguments.push(Argument::from(object.take_in(ctx.ast)));
+        arguments.extend(sources.drain(..));
+        *object = transform_ctx.helper_call_expr(Helper::ObjectSpread2, SPAN, arguments, ctx);
+    }
+
+    /// `{ get: function() {}, configurable: true, enumerable: true }`
+    fn create_descriptor(
+        kind: PropertyKind,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let name = if kind == PropertyKind::Get { "get" } else { "set" };
+        let descriptor = ctx.ast.vec1(Self::create_property(name, value, ctx));
+        Self::complete_descriptor(descriptor, ctx)
+    }
+
+    /// `{ value: value, writable: true, configurable: true, enumerable: true }`
+    fn create_data_descriptor(value: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let writable = ctx.ast.expression_boolean_literal(SPAN, true);
+        let descriptor = ctx.ast.vec_from_array([
+            Self::create_property("value", value, ctx),
+            Self::create_property("writable", writable, ctx),
+        ]);
+        Self::complete_descriptor(descriptor, ctx)
+    }
+
+    /// Adds `configurable: true, enumerable: true`, like properties defined by object literals.
+    fn complete_descriptor(
+        mut descriptor: ArenaVec<'a, ObjectPropertyKind<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        for name in ["configurable", "enumerable"] {
+            let value = ctx.ast.expression_boolean_literal(SPAN, true);
+            descriptor.push(Self::create_property(name, value, ctx));
+        }
+        ctx.ast.expression_object(SPAN, descriptor)
+    }
+
+    fn create_property(
+        name: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let key = ctx.ast.property_key_static_identifier(SPAN, name);
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// `Object.method(...arguments)`
+    fn create_object_method_call(
+        method: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object_symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Object");
+        let object_ctor = ctx.create_ident_expr(
+            SPAN,
+            Atom::from("Object"),
+            object_symbol_id,
+            ReferenceFlags::Read,
+        );
+        let callee = ctx.ast.member_expression_static(
+            SPAN,
+            object_ctor,
+            ctx.ast.identifier_name(SPAN, method),
+            false,
+        );
+        ctx.ast.expression_call(SPAN, Expression::from(callee), NONE, arguments, false)
+    }
+}
+
+fn is_accessor_kind(kind: PropertyKind) -> bool {
+    matches!(kind, PropertyKind::Get | PropertyKind::Set)
+}
+
+fn is_accessor(prop: &ObjectPropertyKind<'_>) -> bool {
+    matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if is_accessor_kind(prop.kind))
+}
+
+/// `[key]: value`, or a computed accessor or method
+fn is_computed(prop: &ObjectPropertyKind<'_>) -> bool {
+    matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed)
+}
+
+/// `__proto__: value`, which sets the prototype of the object.
+fn is_proto_setter(prop: &ObjectPropertyKind<'_>) -> bool {
+    matches!(
+        prop,
+        ObjectPropertyKind::ObjectProperty(prop)
+            if prop.kind == PropertyKind::Init
+                && !prop.computed
+                && !prop.shorthand
+                && !prop.method
+                && prop.key.is_specific_static_name("__proto__")
+    )
+}
+
+/// Visitor for finding `super` in the methods and accessors of an object literal,
+/// including arrow functions in them.
+#[derive(Default)]
+struct SuperFinder {
+    uses_super: bool,
+}
+
+impl SuperFinder {
+    fn uses_super(obj_expr: &ObjectExpression<'_>) -> bool {
+        let mut finder = Self::default();
+        for prop in &obj_expr.properties {
+            if let ObjectPropertyKind::ObjectProperty(prop) = prop
+                && let Expression::FunctionExpression(func) = &prop.value
+                && (prop.method || is_accessor_kind(prop.kind))
+            {
+                finder.visit_function_body_of(func);
+            }
+        }
+        finder.uses_super
+    }
+
+    fn visit_function_body_of(&mut self, func: &Function<'_>) {
+        self.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            self.visit_function_body(body);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _super: &Super) {
+        self.uses_super = true;
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {
+        // Functions, and methods of nested objects, have their own `super`
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_class_body(&mut self, _body: &ClassBody<'a>) {
+        // Methods of classes have their own `super`
+    }
+}
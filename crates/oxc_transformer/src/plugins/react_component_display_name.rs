@@ -0,0 +1,349 @@
+//! React Component Display Name
+//!
+//! This plugin adds `Component.displayName = "Component"` after the declarations of React
+//! components, so they keep their names in React DevTools and error messages after minification.
+//!
+//! > This plugin is not included in `preset-react`.
+//!
+//! Only the top level of the module is checked. A component is:
+//!
+//! * A function declaration, or a function or arrow function assigned to a variable, whose name
+//!   starts with an uppercase letter, and which contains JSX, e.g.
+//!   `function Button() { return <button />; }` or `const Button = () => <button />`.
+//! * A class extending `Component` or `PureComponent` (or `React.Component`), whose name starts
+//!   with an uppercase letter, e.g. `class Button extends React.Component {}`.
+//! * A call of `memo` or `forwardRef` (or `React.memo`) with an anonymous function, assigned to a
+//!   variable, e.g. `const Button = memo(() => <button />)`. The name is inferred from the
+//!   variable. The calls can be nested, e.g. `memo(forwardRef((props, ref) => ...))`. Other
+//!   higher-order components, and `memo(Button)`, are left alone, as they name the component
+//!   they return themselves, after the component they wrap.
+//!
+//! A component which already has a display name, because it is assigned to `displayName`
+//! anywhere in the module, or a class has a static `displayName` member, is left alone.
+//!
+//! An anonymous default export which is a component is named after the source file, like the
+//! `react-display-name` plugin does for `createReactClass`. It is assigned to a new binding,
+//! and the binding is exported instead, e.g. `export default function() {}` becomes
+//! `const _default = function() {}; export default _default;`. The function itself stays
+//! anonymous, but is no longer hoisted.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```jsx
+//! // Button.jsx
+//! export const Button = memo(forwardRef((props, ref) => <button ref={ref} {...props} />));
+//! export default () => <Button />;
+//! ```
+//!
+//! Output:
+//! ```jsx
+//! export const Button = memo(forwardRef((props, ref) => <button ref={ref} {...props} />));
+//! Button.displayName = "Button";
+//! const _default = () => <Button />;
+//! export default _default;
+//! _default.displayName = "Button";
+//! ```
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::{Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct ReactComponentDisplayName<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ReactComponentDisplayName<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ReactComponentDisplayName<'a, '_> {
+    /// Runs before the JSX transform, which removes the JSX that components are found by.
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut finder =
+            DisplayNameFinder { scoping: ctx.scoping(), symbols: FxHashSet::default() };
+        finder.visit_program(program);
+        let named = finder.symbols;
+
+        let body = program.body.take_in(ctx.ast);
+        let mut new_body = ctx.ast.vec_with_capacity(body.len());
+        for mut stmt in body {
+            let mut components = vec![];
+            match &mut stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        Self::collect_components(declaration, &named, &mut components);
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    components.extend(self.name_default_export(decl, &named, &mut new_body, ctx));
+                }
+                stmt @ match_declaration!(Statement) => {
+                    Self::collect_components(stmt.to_declaration(), &named, &mut components);
+                }
+                _ => {}
+            }
+            new_body.push(stmt);
+            new_body.extend(
+                components.into_iter().map(|(binding, name)| {
+                    Self::create_display_name_assignment(&binding, name, ctx)
+                }),
+            );
+        }
+        program.body = new_body;
+    }
+}
+
+impl<'a> ReactComponentDisplayName<'a, '_> {
+    /// The components declared by `decl`, which don't have a display name yet.
+    fn collect_components(
+        decl: &Declaration<'a>,
+        named: &FxHashSet<SymbolId>,
+        components: &mut Vec<(BoundIdentifier<'a>, Atom<'a>)>,
+    ) {
+        let mut add = |ident: &BindingIdentifier<'a>| {
+            if is_componentish_name(&ident.name) && !named.contains(&ident.symbol_id()) {
+                components.push((BoundIdentifier::from_binding_ident(ident), ident.name));
+            }
+        };
+        match decl {
+            Declaration::FunctionDeclaration(func) => {
+                if let Some(id) = &func.id
+                    && is_function_component(func)
+                {
+                    add(id);
+                }
+            }
+            Declaration::ClassDeclaration(class) => {
+                if let Some(id) = &class.id
+                    && is_class_component(class)
+                {
+                    add(id);
+                }
+            }
+            Declaration::VariableDeclaration(decl) if !decl.declare => {
+                for declarator in &decl.declarations {
+                    if let BindingPattern::BindingIdentifier(id) = &declarator.id
+                        && declarator.init.as_ref().is_some_and(|init| is_component(init))
+                    {
+                        add(id);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `export default () => <div />` ->
+    /// `const _default = () => <div />; export default _default;`, with `_default` as the
+    /// component, named after the file.
+    ///
+    /// `export default function() { return <div />; }` ->
+    /// `const _default = function() { return <div />; }; export default _default;`
+    fn name_default_export(
+        &self,
+        decl: &mut ExportDefaultDeclaration<'a>,
+        named: &FxHashSet<SymbolId>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<(BoundIdentifier<'a>, Atom<'a>)> {
+        let filename = ctx.ast.atom(&self.ctx.filename);
+        let init = match &mut decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                if !is_function_component(func) {
+                    return None;
+                }
+                if let Some(id) = &func.id {
+                    return Self::name_default_export_declaration(id, named);
+                }
+                let ExportDefaultDeclarationKind::FunctionDeclaration(mut func) =
+                    decl.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+                func.r#type = FunctionType::FunctionExpression;
+                Expression::FunctionExpression(func)
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                if !is_class_component(class) {
+                    return None;
+                }
+                if let Some(id) = &class.id {
+                    return Self::name_default_export_declaration(id, named);
+                }
+                let ExportDefaultDeclarationKind::ClassDeclaration(mut class) =
+                    decl.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+                class.r#type = ClassType::ClassExpression;
+                Expression::ClassExpression(class)
+            }
+            declaration @ match_expression!(ExportDefaultDeclarationKind) => {
+                if !is_component(declaration.to_expression()) {
+                    return None;
+                }
+                declaration.take_in(ctx.ast).into_expression()
+            }
+            _ => return None,
+        };
+
+        let flags = SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+        let binding = ctx.generate_uid_in_root_scope("default", flags);
+        decl.declaration = ExportDefaultDeclarationKind::Identifier(
+            ctx.ast.alloc(binding.create_read_reference(ctx)),
+        );
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Const,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            Some(init),
+            false,
+        );
+        stmts.push(Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Const,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+        Some((binding, filename))
+    }
+
+    /// `export default function Button() {}` is named after the function.
+    fn name_default_export_declaration(
+        id: &BindingIdentifier<'a>,
+        named: &FxHashSet<SymbolId>,
+    ) -> Option<(BoundIdentifier<'a>, Atom<'a>)> {
+        let binding = BoundIdentifier::from_binding_ident(id);
+        (is_componentish_name(&id.name) && !named.contains(&binding.symbol_id))
+            .then_some((binding, id.name))
+    }
+
+    /// `Component.displayName = "name";`
+    fn create_display_name_assignment(
+        binding: &BoundIdentifier<'a>,
+        name: Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let target = AssignmentTarget::from(ctx.ast.member_expression_static(
+            SPAN,
+            binding.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, "displayName"),
+            false,
+        ));
+        let value = ctx.ast.expression_string_literal(SPAN, name, None);
+        ctx.ast.statement_expression(
+            SPAN,
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value),
+        )
+    }
+}
+
+fn is_componentish_name(name: &str) -> bool {
+    name.as_bytes().first().is_some_and(u8::is_ascii_uppercase)
+}
+
+/// `() => <div />`, `class extends Component {}` or `memo(() => <div />)`
+fn is_component(expr: &Expression) -> bool {
+    match expr {
+        Expression::ArrowFunctionExpression(arrow) => contains_jsx(&arrow.body),
+        Expression::FunctionExpression(func) => is_function_component(func),
+        Expression::ClassExpression(class) => is_class_component(class),
+        Expression::CallExpression(call) => is_anonymous_memo_or_forward_ref(call),
+        _ => false,
+    }
+}
+
+/// `memo(() => {})`, `forwardRef(function() {})` or `memo(forwardRef(() => {}))`
+fn is_anonymous_memo_or_forward_ref(call: &CallExpression) -> bool {
+    let is_wrapper = match &call.callee {
+        Expression::Identifier(ident) => matches!(ident.name.as_str(), "memo" | "forwardRef"),
+        Expression::StaticMemberExpression(member) => {
+            matches!(member.property.name.as_str(), "memo" | "forwardRef")
+        }
+        _ => false,
+    };
+    if !is_wrapper {
+        return false;
+    }
+    match call.arguments.first() {
+        Some(Argument::ArrowFunctionExpression(_)) => true,
+        Some(Argument::FunctionExpression(func)) => func.id.is_none(),
+        Some(Argument::CallExpression(call)) => is_anonymous_memo_or_forward_ref(call),
+        _ => false,
+    }
+}
+
+fn is_function_component(func: &Function) -> bool {
+    func.body.as_ref().is_some_and(|body| contains_jsx(body))
+}
+
+/// `class extends Component {}` or `class extends React.PureComponent {}`, without a static
+/// `displayName` member.
+fn is_class_component(class: &Class) -> bool {
+    let is_component_name = |name: &str| matches!(name, "Component" | "PureComponent");
+    let extends_component = match &class.super_class {
+        Some(Expression::Identifier(ident)) => is_component_name(&ident.name),
+        Some(Expression::StaticMemberExpression(member)) => {
+            is_component_name(&member.property.name)
+        }
+        _ => false,
+    };
+    extends_component
+        && !class.body.body.iter().any(|element| {
+            element.r#static() && element.static_name().is_some_and(|name| name == "displayName")
+        })
+}
+
+fn contains_jsx(body: &FunctionBody) -> bool {
+    let mut finder = JsxFinder { found: false };
+    finder.visit_function_body(body);
+    finder.found
+}
+
+struct JsxFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for JsxFinder {
+    fn visit_jsx_element(&mut self, _element: &JSXElement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _fragment: &JSXFragment<'a>) {
+        self.found = true;
+    }
+}
+
+/// Finds the variables which are assigned a display name, e.g. `Button.displayName = "Button"`.
+struct DisplayNameFinder<'s> {
+    scoping: &'s Scoping,
+    symbols: FxHashSet<SymbolId>,
+}
+
+impl<'a> Visit<'a> for DisplayNameFinder<'_> {
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let Some(member) = expr.left.as_member_expression()
+            && member.static_property_name().is_some_and(|name| name == "displayName")
+            && let Expression::Identifier(ident) = member.object()
+            && let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id()
+        {
+            self.symbols.insert(symbol_id);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+}
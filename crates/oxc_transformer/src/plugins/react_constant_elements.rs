@@ -0,0 +1,221 @@
+//! React Constant Elements
+//!
+//! This plugin hoists JSX elements which are fully static out of functions up to module scope,
+//! so they are only created once, instead of on every render of a component.
+//! The element is created when it is first used, and stored in a module level variable.
+//!
+//! > This plugin is not included in `preset-react`.
+//!
+//! This is an opt-in performance optimization. All calls of the function now return the same
+//! element object, which React handles like an element which didn't change. Only elements which
+//! can't depend on the scope they are in are hoisted:
+//!
+//! * The element and all its descendants are intrinsic elements (`<div>`, `<svg:rect>`), fragments,
+//!   or imported components (`<Foo>`). Other component references may be re-assigned,
+//!   or refer to a binding of the function.
+//! * Attribute values and children are text, primitive literals (`{1}`, `{"a"}`, `` {`a`} ``)
+//!   or other static elements. Expressions referencing anything (e.g. event handlers) are not.
+//! * The element has no spread attributes or children, and no `ref` attribute, as refs are
+//!   attached to a single instance. A static `key` is hoisted with the element.
+//!
+//! Elements which are already at the top level are left untouched. When the JSX development
+//! transforms are enabled, nothing is hoisted, as they add `this` and the source location
+//! of each element.
+//!
+//! ## Missing features
+//!
+//! * Static elements in dynamic elements (`<div>{a}<p>static</p></div>`) are not hoisted.
+//! * `allowMutablePropsOnTags` option.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```jsx
+//! function Spinner() {
+//!   return <div className="spinner"><span>Loading...</span></div>;
+//! }
+//! ```
+//!
+//! Output (with the automatic runtime):
+//! ```js
+//! import { jsx as _jsx } from "react/jsx-runtime";
+//! var _ref;
+//! function Spinner() {
+//!   return _ref || (_ref = _jsx("div", {
+//!     className: "spinner",
+//!     children: _jsx("span", { children: "Loading..." })
+//!   }));
+//! }
+//! ```
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-react-constant-elements>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct ReactConstantElements<'a> {
+    /// Span of the static element which is hoisted when exiting it,
+    /// after it was transformed by the JSX transform.
+    hoisting: Option<Span>,
+    /// Variables of the hoisted elements, declared after the imports.
+    bindings: Vec<BoundIdentifier<'a>>,
+}
+
+impl ReactConstantElements<'_> {
+    pub fn new() -> Self {
+        Self { hoisting: None, bindings: vec![] }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ReactConstantElements<'a> {
+    // `var _ref, _ref2;`
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.bindings.is_empty() {
+            return;
+        }
+        let declarations = ctx.ast.vec_from_iter(self.bindings.drain(..).map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+            )
+        }));
+        let stmt = Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+        let index = program
+            .body
+            .iter()
+            .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+            .unwrap_or(program.body.len());
+        program.body.insert(index, stmt);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Elements in a hoisted element are hoisted with it.
+        if self.hoisting.is_some() {
+            return;
+        }
+        let is_static = match expr {
+            Expression::JSXElement(element) => is_static_element(element, ctx),
+            Expression::JSXFragment(fragment) => is_static_fragment(fragment, ctx),
+            _ => return,
+        };
+        // Only hoist out of functions, top level elements are created once anyway.
+        if is_static && ctx.current_hoist_scope_id() != ctx.scoping().root_scope_id() {
+            self.hoisting = Some(expr.span());
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.hoisting.is_none_or(|span| span != expr.span()) {
+            return;
+        }
+        self.hoisting = None;
+
+        let binding = ctx.generate_uid(
+            "ref",
+            ctx.scoping().root_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+        );
+
+        // `_ref || (_ref = _jsx("div", {}))`
+        let span = expr.span();
+        let element = expr.take_in(ctx.ast);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            element,
+        );
+        *expr = ctx.ast.expression_logical(
+            span,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            assignment,
+        );
+        self.bindings.push(binding);
+    }
+}
+
+fn is_static_element(element: &JSXElement<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    let opening = &element.opening_element;
+    let is_static_name = match &opening.name {
+        JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_) => true,
+        JSXElementName::IdentifierReference(ident) => is_import(ident, ctx),
+        JSXElementName::MemberExpression(_) | JSXElementName::ThisExpression(_) => false,
+    };
+    is_static_name
+        && opening.attributes.iter().all(|attribute| is_static_attribute(attribute, ctx))
+        && element.children.iter().all(|child| is_static_child(child, ctx))
+}
+
+fn is_static_fragment(fragment: &JSXFragment<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    fragment.children.iter().all(|child| is_static_child(child, ctx))
+}
+
+/// Imports can't be re-assigned.
+fn is_import(ident: &IdentifierReference<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    ctx.scoping()
+        .get_reference(ident.reference_id())
+        .symbol_id()
+        .is_some_and(|symbol_id| ctx.scoping().symbol_flags(symbol_id).is_import())
+}
+
+fn is_static_attribute(attribute: &JSXAttributeItem<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    let JSXAttributeItem::Attribute(attribute) = attribute else { return false };
+    if attribute.is_identifier("ref") {
+        return false;
+    }
+    match &attribute.value {
+        None | Some(JSXAttributeValue::StringLiteral(_)) => true,
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            container.expression.as_expression().is_some_and(|expr| is_static_expression(expr, ctx))
+        }
+        Some(JSXAttributeValue::Element(element)) => is_static_element(element, ctx),
+        Some(JSXAttributeValue::Fragment(fragment)) => is_static_fragment(fragment, ctx),
+    }
+}
+
+fn is_static_child(child: &JSXChild<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    match child {
+        JSXChild::Text(_) => true,
+        JSXChild::Element(element) => is_static_element(element, ctx),
+        JSXChild::Fragment(fragment) => is_static_fragment(fragment, ctx),
+        JSXChild::ExpressionContainer(container) => match &container.expression {
+            JSXExpression::EmptyExpression(_) => true,
+            expression => {
+                expression.as_expression().is_some_and(|expr| is_static_expression(expr, ctx))
+            }
+        },
+        JSXChild::Spread(_) => false,
+    }
+}
+
+/// Primitive literals and static elements.
+fn is_static_expression(expr: &Expression<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    match expr.without_parentheses() {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        Expression::JSXElement(element) => is_static_element(element, ctx),
+        Expression::JSXFragment(fragment) => is_static_fragment(fragment, ctx),
+        _ => false,
+    }
+}
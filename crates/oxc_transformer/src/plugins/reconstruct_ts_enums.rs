@@ -0,0 +1,427 @@
+//! Reconstruct TS Enums
+//!
+//! This experimental plugin recognizes the code which TypeScript (and Babel or oxc)
+//! emit for an `enum`, and turns it back into a TypeScript `enum` declaration.
+//! It is meant for migration tooling which ingests compiled JavaScript.
+//!
+//! Both the `tsc` and the Babel / oxc shapes are recognized:
+//!
+//! ```js
+//! var Color;
+//! (function (Color) {
+//!     Color[Color["Red"] = 0] = "Red";
+//!     Color["Blue"] = "blue";
+//! })(Color || (Color = {}));
+//!
+//! var Color = /* @__PURE__ */ function(Color) {
+//!   Color[Color["Red"] = 0] = "Red";
+//!   Color["Blue"] = "blue";
+//!   return Color;
+//! }(Color || {});
+//! ```
+//!
+//! Output:
+//! ```ts
+//! enum Color {
+//!   Red,
+//!   Blue = "blue",
+//! }
+//! ```
+//!
+//! Numeric members map their value back to their name, e.g. `Color[Color["Red"] = 0] = "Red"`,
+//! string members don't. Initializers which TypeScript would infer are omitted.
+//!
+//! The pattern has to match exactly. Anything unexpected in the function body, e.g. another
+//! statement, a numeric member without the reverse mapping, or the same member defined twice,
+//! leaves the code untouched. Enums which are merged with an earlier declaration of the same name
+//! are not recognized either, because the IIFE is not preceded by its `var` declaration.
+//!
+//! [`recognize_compiled_enum`] can be used on its own, without rewriting the code.
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{NodeId, ReferenceId, ScopeFlags, SymbolFlags};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+/// An `enum` recognized by [`recognize_compiled_enum`].
+#[derive(Debug)]
+pub struct CompiledEnum<'a, 'b> {
+    /// The binding declared by the `var` declaration.
+    pub id: &'b BindingIdentifier<'a>,
+    /// `export var Color;`
+    pub exported: bool,
+    /// The function which defines the members.
+    pub function: &'b Function<'a>,
+    /// The `Color || (Color = {})` argument of the IIFE.
+    pub argument: &'b Expression<'a>,
+    pub members: Vec<CompiledEnumMember<'a, 'b>>,
+    /// Number of statements the enum spans. `tsc` emits two, Babel and oxc one.
+    pub statement_count: usize,
+}
+
+/// A member of a [`CompiledEnum`].
+#[derive(Debug)]
+pub struct CompiledEnumMember<'a, 'b> {
+    pub span: Span,
+    pub name: Atom<'a>,
+    pub value: &'b Expression<'a>,
+    /// `Color[Color["Red"] = 0] = "Red"`, which also maps the value back to the name.
+    pub reverse_mapped: bool,
+}
+
+/// Recognize a compiled `enum` at the start of `statements`.
+///
+/// Returns `None` unless the statements exactly match one of the shapes which
+/// TypeScript, Babel or oxc emit for an `enum`.
+pub fn recognize_compiled_enum<'a, 'b>(
+    statements: &'b [Statement<'a>],
+) -> Option<CompiledEnum<'a, 'b>> {
+    let (declaration, exported) = match statements.first()? {
+        Statement::VariableDeclaration(declaration) => (declaration, false),
+        Statement::ExportNamedDeclaration(export) => match &export.declaration {
+            Some(Declaration::VariableDeclaration(declaration)) => (declaration, true),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if !matches!(declaration.kind, VariableDeclarationKind::Var | VariableDeclarationKind::Let)
+        || declaration.declare
+    {
+        return None;
+    }
+    let [declarator] = declaration.declarations.as_slice() else {
+        return None;
+    };
+    let BindingPattern::BindingIdentifier(id) = &declarator.id else {
+        return None;
+    };
+    let name = id.name.as_str();
+
+    let (call, statement_count) = if let Some(init) = &declarator.init {
+        // `var Color = function(Color) { ...; return Color; }(Color || {});`
+        let Expression::CallExpression(call) = init.without_parentheses() else {
+            return None;
+        };
+        (call, 1)
+    } else {
+        // `var Color; (function (Color) { ... })(Color || (Color = {}));`
+        let Some(Statement::ExpressionStatement(stmt)) = statements.get(1) else {
+            return None;
+        };
+        let Expression::CallExpression(call) = stmt.expression.without_parentheses() else {
+            return None;
+        };
+        (call, 2)
+    };
+
+    let [argument] = call.arguments.as_slice() else {
+        return None;
+    };
+    let argument = argument.as_expression()?;
+    if call.optional || !is_enum_argument(argument, name, statement_count == 1) {
+        return None;
+    }
+    let Expression::FunctionExpression(function) = call.callee.without_parentheses() else {
+        return None;
+    };
+    let members = recognize_members(function, name, statement_count == 1)?;
+    Some(CompiledEnum { id, exported, function, argument, members, statement_count })
+}
+
+/// `Color || (Color = {})`, `Color || {}` or, without merging, `{}`.
+fn is_enum_argument(argument: &Expression, name: &str, declared: bool) -> bool {
+    let is_empty_object = |expr: &Expression| matches!(expr, Expression::ObjectExpression(object) if object.properties.is_empty());
+    match argument.without_parentheses() {
+        Expression::LogicalExpression(logical) if logical.operator == LogicalOperator::Or => {
+            if !logical.left.is_specific_id(name) {
+                return false;
+            }
+            match logical.right.without_parentheses() {
+                Expression::AssignmentExpression(assign) => {
+                    !declared
+                        && assign.operator == AssignmentOperator::Assign
+                        && matches!(&assign.left, AssignmentTarget::AssignmentTargetIdentifier(ident) if ident.name == name)
+                        && is_empty_object(assign.right.without_parentheses())
+                }
+                right => declared && is_empty_object(right),
+            }
+        }
+        argument => declared && is_empty_object(argument),
+    }
+}
+
+fn recognize_members<'a, 'b>(
+    function: &'b Function<'a>,
+    name: &str,
+    returns: bool,
+) -> Option<Vec<CompiledEnumMember<'a, 'b>>> {
+    if function.r#async || function.generator || function.id.is_some() {
+        return None;
+    }
+    let params = &function.params;
+    let [param] = params.items.as_slice() else {
+        return None;
+    };
+    if params.rest.is_some()
+        || param.initializer.is_some()
+        || !matches!(&param.pattern, BindingPattern::BindingIdentifier(ident) if ident.name == name)
+    {
+        return None;
+    }
+    let body = function.body.as_ref()?;
+    if !body.directives.is_empty() {
+        return None;
+    }
+
+    let mut statements = body.statements.as_slice();
+    if returns {
+        // `return Color;`
+        let (Statement::ReturnStatement(ret), rest) = statements.split_last()? else {
+            return None;
+        };
+        if !ret.argument.as_ref().is_some_and(|argument| argument.is_specific_id(name)) {
+            return None;
+        }
+        statements = rest;
+    }
+
+    let mut names = FxHashSet::default();
+    let mut members = vec![];
+    for stmt in statements {
+        let Statement::ExpressionStatement(stmt) = stmt else {
+            return None;
+        };
+        let member = recognize_member(&stmt.expression, name)?;
+        if !names.insert(member.name) {
+            return None;
+        }
+        members.push(member);
+    }
+    Some(members)
+}
+
+/// `Color[Color["Red"] = 0] = "Red"` or `Color["Blue"] = "blue"`.
+fn recognize_member<'a, 'b>(
+    expr: &'b Expression<'a>,
+    name: &str,
+) -> Option<CompiledEnumMember<'a, 'b>> {
+    let (key, value) = enum_property_assignment(expr, name)?;
+    let Expression::StringLiteral(string) = value else {
+        return None;
+    };
+    match key {
+        // `Color["Blue"] = "blue"`
+        Expression::StringLiteral(key) => Some(CompiledEnumMember {
+            span: expr.span(),
+            name: key.value,
+            value,
+            reverse_mapped: false,
+        }),
+        // `Color[Color["Red"] = 0] = "Red"`
+        key => {
+            let (Expression::StringLiteral(key), value) = enum_property_assignment(key, name)?
+            else {
+                return None;
+            };
+            if key.value != string.value {
+                return None;
+            }
+            Some(CompiledEnumMember {
+                span: expr.span(),
+                name: key.value,
+                value,
+                reverse_mapped: true,
+            })
+        }
+    }
+}
+
+/// `Color[key] = value`
+fn enum_property_assignment<'a, 'b>(
+    expr: &'b Expression<'a>,
+    name: &str,
+) -> Option<(&'b Expression<'a>, &'b Expression<'a>)> {
+    let Expression::AssignmentExpression(assign) = expr.without_parentheses() else {
+        return None;
+    };
+    if assign.operator != AssignmentOperator::Assign {
+        return None;
+    }
+    let AssignmentTarget::ComputedMemberExpression(member) = &assign.left else {
+        return None;
+    };
+    if !member.object.is_specific_id(name) {
+        return None;
+    }
+    Some((member.expression.without_parentheses(), assign.right.without_parentheses()))
+}
+
+pub struct ReconstructTsEnums;
+
+impl ReconstructTsEnums {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ReconstructTsEnums {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut replacements = vec![];
+        let mut i = 0;
+        while i < stmts.len() {
+            if let Some(compiled) = recognize_compiled_enum(&stmts[i..]) {
+                let count = compiled.statement_count;
+                let span = Span::new(stmts[i].span().start, stmts[i + count - 1].span().end);
+                replacements.push((i, count, Self::reconstruct(&compiled, span, ctx)));
+                i += count;
+            } else {
+                i += 1;
+            }
+        }
+        if replacements.is_empty() {
+            return;
+        }
+
+        let mut old_stmts = stmts.take_in(ctx.ast).into_iter().enumerate();
+        let mut replacements = replacements.into_iter();
+        let mut next = replacements.next();
+        while let Some((i, stmt)) = old_stmts.next() {
+            if let Some((index, count, _)) = &next
+                && *index == i
+            {
+                // Skip the `var` declaration and the IIFE.
+                for _ in 1..*count {
+                    old_stmts.next();
+                }
+                stmts.push(next.take().unwrap().2);
+                next = replacements.next();
+            } else {
+                stmts.push(stmt);
+            }
+        }
+    }
+}
+
+impl<'a> ReconstructTsEnums {
+    fn reconstruct(
+        compiled: &CompiledEnum<'a, '_>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let symbol_id = compiled.id.symbol_id();
+        // The function scope becomes the scope of the enum body.
+        let scope_id = compiled.function.scope_id();
+        let BindingPattern::BindingIdentifier(param) = &compiled.function.params.items[0].pattern
+        else {
+            unreachable!()
+        };
+        let param_symbol_id = param.symbol_id();
+
+        // `Color || (Color = {})` is removed.
+        let mut collector = ReferenceCollector::default();
+        collector.visit_expression(compiled.argument);
+        for (reference_id, name) in collector.references {
+            ctx.delete_reference(reference_id, name);
+        }
+
+        // `Color.A` in an initializer now refers to the enum itself.
+        let mut collector = ReferenceCollector::default();
+        for member in &compiled.members {
+            collector.visit_expression(member.value);
+        }
+        for (reference_id, _) in collector.references {
+            let scoping = ctx.scoping_mut();
+            let reference = scoping.get_reference_mut(reference_id);
+            if reference.symbol_id() == Some(param_symbol_id) {
+                reference.set_symbol_id(symbol_id);
+                scoping.delete_resolved_reference(param_symbol_id, reference_id);
+                scoping.add_resolved_reference(symbol_id, reference_id);
+            }
+        }
+
+        let scoping = ctx.scoping_mut();
+        scoping.remove_binding(scope_id, &param.name);
+        *scoping.scope_flags_mut(scope_id) &= ScopeFlags::StrictMode;
+        *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::RegularEnum;
+
+        // Value TypeScript infers for the next member, if it has no initializer.
+        let mut next_value = Some(0.0);
+        let members = ctx.ast.vec_from_iter(compiled.members.iter().map(|member| {
+            let value = match member.value {
+                Expression::NumericLiteral(lit) if member.reverse_mapped => Some(lit.value),
+                _ => None,
+            };
+            let initializer = if value.is_some() && value == next_value {
+                None
+            } else {
+                Some(member.value.clone_in_with_semantic_ids(ctx.ast.allocator))
+            };
+            next_value = value.map(|value| value + 1.0);
+
+            let member_symbol_id = ctx.scoping_mut().create_symbol(
+                member.span,
+                &member.name,
+                SymbolFlags::EnumMember,
+                scope_id,
+                NodeId::DUMMY,
+            );
+            ctx.scoping_mut().add_binding(scope_id, &member.name, member_symbol_id);
+
+            let id = if is_identifier_name(&member.name) {
+                ctx.ast.ts_enum_member_name_identifier(member.span, member.name)
+            } else {
+                ctx.ast.ts_enum_member_name_string(member.span, member.name, None)
+            };
+            ctx.ast.ts_enum_member(member.span, id, initializer)
+        }));
+
+        let id = ctx.ast.binding_identifier_with_symbol_id(
+            compiled.id.span,
+            compiled.id.name,
+            symbol_id,
+        );
+        let declaration = ctx.ast.declaration_ts_enum_with_scope_id(
+            span,
+            id,
+            ctx.ast.ts_enum_body(compiled.function.span, members),
+            false,
+            false,
+            scope_id,
+        );
+        if compiled.exported {
+            Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+                span,
+                Some(declaration),
+                ctx.ast.vec(),
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            ))
+        } else {
+            Statement::from(declaration)
+        }
+    }
+}
+
+/// Collects the references of all identifiers.
+#[derive(Default)]
+struct ReferenceCollector<'a> {
+    references: Vec<(ReferenceId, &'a str)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.push((ident.reference_id(), ident.name.as_str()));
+    }
+}
@@ -0,0 +1,187 @@
+//! Rename Top Level Bindings
+//!
+//! [`rename_top_level_bindings`] prepares multiple modules to be concatenated into one scope,
+//! as done by a bundler. Top level bindings whose names collide with a top level binding of
+//! an earlier module, or with a global referenced by any of the modules, get a new name and
+//! all their references are rewritten.
+//!
+//! Names are picked deterministically: the first module keeps its names, and a colliding
+//! `foo` becomes `foo$1`, `foo$2` etc., skipping any name which is used anywhere in the module.
+//! Globals are never renamed.
+//!
+//! The names are changed in the [`Scoping`] of each module, the AST keeps the original names.
+//! Print the modules with `oxc_codegen::Codegen::with_scoping` to get the renamed code,
+//! which also expands shorthand properties, e.g. `({ foo })` to `({ foo: foo$1 })`.
+//!
+//! Renamed bindings which are exported by their declaration keep their exported name with an
+//! alias: `export const foo = 1;` becomes `const foo$1 = 1; export { foo$1 as foo };`.
+//!
+//! Modules which contain a direct `eval` or a `with` statement can access top level bindings
+//! by a name which isn't known until runtime, so nothing is renamed if any module contains them.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! // a.js
+//! export const name = "a";
+//! // b.js
+//! const name = "b";
+//! console.log(name);
+//! ```
+//!
+//! Output:
+//! ```js
+//! // a.js
+//! export const name = "a";
+//! // b.js
+//! const name$1 = "b";
+//! console.log(name$1);
+//! ```
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{NodeId, Reference, ReferenceFlags, ScopeFlags, Scoping, SymbolId};
+use oxc_span::SPAN;
+
+/// Maps the original name of each renamed top level binding to its new name.
+pub type RenameMap = FxHashMap<String, String>;
+
+/// Renames the top level bindings of `modules` which collide when the modules are concatenated.
+///
+/// Returns the renamed bindings of each module, in the order of `modules`.
+///
+/// # Errors
+///
+/// Returns an error, without renaming anything, if any module contains a direct `eval`
+/// or a `with` statement.
+pub fn rename_top_level_bindings<'a>(
+    allocator: &'a Allocator,
+    modules: &mut [(&mut Program<'a>, &mut Scoping)],
+) -> Result<Vec<RenameMap>, OxcDiagnostic> {
+    for (_, scoping) in modules.iter() {
+        for scope_id in scoping.scope_descendants_from_root() {
+            let flags = scoping.scope_flags(scope_id);
+            if flags.contains_direct_eval() {
+                return Err(OxcDiagnostic::error(
+                    "Cannot rename the top level bindings of a module which contains a direct `eval`",
+                ));
+            }
+            if flags.contains(ScopeFlags::With) {
+                return Err(OxcDiagnostic::error(
+                    "Cannot rename the top level bindings of a module which contains a `with` statement",
+                ));
+            }
+        }
+    }
+
+    // Globals referenced by any module would be shadowed by a top level binding of the same name.
+    let mut taken_names = FxHashSet::default();
+    for (_, scoping) in modules.iter() {
+        taken_names.extend(scoping.root_unresolved_references().keys().map(ToString::to_string));
+    }
+
+    let ast = AstBuilder::new(allocator);
+    let mut rename_maps = Vec::with_capacity(modules.len());
+    for (program, scoping) in modules.iter_mut() {
+        let root_scope_id = scoping.root_scope_id();
+        let mut symbol_ids = scoping
+            .iter_bindings_in(root_scope_id)
+            .filter(|&symbol_id| scoping.symbol_flags(symbol_id).is_value())
+            .collect::<Vec<_>>();
+        symbol_ids.sort_unstable();
+
+        let mut rename_map = RenameMap::default();
+        let mut renamed_symbols = FxHashSet::default();
+        for symbol_id in symbol_ids {
+            let name = scoping.symbol_name(symbol_id).to_string();
+            if taken_names.insert(name.clone()) {
+                continue;
+            }
+            let mut suffix = 1;
+            let new_name = loop {
+                let new_name = format!("{name}${suffix}");
+                if !taken_names.contains(&new_name)
+                    && !scoping
+                        .iter_bindings()
+                        .any(|(_, bindings)| bindings.contains_key(new_name.as_str()))
+                {
+                    break new_name;
+                }
+                suffix += 1;
+            };
+            scoping.rename_symbol(symbol_id, root_scope_id, &new_name);
+            taken_names.insert(new_name.clone());
+            rename_map.insert(name, new_name);
+            renamed_symbols.insert(symbol_id);
+        }
+
+        if !renamed_symbols.is_empty() {
+            alias_exported_declarations(program, scoping, &renamed_symbols, ast);
+        }
+        rename_maps.push(rename_map);
+    }
+    Ok(rename_maps)
+}
+
+/// `export const foo = 1;` -> `const foo = 1; export { foo as foo };`
+///
+/// The local name in the `export` is printed with the new name of `foo`.
+fn alias_exported_declarations<'a>(
+    program: &mut Program<'a>,
+    scoping: &mut Scoping,
+    renamed_symbols: &FxHashSet<SymbolId>,
+    ast: AstBuilder<'a>,
+) {
+    let mut index = 0;
+    while index < program.body.len() {
+        let Statement::ExportNamedDeclaration(export) = &mut program.body[index] else {
+            index += 1;
+            continue;
+        };
+        let Some(declaration) = &export.declaration else {
+            index += 1;
+            continue;
+        };
+        let mut names = vec![];
+        declaration.bound_names(&mut |ident| names.push((ident.name, ident.symbol_id())));
+        if !names.iter().any(|(_, symbol_id)| renamed_symbols.contains(symbol_id)) {
+            index += 1;
+            continue;
+        }
+
+        let declaration = export.declaration.take().unwrap();
+        let specifiers =
+            ast.vec_from_iter(names.into_iter().map(|(name, symbol_id)| {
+                let reference_id = scoping.create_reference(Reference::new_with_symbol_id(
+                    NodeId::DUMMY,
+                    symbol_id,
+                    ReferenceFlags::Read,
+                ));
+                scoping.add_resolved_reference(symbol_id, reference_id);
+                ast.export_specifier(
+                    SPAN,
+                    ModuleExportName::IdentifierReference(
+                        ast.identifier_reference_with_reference_id(SPAN, name, reference_id),
+                    ),
+                    ModuleExportName::IdentifierName(ast.identifier_name(SPAN, name)),
+                    ImportOrExportKind::Value,
+                )
+            }));
+        let export = Statement::ExportNamedDeclaration(ast.alloc_export_named_declaration(
+            SPAN,
+            None,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        ));
+        program.body[index] = Statement::from(declaration);
+        program.body.insert(index + 1, export);
+        index += 2;
+    }
+}
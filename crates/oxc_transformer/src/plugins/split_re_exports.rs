@@ -0,0 +1,107 @@
+//! Split Re-exports
+//!
+//! This plugin splits re-exports of several names (`export { a, b } from "./m"`)
+//! into one re-export per name, for bundlers and other tools which analyze the exports of
+//! a module statement by statement. Each name can then be dropped on its own if it's unused.
+//!
+//! * Renamed re-exports and re-exports of `default` (`export { default as x } from "./m"`)
+//!   keep their names.
+//! * Import attributes (`with { type: "json" }`) are copied to each re-export.
+//! * Type-only specifiers (`export { type A } from "./m"`) stay together in one re-export,
+//!   before the others. Type-only re-exports (`export type { A, B } from "./m"`) are left untouched.
+//!
+//! Local exports (`export { a, b }`) and `export *` are left untouched.
+//! Re-exporting names one by one doesn't change which modules are loaded, or their order.
+//!
+//! ## Missing features
+//!
+//! * Annotating the re-exports as free of side effects. There is no such annotation for
+//!   module declarations, `/* @__PURE__ */` only applies to calls.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! export { a, b as c, default as d } from "./m";
+//! ```
+//!
+//! Output:
+//! ```js
+//! export { a } from "./m";
+//! export { b as c } from "./m";
+//! export { default as d } from "./m";
+//! ```
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct SplitReExports;
+
+impl SplitReExports {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for SplitReExports {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let needs_split = program.body.iter().any(|stmt| {
+            matches!(stmt, Statement::ExportNamedDeclaration(decl) if Self::is_splittable(decl))
+        });
+        if !needs_split {
+            return;
+        }
+
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) if Self::is_splittable(&decl) => {
+                    Self::split(decl.unbox(), &mut body, ctx);
+                }
+                stmt => body.push(stmt),
+            }
+        }
+        program.body = body;
+    }
+}
+
+impl<'a> SplitReExports {
+    /// `export { a, b } from "./m"`, which re-exports more than one value.
+    fn is_splittable(decl: &ExportNamedDeclaration<'a>) -> bool {
+        decl.source.is_some()
+            && decl.export_kind.is_value()
+            && decl.specifiers.iter().filter(|specifier| specifier.export_kind.is_value()).count()
+                > 1
+    }
+
+    /// `export { type A, b, c } from "./m"`
+    /// -> `export { type A } from "./m"; export { b } from "./m"; export { c } from "./m";`
+    fn split(
+        decl: ExportNamedDeclaration<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let ExportNamedDeclaration { span, specifiers, source, with_clause, .. } = decl;
+        let source = source.unwrap();
+        let (types, values): (Vec<_>, Vec<_>) =
+            specifiers.into_iter().partition(|specifier| specifier.export_kind.is_type());
+        let types = (!types.is_empty()).then(|| ctx.ast.vec_from_iter(types));
+        let groups = types.into_iter().chain(values.into_iter().map(|value| ctx.ast.vec1(value)));
+        for (i, specifiers) in groups.enumerate() {
+            // Comments before the declaration stay before the first re-export.
+            let span = if i == 0 { span } else { SPAN };
+            body.push(Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+                span,
+                None,
+                specifiers,
+                Some(source.clone()),
+                ImportOrExportKind::Value,
+                with_clause.clone_in(ctx.ast.allocator),
+            )));
+        }
+    }
+}
@@ -0,0 +1,492 @@
+//! Switch Lookup
+//!
+//! This plugin converts a `switch` over string literals, whose cases only return or assign
+//! a constant, into a lookup in an object literal, which is hoisted to the top level.
+//! Engines compare the discriminant with each case in turn, while the lookup is a single
+//! property access, and the object is smaller than the cases.
+//!
+//! This is an opt-in performance optimization, only worth it for large `switch` statements.
+//! Only `switch` statements with at least [`SwitchLookupOptions::min_cases`] distinct cases are
+//! converted. The checks are strict, and anything else leaves the `switch` untouched:
+//!
+//! * Every case test is a string literal, or a template literal without expressions.
+//! * Every case either returns a primitive literal, `return "a";`, or assigns one to the same
+//!   variable and breaks, `x = "a"; break;`. Empty cases share the value of the next case,
+//!   e.g. `case "a": case "b": return 1;`. Any other fall-through leaves the `switch` untouched.
+//! * The `default` case is the last one, and only returns or throws, or assigns to the same
+//!   variable. Its value is not hoisted, so it's evaluated only when no case matches, and may
+//!   have side effects, but must not contain functions or classes.
+//! * A case with the key `"__proto__"` leaves the `switch` untouched.
+//!
+//! A `switch` compares with `===`, so only strings match. The lookup object has a `null`
+//! prototype, so inherited properties like `toString` don't match either.
+//! For duplicate keys, the first case wins, as in the `switch`.
+//! The discriminant is evaluated once, in a temporary variable if it's not an identifier.
+//!
+//! Only `switch` statements directly in a statement list are converted.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function color(name) {
+//!   switch (name) {
+//!     case "red": return "#f00";
+//!     case "green": return "#0f0";
+//!     case "blue": return "#00f";
+//!     case "white": return "#fff";
+//!     default: return fallback(name);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _lookup = { __proto__: null, red: "#f00", green: "#0f0", blue: "#00f", white: "#fff" };
+//! function color(name) {
+//!   return typeof name === "string" && name in _lookup ? _lookup[name] : fallback(name);
+//! }
+//! ```
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct SwitchLookupOptions {
+    /// Minimum number of distinct cases for a `switch` to be converted.
+    ///
+    /// Default: `4`
+    pub min_cases: usize,
+}
+
+impl Default for SwitchLookupOptions {
+    fn default() -> Self {
+        Self { min_cases: 4 }
+    }
+}
+
+pub struct SwitchLookup<'a, 'ctx> {
+    options: SwitchLookupOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> SwitchLookup<'a, 'ctx> {
+    pub fn new(options: SwitchLookupOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+/// How the cases produce their value.
+#[derive(Clone, Copy)]
+enum Form<'b, 'a> {
+    /// `return "a";`
+    Return,
+    /// `x = "a"; break;`
+    Assign(&'b IdentifierReference<'a>),
+}
+
+/// What the `default` case does when no case matches.
+#[derive(Clone, Copy)]
+enum DefaultKind {
+    /// `return fallback();` or `x = fallback(); break;`
+    Value,
+    /// `throw new Error();`
+    Throw,
+}
+
+/// A `switch` which can be converted.
+struct Lookup<'a> {
+    /// The variable assigned by the cases, or `None` if they return.
+    target: Option<MaybeBoundIdentifier<'a>>,
+    /// The key and value of each distinct case, in order.
+    entries: Vec<(Atom<'a>, Expression<'a>)>,
+    default: Option<DefaultKind>,
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for SwitchLookup<'a, '_> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !stmts.iter().any(|stmt| matches!(stmt, Statement::SwitchStatement(_))) {
+            return;
+        }
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts.take_in(ctx.ast) {
+            match stmt {
+                Statement::SwitchStatement(mut switch) => {
+                    if let Some(replacement) = self.transform_switch(&mut switch, ctx) {
+                        new_stmts.extend(replacement);
+                    } else {
+                        new_stmts.push(Statement::SwitchStatement(switch));
+                    }
+                }
+                stmt => new_stmts.push(stmt),
+            }
+        }
+        *stmts = new_stmts;
+    }
+}
+
+impl<'a> SwitchLookup<'a, '_> {
+    fn transform_switch(
+        &self,
+        switch: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArenaVec<'a, Statement<'a>>> {
+        let lookup = Self::analyze(switch, ctx)?;
+        if lookup.entries.len() < self.options.min_cases {
+            return None;
+        }
+        let Lookup { target, entries, default } = lookup;
+
+        let default = default.map(|kind| (kind, Self::take_default(switch, ctx)));
+        if target.is_some() {
+            // The assignments are replaced with a single one.
+            for case in &switch.cases {
+                if let Some(Statement::ExpressionStatement(stmt)) = case.consequent.first()
+                    && let Expression::AssignmentExpression(assign) = &stmt.expression
+                    && let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left
+                {
+                    ctx.delete_reference_for_identifier(ident);
+                }
+            }
+        }
+        ctx.scoping_mut().delete_scope(switch.scope_id());
+
+        let object = self.create_lookup_object(entries, ctx);
+
+        // `typeof key === "string" && key in _lookup`, with `key` evaluated once.
+        let discriminant = switch.discriminant.take_in(ctx.ast);
+        let (first, key) = match discriminant {
+            Expression::Identifier(ident)
+                if ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some() =>
+            {
+                let key = MaybeBoundIdentifier::from_identifier_reference(&ident, ctx);
+                (Expression::Identifier(ident), key)
+            }
+            discriminant => {
+                // `(_key = discriminant)`
+                let binding = self.ctx.var_declarations.create_uid_var("key", ctx);
+                let assign = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    discriminant,
+                );
+                (assign, binding.to_maybe_bound_identifier())
+            }
+        };
+        let is_string = ctx.ast.expression_binary(
+            SPAN,
+            ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, first),
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, Atom::from("string"), None),
+        );
+        let is_key = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::In,
+            object.create_read_expression(ctx),
+        );
+        let test = ctx.ast.expression_logical(SPAN, is_string, LogicalOperator::And, is_key);
+        // `_lookup[key]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            object.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+
+        let span = switch.span;
+        let mut stmts = ctx.ast.vec();
+        match (target, default) {
+            // `return test ? _lookup[key] : fallback();`
+            (None, Some((DefaultKind::Value, fallback))) => {
+                let value = ctx.ast.expression_conditional(SPAN, test, value, fallback);
+                stmts.push(ctx.ast.statement_return(span, Some(value)));
+            }
+            // `if (test) return _lookup[key];`
+            (None, default) => {
+                let consequent = ctx.ast.statement_return(SPAN, Some(value));
+                stmts.push(ctx.ast.statement_if(span, test, consequent, None));
+                if let Some((_, error)) = default {
+                    stmts.push(ctx.ast.statement_throw(SPAN, error));
+                }
+            }
+            // `x = test ? _lookup[key] : fallback();`
+            (Some(target), Some((DefaultKind::Value, fallback))) => {
+                let value = ctx.ast.expression_conditional(SPAN, test, value, fallback);
+                let assign = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target.create_write_target(ctx),
+                    value,
+                );
+                stmts.push(ctx.ast.statement_expression(span, assign));
+            }
+            // `if (test) x = _lookup[key];`
+            (Some(target), default) => {
+                let assign = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target.create_write_target(ctx),
+                    value,
+                );
+                let consequent = ctx.ast.statement_expression(SPAN, assign);
+                stmts.push(ctx.ast.statement_if(span, test, consequent, None));
+                if let Some((_, error)) = default {
+                    stmts.push(ctx.ast.statement_throw(SPAN, error));
+                }
+            }
+        }
+        Some(stmts)
+    }
+
+    /// The expression returned, assigned or thrown by the `default` case, which is the last one.
+    fn take_default(switch: &mut SwitchStatement<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let case = switch.cases.last_mut().unwrap();
+        match case.consequent.first_mut() {
+            Some(Statement::ReturnStatement(ret)) => ret.argument.take().unwrap(),
+            Some(Statement::ThrowStatement(throw)) => throw.argument.take_in(ctx.ast),
+            Some(Statement::ExpressionStatement(stmt)) => {
+                let Expression::AssignmentExpression(assign) = &mut stmt.expression else {
+                    unreachable!()
+                };
+                assign.right.take_in(ctx.ast)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// `var _lookup = { __proto__: null, a: 1 };`
+    fn create_lookup_object(
+        &self,
+        entries: Vec<(Atom<'a>, Expression<'a>)>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let proto = Self::create_property("__proto__", ctx.ast.expression_null_literal(SPAN), ctx);
+        let properties = ctx.ast.vec_from_iter(std::iter::once(proto).chain(
+            entries.into_iter().map(|(key, value)| Self::create_property(&key, value, ctx)),
+        ));
+        let object = ctx.generate_uid(
+            "lookup",
+            ctx.scoping().root_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            object.create_binding_pattern(ctx),
+            NONE,
+            Some(ctx.ast.expression_object(SPAN, properties)),
+            false,
+        );
+        self.ctx.top_level_statements.insert_statement(Statement::from(
+            ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ),
+        ));
+        object
+    }
+
+    /// Check that the `switch` can be converted, and collect its cases.
+    fn analyze(switch: &SwitchStatement<'a>, ctx: &TraverseCtx<'a>) -> Option<Lookup<'a>> {
+        let mut form = None;
+        let mut entries = vec![];
+        let mut seen = FxHashSet::default();
+        // Keys of empty cases, which share the value of the next case.
+        let mut pending = vec![];
+        let mut default = None;
+
+        for (i, case) in switch.cases.iter().enumerate() {
+            let is_last = i == switch.cases.len() - 1;
+            let Some(test) = &case.test else {
+                // `case "a": default:` needs the value of the `default` case.
+                if !is_last || !pending.is_empty() {
+                    return None;
+                }
+                default = Some(Self::analyze_default(&case.consequent, form?, ctx)?);
+                continue;
+            };
+            let key = match test {
+                Expression::StringLiteral(lit) => lit.value,
+                Expression::TemplateLiteral(lit) => lit.single_quasi()?,
+                _ => return None,
+            };
+            if key == "__proto__" {
+                return None;
+            }
+            if case.consequent.is_empty() {
+                // Falls out of the `switch`.
+                if is_last {
+                    return None;
+                }
+                pending.push(key);
+                continue;
+            }
+
+            let (case_form, value) = Self::analyze_case(&case.consequent, is_last)?;
+            if !is_primitive_literal(value) {
+                return None;
+            }
+            match form {
+                None => form = Some(case_form),
+                Some(form) if is_same_form(form, case_form, ctx) => {}
+                Some(_) => return None,
+            }
+            for key in std::mem::take(&mut pending).into_iter().chain(std::iter::once(key)) {
+                // The first case with the key wins.
+                if seen.insert(key) {
+                    entries.push((key, value.clone_in(ctx.ast.allocator)));
+                }
+            }
+        }
+
+        let target = match form? {
+            Form::Return => None,
+            Form::Assign(ident) => {
+                Some(MaybeBoundIdentifier::from_identifier_reference(ident, ctx))
+            }
+        };
+        Some(Lookup { target, entries, default })
+    }
+
+    /// `return "a";` or `x = "a"; break;`
+    fn analyze_case<'b>(
+        consequent: &'b [Statement<'a>],
+        is_last: bool,
+    ) -> Option<(Form<'b, 'a>, &'b Expression<'a>)> {
+        match consequent {
+            [Statement::ReturnStatement(ret)] => Some((Form::Return, ret.argument.as_ref()?)),
+            [Statement::ExpressionStatement(stmt)] if is_last => Self::analyze_assignment(stmt),
+            [Statement::ExpressionStatement(stmt), Statement::BreakStatement(brk)]
+                if brk.label.is_none() =>
+            {
+                Self::analyze_assignment(stmt)
+            }
+            _ => None,
+        }
+    }
+
+    /// `x = "a"`
+    fn analyze_assignment<'b>(
+        stmt: &'b ExpressionStatement<'a>,
+    ) -> Option<(Form<'b, 'a>, &'b Expression<'a>)> {
+        let Expression::AssignmentExpression(assign) = &stmt.expression else { return None };
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return None;
+        };
+        (assign.operator == AssignmentOperator::Assign)
+            .then_some((Form::Assign(ident), &assign.right))
+    }
+
+    fn analyze_default(
+        consequent: &[Statement<'a>],
+        form: Form<'_, 'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<DefaultKind> {
+        let (kind, expr) = match (form, consequent) {
+            (Form::Return, [Statement::ReturnStatement(ret)]) => {
+                (DefaultKind::Value, ret.argument.as_ref()?)
+            }
+            (_, [Statement::ThrowStatement(throw)]) => (DefaultKind::Throw, &throw.argument),
+            (Form::Assign(_), _) => {
+                let (default_form, value) = Self::analyze_case(consequent, true)?;
+                if !is_same_form(form, default_form, ctx) {
+                    return None;
+                }
+                (DefaultKind::Value, value)
+            }
+            _ => return None,
+        };
+        // The scopes of functions and classes would have to be moved out of the `switch`.
+        let mut finder = ScopeFinder { found: false };
+        finder.visit_expression(expr);
+        (!finder.found).then_some(kind)
+    }
+
+    /// `a: 1` or `"a-b": 1`
+    fn create_property(
+        key: &str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let key = if is_identifier_name(key) {
+            ctx.ast.property_key_static_identifier(SPAN, ctx.ast.atom(key))
+        } else {
+            PropertyKey::from(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(key), None))
+        };
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+}
+
+fn is_same_form<'a>(a: Form<'_, 'a>, b: Form<'_, 'a>, ctx: &TraverseCtx<'a>) -> bool {
+    match (a, b) {
+        (Form::Return, Form::Return) => true,
+        (Form::Assign(a), Form::Assign(b)) => {
+            a.name == b.name
+                && ctx.scoping().get_reference(a.reference_id()).symbol_id()
+                    == ctx.scoping().get_reference(b.reference_id()).symbol_id()
+        }
+        _ => false,
+    }
+}
+
+/// `"a"`, `` `a` ``, `1`, `-1`, `true`, `null` or `void 0`
+fn is_primitive_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        Expression::UnaryExpression(unary) => {
+            matches!(unary.operator, UnaryOperator::UnaryNegation | UnaryOperator::Void)
+                && matches!(unary.argument, Expression::NumericLiteral(_))
+        }
+        _ => false,
+    }
+}
+
+/// Finds functions and classes, which have their own scope.
+struct ScopeFinder {
+    found: bool,
+}
+
+impl Visit<'_> for ScopeFinder {
+    fn enter_scope(&mut self, _flags: ScopeFlags, _scope_id: &Cell<Option<ScopeId>>) {
+        self.found = true;
+    }
+}
@@ -65,7 +65,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TaggedTemplateTransform<'a, '_> {
 }
 
 impl<'a, 'ctx> TaggedTemplateTransform<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub(crate) fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
         Self { ctx }
     }
 
@@ -116,7 +116,7 @@ impl<'a, 'ctx> TaggedTemplateTransform<'a, 'ctx> {
         *expr = self.transform_tagged_template_impl(tagged.unbox(), ctx);
     }
 
-    fn transform_tagged_template_impl(
+    pub(crate) fn transform_tagged_template_impl(
         &self,
         expr: TaggedTemplateExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
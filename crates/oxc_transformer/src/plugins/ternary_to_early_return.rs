@@ -0,0 +1,179 @@
+//! Ternary to Early Return
+//!
+//! This plugin converts chains of nested ternaries assigned to a variable into an immediately-invoked
+//! arrow function with early returns, which is easier to read once the chain gets long.
+//!
+//! Only ternaries nested in the alternate are flattened, as in `a ? x : b ? y : z`, and only when
+//! the chain has at least [`TernaryToEarlyReturnOptions::min_depth`] ternaries.
+//! Ternaries nested in a consequent are returned as they are.
+//! The tests are evaluated in the same order, and only the selected branch is evaluated.
+//!
+//! The arrow function keeps `this`, `super` and `new.target` of the enclosing function.
+//! Chains containing `arguments`, `await` or `yield` are left untouched, as they can't be moved
+//! into an arrow function without changing their meaning.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const size = n < 10 ? "small" : n < 100 ? "medium" : n < 1000 ? "large" : "huge";
+//! ```
+//!
+//! Output:
+//! ```js
+//! const size = (() => {
+//!   if (n < 10) return "small";
+//!   if (n < 100) return "medium";
+//!   if (n < 1000) return "large";
+//!   return "huge";
+//! })();
+//! ```
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::TraverseCtx, state::TransformState,
+    utils::ast_builder::wrap_statements_in_arrow_function_iife,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct TernaryToEarlyReturnOptions {
+    /// Minimum number of chained ternaries to convert.
+    ///
+    /// Default: `3`
+    pub min_depth: u32,
+}
+
+impl Default for TernaryToEarlyReturnOptions {
+    fn default() -> Self {
+        Self { min_depth: 3 }
+    }
+}
+
+pub struct TernaryToEarlyReturn {
+    options: TernaryToEarlyReturnOptions,
+}
+
+impl TernaryToEarlyReturn {
+    pub fn new(options: TernaryToEarlyReturnOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TernaryToEarlyReturn {
+    fn enter_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(init) = &mut decl.init else { return };
+        if Self::chain_depth(init) < self.options.min_depth || !FunctionBoundaryChecker::check(init)
+        {
+            return;
+        }
+        *init = Self::convert(init.take_in(ctx.ast), ctx);
+    }
+}
+
+impl<'a> TernaryToEarlyReturn {
+    /// Number of ternaries in the chain through alternates.
+    fn chain_depth(expr: &Expression<'a>) -> u32 {
+        let mut depth = 0;
+        let mut expr = expr.without_parentheses();
+        while let Expression::ConditionalExpression(cond) = expr {
+            depth += 1;
+            expr = cond.alternate.without_parentheses();
+        }
+        depth
+    }
+
+    /// `a ? x : b ? y : z` -> `(() => { if (a) return x; if (b) return y; return z; })()`
+    fn convert(expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let span = expr.span();
+        let scope_id =
+            ctx.insert_scope_below_expression(&expr, ScopeFlags::Arrow | ScopeFlags::Function);
+
+        let mut stmts = ctx.ast.vec();
+        let mut expr = expr;
+        loop {
+            match expr {
+                Expression::ParenthesizedExpression(paren) => expr = paren.unbox().expression,
+                Expression::ConditionalExpression(cond) => {
+                    let cond = cond.unbox();
+                    let consequent = ctx.ast.statement_return(SPAN, Some(cond.consequent));
+                    stmts.push(ctx.ast.statement_if(cond.span, cond.test, consequent, None));
+                    expr = cond.alternate;
+                }
+                _ => {
+                    stmts.push(ctx.ast.statement_return(SPAN, Some(expr)));
+                    break;
+                }
+            }
+        }
+
+        wrap_statements_in_arrow_function_iife(stmts, scope_id, span, ctx)
+    }
+}
+
+/// Finds `arguments`, `await` and `yield` which belong to the enclosing function.
+struct FunctionBoundaryChecker {
+    found: bool,
+}
+
+impl FunctionBoundaryChecker {
+    /// Whether `expr` can be moved into an arrow function.
+    fn check(expr: &Expression<'_>) -> bool {
+        let mut checker = Self { found: false };
+        checker.visit_expression(expr);
+        !checker.found
+    }
+}
+
+impl<'a> Visit<'a> for FunctionBoundaryChecker {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_await_expression(&mut self, _it: &AwaitExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        // `await` in an arrow function belongs to the arrow function,
+        // but `arguments` belongs to the enclosing function.
+        let mut checker = ArgumentsFinder { found: false };
+        walk::walk_arrow_function_expression(&mut checker, it);
+        self.found |= checker.found;
+    }
+}
+
+/// Finds `arguments` which belongs to the enclosing function.
+struct ArgumentsFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ArgumentsFinder {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+}
@@ -0,0 +1,160 @@
+//! `.then()` to `.catch()`
+//!
+//! This plugin splits two-argument `.then(onFulfilled, onRejected)` calls into
+//! `.then(onFulfilled).catch(onRejected)`, which is easier to read.
+//!
+//! > This plugin is not included in `preset-env`.
+//!
+//! * `p.then(null, g)` and `p.then(undefined, g)` -> `p.catch(g)`. `Promise.prototype.catch`
+//!   calls `then` with `undefined` as the first argument, so this doesn't change behavior.
+//! * `p.then(f, g)` -> `p.then(f).catch(g)`, only if the `unsafeSplit` option is set.
+//!
+//! The second form **changes behavior**: in `p.then(f, g)`, `g` is only called if `p` rejects,
+//! but in `p.then(f).catch(g)`, `g` is also called if `f` throws or returns a rejected promise.
+//! An error which used to reject the returned promise is handled by `g` instead.
+//! So it is only applied when `f` is a simple reference (`f`, `this.f` or `a.b.f`), and `g` is
+//! a reference or a function, whose evaluation has no side effects.
+//! Whether `f` itself throws is not known, so this is only safe if it doesn't.
+//!
+//! The receiver is assumed to be a promise, as the type of the receiver is not known in general.
+//! A thenable without a `catch` method would be broken, which is why the plugin is opt-in.
+//! `super.then(f, g)`, calls with one argument or more than two arguments, and calls with spread
+//! arguments are not transformed. Calls in optional chains (`p?.then(f, g)`) are not transformed.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! fetchData().then(render, showError);
+//! fetchData().then(null, showError);
+//! ```
+//!
+//! Output, with `unsafeSplit: true`:
+//! ```js
+//! fetchData().then(render).catch(showError);
+//! fetchData().catch(showError);
+//! ```
+//!
+//! ## References
+//!
+//! * `Promise.prototype.then`: <https://tc39.es/ecma262/#sec-promise.prototype.then>
+//! * `Promise.prototype.catch`: <https://tc39.es/ecma262/#sec-promise.prototype.catch>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_syntax::operator::UnaryOperator;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ThenToCatchOptions {
+    /// Split `p.then(f, g)` into `p.then(f).catch(g)`, when `f` is a simple reference.
+    ///
+    /// This is unsafe, as `g` is then also called if `f` throws.
+    ///
+    /// Default: `false`, which only transforms `p.then(null, g)` to `p.catch(g)`.
+    pub unsafe_split: bool,
+}
+
+pub struct ThenToCatch {
+    options: ThenToCatchOptions,
+}
+
+impl ThenToCatch {
+    pub fn new(options: ThenToCatchOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ThenToCatch {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional || call.arguments.len() != 2 {
+            return;
+        }
+        let Expression::StaticMemberExpression(member) = &call.callee else { return };
+        if member.optional
+            || member.property.name != "then"
+            || matches!(member.object, Expression::Super(_))
+        {
+            return;
+        }
+        if call.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+        if Self::is_nullish(call.arguments[0].to_expression(), ctx) {
+            Self::transform_to_catch(call, ctx);
+        } else if self.options.unsafe_split
+            && Self::is_simple_reference(call.arguments[0].to_expression())
+            && Self::is_side_effect_free(call.arguments[1].to_expression())
+        {
+            Self::transform_to_then_catch(call, ctx);
+        }
+    }
+}
+
+impl<'a> ThenToCatch {
+    /// `p.then(null, g)` -> `p.catch(g)`
+    fn transform_to_catch(call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::StaticMemberExpression(member) = &mut call.callee else { unreachable!() };
+        member.property.name = ctx.ast.atom("catch");
+        let first = call.arguments.remove(0);
+        if let Argument::Identifier(ident) = &first {
+            ctx.delete_reference_for_identifier(ident);
+        }
+    }
+
+    /// `p.then(f, g)` -> `p.then(f).catch(g)`
+    fn transform_to_then_catch(call: &mut CallExpression<'a>, ctx: &TraverseCtx<'a>) {
+        let span = call.span;
+        let on_rejected = call.arguments.pop().unwrap();
+        let then_call = Expression::CallExpression(ctx.ast.alloc(call.take_in(ctx.ast)));
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            then_call,
+            ctx.ast.identifier_name(SPAN, "catch"),
+            false,
+        ));
+        *call = ctx.ast.call_expression(span, callee, NONE, ctx.ast.vec1(on_rejected), false);
+    }
+
+    /// `null`, `undefined` or `void 0`.
+    fn is_nullish(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::NullLiteral(_) => true,
+            Expression::Identifier(ident) => {
+                ident.name == "undefined"
+                    && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+            }
+            Expression::UnaryExpression(unary) => {
+                unary.operator == UnaryOperator::Void
+                    && matches!(unary.argument, Expression::NumericLiteral(_))
+            }
+            _ => false,
+        }
+    }
+
+    /// `f`, `this.f` or `a.b.f`.
+    fn is_simple_reference(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(_) => true,
+            Expression::StaticMemberExpression(member) => {
+                !member.optional
+                    && (matches!(member.object, Expression::ThisExpression(_))
+                        || Self::is_simple_reference(&member.object))
+            }
+            _ => false,
+        }
+    }
+
+    /// A reference, or a function expression, which is safe to evaluate after the `then` call.
+    fn is_side_effect_free(expr: &Expression<'a>) -> bool {
+        matches!(expr, Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_))
+            || Self::is_simple_reference(expr)
+    }
+}
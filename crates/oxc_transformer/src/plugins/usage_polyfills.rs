@@ -0,0 +1,290 @@
+//! Usage Polyfills
+//!
+//! This plugin adds a side-effect import of a polyfill for each built-in which is used,
+//! but isn't available in the configured target environment,
+//! like `@babel/preset-env` with `useBuiltIns: "usage"`.
+//!
+//! Three kinds of usage are detected:
+//!
+//! * Global built-ins, e.g. `Promise` or `globalThis`. Shadowed globals are ignored.
+//!   Members of the global object (`globalThis.Promise`, `window.structuredClone`) are globals too,
+//!   but a property of any other object with the same name (`obj.structuredClone`) is not.
+//! * Static methods, e.g. `Promise.allSettled` or `Object.fromEntries`.
+//! * Instance methods, e.g. `arr.flat()`. The type of the receiver is not known in general,
+//!   so a polyfill is imported for every built-in with a method of that name,
+//!   e.g. both `Array.prototype.includes` and `String.prototype.includes` for `x.includes`.
+//!   Array and string literal receivers only import the polyfill of their own type.
+//!
+//! Each polyfill is imported at most once per file.
+//!
+//! Availability is looked up in the compat data per built-in, see [`BuiltIns`].
+//! Built-ins added after ES2022 are not polyfilled.
+//!
+//! Web platform globals (`structuredClone`, `queueMicrotask`, `AbortController`) are not part of
+//! ECMAScript, so they are polyfilled for engine targets only, not for `esXXXX` targets.
+//! `AbortController` and `AbortSignal` are not provided by `core-js`, so they import
+//! `abortcontroller-polyfill` regardless of the `source` option.
+//!
+//! ## Example
+//!
+//! Input (targeting `chrome 60`):
+//! ```js
+//! const results = await Promise.allSettled(tasks.flat());
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.promise.all-settled.js";
+//! import "core-js/modules/es.array.flat.js";
+//! const results = await Promise.allSettled(tasks.flat());
+//! ```
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_compat::BuiltIn;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    options::EnvOptions,
+    plugins::built_ins::BuiltIns,
+    state::TransformState,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct UsagePolyfillsOptions {
+    /// Module to import for each built-in which is not available in the target environment.
+    ///
+    /// `{name}` is replaced with the `core-js` module name of the built-in,
+    /// e.g. `es.array.flat` for `Array.prototype.flat`.
+    ///
+    /// Default: `"core-js/modules/{name}.js"`
+    pub source: String,
+}
+
+impl Default for UsagePolyfillsOptions {
+    fn default() -> Self {
+        Self { source: String::from("core-js/modules/{name}.js") }
+    }
+}
+
+/// Replaced with the module name in [`UsagePolyfillsOptions::source`].
+const NAME_PLACEHOLDER: &str = "{name}";
+
+/// `(global, module, built-in)`
+const GLOBALS: &[(&str, &str, BuiltIn)] = &[
+    ("Map", "es.map", BuiltIn::Map),
+    ("Promise", "es.promise", BuiltIn::Promise),
+    ("Set", "es.set", BuiltIn::Set),
+    ("WeakMap", "es.weak-map", BuiltIn::WeakMap),
+    ("WeakSet", "es.weak-set", BuiltIn::WeakSet),
+    ("globalThis", "es.global-this", BuiltIn::GlobalThis),
+    ("AggregateError", "es.aggregate-error", BuiltIn::AggregateError),
+    ("FinalizationRegistry", "es.finalization-registry", BuiltIn::FinalizationRegistry),
+    ("WeakRef", "es.weak-ref", BuiltIn::WeakRef),
+];
+
+/// `(global, module, built-in)`
+const WEB_GLOBALS: &[(&str, &str, BuiltIn)] = &[
+    ("queueMicrotask", "web.queue-microtask", BuiltIn::QueueMicrotask),
+    ("structuredClone", "web.structured-clone", BuiltIn::StructuredClone),
+];
+
+/// Globals which are polyfilled by a package instead of `core-js`, `(global, source, built-in)`.
+const PACKAGE_GLOBALS: &[(&str, &str, BuiltIn)] = &[
+    (
+        "AbortController",
+        "abortcontroller-polyfill/dist/abortcontroller-polyfill-only",
+        BuiltIn::AbortController,
+    ),
+    (
+        "AbortSignal",
+        "abortcontroller-polyfill/dist/abortcontroller-polyfill-only",
+        BuiltIn::AbortController,
+    ),
+];
+
+/// Names of the global object.
+const GLOBAL_OBJECTS: &[&str] = &["globalThis", "window", "self"];
+
+/// `(global, method, module, built-in)`
+const STATIC_METHODS: &[(&str, &str, &str, BuiltIn)] = &[
+    ("Array", "from", "es.array.from", BuiltIn::ArrayFrom),
+    ("Array", "of", "es.array.of", BuiltIn::ArrayOf),
+    ("Math", "sign", "es.math.sign", BuiltIn::MathSign),
+    ("Math", "trunc", "es.math.trunc", BuiltIn::MathTrunc),
+    ("Number", "isFinite", "es.number.is-finite", BuiltIn::NumberIsFinite),
+    ("Number", "isInteger", "es.number.is-integer", BuiltIn::NumberIsInteger),
+    ("Number", "isNaN", "es.number.is-nan", BuiltIn::NumberIsNaN),
+    ("Number", "isSafeInteger", "es.number.is-safe-integer", BuiltIn::NumberIsSafeInteger),
+    ("Object", "assign", "es.object.assign", BuiltIn::ObjectAssign),
+    ("Object", "is", "es.object.is", BuiltIn::ObjectIs),
+    ("Object", "setPrototypeOf", "es.object.set-prototype-of", BuiltIn::ObjectSetPrototypeOf),
+    ("String", "fromCodePoint", "es.string.from-code-point", BuiltIn::StringFromCodePoint),
+    ("String", "raw", "es.string.raw", BuiltIn::StringRaw),
+    ("Object", "entries", "es.object.entries", BuiltIn::ObjectEntries),
+    (
+        "Object",
+        "getOwnPropertyDescriptors",
+        "es.object.get-own-property-descriptors",
+        BuiltIn::ObjectGetOwnPropertyDescriptors,
+    ),
+    ("Object", "values", "es.object.values", BuiltIn::ObjectValues),
+    ("Object", "fromEntries", "es.object.from-entries", BuiltIn::ObjectFromEntries),
+    ("Promise", "allSettled", "es.promise.all-settled", BuiltIn::PromiseAllSettled),
+    ("Promise", "any", "es.promise.any", BuiltIn::PromiseAny),
+    ("Object", "hasOwn", "es.object.has-own", BuiltIn::ObjectHasOwn),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Receiver {
+    Array,
+    String,
+    Promise,
+}
+
+/// `(receiver, method, module, built-in)`
+const INSTANCE_METHODS: &[(Receiver, &str, &str, BuiltIn)] = &[
+    (Receiver::Array, "copyWithin", "es.array.copy-within", BuiltIn::ArrayPrototypeCopyWithin),
+    (Receiver::Array, "fill", "es.array.fill", BuiltIn::ArrayPrototypeFill),
+    (Receiver::Array, "find", "es.array.find", BuiltIn::ArrayPrototypeFind),
+    (Receiver::Array, "findIndex", "es.array.find-index", BuiltIn::ArrayPrototypeFindIndex),
+    (
+        Receiver::String,
+        "codePointAt",
+        "es.string.code-point-at",
+        BuiltIn::StringPrototypeCodePointAt,
+    ),
+    (Receiver::String, "endsWith", "es.string.ends-with", BuiltIn::StringPrototypeEndsWith),
+    (Receiver::String, "includes", "es.string.includes", BuiltIn::StringPrototypeIncludes),
+    (Receiver::String, "repeat", "es.string.repeat", BuiltIn::StringPrototypeRepeat),
+    (Receiver::String, "startsWith", "es.string.starts-with", BuiltIn::StringPrototypeStartsWith),
+    (Receiver::Array, "includes", "es.array.includes", BuiltIn::ArrayPrototypeIncludes),
+    (Receiver::String, "padEnd", "es.string.pad-end", BuiltIn::StringPrototypePadEnd),
+    (Receiver::String, "padStart", "es.string.pad-start", BuiltIn::StringPrototypePadStart),
+    (Receiver::Promise, "finally", "es.promise.finally", BuiltIn::PromisePrototypeFinally),
+    (Receiver::Array, "flat", "es.array.flat", BuiltIn::ArrayPrototypeFlat),
+    (Receiver::Array, "flatMap", "es.array.flat-map", BuiltIn::ArrayPrototypeFlatMap),
+    (Receiver::String, "trimEnd", "es.string.trim-end", BuiltIn::StringPrototypeTrimEnd),
+    (Receiver::String, "trimStart", "es.string.trim-start", BuiltIn::StringPrototypeTrimStart),
+    (Receiver::String, "matchAll", "es.string.match-all", BuiltIn::StringPrototypeMatchAll),
+    (Receiver::String, "replaceAll", "es.string.replace-all", BuiltIn::StringPrototypeReplaceAll),
+    (Receiver::Array, "at", "es.array.at", BuiltIn::ArrayPrototypeAt),
+    (Receiver::String, "at", "es.string.at-alternative", BuiltIn::StringPrototypeAt),
+];
+
+pub struct UsagePolyfills<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: UsagePolyfillsOptions,
+    built_ins: BuiltIns,
+    /// Modules which have already been imported.
+    imported: FxHashSet<&'static str>,
+}
+
+impl<'a, 'ctx> UsagePolyfills<'a, 'ctx> {
+    pub fn new(
+        options: UsagePolyfillsOptions,
+        env: &EnvOptions,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self { ctx, options, built_ins: BuiltIns::new(env), imported: FxHashSet::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for UsagePolyfills<'a, '_> {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = ctx.scoping().get_reference(reference_id);
+        if reference.symbol_id().is_some() || !reference.is_value() {
+            return;
+        }
+        self.import_global(&ident.name, ctx);
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let method = expr.property.name.as_str();
+
+        // `Promise.allSettled`
+        if let Expression::Identifier(object) = &expr.object
+            && STATIC_METHODS.iter().any(|(global, _, _, _)| object.name == global)
+        {
+            if object.is_global_reference(ctx.scoping()) {
+                for &(global, name, module, built_in) in STATIC_METHODS {
+                    if object.name == global && method == name {
+                        self.import(module, self.built_ins.is_unavailable(built_in), ctx);
+                    }
+                }
+            }
+            return;
+        }
+
+        // `globalThis.structuredClone`
+        if let Expression::Identifier(object) = &expr.object
+            && GLOBAL_OBJECTS.contains(&object.name.as_str())
+            && object.is_global_reference(ctx.scoping())
+        {
+            self.import_global(method, ctx);
+        }
+
+        // `arr.flat`
+        let receiver = match expr.object.without_parentheses() {
+            Expression::ArrayExpression(_) => Some(Receiver::Array),
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => Some(Receiver::String),
+            _ => None,
+        };
+        for &(kind, name, module, built_in) in INSTANCE_METHODS {
+            if method == name && receiver.is_none_or(|receiver| receiver == kind) {
+                self.import(module, self.built_ins.is_unavailable(built_in), ctx);
+            }
+        }
+    }
+}
+
+impl<'a> UsagePolyfills<'a, '_> {
+    fn import_global(&mut self, name: &str, ctx: &TraverseCtx<'a>) {
+        for &(global, module, built_in) in GLOBALS {
+            if name == global {
+                self.import(module, self.built_ins.is_unavailable(built_in), ctx);
+            }
+        }
+        for &(global, module, built_in) in WEB_GLOBALS {
+            if name == global {
+                self.import(module, self.built_ins.is_unavailable(built_in), ctx);
+            }
+        }
+        for &(global, source, built_in) in PACKAGE_GLOBALS {
+            if name == global && self.should_import(source, self.built_ins.is_unavailable(built_in))
+            {
+                self.ctx.module_imports.add_side_effect_import(ctx.ast.atom(source));
+            }
+        }
+    }
+
+    fn import(&mut self, module: &'static str, unavailable: bool, ctx: &TraverseCtx<'a>) {
+        if !self.should_import(module, unavailable) {
+            return;
+        }
+        let source = match self.options.source.split_once(NAME_PLACEHOLDER) {
+            Some((before, after)) => format!("{before}{module}{after}"),
+            None => self.options.source.clone(),
+        };
+        self.ctx.module_imports.add_side_effect_import(ctx.ast.atom(&source));
+    }
+
+    /// `module` is not available in the target environment, and has not been imported yet.
+    fn should_import(&mut self, module: &'static str, unavailable: bool) -> bool {
+        unavailable && self.imported.insert(module)
+    }
+}
@@ -0,0 +1,167 @@
+//! Well-Known Symbols
+//!
+//! This plugin detects references to well-known symbols (`Symbol.iterator`, `Symbol.asyncIterator`, ...)
+//! and to the global symbol registry (`Symbol.for`, `Symbol.keyFor`) which are not available
+//! in the configured target environment.
+//!
+//! This is a conservative, opt-in plugin. Code is never rewritten:
+//!
+//! * Without a `shim`, a warning is reported for each unavailable symbol.
+//! * With a `shim`, a side-effect import of the shim is added once for each unavailable symbol.
+//!   `{name}` in the shim is replaced with the kebab-cased symbol name.
+//!
+//! Only explicit `Symbol.*` member expressions are detected. Symbols which are used implicitly,
+//! e.g. `Symbol.iterator` by `for...of` or array spread, must be polyfilled separately.
+//!
+//! ## Example
+//!
+//! Options:
+//! ```json
+//! { "shim": "core-js/modules/es.symbol.{name}.js" }
+//! ```
+//!
+//! Input (targeting `chrome 60`):
+//! ```js
+//! class Stream {
+//!   async *[Symbol.asyncIterator]() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.symbol.async-iterator.js";
+//! class Stream {
+//!   async *[Symbol.asyncIterator]() {}
+//! }
+//! ```
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_compat::BuiltIn;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    options::EnvOptions,
+    plugins::built_ins::BuiltIns,
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct WellKnownSymbolsOptions {
+    /// Module to import when a symbol is not available in the target environment.
+    ///
+    /// `{name}` is replaced with the kebab-cased name of the symbol,
+    /// e.g. `async-iterator` for `Symbol.asyncIterator` and `for` for `Symbol.for`.
+    ///
+    /// When `None`, a warning is reported instead.
+    ///
+    /// Default: `None`
+    pub shim: Option<String>,
+}
+
+/// Replaced with the kebab-cased symbol name in [`WellKnownSymbolsOptions::shim`].
+const NAME_PLACEHOLDER: &str = "{name}";
+
+/// Well-known symbols and `Symbol` methods introduced in ES2015.
+const ES2015_SYMBOLS: &[&str] = &[
+    "for",
+    "hasInstance",
+    "isConcatSpreadable",
+    "iterator",
+    "keyFor",
+    "match",
+    "replace",
+    "search",
+    "species",
+    "split",
+    "toPrimitive",
+    "toStringTag",
+    "unscopables",
+];
+
+pub struct WellKnownSymbols<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: WellKnownSymbolsOptions,
+    /// Target doesn't support `Symbol` and the ES2015 well-known symbols.
+    symbol: bool,
+    /// Target doesn't support `Symbol.asyncIterator`.
+    async_iterator: bool,
+    /// Symbols which have already been reported or shimmed.
+    seen: FxHashSet<Atom<'a>>,
+}
+
+impl<'a, 'ctx> WellKnownSymbols<'a, 'ctx> {
+    pub fn new(
+        options: WellKnownSymbolsOptions,
+        env: &EnvOptions,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let built_ins = BuiltIns::new(env);
+        Self {
+            ctx,
+            options,
+            symbol: built_ins.is_unavailable(BuiltIn::Symbol),
+            async_iterator: built_ins.is_unavailable(BuiltIn::SymbolAsyncIterator),
+            seen: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for WellKnownSymbols<'a, '_> {
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let name = expr.property.name;
+        if !self.is_unavailable(&name)
+            || !expr.object.is_global_reference_name("Symbol", ctx.scoping())
+        {
+            return;
+        }
+
+        if let Some(shim) = &self.options.shim {
+            if self.seen.insert(name) {
+                let source = match shim.split_once(NAME_PLACEHOLDER) {
+                    Some((before, after)) => format!("{before}{}{after}", to_kebab_case(&name)),
+                    None => shim.clone(),
+                };
+                self.ctx.module_imports.add_side_effect_import(ctx.ast.atom(&source));
+            }
+        } else {
+            let warning = OxcDiagnostic::warn(format!(
+                "`Symbol.{name}` is not available in the configured target environment."
+            ))
+            .with_label(expr.span)
+            .with_help("Configure a `shim` to import a polyfill.");
+            self.ctx.error(warning);
+        }
+    }
+}
+
+impl WellKnownSymbols<'_, '_> {
+    fn is_unavailable(&self, name: &str) -> bool {
+        (self.symbol && ES2015_SYMBOLS.contains(&name))
+            || (self.async_iterator && name == "asyncIterator")
+    }
+}
+
+/// `asyncIterator` -> `async-iterator`
+fn to_kebab_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            result.push('-');
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
@@ -1,3 +1,31 @@
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
 mod options;
+mod throw_expressions;
 
 pub use options::ProposalOptions;
+pub use throw_expressions::ThrowExpressions;
+
+pub struct Proposals {
+    options: ProposalOptions,
+
+    // Plugins
+    throw_expressions: ThrowExpressions,
+}
+
+impl Proposals {
+    pub fn new(options: ProposalOptions) -> Self {
+        Self { throw_expressions: ThrowExpressions::new(), options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Proposals {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.throw_expressions {
+            self.throw_expressions.enter_expression(expr, ctx);
+        }
+    }
+}
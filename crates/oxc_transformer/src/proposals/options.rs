@@ -1,5 +1,8 @@
-#[expect(clippy::empty_structs_with_brackets)]
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ProposalOptions {
-    // Currently no proposals are configured here.
+    /// Lower [throw expressions](https://github.com/tc39/proposal-throw-expressions).
+    ///
+    /// The parser only produces throw expressions when
+    /// `ParseOptions::allow_throw_expressions` is enabled.
+    pub throw_expressions: bool,
 }
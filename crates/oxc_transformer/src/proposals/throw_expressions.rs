@@ -0,0 +1,105 @@
+//! Proposal: Throw Expressions
+//!
+//! This plugin transforms `throw` expressions into an immediately invoked arrow function
+//! which throws its argument.
+//!
+//! Throw expressions are only parsed when `ParseOptions::allow_throw_expressions` is enabled.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const value = input ?? throw new Error("missing input");
+//! function f(x = throw new TypeError("x is required")) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! const value = input ?? (e => {
+//!   throw e;
+//! })(new Error("missing input"));
+//! function f(x = (e => {
+//!   throw e;
+//! })(new TypeError("x is required"))) {}
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-throw-expressions](https://babeljs.io/docs/babel-plugin-proposal-throw-expressions).
+//!
+//! The argument is passed to the arrow function rather than being moved into its body,
+//! so it is still evaluated in the original scope (`this`, `arguments` and any bindings it
+//! references are unaffected).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-proposal-throw-expressions>
+//! * Throw expressions TC39 proposal: <https://github.com/tc39/proposal-throw-expressions>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct ThrowExpressions;
+
+impl ThrowExpressions {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ThrowExpressions {
+    // `throw x` -> `(e => { throw e; })(x)`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UnaryExpression(unary) = expr else {
+            return;
+        };
+        if !unary.operator.is_throw() {
+            return;
+        }
+
+        let span = unary.span;
+        let argument = unary.argument.take_in(ctx.ast);
+
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let binding =
+            ctx.generate_binding(Atom::from("e"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            binding.create_binding_pattern(ctx),
+            NONE,
+            NONE,
+            false,
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_throw(SPAN, binding.create_read_expression(ctx))),
+        );
+        let arrow = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, false, false, NONE, params, NONE, body, scope_id, false, false,
+        );
+
+        *expr = ctx.ast.expression_call(
+            span,
+            arrow,
+            NONE,
+            ctx.ast.vec1(Argument::from(argument)),
+            false,
+        );
+    }
+}
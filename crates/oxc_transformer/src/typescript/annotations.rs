@@ -170,6 +170,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
             );
             program.body.push(export_decl);
         }
+
+        self.keep_triple_slash_directives(program);
     }
 
     fn enter_arrow_function_expression(
@@ -217,7 +219,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
         func.this_param = None;
     }
 
-    fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         // Remove TypeScript annotations from class declarations
         // Note: declare flag is preserved for exit_statements to handle declaration removal
         class.type_parameters = None;
@@ -228,8 +230,13 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
         // Remove type only members
         class.body.body.retain(|elem| match elem {
             ClassElement::MethodDefinition(method) => {
-                matches!(method.r#type, MethodDefinitionType::MethodDefinition)
-                    && !method.value.is_typescript_syntax()
+                let keep = matches!(method.r#type, MethodDefinitionType::MethodDefinition)
+                    && !method.value.is_typescript_syntax();
+                if !keep {
+                    // Abstract methods and overload signatures have no runtime representation
+                    ctx.scoping_mut().delete_scope(method.value.scope_id());
+                }
+                keep
             }
             ClassElement::PropertyDefinition(prop) => {
                 matches!(prop.r#type, PropertyDefinitionType::PropertyDefinition)
@@ -363,6 +370,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
     ) {
         def.accessibility = None;
         def.definite = false;
+        def.r#override = false;
         def.type_annotation = None;
     }
 
@@ -542,6 +550,40 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
 }
 
 impl<'a> TypeScriptAnnotations<'a, '_> {
+    /// Triple-slash directives (`/// <reference types="node" />`) are at the top of the file, and
+    /// attached to the first statement. Move them to the top of the output if that statement was
+    /// removed, e.g. because it's an ambient declaration like `declare module "foo" {}`.
+    fn keep_triple_slash_directives(&self, program: &mut Program<'a>) {
+        // Comments at the top of the file are attached to the first statement of the source.
+        let Some(attached_to) = program.comments.first().map(|comment| comment.attached_to) else {
+            return;
+        };
+        let mut remaining_starts = program
+            .directives
+            .iter()
+            .map(|directive| directive.span.start)
+            .chain(program.body.iter().map(|stmt| stmt.span().start));
+        if remaining_starts.clone().any(|start| start == attached_to) {
+            return;
+        }
+        // Comments at `0` are printed before the hashbang.
+        let target = if program.hashbang.is_some() {
+            remaining_starts.find(|&start| start > 0).unwrap_or(program.span.end)
+        } else {
+            0
+        };
+        for comment in
+            program.comments.iter_mut().take_while(|comment| comment.attached_to == attached_to)
+        {
+            let content = comment.content_span().source_text(self.ctx.source_text);
+            if comment.is_line()
+                && content.strip_prefix('/').is_some_and(|rest| rest.trim_start().starts_with('<'))
+            {
+                comment.attached_to = target;
+            }
+        }
+    }
+
     /// Check if the given name is a JSX pragma or fragment pragma import
     /// and if the file contains JSX elements or fragments
     fn is_jsx_imports(&self, name: &str) -> bool {
@@ -18,6 +18,15 @@ pub fn export_assignment_cannot_bed_used_in_esm(span: Span) -> OxcDiagnostic {
         .with_error_code("TS", "1203")
 }
 
+pub fn export_assignment_cannot_be_used_with_other_exports(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "An export assignment cannot be used in a module with other exported elements.",
+    )
+    .with_help("Assign the other exports to the value of `export =`, or replace `export =` with `export default`.")
+    .with_label(span)
+    .with_error_code("TS", "2309")
+}
+
 pub fn ambient_module_nested(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Ambient modules cannot be nested in other modules or namespaces.")
         .with_label(span)
@@ -1,15 +1,16 @@
 use std::cell::Cell;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_allocator::{StringBuilder, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
-use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_ecmascript::{ToInt32, ToUint32};
-use oxc_semantic::{ScopeFlags, ScopeId};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
 use oxc_span::{Atom, SPAN, Span};
 use oxc_syntax::{
+    identifier::is_identifier_name,
     number::{NumberBase, ToJsString},
     operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     reference::ReferenceFlags,
@@ -17,22 +18,86 @@ use oxc_syntax::{
 };
 use oxc_traverse::{BoundIdentifier, Traverse};
 
-use crate::{context::TraverseCtx, state::TransformState};
+use crate::{TypeScriptOptions, context::TraverseCtx, state::TransformState};
 
 /// enum member values (or None if it can't be evaluated at build time) keyed by names
 type PrevMembers<'a> = FxHashMap<Atom<'a>, Option<ConstantValue<'a>>>;
 
+/// enum member values keyed by names, for enums whose members are all constants
+type InlinedMembers<'a> = FxHashMap<Atom<'a>, ConstantValue<'a>>;
+
 pub struct TypeScriptEnum<'a> {
     enums: FxHashMap<Atom<'a>, PrevMembers<'a>>,
+    /// [`TypeScriptOptions::frozen_string_enums`]
+    frozen_string_enums: bool,
+    /// [`TypeScriptOptions::inline_enums`]
+    inline_enums: bool,
+    /// Enums whose member reads are replaced with their values, and whose declarations are removed.
+    inlined_enums: FxHashMap<SymbolId, InlinedMembers<'a>>,
 }
 
 impl TypeScriptEnum<'_> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+    pub fn new(options: &TypeScriptOptions) -> Self {
+        Self {
+            enums: FxHashMap::default(),
+            frozen_string_enums: options.frozen_string_enums,
+            inline_enums: options.inline_enums,
+            inlined_enums: FxHashMap::default(),
+        }
     }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptEnum<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.inline_enums {
+            return;
+        }
+        let mut collector = EnumUseCollector::new(self, program.source_type.is_script(), ctx);
+        collector.visit_program(program);
+        self.inlined_enums = collector.into_inlinable_enums();
+    }
+
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.inlined_enums.is_empty() {
+            return;
+        }
+        stmts.retain(|stmt| {
+            let Statement::TSEnumDeclaration(decl) = stmt else { return true };
+            let symbol_id = decl.id.symbol_id();
+            if !self.inlined_enums.contains_key(&symbol_id) {
+                return true;
+            }
+            let scoping = ctx.scoping_mut();
+            scoping.remove_binding(scoping.symbol_scope_id(symbol_id), &decl.id.name);
+            scoping.delete_scope(decl.scope_id());
+            false
+        });
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.inlined_enums.is_empty() {
+            return;
+        }
+        let Some(member) = expr.as_member_expression() else { return };
+        let Expression::Identifier(ident) = member.object() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some(members) = self.inlined_enums.get(&symbol_id) else { return };
+        // All reads of the enum's members have been checked to be static and to exist.
+        let value = members[member.static_property_name().unwrap()];
+        ctx.delete_reference_for_identifier(ident);
+        // `Foo.X` -> `1`
+        *expr = match value {
+            ConstantValue::Number(v) => Self::get_initializer_expr(v, ctx),
+            ConstantValue::String(str) => ctx.ast.expression_string_literal(SPAN, str, None),
+        };
+    }
+
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -79,6 +144,12 @@ impl<'a> TypeScriptEnum<'a> {
             return None;
         }
 
+        if self.frozen_string_enums
+            && let Some(object) = self.transform_string_enum(decl, ctx)
+        {
+            return Some(Self::create_enum_declaration(decl, object, export_span, ctx));
+        }
+
         let ast = ctx.ast;
 
         let is_export = export_span.is_some();
@@ -180,7 +251,19 @@ impl<'a> TypeScriptEnum<'a> {
             return Some(ast.statement_expression(decl.span, expr));
         }
 
-        let kind = if is_export || is_not_top_scope {
+        Some(Self::create_enum_declaration(decl, call_expression, export_span, ctx))
+    }
+
+    /// `var Foo = init;`, or `let Foo = init;` if it's exported or not at the top level.
+    fn create_enum_declaration(
+        decl: &TSEnumDeclaration<'a>,
+        init: Expression<'a>,
+        export_span: Option<Span>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let ast = ctx.ast;
+        let is_not_top_scope = !ctx.scoping().scope_flags(ctx.current_scope_id()).is_top();
+        let kind = if export_span.is_some() || is_not_top_scope {
             VariableDeclarationKind::Let
         } else {
             VariableDeclarationKind::Var
@@ -188,21 +271,93 @@ impl<'a> TypeScriptEnum<'a> {
         let decls = {
             let binding_identifier = decl.id.clone();
             let binding = BindingPattern::BindingIdentifier(ctx.alloc(binding_identifier));
-            let decl =
-                ast.variable_declarator(SPAN, kind, binding, NONE, Some(call_expression), false);
+            let decl = ast.variable_declarator(SPAN, kind, binding, NONE, Some(init), false);
             ast.vec1(decl)
         };
         let variable_declaration = ast.declaration_variable(decl.span, kind, decls, false);
 
-        let stmt = if let Some(export_span) = export_span {
-            let declaration = ctx
-                .ast
-                .plain_export_named_declaration_declaration(export_span, variable_declaration);
+        if let Some(export_span) = export_span {
+            let declaration =
+                ast.plain_export_named_declaration_declaration(export_span, variable_declaration);
             Statement::ExportNamedDeclaration(declaration)
         } else {
             Statement::from(variable_declaration)
-        };
-        Some(stmt)
+        }
+    }
+
+    /// ```TypeScript
+    /// enum Foo {
+    ///   X = "x",
+    ///   Y = `${X}y`
+    /// }
+    /// ```
+    /// ```JavaScript
+    /// /* @__PURE__ */ Object.freeze({
+    ///   X: "x",
+    ///   Y: "xy"
+    /// })
+    /// ```
+    ///
+    /// Returns `None` if a member isn't a constant string, or the enum is merged with
+    /// another declaration, which adds properties to the same object.
+    fn transform_string_enum(
+        &mut self,
+        decl: &TSEnumDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if decl.body.members.is_empty()
+            || !ctx.scoping().symbol_redeclarations(decl.id.symbol_id()).is_empty()
+        {
+            return None;
+        }
+
+        let mut members = PrevMembers::default();
+        let mut values = Vec::with_capacity(decl.body.members.len());
+        for member in &decl.body.members {
+            let value = self.computed_constant_value(member.initializer.as_ref()?, &members, ctx);
+            let Some(ConstantValue::String(value)) = value else { return None };
+            members.insert(member.id.static_name(), Some(ConstantValue::String(value)));
+            values.push(value);
+        }
+        self.enums.insert(decl.id.name, members);
+
+        // There is no function scope for the members anymore.
+        ctx.scoping_mut().delete_scope(decl.scope_id());
+
+        let ast = ctx.ast;
+        let properties =
+            ast.vec_from_iter(decl.body.members.iter().zip(values).map(|(member, value)| {
+                let name = member.id.static_name();
+                // `{ __proto__: "x" }` would set the prototype of the object, `{ ["__proto__"]: "x" }` doesn't.
+                let computed = name == "__proto__";
+                let key = if is_identifier_name(&name) && !computed {
+                    ast.property_key_static_identifier(SPAN, name)
+                } else {
+                    PropertyKey::StringLiteral(ast.alloc_string_literal(SPAN, name, None))
+                };
+                ast.object_property_kind_object_property(
+                    member.span,
+                    PropertyKind::Init,
+                    key,
+                    ast.expression_string_literal(SPAN, value, None),
+                    false,
+                    false,
+                    computed,
+                )
+            }));
+
+        // `Object.freeze`
+        let object_symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Object");
+        let object = ctx.create_ident_expr(
+            SPAN,
+            Atom::from("Object"),
+            object_symbol_id,
+            ReferenceFlags::Read,
+        );
+        let property = ast.identifier_name(SPAN, "freeze");
+        let callee = Expression::from(ast.member_expression_static(SPAN, object, property, false));
+        let arguments = ast.vec1(Argument::from(ast.expression_object(SPAN, properties)));
+        Some(ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, true))
     }
 
     fn transform_ts_enum_members(
@@ -348,6 +503,133 @@ impl<'a> TypeScriptEnum<'a> {
     }
 }
 
+/// Evaluates the members of the enums which can be inlined, see [`TypeScriptOptions::inline_enums`].
+///
+/// Member reads are found before the enum declarations are reached, as they can come earlier in the
+/// program, e.g. in a function declared before the enum.
+struct EnumUseCollector<'a, 'e> {
+    r#enum: &'e TypeScriptEnum<'a>,
+    ctx: &'e TraverseCtx<'a>,
+    is_script: bool,
+    /// Member values of the enums whose members are all constants.
+    enums: FxHashMap<SymbolId, InlinedMembers<'a>>,
+    /// Names of the members read from each enum.
+    member_reads: Vec<(SymbolId, Atom<'a>)>,
+    /// Enums which can't be inlined, as they are exported or used in any other way.
+    excluded: FxHashSet<SymbolId>,
+}
+
+impl<'a, 'e> EnumUseCollector<'a, 'e> {
+    fn new(r#enum: &'e TypeScriptEnum<'a>, is_script: bool, ctx: &'e TraverseCtx<'a>) -> Self {
+        Self {
+            r#enum,
+            ctx,
+            is_script,
+            enums: FxHashMap::default(),
+            member_reads: vec![],
+            excluded: FxHashSet::default(),
+        }
+    }
+
+    fn into_inlinable_enums(mut self) -> FxHashMap<SymbolId, InlinedMembers<'a>> {
+        for (symbol_id, name) in self.member_reads {
+            if !self.enums.get(&symbol_id).is_some_and(|members| members.contains_key(&name)) {
+                self.excluded.insert(symbol_id);
+            }
+        }
+        self.enums.retain(|symbol_id, _| !self.excluded.contains(symbol_id));
+        self.enums
+    }
+
+    fn enum_of(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        let scoping = self.ctx.scoping();
+        let symbol_id = scoping.get_reference(ident.reference_id()).symbol_id()?;
+        scoping.symbol_flags(symbol_id).is_enum().then_some(symbol_id)
+    }
+
+    /// The values of all members, or `None` if a member isn't a constant.
+    fn evaluate_members(&self, decl: &TSEnumDeclaration<'a>) -> Option<InlinedMembers<'a>> {
+        let mut members = PrevMembers::default();
+        let mut prev_constant_number = Some(-1.0);
+        for member in &decl.body.members {
+            let value = match &member.initializer {
+                Some(initializer) => {
+                    self.r#enum.computed_constant_value(initializer, &members, self.ctx)?
+                }
+                None => ConstantValue::Number(prev_constant_number? + 1.0),
+            };
+            prev_constant_number = match value {
+                ConstantValue::Number(v) => Some(v),
+                ConstantValue::String(_) => None,
+            };
+            members.insert(member.id.static_name(), Some(value));
+        }
+        Some(members.into_iter().map(|(name, value)| (name, value.unwrap())).collect())
+    }
+}
+
+impl<'a> Visit<'a> for EnumUseCollector<'a, '_> {
+    fn visit_ts_enum_declaration(&mut self, decl: &TSEnumDeclaration<'a>) {
+        let symbol_id = decl.id.symbol_id();
+        let scoping = self.ctx.scoping();
+        let is_global =
+            self.is_script && scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id();
+        if decl.declare || is_global || !scoping.symbol_redeclarations(symbol_id).is_empty() {
+            self.excluded.insert(symbol_id);
+        } else if let Some(members) = self.evaluate_members(decl) {
+            self.enums.insert(symbol_id, members);
+        } else {
+            self.excluded.insert(symbol_id);
+        }
+        walk::walk_ts_enum_declaration(self, decl);
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if let Some(Declaration::TSEnumDeclaration(enum_decl)) = &decl.declaration {
+            self.excluded.insert(enum_decl.id.symbol_id());
+        }
+        walk::walk_export_named_declaration(self, decl);
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        // `Foo.X` or `Foo["X"]`
+        if let Some(member) = expr.as_member_expression()
+            && !member.optional()
+            && let Expression::Identifier(ident) = member.object()
+            && let Some(symbol_id) = self.enum_of(ident)
+        {
+            if let Some(name) = member.static_property_name() {
+                self.member_reads.push((symbol_id, Atom::from(name)));
+            } else {
+                self.excluded.insert(symbol_id);
+            }
+            return;
+        }
+        walk::walk_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        // `delete Foo.X`
+        if expr.operator == UnaryOperator::Delete
+            && let Some(member) = expr.argument.without_parentheses().as_member_expression()
+            && let Expression::Identifier(ident) = member.object()
+            && let Some(symbol_id) = self.enum_of(ident)
+        {
+            self.excluded.insert(symbol_id);
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.enum_of(ident) {
+            self.excluded.insert(symbol_id);
+        }
+    }
+
+    // Types are removed, so using an enum in a type doesn't prevent inlining it.
+    fn visit_ts_type(&mut self, _ty: &TSType<'a>) {}
+}
+
 #[derive(Debug, Clone, Copy)]
 enum ConstantValue<'a> {
     Number(f64),
@@ -61,12 +61,13 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            r#enum: TypeScriptEnum::new(options),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(options.only_remove_type_imports, ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
             remove_class_fields_without_initializer: !options.allow_declare_fields
-                || options.remove_class_fields_without_initializer,
+                || options.remove_class_fields_without_initializer
+                || !options.use_define_for_class_fields,
         }
     }
 }
@@ -80,6 +81,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
             program.body.clear();
         } else {
             program.source_type = program.source_type.with_javascript(true);
+            self.r#enum.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
             self.module.enter_program(program, ctx);
         }
@@ -139,6 +141,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -215,6 +218,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
         stmts: &mut ArenaVec<'a, Statement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        self.r#enum.enter_statements(stmts, ctx);
         self.annotations.enter_statements(stmts, ctx);
     }
 
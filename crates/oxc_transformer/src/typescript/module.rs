@@ -15,12 +15,14 @@ use crate::{
 pub struct TypeScriptModule<'a, 'ctx> {
     /// <https://babeljs.io/docs/babel-plugin-transform-typescript#onlyremovetypeimports>
     only_remove_type_imports: bool,
+    /// `export =` is combined with other exports, which is already reported.
+    has_conflicting_exports: bool,
     ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
     pub fn new(only_remove_type_imports: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { only_remove_type_imports, ctx }
+        Self { only_remove_type_imports, has_conflicting_exports: false, ctx }
     }
 }
 
@@ -28,6 +30,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptModule<'a, '_> {
     #[inline]
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.mark_unused_import_equals_references_as_type(&program.body, ctx);
+        self.check_export_assignment_with_other_exports(&program.body);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -58,13 +61,43 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptModule<'a, '_> {
 }
 
 impl<'a> TypeScriptModule<'a, '_> {
+    /// `module.exports = expression` would be mixed with ESM exports, which can't be represented
+    /// in either module format.
+    ///
+    /// ```TypeScript
+    /// export = foo;
+    /// export const bar = 1;
+    /// ```
+    fn check_export_assignment_with_other_exports(&mut self, stmts: &[Statement<'a>]) {
+        let has_other_exports = stmts.iter().any(|stmt| match stmt {
+            // `export {}` only marks the file as a module
+            Statement::ExportNamedDeclaration(decl) => {
+                decl.declaration.is_some() || !decl.specifiers.is_empty()
+            }
+            Statement::ExportDefaultDeclaration(_) | Statement::ExportAllDeclaration(_) => true,
+            _ => false,
+        });
+        if !has_other_exports {
+            return;
+        }
+        self.has_conflicting_exports = true;
+        for stmt in stmts {
+            if let Statement::TSExportAssignment(export_assignment) = stmt {
+                self.ctx.error(diagnostics::export_assignment_cannot_be_used_with_other_exports(
+                    export_assignment.span,
+                ));
+            }
+        }
+    }
+
     /// Transform `export = expression` to `module.exports = expression`.
     fn transform_ts_export_assignment(
         &self,
         export_assignment: &mut TSExportAssignment<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Statement<'a> {
-        if self.ctx.module.is_esm() {
+        // Only report one of the two errors for the same `export =`
+        if self.ctx.module.is_esm() && !self.has_conflicting_exports {
             self.ctx.error(diagnostics::export_assignment_cannot_bed_used_in_esm(
                 export_assignment.span,
             ));
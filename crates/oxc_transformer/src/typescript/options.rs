@@ -84,6 +84,87 @@ pub struct TypeScriptOptions {
     #[serde(default)]
     pub remove_class_fields_without_initializer: bool,
 
+    /// Whether class fields are defined with `[[Define]]` semantics, like TypeScript's
+    /// [`useDefineForClassFields`](https://www.typescriptlang.org/tsconfig/#useDefineForClassFields) option.
+    ///
+    /// When `false`, class fields without initializer are removed, and when class fields are transformed,
+    /// they are assigned with `=` instead of `Object.defineProperty`. This is the same as setting both
+    /// [`crate::CompilerAssumptions::set_public_class_fields`] and
+    /// [`TypeScriptOptions::remove_class_fields_without_initializer`] to `true`.
+    ///
+    /// When `false`, public class fields are always moved to assignments in the constructor, or in a
+    /// static block, even when the target supports class fields. Private fields are left as they are.
+    ///
+    /// ```ts
+    /// class Foo extends Bar {
+    ///    x: number;
+    ///    y = 0;
+    /// }
+    /// ```
+    /// // transform into
+    /// ```js
+    /// class Foo extends Bar {
+    ///   constructor(...args) {
+    ///     super(...args);
+    ///     this.y = 0;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub use_define_for_class_fields: bool,
+
+    /// Transform enums whose members are all strings to frozen objects, instead of functions
+    /// which add the members to a mutable object one by one.
+    ///
+    /// ```ts
+    /// enum Color {
+    ///   Red = "RED",
+    ///   Green = "GREEN",
+    /// }
+    /// ```
+    /// // transform into
+    /// ```js
+    /// var Color = /* @__PURE__ */ Object.freeze({
+    ///   Red: "RED",
+    ///   Green: "GREEN"
+    /// });
+    /// ```
+    ///
+    /// The members of a frozen object can't change, so minifiers can inline them, and remove the enum
+    /// when it's unused. Enums with numeric or computed members, and enums which are merged with
+    /// another declaration of the same name, are transformed as usual.
+    ///
+    /// Defaults to `false`.
+    pub frozen_string_enums: bool,
+
+    /// Replace reads of enum members with their values, and remove the enums.
+    ///
+    /// ```ts
+    /// enum Direction {
+    ///   Up = 1,
+    ///   Down,
+    /// }
+    /// move(Direction.Down);
+    /// ```
+    /// // transform into
+    /// ```js
+    /// move(2);
+    /// ```
+    ///
+    /// An enum is only inlined if all its members are constants, and it's only used to read members
+    /// by name, e.g. `Direction.Up` or `Direction["Up"]`. Enums which are exported, merged with another
+    /// declaration, used as a value (`Object.keys(Direction)`), used for a reverse mapping
+    /// (`Direction[1]`) or mutated are transformed as usual. So are enums at the top level of a script,
+    /// which are global variables.
+    ///
+    /// The declaration of an inlined enum is always removed, there is no option to keep the runtime
+    /// object, as nothing refers to it anymore once all its member reads are replaced.
+    ///
+    /// Defaults to `false`.
+    pub inline_enums: bool,
+
     /// Unused.
     pub optimize_const_enums: bool,
 
@@ -107,6 +188,9 @@ impl Default for TypeScriptOptions {
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
             remove_class_fields_without_initializer: false,
+            use_define_for_class_fields: default_as_true(),
+            frozen_string_enums: false,
+            inline_enums: false,
             optimize_const_enums: false,
             rewrite_import_extensions: None,
         }
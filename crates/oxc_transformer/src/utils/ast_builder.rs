@@ -57,6 +57,38 @@ pub fn wrap_expression_in_arrow_function_iife<'a>(
     wrap_statements_in_arrow_function_iife(stmts, scope_id, span, ctx)
 }
 
+/// Wrap an `Expression` in a function expression which is called with the current `this`.
+///
+/// `expr` -> `function() { return expr; }.call(this)`
+pub fn wrap_expression_in_function_called_with_this<'a>(
+    expr: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.insert_scope_below_expression(&expr, ScopeFlags::Function);
+    let span = expr.span();
+    let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(expr)));
+    let kind = FormalParameterKind::FormalParameter;
+    let params = ctx.ast.alloc_formal_parameters(SPAN, kind, ctx.ast.vec(), NONE);
+    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+    let func = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+        SPAN,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+        false,
+        false,
+    );
+    create_call_call(func, ctx.ast.expression_this(SPAN), span, ctx)
+}
+
 /// Wrap statements in an IIFE (immediately invoked function expression).
 ///
 /// `x; y; z;` -> `(() => { x; y; z; })()`
@@ -0,0 +1,145 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::test_with_source_type;
+
+#[test]
+fn ambient_declarations_are_erased() {
+    for source in [
+        "declare module 'foo' { export const x: number; }",
+        "declare module 'foo';",
+        "declare global { interface Window { a: string } }",
+        "declare const a: number;",
+        "declare let a: number, b: string;",
+        "declare var a: number;",
+        "declare function f(): void;",
+        "declare function f(a: number): void; declare function f(a: string): void;",
+        "declare class C { m(): void }",
+        "declare abstract class C {}",
+        "declare enum E { A }",
+        "declare const enum E { A }",
+        "declare namespace N { const x: number; }",
+        "declare module N.M { const x: number; }",
+        "interface I {} type T = I;",
+    ] {
+        assert_eq!(
+            test_with_source_type(source, SourceType::ts(), &TransformOptions::default()),
+            Ok(String::new()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn exported_ambient_declarations_are_erased() {
+    for source in [
+        "export declare const a: number;",
+        "export declare function f(): void;",
+        "export declare class C {}",
+        "export declare enum E { A }",
+        "export declare namespace N {}",
+        "export declare const a: number; export declare function f(): void;",
+        "export type T = number; export interface I {}",
+    ] {
+        // The output is still a module.
+        assert_eq!(
+            test_with_source_type(source, SourceType::ts(), &TransformOptions::default()),
+            Ok("export {};\n".to_string()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn ambient_declarations_with_runtime_code() {
+    assert_eq!(
+        test_with_source_type(
+            "declare const a: number; const b = a + 1; declare function f(): void; f();",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("const b = a + 1;\nf();\n".to_string())
+    );
+    assert_eq!(
+        test_with_source_type(
+            "export declare const a: number; export const b = a + 1;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("export const b = a + 1;\n".to_string())
+    );
+    assert_eq!(
+        test_with_source_type(
+            "declare global { var a: number } globalThis.a = 1;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("globalThis.a = 1;\n".to_string())
+    );
+}
+
+#[test]
+fn triple_slash_directives_are_kept() {
+    assert_eq!(
+        test_with_source_type(
+            "/// <reference types=\"node\" />\n/// <reference path=\"./globals.d.ts\" />\ndeclare const a: number;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("/// <reference types=\"node\" />\n/// <reference path=\"./globals.d.ts\" />\n"
+            .to_string())
+    );
+    assert_eq!(
+        test_with_source_type(
+            "/// <reference types=\"node\" />\ndeclare const a: number;\nconst b = a;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("/// <reference types=\"node\" />\nconst b = a;\n".to_string())
+    );
+    assert_eq!(
+        test_with_source_type(
+            "/// <reference lib=\"dom\" />\n'use strict';\ndeclare const a: number;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("/// <reference lib=\"dom\" />\n'use strict';\n".to_string())
+    );
+    assert_eq!(
+        test_with_source_type(
+            "/// <reference types=\"node\" />\nexport declare const a: number;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("/// <reference types=\"node\" />\nexport {};\n".to_string())
+    );
+    // Other comments of erased declarations are removed with them.
+    assert_eq!(
+        test_with_source_type(
+            "// Types of the globals.\n/// <reference types=\"node\" />\ndeclare const a: number;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("/// <reference types=\"node\" />\n".to_string())
+    );
+}
+
+#[test]
+fn triple_slash_directives_after_hashbang() {
+    assert_eq!(
+        test_with_source_type(
+            "#!/usr/bin/env node\n/// <reference types=\"node\" />\ndeclare const a: number;\nrun();",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("#!/usr/bin/env node\n/// <reference types=\"node\" />\nrun();\n".to_string())
+    );
+    assert_eq!(
+        test_with_source_type(
+            "#!/usr/bin/env node\n/// <reference types=\"node\" />\ndeclare const a: number;",
+            SourceType::ts(),
+            &TransformOptions::default()
+        ),
+        Ok("#!/usr/bin/env node\n/// <reference types=\"node\" />\n".to_string())
+    );
+}
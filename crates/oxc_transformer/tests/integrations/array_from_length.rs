@@ -0,0 +1,77 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.array_from_length = true;
+    options
+}
+
+#[test]
+fn converts_fill_map() {
+    assert_transform(
+        "x = Array(n).fill().map((_, i) => i * 2);",
+        "x = Array.from({ length: n }, (_, i) => i * 2);",
+        &options(),
+    );
+    assert_transform(
+        "x = Array(3).fill(undefined).map(() => ({}));",
+        "x = Array.from({ length: 3 }, () => ({}));",
+        &options(),
+    );
+    assert_transform(
+        "x = new Array(a.length).fill(void 0).map(async (v) => await v, o);",
+        "x = Array.from({ length: a.length }, async (v) => await v, o);",
+        &options(),
+    );
+    assert_transform(
+        "x = Array(4294967295).fill().map(() => 0);",
+        "x = Array.from({ length: 4294967295 }, () => 0);",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_chained_methods() {
+    assert_transform(
+        "x = Array(n).fill().map((_, i) => i).filter(Boolean).join();",
+        "x = Array.from({ length: n }, (_, i) => i).filter(Boolean).join();",
+        &options(),
+    );
+    assert_unchanged("x = Array(n).fill().reverse().map((_, i) => i);", &options());
+}
+
+#[test]
+fn skips_other_fill_values() {
+    assert_unchanged("x = Array(n).fill(0).map((v, i) => v + i);", &options());
+    assert_unchanged("x = Array(n).fill(null).map(() => 1);", &options());
+    assert_unchanged("x = Array(n).fill(void f()).map(() => 1);", &options());
+    assert_unchanged("x = Array(n).fill(undefined, 1).map(() => 1);", &options());
+    assert_unchanged("let undefined = 1; x = Array(n).fill(undefined).map(() => 1);", &options());
+}
+
+#[test]
+fn skips_other_callbacks() {
+    assert_unchanged("x = Array(n).fill().map((v, i, array) => array);", &options());
+    assert_unchanged("x = Array(n).fill().map((...args) => args);", &options());
+    assert_unchanged("x = Array(n).fill().map(function(v, i) { return arguments; });", &options());
+    assert_unchanged("x = Array(n).fill().map(f);", &options());
+    assert_unchanged("x = Array(n).fill().map(...fns);", &options());
+}
+
+#[test]
+fn skips_other_arrays() {
+    assert_unchanged("x = Array('3').fill().map(() => 1);", &options());
+    assert_unchanged("x = Array(-1).fill().map(() => 1);", &options());
+    assert_unchanged("x = Array(1.5).fill().map(() => 1);", &options());
+    assert_unchanged("x = Array(4294967296).fill().map(() => 1);", &options());
+    assert_unchanged("x = Array(1e300).fill().map(() => 1);", &options());
+    assert_unchanged("x = Array(1, 2).fill().map(() => 1);", &options());
+    assert_unchanged("x = Array().fill().map(() => 1);", &options());
+    assert_unchanged("x = Array?.(n).fill().map(() => 1);", &options());
+    assert_unchanged("x = Array(n).fill?.().map(() => 1);", &options());
+    assert_unchanged("x = Array(n).fill().map?.(() => 1);", &options());
+    assert_unchanged("x = [1, 2].fill().map(() => 1);", &options());
+    assert_unchanged("let Array = f; x = Array(n).fill().map(() => 1);", &options());
+}
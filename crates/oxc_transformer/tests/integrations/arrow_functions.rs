@@ -0,0 +1,54 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, code_or_errors, codegen, print_cases, test};
+
+#[test]
+fn arrow_functions() {
+    let cases = [
+        "const f = () => {};",
+        "const f = (a, b) => a + b;",
+        "const f = () => ({ a: 1 });",
+        "const double = [1, 2, 3].map(n => n * 2);",
+        // `this` is only captured when it's used.
+        "function f() { return () => 1; }",
+        "function f() { return () => this.x; }",
+        "function f() { return () => () => this.x + this.y; }",
+        "function f() { g(() => this.x); h(() => this.y); }",
+        "const f = () => this;",
+        // Functions have their own `this`, and don't need a capture.
+        "function f() { return () => function() { return this; }; }",
+        // `arguments` and `new.target`.
+        "function f() { return () => arguments[0]; }",
+        "function f() { return () => () => arguments.length + this.x; }",
+        "function F() { return () => new.target; }",
+        "function f() { let arguments; return () => arguments; }",
+        "function f() { return () => { var arguments = 1; return arguments; }; }",
+        "class C { static { const f = () => new.target; } }",
+        "function F() { return () => [arguments, new.target, this]; }",
+        // An outer `arguments` binding is captured if it's a parameter which is never reassigned.
+        "function f(arguments) { return () => arguments; }",
+        "function f(arguments) { return () => () => arguments[0]; }",
+        "function f(arguments) { arguments = 1; return () => arguments; }",
+        "function f(arguments) { function g() { return () => arguments; } }",
+        "function f(arguments) { { let arguments; g(() => arguments); } }",
+        // `super` in methods.
+        "class C extends B { m() { return () => super.m(); } }",
+        "const o = { m() { return () => super.m(this); } };",
+        "class C extends B { m() { return () => () => super.m(); } }",
+        // Arrow functions in class field initializers.
+        "class C { x = () => this; static y = () => this.z; }",
+        "class C { #x = 1; f = () => this.#x; }",
+        "class C { static x = f(() => this, () => 1); }",
+    ];
+
+    // Nothing else needs to be transformed for ES2022.
+    let options = TransformOptions::from_target("es2022").unwrap();
+    for case in cases {
+        assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())), "{case}");
+    }
+
+    let options = TransformOptions::from_target("chrome40").unwrap();
+    let snapshot = print_cases("", cases, |case| code_or_errors(case, test(case, &options)));
+    assert_snapshot("arrow_functions", &snapshot);
+}
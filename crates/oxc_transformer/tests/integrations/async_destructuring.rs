@@ -0,0 +1,31 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test};
+
+/// Destructuring the results of `Promise.all`, with async functions and object rest both lowered.
+#[test]
+fn async_destructuring() {
+    let cases = [
+        "async function f() { const [a, b] = await Promise.all([x(), y()]); return a + b; }",
+        "async function f() { const [a, ...rest] = await Promise.all(ps); return rest; }",
+        // Defaults are evaluated in order, and may await.
+        "async function f() { const [a = 1, b = await g(a)] = await Promise.all(ps); }",
+        "async function f() { const [{ data: { items } = {} }, [first]] = await Promise.all([a(), b()]); }",
+        "async function f() { const [{ a, ...others }, b] = await Promise.all(ps); use(others, b); }",
+        "async function f() { const [, [{ ...c } = await d()]] = await Promise.all(ps); }",
+        "async function f() { let a, b; [a, { b, ...c }] = await Promise.all(ps); }",
+        "async function f() { if (c) return ({ a, ...b } = (await Promise.all(ps))[0]); }",
+        "const f = async () => { const [{ ...a }] = await Promise.all(ps); return a; };",
+        "async function f([{ a, ...b }], c) { await Promise.all([b, c]); }",
+        "Promise.all(ps).then(async ([{ a, ...b }, c]) => b);",
+        "async function f() { for await (const [a, { ...b }] of await Promise.all(ps)) use(a, b); }",
+        "async function f() { for await (const x of xs) use(x); }",
+        "class A { async m() { const [{ ...a }] = await Promise.all([this.x, super.y]); } }",
+    ];
+
+    let options = TransformOptions::from_target("es2016").unwrap();
+    assert_snapshot(
+        "async_destructuring",
+        &print_cases("", cases, |case| test(case, &options).unwrap()),
+    );
+}
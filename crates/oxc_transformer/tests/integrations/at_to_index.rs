@@ -0,0 +1,54 @@
+use oxc_span::SourceType;
+use oxc_transformer::{AtToIndexOptions, TransformOptions};
+
+use crate::{assert_snapshot, codegen, print_case, test};
+
+#[test]
+fn at_to_index() {
+    let cases = [
+        "x = arr.at(0); y = arr.at(2);",
+        "x = arr.at(-1); y = (arr).at(-(2));",
+        "x = arr.at(-0);",
+        // The receiver is evaluated once.
+        "x = getArr().at(-1);",
+        "x = obj.list.at(-1); y = obj.list.at(1);",
+        "x = 'abc'.at(-1); y = `${a}`.at(0);",
+        "x = [1, 2, 3].at(-1);",
+        "x = arr.at(0).at(-1);",
+        // Not transformed.
+        "x = arr.at(i); y = arr.at(1.5); z = arr.at(-i);",
+        "x = arr?.at(0); y = arr.at?.(0); z = arr.at(...args); w = arr.at();",
+        "class C extends B { m() { return super.at(0); } }",
+        "x = arr.at(0, 1);",
+        // `arr[0]()` would call the element with `arr` as `this`.
+        "arr.at(0)(); (arr.at(-1))(); arr.at(0)`tag`;",
+        // Lowered optional calls read the callee into a temp var first.
+        "arr.at(0)?.();",
+    ];
+    let polyfill_cases =
+        ["x = arr.at(i); y = str.at(j);", "x = [1, 2].at(i);", "x = 'abc'.at(i); y = arr.at(-1);"];
+
+    // `.at()` is part of ES2022, and supported by Chrome 92.
+    for target in ["es2022", "chrome92"] {
+        let mut options = TransformOptions::from_target(target).unwrap();
+        options.plugins.at_to_index = Some(AtToIndexOptions::default());
+        for case in cases.iter().chain(&polyfill_cases) {
+            assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())), "{target}");
+        }
+    }
+
+    let mut options = TransformOptions::from_target("chrome80").unwrap();
+    options.plugins.at_to_index = Some(AtToIndexOptions::default());
+    let mut polyfill_options = options.clone();
+    polyfill_options.plugins.at_to_index =
+        Some(AtToIndexOptions { polyfill: Some("core-js/modules/{name}.js".to_string()) });
+
+    let snapshot = cases
+        .into_iter()
+        .map(|case| (case, &options))
+        .chain(polyfill_cases.into_iter().map(|case| (case, &polyfill_options)))
+        .enumerate()
+        .map(|(i, (case, options))| print_case(&i.to_string(), case, &test(case, options).unwrap()))
+        .collect::<String>();
+    assert_snapshot("at_to_index", &snapshot);
+}
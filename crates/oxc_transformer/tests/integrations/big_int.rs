@@ -0,0 +1,67 @@
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+const LITERAL: &str =
+    "Big integer literals are not available in the configured target environment.";
+
+fn errors(source: &str, target: &str) -> Vec<String> {
+    let options = TransformOptions::from_target(target).unwrap();
+    match test(source, &options) {
+        Ok(_) => vec![],
+        Err(errors) => errors.into_iter().map(|error| error.message.to_string()).collect(),
+    }
+}
+
+fn global(name: &str) -> String {
+    format!("`{name}` is not available in the configured target environment.")
+}
+
+#[test]
+fn reports_literals() {
+    assert_eq!(errors("x = 1n / 2n;", "es2019"), [LITERAL, LITERAL]);
+    assert_eq!(errors("x = 2n ** 64n;", "es2019"), [LITERAL, LITERAL]);
+    assert_eq!(errors("x = typeof 1n;", "es2019"), [LITERAL]);
+    assert_eq!(errors("x = `${1n}n`;", "es2019"), [LITERAL]);
+    assert_eq!(errors("x = { 1n: a };", "es2019"), [LITERAL]);
+}
+
+#[test]
+fn reports_globals() {
+    assert_eq!(errors("x = BigInt(value);", "es2019"), [global("BigInt")]);
+    assert_eq!(errors("x = BigInt.asIntN(64, value);", "es2019"), [global("BigInt")]);
+    assert_eq!(
+        errors("x = BigInt(1) * BigInt(2);", "es2019"),
+        [global("BigInt"), global("BigInt")]
+    );
+    assert_eq!(errors("x = new BigInt64Array(2);", "es2019"), [global("BigInt64Array")]);
+    assert_eq!(
+        errors("x = value instanceof BigUint64Array;", "es2019"),
+        [global("BigUint64Array")]
+    );
+    assert_eq!(errors("x = BigInt(`${1n}`);", "es2019"), [global("BigInt"), LITERAL.to_string()]);
+}
+
+#[test]
+fn skips_feature_detection_and_local_bindings() {
+    assert!(errors("x = typeof BigInt === 'function';", "es2019").is_empty());
+    assert!(errors("x = typeof BigInt === 'function' ? BigInt(y) : y;", "es2019").is_empty());
+    assert!(errors("x = typeof BigInt === 'undefined' ? y : BigInt(y);", "es2019").is_empty());
+    assert!(errors("x = typeof BigInt !== 'undefined' && BigInt(y);", "es2019").is_empty());
+    assert!(errors("if (typeof BigInt64Array !== 'undefined') { x = new BigInt64Array(1); } else { x = []; }", "es2019").is_empty());
+    // Guarded by a test of another global.
+    assert_eq!(
+        errors("x = typeof BigInt64Array === 'function' ? BigInt(y) : y;", "es2019"),
+        [global("BigInt")]
+    );
+    assert!(errors("function f(BigInt) { return BigInt(1); }", "es2019").is_empty());
+    assert!(errors("x = window.BigInt;", "es2019").is_empty());
+}
+
+#[test]
+fn skips_supported_targets() {
+    for target in ["es2020", "chrome67", "esnext"] {
+        assert!(errors("x = BigInt(1) + 2n ** 3n; y = new BigInt64Array(1);", target).is_empty());
+    }
+    assert!(!errors("x = 1n;", "chrome66").is_empty());
+}
@@ -0,0 +1,52 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test_with_source_type};
+
+fn options(target: &str, use_define_for_class_fields: bool) -> TransformOptions {
+    let mut options = TransformOptions::from_target(target).unwrap();
+    options.typescript.use_define_for_class_fields = use_define_for_class_fields;
+    options
+}
+
+#[test]
+fn use_define_for_class_fields() {
+    let cases = [
+        "class C { x = 1; y; }",
+        "class C { x: number; y: number = 0; declare z: number; }",
+        // Initializers run in order, so later fields can read earlier ones.
+        "class C { a = 1; b = this.a + 1; }",
+        // Fields are initialized after `super()` in derived classes.
+        "class C extends B { x = 1; }",
+        "class C extends B { x = 1; constructor(a) { foo(); super(a); bar(); } }",
+        // Computed keys are evaluated once, when the class is defined.
+        "class C { [key()] = 1; [other] = 2; }",
+        "class C { static x = 1; static y = C.x + 1; }",
+        "const C = class { x = 1; static y = 2; };",
+    ];
+
+    let snapshot = [("define", true), ("assign", false)]
+        .into_iter()
+        .map(|(name, use_define_for_class_fields)| {
+            let options = options("es2021", use_define_for_class_fields);
+            print_cases(name, cases, |case| {
+                test_with_source_type(case, SourceType::ts(), &options).unwrap()
+            })
+        })
+        .collect::<String>();
+    assert_snapshot("class_fields", &snapshot);
+}
+
+/// Public class fields are assigned even for targets which support class fields.
+#[test]
+fn use_define_for_class_fields_esnext() {
+    let source = "class C { x = 1; y; declare z: number; static w = 2; #p = 3; }";
+    assert_eq!(
+        test_with_source_type(source, SourceType::ts(), &options("esnext", false)).unwrap(),
+        "class C {\n\tconstructor() {\n\t\tthis.x = 1;\n\t}\n\tstatic {\n\t\tthis.w = 2;\n\t}\n\t#p = 3;\n}\n"
+    );
+    assert_eq!(
+        test_with_source_type(source, SourceType::ts(), &options("esnext", true)).unwrap(),
+        "class C {\n\tx = 1;\n\ty;\n\tstatic w = 2;\n\t#p = 3;\n}\n"
+    );
+}
@@ -0,0 +1,67 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.coalesce_template_literals = true;
+    options
+}
+
+#[test]
+fn folds_literals() {
+    assert_transform("x = `a${'b'}c`;", "x = 'abc';", &options());
+    assert_transform("x = `a${1}b${1.5}c${10n}d`;", "x = 'a1b1.5c10d';", &options());
+    assert_transform("x = `${true}${false}${null}`;", "x = 'truefalsenull';", &options());
+    assert_transform("x = `a${1}b${c}d${2}`;", "x = `a1b${c}d2`;", &options());
+    assert_transform("x = `${c}${1}${d}`;", "x = `${c}1${d}`;", &options());
+    assert_transform("x = ``;", "x = '';", &options());
+    assert_transform("x = `abc`;", "x = 'abc';", &options());
+}
+
+#[test]
+fn flattens_nested_templates() {
+    assert_transform("x = `a${`b${c}d`}e`;", "x = `ab${c}de`;", &options());
+    assert_transform("x = `a${`b${`c${1}`}`}`;", "x = 'abc1';", &options());
+    assert_transform("x = `${`${a}`}${`${b}`}`;", "x = `${a}${b}`;", &options());
+}
+
+#[test]
+fn escapes_strings() {
+    assert_transform(r"x = `a${'`'}b`;", r"x = 'a`b';", &options());
+    assert_transform(r"x = `a${'`'}${b}`;", r"x = `a\`${b}`;", &options());
+    assert_transform(r"x = `${'\\'}${b}`;", r"x = `\\${b}`;", &options());
+    assert_transform(r"x = `${'\n\r'}${b}`;", r"x = `\n\r${b}`;", &options());
+    assert_transform(r"x = `${'${a}'}${b}`;", r"x = `\${a}${b}`;", &options());
+    assert_transform(r"x = `$${'{a}'}${b}`;", r"x = `$\{a}${b}`;", &options());
+    assert_transform(r"x = `${'$'}{a}${b}`;", r"x = `$\{a}${b}`;", &options());
+    assert_transform(r"x = `\$${'{a}'}${b}`;", r"x = `\${a}${b}`;", &options());
+    assert_transform(r"x = `${'$'}${b}`;", r"x = `$${b}`;", &options());
+}
+
+#[test]
+fn keeps_raw_text_of_quasis() {
+    assert_transform(r"x = `\x41${1}\u{42}${c}`;", r"x = `\x411\u{42}${c}`;", &options());
+    assert_transform(r"x = `\n${1}`;", r"x = '\n1';", &options());
+}
+
+#[test]
+fn keeps_other_interpolations() {
+    assert_unchanged("x = `a${b}c`;", &options());
+    assert_unchanged("x = `a${b + 1}c${f()}`;", &options());
+    assert_unchanged("x = `a${-1}`;", &options());
+    assert_unchanged("x = `a${/b/}`;", &options());
+}
+
+#[test]
+fn keeps_tagged_templates() {
+    assert_unchanged("x = tag`a${1}b`;", &options());
+    assert_unchanged("x = tag`abc`;", &options());
+    assert_transform("x = tag`a${`b${1}`}`;", "x = tag`a${'b1'}`;", &options());
+}
+
+#[test]
+fn keeps_expression_statements() {
+    assert_unchanged("`abc`;", &options());
+    assert_transform("`a${1}`;", "`a1`;", &options());
+}
@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.dedupe_export_names = true;
+    options
+}
+
+/// The messages of the errors reported by the transformer for `source`.
+/// The errors reported by the parser for the duplicate exports in `source` are ignored.
+fn errors(source: &str, source_type: SourceType) -> Vec<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &options())
+        .build_with_scoping(scoping, &mut program);
+    ret.errors.into_iter().map(|error| error.message.to_string()).collect()
+}
+
+#[test]
+fn removes_identical_exports() {
+    assert_transform("export { x }; export { x }; let x;", "export { x }; let x;", &options());
+    assert_transform("export { x, y, x }; let x, y;", "export { x, y }; let x, y;", &options());
+    assert_transform(
+        "export { x as y }; export { x as y }; let x;",
+        "export { x as y }; let x;",
+        &options(),
+    );
+    assert_transform("export let x; export { x };", "export let x;", &options());
+    assert_transform("export { x }; export function x() {}", "export function x() {}", &options());
+    assert_transform("export { x }; export class x {}", "export class x {}", &options());
+    assert_transform(
+        "export const { a, b: [c] } = o; export { c };",
+        "export const { a, b: [c] } = o;",
+        &options(),
+    );
+}
+
+#[test]
+fn removes_identical_re_exports() {
+    assert_transform(
+        "export { x } from 'm'; export { x } from 'm';",
+        "export { x } from 'm';",
+        &options(),
+    );
+    assert_transform(
+        "export { x, y } from 'm'; export { y as z, x } from 'm';",
+        "export { x, y } from 'm'; export { y as z } from 'm';",
+        &options(),
+    );
+    assert_transform(
+        "export * as ns from 'm'; export * as ns from 'm';",
+        "export * as ns from 'm';",
+        &options(),
+    );
+    assert_transform(
+        "export { 'a-b' } from 'm'; export { 'a-b' } from 'm';",
+        "export { 'a-b' } from 'm';",
+        &options(),
+    );
+}
+
+#[test]
+fn removes_identical_default_exports() {
+    assert_transform(
+        "export default function f() {} export { f as default };",
+        "export default function f() {}",
+        &options(),
+    );
+    assert_transform(
+        "export { x as default }; export { x as default }; let x;",
+        "export { x as default }; let x;",
+        &options(),
+    );
+}
+
+#[test]
+fn leaves_distinct_exports() {
+    assert_unchanged("export { x, x as y }; let x;", &options());
+    assert_unchanged("export { x } from 'm'; export { x as y } from 'm';", &options());
+    assert_unchanged("export * from 'm'; export * from 'n'; export { x } from 'm';", &options());
+    assert_unchanged("export {}; export { x }; let x;", &options());
+}
+
+#[test]
+fn reports_duplicate_exports() {
+    for source in [
+        "export { x as y }; export { z as y }; let x, z;",
+        "export let x; export { y as x }; let y;",
+        "export { x } from 'm'; export { x } from 'n';",
+        "export { x } from 'm'; export { x }; let x;",
+        "export { y as x } from 'm'; export { x } from 'm';",
+        "export * as ns from 'm'; export * as ns from 'n';",
+        "export * as ns from 'm'; export { ns } from 'm';",
+        "export default x; export { x as default }; let x;",
+        "export default function() {} export { f as default }; function f() {}",
+        "export default 1; export default 1;",
+    ] {
+        let errors = errors(source, SourceType::mjs());
+        assert_eq!(errors.len(), 1, "{source}");
+        assert!(errors[0].starts_with("Duplicated export"), "{source}");
+    }
+    assert_eq!(
+        errors("export { a as x, b as x, c as x }; let a, b, c;", SourceType::mjs()),
+        ["Duplicated export 'x'", "Duplicated export 'x'"],
+    );
+}
+
+#[test]
+fn ignores_type_exports() {
+    for source in [
+        "export type { x }; export { x }; const x = 1; type x = number;",
+        "export { type x, x }; const x = 1; type x = number;",
+        "export type x = number; export const x = 1;",
+        "export interface x {} export { y as x }; const y = 1;",
+        "export type * as ns from 'm'; export * as ns from 'n';",
+        "export declare const x: number; export { y as x }; const y = 1;",
+    ] {
+        assert_eq!(errors(source, SourceType::ts()), Vec::<String>::new(), "{source}");
+    }
+}
+
+#[test]
+fn scripts_are_untouched() {
+    let source = "var x;";
+    assert_eq!(errors(source, SourceType::cjs()), Vec::<String>::new());
+}
@@ -0,0 +1,202 @@
+use oxc_span::SourceType;
+use oxc_transformer::{DestructureMemberChainsOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(threshold: usize) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.destructure_member_chains = Some(DestructureMemberChainsOptions { threshold });
+    options
+}
+
+fn destructures(source: &str, expected: &str) {
+    assert_eq!(
+        test(source, &options(2)).unwrap(),
+        codegen(expected, SourceType::default()),
+        "{source}"
+    );
+}
+
+fn leaves_unchanged(source: &str) {
+    destructures(source, source);
+}
+
+#[test]
+fn destructures_repeated_reads() {
+    destructures(
+        "
+        function listen(config) {
+            const url = config.server.host + ':' + config.server.port;
+            start(url, config.server.port);
+        }
+        ",
+        "
+        function listen(config) {
+            const { host, port } = config.server;
+            const url = host + ':' + port;
+            start(url, port);
+        }
+        ",
+    );
+    destructures(
+        "
+        const options = load();
+        console.log(options.name, options.version);
+        ",
+        "
+        const options = load();
+        const { name, version } = options;
+        console.log(name, version);
+        ",
+    );
+}
+
+#[test]
+fn respects_threshold() {
+    let source = "function f(config) { return config.a.b + config.a.c + config.a.d; }";
+    assert_eq!(test(source, &options(4)).unwrap(), codegen(source, SourceType::default()));
+    assert_eq!(
+        test(source, &options(3)).unwrap(),
+        codegen(
+            "function f(config) { const { b, c, d } = config.a; return b + c + d; }",
+            SourceType::default()
+        ),
+    );
+    leaves_unchanged("function f(config) { return config.a.b; }");
+}
+
+#[test]
+fn stops_at_mutations() {
+    // Property assignment between the reads.
+    destructures(
+        "
+        function f(config) {
+            let x = config.a.b + config.a.c;
+            config.a.b = 1;
+            return config.a.b + config.a.c;
+        }
+        ",
+        "
+        function f(config) {
+            const { b, c } = config.a;
+            let x = b + c;
+            config.a.b = 1;
+            return config.a.b + config.a.c;
+        }
+        ",
+    );
+    // Calls may mutate any object, and the first read is after the call in its statement.
+    leaves_unchanged("function f(config) { update(config), log(config.a.b, config.a.c); }");
+    leaves_unchanged("function f(config) { x = config.a.b; update(); y = config.a.c; }");
+    leaves_unchanged("function f(config) { x = config.a.b; delete config.a.c; y = config.a.c; }");
+    leaves_unchanged("function f(config) { x = config.a.b; config.a.c++; y = config.a.c; }");
+    leaves_unchanged("async function f(config) { x = config.a.b; await 0; y = config.a.c; }");
+    leaves_unchanged("function f(config) { x = config.a.b; [config.a.c] = [1]; y = config.a.c; }");
+    // The mutation in the loop happens before the reads of the next iteration.
+    leaves_unchanged(
+        "function f(config) { x = config.a.b; while (x) { y = config.a.c; config.a.c = 0; } }",
+    );
+    leaves_unchanged("function f(config, it) { x = config.a.b; for (y of it) z = config.a.c; }");
+}
+
+#[test]
+fn skips_reassigned_and_unknown_objects() {
+    leaves_unchanged("function f(config) { config = {}; return config.a + config.b; }");
+    leaves_unchanged("function f() { return config.a + config.b; }");
+    leaves_unchanged("function f() { return this.a + this.b; }");
+}
+
+/// The declaration would read the object before it's initialized.
+#[test]
+fn skips_objects_declared_by_the_first_statement() {
+    leaves_unchanged(
+        "function f() { const config = { a: 1 }, x = config.a + config.a; return x; }",
+    );
+    leaves_unchanged("function f() { var config = { a: 1 }, x = config.a + config.b; return x; }");
+    destructures(
+        "function f() { const config = { a: 1 }; const x = config.a + config.a; return x; }",
+        "function f() { const config = { a: 1 }; const { a } = config; const x = a + a; return x; }",
+    );
+}
+
+#[test]
+fn skips_computed_and_optional_keys() {
+    leaves_unchanged("function f(config, k) { return config[k] + config[k]; }");
+    leaves_unchanged("function f(config) { return config?.a + config?.b; }");
+    leaves_unchanged("function f(config) { return config.a?.b + config.a?.c; }");
+    destructures(
+        "function f(config, k) { return config.a[k] + config.a[k]; }",
+        "function f(config, k) { const { a } = config; return a[k] + a[k]; }",
+    );
+}
+
+#[test]
+fn skips_non_reads() {
+    // `this` is `config.a` in the calls.
+    leaves_unchanged("function f(config) { return config.a.b() + (config.a.c)(); }");
+    leaves_unchanged("function f(config) { return config.a.b`` + config.a.c``; }");
+    destructures(
+        "function f(config) { log(config.a.b, config.a.c.trim()); }",
+        "function f(config) { const { b, c } = config.a; log(b, c.trim()); }",
+    );
+}
+
+#[test]
+fn skips_conditional_first_reads() {
+    leaves_unchanged("function f(config, x) { if (x) return config.a.b + config.a.c; }");
+    leaves_unchanged("function f(config, x) { return x && config.a.b + config.a.c; }");
+    // An error thrown by the destructuring would no longer be caught.
+    destructures(
+        "function f(config) { try { x = config.a.b; } catch {} return config.a.c + config.a.d; }",
+        "function f(config) { try { x = config.a.b; } catch {} const { c, d } = config.a; return c + d; }",
+    );
+    // Later reads may be conditional.
+    destructures(
+        "function f(config, x) { let y = config.a.b; if (x) y = config.a.c; return y; }",
+        "function f(config, x) { const { b, c } = config.a; let y = b; if (x) y = c; return y; }",
+    );
+}
+
+#[test]
+fn skips_reads_in_nested_functions() {
+    destructures(
+        "
+        function f(config) {
+            const x = config.a.b + config.a.c;
+            return () => config.a.b;
+        }
+        ",
+        "
+        function f(config) {
+            const { b, c } = config.a;
+            const x = b + c;
+            return () => config.a.b;
+        }
+        ",
+    );
+    leaves_unchanged("function f(config) { return () => config.a.b + config.a.c; }");
+    destructures(
+        "function f(config) { return () => { return config.a.b + config.a.c; }; }",
+        "function f(config) { return () => { const { b, c } = config.a; return b + c; }; }",
+    );
+}
+
+#[test]
+fn skips_unavailable_names() {
+    // `c` is bound elsewhere, `default` is a keyword, `x` is a global.
+    destructures(
+        "
+        function f(config) {
+            return config.a.b + config.a.c + config.a.default + config.a.x + config.a.b;
+        }
+        function g(c) { return c + x; }
+        ",
+        "
+        function f(config) {
+            const { b } = config.a;
+            return b + config.a.c + config.a.default + config.a.x + b;
+        }
+        function g(c) { return c + x; }
+        ",
+    );
+}
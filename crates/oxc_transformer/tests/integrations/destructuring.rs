@@ -0,0 +1,35 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test};
+
+#[test]
+fn destructuring() {
+    let cases = [
+        "var [a, b] = c;",
+        // Holes.
+        "var [, a, , b] = c;",
+        "var [,] = c;",
+        // The iterable is read once, before the elements.
+        "let [a, ...b] = getIterable();",
+        // Defaults can depend on earlier bindings.
+        "const [a = 1, b = a] = c;",
+        "const { a = 1, b = a } = c;",
+        // Nested patterns.
+        "var [[a, [b]], { c: [d = 1] }] = e;",
+        "var { a: { b: [c, ...d] }, e: { f = c } = {} } = g;",
+        // Computed keys are evaluated after the source, in order.
+        "const { [key()]: a, [a]: b, 'c-d': c, 1: d } = getObj();",
+        "const {} = a;",
+        // Object rest is lowered first.
+        "const [{ a, ...b }, c] = d;",
+        "const { a: [b], ...c } = d;",
+        // Other declarators, and other positions, are left as they are.
+        "var a = 1, [b] = c, d;",
+        "for (var [a, b] = c; a;) {}",
+        "for (const [a, b] of c) {}",
+        "export const [a] = b;",
+    ];
+
+    let options = TransformOptions::from_target("chrome40").unwrap();
+    assert_snapshot("destructuring", &print_cases("", cases, |case| test(case, &options).unwrap()));
+}
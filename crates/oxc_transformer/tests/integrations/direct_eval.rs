@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{DirectEvalOptions, TransformOptions, Transformer};
+
+use crate::codegen;
+
+/// Transforms `source_text` as a script, as only sloppy mode code can shadow `eval`.
+/// Returns the code and the messages of the diagnostics.
+fn transform(source_text: &str, indirect: bool) -> (String, Vec<String>) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::cjs()).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let mut options = TransformOptions::default();
+    options.plugins.direct_eval = Some(DirectEvalOptions { indirect });
+    let ret = Transformer::new(&allocator, Path::new(""), &options)
+        .build_with_scoping(scoping, &mut program);
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    (code, ret.errors.into_iter().map(|error| error.message.to_string()).collect())
+}
+
+fn converts(source: &str, expected: &str) {
+    let (code, errors) = transform(source, true);
+    assert_eq!(code, codegen(expected, SourceType::cjs()), "{source}");
+    assert!(!errors.is_empty(), "{source}");
+    assert!(errors.iter().all(|error| error.contains("evaluates the code in the global scope")));
+}
+
+fn leaves_unchanged(source: &str) {
+    for indirect in [false, true] {
+        let (code, errors) = transform(source, indirect);
+        assert_eq!(code, codegen(source, SourceType::cjs()), "{source}");
+        assert!(errors.is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn reports_direct_eval() {
+    for (source, count) in [
+        ("eval('x');", 1),
+        ("function f(code) { return eval(code); }", 1),
+        ("(eval)('x');", 1),
+        ("eval(eval('x'));", 2),
+        ("const f = () => eval('this');", 1),
+    ] {
+        let (code, errors) = transform(source, false);
+        assert_eq!(code, codegen(source, SourceType::cjs()), "{source}");
+        assert_eq!(errors.len(), count, "{source}");
+        assert!(errors.iter().all(|error| error.contains("Direct `eval`")), "{source}");
+    }
+}
+
+#[test]
+fn converts_to_indirect_eval() {
+    converts("eval('x');", "(0, eval)('x');");
+    converts(
+        "function f(code) { return eval(code); }",
+        "function f(code) { return (0, eval)(code); }",
+    );
+    converts("(eval)('x');", "(0, eval)('x');");
+    converts("eval(eval('x'));", "(0, eval)((0, eval)('x'));");
+    converts("eval(...args);", "(0, eval)(...args);");
+}
+
+#[test]
+fn indirect_eval_is_untouched() {
+    leaves_unchanged("(0, eval)('x');");
+    leaves_unchanged("window.eval('x');");
+    leaves_unchanged("globalThis.eval('x');");
+    leaves_unchanged("eval?.('x');");
+    leaves_unchanged("const e = eval; e('x');");
+}
+
+#[test]
+fn shadowed_eval_is_untouched() {
+    leaves_unchanged("function f(eval) { return eval('x'); }");
+    leaves_unchanged("var eval = (x) => x; eval('x');");
+    leaves_unchanged("try {} catch (eval) { eval('x'); }");
+}
@@ -0,0 +1,135 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::{codegen, test};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.dynamic_requires = true;
+    options
+}
+
+fn converts(source: &str, expected: &str) {
+    assert_eq!(test(source, &options()), Ok(codegen(expected, SourceType::mjs())), "{source}");
+}
+
+fn leaves_unchanged(source: &str) {
+    converts(source, source);
+}
+
+#[test]
+fn conditional_requires() {
+    converts(
+        "const x = cond ? require('a') : require('b');",
+        "const x = cond ? await import('a') : await import('b');",
+    );
+    converts("const x = cond && require('a');", "const x = cond && await import('a');");
+    converts("if (cond) require('a');", "if (cond) await import('a');");
+    converts("cond && require('a');", "cond && await import('a');");
+    converts("try { require('a'); } catch {}", "try { await import('a'); } catch {}");
+}
+
+#[test]
+fn destructured_requires() {
+    converts(
+        "const { a, b } = cond ? require('x') : require('y');",
+        "const { a, b } = cond ? await import('x') : await import('y');",
+    );
+    converts(
+        "const { a } = require(cond ? 'x' : 'y');",
+        "const { a } = await import(cond ? 'x' : 'y');",
+    );
+}
+
+#[test]
+fn dynamic_specifiers() {
+    converts(
+        "const locale = require(`./locale/${lang}.js`);",
+        "const locale = await import(`./locale/${lang}.js`);",
+    );
+    converts("const m = require(name);", "const m = await import(name);");
+    converts("const m = require(name).default;", "const m = (await import(name)).default;");
+}
+
+#[test]
+fn requires_in_expressions() {
+    converts("foo(require('a'));", "foo(await import('a'));");
+    converts("const a = require('a')();", "const a = (await import('a'))();");
+    converts("const a = [require('a')];", "const a = [await import('a')];");
+}
+
+#[test]
+fn async_functions() {
+    converts(
+        "async function f() { return require('a'); }",
+        "async function f() { return await import('a'); }",
+    );
+    converts(
+        "const f = async () => cond ? require('a') : null;",
+        "const f = async () => cond ? await import('a') : null;",
+    );
+    converts(
+        "class C { async m() { const a = require('a'); } }",
+        "class C { async m() { const a = await import('a'); } }",
+    );
+}
+
+#[test]
+fn static_top_level_requires_are_untouched() {
+    leaves_unchanged("const fs = require('fs');");
+    leaves_unchanged("const { join } = require('path');");
+    leaves_unchanged("require('polyfill');");
+    leaves_unchanged("const a = require(`a`);");
+    leaves_unchanged("const b = require('a').b;");
+    leaves_unchanged("export const a = require('a');");
+}
+
+#[test]
+fn other_calls_are_untouched() {
+    leaves_unchanged("function f(require) { return require('a'); }");
+    leaves_unchanged("const require = createRequire(import.meta.url); cond && require('a');");
+    leaves_unchanged("const x = cond ? require() : require('a', 'b');");
+    leaves_unchanged("cond && require(...args);");
+    leaves_unchanged("cond && require?.('a');");
+    leaves_unchanged("cond && require.resolve('a');");
+}
+
+#[test]
+fn reports_requires_where_await_is_not_allowed() {
+    for source in [
+        "function f() { return require('a'); }",
+        "const f = () => cond ? require('a') : null;",
+        "async function f(a = require('a')) {}",
+        "async function f() { function g() { require('a'); } }",
+        "class C { a = require('a'); }",
+        "class C { static { require('a'); } }",
+        "function* g() { yield require('a'); }",
+    ] {
+        let errors = test(source, &options()).unwrap_err();
+        assert_eq!(errors.len(), 1, "{source}");
+        assert!(errors[0].message.contains("was not converted to `import()`"), "{source}");
+    }
+}
+
+#[test]
+fn scripts_are_untouched() {
+    let source = "const x = cond ? require('a') : require('b');";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &options())
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    assert_eq!(code, codegen(source, SourceType::cjs()));
+}
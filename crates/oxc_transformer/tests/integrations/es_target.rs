@@ -3,12 +3,10 @@ use std::str::FromStr;
 use oxc_span::SourceType;
 use oxc_transformer::{ESTarget, TransformOptions};
 
-use crate::{codegen, test};
+use crate::{assert_snapshot, code_or_errors, codegen, print_case, test};
 
 #[test]
 fn es_target() {
-    use std::fmt::Write;
-
     let cases = [
         ("es6", "a ** b"),
         ("es2015", "a ** b"),
@@ -34,28 +32,15 @@ fn es_target() {
         assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
     }
 
-    #[cfg_attr(miri, expect(unused_variables))]
-    let snapshot =
-        cases.into_iter().enumerate().fold(String::new(), |mut w, (i, (target, case))| {
+    let snapshot = cases
+        .into_iter()
+        .enumerate()
+        .map(|(i, (target, case))| {
             let options = TransformOptions::from_target(target).unwrap();
-            let result = match test(case, &options) {
-                Ok(code) => code,
-                Err(errors) => errors
-                    .into_iter()
-                    .map(|err| format!("{:?}", err.with_source_code(case.to_string())))
-                    .collect::<Vec<_>>()
-                    .join("\n"),
-            };
-            write!(w, "########## {i} {target}\n{case}\n----------\n{result}\n").unwrap();
-            w
-        });
-
-    #[cfg(not(miri))]
-    {
-        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
-            insta::assert_snapshot!("es_target", snapshot);
-        });
-    }
+            print_case(&format!("{i} {target}"), case, &code_or_errors(case, test(case, &options)))
+        })
+        .collect::<String>();
+    assert_snapshot("es_target", &snapshot);
 }
 
 #[test]
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use oxc_transformer::{ExpandExportStarOptions, TransformOptions};
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.expand_export_star = Some(ExpandExportStarOptions::new(Arc::new(|source| {
+        let names: &[&str] = match source {
+            "./a" => &["a", "shared", "default"],
+            "./b" => &["b", "shared", "b-c"],
+            "./c" => &["c", "local"],
+            "./d" => &["d", "default"],
+            _ => return None,
+        };
+        Some(names.iter().map(ToString::to_string).collect())
+    })));
+    options
+}
+
+#[test]
+fn expands_export_star() {
+    assert_transform("export * from './c';", "export { c, local } from './c';", &options());
+    assert_transform(
+        "export * from './c' with { type: 'js' };",
+        "export { c, local } from './c' with { type: 'js' };",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_default_export() {
+    assert_transform("export * from './a';", "export { a, shared } from './a';", &options());
+}
+
+#[test]
+fn keeps_colliding_export_stars() {
+    // `shared` is ambiguous, unless both modules re-export the same binding.
+    assert_unchanged("export * from './a'; export * from './b';", &options());
+    assert_unchanged("export * from './a'; export * from './a'; export * from './b';", &options());
+    assert_transform(
+        "export * from './a'; export * from './b'; export * from './c';",
+        "export * from './a'; export * from './b'; export { c, local } from './c';",
+        &options(),
+    );
+    // `default` is never re-exported, so it doesn't collide.
+    assert_transform(
+        "export * from './a'; export * from './d';",
+        "export { a, shared } from './a'; export { d } from './d';",
+        &options(),
+    );
+    // Local exports take precedence over colliding names.
+    assert_transform(
+        "export * from './a'; export * from './b'; export const shared = 1;",
+        "export { a } from './a'; export { b, 'b-c' } from './b'; export const shared = 1;",
+        &options(),
+    );
+}
+
+#[test]
+fn collapses_duplicate_export_stars() {
+    assert_transform(
+        "export * from './c'; export * from './c';",
+        "export { c, local } from './c';",
+        &options(),
+    );
+    assert_transform(
+        "export * from './a'; export const x = 1; export * from './a'; export * from './c';",
+        "export { a, shared } from './a'; export const x = 1; export { c, local } from './c';",
+        &options(),
+    );
+}
+
+#[test]
+fn local_exports_take_precedence() {
+    assert_transform(
+        "export * from './c'; export const local = 1;",
+        "export { c } from './c'; export const local = 1;",
+        &options(),
+    );
+    assert_transform(
+        "export * from './a'; export { x as shared } from './x'; export default 1;",
+        "export { a } from './a'; export { x as shared } from './x'; export default 1;",
+        &options(),
+    );
+    assert_transform(
+        "export * from './c'; export * as c from './x';",
+        "export { local } from './c'; export * as c from './x';",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_unresolved_modules() {
+    for source in [
+        "export * from './unknown';",
+        "export * from './c'; export * from './unknown';",
+        "export * as ns from './c';",
+    ] {
+        assert_unchanged(source, &options());
+    }
+}
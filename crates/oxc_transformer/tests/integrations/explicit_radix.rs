@@ -0,0 +1,61 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ExplicitRadixOptions, TransformOptions};
+
+use crate::{assert_transform, assert_unchanged, codegen, test};
+
+fn options(radix: u32) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.explicit_radix = Some(ExplicitRadixOptions { radix });
+    options
+}
+
+#[test]
+fn adds_radix() {
+    assert_transform("x = parseInt(input);", "x = parseInt(input, 10);", &options(10));
+    assert_transform("x = (parseInt)(a.b);", "x = parseInt(a.b, 10);", &options(10));
+    assert_transform("x = Number.parseInt('08');", "x = Number.parseInt('08', 10);", &options(10));
+    assert_transform(
+        "x = parseInt(parseInt(a));",
+        "x = parseInt(parseInt(a, 10), 10);",
+        &options(10),
+    );
+}
+
+#[test]
+fn configurable_radix() {
+    assert_eq!(
+        test("x = parseInt(input);", &options(16)),
+        Ok(codegen("x = parseInt(input, 16);", SourceType::mjs()))
+    );
+}
+
+#[test]
+fn validates_radix() {
+    let parse = |value| serde_json::from_value::<ExplicitRadixOptions>(value).map(|o| o.radix);
+    assert_eq!(parse(serde_json::json!({})).unwrap(), 10);
+    assert_eq!(parse(serde_json::json!({ "radix": 2 })).unwrap(), 2);
+    assert_eq!(parse(serde_json::json!({ "radix": 36 })).unwrap(), 36);
+    for radix in [0, 1, 37, 100] {
+        let err = parse(serde_json::json!({ "radix": radix })).unwrap_err();
+        assert!(err.to_string().contains("radix must be between 2 and 36"), "{err}");
+    }
+}
+
+#[test]
+fn keeps_calls_with_radix() {
+    assert_unchanged("x = parseInt(input, 16);", &options(10));
+    assert_unchanged("x = parseInt(input, undefined);", &options(10));
+    assert_unchanged("x = Number.parseInt(input, 8);", &options(10));
+    assert_unchanged("x = parseInt(...args);", &options(10));
+    assert_unchanged("x = parseInt();", &options(10));
+    assert_unchanged("x = parseFloat(input);", &options(10));
+}
+
+#[test]
+fn keeps_other_functions() {
+    assert_unchanged("function f(parseInt) { return parseInt(input); }", &options(10));
+    assert_unchanged("import { parseInt } from 'x'; x = parseInt(input);", &options(10));
+    assert_unchanged("const Number = {}; x = Number.parseInt(input);", &options(10));
+    assert_unchanged("x = obj.parseInt(input);", &options(10));
+    assert_unchanged("x = Number['parseInt'](input);", &options(10));
+}
@@ -0,0 +1,114 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, FeatureGuardsOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options_for(query: &str, features: &[(&str, bool)]) -> TransformOptions {
+    let mut options = TransformOptions {
+        env: EnvOptions::from_browserslist_query(query).unwrap(),
+        ..TransformOptions::default()
+    };
+    options.plugins.feature_guards = Some(FeatureGuardsOptions {
+        features: features
+            .iter()
+            .map(|(name, available)| ((*name).to_string(), *available))
+            .collect(),
+    });
+    options
+}
+
+fn folds(options: &TransformOptions, source: &str, expected: &str) {
+    assert_eq!(test(source, options), Ok(codegen(expected, SourceType::mjs())), "{source}");
+}
+
+#[test]
+fn folds_bigint_guards() {
+    let options = options_for("chrome 100", &[("BigInt", true)]);
+    folds(&options, "typeof BigInt !== 'undefined'", "true");
+    folds(&options, "typeof BigInt != 'undefined'", "true");
+    folds(&options, "typeof BigInt === 'undefined'", "false");
+    folds(&options, "'undefined' == typeof BigInt", "false");
+    // The type of an available global is not known.
+    folds(&options, "typeof BigInt === 'function'", "typeof BigInt === 'function'");
+    folds(
+        &options,
+        "const big = typeof BigInt !== 'undefined' ? BigInt(value) : value;",
+        "const big = true ? BigInt(value) : value;",
+    );
+
+    let options = options_for("chrome 100", &[("BigInt", false)]);
+    folds(&options, "typeof BigInt !== 'undefined'", "false");
+    folds(&options, "typeof BigInt === 'function'", "false");
+    folds(&options, "typeof BigInt !== 'function'", "true");
+    // The branch which uses the feature is kept for dead code elimination.
+    folds(
+        &options,
+        "if (typeof BigInt === 'function') { use(BigInt(1)); } else { use(1); }",
+        "if (false) { use(BigInt(1)); } else { use(1); }",
+    );
+}
+
+#[test]
+fn bigint_follows_the_target() {
+    // Chrome 60 doesn't support BigInt.
+    let options = options_for("chrome 60", &[]);
+    folds(&options, "typeof BigInt === 'undefined'", "true");
+    // An explicit feature takes precedence over the target.
+    let options = options_for("chrome 60", &[("BigInt", true)]);
+    folds(&options, "typeof BigInt === 'undefined'", "false");
+    // Being supported by the target is not enough, the global may still be missing at runtime.
+    let options = options_for("chrome 100", &[]);
+    folds(&options, "typeof BigInt === 'undefined'", "typeof BigInt === 'undefined'");
+}
+
+#[test]
+fn folds_intl_guards() {
+    let options = options_for("chrome 100", &[("Intl", true), ("Intl.Segmenter", false)]);
+    folds(&options, "typeof Intl.Segmenter !== 'undefined'", "false");
+    folds(&options, "typeof Intl.Segmenter === 'function'", "false");
+    folds(
+        &options,
+        "typeof Intl.DateTimeFormat !== 'undefined'",
+        "typeof Intl.DateTimeFormat !== 'undefined'",
+    );
+    folds(
+        &options,
+        "typeof Intl['Segmenter'] !== 'undefined'",
+        "typeof Intl['Segmenter'] !== 'undefined'",
+    );
+}
+
+#[test]
+fn folds_combined_guards() {
+    let options =
+        options_for("chrome 100", &[("Intl", true), ("Intl.Segmenter", true), ("BigInt", false)]);
+    folds(
+        &options,
+        "if (typeof Intl !== 'undefined' && typeof Intl.Segmenter !== 'undefined') segment();",
+        "if (true) segment();",
+    );
+    folds(&options, "(typeof Intl === 'undefined') || other", "other");
+    folds(&options, "!(typeof BigInt !== 'undefined')", "true");
+    folds(&options, "typeof BigInt !== 'undefined' && BigInt(1)", "false && BigInt(1)");
+    folds(&options, "other && typeof BigInt !== 'undefined'", "other && false");
+    folds(&options, "typeof BigInt !== 'undefined' ?? other", "false ?? other");
+
+    let options = options_for("chrome 100", &[("Intl.Segmenter", false)]);
+    folds(
+        &options,
+        "typeof Intl !== 'undefined' && typeof Intl.Segmenter === 'function'",
+        "typeof Intl !== 'undefined' && false",
+    );
+}
+
+#[test]
+fn ignores_shadowed_globals() {
+    let options = options_for("chrome 100", &[("BigInt", false), ("Intl.Segmenter", false)]);
+    for source in [
+        "function f(BigInt) { return typeof BigInt !== 'undefined'; }",
+        "const Intl = {}; typeof Intl.Segmenter !== 'undefined';",
+        "typeof globalThis.BigInt !== 'undefined';",
+    ] {
+        folds(&options, source, source);
+    }
+}
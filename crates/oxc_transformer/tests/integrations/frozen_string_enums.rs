@@ -0,0 +1,42 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test_with_source_type};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.typescript.frozen_string_enums = true;
+    options
+}
+
+#[test]
+fn frozen_string_enums() {
+    let cases = [
+        "enum Color { Red = 'RED', Green = 'GREEN' }",
+        "export enum Color { Red = 'RED', Green = 'GREEN' }",
+        "function f() { enum Color { Red = 'RED' } return Color.Red; }",
+        "const enum Color { Red = 'RED' }",
+        "declare enum Color { Red = 'RED' }",
+        // Members can refer to earlier members and other enums.
+        "enum A { X = 'x', Y = X + 'y', Z = `${Y}z` } enum B { X = A.X }",
+        "enum E { 'a-b' = 'a', __proto__ = 'b', 'c' = 'c' }",
+        // Numeric members need a reverse mapping, so these enums are transformed as usual.
+        "enum E { A = 'a', B = 1 }",
+        "enum E { A, B }",
+        "enum E {}",
+        // Members which can't be evaluated at build time.
+        "enum E { A = 'a', B = 'b'.repeat(2) }",
+        "declare const x: string; enum E { A = x }",
+        "enum E { A = 'a', B = E.A }",
+        // Merged declarations add members to the same object.
+        "enum E { A = 'a' } enum E { B = 'b' }",
+        "enum E { A = 'a' } namespace E { export const b = 'b'; }",
+    ];
+
+    assert_snapshot(
+        "frozen_string_enums",
+        &print_cases("", cases, |case| {
+            test_with_source_type(case, SourceType::ts(), &options()).unwrap()
+        }),
+    );
+}
@@ -0,0 +1,29 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, codegen, print_cases, test};
+
+#[test]
+fn global_this() {
+    let cases = [
+        "const g = globalThis;",
+        // The helper is imported once.
+        "globalThis.fetch = fetch; globalThis.process.exit();",
+        "if (typeof globalThis.window !== 'undefined') {}",
+        "globalThis[key] = value;",
+        // Local bindings are not replaced.
+        "function f(globalThis) { return globalThis.x; }",
+        "{ let globalThis = {}; globalThis.x; } globalThis.y;",
+        "import { globalThis } from 'mod'; globalThis.x;",
+        "globalThis = {};",
+    ];
+
+    // `globalThis` is supported in Chrome 71.
+    let options = TransformOptions::from_target("chrome71").unwrap();
+    for case in cases {
+        assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
+    }
+
+    let options = TransformOptions::from_target("chrome70").unwrap();
+    assert_snapshot("global_this", &print_cases("", cases, |case| test(case, &options).unwrap()));
+}
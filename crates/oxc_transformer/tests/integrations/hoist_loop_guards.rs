@@ -0,0 +1,194 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged, codegen, test_with_source_type};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.hoist_loop_guards = true;
+    options
+}
+
+#[track_caller]
+fn assert_script_unchanged(source: &str) {
+    let source_type = SourceType::cjs();
+    assert_eq!(
+        test_with_source_type(source, source_type, &options()),
+        Ok(codegen(source, source_type))
+    );
+}
+
+#[test]
+fn hoists_guards() {
+    assert_transform(
+        "function f(items, enabled) {
+            for (let i = 0; i < items.length; i++) { if (enabled) { items[i].update(); } }
+        }",
+        "function f(items, enabled) {
+            if (enabled) for (let i = 0; i < items.length; i++) { items[i].update(); }
+        }",
+        &options(),
+    );
+    assert_transform(
+        "function f(queue, mode) { while (queue.length > 0) if (mode === 'sync') run(queue.pop()); }",
+        "function f(queue, mode) { if (mode === 'sync') while (queue.length > 0) run(queue.pop()); }",
+        &options(),
+    );
+    assert_transform(
+        "function f(a, b, h) { const c = !a; do { if (c && typeof b !== 'string') g(); } while (h.done); }",
+        "function f(a, b, h) { const c = !a; if (c && typeof b !== 'string') do { g(); } while (h.done); }",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_break_and_continue() {
+    assert_transform(
+        "function f(items, enabled) {
+            outer: for (let i = 0, n = items.length; i < n; i += 1) {
+                if (enabled) { if (items[i]) continue outer; break; }
+            }
+        }",
+        "function f(items, enabled) {
+            if (enabled) outer: for (let i = 0, n = items.length; i < n; i += 1) {
+                if (items[i]) continue outer; break;
+            }
+        }",
+        &options(),
+    );
+}
+
+#[test]
+fn hoists_out_of_nested_loops() {
+    assert_transform(
+        "function f(rows, enabled) {
+            for (let i = 0; i < rows.length; i++)
+                for (let j = 0; j < rows[i].length; j++) { if (enabled) g(i, j); }
+        }",
+        "function f(rows, enabled) {
+            if (enabled) for (let i = 0; i < rows.length; i++)
+                for (let j = 0; j < rows[i].length; j++) { g(i, j); }
+        }",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_variant_conditions() {
+    // The loop variable.
+    assert_unchanged("function f(n) { for (let i = 0; i < n; i++) { if (i) g(); } }", &options());
+    // Reassigned, possibly by the loop.
+    assert_unchanged(
+        "function f(n) { let done = false; for (let i = 0; i < n; i++) { if (!done) done = g(); } }",
+        &options(),
+    );
+    assert_unchanged(
+        "let enabled = true; function f(n) { for (let i = 0; i < n; i++) if (enabled) g(); } function g() { enabled = false; }",
+        &options(),
+    );
+    // Declared after the loop, or in another function.
+    assert_unchanged(
+        "function f(n) { for (let i = 0; i < n; i++) if (enabled) g(); } var enabled = true;",
+        &options(),
+    );
+    assert_unchanged(
+        "const enabled = true; function f(n) { for (let i = 0; i < n; i++) if (enabled) g(); }",
+        &options(),
+    );
+    // Globals and imports.
+    assert_unchanged("function f(n) { for (let i = 0; i < n; i++) if (enabled) g(); }", &options());
+    assert_unchanged(
+        "import { enabled } from 'x'; function f(n) { for (let i = 0; i < n; i++) if (enabled) g(); }",
+        &options(),
+    );
+}
+
+/// Variables which can be reassigned without an assignment to them.
+#[test]
+fn skips_implicitly_reassigned_variables() {
+    // `arguments` is mapped to the params in sloppy mode.
+    assert_script_unchanged(
+        "function f(n, enabled) { for (let i = 0; i < n; i++) if (enabled) arguments[1] = g(); }",
+    );
+    assert_script_unchanged(
+        "function f(n, enabled) { const h = () => arguments; for (let i = 0; i < n; i++) if (enabled) h()[1] = 0; }",
+    );
+    assert_script_unchanged(
+        "function f(n, enabled) { var a = arguments; for (let i = 0; i < n; i++) if (enabled) a[1] = 0; }",
+    );
+    // A direct `eval` can assign any variable in scope.
+    assert_script_unchanged(
+        "function f(n, enabled) { for (let i = 0; i < n; i++) if (enabled) eval('enabled = false'); }",
+    );
+    assert_script_unchanged(
+        "function f(n, enabled) { function h() { eval(s); } for (let i = 0; i < n; i++) if (enabled) h(); }",
+    );
+    // The params of strict mode functions, including modules, functions with non-simple params,
+    // and other functions are not aliased.
+    assert_transform(
+        "function f(n, enabled) { for (let i = 0; i < n; i++) if (enabled) arguments[1] = g(); }",
+        "function f(n, enabled) { if (enabled) for (let i = 0; i < n; i++) arguments[1] = g(); }",
+        &options(),
+    );
+    assert_transform(
+        "function f(n, enabled = true) { for (let i = 0; i < n; i++) if (enabled) arguments[1] = g(); }",
+        "function f(n, enabled = true) { if (enabled) for (let i = 0; i < n; i++) arguments[1] = g(); }",
+        &options(),
+    );
+    assert_transform(
+        "function f(n, enabled) { function h() { return arguments; } for (let i = 0; i < n; i++) if (enabled) h(); }",
+        "function f(n, enabled) { function h() { return arguments; } if (enabled) for (let i = 0; i < n; i++) h(); }",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_side_effects_in_conditions() {
+    assert_unchanged(
+        "function f(n, o) { for (let i = 0; i < n; i++) if (o.enabled) g(); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(n, o) { for (let i = 0; i < n; i++) if (o == 1) g(); }",
+        &options(),
+    );
+    assert_unchanged("function f(n, c) { for (let i = 0; i < n; i++) if (c()) g(); }", &options());
+}
+
+#[test]
+fn skips_side_effects_in_headers() {
+    assert_unchanged("function f(c) { for (let i = 0; i < n(); i++) if (c) g(); }", &options());
+    assert_unchanged(
+        "function f(c, n) { for (let i = start(); i < n; i++) if (c) g(); }",
+        &options(),
+    );
+    // Assigns to a variable, which is observable after the loop.
+    assert_unchanged(
+        "function f(c, n) { var i; for (i = 0; i < n; i++) if (c) g(); return i; }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(c, n) { for (var i = 0; i < n; i++) if (c) g(); return i; }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(c, n) { let j = 0; for (let i = 0; i < n; i++, j++) if (c) g(); }",
+        &options(),
+    );
+    assert_unchanged("function f(c, n) { for (const x of n) if (c) g(x); }", &options());
+    // Never ends when the condition is false.
+    assert_unchanged("function f(c) { while (true) if (c) break; }", &options());
+    assert_unchanged("function f(c) { for (;;) if (c) break; }", &options());
+}
+
+#[test]
+fn skips_other_bodies() {
+    assert_unchanged(
+        "function f(c, n) { for (let i = 0; i < n; i++) { if (c) g(); else h(); } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(c, n) { for (let i = 0; i < n; i++) { if (c) g(); h(); } }",
+        &options(),
+    );
+}
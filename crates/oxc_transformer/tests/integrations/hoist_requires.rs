@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::codegen;
+
+fn transform(source_text: &str, source_type: SourceType) -> String {
+    let mut options = TransformOptions::default();
+    options.plugins.hoist_requires = true;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn hoists(source: &str, expected: &str) {
+    assert_eq!(
+        transform(source, SourceType::cjs()),
+        codegen(expected, SourceType::cjs()),
+        "{source}"
+    );
+}
+
+fn leaves_unchanged(source: &str) {
+    hoists(source, source);
+}
+
+#[test]
+fn hoists_over_side_effect_free_statements() {
+    hoists(
+        "
+        'use strict';
+        function main() {}
+        const fs = require('fs');
+        var DEBUG = false, name;
+        const { join } = require('path');
+        const helper = () => join('a', 'b');
+        require('./polyfill');
+        const read = require(`fs/promises`).readFile;
+        main();
+        ",
+        "
+        'use strict';
+        const fs = require('fs');
+        const { join } = require('path');
+        require('./polyfill');
+        const read = require(`fs/promises`).readFile;
+        function main() {}
+        var DEBUG = false, name;
+        const helper = () => join('a', 'b');
+        main();
+        ",
+    );
+}
+
+#[test]
+fn stops_at_side_effects() {
+    hoists(
+        "
+        function f() {}
+        const a = require('a');
+        setup();
+        const b = require('b');
+        ",
+        "
+        const a = require('a');
+        function f() {}
+        setup();
+        const b = require('b');
+        ",
+    );
+    for source in [
+        "setup(); const a = require('a');",
+        "const config = load(); const a = require('a');",
+        "const { x } = {}; const a = require('a');",
+        "class A {} const a = require('a');",
+        "if (cond) { require('a'); } const b = 1; const a = require('a');",
+    ] {
+        leaves_unchanged(source);
+    }
+}
+
+#[test]
+fn keeps_redeclared_vars_in_order() {
+    leaves_unchanged("var a = 1; var a = require('a');");
+    hoists(
+        "var a; const b = require('b'); var a = require('a');",
+        "const b = require('b'); var a = require('a'); var a;",
+    );
+}
+
+#[test]
+fn ignores_dynamic_and_nested_requires() {
+    for source in [
+        "const x = 1; const a = require(name);",
+        "const x = 1; const a = require(`./${name}`);",
+        "const x = 1; const a = cond ? require('a') : null;",
+        "const x = 1; const a = require('a') || {};",
+        "const x = 1; const a = require('a', 'b');",
+        "function f() { const x = 1; const a = require('a'); }",
+        "function require() {} const a = require('a');",
+        "const x = 1; const a = require('a'), b = 2;",
+    ] {
+        leaves_unchanged(source);
+    }
+}
+
+#[test]
+fn ignores_es_modules() {
+    let source = "function f() {} const a = require('a');";
+    assert_eq!(transform(source, SourceType::mjs()), codegen(source, SourceType::mjs()));
+}
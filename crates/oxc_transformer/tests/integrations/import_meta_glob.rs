@@ -0,0 +1,141 @@
+use std::sync::{Arc, Mutex};
+
+use oxc_span::SourceType;
+use oxc_transformer::{ImportMetaGlobOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+/// Matches `./dir/*.js` to `./dir/a.js` and `./dir/b.js`, minus the negated patterns.
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.import_meta_glob =
+        Some(ImportMetaGlobOptions::new(Arc::new(|patterns, negated| {
+            if patterns != ["./dir/*.js"] {
+                return None;
+            }
+            Some(
+                ["./dir/a.js", "./dir/b.js"]
+                    .into_iter()
+                    .filter(|path| !negated.contains(path))
+                    .map(String::from)
+                    .collect(),
+            )
+        })));
+    options
+}
+
+fn expands(source: &str, expected: &str) {
+    assert_eq!(test(source, &options()), Ok(codegen(expected, SourceType::mjs())), "{source}");
+}
+
+fn leaves_unchanged(source: &str) {
+    assert_eq!(test(source, &options()), Ok(codegen(source, SourceType::mjs())), "{source}");
+}
+
+#[test]
+fn lazy() {
+    expands(
+        "const modules = import.meta.glob('./dir/*.js');",
+        "const modules = {
+            './dir/a.js': () => import('./dir/a.js'),
+            './dir/b.js': () => import('./dir/b.js'),
+        };",
+    );
+    expands(
+        "const modules = import.meta.glob(`./dir/*.js`, { eager: false, import: 'setup' });",
+        "const modules = {
+            './dir/a.js': () => import('./dir/a.js').then((m) => m.setup),
+            './dir/b.js': () => import('./dir/b.js').then((m) => m.setup),
+        };",
+    );
+    expands(
+        "const modules = import.meta.glob('./dir/*.js', { import: 'a-b' });",
+        "const modules = {
+            './dir/a.js': () => import('./dir/a.js').then((m) => m['a-b']),
+            './dir/b.js': () => import('./dir/b.js').then((m) => m['a-b']),
+        };",
+    );
+}
+
+#[test]
+fn eager() {
+    expands(
+        "const modules = import.meta.glob('./dir/*.js', { eager: true });",
+        "import * as _glob from './dir/a.js';
+        import * as _glob2 from './dir/b.js';
+        const modules = { './dir/a.js': _glob, './dir/b.js': _glob2 };",
+    );
+    expands(
+        "const modules = import.meta.glob('./dir/*.js', { eager: true, import: 'default' });",
+        "import _glob from './dir/a.js';
+        import _glob2 from './dir/b.js';
+        const modules = { './dir/a.js': _glob, './dir/b.js': _glob2 };",
+    );
+    expands(
+        "const modules = import.meta.glob('./dir/*.js', { import: 'setup', eager: true });",
+        "import { setup as _glob } from './dir/a.js';
+        import { setup as _glob2 } from './dir/b.js';
+        const modules = { './dir/a.js': _glob, './dir/b.js': _glob2 };",
+    );
+}
+
+#[test]
+fn negated_patterns() {
+    expands(
+        "const modules = import.meta.glob(['./dir/*.js', '!./dir/b.js']);",
+        "const modules = { './dir/a.js': () => import('./dir/a.js') };",
+    );
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let mut options = TransformOptions::default();
+    options.plugins.import_meta_glob = Some(ImportMetaGlobOptions::new(Arc::new({
+        let calls = Arc::clone(&calls);
+        move |patterns, negated| {
+            calls.lock().unwrap().push((
+                patterns.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                negated.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ));
+            Some(vec![])
+        }
+    })));
+    assert_eq!(
+        test("import.meta.glob(['!./c.js', './*.js', '!./d.js', './*.ts']);", &options),
+        Ok(codegen("({});", SourceType::mjs()))
+    );
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [(
+            vec!["./*.js".to_string(), "./*.ts".to_string()],
+            vec!["./c.js".to_string(), "./d.js".to_string()]
+        )]
+    );
+}
+
+#[test]
+fn leaves_unsupported_calls_unchanged() {
+    for source in [
+        // Non-literal patterns.
+        "import.meta.glob(pattern);",
+        "import.meta.glob(`./${dir}/*.js`);",
+        "import.meta.glob(['./dir/*.js', pattern]);",
+        // Only negated patterns.
+        "import.meta.glob('!./dir/*.js');",
+        // Unresolved patterns.
+        "import.meta.glob('./other/*.js');",
+        // Unsupported or non-literal options.
+        "import.meta.glob('./dir/*.js', { query: '?raw' });",
+        "import.meta.glob('./dir/*.js', { eager });",
+        "import.meta.glob('./dir/*.js', { eager: isEager });",
+        "import.meta.glob('./dir/*.js', { import: name });",
+        "import.meta.glob('./dir/*.js', { ...options });",
+        "import.meta.glob('./dir/*.js', options);",
+        "import.meta.glob('./dir/*.js', {}, extra);",
+        "import.meta.glob();",
+        // Not `import.meta.glob`.
+        "import.meta?.glob('./dir/*.js');",
+        "import.meta.globEager('./dir/*.js');",
+        "meta.glob('./dir/*.js');",
+    ] {
+        leaves_unchanged(source);
+    }
+}
@@ -0,0 +1,63 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test_with_source_type};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.typescript.inline_enums = true;
+    options
+}
+
+#[test]
+fn inline_enums() {
+    let cases = [
+        "enum Direction { Up = 1, Down, Left = 'left' } move(Direction.Down, Direction['Left']);",
+        "enum E { A = -1, B = 1 / 0, C = 'a' + 'b', D = A << 3 } f(E.A, E.B, E.C, E.D, E.A ** 2);",
+        "const enum E { A, B } f(E.B);",
+        // Reads before the declaration, and in nested functions.
+        "function f() { return E.A; } enum E { A = 'a' }",
+        "function f() { enum E { A, B } return () => E.B; }",
+        // Used in types only.
+        "enum E { A, B } let x: E = E.A; let y: E.B; type T = typeof E; type K = keyof typeof E;",
+        // Unused enums are removed.
+        "enum E { A }",
+        // Enums which are transformed as usual.
+        "export enum E { A } f(E.A);",
+        "enum E { A } export { E };",
+        "enum E { A } export default E;",
+        "enum E { A } f(Object.keys(E));",
+        "enum E { A } f(E[0]);",
+        "enum E { A } f(E[E.A]);",
+        "enum E { A } for (const key in E) {}",
+        "enum E { A } f(E.B);",
+        "enum E { A } f(E?.A);",
+        "enum E { A } E.A = 1;",
+        "enum E { A } E.A++;",
+        "enum E { A } delete E.A;",
+        "enum E { A = 'a', B = 'b'.repeat(2) } f(E.A);",
+        "declare const x: number; enum E { A = x } f(E.A);",
+        "enum E { A = 1, B = E.A } f(E.B);",
+        "enum E { A } enum E { B = 1 } f(E.A);",
+        "enum E { A } namespace E { export const b = 1; } f(E.A);",
+        "declare enum E { A } f(E.A);",
+    ];
+
+    assert_snapshot(
+        "inline_enums",
+        &print_cases("", cases, |case| {
+            test_with_source_type(case, SourceType::ts(), &options()).unwrap()
+        }),
+    );
+}
+
+#[test]
+fn top_level_enums_of_scripts_are_global() {
+    let source_type = SourceType::ts().with_script(true);
+    let code = test_with_source_type("enum E { A } f(E.A);", source_type, &options()).unwrap();
+    assert!(code.contains("var E = "), "{code}");
+    let code =
+        test_with_source_type("function g() { enum E { A } f(E.A); }", source_type, &options())
+            .unwrap();
+    assert_eq!(code, "function g() {\n\tf(0);\n}\n");
+}
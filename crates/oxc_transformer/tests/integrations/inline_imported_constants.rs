@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    ExportedConstant, InlineImportedConstantsOptions, TransformOptions, collect_constant_exports,
+};
+
+use crate::{assert_transform, assert_unchanged, codegen, test_with_source_type};
+
+const CONFIG: &str = "
+export const MAX = 100, MIN = -1, NAME = 'app', DEBUG = false, NONE = null, UNSET = void 0;
+export const TEMPLATE = `template`, LONG = 'a string which is longer than the maximum length';
+export const OBJECT = {};
+export let count = 0;
+export function increment() { count++; }
+const LOCAL = 1;
+export { LOCAL as RENAMED };
+export default 42;
+";
+
+/// The constants exported by `source_text`.
+fn collect(source_text: &str) -> Vec<(String, ExportedConstant)> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let scoping = SemanticBuilder::new().build(&ret.program).semantic.into_scoping();
+    collect_constant_exports(&ret.program, &scoping)
+}
+
+fn options() -> TransformOptions {
+    let config = collect(CONFIG);
+    let mut options = TransformOptions::default();
+    options.plugins.inline_imported_constants =
+        Some(InlineImportedConstantsOptions::new(Arc::new(move |source, name| match source {
+            "./config" => config.iter().find(|(n, _)| n == name).map(|(_, value)| value.clone()),
+            // `export { MAX as LIMIT } from "./config"`, followed by the resolver.
+            "./limits" if name == "LIMIT" => Some(ExportedConstant::Number(100.0)),
+            "./numbers" => Some(ExportedConstant::Number(f64::NAN)),
+            _ => None,
+        })));
+    options
+}
+
+#[test]
+fn collects_constant_exports() {
+    let string = |value: &str| ExportedConstant::String(value.to_string());
+    assert_eq!(
+        collect(CONFIG),
+        [
+            ("MAX".to_string(), ExportedConstant::Number(100.0)),
+            ("MIN".to_string(), ExportedConstant::Number(-1.0)),
+            ("NAME".to_string(), string("app")),
+            ("DEBUG".to_string(), ExportedConstant::Boolean(false)),
+            ("NONE".to_string(), ExportedConstant::Null),
+            ("UNSET".to_string(), ExportedConstant::Undefined),
+            ("TEMPLATE".to_string(), string("template")),
+            ("LONG".to_string(), string("a string which is longer than the maximum length")),
+            ("RENAMED".to_string(), ExportedConstant::Number(1.0)),
+        ]
+    );
+}
+
+#[test]
+fn inlines_constants() {
+    assert_transform(
+        "import { MAX, MIN, NAME, DEBUG, NONE, UNSET, TEMPLATE } from './config';
+        f(MAX, MIN, NAME, DEBUG, NONE, UNSET, TEMPLATE);",
+        "import './config'; f(100, -1, 'app', false, null, void 0, 'template');",
+        &options(),
+    );
+    assert_transform(
+        "import { RENAMED as one } from './config'; const f = () => one + 1;",
+        "import './config'; const f = () => 1 + 1;",
+        &options(),
+    );
+    assert_transform(
+        "import { LIMIT } from './limits'; f(LIMIT);",
+        "import './limits'; f(100);",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_other_imports() {
+    assert_transform(
+        "import answer, { MAX, OBJECT, increment, count, LONG } from './config';
+        f(answer, MAX, OBJECT, count, LONG); increment();",
+        "import answer, { OBJECT, increment, count, LONG } from './config';
+        f(answer, 100, OBJECT, count, LONG); increment();",
+        &options(),
+    );
+    assert_unchanged("import * as config from './config'; f(config.MAX);", &options());
+    assert_unchanged("import { NOT_A_NUMBER } from './numbers'; f(NOT_A_NUMBER);", &options());
+}
+
+#[test]
+fn keeps_re_exported_imports() {
+    assert_transform(
+        "import { MAX } from './config'; f(MAX); export { MAX };",
+        "import { MAX } from './config'; f(100); export { MAX };",
+        &options(),
+    );
+    assert_unchanged("export { MAX } from './config'; export * from './config';", &options());
+}
+
+#[test]
+fn expands_shorthand_properties() {
+    assert_transform(
+        "import { MAX, NAME } from './config'; const o = { MAX, NAME: NAME, other };",
+        "import './config'; const o = { MAX: 100, NAME: 'app', other };",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_shadowed_bindings() {
+    assert_transform(
+        "import { MAX } from './config'; function f(MAX) { return MAX; } g(MAX);",
+        "import './config'; function f(MAX) { return MAX; } g(100);",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_type_imports() {
+    assert_eq!(
+        test_with_source_type(
+            "import type { MAX } from './config'; import { type MIN, NAME } from './config';
+            let a: typeof MAX; f(NAME);",
+            SourceType::ts(),
+            &options(),
+        ),
+        // The TypeScript transform removes imports without value references.
+        Ok(codegen("let a; f('app'); export {};", SourceType::mjs()))
+    );
+}
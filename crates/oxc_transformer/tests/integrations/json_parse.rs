@@ -0,0 +1,74 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Argument, Expression, Statement};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{JsonParseOptions, TransformOptions};
+use serde_json::{Value, json};
+
+use crate::{codegen, test};
+
+fn options(threshold: usize) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.json_parse = Some(JsonParseOptions { threshold });
+    options
+}
+
+/// Transform `const x = <literal>;` and decode the string passed to `JSON.parse`.
+fn round_trip(literal: &str) -> Value {
+    let code = test(&format!("const x = {literal};"), &options(0)).unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &code, SourceType::mjs()).parse();
+    let Some(Statement::VariableDeclaration(decl)) = ret.program.body.first() else {
+        panic!("expected a variable declaration: {code}");
+    };
+    let Some(Expression::CallExpression(call)) = &decl.declarations[0].init else {
+        panic!("expected `JSON.parse` call: {code}");
+    };
+    let [Argument::StringLiteral(json)] = call.arguments.as_slice() else {
+        panic!("expected a single string argument: {code}");
+    };
+    serde_json::from_str(&json.value).unwrap()
+}
+
+#[test]
+fn round_trips_values() {
+    assert_eq!(
+        round_trip("{ a: 1, 'b-c': [true, false, null], 1: -2.5, nested: { deep: [] } }"),
+        json!({ "a": 1, "b-c": [true, false, null], "1": -2.5, "nested": { "deep": [] } })
+    );
+    assert_eq!(round_trip("[0.1, 1e-7, 1e21, 0x10, 1_000]"), json!([0.1, 1e-7, 1e21, 16, 1000]));
+    assert_eq!(round_trip("{ 1e21: 1, 0.5: 2 }"), json!({ "1e+21": 1, "0.5": 2 }));
+}
+
+#[test]
+fn round_trips_string_escapes() {
+    assert_eq!(
+        round_trip(r#"['"quoted"', 'back\\slash', 'line\nbreak\ttab\r', '\0\x1f\u2028', '😀']"#),
+        json!(["\"quoted\"", "back\\slash", "line\nbreak\ttab\r", "\0\u{1f}\u{2028}", "😀"])
+    );
+}
+
+#[test]
+fn respects_threshold() {
+    let source = "const small = { a: 1 }; const large = [1, 2, 3, 4, 5, 6, 7, 8, 9];";
+    let expected = "const small = { a: 1 }; const large = JSON.parse('[1,2,3,4,5,6,7,8,9]');";
+    // `{"a":1}` is 7 bytes, `[1,2,3,4,5,6,7,8,9]` is 19 bytes.
+    assert_eq!(test(source, &options(8)), Ok(codegen(expected, SourceType::mjs())));
+    assert_eq!(test(source, &options(20)), Ok(codegen(source, SourceType::mjs())));
+}
+
+#[test]
+fn skips_non_json_values() {
+    for source in [
+        "const x = { a: () => {} };",
+        "const x = { a: undefined };",
+        "const x = [,];",
+        "const x = { ['a']: 1 };",
+        "const x = { __proto__: null };",
+        "const x = { '__proto__': null };",
+        "const x = [1e999];",
+        "function f(JSON) { return { a: 1 }; }",
+    ] {
+        assert_eq!(test(source, &options(0)), Ok(codegen(source, SourceType::mjs())), "{source}");
+    }
+}
@@ -0,0 +1,73 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.json_parse_to_literal = true;
+    options
+}
+
+#[test]
+fn converts_literals() {
+    assert_transform(
+        r#"x = JSON.parse('{"name":"oxc","tags":["parser", "linter"],"stars":1e4}');"#,
+        r#"x = { name: "oxc", tags: ["parser", "linter"], stars: 1e4 };"#,
+        &options(),
+    );
+    assert_transform(
+        r"x = JSON.parse(' [ true, false, null, -0.5, 0, {} , [] ] ');",
+        "x = [true, false, null, -0.5, 0, {}, []];",
+        &options(),
+    );
+    assert_transform(r#"x = (JSON.parse)("1");"#, "x = 1;", &options());
+    assert_transform(r"JSON.parse('{}');", "({});", &options());
+}
+
+#[test]
+fn quotes_keys() {
+    assert_transform(
+        r#"x = JSON.parse('{"a-b":1,"1":2,"":3,"if":4,"$_":5}');"#,
+        r#"x = { "a-b": 1, "1": 2, "": 3, if: 4, $_: 5 };"#,
+        &options(),
+    );
+    assert_transform(
+        r#"x = JSON.parse('{"__proto__":null}');"#,
+        r#"x = { ["__proto__"]: null };"#,
+        &options(),
+    );
+}
+
+#[test]
+fn unescapes_strings() {
+    assert_transform(
+        r#"x = JSON.parse('["\\"\\\\\\/\\b\\f\\n\\r\\t", "\\u00e9\\ud83d\\ude00"]');"#,
+        r#"x = ["\"\\/\b\f\n\r\t", "é😀"];"#,
+        &options(),
+    );
+}
+
+#[test]
+fn skips_invalid_json() {
+    assert_unchanged(r#"x = JSON.parse("{'a':1}");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("[1,]");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("[01]");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("[.5]");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("[1.]");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("'a'");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("[1] 2");"#, &options());
+    assert_unchanged(r#"x = JSON.parse('["\\x41"]');"#, &options());
+    assert_unchanged(r#"x = JSON.parse('["\\ud83d"]');"#, &options());
+    assert_unchanged(r#"x = JSON.parse("1e999");"#, &options());
+    assert_unchanged(r#"x = JSON.parse("");"#, &options());
+}
+
+#[test]
+fn skips_other_calls() {
+    assert_unchanged(r#"x = JSON.parse("{}", reviver);"#, &options());
+    assert_unchanged("x = JSON.parse(json);", &options());
+    assert_unchanged("x = JSON.parse(`{}`);", &options());
+    assert_unchanged(r#"x = JSON?.parse("{}");"#, &options());
+    assert_unchanged(r#"x = JSON.parse?.("{}");"#, &options());
+    assert_unchanged(r#"let JSON = f; x = JSON.parse("{}");"#, &options());
+}
@@ -0,0 +1,51 @@
+use std::{fmt::Write, path::Path};
+
+use oxc_span::SourceType;
+use oxc_transformer::{JsxOptions, JsxRuntime, TransformOptions};
+
+use crate::{assert_snapshot, print_cases, transform};
+
+/// Transforms `source_text` as `test.jsx`, and returns the code followed by the warnings.
+fn transform_jsx(source_text: &str, jsx: JsxOptions) -> String {
+    let options = TransformOptions { jsx, ..TransformOptions::default() };
+    let (code, errors) = transform(source_text, SourceType::jsx(), Path::new("test.jsx"), &options);
+    errors.into_iter().fold(code, |mut code, err| {
+        writeln!(code, "{:?}", err.with_source_code(source_text.to_string())).unwrap();
+        code
+    })
+}
+
+#[test]
+fn jsx_dev_props() {
+    let cases = [
+        "<div />;",
+        "const a = <div>\n  <span>{x}</span>\n    <b />\n</div>;",
+        // Columns are counted in UTF-16 code units.
+        "const s = '😀é'; <div />;",
+        "<Foo.Bar.Baz />; <a:b />;",
+        // Fragments have no props.
+        "<><div /></>;",
+        "<div __source={source} />;",
+        "<div __self={self} />;",
+        "class A { render() { return <div />; } }",
+        // `this` is not available before `super()` in a derived constructor.
+        "class B extends A { constructor() { super(<div />); <div />; } }",
+        "function f() { return <div {...props} key='k' />; }",
+    ];
+
+    let classic =
+        JsxOptions { runtime: JsxRuntime::Classic, development: true, ..JsxOptions::default() };
+    let automatic = JsxOptions { development: true, ..JsxOptions::default() };
+    let preserve = JsxOptions {
+        jsx_plugin: false,
+        jsx_self_plugin: true,
+        jsx_source_plugin: true,
+        ..JsxOptions::default()
+    };
+
+    let snapshot = [("classic", classic), ("automatic", automatic), ("preserve", preserve)]
+        .into_iter()
+        .map(|(name, jsx)| print_cases(name, cases, |case| transform_jsx(case, jsx.clone())))
+        .collect::<String>();
+    assert_snapshot("jsx_dev_props", &snapshot);
+}
@@ -0,0 +1,22 @@
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.lazy_top_level_await = true;
+    options
+}
+
+#[test]
+fn leaves_unsupported_modules_unchanged() {
+    for source in [
+        "if (await ready()) { var mode = 'ready'; } export { mode };",
+        "for (var i = 0; i < (await count()); i++) {}",
+        "await using resource = await open();",
+    ] {
+        let errors = test(source, &options()).unwrap_err();
+        assert_eq!(errors.len(), 1, "{source}");
+        assert!(errors[0].message.contains("Top-level await was not deferred"), "{source}");
+    }
+}
@@ -1,5 +1,56 @@
+mod ambient_declarations;
+mod array_from_length;
+mod arrow_functions;
+mod async_destructuring;
+mod at_to_index;
+mod big_int;
+mod class_fields;
+mod coalesce_template_literals;
+mod dedupe_export_names;
+mod destructure_member_chains;
+mod destructuring;
+mod direct_eval;
+mod dynamic_requires;
 mod es_target;
+mod expand_export_star;
+mod explicit_radix;
+mod feature_guards;
+mod frozen_string_enums;
+mod global_this;
+mod hoist_loop_guards;
+mod hoist_requires;
+mod import_meta_glob;
+mod inline_enums;
+mod inline_imported_constants;
+mod json_parse;
+mod json_parse_to_literal;
+mod jsx_dev_props;
+mod lazy_top_level_await;
+mod module_factory;
+mod namespace_to_named_imports;
+mod normalize_template_line_endings;
+mod normalize_undefined;
+mod object_iteration_to_for_in;
+mod object_rest;
+mod promise_finally;
+mod property_mutators;
+mod react_component_display_name;
+mod react_constant_elements;
+mod reconstruct_ts_enums;
+mod rename_top_level_bindings;
+mod return_await;
+mod split_re_exports;
+mod spread;
+mod switch_lookup;
 mod targets;
+mod template_literals;
+mod ternary_to_early_return;
+mod then_to_catch;
+mod typescript_assertions;
+mod typescript_modifiers;
+mod typescript_module;
+mod usage_polyfills;
+mod well_known_symbols;
 
 use std::path::Path;
 
@@ -27,19 +78,89 @@ pub(crate) fn test(
     source_text: &str,
     options: &TransformOptions,
 ) -> Result<String, Vec<OxcDiagnostic>> {
-    let source_type = SourceType::default();
+    test_with_source_type(source_text, SourceType::default(), options)
+}
+
+pub(crate) fn test_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: &TransformOptions,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    let (code, errors) = transform(source_text, source_type, Path::new(""), options);
+    if errors.is_empty() { Ok(code) } else { Err(errors) }
+}
+
+/// Transforms `source_text` as the file at `source_path`, and returns the code and the errors.
+pub(crate) fn transform(
+    source_text: &str,
+    source_type: SourceType,
+    source_path: &Path,
+    options: &TransformOptions,
+) -> (String, Vec<OxcDiagnostic>) {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut program = ret.program;
     let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
-    let ret = Transformer::new(&allocator, Path::new(""), options)
+    let ret = Transformer::new(&allocator, source_path, options)
         .build_with_scoping(scoping, &mut program);
-    if !ret.errors.is_empty() {
-        return Err(ret.errors);
-    }
     let code = Codegen::new()
         .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
         .build(&program)
         .code;
-    Ok(code)
+    (code, ret.errors)
+}
+
+/// Asserts that `source_text` is transformed into `expected`, after printing both with codegen.
+#[track_caller]
+pub(crate) fn assert_transform(source_text: &str, expected: &str, options: &TransformOptions) {
+    assert_eq!(test(source_text, options), Ok(codegen(expected, SourceType::mjs())));
+}
+
+#[track_caller]
+pub(crate) fn assert_unchanged(source_text: &str, options: &TransformOptions) {
+    assert_transform(source_text, source_text, options);
+}
+
+/// The code, or the errors with the source text.
+pub(crate) fn code_or_errors(
+    source_text: &str,
+    result: Result<String, Vec<OxcDiagnostic>>,
+) -> String {
+    result.unwrap_or_else(|errors| {
+        errors
+            .into_iter()
+            .map(|err| format!("{:?}", err.with_source_code(source_text.to_string())))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+/// Prints a case of a snapshot: its header, source text and result.
+pub(crate) fn print_case(header: &str, source_text: &str, result: &str) -> String {
+    format!("########## {header}\n{source_text}\n----------\n{result}\n")
+}
+
+/// Prints each of `cases` with the result of `transform`.
+/// The header of a case is its index, followed by `label` if it's not empty.
+pub(crate) fn print_cases<'s>(
+    label: &str,
+    cases: impl IntoIterator<Item = &'s str>,
+    mut transform: impl FnMut(&'s str) -> String,
+) -> String {
+    cases.into_iter().enumerate().fold(String::new(), |mut w, (i, case)| {
+        let header = if label.is_empty() { i.to_string() } else { format!("{i} {label}") };
+        w.push_str(&print_case(&header, case, &transform(case)));
+        w
+    })
+}
+
+/// Asserts the snapshot `name`, of the cases printed by [`print_cases`].
+#[cfg_attr(miri, expect(unused_variables))]
+pub(crate) fn assert_snapshot(name: &str, snapshot: &str) {
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!(name, snapshot);
+        });
+    }
 }
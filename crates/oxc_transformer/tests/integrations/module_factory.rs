@@ -0,0 +1,426 @@
+use std::{fmt::Write, path::Path, process::Command};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    JsxRuntime, MODULE_FACTORY_RUNTIME, ModuleFactoryOptions, TransformOptions, Transformer,
+};
+
+use crate::{assert_snapshot, codegen};
+
+fn transform_with(
+    id: &str,
+    source_text: &str,
+    source_type: SourceType,
+    options: &mut TransformOptions,
+) -> Result<String, Vec<String>> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{source_text}");
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    options.plugins.module_factory = Some(ModuleFactoryOptions { id: Some(id.to_string()) });
+    let ret = Transformer::new(&allocator, Path::new(id), options)
+        .build_with_scoping(scoping, &mut program);
+    if !ret.errors.is_empty() {
+        return Err(ret.errors.into_iter().map(|error| error.message.to_string()).collect());
+    }
+    Ok(Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code)
+}
+
+fn transform(id: &str, source_text: &str) -> String {
+    transform_with(id, source_text, SourceType::mjs(), &mut TransformOptions::default()).unwrap()
+}
+
+/// Bundles `modules` with the runtime, requires the first module, and returns what it logged.
+fn run(modules: &[(&str, &str)]) -> String {
+    let mut bundle = String::from(MODULE_FACTORY_RUNTIME);
+    for (id, source_text) in modules {
+        bundle.push_str(&transform(id, source_text));
+    }
+    writeln!(bundle, "__require('{}');", modules[0].0).unwrap();
+
+    let output = Command::new("node").args(["--eval", &bundle]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}\n{bundle}");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+#[cfg(not(miri))]
+fn wraps_module() {
+    let code = transform(
+        "./a.js",
+        "
+import def, { b as c } from './b.js';
+import * as ns from './c.js';
+import './side.js';
+export { d as e, default as g } from './d.js';
+export * from './e.js';
+export * as f from './f.js';
+export const x = c + 1, { y } = ns;
+export function h() { return def(ns, { c }, c`t`); }
+export { c as reexported, x as 'string name', x as __proto__ };
+export default x * 2;
+",
+    );
+    assert_snapshot("module_factory", &code);
+}
+
+#[test]
+fn wraps_module_without_exports() {
+    assert_eq!(
+        transform("main.js", "console.log(this);"),
+        codegen(
+            "__define('main.js', function(_exports) { 'use strict'; console.log(this); });",
+            SourceType::cjs()
+        )
+    );
+}
+
+#[test]
+fn default_id_is_source_path() {
+    let code = transform_with("src/a.js", "", SourceType::mjs(), &mut TransformOptions::default());
+    assert!(code.unwrap().starts_with("__define('src/a.js', function(_exports) {"));
+}
+
+#[test]
+fn reuses_required_module() {
+    assert_eq!(
+        transform("a.js", "import a from './b.js'; import { b } from './b.js'; a(b);"),
+        codegen(
+            "__define('a.js', function(_exports) {
+                'use strict';
+                var _b = __exports('./b.js');
+                __require('./b.js');
+                (0, _b.default)(_b.b);
+            });",
+            SourceType::cjs()
+        )
+    );
+}
+
+#[test]
+fn names_anonymous_default_exports() {
+    assert_eq!(
+        transform("a.js", "export default function () {}"),
+        codegen(
+            "__define('a.js', function(_exports) {
+                'use strict';
+                __export(_exports, { default: () => _default });
+                function _default() {}
+            });",
+            SourceType::cjs()
+        )
+    );
+    assert_eq!(
+        transform("a.js", "export default class {}"),
+        codegen(
+            "__define('a.js', function(_exports) {
+                'use strict';
+                __export(_exports, { default: () => _default });
+                class _default {}
+            });",
+            SourceType::cjs()
+        )
+    );
+}
+
+#[test]
+fn rewrites_jsx_element_names() {
+    let mut options = TransformOptions::default();
+    options.jsx.jsx_plugin = false;
+    let code = transform_with(
+        "a.jsx",
+        "import Button, { Icon } from './button.js'; import * as ui from './ui.js'; <Button><Icon.Small /><ui.Menu /></Button>;",
+        SourceType::jsx().with_module(true),
+        &mut options,
+    );
+    assert_eq!(
+        code.unwrap(),
+        codegen(
+            "__define('a.jsx', function(_exports) {
+                'use strict';
+                var _button = __exports('./button.js');
+                var _ui = __exports('./ui.js');
+                __require('./button.js');
+                __require('./ui.js');
+                <_button.default><_button.Icon.Small /><_ui.Menu /></_button.default>;
+            });",
+            SourceType::jsx()
+        )
+    );
+}
+
+#[test]
+fn wraps_imports_added_by_other_transforms() {
+    let mut options = TransformOptions::default();
+    options.jsx.runtime = JsxRuntime::Automatic;
+    let code = transform_with(
+        "a.jsx",
+        "export const App = () => <div />;",
+        SourceType::jsx().with_module(true),
+        &mut options,
+    )
+    .unwrap();
+    assert!(code.contains("\tvar _jsxRuntime = __exports('react/jsx-runtime');\n"), "{code}");
+    assert!(code.contains("\t__require('react/jsx-runtime');\n"), "{code}");
+    assert!(code.contains("(0, _jsxRuntime.jsx)('div', {})"), "{code}");
+}
+
+#[test]
+fn removes_type_imports() {
+    let code = transform_with(
+        "a.ts",
+        "import type { A } from './a.js'; import { B, type C } from './b.js'; export type { A }; export const b: C = B;",
+        SourceType::ts(),
+        &mut TransformOptions::default(),
+    );
+    assert_eq!(
+        code.unwrap(),
+        codegen(
+            "__define('a.ts', function(_exports) {
+                'use strict';
+                __export(_exports, { b: () => b });
+                var _b = __exports('./b.js');
+                __require('./b.js');
+                const b = _b.B;
+            });",
+            SourceType::cjs()
+        )
+    );
+}
+
+#[test]
+fn leaves_scripts_and_top_level_await_unchanged() {
+    let source = "const a = require('./a.js');";
+    let code = transform_with("a.cjs", source, SourceType::cjs(), &mut TransformOptions::default());
+    assert_eq!(code.unwrap(), codegen(source, SourceType::cjs()));
+
+    let errors = transform_with(
+        "a.js",
+        "export const a = await load();",
+        SourceType::mjs(),
+        &mut TransformOptions::default(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        ["Module was not wrapped in a module factory, because it contains top-level `await`."]
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))] // we run big endian tests on docker that does not have node installed
+fn executes_modules() {
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import greet, { name as who } from 'greet';
+                import * as ns from 'greet';
+                import 'side-effect';
+                console.log(greet(who), ns.name, Object.keys(ns).sort().join());",
+            ),
+            ("greet", "export const name = 'world'; export default (name) => `hello ${name}`;"),
+            ("side-effect", "console.log('side effect first');"),
+        ]),
+        "side effect first\nhello world world default,name\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn keeps_live_bindings() {
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import { count, increment, counter } from 'counter';
+                console.log(count);
+                increment();
+                increment();
+                console.log(count, counter.count);",
+            ),
+            (
+                "counter",
+                "export let count = 0;
+                export function increment() { count++; }
+                export * as counter from 'counter';",
+            ),
+        ]),
+        "0\n2 2\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn calls_imported_functions_without_this() {
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import { f } from 'f'; import * as ns from 'f'; console.log(f(), ns.f() === ns);"
+            ),
+            ("f", "export function f() { return this; }"),
+        ]),
+        "undefined true\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn evaluates_circular_dependencies() {
+    assert_eq!(
+        run(&[
+            (
+                "even",
+                "import { isOdd } from 'odd';
+                export function isEven(n) { return n === 0 || isOdd(n - 1); }
+                console.log(isEven(4), isEven(3));",
+            ),
+            (
+                "odd",
+                "import { isEven } from 'even';
+                export function isOdd(n) { return n !== 0 && isEven(n - 1); }
+                console.log('odd', isEven(2));",
+            ),
+        ]),
+        "odd true\ntrue false\n"
+    );
+
+    // Bindings which are not initialized yet are in the temporal dead zone.
+    assert_eq!(
+        run(&[
+            ("a", "import 'b'; export const value = 1;"),
+            (
+                "b",
+                "import { value } from 'a';
+                try { value; } catch (error) { console.log(error.name); }",
+            ),
+        ]),
+        "ReferenceError\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn exports_default() {
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import expression from 'expression';
+                import fn from 'function';
+                import Class from 'class';
+                import value from 'renamed';
+                console.log(expression, fn(), fn.name, new Class().name, value);",
+            ),
+            ("expression", "export default 1 + 1;"),
+            ("function", "export default function () { return 'fn'; }"),
+            ("class", "export default class { name = 'class'; }"),
+            ("renamed", "const value = 'renamed'; export { value as default };"),
+        ]),
+        "2 fn _default class renamed\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn re_exports() {
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import * as all from 'all';
+                console.log(Object.keys(all).sort().join(), all.a, all.b, all.c, all.renamed);",
+            ),
+            (
+                "all",
+                "export * from 'a';
+                export * from 'b';
+                export { c as renamed } from 'c';
+                export const b = 'own b';",
+            ),
+            ("a", "export const a = 'a'; export default 'default is not re-exported';"),
+            ("b", "export const b = 'b';"),
+            ("c", "export const c = 'c';"),
+        ]),
+        "a,b,renamed a own b undefined c\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn keeps_live_bindings_of_default_and_re_exports() {
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import name, { reset } from 'default';
+                import { count, increment } from 'middle';
+                console.log(name());
+                reset();
+                console.log(name);
+                increment();
+                console.log(count);",
+            ),
+            (
+                "default",
+                "export default function name() { return 'before'; }
+                export function reset() { name = 'after'; }",
+            ),
+            ("middle", "export { count, increment } from 'counter';"),
+            ("counter", "export let count = 0; export function increment() { count++; }"),
+        ]),
+        "before\nafter\n1\n"
+    );
+}
+
+#[test]
+#[cfg(not(target_endian = "big"))]
+fn hoists_default_function_declarations() {
+    // The default export is a function declaration, which is hoisted and keeps its name.
+    assert_eq!(
+        run(&[
+            (
+                "main",
+                "import fact from 'fact';
+                console.log(fact(5));",
+            ),
+            (
+                "fact",
+                "console.log(fact(3), fact.name);
+                export default function fact(n) { return n <= 1 ? 1 : n * fact(n - 1); }",
+            ),
+        ]),
+        "6 fact\n120\n"
+    );
+
+    // A module in a cycle can call the default functions of a module which is not evaluated yet.
+    assert_eq!(
+        run(&[
+            (
+                "a",
+                "import b from 'b';
+                export default function () { return 'a'; }
+                console.log(b());",
+            ),
+            (
+                "b",
+                "import a from 'a';
+                import named from 'a2';
+                export default function b() { return 'b'; }
+                console.log(a(), named());",
+            ),
+            ("a2", "import 'b'; export default function named() { return 'a2'; }"),
+        ]),
+        "a a2\nb\n"
+    );
+}
@@ -0,0 +1,85 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.namespace_to_named_imports = true;
+    options
+}
+
+#[test]
+fn replaces_namespace_imports() {
+    assert_transform(
+        "import * as utils from 'm'; utils.log(utils.format(value), utils.log);",
+        "import { log, format } from 'm'; log(format(value), log);",
+        &options(),
+    );
+    assert_transform(
+        "import d, * as ns from 'm'; d(ns.foo);",
+        "import d, { foo } from 'm'; d(foo);",
+        &options(),
+    );
+    assert_transform(
+        "import * as ns from 'm'; function f() { return () => typeof ns.foo; }",
+        "import { foo } from 'm'; function f() { return () => typeof foo; }",
+        &options(),
+    );
+    assert_transform(
+        "import * as ns from 'm'; ns.tag`a`; ns.f?.();",
+        "import { tag, f } from 'm'; tag`a`; f?.();",
+        &options(),
+    );
+    assert_transform(
+        "import * as ns from 'm' with { type: 'json' }; x = ns.default;",
+        "import { default as _default } from 'm' with { type: 'json' }; x = _default;",
+        &options(),
+    );
+}
+
+#[test]
+fn avoids_name_collisions() {
+    assert_transform(
+        "import * as ns from 'm'; const foo = 1; ns.foo(foo);",
+        "import { foo as _foo } from 'm'; const foo = 1; _foo(foo);",
+        &options(),
+    );
+    assert_transform(
+        "import * as ns from 'm'; function f(bar) { return ns.bar + bar; }",
+        "import { bar as _bar } from 'm'; function f(bar) { return _bar + bar; }",
+        &options(),
+    );
+    assert_transform(
+        "import * as ns from 'm'; ns.baz(baz, ns.class);",
+        "import { baz as _baz, class as _class } from 'm'; _baz(baz, _class);",
+        &options(),
+    );
+    assert_transform(
+        "import * as a from 'a'; import * as b from 'b'; a.x(b.x);",
+        "import { x } from 'a'; import { x as _x } from 'b'; x(_x);",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_namespaces_used_as_values() {
+    assert_unchanged("import * as ns from 'm'; ns[name]();", &options());
+    assert_unchanged("import * as ns from 'm'; ns.foo(); call(ns);", &options());
+    assert_unchanged("import * as ns from 'm'; ns.foo(); export { ns };", &options());
+    assert_unchanged("import * as ns from 'm'; for (const key in ns) ns.foo(key);", &options());
+    assert_unchanged("import * as ns from 'm'; ns.foo = 1;", &options());
+    assert_unchanged("import * as ns from 'm'; ns.foo++;", &options());
+    assert_unchanged("import * as ns from 'm'; delete ns.foo;", &options());
+    assert_unchanged("import * as ns from 'm'; ns?.foo;", &options());
+    assert_unchanged("import * as ns from 'm'; ({ ns } = ns.foo);", &options());
+    assert_unchanged("import * as ns from 'm';", &options());
+}
+
+#[test]
+fn keeps_member_assignments_of_properties() {
+    assert_transform(
+        "import * as ns from 'm'; ns.foo.bar = 1; ns.foo.baz();",
+        "import { foo } from 'm'; foo.bar = 1; foo.baz();",
+        &options(),
+    );
+}
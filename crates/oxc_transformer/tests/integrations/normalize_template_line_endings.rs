@@ -0,0 +1,34 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged, test};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.normalize_template_line_endings = true;
+    options
+}
+
+#[test]
+fn normalizes_raw_strings_of_tagged_templates() {
+    // The parser keeps the raw text of templates with invalid escapes.
+    assert_transform("tag`\\unicode\r\n${a}\rb\r\n`;", "tag`\\unicode\n${a}\nb\n`;", &options());
+    assert_transform("String.raw`\\xyz\r\r\n`;", "String.raw`\\xyz\n\n`;", &options());
+}
+
+#[test]
+fn normalizes_template_literals() {
+    assert_transform("x = `a\r\nb${c}d\re`;", "x = `a\nb${c}d\ne`;", &options());
+    assert_transform("x = `a\\\r\nb`;", "x = `a\\\nb`;", &options());
+}
+
+#[test]
+fn keeps_escapes_and_interpolations() {
+    assert_unchanged("x = `a\\r\\n${'\\r\\n'}b\\r`;", &options());
+    assert_transform("tag`\\unicode\\r\\n${`\r\n`}`;", "tag`\\unicode\\r\\n${`\n`}`;", &options());
+}
+
+#[test]
+fn is_opt_in() {
+    let output = test("tag`\\unicode\r\n`;", &TransformOptions::default()).unwrap();
+    assert_eq!(output, "tag`\\unicode\r\n`;\n");
+}
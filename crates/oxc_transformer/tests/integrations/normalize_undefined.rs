@@ -0,0 +1,81 @@
+use oxc_transformer::{NormalizeUndefinedOptions, TransformOptions, UndefinedStyle};
+
+use crate::{assert_transform, assert_unchanged, test};
+
+fn options(style: UndefinedStyle) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.normalize_undefined = Some(NormalizeUndefinedOptions { style });
+    options
+}
+
+#[test]
+fn undefined_to_void_0() {
+    let options = options(UndefinedStyle::Void0);
+    assert_transform(
+        "if (x === undefined) f(undefined);",
+        "if (x === void 0) f(void 0);",
+        &options,
+    );
+    assert_transform("x = { undefined };", "x = { undefined: void 0 };", &options);
+    assert_transform("x = undefined ?? (undefined);", "x = void 0 ?? void 0;", &options);
+}
+
+#[test]
+fn keeps_local_undefined() {
+    let options = options(UndefinedStyle::Void0);
+    assert_unchanged("function f(undefined) { return undefined; }", &options);
+    assert_unchanged("let undefined = 1; x = { undefined };", &options);
+    assert_unchanged("undefined = 1;", &options);
+    assert_unchanged("x = o.undefined;", &options);
+}
+
+#[test]
+fn keeps_delete_operands() {
+    assert_unchanged(
+        "x = delete undefined, y = delete (undefined);",
+        &options(UndefinedStyle::Void0),
+    );
+    assert_unchanged(
+        "x = delete void 0, y = delete (void 0);",
+        &options(UndefinedStyle::Undefined),
+    );
+}
+
+#[test]
+fn keeps_with_bodies() {
+    assert_unchanged(
+        "with (o) { f(undefined, () => undefined); }",
+        &options(UndefinedStyle::Void0),
+    );
+    assert_unchanged("with (o) f(void 0);", &options(UndefinedStyle::Undefined));
+    assert_transform(
+        "with (undefined) f(undefined);",
+        "with (void 0) f(undefined);",
+        &options(UndefinedStyle::Void0),
+    );
+}
+
+#[test]
+fn void_0_to_undefined() {
+    let options = options(UndefinedStyle::Undefined);
+    assert_transform(
+        "if (x === void 0) f(void 'a');",
+        "if (x === undefined) f(undefined);",
+        &options,
+    );
+    assert_transform("x = void -1, y = void void 0;", "x = undefined, y = undefined;", &options);
+}
+
+#[test]
+fn keeps_void_of_other_expressions() {
+    let options = options(UndefinedStyle::Undefined);
+    assert_unchanged("x = void f();", &options);
+    assert_unchanged("x = void y;", &options);
+    assert_unchanged("function f(undefined) { return void 0; }", &options);
+}
+
+#[test]
+fn is_opt_in() {
+    let output = test("x = undefined, y = void 0;", &TransformOptions::default()).unwrap();
+    assert_eq!(output, "x = undefined, y = void 0;\n");
+}
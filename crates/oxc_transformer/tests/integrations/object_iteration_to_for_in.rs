@@ -0,0 +1,225 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.object_iteration_to_for_in = true;
+    options
+}
+
+#[test]
+fn converts_entries() {
+    assert_transform(
+        "const obj = {}; for (const [k, v] of Object.entries(obj)) { log(k, v); }",
+        "const obj = {}; for (const k in obj) {
+            if (!Object.prototype.hasOwnProperty.call(obj, k)) continue;
+            const v = obj[k];
+            log(k, v);
+        }",
+        &options(),
+    );
+    assert_transform(
+        "function f(obj) { for (let [k] of Object.entries(obj)) log(k); }",
+        "function f(obj) { for (let k in obj) {
+            if (!Object.prototype.hasOwnProperty.call(obj, k)) continue;
+            log(k);
+        } }",
+        &options(),
+    );
+}
+
+#[test]
+fn converts_keys_and_values() {
+    assert_transform(
+        "function f(obj) { for (const k of Object.keys(obj)) log(k); }",
+        "function f(obj) { for (const k in obj) {
+            if (!Object.prototype.hasOwnProperty.call(obj, k)) continue;
+            log(k);
+        } }",
+        &options(),
+    );
+    assert_transform(
+        "function f(obj) { let total = 0; for (const v of Object.values(obj)) { total += v; } return total; }",
+        "function f(obj) { let total = 0; for (const _key in obj) {
+            if (!Object.prototype.hasOwnProperty.call(obj, _key)) continue;
+            const v = obj[_key];
+            total += v;
+        } return total; }",
+        &options(),
+    );
+}
+
+#[test]
+fn reads_properties_of_the_object() {
+    assert_transform(
+        "function f(obj, out) { for (const [k, v] of Object.entries(obj)) { out[k] = obj.scale * v + obj[k]; } }",
+        "function f(obj, out) { for (const k in obj) {
+            if (!Object.prototype.hasOwnProperty.call(obj, k)) continue;
+            const v = obj[k];
+            out[k] = obj.scale * v + obj[k];
+        } }",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_labels_and_continue() {
+    assert_transform(
+        "function f(obj) { outer: for (const [k, v] of Object.entries(obj)) { if (v) continue outer; break; } }",
+        "function f(obj) { outer: for (const k in obj) {
+            if (!Object.prototype.hasOwnProperty.call(obj, k)) continue;
+            const v = obj[k];
+            if (v) continue outer;
+            break;
+        } }",
+        &options(),
+    );
+}
+
+/// Loops are converted from the inside out.
+#[test]
+fn converts_nested_loops() {
+    assert_transform(
+        "function f(a, b) { for (const x of Object.values(a)) for (const y of Object.values(b)) log(x, y); }",
+        "function f(a, b) { for (const _key2 in a) {
+            if (!Object.prototype.hasOwnProperty.call(a, _key2)) continue;
+            const x = a[_key2];
+            for (const _key in b) {
+                if (!Object.prototype.hasOwnProperty.call(b, _key)) continue;
+                const y = b[_key];
+                log(x, y);
+            }
+        } }",
+        &options(),
+    );
+}
+
+#[test]
+fn leaves_unsupported_patterns() {
+    assert_unchanged(
+        "function f(obj) { for (const [k, v = 1] of Object.entries(obj)) log(k, v); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const [k, ...rest] of Object.entries(obj)) log(k, rest); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const [, v] of Object.entries(obj)) log(v); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const [k, [a]] of Object.entries(obj)) log(k, a); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const { length } of Object.keys(obj)) log(length); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const e of Object.entries(obj)) log(e); }",
+        &options(),
+    );
+    assert_unchanged("function f(obj) { for (const [k] of Object.keys(obj)) log(k); }", &options());
+    assert_unchanged(
+        "function f(obj) { for (var [k, v] of Object.entries(obj)) log(k, v); return k; }",
+        &options(),
+    );
+    assert_unchanged("function f(obj) { for (var k of Object.keys(obj)) log(k); }", &options());
+    assert_unchanged("function f(obj) { let k; for (k of Object.keys(obj)) log(k); }", &options());
+    assert_unchanged(
+        "async function f(obj) { for await (const k of Object.keys(obj)) log(k); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const k of Object.getOwnPropertyNames(obj)) log(k); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const k of Object.keys(obj, 1)) log(k); }",
+        &options(),
+    );
+    assert_unchanged("function f(obj) { for (const k of Object.keys?.(obj)) log(k); }", &options());
+    assert_unchanged("for (const k of Object.keys(obj)) log(k);", &options());
+    assert_unchanged("function f(obj) { for (const k of Object.keys(obj.x)) log(k); }", &options());
+}
+
+#[test]
+fn requires_global_object() {
+    assert_unchanged(
+        "function f(Object, obj) { for (const k of Object.keys(obj)) log(k); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const k of Object.keys(obj)) { let Object; log(k, Object); } }",
+        &options(),
+    );
+}
+
+#[test]
+fn leaves_reassigned_object() {
+    assert_unchanged(
+        "function f(obj) { obj = g(); for (const k of Object.keys(obj)) log(k); }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const k of Object.keys(obj)) { obj = {}; } }",
+        &options(),
+    );
+}
+
+/// `for...in` reads the values lazily, and skips deleted properties, so the body can't change
+/// the properties of the object.
+#[test]
+fn leaves_mutations_during_iteration() {
+    for body in [
+        "obj[k] = 1;",
+        "obj.x = 1;",
+        "obj.x++;",
+        "[obj.x] = [1];",
+        "({ a: obj[k] } = o);",
+        "delete obj[k];",
+        "delete obj.x;",
+        "obj.clear();",
+        "obj[k]();",
+        "obj.x`tag`;",
+        "Object.assign(obj, { x: 1 });",
+        "mutate(obj);",
+        "const alias = obj;",
+        "others.push(() => obj);",
+        "obj?.x;",
+    ] {
+        assert_unchanged(
+            &format!(
+                "function f(obj) {{ for (const [k, v] of Object.entries(obj)) {{ {body} }} }}"
+            ),
+            &options(),
+        );
+    }
+}
+
+/// The generated code refers to `Object`, the object and the loop variables in the body.
+#[test]
+fn leaves_conflicting_names() {
+    assert_unchanged(
+        "function f(obj) { for (const [k, v] of Object.entries(obj)) { let v; log(k, v); } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const k of Object.keys(obj)) { let k; log(k); } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const v of Object.values(obj)) { let obj = v; log(obj); } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const [k, v] of Object.entries(obj)) { function v() {} } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(obj) { for (const [k, Object] of Object.entries(obj)) log(k); }",
+        &options(),
+    );
+}
@@ -0,0 +1,37 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test};
+
+#[test]
+fn object_rest_destructuring() {
+    let cases = [
+        "const { a, ...b } = c;",
+        "const [, { ...a }, , b] = c;",
+        // Defaults can depend on earlier bindings.
+        "const { a = 1, b = a, ...c } = d;",
+        "const { a: { b, ...c }, d = 1, ...e } = f;",
+        // The source is evaluated once, before computed keys.
+        "const { [key()]: a, ...b } = getObj();",
+        "({ a, ...b } = c);",
+        "({ [key()]: a, ...b } = getObj());",
+        // Assignments which are not expression statements.
+        "function f() { return ({ a, ...b } = c); }",
+        "if (({ a, ...b } = c)) {}",
+        "x && ({ [key()]: a, ...b } = getObj());",
+        "for (const { a, ...b } of c) {}",
+        // Params which depend on bindings moved to the body are moved too.
+        "function f({ a, ...b }, c = a) {}",
+        "function f([, { ...a } = {}], { b, ...c } = a, d = c, e) {}",
+        "function f({ ...a }, b = () => a, [c = a], d) {}",
+        "const f = ({ ...a }, b = a) => b;",
+        "function f({ a, ...b }, c = 1) {}",
+        // Params which are redeclared by the body are moved to a `var`.
+        "function f({ ...a }, b = a) { var b; }",
+        // Bodies which are not statement lists.
+        "if (a) ({ q, ...r } = x);",
+        "for (;;) ({ [key()]: q, ...r } = x);",
+    ];
+
+    let options = TransformOptions::from_target("es2017").unwrap();
+    assert_snapshot("object_rest", &print_cases("", cases, |case| test(case, &options).unwrap()));
+}
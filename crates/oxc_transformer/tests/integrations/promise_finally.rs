@@ -0,0 +1,39 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, codegen, print_cases, test};
+
+#[test]
+fn promise_finally() {
+    let cases = [
+        "p.finally(() => cleanup());",
+        "fetchData().then(render).finally(hideSpinner);",
+        "p.finally(a).finally(b);",
+        "p.finally();",
+        "async function f() { await p.finally(async () => { await close(); }); }",
+        "p.finally(() => {}).then((value) => value);",
+        // Not transformed.
+        "p.finally(a, b); p.finally(...args); p.finally?.(a); p['finally'](a);",
+        "class C extends P { m() { return super.finally(a); } }",
+    ];
+
+    // `Promise.prototype.finally` is part of ES2018, optional chaining of ES2020.
+    let mut options = TransformOptions::from_target("es2020").unwrap();
+    options.plugins.promise_finally = true;
+    for case in cases {
+        assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
+    }
+
+    // Safari 11.1 supports `Promise.prototype.finally`, but not all of the ES2018 syntax.
+    let mut options = TransformOptions::from_target("safari11.1").unwrap();
+    options.plugins.promise_finally = true;
+    let case = "p.finally(() => cleanup());";
+    assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
+
+    let mut options = TransformOptions::from_target("es2017").unwrap();
+    options.plugins.promise_finally = true;
+    assert_snapshot(
+        "promise_finally",
+        &print_cases("", cases, |case| test(case, &options).unwrap()),
+    );
+}
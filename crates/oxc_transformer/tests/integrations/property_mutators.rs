@@ -0,0 +1,64 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, codegen, print_cases, test};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.property_mutators = true;
+    options
+}
+
+#[test]
+fn no_accessors() {
+    let options = options();
+    let cases = [
+        "({});",
+        "({ a: 1, b() {}, [c]: 2, ...d });",
+        "class C { get x() {} set x(v) {} static get y() {} }",
+        // `super` is not valid in the function expressions accessors are defined with.
+        "({ get x() { return super.x; } });",
+        "({ get x() {}, m() { return () => super.m(); } });",
+    ];
+    for case in cases {
+        assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
+    }
+}
+
+#[test]
+fn accessors() {
+    let cases = [
+        "({ get x() { return 1; } });",
+        "({ set x(v) { this._x = v; } });",
+        // A getter and a setter of the same key are merged.
+        "({ get x() { return this._x; }, set x(v) { this._x = v; } });",
+        "({ get x() {}, get y() {}, set x(v) {} });",
+        "({ get 'a-b'() {}, get 1() {}, set '1'(v) {} });",
+        // The later accessor replaces the earlier one of the same kind.
+        "({ get x() { return 1; }, get x() { return 2; } });",
+        // Properties keep their order around accessors.
+        "({ a: 1, get x() {}, b: 2 });",
+        "({ get x() {}, a: f(), set x(v) {} });",
+        "({ a: 1, get x() {}, b() {}, c, set y(v) {} });",
+        // Computed keys are evaluated in order.
+        "({ [a()]: 1, get [b()]() {}, set [b()](v) {}, [c()]: 2 });",
+        "({ get x() {}, [Symbol.iterator]: function* () {} });",
+        "({ ...a, get x() {}, ...b, c: 1, ...d });",
+        "({ get x() {}, __proto__: p });",
+        "({ get x() { return { get y() {} }; } });",
+        "var x = 1; f({ x, get y() { return x; } });",
+        // Shorthand properties and methods are converted to ES5 properties.
+        // (Codegen prints `a: a` as `a`.)
+        "({ a, b() {}, get x() {}, c, d() {} });",
+        // Properties with computed keys are defined in order.
+        "({ a: 1, [b()]: 2, get x() {}, [c()]() {}, d: 3 });",
+        // `super` in nested functions and classes refers to their own home object.
+        "({ get x() { return { m() { return super.m(); } }; } });",
+    ];
+
+    let options = options();
+    assert_snapshot(
+        "property_mutators",
+        &print_cases("", cases, |case| test(case, &options).unwrap()),
+    );
+}
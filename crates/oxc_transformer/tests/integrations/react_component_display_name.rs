@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, transform};
+
+/// Options which don't transform the JSX.
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.jsx.jsx_plugin = false;
+    options.plugins.react_component_display_name = true;
+    options
+}
+
+#[test]
+fn react_component_display_name() {
+    let cases = [
+        "function Button() { return <button />; }",
+        "const Button = () => <button />, Link = function() { return <a />; };",
+        "export function Button() { return <button />; } export let Link = () => <a />;",
+        "class Button extends React.Component { render() { return <button />; } }",
+        "class Button extends PureComponent {} const Link = class extends Component {};",
+        // `memo` and `forwardRef`, named after the variable.
+        "const Button = memo(() => <button />);",
+        "const Button = React.forwardRef(function(props, ref) { return <button ref={ref} />; });",
+        "export const Button = memo(forwardRef((props, ref) => <button ref={ref} />));",
+        // Anonymous default exports, named after the file.
+        "export default () => <header />;",
+        "export default function() { return <header />; }",
+        "export default class extends React.Component {}",
+        "export default memo(() => <header />);",
+        "export default function Header() { return <header />; }",
+        // Already named.
+        "function Button() { return <button />; } Button.displayName = 'MyButton';",
+        "const Button = memo(() => <button />); if (dev) { Button['displayName'] = 'B'; }",
+        "class Button extends Component { static displayName = 'MyButton'; }",
+        "class Button extends Component { static get displayName() { return 'B'; } }",
+        "export default function Header() { return <header />; } Header.displayName = 'H';",
+        // Not components.
+        "function Point(x, y) { this.x = x; this.y = y; }",
+        "function button() { return <button />; }",
+        "class Store extends Base {}",
+        "const Button = withStyles(styles)(() => <button />);",
+        "const Button = memo(Base); const Link = memo(function Link() { return <a />; });",
+        "const Button = withRouter(memo(() => <button />));",
+        "export default function header() { return <header />; }",
+        "export default 1;",
+        "function wrap() { function Inner() { return <div />; } return Inner; }",
+    ];
+
+    assert_snapshot(
+        "react_component_display_name",
+        &print_cases("", cases, |case| {
+            let (code, errors) =
+                transform(case, SourceType::jsx(), Path::new("Header.jsx"), &options());
+            assert!(errors.is_empty());
+            code
+        }),
+    );
+}
@@ -0,0 +1,68 @@
+use oxc_span::SourceType;
+use oxc_transformer::{JsxRuntime, TransformOptions};
+
+use crate::{assert_snapshot, print_cases, test_with_source_type};
+
+fn options(runtime: JsxRuntime, development: bool) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.jsx.runtime = runtime;
+    options.jsx.development = development;
+    options.plugins.react_constant_elements = true;
+    options
+}
+
+#[test]
+fn react_constant_elements() {
+    let cases = [
+        "function Spinner() { return <div className='spinner'><span>Loading...</span></div>; }",
+        "const Icon = () => <svg viewBox='0 0 10 10'><path d='M0 0L10 10' /></svg>;",
+        "function List() { return <ul><li key='a'>a</li><li key={'b'}>{1}{`b`}{null}{/* c */}</li></ul>; }",
+        "function F() { return <><br /><hr disabled /></>; }",
+        "function F() { return <div title={<b>bold</b>} />; }",
+        "function F() { return [<i key='1' />, <i key='2' />]; }",
+        "class C { render() { return <div>static</div>; } }",
+        // Imports can't be re-assigned.
+        "import Foo from 'foo'; function F() { return <div><Foo title='a' /></div>; }",
+        // Not hoisted.
+        "function F({ a }) { return <div>{a}<p>static</p></div>; }",
+        "let Foo = A; function F() { return <Foo />; }",
+        "function F(Foo) { return <Foo />; }",
+        "const top = <div>top level</div>;",
+        "function F({ a }) { return <div className={a} />; }",
+        "function F(props) { return <div {...props} />; }",
+        "function F({ onClick }) { return <button onClick={onClick}>click</button>; }",
+        "function F() { return <button onClick={() => {}}>click</button>; }",
+        "function F(ref) { return <div ref={ref} />; }",
+        "function F() { return <div ref='legacy' />; }",
+        "function F() { return <Foo />; }",
+        "function F() { return <div><Foo /></div>; }",
+        "function F() { return <a.b />; }",
+        "function F({ key }) { return <li key={key}>item</li>; }",
+        "function F() { return <div>{...children}</div>; }",
+        "function F({ a }) { return <div>{`${a}`}</div>; }",
+    ];
+
+    let snapshot = [("automatic", JsxRuntime::Automatic), ("classic", JsxRuntime::Classic)]
+        .into_iter()
+        .map(|(name, runtime)| {
+            let options = options(runtime, false);
+            print_cases(name, cases, |case| {
+                test_with_source_type(case, SourceType::jsx(), &options).unwrap()
+            })
+        })
+        .collect::<String>();
+    assert_snapshot("react_constant_elements", &snapshot);
+}
+
+#[test]
+fn react_constant_elements_development() {
+    // `jsxDEV` calls reference `this` and the source location, so nothing is hoisted.
+    let source_text = "function F() { return <div>static</div>; }";
+    let result = test_with_source_type(
+        source_text,
+        SourceType::jsx(),
+        &options(JsxRuntime::Automatic, true),
+    )
+    .unwrap();
+    assert!(!result.contains("_ref"), "{result}");
+}
@@ -0,0 +1,127 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, recognize_compiled_enum};
+
+use crate::{codegen, test};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.reconstruct_ts_enums = true;
+    options
+}
+
+fn reconstructs(source: &str, expected: &str) {
+    assert_eq!(test(source, &options()), Ok(codegen(expected, SourceType::ts())), "{source}");
+}
+
+fn leaves_unchanged(source: &str) {
+    assert_eq!(test(source, &options()), Ok(codegen(source, SourceType::mjs())), "{source}");
+}
+
+#[test]
+fn tsc_output() {
+    reconstructs(
+        r#"
+var Direction;
+(function (Direction) {
+    Direction[Direction["Up"] = 0] = "Up";
+    Direction[Direction["Down"] = 1] = "Down";
+    Direction[Direction["Left"] = 5] = "Left";
+    Direction[Direction["Right"] = 6] = "Right";
+})(Direction || (Direction = {}));
+"#,
+        "enum Direction { Up, Down, Left = 5, Right }",
+    );
+    reconstructs(
+        r#"
+export var Color;
+(function (Color) {
+    Color["Red"] = "red";
+    Color["Green"] = "green";
+})(Color || (Color = {}));
+"#,
+        "export enum Color { Red = 'red', Green = 'green' }",
+    );
+}
+
+#[test]
+fn babel_and_oxc_output() {
+    reconstructs(
+        r#"
+var Foo = /* @__PURE__ */ function(Foo) {
+  Foo[Foo["a"] = 10] = "a";
+  Foo[Foo["b"] = 10] = "b";
+  Foo[Foo["c"] = Foo.b + x] = "c";
+  Foo["d"] = "d";
+  Foo[Foo["e-f"] = -1] = "e-f";
+  return Foo;
+}(Foo || {});
+"#,
+        "enum Foo { a = 10, b = 10, c = Foo.b + x, d = 'd', 'e-f' = -1 }",
+    );
+    reconstructs(
+        r#"
+function f() {
+  let Inner = function(Inner) {
+    Inner[Inner["a"] = 0] = "a";
+    return Inner;
+  }({});
+  return Inner.a;
+}
+"#,
+        "function f() { enum Inner { a } return Inner.a; }",
+    );
+}
+
+#[test]
+fn bails_on_partial_patterns() {
+    for source in [
+        // Numeric member without the reverse mapping.
+        "var E; (function (E) { E['A'] = 0; })(E || (E = {}));",
+        // Reverse mapping to a different name.
+        "var E; (function (E) { E[E['A'] = 0] = 'B'; })(E || (E = {}));",
+        // Duplicate member.
+        "var E; (function (E) { E['A'] = 'a'; E['A'] = 'b'; })(E || (E = {}));",
+        // Unrelated statement.
+        "var E; (function (E) { E['A'] = 'a'; console.log(E); })(E || (E = {}));",
+        // Parameter name doesn't match.
+        "var E; (function (F) { F['A'] = 'a'; })(E || (E = {}));",
+        // Missing `return E`.
+        "var E = function (E) { E['A'] = 'a'; }(E || {});",
+        // `var` without assignment in the argument.
+        "var E; (function (E) { E['A'] = 'a'; })(E || {});",
+        // Merged with an earlier declaration.
+        "(function (E) { E['A'] = 'a'; })(E || (E = {}));",
+        // Not an IIFE of a plain function.
+        "var E; (async function (E) { E['A'] = 'a'; })(E || (E = {}));",
+        "var E; ((E) => { E['A'] = 'a'; })(E || (E = {}));",
+    ] {
+        leaves_unchanged(source);
+    }
+}
+
+#[test]
+fn recognizes_reverse_mappings() {
+    let source = r#"
+var E;
+(function (E) {
+    E[E["A"] = 1] = "A";
+    E["B"] = "b";
+})(E || (E = {}));
+"#;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+    let compiled = recognize_compiled_enum(&ret.program.body).unwrap();
+    assert_eq!(compiled.id.name, "E");
+    assert!(!compiled.exported);
+    assert_eq!(compiled.statement_count, 2);
+    let members = compiled
+        .members
+        .iter()
+        .map(|member| (member.name.as_str(), member.reverse_mapped))
+        .collect::<Vec<_>>();
+    assert_eq!(members, [("A", true), ("B", false)]);
+
+    assert!(recognize_compiled_enum(&ret.program.body[1..]).is_none());
+}
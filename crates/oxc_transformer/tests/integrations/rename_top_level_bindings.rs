@@ -0,0 +1,166 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{RenameMap, rename_top_level_bindings};
+
+use crate::codegen;
+
+/// Renames the bindings of `sources`, and returns the printed modules and their rename maps.
+fn rename(sources: &[&str]) -> Result<Vec<(String, RenameMap)>, String> {
+    let allocator = Allocator::default();
+    let mut programs = sources
+        .iter()
+        .map(|source| {
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+            assert!(ret.errors.is_empty());
+            ret.program
+        })
+        .collect::<Vec<_>>();
+    let mut scopings = programs
+        .iter()
+        .map(|program| SemanticBuilder::new().build(program).semantic.into_scoping())
+        .collect::<Vec<_>>();
+
+    let mut modules = programs.iter_mut().zip(scopings.iter_mut()).collect::<Vec<_>>();
+    let rename_maps =
+        rename_top_level_bindings(&allocator, &mut modules).map_err(|err| err.to_string())?;
+
+    Ok(programs
+        .into_iter()
+        .zip(scopings)
+        .zip(rename_maps)
+        .map(|((program, scoping), rename_map)| {
+            let code = Codegen::new()
+                .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+                .with_scoping(Some(scoping))
+                .build(&program)
+                .code;
+            (code, rename_map)
+        })
+        .collect())
+}
+
+fn renames(sources: &[&str], expected: &[&str]) {
+    let modules = rename(sources).unwrap();
+    for (index, ((code, _), expected)) in modules.iter().zip(expected).enumerate() {
+        assert_eq!(code, &codegen(expected, SourceType::mjs()), "module {index}");
+    }
+}
+
+fn rename_map(pairs: &[(&str, &str)]) -> RenameMap {
+    pairs.iter().map(|(from, to)| ((*from).to_string(), (*to).to_string())).collect()
+}
+
+#[test]
+fn renames_colliding_bindings() {
+    renames(
+        &[
+            "const name = 'a'; function greet() { return name; } greet();",
+            "
+            const name = 'b';
+            function greet() { return name; }
+            const { value } = { value: name };
+            console.log(greet(), value, { name });
+            ",
+        ],
+        &[
+            "const name = 'a'; function greet() { return name; } greet();",
+            "
+            const name$1 = 'b';
+            function greet$1() { return name$1; }
+            const { value } = { value: name$1 };
+            console.log(greet$1(), value, { name: name$1 });
+            ",
+        ],
+    );
+}
+
+#[test]
+fn returns_rename_maps() {
+    let modules = rename(&[
+        "var a = 1, b = 2;",
+        "var a = 3; import { b } from 'b';",
+        "let a = 4; class b {}",
+    ])
+    .unwrap();
+    assert!(modules[0].1.is_empty());
+    assert_eq!(modules[1].1, rename_map(&[("a", "a$1"), ("b", "b$1")]));
+    assert_eq!(modules[2].1, rename_map(&[("a", "a$2"), ("b", "b$2")]));
+    assert_eq!(
+        modules[1].0,
+        codegen("var a$1 = 3; import { b as b$1 } from 'b';", SourceType::mjs())
+    );
+}
+
+#[test]
+fn skips_names_used_in_the_module() {
+    renames(
+        &["let x = 1;", "let x = 2; function f(x$1) { return x + x$1; } let x$2 = x;"],
+        &["let x = 1;", "let x$3 = 2; function f(x$1) { return x$3 + x$1; } let x$2 = x$3;"],
+    );
+}
+
+#[test]
+fn renames_bindings_shadowing_globals() {
+    renames(
+        &["const document = {}; export { document };", "console.log(document.title);"],
+        &[
+            "const document$1 = {}; export { document$1 as document };",
+            "console.log(document.title);",
+        ],
+    );
+    // Globals are never renamed, even if they are referenced by multiple modules.
+    renames(&["window.a = 1;", "window.b = 2;"], &["window.a = 1;", "window.b = 2;"]);
+}
+
+#[test]
+fn keeps_exported_names() {
+    renames(
+        &[
+            "export const a = 1; export function f() {}",
+            "
+            export const a = 2, b = 3;
+            export function f() {}
+            export class C {}
+            export default function g() { return a; }
+            const c = 4;
+            export { c as a2 };
+            ",
+        ],
+        &[
+            "export const a = 1; export function f() {}",
+            "
+            const a$1 = 2, b = 3;
+            export { a$1 as a, b as b };
+            function f$1() {}
+            export { f$1 as f };
+            export class C {}
+            export default function g() { return a$1; }
+            const c = 4;
+            export { c as a2 };
+            ",
+        ],
+    );
+}
+
+#[test]
+fn bails_out_on_eval_and_with() {
+    assert_eq!(
+        rename(&["let a;", "let a; eval('a');"]).unwrap_err(),
+        "Cannot rename the top level bindings of a module which contains a direct `eval`"
+    );
+    assert!(rename(&["let a;", "let a; (0, eval)('a');"]).is_ok());
+
+    let allocator = Allocator::default();
+    let mut program =
+        Parser::new(&allocator, "var a; with (obj) { a; }", SourceType::cjs()).parse().program;
+    let mut scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    assert_eq!(
+        rename_top_level_bindings(&allocator, &mut [(&mut program, &mut scoping)])
+            .unwrap_err()
+            .to_string(),
+        "Cannot rename the top level bindings of a module which contains a `with` statement"
+    );
+}
@@ -0,0 +1,38 @@
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test};
+
+/// `return await` in a `try` block must keep waiting for the promise, so that `catch`
+/// handles its rejection and `finally` runs after it settles. It is lowered to `return yield`,
+/// never to `return` of the promise.
+#[test]
+fn return_await() {
+    let cases = [
+        "async function f() { try { return await g(); } finally { cleanup(); } }",
+        "async function f() { try { return await g(); } catch (e) { return await h(e); } finally { cleanup(); } }",
+        "async function f() { try { try { return await g(); } finally { a(); } } finally { b(); } }",
+        "async function f() { try { return await g(); } catch { try { return await h(); } finally { a(); } } }",
+        "async function f() { for (const x of xs) { try { return await g(x); } finally { done(x); } } }",
+        "async function f() { while (true) { try { if (await ready()) return await g(); } catch (e) { continue; } } }",
+        "async function f() { try { for await (const x of xs) return await g(x); } finally { cleanup(); } }",
+        "const f = async () => { try { return await g(); } finally { cleanup(); } };",
+        "class A { async m() { try { return await super.g(); } finally { this.cleanup(); } } }",
+        "async function* f() { try { return await g(); } finally { cleanup(); } }",
+        // Outside of `try`, the result is the same either way.
+        "async function f() { return await g(); }",
+        "const f = async () => await g();",
+    ];
+
+    let options = TransformOptions::from_target("es2016").unwrap();
+    let snapshot = print_cases("", cases, |case| {
+        let result = test(case, &options).unwrap();
+        // Every `await` of a returned value is still awaited.
+        assert_eq!(
+            result.matches("return yield").count(),
+            case.matches("return await").count() + case.matches("=> await").count(),
+            "{case}\n{result}"
+        );
+        result
+    });
+    assert_snapshot("return_await", &snapshot);
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use oxc_span::SourceType;
+use oxc_transformer::{ExpandExportStarOptions, TransformOptions};
+
+use crate::{assert_transform, assert_unchanged, codegen, test, test_with_source_type};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.split_re_exports = true;
+    options
+}
+
+#[test]
+fn splits_re_exports() {
+    assert_transform(
+        "export { a, b } from './m';",
+        "export { a } from './m';\nexport { b } from './m';",
+        &options(),
+    );
+    assert_transform(
+        "export { a as x, 'b-c' as 'd-e', f as 'g' } from './m';",
+        "export { a as x } from './m';\nexport { 'b-c' as 'd-e' } from './m';\nexport { f as 'g' } from './m';",
+        &options(),
+    );
+    assert_transform(
+        "import x from './x'; export { a, b } from './m'; export const c = 1;",
+        "import x from './x';\nexport { a } from './m';\nexport { b } from './m';\nexport const c = 1;",
+        &options(),
+    );
+}
+
+#[test]
+fn default_re_exports() {
+    assert_transform(
+        "export { default, a } from './m';",
+        "export { default } from './m';\nexport { a } from './m';",
+        &options(),
+    );
+    assert_transform(
+        "export { default as x, default as y } from './m';",
+        "export { default as x } from './m';\nexport { default as y } from './m';",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_import_attributes() {
+    assert_transform(
+        "export { a, b } from './data.json' with { type: 'json' };",
+        "export { a } from './data.json' with { type: 'json' };\nexport { b } from './data.json' with { type: 'json' };",
+        &options(),
+    );
+}
+
+#[test]
+fn leaves_other_exports_untouched() {
+    for source in [
+        "export { a } from './m';",
+        "export {} from './m';",
+        "const a = 1, b = 2; export { a, b };",
+        "export * from './m';",
+        "export * as ns from './m';",
+    ] {
+        assert_unchanged(source, &options());
+    }
+}
+
+#[test]
+fn splits_expanded_export_star() {
+    let mut options = options();
+    options.plugins.expand_export_star =
+        Some(ExpandExportStarOptions::new(Arc::new(|_| Some(vec!["a".into(), "b".into()]))));
+    assert_eq!(
+        test("export * from './m';", &options),
+        Ok(codegen("export { a } from './m';\nexport { b } from './m';", SourceType::mjs()))
+    );
+}
+
+#[test]
+fn type_only_specifiers() {
+    let mut options = options();
+    options.typescript.only_remove_type_imports = true;
+    let transform =
+        |source_text: &str| test_with_source_type(source_text, SourceType::ts(), &options);
+    assert_eq!(
+        transform("export { type A, b, type C, d } from './m';"),
+        Ok(codegen("export { b } from './m';\nexport { d } from './m';", SourceType::mjs()))
+    );
+    // The type-only re-export is removed as a whole by the TypeScript transform.
+    assert_eq!(
+        transform("export type { A, B } from './m';"),
+        Ok(codegen("export {};", SourceType::mjs()))
+    );
+}
@@ -0,0 +1,39 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, codegen, print_cases, test};
+
+#[test]
+fn spread_arguments() {
+    let cases = [
+        "f(...args);",
+        "f(a, ...b, c);",
+        "f(...a, ...b);",
+        "f(a, b, ...c);",
+        "obj.method(...args);",
+        "obj[key](a, ...args);",
+        // The receiver is evaluated once.
+        "getObj().method(...args);",
+        "a.b.c(...args);",
+        "console.log(...args);",
+        "this.method(...args);",
+        "(obj.method)(...args);",
+        "(0, obj.method)(...args);",
+        "class C extends B { m() { super.m(...args); } constructor() { super(...args); } }",
+        "new C(...args);",
+        "new C(a, ...args);",
+        "new a.C(...args);",
+        "f(...g(...args));",
+        "const obj = {}; obj.method(...args);",
+        "f(a, b);",
+    ];
+
+    // Spread arguments are supported in Chrome 46.
+    let options = TransformOptions::from_target("chrome46").unwrap();
+    for case in cases {
+        assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
+    }
+
+    let options = TransformOptions::from_target("chrome45").unwrap();
+    assert_snapshot("spread", &print_cases("", cases, |case| test(case, &options).unwrap()));
+}
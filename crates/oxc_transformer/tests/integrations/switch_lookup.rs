@@ -0,0 +1,188 @@
+use oxc_transformer::{SwitchLookupOptions, TransformOptions};
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.switch_lookup = Some(SwitchLookupOptions { min_cases: 3 });
+    options
+}
+
+#[test]
+fn converts_returns() {
+    assert_transform(
+        "function f(k) {
+            switch (k) {
+                case 'a': return 1;
+                case 'b': return 'two';
+                case `c`: return -3;
+                default: return fallback(k);
+            }
+        }",
+        "var _lookup = { __proto__: null, a: 1, b: 'two', c: -3 };
+        function f(k) {
+            return typeof k === 'string' && k in _lookup ? _lookup[k] : fallback(k);
+        }",
+        &options(),
+    );
+}
+
+#[test]
+fn converts_returns_without_default() {
+    assert_transform(
+        "function f(k) {
+            switch (k) { case 'a': return true; case 'b': return null; case 'c-d': return void 0; }
+            return false;
+        }",
+        "var _lookup = { __proto__: null, a: true, b: null, 'c-d': void 0 };
+        function f(k) {
+            if (typeof k === 'string' && k in _lookup) return _lookup[k];
+            return false;
+        }",
+        &options(),
+    );
+    assert_transform("function f(k) {
+            switch (k) { case 'a': return 1; case 'b': return 2; case 'c': return 3; default: throw new Error(k); }
+        }", "var _lookup = { __proto__: null, a: 1, b: 2, c: 3 };
+        function f(k) {
+            if (typeof k === 'string' && k in _lookup) return _lookup[k];
+            throw new Error(k);
+        }", &options());
+}
+
+#[test]
+fn converts_assignments() {
+    assert_transform(
+        "let k, x;
+        switch (k) {
+            case 'a': x = 1; break;
+            case 'b': x = 2; break;
+            case 'c': x = 3; break;
+            default: x = 0;
+        }",
+        "var _lookup = { __proto__: null, a: 1, b: 2, c: 3 };
+        let k, x;
+        x = typeof k === 'string' && k in _lookup ? _lookup[k] : 0;",
+        &options(),
+    );
+    assert_transform(
+        "let x;
+        switch (k) { case 'a': x = 1; break; case 'b': x = 2; break; case 'c': x = 3 }", // `k` is a global, which is read once.
+        "var _lookup = { __proto__: null, a: 1, b: 2, c: 3 };
+        var _key;
+        let x;
+        if (typeof (_key = k) === 'string' && _key in _lookup) x = _lookup[_key];",
+        &options(),
+    );
+}
+
+#[test]
+fn shares_values_of_empty_cases() {
+    assert_transform(
+        "function f(k) { switch (k) { case 'a': case 'b': return 1; case 'c': return 2; } }",
+        "var _lookup = { __proto__: null, a: 1, b: 1, c: 2 };
+        function f(k) { if (typeof k === 'string' && k in _lookup) return _lookup[k]; }",
+        &options(),
+    );
+}
+
+#[test]
+fn keeps_first_duplicate_key() {
+    assert_transform("function f(k) {
+            switch (k) { case 'a': return 1; case 'b': return 2; case 'a': return 3; case 'c': return 4; }
+        }", "var _lookup = { __proto__: null, a: 1, b: 2, c: 4 };
+        function f(k) { if (typeof k === 'string' && k in _lookup) return _lookup[k]; }", &options());
+}
+
+#[test]
+fn evaluates_discriminant_once() {
+    assert_transform(
+        "function f() { switch (key()) { case 'a': return 1; case 'b': return 2; case 'c': return 3; } }",
+        "var _lookup = { __proto__: null, a: 1, b: 2, c: 3 };
+        function f() {
+            var _key;
+            if (typeof (_key = key()) === 'string' && _key in _lookup) return _lookup[_key];
+        }",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_small_switches() {
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; } }",
+        &options(),
+    );
+    // Duplicate keys are not counted.
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; case 'a': return 3; } }",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_fall_through() {
+    assert_unchanged(
+        "let x;
+        switch (k) { case 'a': x = 1; case 'b': x = 2; break; case 'c': x = 3; break; }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; case 'c': case 'd': } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; case 'c': default: return 3; } }",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_side_effects() {
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return g(); case 'c': return 3; } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': log(); return 2; case 'c': return 3; } }",
+        &options(),
+    );
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; case 'c': return 3; default: return () => k; } }",
+        &options(),
+    );
+}
+
+#[test]
+fn skips_unsupported_cases() {
+    // Not a string.
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; case 3: return 3; } }",
+        &options(),
+    );
+    // `__proto__` would set the prototype of the lookup object.
+    assert_unchanged(
+        "function f(k) { switch (k) { case 'a': return 1; case 'b': return 2; case '__proto__': return 3; } }",
+        &options(),
+    );
+    // `default` is not the last case.
+    assert_unchanged(
+        "function f(k) { switch (k) { default: return 0; case 'a': return 1; case 'b': return 2; case 'c': return 3; } }",
+        &options(),
+    );
+    // Different variables.
+    assert_unchanged(
+        "let x, y;
+        switch (k) { case 'a': x = 1; break; case 'b': y = 2; break; case 'c': x = 3; break; }",
+        &options(),
+    );
+    // Labeled `break`.
+    assert_unchanged("let x;
+        outer: switch (k) { case 'a': x = 1; break outer; case 'b': x = 2; break; case 'c': x = 3; break; }", &options());
+    // Compound assignment.
+    assert_unchanged(
+        "let x;
+        switch (k) { case 'a': x += 1; break; case 'b': x = 2; break; case 'c': x = 3; break; }",
+        &options(),
+    );
+}
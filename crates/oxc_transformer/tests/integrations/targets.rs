@@ -35,3 +35,70 @@ fn targets() {
         assert_eq!(test(case, &options), test(case, &options_node));
     }
 }
+
+#[test]
+fn target_matrix() {
+    // Each feature is lowered when the lowest engine in the matrix does not support it.
+    let env =
+        EnvOptions::from_engine_versions([("chrome", "80"), ("node", "14"), ("safari", "13")])
+            .unwrap();
+    assert!(env.es2015.arrow_function.is_none());
+    assert!(!env.es2016.exponentiation_operator);
+    assert!(!env.es2017.async_to_generator);
+    assert!(env.es2018.object_rest_spread.is_none());
+    assert!(!env.es2019.optional_catch_binding);
+    // `safari13` does not support nullish coalescing and optional chaining.
+    assert!(env.es2020.nullish_coalescing_operator);
+    assert!(env.es2020.optional_chaining);
+    assert!(env.es2021.logical_assignment_operators);
+    assert!(env.es2022.class_static_block);
+    assert!(env.es2022.class_properties.is_some());
+
+    // Raising every engine disables the transforms.
+    let env =
+        EnvOptions::from_engine_versions([("chrome", "91"), ("node", "16.1"), ("safari", "14")])
+            .unwrap();
+    assert!(!env.es2020.nullish_coalescing_operator);
+    assert!(!env.es2020.optional_chaining);
+    assert!(!env.es2021.logical_assignment_operators);
+
+    // A single old engine in the matrix enables the transform.
+    let env = EnvOptions::from_engine_versions([("chrome", "100"), ("firefox", "50")]).unwrap();
+    assert!(env.es2017.async_to_generator);
+    assert!(env.es2018.object_rest_spread.is_some());
+
+    assert_eq!(
+        EnvOptions::from_engine_versions([("chrome", "80"), ("netscape", "4")]).unwrap_err(),
+        "engine 'netscape' is not supported."
+    );
+}
+
+#[test]
+fn target_matrix_without_lowering_emits_diagnostic() {
+    // BigInt cannot be lowered, so an error is reported instead of silently emitting it.
+    let options = TransformOptions {
+        env: EnvOptions::from_engine_versions([("chrome", "60"), ("node", "14")]).unwrap(),
+        ..TransformOptions::default()
+    };
+    let errors = test("1n", &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "Big integer literals are not available in the configured target environment."
+    );
+}
+
+#[test]
+fn optional_catch_binding_target() {
+    // Optional catch binding is supported since Chrome 66.
+    let options = |query| TransformOptions {
+        env: EnvOptions::from_browserslist_query(query).unwrap(),
+        ..TransformOptions::default()
+    };
+    let code = "try {} catch { try {} catch {} }";
+    assert_eq!(test(code, &options("chrome 66")), Ok(codegen(code, SourceType::mjs())));
+    assert_eq!(
+        test(code, &options("chrome 65")),
+        Ok(codegen("try {} catch (_unused) { try {} catch (_unused2) {} }", SourceType::mjs()))
+    );
+}
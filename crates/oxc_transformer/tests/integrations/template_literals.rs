@@ -0,0 +1,31 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, codegen, print_cases, test};
+
+#[test]
+fn tagged_templates() {
+    let cases = [
+        "tag`a${b}c`;",
+        "tag`a`; tag`a`;",
+        "function f() { return tag`a`; }",
+        "obj.tag`a${b}`; obj[key]`a`;",
+        r"String.raw`\n\u{41}`;",
+        // Invalid escape sequences have no cooked value.
+        r"tag`\unicode and \u{55}`;",
+        "tag`a${tag`b`}`;",
+        "`a${b}`;",
+    ];
+
+    // Template literals are supported in es2015.
+    let options = TransformOptions::from_target("es2015").unwrap();
+    for case in cases {
+        assert_eq!(test(case, &options), Ok(codegen(case, SourceType::mjs())));
+    }
+
+    let options = TransformOptions::from_target("chrome40").unwrap();
+    assert_snapshot(
+        "template_literals",
+        &print_cases("", cases, |case| test(case, &options).unwrap()),
+    );
+}
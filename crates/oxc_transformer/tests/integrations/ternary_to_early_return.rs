@@ -0,0 +1,91 @@
+use oxc_span::SourceType;
+use oxc_transformer::{TernaryToEarlyReturnOptions, TransformOptions};
+
+use crate::{assert_transform, assert_unchanged, codegen, test};
+
+fn options(min_depth: u32) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.ternary_to_early_return = Some(TernaryToEarlyReturnOptions { min_depth });
+    options
+}
+
+#[test]
+fn converts_chains() {
+    assert_transform(
+        "const size = n < 10 ? 'small' : n < 100 ? 'medium' : n < 1000 ? 'large' : 'huge';",
+        "const size = (() => {
+            if (n < 10) return 'small';
+            if (n < 100) return 'medium';
+            if (n < 1000) return 'large';
+            return 'huge';
+        })();",
+        &options(3),
+    );
+    assert_transform(
+        "let x = a ? 1 : (b ? 2 : (c ? 3 : 4)), y;",
+        "let x = (() => { if (a) return 1; if (b) return 2; if (c) return 3; return 4; })(), y;",
+        &options(3),
+    );
+}
+
+#[test]
+fn keeps_nested_consequents() {
+    assert_transform(
+        "var x = a ? (p ? 1 : 2) : b ? 3 : c ? 4 : 5;",
+        "var x = (() => { if (a) return p ? 1 : 2; if (b) return 3; if (c) return 4; return 5; })();",
+        &options(3),
+    );
+}
+
+#[test]
+fn respects_min_depth() {
+    assert_unchanged("const x = a ? 1 : b ? 2 : 3;", &options(3));
+    assert_unchanged("const x = a ? (b ? 1 : 2) : (c ? 3 : 4);", &options(3));
+    assert_eq!(
+        test("const x = a ? 1 : 2;", &options(1)),
+        Ok(codegen("const x = (() => { if (a) return 1; return 2; })();", SourceType::mjs()))
+    );
+}
+
+#[test]
+fn keeps_this() {
+    assert_transform(
+        "class A { m() { const x = this.a ? 1 : this.b ? 2 : super.c ? 3 : 4; } }",
+        "class A { m() { const x = (() => { if (this.a) return 1; if (this.b) return 2; if (super.c) return 3; return 4; })(); } }",
+        &options(3),
+    );
+}
+
+#[test]
+fn skips_function_boundaries() {
+    assert_unchanged(
+        "function f() { const x = a ? 1 : b ? arguments[0] : c ? 3 : 4; }",
+        &options(3),
+    );
+    assert_unchanged(
+        "function f() { const x = a ? 1 : b ? () => arguments : c ? 3 : 4; }",
+        &options(3),
+    );
+    assert_unchanged(
+        "async function f() { const x = a ? 1 : b ? await g() : c ? 3 : 4; }",
+        &options(3),
+    );
+    assert_unchanged("function* f() { const x = a ? 1 : b ? yield : c ? 3 : 4; }", &options(3));
+    // `arguments`, `await` and `yield` of nested functions.
+    assert_transform(
+        "const x = a ? function() { return arguments; } : b ? async () => await g() : c ? 3 : 4;",
+        "const x = (() => {
+            if (a) return function() { return arguments; };
+            if (b) return async () => await g();
+            if (c) return 3;
+            return 4;
+        })();",
+        &options(3),
+    );
+}
+
+#[test]
+fn skips_other_expressions() {
+    assert_unchanged("x = a ? 1 : b ? 2 : c ? 3 : 4;", &options(3));
+    assert_unchanged("f(a ? 1 : b ? 2 : c ? 3 : 4);", &options(3));
+}
@@ -0,0 +1,84 @@
+use oxc_transformer::{ThenToCatchOptions, TransformOptions};
+
+use crate::{assert_transform, assert_unchanged};
+
+fn options(unsafe_split: bool) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.then_to_catch = Some(ThenToCatchOptions { unsafe_split });
+    options
+}
+
+#[test]
+fn nullish_on_fulfilled() {
+    for unsafe_split in [false, true] {
+        assert_transform("p.then(null, onError);", "p.catch(onError);", &options(unsafe_split));
+        assert_transform(
+            "p.then(undefined, onError);",
+            "p.catch(onError);",
+            &options(unsafe_split),
+        );
+        assert_transform(
+            "p.then(void 0, (e) => log(e));",
+            "p.catch((e) => log(e));",
+            &options(unsafe_split),
+        );
+        assert_transform(
+            "fetchData().then(null, a).then(null, b);",
+            "fetchData().catch(a).catch(b);",
+            &options(unsafe_split),
+        );
+    }
+    // Local `undefined`.
+    assert_unchanged("function f(undefined) { p.then(undefined, onError); }", &options(false));
+}
+
+#[test]
+fn unsafe_split() {
+    assert_unchanged("p.then(render, showError);", &options(false));
+    assert_transform(
+        "p.then(render, showError);",
+        "p.then(render).catch(showError);",
+        &options(true),
+    );
+    assert_transform(
+        "fetchData().then(this.render, (e) => log(e));",
+        "fetchData().then(this.render).catch((e) => log(e));",
+        &options(true),
+    );
+    assert_transform(
+        "p.then(a.b.render, function(e) { log(e); });",
+        "p.then(a.b.render).catch(function(e) { log(e); });",
+        &options(true),
+    );
+    assert_transform(
+        "p.then(a, b).then(c, d);",
+        "p.then(a).catch(b).then(c).catch(d);",
+        &options(true),
+    );
+}
+
+#[test]
+fn skips_unsafe_handlers() {
+    // `onFulfilled` is not a simple reference.
+    assert_unchanged("p.then((v) => use(v), showError);", &options(true));
+    assert_unchanged("p.then(getRender(), showError);", &options(true));
+    assert_unchanged("p.then(a?.render, showError);", &options(true));
+    assert_unchanged("p.then(a[key], showError);", &options(true));
+    // Evaluating `onRejected` has side effects.
+    assert_unchanged("p.then(render, getHandler());", &options(true));
+}
+
+#[test]
+fn skips_other_calls() {
+    // No `onRejected`.
+    assert_unchanged("p.then(render);", &options(true));
+    assert_unchanged("p.then(null);", &options(true));
+    assert_unchanged("p.then(render, showError, extra);", &options(true));
+    assert_unchanged("p.then(...handlers); p.then(null, ...handlers);", &options(true));
+    assert_unchanged("p?.then(null, showError); p.then?.(null, showError);", &options(true));
+    assert_unchanged("p['then'](null, showError);", &options(true));
+    assert_unchanged(
+        "class C extends P { m() { return super.then(null, showError); } }",
+        &options(true),
+    );
+}
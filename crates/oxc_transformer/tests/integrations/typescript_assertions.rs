@@ -0,0 +1,142 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::{codegen, test_with_source_type};
+
+#[track_caller]
+fn assert_transform(source: &str, expected: &str) {
+    assert_eq!(
+        test_with_source_type(source, SourceType::ts(), &TransformOptions::default()),
+        Ok(codegen(expected, SourceType::mjs())),
+        "{source}"
+    );
+}
+
+#[test]
+fn non_null_call_targets() {
+    assert_transform("fn!();", "fn();");
+    // Keeps `this` of the call.
+    assert_transform("a.b!();", "a.b();");
+    assert_transform("(a.b)!();", "a.b();");
+    assert_transform("(a.b as any)!();", "a.b();");
+    assert_transform("a.b!`t`;", "a.b`t`;");
+    assert_transform("(0, a.b)!();", "(0, a.b)();");
+    assert_transform("new a!();", "new a();");
+    assert_transform("a!!();", "a();");
+}
+
+#[test]
+fn non_null_in_optional_chains() {
+    // Within a chain, `!` doesn't end the chain.
+    assert_transform("a?.b!.c;", "a?.b.c;");
+    assert_transform("a?.b!();", "a?.b();");
+    assert_transform("a?.b![c]();", "a?.b[c]();");
+    assert_transform("a!?.b;", "a?.b;");
+    assert_transform("a?.b!?.c;", "a?.b?.c;");
+    assert_transform("a?.b!;", "a?.b;");
+    // A parenthesized chain ends the chain, which must be kept.
+    assert_transform("(a?.b)!.c;", "(a?.b).c;");
+    assert_transform("(a?.b)!();", "(a?.b)();");
+    assert_transform("(a?.b!)();", "(a?.b)();");
+    assert_transform("(a?.b)![c];", "(a?.b)[c];");
+    assert_transform("(a?.b)!`t`;", "(a?.b)`t`;");
+    assert_transform("new (a?.b)!();", "new (a?.b)();");
+    assert_transform("(a?.b as any).c;", "(a?.b).c;");
+    assert_transform("x = (a?.b)!;", "x = a?.b;");
+}
+
+#[test]
+fn non_null_assignment_targets() {
+    assert_transform("x! = 1;", "x = 1;");
+    assert_transform("(x!) = 1;", "x = 1;");
+    assert_transform("x! += 1;", "x += 1;");
+    assert_transform("a.b! ??= c;", "a.b ??= c;");
+    assert_transform("x!++;", "x++;");
+    assert_transform("[x!, a.b!] = c;", "[x, a.b] = c;");
+    assert_transform("[x! = 1, ...y!] = c;", "[x = 1, ...y] = c;");
+    assert_transform("({ a: x!, ...y! } = c);", "({ a: x, ...y } = c);");
+    assert_transform("[(a?.b)!.c] = d;", "[(a?.b).c] = d;");
+    assert_transform("for (x! of y);", "for (x of y);");
+    assert_transform("for (a.b! in c);", "for (a.b in c);");
+}
+
+#[test]
+fn type_assertions() {
+    assert_transform("(a.b as any)();", "a.b();");
+    assert_transform("(a.b satisfies any)();", "a.b();");
+    assert_transform("(<any>a.b)();", "a.b();");
+    assert_transform("(a as any) = 1;", "a = 1;");
+    assert_transform("((a as any)) = 1;", "a = 1;");
+    assert_transform("(<any>a).b = 1;", "a.b = 1;");
+    assert_transform("(x satisfies number)++;", "x++;");
+    assert_transform("for ((x as any) of y);", "for (x of y);");
+    assert_transform(
+        "({ a: (x as any) = 1, b: (c as any).d } = e);",
+        "({ a: x = 1, b: c.d } = e);",
+    );
+}
+
+#[test]
+fn non_null_at_module_scope() {
+    assert_transform("const a = b!;", "const a = b;");
+    assert_transform("export const a = b!.c!;", "export const a = b.c;");
+    assert_transform("delete a!.b; typeof x!;", "delete a.b; typeof x;");
+}
+
+#[test]
+fn definite_assignment_assertions() {
+    assert_transform("let x!: number;", "let x;");
+    assert_transform("export let x!: number, y!: string;", "export let x, y;");
+    // Class fields are still defined.
+    assert_transform(
+        "class A { x!: number; static y!: number; accessor z!: number; declare w: number }",
+        "class A { x; static y; accessor z; }",
+    );
+}
+
+#[test]
+fn definite_assignment_assertions_without_define_for_class_fields() {
+    let mut options = TransformOptions::default();
+    options.typescript.use_define_for_class_fields = false;
+    // Like fields without an initializer, they are removed.
+    assert_eq!(
+        test_with_source_type(
+            "class A { x!: number; static y!: number; accessor z!: number }",
+            SourceType::ts(),
+            &options
+        ),
+        Ok(codegen("class A { accessor z; }", SourceType::mjs()))
+    );
+}
+
+#[test]
+fn erased_assertions_keep_the_span_of_the_expression() {
+    let source_text = "fn!(); (a.b as any)!(); x = (c satisfies any)!;";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &TransformOptions::default())
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+
+    let spans = program
+        .body
+        .iter()
+        .map(|stmt| {
+            let Statement::ExpressionStatement(stmt) = stmt else { unreachable!() };
+            match &stmt.expression {
+                Expression::CallExpression(call) => call.callee.span(),
+                Expression::AssignmentExpression(assign) => assign.right.span(),
+                _ => unreachable!(),
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(spans, [Span::new(0, 2), Span::new(8, 11), Span::new(29, 30)]);
+}
@@ -0,0 +1,36 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{assert_snapshot, print_cases, test_with_source_type};
+
+/// `override`, accessibility, `abstract`, `readonly` and variance modifiers are type-only.
+#[test]
+fn typescript_modifiers() {
+    let cases = [
+        // `override` on methods, fields and accessors.
+        "class A extends B { override m() {} override x = 1; override get y() { return 1; } override set y(v) {} override accessor z = 1; }",
+        "class A extends B { override static m() {} static override x = 1; }",
+        "class A extends B { override async *m() {} override ['computed']() {} override #p = 1; }",
+        // Combined with access modifiers, `readonly` and `abstract`.
+        "class A extends B { public override m() {} protected override x = 1; private override readonly y = 2; }",
+        "abstract class A extends B { abstract override m(): void; protected abstract override x: number; public override n() {} }",
+        "abstract class A extends B { abstract override accessor x: number; public override accessor y = 1; }",
+        "class A extends B { override declare x: number; declare override y: number; override z: number; }",
+        // Parameter properties.
+        "class A extends B { constructor(override x: number, public override readonly y = 1) { super(); } }",
+        // Variance modifiers on type parameters.
+        "interface I<in T, out U, in out V> {} type F<in out T> = (x: T) => T;",
+        "class A<in T, out U> { m<const V>(v: V): V { return v; } }",
+        "function f<const T, U extends T = T>(x: T): U { return x as U; }",
+        // Inferred type parameters.
+        "type T<X> = X extends Array<infer U extends string> ? U : never; const x: T<string[]> = 'a';",
+        "type F<out T> = T extends (infer U)[] ? U : T; let y = <T,>(v: T) => v;",
+    ];
+
+    assert_snapshot(
+        "typescript_modifiers",
+        &print_cases("", cases, |case| {
+            test_with_source_type(case, SourceType::ts(), &TransformOptions::default()).unwrap()
+        }),
+    );
+}
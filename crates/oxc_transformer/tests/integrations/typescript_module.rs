@@ -0,0 +1,32 @@
+use oxc_span::SourceType;
+use oxc_transformer::{Module, TransformOptions};
+
+use crate::{assert_snapshot, code_or_errors, print_cases, test_with_source_type};
+
+#[test]
+fn import_equals_and_export_assignment() {
+    let cases = [
+        "import foo = require('foo'); export = foo;",
+        "import Bar = Namespace.Bar; import Baz = A.B.Baz; new Bar(Baz);",
+        "export import Bar = Namespace.Bar;",
+        // Unused, so removed as a type-only import.
+        "import foo = require('foo'); import Bar = Namespace.Bar;",
+        "import type foo = require('foo'); let x: foo;",
+        "export = { a: 1 }; export const b = 2;",
+        "export = foo; export default bar;",
+        "export = foo; export * from 'bar';",
+        "export = foo; export {};",
+    ];
+
+    let snapshot = [("commonjs", Module::CommonJS), ("esm", Module::Esm)]
+        .into_iter()
+        .map(|(name, module)| {
+            let mut options = TransformOptions::default();
+            options.env.module = module;
+            print_cases(name, cases, |case| {
+                code_or_errors(case, test_with_source_type(case, SourceType::ts(), &options))
+            })
+        })
+        .collect::<String>();
+    assert_snapshot("typescript_module", &snapshot);
+}
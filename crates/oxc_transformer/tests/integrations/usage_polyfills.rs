@@ -0,0 +1,201 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, TransformOptions, UsagePolyfillsOptions};
+
+use crate::{codegen, test};
+
+fn options_for(query: &str) -> TransformOptions {
+    let mut options = TransformOptions {
+        env: EnvOptions::from_browserslist_query(query).unwrap(),
+        ..TransformOptions::default()
+    };
+    options.plugins.usage_polyfills =
+        Some(UsagePolyfillsOptions { source: "polyfill/{name}".to_string() });
+    options
+}
+
+fn polyfills(query: &str, source: &str, imports: &[&str]) {
+    let imports =
+        imports.iter().map(|name| format!("import 'polyfill/{name}';")).collect::<Vec<_>>();
+    let expected = format!("{}\n{source}", imports.join("\n"));
+    assert_eq!(
+        test(source, &options_for(query)),
+        Ok(codegen(&expected, SourceType::mjs())),
+        "{source}"
+    );
+}
+
+#[test]
+fn supported_built_ins_are_untouched() {
+    for source in [
+        "Promise.allSettled(tasks.flat());",
+        "new Map(Object.entries(obj));",
+        "globalThis.x = 'a'.replaceAll('b', 'c');",
+    ] {
+        polyfills("chrome 100", source, &[]);
+    }
+    // Built-ins added after ES2022 are not polyfilled.
+    polyfills("chrome 30", "arr.findLast(f).toSorted();", &[]);
+}
+
+#[test]
+fn globals_and_static_methods() {
+    // Chrome 70 supports neither `Promise.allSettled`, `Promise.any`, `Object.fromEntries`
+    // nor `globalThis`.
+    polyfills(
+        "chrome 70",
+        "Promise.allSettled(a); Promise.any(b); Object.fromEntries(c); globalThis.d;",
+        &["es.promise.all-settled", "es.promise.any", "es.object.from-entries", "es.global-this"],
+    );
+    polyfills("chrome 30", "new Map(Object.entries(obj));", &["es.map", "es.object.entries"]);
+    // The global is polyfilled together with its static method.
+    polyfills("chrome 30", "Promise.allSettled(a);", &["es.promise.all-settled", "es.promise"]);
+}
+
+#[test]
+fn instance_methods() {
+    polyfills(
+        "chrome 60",
+        "arr.flat(); arr.flatMap(f); a.finally(g);",
+        &["es.array.flat", "es.array.flat-map", "es.promise.finally"],
+    );
+    // Unknown receivers get the polyfills of every built-in with that method.
+    polyfills("chrome 30", "x.includes(y);", &["es.string.includes", "es.array.includes"]);
+    polyfills("chrome 91", "x?.at(-1);", &["es.array.at", "es.string.at-alternative"]);
+    // Literal receivers only get the polyfill of their own type.
+    polyfills("chrome 30", "[1, 2].includes(y);", &["es.array.includes"]);
+    polyfills(
+        "chrome 30",
+        "'abc'.includes(y); `abc`.at(0);",
+        &["es.string.includes", "es.string.at-alternative"],
+    );
+}
+
+#[test]
+fn imports_each_polyfill_once() {
+    polyfills(
+        "chrome 60",
+        "a.flat(); b.flat(); Promise.allSettled(c); Promise.allSettled(d);",
+        &["es.array.flat", "es.promise.all-settled"],
+    );
+}
+
+#[test]
+fn ignores_shadowed_globals() {
+    for source in [
+        "function f(Promise) { return Promise.allSettled(a); }",
+        "const Map = foo; new Map();",
+        "let Object = bar; Object.fromEntries(x);",
+        "class globalThis {}",
+    ] {
+        polyfills("chrome 30", source, &[]);
+    }
+}
+
+#[test]
+fn web_globals() {
+    polyfills(
+        "chrome 60",
+        "structuredClone(a); queueMicrotask(b);",
+        &["web.structured-clone", "web.queue-microtask"],
+    );
+    // Chrome 80 supports `queueMicrotask`, but not `structuredClone`.
+    polyfills("chrome 80", "queueMicrotask(b); structuredClone(a);", &["web.structured-clone"]);
+    polyfills("chrome 100", "structuredClone(a); queueMicrotask(b);", &[]);
+    // References which are not called are usage too.
+    polyfills(
+        "chrome 60",
+        "typeof structuredClone; const f = queueMicrotask;",
+        &["web.structured-clone", "web.queue-microtask"],
+    );
+    polyfills("chrome 60", "structuredClone(a); structuredClone(b);", &["web.structured-clone"]);
+}
+
+/// Built-ins are looked up in the compat data one by one, not by the edition which added them.
+#[test]
+fn built_ins_compat_data() {
+    // Chrome 73 supports `Object.fromEntries` and `globalThis`, but not `Promise.allSettled`.
+    polyfills(
+        "chrome 73",
+        "Object.fromEntries(a); globalThis.b; Promise.allSettled(c);",
+        &["es.promise.all-settled"],
+    );
+    polyfills("chrome 72", "Object.fromEntries(a);", &["es.object.from-entries"]);
+    // Chrome 92 supports `.at()`, but not `Object.hasOwn` of the same edition.
+    polyfills("chrome 92", "x.at(-1); Object.hasOwn(y, 'z');", &["es.object.has-own"]);
+    // Every target engine is checked.
+    polyfills("chrome 100, safari 14", "x.at(-1);", &["es.array.at", "es.string.at-alternative"]);
+}
+
+/// Web globals are looked up in the compat data, not approximated by edition.
+#[test]
+fn web_globals_compat_data() {
+    polyfills("chrome 97", "structuredClone(a);", &["web.structured-clone"]);
+    polyfills("chrome 98", "structuredClone(a);", &[]);
+    polyfills("chrome 70", "queueMicrotask(b);", &["web.queue-microtask"]);
+    // Chrome 71 supports `queueMicrotask`, but not all of the ES2020 syntax.
+    polyfills(
+        "chrome 71",
+        "queueMicrotask(b); Promise.allSettled(c);",
+        &["es.promise.all-settled"],
+    );
+
+    // Web globals are not part of any edition.
+    let mut options = TransformOptions {
+        env: EnvOptions::from_target("es2015").unwrap(),
+        ..TransformOptions::default()
+    };
+    options.plugins.usage_polyfills = Some(UsagePolyfillsOptions::default());
+    let source = "structuredClone(a); queueMicrotask(b); new AbortController();";
+    assert_eq!(test(source, &options), Ok(codegen(source, SourceType::mjs())));
+}
+
+#[test]
+fn members_of_the_global_object() {
+    polyfills(
+        "chrome 60",
+        "globalThis.structuredClone(a); window.queueMicrotask(b);",
+        &["web.structured-clone", "es.global-this", "web.queue-microtask"],
+    );
+    polyfills("chrome 30", "self.Promise;", &["es.promise"]);
+    // Properties of other objects, and of a shadowed global object.
+    for source in [
+        "obj.structuredClone(a); obj.queueMicrotask(b);",
+        "function f(window) { window.structuredClone(a); }",
+    ] {
+        polyfills("chrome 80", source, &[]);
+    }
+}
+
+#[test]
+fn ignores_shadowed_web_globals() {
+    for source in [
+        "function structuredClone(x) { return x; } structuredClone(a);",
+        "const queueMicrotask = (f) => f(); queueMicrotask(b);",
+        "import { AbortController } from 'abort'; new AbortController();",
+    ] {
+        polyfills("chrome 60", source, &[]);
+    }
+}
+
+#[test]
+fn abort_controller_from_package() {
+    // The polyfill is imported once for both globals, ignoring the `source` option.
+    let source = "const controller = new AbortController(); AbortSignal.timeout(1000);";
+    let expected =
+        format!("import 'abortcontroller-polyfill/dist/abortcontroller-polyfill-only';\n{source}");
+    assert_eq!(test(source, &options_for("chrome 65")), Ok(codegen(&expected, SourceType::mjs())));
+    polyfills("chrome 66", source, &[]);
+}
+
+#[test]
+fn default_source() {
+    let mut options = TransformOptions {
+        env: EnvOptions::from_browserslist_query("chrome 60").unwrap(),
+        ..TransformOptions::default()
+    };
+    options.plugins.usage_polyfills = Some(UsagePolyfillsOptions::default());
+    assert_eq!(
+        test("arr.flat();", &options),
+        Ok(codegen("import 'core-js/modules/es.array.flat.js';\narr.flat();", SourceType::mjs()))
+    );
+}
@@ -0,0 +1,97 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, TransformOptions, WellKnownSymbolsOptions};
+
+use crate::{codegen, test};
+
+fn options_for(query: &str, shim: Option<&str>) -> TransformOptions {
+    let mut options = TransformOptions {
+        env: EnvOptions::from_browserslist_query(query).unwrap(),
+        ..TransformOptions::default()
+    };
+    options.plugins.well_known_symbols =
+        Some(WellKnownSymbolsOptions { shim: shim.map(ToString::to_string) });
+    options
+}
+
+#[test]
+fn supported_symbols_are_untouched() {
+    let options = options_for("chrome 100", Some("shim/{name}"));
+    for source in [
+        "class C { *[Symbol.iterator]() {} async *[Symbol.asyncIterator]() {} }",
+        "const key = Symbol.for('app');",
+    ] {
+        assert_eq!(test(source, &options), Ok(codegen(source, SourceType::mjs())));
+    }
+}
+
+/// Symbols are looked up in the compat data, not derived from the syntax transforms.
+#[test]
+fn symbols_compat_data() {
+    // Safari 11.1 supports `Symbol.asyncIterator`, but not async generator functions.
+    let options = options_for("safari 11.1", Some("shim/{name}"));
+    let source = "obj[Symbol.asyncIterator]; obj[Symbol.iterator];";
+    assert_eq!(test(source, &options), Ok(codegen(source, SourceType::mjs())));
+
+    // Firefox 36 supports `Symbol`, but not all of the ES2015 syntax.
+    let options = options_for("firefox 36", Some("shim/{name}"));
+    let source = "obj[Symbol.iterator]; Symbol.for('app');";
+    assert_eq!(test(source, &options), Ok(codegen(source, SourceType::mjs())));
+}
+
+#[test]
+fn imports_shim_once_per_symbol() {
+    // Chrome 60 supports ES2015 symbols, but not `Symbol.asyncIterator`.
+    let options = options_for("chrome 60", Some("core-js/modules/es.symbol.{name}.js"));
+    let source = "
+        class Stream { [Symbol.asyncIterator]() {} [Symbol.iterator]() {} }
+        obj[Symbol.asyncIterator];
+    ";
+    let expected = "
+        import 'core-js/modules/es.symbol.async-iterator.js';
+        class Stream { [Symbol.asyncIterator]() {} [Symbol.iterator]() {} }
+        obj[Symbol.asyncIterator];
+    ";
+    assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::mjs())));
+
+    let options = options_for("chrome 30", Some("shim/{name}"));
+    let source = "
+        const key = Symbol.for('app');
+        x[Symbol.iterator]();
+        x[Symbol.toStringTag];
+        y[Symbol.iterator]();
+    ";
+    let expected = "
+        import 'shim/for';
+        import 'shim/iterator';
+        import 'shim/to-string-tag';
+        const key = Symbol.for('app');
+        x[Symbol.iterator]();
+        x[Symbol.toStringTag];
+        y[Symbol.iterator]();
+    ";
+    assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::mjs())));
+}
+
+#[test]
+fn reports_without_shim() {
+    let options = options_for("chrome 30", None);
+    let errors = test("o[Symbol.iterator]; Symbol.keyFor(s);", &options).unwrap_err();
+    let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "`Symbol.iterator` is not available in the configured target environment.",
+            "`Symbol.keyFor` is not available in the configured target environment.",
+        ]
+    );
+}
+
+#[test]
+fn ignores_shadowed_and_unknown_symbols() {
+    let options = options_for("chrome 30", None);
+    for source in
+        ["function f(Symbol) { return Symbol.iterator; }", "Symbol.custom;", "Foo.iterator;"]
+    {
+        assert_eq!(test(source, &options), Ok(codegen(source, SourceType::mjs())));
+    }
+}
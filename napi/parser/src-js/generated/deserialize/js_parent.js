@@ -5256,6 +5256,8 @@ function deserializeUnaryOperator(pos) {
       return "void";
     case 6:
       return "delete";
+    case 7:
+      return "throw";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for UnaryOperator`);
   }
@@ -5522,6 +5522,8 @@ function deserializeUnaryOperator(pos) {
       return "void";
     case 6:
       return "delete";
+    case 7:
+      return "throw";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for UnaryOperator`);
   }
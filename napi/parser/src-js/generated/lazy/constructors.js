@@ -12143,6 +12143,8 @@ function constructUnaryOperator(pos, ast) {
       return "void";
     case 6:
       return "delete";
+    case 7:
+      return "throw";
     default:
       throw new Error(`Unexpected discriminant ${ast.buffer[pos]} for UnaryOperator`);
   }
@@ -139,6 +139,7 @@ export interface OxcParserOptions {
   allowReturnOutsideFunction: boolean
   preserveParens: boolean
   allowV8Intrinsics: boolean
+  allowThrowExpressions: boolean
   semanticErrors: boolean
 }
 
@@ -338,6 +338,12 @@ export declare function moduleRunnerTransformSync(filename: string, sourceText:
 export interface PluginsOptions {
   styledComponents?: StyledComponentsOptions
   taggedTemplateEscape?: boolean
+  /**
+   * Hoist constant regular expressions and frozen literals out of functions.
+   *
+   * @default false
+   */
+  hoistConstantExpressions?: boolean
 }
 
 export interface ReactRefreshOptions {
@@ -491,12 +497,15 @@ export interface TransformOptions {
    *
    * * `'es2015'`
    * * `['es2020', 'chrome58', 'edge16', 'firefox57', 'node12', 'safari11']`
+   * * `{ chrome: 80, node: 14, safari: '13.1' }`
+   *
+   * When multiple engines are specified, a feature is transformed if any of them does not support it.
    *
    * @default `esnext` (No transformation)
    *
    * @see [esbuild#target](https://esbuild.github.io/api/#target)
    */
-  target?: string | Array<string>
+  target?: string | Array<string> | Record<string, string | number>
   /** Behaviour for runtime helpers. */
   helpers?: Helpers
   /** Define Plugin */
@@ -624,6 +633,36 @@ export interface TypeScriptOptions {
    * Defaults to `false`.
    */
   removeClassFieldsWithoutInitializer?: boolean
+  /**
+   * Whether class fields are defined with `[[Define]]` semantics, like TypeScript's
+   * `useDefineForClassFields` option.
+   *
+   * When `false`, class fields without initializer are removed, and when class fields are transformed,
+   * they are assigned with `=` instead of `Object.defineProperty`. This is the same as setting both
+   * `set_public_class_fields` and `remove_class_fields_without_initializer` to `true`.
+   *
+   * When `false`, public class fields are always moved to assignments in the constructor, or in a
+   * static block, even when the target supports class fields. Private fields are left as they are.
+   *
+   * @default true
+   */
+  useDefineForClassFields?: boolean
+  /**
+   * Transform enums whose members are all strings to frozen objects, instead of functions
+   * which add the members to a mutable object one by one, so minifiers can inline the members.
+   *
+   * @default false
+   */
+  frozenStringEnums?: boolean
+  /**
+   * Replace reads of enum members with their values, and remove the enums, when the enums are
+   * only used to read members by name in the file.
+   *
+   * The declarations of inlined enums are always removed, as nothing refers to them anymore.
+   *
+   * @default false
+   */
+  inlineEnums?: boolean
   /**
    * Also generate a `.d.ts` declaration file for TypeScript files.
    *
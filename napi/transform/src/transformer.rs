@@ -6,7 +6,10 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use napi::{Either, Task, bindgen_prelude::AsyncTask};
+use napi::{
+    Either, Task,
+    bindgen_prelude::{AsyncTask, Either3},
+};
 use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
@@ -125,11 +128,15 @@ pub struct TransformOptions {
     ///
     /// * `'es2015'`
     /// * `['es2020', 'chrome58', 'edge16', 'firefox57', 'node12', 'safari11']`
+    /// * `{ chrome: 80, node: 14, safari: '13.1' }`
+    ///
+    /// When multiple engines are specified, a feature is transformed if any of them does not support it.
     ///
     /// @default `esnext` (No transformation)
     ///
     /// @see [esbuild#target](https://esbuild.github.io/api/#target)
-    pub target: Option<Either<String, Vec<String>>>,
+    #[napi(ts_type = "string | Array<string> | Record<string, string | number>")]
+    pub target: Option<Target>,
 
     /// Behaviour for runtime helpers.
     pub helpers: Option<Helpers>,
@@ -149,14 +156,25 @@ pub struct TransformOptions {
     pub plugins: Option<PluginsOptions>,
 }
 
+/// `string | Array<string> | Record<string, string | number>`
+type Target = Either3<String, Vec<String>, FxHashMap<String, Either<String, f64>>>;
+
 impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
     type Error = String;
 
     fn try_from(options: TransformOptions) -> Result<Self, Self::Error> {
         let env = match options.target {
-            Some(Either::A(s)) => EnvOptions::from_target(&s)?,
-            Some(Either::B(list)) => EnvOptions::from_target_list(&list)?,
-            _ => EnvOptions::default(),
+            Some(Either3::A(s)) => EnvOptions::from_target(&s)?,
+            Some(Either3::B(list)) => EnvOptions::from_target_list(&list)?,
+            Some(Either3::C(map)) => {
+                EnvOptions::from_engine_versions(map.into_iter().map(|(engine, version)| {
+                    match version {
+                        Either::A(s) => (engine, s),
+                        Either::B(n) => (engine, n.to_string()),
+                    }
+                }))?
+            }
+            None => EnvOptions::default(),
         };
         Ok(Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
@@ -309,6 +327,30 @@ pub struct TypeScriptOptions {
     ///
     /// Defaults to `false`.
     pub remove_class_fields_without_initializer: Option<bool>,
+    /// Whether class fields are defined with `[[Define]]` semantics, like TypeScript's
+    /// `useDefineForClassFields` option.
+    ///
+    /// When `false`, class fields without initializer are removed, and when class fields are transformed,
+    /// they are assigned with `=` instead of `Object.defineProperty`. This is the same as setting both
+    /// `set_public_class_fields` and `remove_class_fields_without_initializer` to `true`.
+    ///
+    /// When `false`, public class fields are always moved to assignments in the constructor, or in a
+    /// static block, even when the target supports class fields. Private fields are left as they are.
+    ///
+    /// @default true
+    pub use_define_for_class_fields: Option<bool>,
+    /// Transform enums whose members are all strings to frozen objects, instead of functions
+    /// which add the members to a mutable object one by one, so minifiers can inline the members.
+    ///
+    /// @default false
+    pub frozen_string_enums: Option<bool>,
+    /// Replace reads of enum members with their values, and remove the enums, when the enums are
+    /// only used to read members by name in the file.
+    ///
+    /// The declarations of inlined enums are always removed, as nothing refers to them anymore.
+    ///
+    /// @default false
+    pub inline_enums: Option<bool>,
     /// Also generate a `.d.ts` declaration file for TypeScript files.
     ///
     /// The source file must be compliant with all
@@ -344,6 +386,11 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
             remove_class_fields_without_initializer: options
                 .remove_class_fields_without_initializer
                 .unwrap_or(ops.remove_class_fields_without_initializer),
+            use_define_for_class_fields: options
+                .use_define_for_class_fields
+                .unwrap_or(ops.use_define_for_class_fields),
+            frozen_string_enums: options.frozen_string_enums.unwrap_or(ops.frozen_string_enums),
+            inline_enums: options.inline_enums.unwrap_or(ops.inline_enums),
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
@@ -468,6 +515,10 @@ pub struct StyledComponentsOptions {
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
     pub tagged_template_escape: Option<bool>,
+    /// Hoist constant regular expressions and frozen literals out of functions.
+    ///
+    /// @default false
+    pub hoist_constant_expressions: Option<bool>,
 }
 
 impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
@@ -477,6 +528,40 @@ impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
                 .styled_components
                 .map(oxc::transformer::StyledComponentsOptions::from),
             tagged_template_transform: options.tagged_template_escape.unwrap_or(false),
+            hoist_constant_expressions: options.hoist_constant_expressions.unwrap_or(false),
+            well_known_symbols: None,
+            json_parse: None,
+            lazy_top_level_await: false,
+            expand_export_star: None,
+            reconstruct_ts_enums: false,
+            import_meta_glob: None,
+            usage_polyfills: None,
+            hoist_requires: false,
+            destructure_member_chains: None,
+            feature_guards: None,
+            property_mutators: false,
+            at_to_index: None,
+            promise_finally: false,
+            react_constant_elements: false,
+            dynamic_requires: false,
+            split_re_exports: false,
+            direct_eval: None,
+            module_factory: None,
+            inline_imported_constants: None,
+            switch_lookup: None,
+            hoist_loop_guards: false,
+            normalize_template_line_endings: false,
+            normalize_undefined: None,
+            explicit_radix: None,
+            ternary_to_early_return: None,
+            array_from_length: false,
+            json_parse_to_literal: false,
+            then_to_catch: None,
+            coalesce_template_literals: false,
+            namespace_to_named_imports: false,
+            dedupe_export_names: false,
+            object_iteration_to_for_in: false,
+            react_component_display_name: false,
         }
     }
 }
@@ -665,7 +750,10 @@ pub struct Es2015Options {
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            ..Default::default()
+        }
     }
 }
 
@@ -108,6 +108,17 @@ describe("target", () => {
     expect(ret.code).toEqual(code);
   });
 
+  it("should transform for the lowest engine in a target map", () => {
+    const code = "a ?? b;\n";
+    const ret = transformSync("test.js", code, { target: { chrome: 80, safari: "13" } });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.code).not.toEqual(code);
+
+    const ret2 = transformSync("test.js", code, { target: { chrome: 80, safari: "13.1" } });
+    expect(ret2.errors.length).toBe(0);
+    expect(ret2.code).toEqual(code);
+  });
+
   it("should turn off class propertiers because plugin is not ready", () => {
     const code = "class Foo {\n\t#a;\n}\n";
     const ret = transformSync("test.js", code, { target: "es2015" });
@@ -0,0 +1,8 @@
+function _globalThis() {
+  if (typeof globalThis === "object" && globalThis) return globalThis;
+  if (typeof self === "object" && self) return self;
+  if (typeof window === "object" && window) return window;
+  if (typeof global === "object" && global) return global;
+  return Function("return this")();
+}
+export { _globalThis as default };
\ No newline at end of file
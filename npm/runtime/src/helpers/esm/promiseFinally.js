@@ -0,0 +1,15 @@
+function _promiseFinally(promise, onFinally) {
+  if (typeof onFinally !== "function") return promise.then(onFinally, onFinally);
+  var C = promise.constructor;
+  if (typeof C !== "function" || typeof C.resolve !== "function") C = Promise;
+  return promise.then(function (value) {
+    return C.resolve(onFinally()).then(function () {
+      return value;
+    });
+  }, function (reason) {
+    return C.resolve(onFinally()).then(function () {
+      throw reason;
+    });
+  });
+}
+export { _promiseFinally as default };
\ No newline at end of file
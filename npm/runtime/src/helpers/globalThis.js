@@ -0,0 +1,8 @@
+function _globalThis() {
+  if (typeof globalThis === "object" && globalThis) return globalThis;
+  if (typeof self === "object" && self) return self;
+  if (typeof window === "object" && window) return window;
+  if (typeof global === "object" && global) return global;
+  return Function("return this")();
+}
+module.exports = _globalThis, module.exports.__esModule = true, module.exports["default"] = module.exports;
\ No newline at end of file
@@ -43,6 +43,24 @@ const customEs2020 = [
       electron: "12.0",
     },
   },
+  {
+    name: "GlobalThis",
+    babel: null,
+    // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/globalThis#browser_compatibility
+    targets: {
+      chrome: "71",
+      opera: "58",
+      edge: "79",
+      firefox: "65",
+      safari: "12.1",
+      node: "12.0",
+      deno: "1.0",
+      ios: "12.2",
+      samsung: "10.0",
+      opera_mobile: "50",
+      electron: "5.0",
+    },
+  },
 ].map(f("ES2020"));
 
 const customEs2022 = [
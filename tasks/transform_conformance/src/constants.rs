@@ -58,6 +58,7 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-development",
     // // Proposal
     // "babel-plugin-proposal-decorators",
+    "babel-plugin-proposal-throw-expressions",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
     // Legacy decorators, tests almost ported from TypeScript
@@ -65,6 +66,9 @@ pub const PLUGINS: &[&str] = &[
     // Built-in third-party plugins
     "plugin-styled-components",
     "plugin-tagged-template-transform",
+    "plugin-hoist-constant-expressions",
+    "plugin-json-parse",
+    "plugin-lazy-top-level-await",
 ];
 
 pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
@@ -0,0 +1,2 @@
+const fail = () => throw new Error("not implemented");
+const failWith = (message) => throw new Error(message);
@@ -0,0 +1,6 @@
+const fail = () => ((e) => {
+  throw e;
+})(new Error("not implemented"));
+const failWith = (message) => ((e) => {
+  throw e;
+})(new Error(message));
@@ -0,0 +1,2 @@
+const value = input ?? throw new Error("missing input");
+const other = input || throw new Error("falsy input");
@@ -0,0 +1,6 @@
+const value = input ?? ((e) => {
+  throw e;
+})(new Error("missing input"));
+const other = input || ((e) => {
+  throw e;
+})(new Error("falsy input"));
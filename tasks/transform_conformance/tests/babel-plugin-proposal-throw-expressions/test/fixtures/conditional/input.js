@@ -0,0 +1,2 @@
+const result = ok ? value : throw error;
+const nested = a ? throw first : b ? throw second : c;
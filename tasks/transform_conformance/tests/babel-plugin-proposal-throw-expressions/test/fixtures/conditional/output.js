@@ -0,0 +1,8 @@
+const result = ok ? value : ((e) => {
+  throw e;
+})(error);
+const nested = a ? ((e) => {
+  throw e;
+})(first) : b ? ((e) => {
+  throw e;
+})(second) : c;
@@ -0,0 +1,3 @@
+function save(filename = throw new TypeError("Argument required")) {
+  return filename;
+}
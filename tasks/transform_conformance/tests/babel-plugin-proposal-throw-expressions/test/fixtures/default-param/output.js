@@ -0,0 +1,5 @@
+function save(filename = ((e) => {
+  throw e;
+})(new TypeError("Argument required"))) {
+  return filename;
+}
@@ -0,0 +1,9 @@
+function getEncoder(encoding) {
+  const e = encoding;
+  return e === "utf8" ? new UTF8Encoder() : throw new Error(`Unsupported encoding: ${e}`);
+}
+class Config {
+  get(key) {
+    return this.values[key] ?? throw new Error(this.missing(key));
+  }
+}
@@ -0,0 +1,13 @@
+function getEncoder(encoding) {
+  const e = encoding;
+  return e === "utf8" ? new UTF8Encoder() : ((e) => {
+    throw e;
+  })(new Error(`Unsupported encoding: ${e}`));
+}
+class Config {
+  get(key) {
+    return this.values[key] ?? ((e) => {
+      throw e;
+    })(new Error(this.missing(key)));
+  }
+}
@@ -0,0 +1,9 @@
+async function* f(asyncIterable) {
+  for await (const x of asyncIterable) {
+    if (x === 1) continue;
+    if (x === 2) break;
+    if (x === 3) return;
+    if (x === 4) throw new Error("unexpected");
+    yield x;
+  }
+}
@@ -0,0 +1,5 @@
+const f = async (asyncIterable) => {
+  for await (const [key, value] of asyncIterable) {
+    console.log(key, value);
+  }
+};
@@ -0,0 +1,6 @@
+async function f(asyncIterable) {
+  for await (const x of asyncIterable) {
+    if (x === 2) break;
+    console.log(x);
+  }
+}
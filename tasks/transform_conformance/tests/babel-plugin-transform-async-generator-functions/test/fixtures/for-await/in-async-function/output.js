@@ -0,0 +1,33 @@
+function f(_x) {
+  return _f.apply(this, arguments);
+}
+function _f() {
+  _f = babelHelpers.asyncToGenerator(function* (asyncIterable) {
+    var _iteratorAbruptCompletion = false;
+    var _didIteratorError = false;
+    var _iteratorError;
+    try {
+      for (var _iterator = babelHelpers.asyncIterator(asyncIterable), _step; _iteratorAbruptCompletion = !(_step = yield _iterator.next()).done; _iteratorAbruptCompletion = false) {
+        const x = _step.value;
+        {
+          if (x === 2) break;
+          console.log(x);
+        }
+      }
+    } catch (err) {
+      _didIteratorError = true;
+      _iteratorError = err;
+    } finally {
+      try {
+        if (_iteratorAbruptCompletion && _iterator.return != null) {
+          yield _iterator.return();
+        }
+      } finally {
+        if (_didIteratorError) {
+          throw _iteratorError;
+        }
+      }
+    }
+  });
+  return _f.apply(this, arguments);
+}
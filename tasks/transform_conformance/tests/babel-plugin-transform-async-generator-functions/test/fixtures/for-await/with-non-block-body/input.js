@@ -0,0 +1,3 @@
+async function* fn(asyncIterable) {
+  for await (const chunk of asyncIterable) yield chunk;
+}
@@ -0,0 +1,12 @@
+class Foo {
+  #foo = 1;
+  static #bar = 2;
+
+  test(other) {
+    return #foo in other && #bar in other;
+  }
+
+  static check(a, b) {
+    return #foo in getObject(a) || (#bar in b ? 1 : 0);
+  }
+}
@@ -0,0 +1,24 @@
+var _foo = /*#__PURE__*/ babelHelpers.classPrivateFieldLooseKey("foo");
+var _bar = /*#__PURE__*/ babelHelpers.classPrivateFieldLooseKey("bar");
+
+class Foo {
+  constructor() {
+    Object.defineProperty(this, _foo, {
+      writable: true,
+      value: 1
+    });
+  }
+
+  test(other) {
+    return Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(other), _foo) && Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(other), _bar);
+  }
+
+  static check(a, b) {
+    return Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(getObject(a)), _foo) || (Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(b), _bar) ? 1 : 0);
+  }
+}
+
+Object.defineProperty(Foo, _bar, {
+  writable: true,
+  value: 2
+});
@@ -0,0 +1,10 @@
+class Foo {
+  #x;
+
+  method(obj) {
+    this.#x ??= 1;
+    obj.#x ||= 2;
+    getObj().#x &&= 3;
+    this.nested.obj.#x ??= 4;
+  }
+}
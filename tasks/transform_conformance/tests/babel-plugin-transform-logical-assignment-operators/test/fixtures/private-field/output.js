@@ -0,0 +1,11 @@
+class Foo {
+  #x;
+
+  method(obj) {
+    var _getObj, _this$nested$obj;
+    this.#x ?? (this.#x = 1);
+    obj.#x || (obj.#x = 2);
+    (_getObj = getObj()).#x && (_getObj.#x = 3);
+    (_this$nested$obj = this.nested.obj).#x ?? (_this$nested$obj.#x = 4);
+  }
+}
@@ -0,0 +1,7 @@
+getObj().prop ??= 1;
+getObj()[getKey()] ??= 2;
+(a ?? b).prop ??= 3;
+(a?.b).c ??= 4;
+a.b.c.d.e ??= 5;
+a.b[c.d][e()] ??= 6;
+a.b ??= a.b ??= 7;
@@ -0,0 +1,8 @@
+var _getObj, _getObj2, _getKey, _ref, _a$b, _a$b$c$d, _a$b$c$d2, _e, _a, _a2;
+(_getObj = getObj()).prop ?? (_getObj.prop = 1);
+(_getObj2 = getObj())[(_getKey = getKey())] ?? (_getObj2[_getKey] = 2);
+(_ref = a ?? b).prop ?? (_ref.prop = 3);
+(_a$b = a?.b).c ?? (_a$b.c = 4);
+(_a$b$c$d = a.b.c.d).e ?? (_a$b$c$d.e = 5);
+(_a$b$c$d2 = a.b[c.d])[(_e = e())] ?? (_a$b$c$d2[_e] = 6);
+(_a = a).b ?? (_a.b = (_a2 = a).b ?? (_a2.b = 7));
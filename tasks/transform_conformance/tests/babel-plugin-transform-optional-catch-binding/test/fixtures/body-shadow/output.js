@@ -0,0 +1,6 @@
+try {
+  throw 0;
+} catch (_unused2) {
+  let _unused = 1;
+  console.log(_unused);
+}
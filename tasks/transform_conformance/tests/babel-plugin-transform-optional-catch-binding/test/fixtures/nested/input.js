@@ -0,0 +1,9 @@
+try {
+  a();
+} catch {
+  try {
+    b();
+  } catch {
+    c();
+  }
+}
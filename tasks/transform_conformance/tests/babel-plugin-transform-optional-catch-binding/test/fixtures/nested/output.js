@@ -0,0 +1,9 @@
+try {
+  a();
+} catch (_unused) {
+  try {
+    b();
+  } catch (_unused2) {
+    c();
+  }
+}
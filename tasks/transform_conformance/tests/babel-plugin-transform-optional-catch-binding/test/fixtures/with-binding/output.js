@@ -0,0 +1,10 @@
+try {
+  throw 0;
+} catch (e) {
+  console.log(e);
+}
+try {
+  throw 0;
+} catch (_unused) {
+  console.log("caught");
+}
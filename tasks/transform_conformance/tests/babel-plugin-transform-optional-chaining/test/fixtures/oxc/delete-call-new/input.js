@@ -0,0 +1,29 @@
+// Delete
+delete a?.b;
+delete a?.[b];
+delete a?.b.c;
+delete a.b?.c.d;
+delete a?.b?.c;
+delete (a?.b);
+delete ((a?.b.c));
+delete (a?.b).c;
+x = delete a?.b;
+function f(x = delete (a?.b)) {}
+
+// Call
+a?.b();
+a?.b.c();
+a?.b?.c();
+a.b?.();
+a?.b.c?.();
+(a?.b).c();
+(a?.b.c)();
+(a?.b.c)?.();
+
+// New
+new (a?.b)();
+new (a?.b.c)();
+new (a?.b?.c)(d);
+
+// Write
+(a?.b).c = d;
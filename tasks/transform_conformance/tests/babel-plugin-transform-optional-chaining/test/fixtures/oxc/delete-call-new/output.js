@@ -0,0 +1,27 @@
+var _a, _a2, _a3, _a$b, _a4, _a5, _a6, _a7, _a8, _a10, _a11, _a12, _a$b2, _a13, _a14, _a14$b, _a14$b$c, _a15, _a16, _a16$b, _a17, _a17$b, _a17$b$c, _a18, _a19, _a20, _a21;
+(_a = a) === null || _a === void 0 ? true : delete _a.b;
+(_a2 = a) === null || _a2 === void 0 ? true : delete _a2[b];
+(_a3 = a) === null || _a3 === void 0 ? true : delete _a3.b.c;
+(_a$b = a.b) === null || _a$b === void 0 ? true : delete _a$b.c.d;
+(_a4 = a) === null || _a4 === void 0 || (_a4 = _a4.b) === null || _a4 === void 0 ? true : delete _a4.c;
+(_a5 = a) === null || _a5 === void 0 ? true : delete _a5.b;
+(_a6 = a) === null || _a6 === void 0 ? true : delete _a6.b.c;
+delete ((_a7 = a) === null || _a7 === void 0 ? void 0 : _a7.b).c;
+x = (_a8 = a) === null || _a8 === void 0 ? true : delete _a8.b;
+function f(x = (() => {
+  var _a9;
+  return (_a9 = a) === null || _a9 === void 0 ? true : delete _a9.b;
+})()) {}
+(_a10 = a) === null || _a10 === void 0 ? void 0 : _a10.b();
+(_a11 = a) === null || _a11 === void 0 ? void 0 : _a11.b.c();
+(_a12 = a) === null || _a12 === void 0 || (_a12 = _a12.b) === null || _a12 === void 0 ? void 0 : _a12.c();
+(_a$b2 = (_a13 = a).b) === null || _a$b2 === void 0 ? void 0 : _a$b2.call(_a13);
+(_a14 = a) === null || _a14 === void 0 || (_a14$b$c = (_a14$b = _a14.b).c) === null || _a14$b$c === void 0 ? void 0 : _a14$b$c.call(_a14$b);
+((_a15 = a) === null || _a15 === void 0 ? void 0 : _a15.b).c();
+((_a16 = a) === null || _a16 === void 0 ? void 0 : (_a16$b = _a16.b).c.bind(_a16$b))();
+(_a17 = a) === null || _a17 === void 0 || (_a17$b$c = (_a17$b = _a17.b).c) === null || _a17$b$c === void 0 ? void 0 : _a17$b$c.call(_a17$b);
+new ((_a18 = a) === null || _a18 === void 0 ? void 0 : _a18.b)();
+new ((_a19 = a) === null || _a19 === void 0 ? void 0 : _a19.b.c)();
+new ((_a20 = a) === null || _a20 === void 0 || (_a20 = _a20.b) === null || _a20 === void 0 ? void 0 : _a20.c)(d);
+((_a21 = a) === null || _a21 === void 0 ? void 0 : _a21.b).c = d;
+
@@ -0,0 +1,14 @@
+class Foo {
+  #field = 1;
+  #method() {}
+  get #accessor() {}
+  static #staticMethod() {}
+
+  test(other) {
+    return #field in other && #method in other && #accessor in other;
+  }
+
+  static check(a) {
+    return #staticMethod in a || #method in (a ?? this);
+  }
+}
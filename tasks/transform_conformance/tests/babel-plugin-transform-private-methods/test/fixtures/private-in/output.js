@@ -0,0 +1,21 @@
+var _field = /*#__PURE__*/ new WeakMap();
+var _Foo_brand = /*#__PURE__*/ new WeakSet();
+
+class Foo {
+  constructor() {
+    babelHelpers.classPrivateMethodInitSpec(this, _Foo_brand);
+    babelHelpers.classPrivateFieldInitSpec(this, _field, 1);
+  }
+
+  test(other) {
+    return _field.has(babelHelpers.checkInRHS(other)) && _Foo_brand.has(babelHelpers.checkInRHS(other)) && _Foo_brand.has(babelHelpers.checkInRHS(other));
+  }
+
+  static check(a) {
+    return babelHelpers.checkInRHS(a) === Foo || _Foo_brand.has(babelHelpers.checkInRHS(a ?? this));
+  }
+}
+
+function _method() {}
+function _get_accessor() {}
+function _staticMethod() {}
@@ -0,0 +1,28 @@
+abstract class Shape {
+  abstract name: string;
+  abstract area(): number;
+  abstract get sides(): number;
+  abstract set sides(value: number);
+  abstract accessor label: string;
+  color = "red";
+  constructor(public readonly id: number, protected abstractFlag: boolean) {
+    console.log(this.id);
+  }
+  describe() {
+    return `${this.name}: ${this.area()}`;
+  }
+}
+abstract class Square extends Shape {
+  constructor(id: number, private size: number) {
+    super(id, false);
+  }
+  area() {
+    return this.size ** 2;
+  }
+  get sides() {
+    return 4;
+  }
+}
+export default abstract class {
+  abstract run(): void;
+}
@@ -0,0 +1,24 @@
+class Shape {
+  color = "red";
+  constructor(id, abstractFlag) {
+    this.id = id;
+    this.abstractFlag = abstractFlag;
+    console.log(this.id);
+  }
+  describe() {
+    return `${this.name}: ${this.area()}`;
+  }
+}
+class Square extends Shape {
+  constructor(id, size) {
+    super(id, false);
+    this.size = size;
+  }
+  area() {
+    return this.size ** 2;
+  }
+  get sides() {
+    return 4;
+  }
+}
+export default class {}
@@ -0,0 +1,5 @@
+function outer() {
+  return function inner(s) {
+    return [/a/.test(s), Object.freeze({ a: 1 })];
+  };
+}
@@ -0,0 +1,7 @@
+var _regex = /a/;
+var _frozen = Object.freeze({ a: 1 });
+function outer() {
+  return function inner(s) {
+    return [_regex.test(s), _frozen];
+  };
+}
@@ -0,0 +1,6 @@
+function keys() {
+  return Object.freeze(["a", 1, true, null, 1n]);
+}
+function defaults() {
+  return Object.freeze({ a: 1, "b": "b", 2: null });
+}
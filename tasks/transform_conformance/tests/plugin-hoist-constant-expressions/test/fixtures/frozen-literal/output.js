@@ -0,0 +1,18 @@
+var _frozen = Object.freeze([
+  "a",
+  1,
+  true,
+  null,
+  1n
+]);
+var _frozen2 = Object.freeze({
+  a: 1,
+  "b": "b",
+  2: null
+});
+function keys() {
+  return _frozen;
+}
+function defaults() {
+  return _frozen2;
+}
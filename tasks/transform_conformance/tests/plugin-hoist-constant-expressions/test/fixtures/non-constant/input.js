@@ -0,0 +1,14 @@
+function f(x, Object) {
+  return Object.freeze([x]);
+}
+function g(x) {
+  Object.freeze([x]);
+  Object.freeze({ x });
+  Object.freeze({ [x]: 1 });
+  Object.freeze({ a: [] });
+  Object.freeze({ ...x });
+  Object.freeze({ get a() { return 1; } });
+  Object?.freeze([1]);
+  Object.freeze([1], 2);
+  return Object.freeze([1, , 2]);
+}
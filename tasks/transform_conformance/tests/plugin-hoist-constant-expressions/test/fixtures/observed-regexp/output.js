@@ -0,0 +1,13 @@
+function f(s) {
+  const re = /a/;
+  return re.test(s);
+}
+function g(s) {
+  /a/.lastIndex = 1;
+  /a/.source;
+  s.replace(/a/, "b");
+  /a/.test?.(s);
+  /a/?.test(s);
+  /a/["test"](s);
+  return /a/.match(s);
+}
@@ -0,0 +1,7 @@
+function isDigits(s) {
+  return /^\d+$/.test(s);
+}
+const isWord = (s) => /^\w+$/iu.test(s);
+function firstWord(s) {
+  return (/\w+/).exec(s);
+}
@@ -0,0 +1,10 @@
+var _regex = /^\d+$/;
+var _regex2 = /^\w+$/iu;
+var _regex3 = /\w+/;
+function isDigits(s) {
+  return _regex.test(s);
+}
+const isWord = (s) => _regex2.test(s);
+function firstWord(s) {
+  return _regex3.exec(s);
+}
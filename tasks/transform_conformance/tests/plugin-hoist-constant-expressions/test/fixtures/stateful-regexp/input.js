@@ -0,0 +1,13 @@
+function findAll(s) {
+  const re = /a/g;
+  return re.exec(s);
+}
+function global(s) {
+  return /a/g.test(s);
+}
+function globalExec(s) {
+  return /a/g.exec(s);
+}
+function sticky(s) {
+  return /a/y.test(s);
+}
@@ -0,0 +1,5 @@
+const re = /a/;
+const frozen = Object.freeze(["a"]);
+class Foo {
+  re = /a/;
+}
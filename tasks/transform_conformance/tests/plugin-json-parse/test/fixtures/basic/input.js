@@ -0,0 +1,3 @@
+const object = { a: 1, "b-c": [true, false, null], 1: -2.5, 1e21: "big" };
+const array = [{ nested: { deep: [] } }, -0, 0.1, 1e-7];
+const empty = {};
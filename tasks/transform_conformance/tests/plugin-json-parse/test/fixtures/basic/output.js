@@ -0,0 +1,3 @@
+const object = JSON.parse("{\"a\":1,\"b-c\":[true,false,null],\"1\":-2.5,\"1e+21\":\"big\"}");
+const array = JSON.parse("[{\"nested\":{\"deep\":[]}},-0,0.1,1e-7]");
+const empty = JSON.parse("{}");
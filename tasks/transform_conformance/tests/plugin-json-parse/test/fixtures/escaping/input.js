@@ -0,0 +1 @@
+const strings = ['"quoted"', "back\\slash", "line\nbreak\ttab\r", "\u0000\u001f\u007f", "  ", "emoji 😀", "</script>"];
@@ -0,0 +1 @@
+const strings = JSON.parse("[\"\\\"quoted\\\"\",\"back\\\\slash\",\"line\\nbreak\\ttab\\r\",\"\\u0000\\u001f\",\"\u2028\u2029\",\"emoji 😀\",\"<\/script>\"]");
@@ -0,0 +1,10 @@
+const fn = { a: 1, b: () => {} };
+const undef = { a: undefined, b: [1, 2] };
+const ident = [a, { b: 1 }];
+const spread = { ...rest, a: [1] };
+const hole = [1, , 2];
+const computed = { ["a"]: 1, b: { c: 2 } };
+const method = { a() {}, b: [3] };
+const shorthand = { a };
+const template = { a: `x` };
+const infinity = [1e999];
@@ -0,0 +1,29 @@
+const fn = {
+  a: 1,
+  b: () => {}
+};
+const undef = {
+  a: undefined,
+  b: JSON.parse("[1,2]")
+};
+const ident = [a, JSON.parse("{\"b\":1}")];
+const spread = {
+  ...rest,
+  a: JSON.parse("[1]")
+};
+const hole = [
+  1,
+  ,
+  2
+];
+const computed = {
+  ["a"]: 1,
+  b: JSON.parse("{\"c\":2}")
+};
+const method = {
+  a() {},
+  b: JSON.parse("[3]")
+};
+const shorthand = { a };
+const template = { a: `x` };
+const infinity = [Infinity];
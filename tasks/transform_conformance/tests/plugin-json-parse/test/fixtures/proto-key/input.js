@@ -0,0 +1,3 @@
+const a = { __proto__: null, b: 1 };
+const b = { "__proto__": { c: 1 } };
+const c = { ["__proto__"]: 1 };
@@ -0,0 +1,4 @@
+function f(JSON) {
+  return { a: 1 };
+}
+const g = { b: 2 };
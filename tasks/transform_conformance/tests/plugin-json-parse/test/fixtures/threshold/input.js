@@ -0,0 +1,2 @@
+const small = { a: 1 };
+const large = { name: "a long enough value" };
@@ -0,0 +1,14 @@
+import { load } from "./load.js";
+
+const config = await load();
+export const value = config.value;
+export let count = 0;
+export class Client {
+  constructor() {
+    this.config = config;
+  }
+}
+export function increment() {
+  count++;
+}
+export default config;
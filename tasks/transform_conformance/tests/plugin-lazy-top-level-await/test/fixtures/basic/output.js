@@ -0,0 +1,36 @@
+import { load } from "./load.js";
+export function increment() {
+  count++;
+}
+var config, value, count, Client, _default, _promise;
+function _init() {
+  return _promise || (_promise = Promise.resolve().then(async () => {
+    config = await load();
+    value = config.value;
+    count = 0;
+    Client = class {
+      constructor() {
+        this.config = config;
+      }
+    };
+    _default = config;
+  }));
+}
+async function _getValue() {
+  await _init();
+  return value;
+}
+async function _getCount() {
+  await _init();
+  return count;
+}
+async function _getClient() {
+  await _init();
+  return Client;
+}
+async function _getDefault() {
+  await _init();
+  return _default;
+}
+export { _getValue as value, _getCount as count, _getClient as Client, _getDefault as default };
+_init();
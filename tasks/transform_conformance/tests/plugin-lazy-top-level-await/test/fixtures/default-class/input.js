@@ -0,0 +1,3 @@
+const base = await import("./base.js");
+
+export default class Widget extends base.Widget {}
@@ -0,0 +1,13 @@
+var base, Widget, _promise;
+function _init() {
+  return _promise || (_promise = Promise.resolve().then(async () => {
+    base = await import("./base.js");
+    Widget = class extends base.Widget {};
+  }));
+}
+async function _getWidget() {
+  await _init();
+  return Widget;
+}
+export { _getWidget as default };
+_init();
@@ -0,0 +1 @@
+export default await fetch("/data.json").then((res) => res.json());
@@ -0,0 +1,12 @@
+var _default, _promise;
+function _init() {
+  return _promise || (_promise = Promise.resolve().then(async () => {
+    _default = await fetch("/data.json").then((res) => res.json());
+  }));
+}
+async function _getDefault() {
+  await _init();
+  return _default;
+}
+export { _getDefault as default };
+_init();
@@ -0,0 +1,3 @@
+export const { a, b: [c, d = 1], ...rest } = await import("./data.js");
+const [first, , ...others] = await Promise.all([1, 2, 3]);
+export { first, others as remaining };
@@ -0,0 +1,38 @@
+export { _getFirst as first, _getOthers as remaining };
+var a, c, d, rest, first, others, _promise;
+function _init() {
+  return _promise || (_promise = Promise.resolve().then(async () => {
+    ({a, b: [c, d = 1], ...rest} = await import("./data.js"));
+    [first, , ...others] = await Promise.all([
+      1,
+      2,
+      3
+    ]);
+  }));
+}
+async function _getFirst() {
+  await _init();
+  return first;
+}
+async function _getOthers() {
+  await _init();
+  return others;
+}
+async function _getA() {
+  await _init();
+  return a;
+}
+async function _getC() {
+  await _init();
+  return c;
+}
+async function _getD() {
+  await _init();
+  return d;
+}
+async function _getRest() {
+  await _init();
+  return rest;
+}
+export { _getA as a, _getC as c, _getD as d, _getRest as rest };
+_init();
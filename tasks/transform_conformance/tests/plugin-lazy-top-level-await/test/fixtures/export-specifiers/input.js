@@ -0,0 +1,7 @@
+import { dep } from "./dep.js";
+
+let value = await dep();
+function helper() {
+  return value;
+}
+export { value, value as alias, helper, dep };
@@ -0,0 +1,16 @@
+import { dep } from "./dep.js";
+function helper() {
+  return value;
+}
+export { _getValue as value, _getValue as alias, helper, dep };
+var value, _promise;
+function _init() {
+  return _promise || (_promise = Promise.resolve().then(async () => {
+    value = await dep();
+  }));
+}
+async function _getValue() {
+  await _init();
+  return value;
+}
+_init();
@@ -0,0 +1,5 @@
+export const value = 1;
+
+export async function load() {
+  return await fetch("/data.json");
+}
@@ -0,0 +1,4 @@
+export const value = 1;
+export async function load() {
+  return await fetch("/data.json");
+}
@@ -0,0 +1,7 @@
+export * from "./a.js";
+export * as b from "./b.js";
+export { c, d as default } from "./c.js";
+
+for await (const chunk of stream()) {
+  console.log(chunk);
+}
@@ -0,0 +1,12 @@
+export * from "./a.js";
+export * as b from "./b.js";
+export { c, d as default } from "./c.js";
+var _promise;
+function _init() {
+  return _promise || (_promise = Promise.resolve().then(async () => {
+    for await (const chunk of stream()) {
+      console.log(chunk);
+    }
+  }));
+}
+_init();